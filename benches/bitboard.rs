@@ -0,0 +1,90 @@
+use criterion::{Criterion, black_box, criterion_group, criterion_main};
+use magpie::othello::{Board, Kernel, Position, Stone, Symmetry, bench};
+
+fn bench_flips(c: &mut Criterion) {
+    let moves = moves_of_games();
+    let mut group = c.benchmark_group("raw_flips");
+    for kernel in Kernel::ALL
+        .into_iter()
+        .filter(|kernel| kernel.is_supported())
+    {
+        group.bench_function(kernel.name(), |b| {
+            b.iter(|| {
+                moves.iter().fold(0, |acc, (own, opponent, pos)| {
+                    acc ^ bench::flips(black_box(kernel), *own, *opponent, *pos)
+                })
+            });
+        });
+    }
+    group.finish();
+}
+
+fn bench_moves(c: &mut Criterion) {
+    let moves = moves_of_games();
+    c.bench_function("raw_moves", |b| {
+        b.iter(|| {
+            moves.iter().fold(0, |acc, (own, opponent, _)| {
+                acc ^ bench::moves(black_box(*own), black_box(*opponent))
+            })
+        });
+    });
+}
+
+fn bench_neighbours(c: &mut Criterion) {
+    let moves = moves_of_games();
+    c.bench_function("raw_neighbours", |b| {
+        b.iter(|| {
+            moves.iter().fold(0, |acc, (own, _, _)| {
+                acc ^ bench::neighbours(black_box(*own))
+            })
+        });
+    });
+}
+
+fn bench_transform(c: &mut Criterion) {
+    let bits = 0x00_11_66_0c_3c_2c_00_00;
+    let mut group = c.benchmark_group("raw_transform");
+    for symmetry in Symmetry::ALL {
+        group.bench_function(format!("{symmetry:?}"), |b| {
+            b.iter(|| bench::transform(black_box(symmetry), black_box(bits)));
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_flips,
+    bench_moves,
+    bench_neighbours,
+    bench_transform,
+);
+criterion_main!(benches);
+
+/// Every legal move of a few deterministic games, in which moves are chosen
+/// by cycling through the legal ones, as the stones of the player to move,
+/// the stones of the opponent and the move.
+fn moves_of_games() -> Vec<(u64, u64, u64)> {
+    let mut moves = Vec::new();
+    for seed in 0..8 {
+        let mut board = Board::standard();
+        let mut stone = Stone::Black;
+        for ply in 0.. {
+            let legal: Vec<Position> = board.moves_for(stone).hot_bits().collect();
+            if legal.is_empty() {
+                stone = stone.flip();
+                if board.moves_for(stone).is_empty() {
+                    break;
+                }
+                continue;
+            }
+            let own = board.bits_for(stone).raw();
+            let opponent = board.bits_for(stone.flip()).raw();
+            moves.extend(legal.iter().map(|pos| (own, opponent, pos.raw())));
+            let pos = legal[(seed + ply) % legal.len()];
+            board.play(stone, pos);
+            stone = stone.flip();
+        }
+    }
+    moves
+}
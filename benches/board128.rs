@@ -0,0 +1,82 @@
+use criterion::{BenchmarkId, Criterion, black_box, criterion_group, criterion_main};
+use magpie::othello::{Board, Board128, Position, Stone};
+
+fn bench_legal_moves(c: &mut Criterion) {
+    let board = board_for_legal_moves();
+    let packed = Board128::from(board);
+    let mut group = c.benchmark_group("layout_legal_moves");
+    group.bench_function(BenchmarkId::from_parameter("board"), |b| {
+        b.iter(|| black_box(board).moves_for(black_box(Stone::Black)));
+    });
+    group.bench_function(BenchmarkId::from_parameter("board128"), |b| {
+        b.iter(|| black_box(packed).moves_for(black_box(Stone::Black)));
+    });
+    group.finish();
+}
+
+fn bench_play(c: &mut Criterion) {
+    let board = board_for_play();
+    let packed = Board128::from(board);
+    let mut group = c.benchmark_group("layout_play");
+    group.bench_function(BenchmarkId::from_parameter("board"), |b| {
+        b.iter(|| black_box(board).play(black_box(Stone::Black), black_box(Position::E5)));
+    });
+    group.bench_function(BenchmarkId::from_parameter("board128"), |b| {
+        b.iter(|| black_box(packed).play(black_box(Stone::Black), black_box(Position::E5)));
+    });
+    group.finish();
+}
+
+fn bench_playout(c: &mut Criterion) {
+    let mut group = c.benchmark_group("layout_playout");
+    group.bench_function(BenchmarkId::from_parameter("board"), |b| {
+        b.iter(|| {
+            let mut board = black_box(Board::standard());
+            let mut stone = Stone::Black;
+            loop {
+                if board.moves_for(stone).is_empty() {
+                    stone = stone.flip();
+                }
+                let Some(pos) = board.moves_for(stone).hot_bits().next() else {
+                    break board;
+                };
+                board.play(stone, pos);
+                stone = stone.flip();
+            }
+        });
+    });
+    group.bench_function(BenchmarkId::from_parameter("board128"), |b| {
+        b.iter(|| {
+            let mut board = black_box(Board128::standard());
+            let mut stone = Stone::Black;
+            loop {
+                if board.moves_for(stone).is_empty() {
+                    stone = stone.flip();
+                }
+                let Some(pos) = board.moves_for(stone).hot_bits().next() else {
+                    break board;
+                };
+                board.play(stone, pos);
+                stone = stone.flip();
+            }
+        });
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_legal_moves, bench_play, bench_playout);
+criterion_main!(benches);
+
+fn board_for_play() -> Board {
+    let black_pos = 0x88_01_00_00_81_00_00_49;
+    let white_pos = 0x00_48_2a_1c_76_1c_2a_00;
+
+    Board::try_from((black_pos, white_pos)).unwrap()
+}
+
+fn board_for_legal_moves() -> Board {
+    let black_pos = 0x00_11_66_0c_3c_2c_00_00;
+    let white_pos = 0x00_66_00_52_40_52_56_00;
+
+    Board::try_from((black_pos, white_pos)).unwrap()
+}
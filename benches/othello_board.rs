@@ -1,6 +1,7 @@
-use criterion::{black_box, criterion_group, criterion_main, Criterion};
-use magpie::othello::{Bitboard, Board, Position, Stone};
+use criterion::{BenchmarkId, Criterion, black_box, criterion_group, criterion_main};
+use magpie::othello::{Bitboard, Board, Kernel, Position, Stone};
 
+#[allow(clippy::clone_on_copy)]
 fn bench_clone(c: &mut Criterion) {
     let board = Board::standard();
     c.bench_function("clone", |b| b.iter(|| board.clone()));
@@ -13,6 +14,37 @@ fn bench_legal_moves(c: &mut Criterion) {
     });
 }
 
+fn bench_legal_moves_fast(c: &mut Criterion) {
+    let mut group = c.benchmark_group("legal_moves_fast");
+    for (name, board) in [
+        ("opening", Board::standard()),
+        ("dense", board_for_legal_moves()),
+    ] {
+        group.bench_function(BenchmarkId::new("moves_for", name), |b| {
+            b.iter(|| board.moves_for(black_box(Stone::Black)));
+        });
+        group.bench_function(BenchmarkId::new("legal_moves_fast", name), |b| {
+            b.iter(|| board.legal_moves_fast(black_box(Stone::Black)));
+        });
+    }
+    group.finish();
+}
+
+fn bench_legal_moves_kogge_stone(c: &mut Criterion) {
+    let board = board_for_legal_moves();
+    c.bench_function("legal_moves_kogge_stone", |b| {
+        b.iter(|| board.moves_with(Kernel::KoggeStone, black_box(Stone::Black)));
+    });
+}
+
+fn bench_mobility(c: &mut Criterion) {
+    let board = board_for_legal_moves();
+    c.bench_function("mobility", |b| {
+        b.iter(|| board.mobility(black_box(Stone::Black)));
+    });
+}
+
+#[allow(clippy::clone_on_copy)]
 fn bench_play(c: &mut Criterion) {
     let board = board_for_play();
     let pos: Position = (0x00_00_00_00_08_00_00_00).try_into().unwrap();
@@ -23,6 +55,35 @@ fn bench_play(c: &mut Criterion) {
     });
 }
 
+fn bench_flips(c: &mut Criterion) {
+    let boards = boards_for_flips();
+    let mut group = c.benchmark_group("flips");
+    for kernel in Kernel::ALL
+        .into_iter()
+        .filter(|kernel| kernel.is_supported())
+    {
+        group.bench_function(kernel.name(), |b| {
+            b.iter(|| {
+                boards.iter().fold(0, |acc, (board, stone, pos)| {
+                    acc ^ board.flips_with(black_box(kernel), *stone, *pos).raw()
+                })
+            });
+        });
+    }
+    group.finish();
+}
+
+fn bench_make_unmake(c: &mut Criterion) {
+    let mut board = board_for_play();
+    let pos: Position = (0x00_00_00_00_08_00_00_00).try_into().unwrap();
+    c.bench_function("make_unmake", |b| {
+        b.iter(|| {
+            let undo = board.make(black_box(Stone::Black), black_box(pos));
+            board.unmake(undo);
+        });
+    });
+}
+
 fn bench_legal_move_check(c: &mut Criterion) {
     let board = board_for_play();
     let pos: Position = (0x00_00_00_00_08_00_00_00).try_into().unwrap();
@@ -51,7 +112,12 @@ criterion_group!(
     benches,
     bench_clone,
     bench_legal_moves,
+    bench_legal_moves_fast,
+    bench_legal_moves_kogge_stone,
+    bench_mobility,
     bench_play,
+    bench_flips,
+    bench_make_unmake,
     bench_legal_move_check,
     bench_bits_extraction,
     bench_hot_bits_extraction,
@@ -71,3 +137,27 @@ fn board_for_legal_moves() -> Board {
 
     Board::try_from((black_pos, white_pos)).unwrap()
 }
+
+/// Every legal move of a few deterministic games, in which moves are chosen
+/// by cycling through the legal ones.
+fn boards_for_flips() -> Vec<(Board, Stone, Position)> {
+    let mut boards = Vec::new();
+    for seed in 0..8 {
+        let mut board = Board::standard();
+        let mut stone = Stone::Black;
+        for ply in 0.. {
+            let moves: Vec<Position> = board.moves_for(stone).hot_bits().collect();
+            if moves.is_empty() {
+                stone = stone.flip();
+                if board.moves_for(stone).is_empty() {
+                    break;
+                }
+                continue;
+            }
+            boards.extend(moves.iter().map(|pos| (board, stone, *pos)));
+            board.play(stone, moves[(seed + ply) % moves.len()]);
+            stone = stone.flip();
+        }
+    }
+    boards
+}
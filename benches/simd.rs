@@ -0,0 +1,19 @@
+use criterion::{Criterion, black_box, criterion_group, criterion_main};
+use magpie::othello::{Board, Stone};
+
+fn bench_legal_moves_x8(c: &mut Criterion) {
+    let boards = [board_for_legal_moves(); 8];
+    c.bench_function("legal_moves_x8", |b| {
+        b.iter(|| Board::moves_for_x8(black_box(&boards), black_box([Stone::Black; 8])));
+    });
+}
+
+criterion_group!(benches, bench_legal_moves_x8);
+criterion_main!(benches);
+
+fn board_for_legal_moves() -> Board {
+    let black_pos = 0x00_11_66_0c_3c_2c_00_00;
+    let white_pos = 0x00_66_00_52_40_52_56_00;
+
+    Board::try_from((black_pos, white_pos)).unwrap()
+}
@@ -0,0 +1,111 @@
+pub fn generate() -> String {
+    let mut diagonals = [0u64; 15];
+    let mut anti_diagonals = [0u64; 15];
+    for rank in 0..8 {
+        for file in 0..8 {
+            let square = 1u64 << (63 - (rank * 8 + file));
+            // Offset by 7 so that the main diagonals end up in the middle
+            diagonals[file + 7 - rank] |= square;
+            anti_diagonals[file + rank] |= square;
+        }
+    }
+
+    let format = |masks: &[u64]| {
+        masks
+            .iter()
+            .map(|mask| format!("    {mask:#018x},"))
+            .collect::<Vec<String>>()
+            .join("\n")
+    };
+
+    format!(
+        "\
+{}
+
+// Diagonals parallel to the one from A1 to H8, starting with the one that
+// only contains A8 and ending with the one that only contains H1.
+pub const DIAGONALS: [u64; 15] = [
+{}
+];
+
+// Diagonals parallel to the one from H1 to A8, starting with the one that
+// only contains A1 and ending with the one that only contains H8.
+pub const ANTI_DIAGONALS: [u64; 15] = [
+{}
+];",
+        square_classes(),
+        format(&diagonals),
+        format(&anti_diagonals)
+    )
+}
+
+/// The comment, name and membership test of a class of squares. The test
+/// is given the smaller and the larger of the distances from a square to
+/// the closest rank and file on the edge.
+type SquareClass = (&'static str, &'static str, fn(usize, usize) -> bool);
+
+/// Generates the masks of the named classes of squares, which are defined
+/// by the distance of a square to the closest rank and file on the edge.
+fn square_classes() -> String {
+    let classes: [SquareClass; 9] = [
+        ("The four corners", "CORNERS", |near, far| {
+            near == 0 && far == 0
+        }),
+        (
+            "The squares orthogonally adjacent to the corners",
+            "C_SQUARES",
+            |near, far| near == 0 && far == 1,
+        ),
+        (
+            "The squares diagonally adjacent to the corners, B2, G2, B7 and G7",
+            "X_SQUARES",
+            |near, far| near == 1 && far == 1,
+        ),
+        (
+            "The squares on the edges two steps away from the corners",
+            "A_SQUARES",
+            |near, far| near == 0 && far == 2,
+        ),
+        (
+            "The squares in the middle of the edges",
+            "B_SQUARES",
+            |near, far| near == 0 && far == 3,
+        ),
+        (
+            "The squares along the edges, corners included",
+            "EDGES",
+            |near, _| near == 0,
+        ),
+        (
+            "The squares along the edges, without the corners",
+            "INNER_EDGES",
+            |near, far| near == 0 && far > 0,
+        ),
+        (
+            "The 4x4 block of squares from C3 to F6",
+            "SWEET_16",
+            |near, _| near >= 2,
+        ),
+        ("The four center squares", "CENTER", |near, _| near == 3),
+    ];
+
+    classes
+        .iter()
+        .map(|(doc, name, contains)| {
+            let mut mask = 0u64;
+            for rank in 0..8 {
+                for file in 0..8 {
+                    let rank_distance = rank.min(7 - rank);
+                    let file_distance = file.min(7 - file);
+                    let near = rank_distance.min(file_distance);
+                    let far = rank_distance.max(file_distance);
+                    if contains(near, far) {
+                        mask |= 1u64 << (63 - (rank * 8 + file));
+                    }
+                }
+            }
+            format!("// {doc}\npub const {name}: u64 = {mask:#018x};")
+        })
+        .collect::<Vec<String>>()
+        .join("\n\n")
+}
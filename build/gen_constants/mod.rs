@@ -2,11 +2,13 @@ use std::{env, fs::File, io::Write, path::Path};
 
 #[allow(dead_code)]
 mod common;
+mod masks;
 mod shift_rays;
 
 pub fn generate_constants() -> std::io::Result<()> {
     let common = include_str!("./common.rs").to_string();
     let shift_rays = shift_rays::generate();
+    let masks = masks::generate();
 
     let out_dir = env::var("OUT_DIR").unwrap();
     let path = Path::new(&out_dir).join("gen.rs");
@@ -14,6 +16,7 @@ pub fn generate_constants() -> std::io::Result<()> {
 
     writeln!(&mut output, "{common}")?;
     writeln!(&mut output, "{shift_rays}")?;
+    writeln!(&mut output, "{masks}")?;
     writeln!(&mut output, "{}", write_rotation_tables())?;
     Ok(())
 }
@@ -0,0 +1,6 @@
+use magpie::{eval::Heuristic, protocol::nboard::NboardServer, search::Searcher};
+
+fn main() -> std::io::Result<()> {
+    let engine = Searcher::new(Heuristic::default());
+    NboardServer::new(engine).run_stdio()
+}
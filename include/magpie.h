@@ -0,0 +1,82 @@
+/*
+ * C interface to magpie, a high-performance Othello library.
+ *
+ * Build the library with:
+ *
+ *     cargo rustc --release --lib --features ffi --crate-type cdylib
+ *
+ * Positions are bitboards with exactly one bit set, where the most
+ * significant bit represents A1 and the least significant bit represents H8.
+ */
+
+#ifndef MAGPIE_H
+#define MAGPIE_H
+
+/* This file is generated by cbindgen from src/ffi, do not edit it by hand. */
+
+#include <stdbool.h>
+#include <stdint.h>
+
+#define MAGPIE_BLACK 0
+
+#define MAGPIE_WHITE 1
+
+typedef enum MagpieResult {
+  MAGPIE_RESULT_OK = 0,
+  MAGPIE_RESULT_ILLEGAL_MOVE = 1,
+  MAGPIE_RESULT_INVALID_ARGUMENT = 2,
+} MagpieResult;
+
+typedef enum MagpieStatus {
+  MAGPIE_STATUS_PROGRESSING = 0,
+  MAGPIE_STATUS_BLACK_WINS = 1,
+  MAGPIE_STATUS_WHITE_WINS = 2,
+  MAGPIE_STATUS_DRAW = 3,
+} MagpieStatus;
+
+typedef struct MagpieGame MagpieGame;
+
+typedef struct MagpieBoard {
+  uint64_t black;
+  uint64_t white;
+} MagpieBoard;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+struct MagpieBoard magpie_board_standard(void);
+
+struct MagpieBoard magpie_board_empty(void);
+
+uint64_t magpie_board_moves(struct MagpieBoard board, uint8_t stone);
+
+bool magpie_board_is_legal_move(struct MagpieBoard board, uint8_t stone, uint64_t position);
+
+enum MagpieResult magpie_board_play(struct MagpieBoard *board, uint8_t stone, uint64_t position);
+
+uint64_t magpie_position_from_notation(const char *notation);
+
+struct MagpieGame *magpie_game_new(void);
+
+void magpie_game_free(struct MagpieGame *game);
+
+struct MagpieBoard magpie_game_board(const struct MagpieGame *game);
+
+uint8_t magpie_game_current_turn(const struct MagpieGame *game);
+
+uint64_t magpie_game_moves(const struct MagpieGame *game);
+
+enum MagpieResult magpie_game_play(struct MagpieGame *game, uint64_t position);
+
+bool magpie_game_pass_if_forced(struct MagpieGame *game);
+
+bool magpie_game_undo(struct MagpieGame *game);
+
+enum MagpieStatus magpie_game_status(const struct MagpieGame *game);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* MAGPIE_H */
@@ -0,0 +1,460 @@
+use crate::{
+    eval::{Evaluator, wdl_model},
+    othello::{Game, Move, Position, Stone},
+    search::{SearchLimits, Searcher, WIN_SCORE},
+};
+
+/// The number of centidiscs in a disc.
+pub(crate) const CENTIDISCS: i32 = 100;
+
+/// How good a move was, judged by how much it lost compared to the best
+/// move found.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum MoveQuality {
+    /// The move lost nothing, and is as good as the best move found.
+    Best,
+    /// The move lost less than an inaccuracy.
+    Good,
+    /// The move lost enough to be an inaccuracy.
+    Inaccuracy,
+    /// The move lost enough to be a mistake.
+    Mistake,
+    /// The move lost enough to be a blunder.
+    Blunder,
+}
+
+/// The losses in centidiscs from which moves are labeled as inaccuracies,
+/// mistakes and blunders.
+///
+/// # Examples
+/// ```rust
+/// use magpie::analysis::{MoveQuality, Thresholds};
+///
+/// let thresholds = Thresholds::default();
+/// assert_eq!(MoveQuality::Best, thresholds.classify(0));
+/// assert_eq!(MoveQuality::Good, thresholds.classify(100));
+/// assert_eq!(MoveQuality::Mistake, thresholds.classify(400));
+/// assert_eq!(MoveQuality::Blunder, thresholds.classify(1200));
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct Thresholds {
+    /// The smallest loss of an inaccuracy.
+    pub inaccuracy: i32,
+    /// The smallest loss of a mistake.
+    pub mistake: i32,
+    /// The smallest loss of a blunder.
+    pub blunder: i32,
+}
+
+impl Thresholds {
+    /// Returns thresholds given in whole discs.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::analysis::Thresholds;
+    ///
+    /// assert_eq!(Thresholds::default(), Thresholds::discs(2, 4, 8));
+    /// ```
+    #[must_use]
+    pub fn discs(inaccuracy: i32, mistake: i32, blunder: i32) -> Self {
+        Self {
+            inaccuracy: inaccuracy * CENTIDISCS,
+            mistake: mistake * CENTIDISCS,
+            blunder: blunder * CENTIDISCS,
+        }
+    }
+
+    /// Labels a move by its loss in centidiscs.
+    #[must_use]
+    pub fn classify(&self, loss: i32) -> MoveQuality {
+        if loss >= self.blunder {
+            MoveQuality::Blunder
+        } else if loss >= self.mistake {
+            MoveQuality::Mistake
+        } else if loss >= self.inaccuracy {
+            MoveQuality::Inaccuracy
+        } else if loss > 0 {
+            MoveQuality::Good
+        } else {
+            MoveQuality::Best
+        }
+    }
+}
+
+impl Default for Thresholds {
+    /// Returns thresholds of 2, 4 and 8 discs.
+    fn default() -> Self {
+        Self::discs(2, 4, 8)
+    }
+}
+
+/// Thresholds that depend on the stage of the game, since the same loss
+/// weighs more heavily the fewer moves are left to make up for it.
+///
+/// The stages are spread evenly over the game, from the standard opening
+/// position to a full board, as measured by [`Board::stage`] before the
+/// move is made.
+///
+/// [`Board::stage`]: crate::othello::Board::stage
+///
+/// # Examples
+/// ```rust
+/// use magpie::analysis::{MoveQuality, StagedThresholds, Thresholds};
+///
+/// let thresholds = StagedThresholds::new(vec![
+///     Thresholds::discs(4, 8, 16),
+///     Thresholds::discs(3, 6, 12),
+///     Thresholds::discs(2, 4, 8),
+/// ]);
+/// // Losing 4 discs is an inaccuracy in the opening but a mistake at the end
+/// assert_eq!(MoveQuality::Inaccuracy, thresholds.classify(5, 400));
+/// assert_eq!(MoveQuality::Mistake, thresholds.classify(50, 400));
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct StagedThresholds {
+    stages: Vec<Thresholds>,
+}
+
+impl StagedThresholds {
+    /// Returns thresholds for the specified stages, in the order they
+    /// occur in the game.
+    ///
+    /// # Panics
+    /// Panics if there are no stages.
+    #[must_use]
+    pub fn new(stages: Vec<Thresholds>) -> Self {
+        assert!(!stages.is_empty(), "at least one stage is required");
+        Self { stages }
+    }
+
+    /// Returns thresholds that are the same throughout the game.
+    #[must_use]
+    pub fn uniform(thresholds: Thresholds) -> Self {
+        Self::new(vec![thresholds])
+    }
+
+    /// Returns the thresholds of every stage.
+    #[must_use]
+    pub fn stages(&self) -> &[Thresholds] {
+        &self.stages
+    }
+
+    /// Returns the thresholds that apply at the specified stage of the
+    /// game, from 0 to 60.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::analysis::{StagedThresholds, Thresholds};
+    ///
+    /// let opening = Thresholds::discs(4, 8, 16);
+    /// let endgame = Thresholds::discs(2, 4, 8);
+    /// let thresholds = StagedThresholds::new(vec![opening, endgame]);
+    /// assert_eq!(&opening, thresholds.for_stage(0));
+    /// assert_eq!(&endgame, thresholds.for_stage(60));
+    /// ```
+    #[must_use]
+    pub fn for_stage(&self, stage: u8) -> &Thresholds {
+        let index = usize::from(stage) * self.stages.len() / 61;
+        &self.stages[index.min(self.stages.len() - 1)]
+    }
+
+    /// Labels a move made at the specified stage by its loss in
+    /// centidiscs.
+    #[must_use]
+    pub fn classify(&self, stage: u8, loss: i32) -> MoveQuality {
+        self.for_stage(stage).classify(loss)
+    }
+}
+
+impl Default for StagedThresholds {
+    /// Returns the default thresholds throughout the game.
+    fn default() -> Self {
+        Self::uniform(Thresholds::default())
+    }
+}
+
+impl From<Thresholds> for StagedThresholds {
+    fn from(thresholds: Thresholds) -> Self {
+        Self::uniform(thresholds)
+    }
+}
+
+/// Controls how deeply [`annotate`] searches and how it labels moves.
+///
+/// [`annotate`]: crate::analysis::annotate
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct AnnotationSettings {
+    /// The limits of the search of every position.
+    pub limits: SearchLimits,
+    /// The losses from which moves are labeled as mistakes, depending on
+    /// the stage of the game.
+    pub thresholds: StagedThresholds,
+}
+
+impl AnnotationSettings {
+    /// Returns settings that search every position within the specified
+    /// limits and label moves with the default thresholds.
+    #[must_use]
+    pub fn new(limits: SearchLimits) -> Self {
+        Self {
+            limits,
+            thresholds: StagedThresholds::default(),
+        }
+    }
+
+    /// Returns a copy of the settings with the specified thresholds, which
+    /// either apply throughout the game or depend on its stage.
+    #[must_use]
+    pub fn with_thresholds(self, thresholds: impl Into<StagedThresholds>) -> Self {
+        Self {
+            thresholds: thresholds.into(),
+            ..self
+        }
+    }
+}
+
+/// A move of an annotated game, along with the best move found instead.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct AnnotatedMove {
+    /// The index of the move in the history of the game.
+    pub ply: usize,
+    /// The player who made the move.
+    pub stone: Stone,
+    /// The stage of the game before the move, which selects the thresholds
+    /// the move is labeled by.
+    pub stage: u8,
+    /// The move that was played.
+    pub played: Position,
+    /// The best move found by the search.
+    pub best: Position,
+    /// The score of the played move in centidiscs, from the perspective of
+    /// the player who made it.
+    pub played_score: i32,
+    /// The score of the best move in centidiscs, from the perspective of
+    /// the player who made the move.
+    pub best_score: i32,
+    /// How much worse the played move was than the best move, in
+    /// centidiscs.
+    pub loss: i32,
+    /// The label of the move, based on its loss and the stage of the game.
+    pub quality: MoveQuality,
+}
+
+impl AnnotatedMove {
+    /// Returns how much the move lowered the expected result of the player
+    /// who made it, where a win counts as 1 and a draw as 0.5.
+    ///
+    /// Both scores are converted by [`wdl_model`] with the empty squares
+    /// left after the move. Unlike the loss in discs, this reflects that
+    /// giving away a few discs hardly matters in a game that is clearly won
+    /// or lost, but decides a close one.
+    ///
+    /// [`wdl_model`]: crate::eval::wdl_model
+    #[must_use]
+    pub fn expected_loss(&self) -> f64 {
+        let empties = 59u8.saturating_sub(self.stage);
+        let expected = |score: i32| {
+            wdl_model(f64::from(score) / f64::from(CENTIDISCS), empties).expected_score()
+        };
+        (expected(self.best_score) - expected(self.played_score)).max(0.0)
+    }
+}
+
+/// How accurately one of the players played a game.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+pub struct PlayerSummary {
+    /// The number of annotated moves made by the player.
+    pub moves: usize,
+    /// The number of moves that lost nothing.
+    pub best_moves: usize,
+    /// The number of inaccuracies.
+    pub inaccuracies: usize,
+    /// The number of mistakes.
+    pub mistakes: usize,
+    /// The number of blunders.
+    pub blunders: usize,
+    /// The sum of the losses of every move, in centidiscs.
+    pub total_loss: i64,
+}
+
+impl PlayerSummary {
+    /// Returns the average loss per move in centidiscs, or 0 if the player
+    /// made no moves.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn average_loss(&self) -> f64 {
+        if self.moves == 0 {
+            0.0
+        } else {
+            self.total_loss as f64 / self.moves as f64
+        }
+    }
+
+    /// Returns the share of moves that lost nothing, or 1 if the player
+    /// made no moves.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn accuracy(&self) -> f64 {
+        if self.moves == 0 {
+            1.0
+        } else {
+            self.best_moves as f64 / self.moves as f64
+        }
+    }
+
+    fn add(&mut self, annotated: &AnnotatedMove) {
+        self.moves += 1;
+        self.total_loss += i64::from(annotated.loss);
+        match annotated.quality {
+            MoveQuality::Best => self.best_moves += 1,
+            MoveQuality::Good => {}
+            MoveQuality::Inaccuracy => self.inaccuracies += 1,
+            MoveQuality::Mistake => self.mistakes += 1,
+            MoveQuality::Blunder => self.blunders += 1,
+        }
+    }
+}
+
+/// The moves of a game labeled by [`annotate`], along with a summary for
+/// each player.
+///
+/// [`annotate`]: crate::analysis::annotate
+#[derive(Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct Annotation {
+    /// Every annotated move, in the order they were played.
+    pub moves: Vec<AnnotatedMove>,
+    /// The summary of the black player.
+    pub black: PlayerSummary,
+    /// The summary of the white player.
+    pub white: PlayerSummary,
+}
+
+impl Annotation {
+    /// Returns the summary of the specified player.
+    #[must_use]
+    pub fn summary(&self, stone: Stone) -> &PlayerSummary {
+        match stone {
+            Stone::Black => &self.black,
+            Stone::White => &self.white,
+        }
+    }
+
+    /// Returns the average [expected loss] of the moves of the specified
+    /// player, or 0 if they made no moves.
+    ///
+    /// [expected loss]: crate::analysis::AnnotatedMove::expected_loss
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn average_expected_loss(&self, stone: Stone) -> f64 {
+        let moves = self.summary(stone).moves;
+        if moves == 0 {
+            return 0.0;
+        }
+        let total: f64 = self
+            .moves
+            .iter()
+            .filter(|annotated| annotated.stone == stone)
+            .map(AnnotatedMove::expected_loss)
+            .sum();
+        total / moves as f64
+    }
+
+    /// Returns the moves with the specified label.
+    pub fn with_quality(&self, quality: MoveQuality) -> impl Iterator<Item = &AnnotatedMove> {
+        self.moves.iter().filter(move |mv| mv.quality == quality)
+    }
+}
+
+/// Searches every position of a game and labels the moves that were played
+/// by how much they lost compared to the best move.
+///
+/// Every legal move of a position is searched within the limits of the
+/// settings, so that the played move and the best move are scored by the
+/// same search. Scores of solved positions are exact disc differentials,
+/// while other scores are taken to be in discs as returned by the
+/// evaluator, like those of [`DiscDifference`]. Passes and the placements
+/// that open a game of Reversi are not annotated.
+///
+/// The history of the game is annotated whether or not it has concluded,
+/// while moves that were undone and not redone are left out.
+///
+/// [`DiscDifference`]: crate::eval::DiscDifference
+///
+/// # Examples
+/// ```rust
+/// use magpie::analysis::{AnnotationSettings, MoveQuality, annotate};
+/// use magpie::eval::DiscDifference;
+/// use magpie::othello::{Game, Stone};
+/// use magpie::search::{SearchLimits, Searcher};
+///
+/// let mut game = Game::new();
+/// game.play("f5".parse().unwrap()).unwrap();
+/// let mut searcher = Searcher::new(DiscDifference);
+/// let settings = AnnotationSettings::new(SearchLimits::depth(1));
+/// let annotation = annotate(&game, &mut searcher, &settings);
+/// // Every opening move is equally good
+/// assert_eq!(MoveQuality::Best, annotation.moves[0].quality);
+/// assert_eq!(1.0, annotation.summary(Stone::Black).accuracy());
+/// ```
+pub fn annotate<E: Evaluator>(
+    game: &Game,
+    searcher: &mut Searcher<E>,
+    settings: &AnnotationSettings,
+) -> Annotation {
+    let mut replay = game.clone();
+    while replay.undo().is_some() {}
+
+    let mut annotation = Annotation::default();
+    for (ply, played) in game.history().iter().enumerate() {
+        let board = replay.board();
+        let stone = played.stone();
+        let placing = replay.is_placing();
+        replay.redo();
+        if let (Move::Play(pos), false) = (played.mv(), placing) {
+            let moves = board.moves_for(stone);
+            let report = searcher.multipv(
+                &board,
+                stone,
+                settings.limits,
+                usize::from(moves.count_set()),
+            );
+            if let (Some(best), Some(line)) = (
+                report.lines.first(),
+                report.lines.iter().find(|line| line.mv == pos),
+            ) {
+                let best_score = centidiscs(best.score);
+                let played_score = centidiscs(line.score);
+                let loss = (best_score - played_score).max(0);
+                let stage = board.stage();
+                let annotated = AnnotatedMove {
+                    ply,
+                    stone,
+                    stage,
+                    played: pos,
+                    best: best.mv,
+                    played_score,
+                    best_score,
+                    loss,
+                    quality: settings.thresholds.classify(stage, loss),
+                };
+                match stone {
+                    Stone::Black => annotation.black.add(&annotated),
+                    Stone::White => annotation.white.add(&annotated),
+                }
+                annotation.moves.push(annotated);
+            }
+        }
+    }
+    annotation
+}
+
+/// Converts a score of the searcher to centidiscs. Scores of solved
+/// positions carry the exact disc differential.
+pub(crate) fn centidiscs(score: i32) -> i32 {
+    // The disc differential of a finished game is at most 64
+    if score.abs() > WIN_SCORE - 64 {
+        (score - score.signum() * WIN_SCORE) * CENTIDISCS
+    } else {
+        score.saturating_mul(CENTIDISCS)
+    }
+}
@@ -0,0 +1,103 @@
+use crate::{
+    analysis::annotate::centidiscs,
+    eval::Evaluator,
+    othello::{Game, Stone},
+    search::{SearchLimits, Searcher},
+};
+use std::io::{self, Write};
+
+/// Searches every position of a game and returns its score, for plotting
+/// how the evaluation changed over the course of the game.
+///
+/// Every point pairs the number of moves played, passes included, with
+/// the score of the position at that point in centidiscs, from the
+/// perspective of black. The curve starts at the starting position and
+/// ends after the last move in the history of the game. Positions in which
+/// the opening stones of a game of Reversi are still being placed are left
+/// out. Scores are converted like those of [`annotate`].
+///
+/// [`annotate`]: crate::analysis::annotate
+///
+/// # Examples
+/// ```rust
+/// use magpie::analysis::eval_curve;
+/// use magpie::eval::DiscDifference;
+/// use magpie::othello::Transcript;
+/// use magpie::search::{SearchLimits, Searcher};
+///
+/// let game = "f5d6c3".parse::<Transcript>().unwrap().replay().unwrap();
+/// let mut searcher = Searcher::new(DiscDifference);
+/// let curve = eval_curve(&game, &mut searcher, SearchLimits::depth(1));
+/// assert_eq!(vec![0, 1, 2, 3], curve.iter().map(|(ply, _)| *ply).collect::<Vec<_>>());
+/// ```
+pub fn eval_curve<E: Evaluator>(
+    game: &Game,
+    searcher: &mut Searcher<E>,
+    limits: SearchLimits,
+) -> Vec<(usize, i32)> {
+    let mut replay = game.clone();
+    while replay.undo().is_some() {}
+
+    let mut curve = Vec::with_capacity(game.history().len() + 1);
+    for ply in 0..=game.history().len() {
+        if ply > 0 {
+            replay.redo();
+        }
+        if replay.is_placing() {
+            continue;
+        }
+        let stone = replay.current_turn();
+        let score = centidiscs(searcher.search(&replay.board(), stone, limits).score);
+        curve.push((
+            ply,
+            match stone {
+                Stone::Black => score,
+                Stone::White => -score,
+            },
+        ));
+    }
+    curve
+}
+
+/// Writes an evaluation curve as CSV, with a `ply,score` header followed
+/// by one line per point.
+///
+/// # Examples
+/// ```rust
+/// use magpie::analysis::write_curve_csv;
+///
+/// let mut csv = Vec::new();
+/// write_curve_csv(&[(0, 0), (1, 300)], &mut csv).unwrap();
+/// assert_eq!("ply,score\n0,0\n1,300\n", String::from_utf8(csv).unwrap());
+/// ```
+pub fn write_curve_csv(curve: &[(usize, i32)], mut writer: impl Write) -> io::Result<()> {
+    writeln!(writer, "ply,score")?;
+    for (ply, score) in curve {
+        writeln!(writer, "{ply},{score}")?;
+    }
+    Ok(())
+}
+
+/// Writes an evaluation curve as a JSON array with one object per point.
+///
+/// # Examples
+/// ```rust
+/// use magpie::analysis::write_curve_json;
+///
+/// let mut json = Vec::new();
+/// write_curve_json(&[(0, 0), (1, -300)], &mut json).unwrap();
+/// assert_eq!(
+///     r#"[{"ply":0,"score":0},{"ply":1,"score":-300}]"#,
+///     String::from_utf8(json).unwrap()
+/// );
+/// ```
+pub fn write_curve_json(curve: &[(usize, i32)], mut writer: impl Write) -> io::Result<()> {
+    write!(writer, "[")?;
+    for (index, (ply, score)) in curve.iter().enumerate() {
+        if index > 0 {
+            write!(writer, ",")?;
+        }
+        write!(writer, r#"{{"ply":{ply},"score":{score}}}"#)?;
+    }
+    write!(writer, "]")
+}
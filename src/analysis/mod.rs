@@ -0,0 +1,44 @@
+//! Reviews finished games with the engine.
+//!
+//! [`annotate`] searches every position of a game, compares the move that
+//! was played with the best move found and labels each move by how much it
+//! lost, measured in centidiscs: hundredths of a disc of the final disc
+//! differential. Losses can be judged more strictly as the game goes on
+//! with [`StagedThresholds`]. The [`Annotation`] it returns also summarizes
+//! the accuracy of both players.
+//!
+//! [`eval_curve`] scores every position of a game instead, for plotting
+//! the evaluation over the course of the game, and can be exported as CSV
+//! or JSON.
+//!
+//! [`annotate`]: crate::analysis::annotate
+//! [`StagedThresholds`]: crate::analysis::StagedThresholds
+//! [`Annotation`]: crate::analysis::Annotation
+//! [`eval_curve`]: crate::analysis::eval_curve
+//!
+//! # Examples
+//! ```rust
+//! use magpie::analysis::{AnnotationSettings, annotate};
+//! use magpie::eval::DiscDifference;
+//! use magpie::othello::{Stone, Transcript};
+//! use magpie::search::{SearchLimits, Searcher};
+//!
+//! let game = "f5d6c3d3c4f4".parse::<Transcript>().unwrap().replay().unwrap();
+//! let mut searcher = Searcher::new(DiscDifference);
+//! let settings = AnnotationSettings::new(SearchLimits::depth(2));
+//! let annotation = annotate(&game, &mut searcher, &settings);
+//! assert_eq!(6, annotation.moves.len());
+//! assert_eq!(3, annotation.summary(Stone::Black).moves);
+//! ```
+
+/// Labels the moves of a game and summarizes the accuracy of the players.
+mod annotate;
+/// Scores every position of a game for plotting.
+mod curve;
+
+pub use annotate::{
+    AnnotatedMove, Annotation, AnnotationSettings, MoveQuality, PlayerSummary, StagedThresholds,
+    Thresholds, annotate,
+};
+pub(crate) use annotate::{CENTIDISCS, centidiscs};
+pub use curve::{eval_curve, write_curve_csv, write_curve_json};
@@ -0,0 +1,272 @@
+//! Play Othello against the built-in engine in the terminal.
+//!
+//! Moves are entered in algebraic notation, such as `f5`. Forced passes are
+//! made automatically. Type `help` during the game for the other commands.
+
+use magpie::{
+    eval::Heuristic,
+    othello::{Format, Game, Move, Position, Scoring, Status, Stone},
+    search::{SearchLimits, Searcher},
+};
+use std::{
+    io::{self, BufRead, Write},
+    process::ExitCode,
+    str::FromStr,
+};
+
+const USAGE: &str = "\
+Usage: magpie-cli [OPTIONS]
+
+Options:
+  --white          Play the white stones instead of the black ones
+  --depth <DEPTH>  Search depth of the engine, from 1 to 20 [default: 6]
+  --unicode        Draw the board with Unicode characters
+  --colors         Color the board with ANSI escape codes
+  --help           Print this message";
+
+const COMMANDS: &str = "\
+Commands:
+  <move>  Play a move in algebraic notation, such as f5
+  undo    Take back your last move along with the engine's reply
+  hint    Ask the engine for a move
+  board   Print the board again
+  help    Print this message
+  quit    Leave the game";
+
+fn main() -> ExitCode {
+    let options = match Options::parse(std::env::args().skip(1)) {
+        Ok(Some(options)) => options,
+        Ok(None) => {
+            println!("{USAGE}");
+            return ExitCode::SUCCESS;
+        }
+        Err(error) => {
+            eprintln!("error: {error}\n\n{USAGE}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let mut session = Session::new(options);
+    match session.run(io::stdin().lock(), io::stdout().lock()) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("error: {error}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// The settings chosen on the command line.
+struct Options {
+    human: Stone,
+    depth: u8,
+    format: Format,
+    colors: bool,
+}
+
+impl Options {
+    /// Parses the command line arguments, returning `None` if help was
+    /// requested.
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<Option<Self>, String> {
+        let mut options = Options {
+            human: Stone::Black,
+            depth: 6,
+            format: Format::Standard,
+            colors: false,
+        };
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--white" => options.human = Stone::White,
+                "--depth" => {
+                    let depth = args.next().ok_or("--depth requires a value")?;
+                    options.depth = depth
+                        .parse()
+                        .ok()
+                        .filter(|depth| (1..=20).contains(depth))
+                        .ok_or_else(|| format!("invalid depth '{depth}'"))?;
+                }
+                "--unicode" => options.format = Format::Unicode,
+                "--colors" => options.colors = true,
+                "--help" | "-h" => return Ok(None),
+                _ => return Err(format!("unexpected argument '{arg}'")),
+            }
+        }
+        Ok(Some(options))
+    }
+}
+
+/// A command entered by the player.
+enum Command {
+    Play(Position),
+    Undo,
+    Hint,
+    Board,
+    Help,
+    Quit,
+}
+
+impl FromStr for Command {
+    type Err = ();
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input.trim().to_lowercase().as_str() {
+            "undo" | "u" => Ok(Command::Undo),
+            "hint" | "h" => Ok(Command::Hint),
+            "board" | "b" => Ok(Command::Board),
+            "help" | "?" => Ok(Command::Help),
+            "quit" | "q" | "exit" => Ok(Command::Quit),
+            notation => Position::try_from(notation)
+                .map(Command::Play)
+                .map_err(|_| ()),
+        }
+    }
+}
+
+/// A game between the player and the engine.
+struct Session {
+    options: Options,
+    game: Game,
+    engine: Searcher<Heuristic>,
+}
+
+impl Session {
+    fn new(options: Options) -> Self {
+        Self {
+            options,
+            game: Game::new(),
+            engine: Searcher::new(Heuristic::default()),
+        }
+    }
+
+    fn run(&mut self, input: impl BufRead, mut output: impl Write) -> io::Result<()> {
+        let out = &mut output;
+        let mut lines = input.lines();
+        writeln!(
+            out,
+            "You play {}. Type help for a list of commands.",
+            name(self.options.human)
+        )?;
+        let mut show_board = true;
+
+        loop {
+            let stone = self.game.current_turn();
+            if self.game.pass_if_forced() {
+                writeln!(out, "No legal moves for {}, who passes.", name(stone))?;
+                continue;
+            }
+            if self.game.status() != Status::Progressing {
+                return self.finish(out);
+            }
+
+            if stone != self.options.human {
+                let mv = self.best_move();
+                self.game.play(mv).expect("engine chose an illegal move");
+                writeln!(out, "Magpie plays {mv}.")?;
+                show_board = true;
+                continue;
+            }
+
+            if show_board {
+                self.print_board(out)?;
+                show_board = false;
+            }
+            write!(out, "> ")?;
+            out.flush()?;
+            let Some(line) = lines.next().transpose()? else {
+                return Ok(());
+            };
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            match line.parse() {
+                Ok(Command::Play(pos)) => {
+                    if self.game.play(pos).is_ok() {
+                        show_board = true;
+                    } else {
+                        writeln!(out, "{pos} is not a legal move.")?;
+                    }
+                }
+                Ok(Command::Undo) => {
+                    if self.undo() {
+                        show_board = true;
+                    } else {
+                        writeln!(out, "There is nothing to undo.")?;
+                    }
+                }
+                Ok(Command::Hint) => writeln!(out, "Magpie suggests {}.", self.best_move())?,
+                Ok(Command::Board) => show_board = true,
+                Ok(Command::Help) => writeln!(out, "{COMMANDS}")?,
+                Ok(Command::Quit) => return Ok(()),
+                Err(()) => writeln!(
+                    out,
+                    "Unknown command '{}'. Type help for a list of commands.",
+                    line.trim()
+                )?,
+            }
+        }
+    }
+
+    /// Searches for the best move of the player to move.
+    fn best_move(&mut self) -> Position {
+        let limits = SearchLimits::depth(self.options.depth);
+        let report = self
+            .engine
+            .search(&self.game.board(), self.game.current_turn(), limits);
+        report
+            .best_move
+            .expect("the player to move has legal moves")
+    }
+
+    /// Takes back moves until the last move of the player has been undone,
+    /// returning false if the player has not made any moves yet.
+    fn undo(&mut self) -> bool {
+        let played = self
+            .game
+            .history()
+            .iter()
+            .any(|played| played.stone() == self.options.human && played.mv() != Move::Pass);
+        if !played {
+            return false;
+        }
+        while let Some(played) = self.game.undo() {
+            if played.stone() == self.options.human && played.mv() != Move::Pass {
+                break;
+            }
+        }
+        true
+    }
+
+    /// Prints the board, with the legal moves of the player while the game
+    /// is in progress.
+    fn print_board(&self, out: &mut impl Write) -> io::Result<()> {
+        let mut display = self.game.display().with_format(self.options.format);
+        if self.game.status() == Status::Progressing {
+            display = display.with_legal_moves(self.options.human);
+        }
+        if self.options.colors {
+            display = display.ansi_colors();
+        }
+        write!(out, "{display}")
+    }
+
+    fn finish(&self, out: &mut impl Write) -> io::Result<()> {
+        self.print_board(out)?;
+        let (black, white) = self
+            .game
+            .final_score(Scoring::DiscCount)
+            .expect("the game has concluded");
+        let result = match self.game.status() {
+            Status::Win(stone) if stone == self.options.human => "You win",
+            Status::Win(_) => "Magpie wins",
+            _ => "The game is drawn",
+        };
+        writeln!(out, "{result}, {black} - {white} (black - white).")
+    }
+}
+
+fn name(stone: Stone) -> &'static str {
+    match stone {
+        Stone::Black => "black",
+        Stone::White => "white",
+    }
+}
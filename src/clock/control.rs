@@ -0,0 +1,74 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use core::time::Duration;
+
+/// The rules that decide how much time the players have for their moves.
+///
+/// Both players start with the same main time. Depending on the time
+/// control, time is either added after every move or granted in periods once
+/// the main time has run out.
+///
+/// # Examples
+/// ```rust
+/// use magpie::clock::TimeControl;
+/// use std::time::Duration;
+///
+/// let control = TimeControl::Increment {
+///     base: Duration::from_secs(300),
+///     increment: Duration::from_secs(3),
+/// };
+/// assert_eq!(Duration::from_secs(300), control.base());
+/// assert_eq!(Duration::from_secs(3), control.increment());
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum TimeControl {
+    /// A fixed amount of time for the entire game.
+    SuddenDeath {
+        /// The time on the clock at the start of the game.
+        base: Duration,
+    },
+    /// A fixed amount of time for the entire game, with time added after
+    /// every move, also known as a Fischer increment.
+    Increment {
+        /// The time on the clock at the start of the game.
+        base: Duration,
+        /// The time added to the clock after every move.
+        increment: Duration,
+    },
+    /// A main time followed by a number of periods. Once the main time has
+    /// run out, every move must be made within a period. A move that takes
+    /// longer uses up the period, and the player loses on time when the
+    /// last period is used up.
+    ByoYomi {
+        /// The time on the clock at the start of the game.
+        base: Duration,
+        /// The length of every period.
+        period: Duration,
+        /// The number of periods.
+        periods: u32,
+    },
+}
+
+impl TimeControl {
+    /// Returns the main time at the start of the game.
+    #[must_use]
+    pub fn base(&self) -> Duration {
+        match *self {
+            Self::SuddenDeath { base }
+            | Self::Increment { base, .. }
+            | Self::ByoYomi { base, .. } => base,
+        }
+    }
+
+    /// Returns the time added to the clock after every move, which is zero
+    /// unless the time control has an increment.
+    #[must_use]
+    pub fn increment(&self) -> Duration {
+        match *self {
+            Self::Increment { increment, .. } => increment,
+            Self::SuddenDeath { .. } | Self::ByoYomi { .. } => Duration::ZERO,
+        }
+    }
+}
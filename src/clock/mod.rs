@@ -0,0 +1,32 @@
+//! Time controls and the clocks that enforce them.
+//!
+//! A [`TimeControl`] describes how much time the players have, while a
+//! [`Clock`] tracks the time left for both players as moves are recorded.
+//! Both can be serialized through the optional `serde` feature, for example
+//! to store the time control of a match in a configuration file.
+//!
+//! [`TimeControl`]: crate::clock::TimeControl
+//! [`Clock`]: crate::clock::Clock
+//!
+//! # Examples
+//! ```rust
+//! use magpie::clock::{Clock, TimeControl};
+//! use magpie::othello::Stone;
+//! use std::time::Duration;
+//!
+//! let mut clock = Clock::new(TimeControl::SuddenDeath {
+//!     base: Duration::from_secs(60),
+//! });
+//! clock.record(Stone::Black, Duration::from_secs(5)).unwrap();
+//! clock.record(Stone::White, Duration::from_secs(8)).unwrap();
+//! assert_eq!(Duration::from_secs(55), clock.remaining(Stone::Black));
+//! assert_eq!(2, clock.moves_played());
+//! ```
+
+/// The rules that decide how much time the players have.
+mod control;
+/// Tracks the time left for both players.
+mod timer;
+
+pub use control::TimeControl;
+pub use timer::{Clock, Flagged, PlayerClock};
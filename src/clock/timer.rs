@@ -0,0 +1,286 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{clock::TimeControl, othello::Stone};
+use core::time::Duration;
+
+#[cfg(feature = "std")]
+use crate::search::ClockState;
+
+/// The time left for a single player.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct PlayerClock {
+    /// The main time left on the clock.
+    pub remaining: Duration,
+    /// The number of byo-yomi periods left, which is zero for other time
+    /// controls.
+    pub periods: u32,
+}
+
+/// Tracks the time left for both players across the moves of a game.
+///
+/// The clock does not measure time itself. Instead, the time spent on every
+/// move is recorded with [`Clock::record`], which makes the clock usable
+/// with wall-clock time as well as time reported by a server or a user
+/// interface. Once a player has run out of time, the clock is flagged and
+/// stops accepting moves.
+///
+/// # Examples
+/// ```rust
+/// use magpie::clock::{Clock, TimeControl};
+/// use magpie::othello::Stone;
+/// use std::time::Duration;
+///
+/// let mut clock = Clock::new(TimeControl::Increment {
+///     base: Duration::from_secs(10),
+///     increment: Duration::from_secs(1),
+/// });
+/// clock.record(Stone::Black, Duration::from_secs(4)).unwrap();
+/// assert_eq!(Duration::from_secs(7), clock.remaining(Stone::Black));
+/// assert_eq!(Duration::from_secs(10), clock.remaining(Stone::White));
+///
+/// assert!(clock.record(Stone::White, Duration::from_secs(11)).is_err());
+/// assert_eq!(Some(Stone::White), clock.flagged());
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct Clock {
+    control: TimeControl,
+    black: PlayerClock,
+    white: PlayerClock,
+    moves_played: u32,
+    flagged: Option<Stone>,
+}
+
+impl Clock {
+    /// Returns a clock where both players have the full main time of the
+    /// time control left.
+    #[must_use]
+    pub fn new(control: TimeControl) -> Self {
+        let player = PlayerClock {
+            remaining: control.base(),
+            periods: match control {
+                TimeControl::ByoYomi { periods, .. } => periods,
+                TimeControl::SuddenDeath { .. } | TimeControl::Increment { .. } => 0,
+            },
+        };
+        Self {
+            control,
+            black: player,
+            white: player,
+            moves_played: 0,
+            flagged: None,
+        }
+    }
+
+    /// Returns the time control of the clock.
+    #[must_use]
+    pub fn control(&self) -> TimeControl {
+        self.control
+    }
+
+    /// Returns the time left for the specified player.
+    #[must_use]
+    pub fn player(&self, stone: Stone) -> PlayerClock {
+        match stone {
+            Stone::Black => self.black,
+            Stone::White => self.white,
+        }
+    }
+
+    /// Sets the time left for the specified player, such as when a server
+    /// reports the state of the clock.
+    pub fn set_player(&mut self, stone: Stone, player: PlayerClock) {
+        *self.player_mut(stone) = player;
+    }
+
+    /// Returns the main time left for the specified player.
+    #[must_use]
+    pub fn remaining(&self, stone: Stone) -> Duration {
+        self.player(stone).remaining
+    }
+
+    /// Returns the longest time the specified player may spend on their
+    /// next move without running out of time.
+    ///
+    /// Under byo-yomi, this includes every period left, even though all but
+    /// the last are used up by such a long move.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::clock::{Clock, TimeControl};
+    /// use magpie::othello::Stone;
+    /// use std::time::Duration;
+    ///
+    /// let clock = Clock::new(TimeControl::ByoYomi {
+    ///     base: Duration::from_secs(60),
+    ///     period: Duration::from_secs(10),
+    ///     periods: 3,
+    /// });
+    /// assert_eq!(Duration::from_secs(90), clock.available(Stone::Black));
+    /// ```
+    #[must_use]
+    pub fn available(&self, stone: Stone) -> Duration {
+        let player = self.player(stone);
+        match self.control {
+            TimeControl::ByoYomi { period, .. } => player
+                .remaining
+                .saturating_add(period.saturating_mul(player.periods)),
+            TimeControl::SuddenDeath { .. } | TimeControl::Increment { .. } => player.remaining,
+        }
+    }
+
+    /// Returns the number of moves recorded so far, by both players.
+    #[must_use]
+    pub fn moves_played(&self) -> u32 {
+        self.moves_played
+    }
+
+    /// Returns the player who ran out of time, if any.
+    #[must_use]
+    pub fn flagged(&self) -> Option<Stone> {
+        self.flagged
+    }
+
+    /// Records that the specified player spent the elapsed time on a move.
+    ///
+    /// Returns an error if the player ran out of time during the move, or
+    /// if a player already had. Passes may be recorded like any other move.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::clock::{Clock, TimeControl};
+    /// use magpie::othello::Stone;
+    /// use std::time::Duration;
+    ///
+    /// let mut clock = Clock::new(TimeControl::ByoYomi {
+    ///     base: Duration::from_secs(5),
+    ///     period: Duration::from_secs(10),
+    ///     periods: 2,
+    /// });
+    /// // Uses up the main time and one period
+    /// clock.record(Stone::Black, Duration::from_secs(20)).unwrap();
+    /// assert_eq!(Duration::ZERO, clock.remaining(Stone::Black));
+    /// assert_eq!(1, clock.player(Stone::Black).periods);
+    ///
+    /// // Moves within a period do not use it up
+    /// clock.record(Stone::Black, Duration::from_secs(10)).unwrap();
+    /// assert_eq!(1, clock.player(Stone::Black).periods);
+    /// ```
+    pub fn record(&mut self, stone: Stone, elapsed: Duration) -> Result<(), Flagged> {
+        if let Some(stone) = self.flagged {
+            return Err(Flagged(stone));
+        }
+        let control = self.control;
+        let player = self.player_mut(stone);
+        let flagged = match control {
+            TimeControl::SuddenDeath { .. } | TimeControl::Increment { .. } => {
+                match player.remaining.checked_sub(elapsed) {
+                    Some(remaining) => {
+                        player.remaining = remaining + control.increment();
+                        false
+                    }
+                    None => true,
+                }
+            }
+            TimeControl::ByoYomi { period, .. } => {
+                match elapsed.checked_sub(player.remaining) {
+                    Some(overtime) if !overtime.is_zero() => {
+                        player.remaining = Duration::ZERO;
+                        // Every period that is exceeded is used up
+                        let used = if period.is_zero() {
+                            player.periods
+                        } else {
+                            let used = overtime.as_nanos().saturating_sub(1) / period.as_nanos();
+                            u32::try_from(used).unwrap_or(u32::MAX)
+                        };
+                        match player.periods.checked_sub(used) {
+                            Some(periods) if periods > 0 => {
+                                player.periods = periods;
+                                false
+                            }
+                            _ => {
+                                player.periods = 0;
+                                true
+                            }
+                        }
+                    }
+                    _ => {
+                        player.remaining -= elapsed;
+                        false
+                    }
+                }
+            }
+        };
+        if flagged {
+            self.flagged = Some(stone);
+            return Err(Flagged(stone));
+        }
+        self.moves_played += 1;
+        Ok(())
+    }
+
+    /// Describes the clock of the specified player for a [`TimeManager`].
+    ///
+    /// Under byo-yomi, a single period is counted as both remaining time
+    /// and increment, so that the time manager plans to use most of a
+    /// period once the main time has run out.
+    ///
+    /// [`TimeManager`]: crate::search::TimeManager
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::clock::{Clock, TimeControl};
+    /// use magpie::othello::{Board, Stone};
+    /// use magpie::search::TimeManager;
+    /// use std::time::Duration;
+    ///
+    /// let clock = Clock::new(TimeControl::SuddenDeath {
+    ///     base: Duration::from_secs(60),
+    /// });
+    /// let empties = Board::standard().empty_squares().count_set();
+    /// let budget = TimeManager::new().budget(&clock.state(Stone::Black, empties));
+    /// assert!(budget.hard < clock.available(Stone::Black));
+    /// ```
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn state(&self, stone: Stone, empties: u8) -> ClockState {
+        let player = self.player(stone);
+        let (remaining, increment) = match self.control {
+            TimeControl::ByoYomi { period, .. } if player.periods > 0 => {
+                (player.remaining + period, period)
+            }
+            control => (player.remaining, control.increment()),
+        };
+        ClockState {
+            remaining,
+            increment,
+            moves_played: self.moves_played,
+            empties,
+        }
+    }
+
+    fn player_mut(&mut self, stone: Stone) -> &mut PlayerClock {
+        match stone {
+            Stone::Black => &mut self.black,
+            Stone::White => &mut self.white,
+        }
+    }
+}
+
+/// The error returned when a player runs out of time. Carries the player
+/// who did.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct Flagged(pub Stone);
+
+impl core::fmt::Display for Flagged {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self.0 {
+            Stone::Black => f.write_str("black ran out of time"),
+            Stone::White => f.write_str("white ran out of time"),
+        }
+    }
+}
+
+impl core::error::Error for Flagged {}
@@ -0,0 +1,66 @@
+use std::io;
+
+/// Compresses and decompresses blocks of records.
+///
+/// Magpie does not ship any compression algorithms itself, but any
+/// compression library can be plugged in by implementing this trait. The
+/// identifier of the codec is stored in the header of the pack, so that
+/// packs are only ever read with the codec they were written with.
+///
+/// # Examples
+/// ```rust
+/// use magpie::datapack::Codec;
+/// use std::io;
+///
+/// /// Flips every bit, which obviously does not save any space.
+/// struct Inverted;
+///
+/// impl Codec for Inverted {
+///     fn id(&self) -> u8 {
+///         0xFF
+///     }
+///
+///     fn compress(&mut self, data: &[u8]) -> Vec<u8> {
+///         data.iter().map(|byte| !byte).collect()
+///     }
+///
+///     fn decompress(&mut self, data: &[u8]) -> io::Result<Vec<u8>> {
+///         Ok(data.iter().map(|byte| !byte).collect())
+///     }
+/// }
+/// ```
+pub trait Codec {
+    /// Returns the identifier stored in the header of the pack.
+    ///
+    /// The identifier 0 is reserved for [`Uncompressed`] packs.
+    ///
+    /// [`Uncompressed`]: crate::datapack::Uncompressed
+    fn id(&self) -> u8;
+
+    /// Compresses a block of records.
+    fn compress(&mut self, data: &[u8]) -> Vec<u8>;
+
+    /// Decompresses a block of records previously compressed by the codec.
+    fn decompress(&mut self, data: &[u8]) -> io::Result<Vec<u8>>;
+}
+
+/// Stores records without compression, which allows random access through
+/// [`PackView`].
+///
+/// [`PackView`]: crate::datapack::PackView
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+pub struct Uncompressed;
+
+impl Codec for Uncompressed {
+    fn id(&self) -> u8 {
+        0
+    }
+
+    fn compress(&mut self, data: &[u8]) -> Vec<u8> {
+        data.to_vec()
+    }
+
+    fn decompress(&mut self, data: &[u8]) -> io::Result<Vec<u8>> {
+        Ok(data.to_vec())
+    }
+}
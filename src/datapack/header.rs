@@ -0,0 +1,124 @@
+/// The size of the header, in bytes.
+pub(crate) const HEADER_SIZE: usize = 16;
+
+/// The magic bytes every pack starts with.
+const MAGIC: &[u8; 4] = b"MGPK";
+
+/// The version of the format written by this library.
+pub(crate) const VERSION: u8 = 1;
+
+/// The header at the start of every pack.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct Header {
+    /// The version of the format.
+    pub version: u8,
+    /// The identifier of the codec the records were compressed with, or 0
+    /// if they are uncompressed.
+    pub codec: u8,
+    /// The number of records in the pack.
+    pub count: u64,
+}
+
+impl Header {
+    /// Parses the 16 byte header at the start of a pack.
+    ///
+    /// Returns an error if the bytes are too short, do not start with the
+    /// magic bytes or were written in an unsupported version of the format.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::datapack::{Header, PackError};
+    ///
+    /// let mut bytes = *b"MGPK\x01\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00";
+    /// bytes[8] = 3;
+    /// assert_eq!(3, Header::parse(&bytes).unwrap().count);
+    ///
+    /// let error = Header::parse(b"WTHOR").unwrap_err();
+    /// assert!(matches!(error, PackError::Truncated));
+    /// ```
+    pub fn parse(bytes: &[u8]) -> Result<Self, PackError> {
+        let bytes = bytes.get(..HEADER_SIZE).ok_or(PackError::Truncated)?;
+        if &bytes[..4] != MAGIC {
+            return Err(PackError::InvalidMagic);
+        }
+        if bytes[4] != VERSION {
+            return Err(PackError::UnsupportedVersion(bytes[4]));
+        }
+        Ok(Self {
+            version: bytes[4],
+            codec: bytes[5],
+            count: u64::from_le_bytes(bytes[8..16].try_into().unwrap()),
+        })
+    }
+
+    /// Encodes the header as 16 bytes.
+    pub(crate) fn to_bytes(self) -> [u8; HEADER_SIZE] {
+        let mut bytes = [0; HEADER_SIZE];
+        bytes[..4].copy_from_slice(MAGIC);
+        bytes[4] = self.version;
+        bytes[5] = self.codec;
+        bytes[8..].copy_from_slice(&self.count.to_le_bytes());
+        bytes
+    }
+}
+
+/// This enum represents errors that may occur when reading or writing
+/// packs.
+#[derive(Debug)]
+pub enum PackError {
+    /// Indicates that reading or writing the pack failed.
+    Io(std::io::Error),
+    /// Indicates that the data does not start with the magic bytes of a
+    /// pack.
+    InvalidMagic,
+    /// Indicates that the pack was written in an unsupported version of the
+    /// format.
+    UnsupportedVersion(u8),
+    /// Indicates that the pack was compressed with a codec other than the
+    /// one it is being read with. Carries the identifier of the codec of the
+    /// pack.
+    UnsupportedCodec(u8),
+    /// Indicates that the pack ended before all records could be read.
+    Truncated,
+    /// Indicates that a compressed block did not decompress to whole
+    /// records.
+    InvalidBlock,
+    /// Indicates that a record could not be decoded. Carries the index of
+    /// the offending record.
+    InvalidRecord(u64),
+}
+
+impl From<std::io::Error> for PackError {
+    fn from(error: std::io::Error) -> Self {
+        if error.kind() == std::io::ErrorKind::UnexpectedEof {
+            PackError::Truncated
+        } else {
+            PackError::Io(error)
+        }
+    }
+}
+
+impl std::fmt::Display for PackError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PackError::Io(error) => write!(f, "i/o error: {error}"),
+            PackError::InvalidMagic => f.write_str("not a position pack"),
+            PackError::UnsupportedVersion(version) => {
+                write!(f, "unsupported pack version {version}")
+            }
+            PackError::UnsupportedCodec(codec) => write!(f, "unsupported codec {codec}"),
+            PackError::Truncated => f.write_str("unexpected end of pack"),
+            PackError::InvalidBlock => f.write_str("block does not contain whole records"),
+            PackError::InvalidRecord(index) => write!(f, "record {index} is invalid"),
+        }
+    }
+}
+
+impl std::error::Error for PackError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            PackError::Io(error) => Some(error),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,90 @@
+//! A compact binary format for storing training positions.
+//!
+//! A pack holds a sequence of [`PackRecord`]s, each pairing a board and the
+//! player to move with the move that was played, the final result of the
+//! game and optionally an evaluation of the position. Packs are meant for
+//! datasets produced by the [`selfplay`] module or converted from [`wthor`]
+//! archives, and both can be turned into records directly.
+//!
+//! # Format
+//!
+//! All integers are stored in little-endian byte order. A pack starts with
+//! a 16 byte header:
+//!
+//! | Offset | Size | Contents                                          |
+//! |--------|------|---------------------------------------------------|
+//! | 0      | 4    | The magic bytes `MGPK`                            |
+//! | 4      | 1    | The format version, currently 1                   |
+//! | 5      | 1    | The identifier of the [`Codec`], 0 if uncompressed |
+//! | 6      | 2    | Reserved, always 0                                |
+//! | 8      | 8    | The number of records                             |
+//!
+//! Every record takes up 24 bytes:
+//!
+//! | Offset | Size | Contents                                            |
+//! |--------|------|-----------------------------------------------------|
+//! | 0      | 8    | The black stones                                    |
+//! | 8      | 8    | The white stones                                    |
+//! | 16     | 1    | The player to move, 0 for black and 1 for white     |
+//! | 17     | 1    | The move played, as `rank * 8 + file`               |
+//! | 18     | 1    | The final result, as a signed disc difference       |
+//! | 19     | 1    | Flags, where bit 0 is set if an evaluation is given |
+//! | 20     | 4    | The evaluation as a 32-bit float, or 0              |
+//!
+//! In uncompressed packs, the records directly follow the header. Since
+//! every record has the same size, any record can be looked up without
+//! reading the ones before it, which [`PackView`] does on a byte slice such
+//! as a memory-mapped file.
+//!
+//! In compressed packs, the records are grouped into blocks of up to 4096
+//! records. Every block is compressed separately by the codec and stored
+//! with its compressed size as a 32-bit integer in front of it. Compressed
+//! packs can only be read sequentially, with [`PackReader`].
+//!
+//! [`selfplay`]: crate::selfplay
+//! [`wthor`]: crate::wthor
+//! [`PackRecord`]: crate::datapack::PackRecord
+//! [`Codec`]: crate::datapack::Codec
+//! [`PackView`]: crate::datapack::PackView
+//! [`PackReader`]: crate::datapack::PackReader
+//!
+//! # Examples
+//! ```rust
+//! use magpie::datapack::{PackReader, PackRecord, PackWriter};
+//! use magpie::othello::{Board, Position, Stone};
+//! use std::io::Cursor;
+//!
+//! let record = PackRecord {
+//!     board: Board::standard(),
+//!     stone: Stone::Black,
+//!     mv: Position::try_from("f5").unwrap(),
+//!     result: 2,
+//!     eval: Some(0.5),
+//! };
+//!
+//! let mut writer = PackWriter::new(Cursor::new(Vec::new())).unwrap();
+//! writer.write(&record).unwrap();
+//! let bytes = writer.finish().unwrap().into_inner();
+//!
+//! let reader = PackReader::new(bytes.as_slice()).unwrap();
+//! assert_eq!(1, reader.header().count);
+//! let records: Vec<_> = reader.collect::<Result<_, _>>().unwrap();
+//! assert_eq!(vec![record], records);
+//! ```
+
+/// Compression of record blocks.
+mod codec;
+/// The file header and the errors that may occur.
+mod header;
+/// Sequential and random access to records.
+mod reader;
+/// A single training position and its encoding.
+mod record;
+/// Writes records to any destination.
+mod writer;
+
+pub use codec::{Codec, Uncompressed};
+pub use header::{Header, PackError};
+pub use reader::{PackReader, PackView};
+pub use record::PackRecord;
+pub use writer::PackWriter;
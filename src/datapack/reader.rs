@@ -0,0 +1,215 @@
+use crate::datapack::{
+    Codec, PackRecord, Uncompressed,
+    header::{HEADER_SIZE, Header, PackError},
+    record::RECORD_SIZE,
+    writer::BLOCK_RECORDS,
+};
+use std::io::Read;
+
+/// Reads records one at a time from a pack.
+///
+/// Unlike [`PackView`], the reader works with any source and also reads
+/// compressed packs, but only in order. The reader stops after the first
+/// error.
+///
+/// [`PackView`]: crate::datapack::PackView
+///
+/// # Examples
+/// ```rust
+/// use magpie::datapack::{PackError, PackReader};
+///
+/// let reader = PackReader::new(b"MGPK".as_slice());
+/// assert!(matches!(reader, Err(PackError::Truncated)));
+/// ```
+#[derive(Debug)]
+pub struct PackReader<R, C = Uncompressed> {
+    reader: R,
+    codec: C,
+    header: Header,
+    index: u64,
+    block: Vec<u8>,
+    offset: usize,
+}
+
+impl<R: Read> PackReader<R> {
+    /// Reads the header of an uncompressed pack and returns a reader
+    /// positioned at the first record.
+    ///
+    /// Returns an error if the header could not be read or if the pack is
+    /// compressed.
+    pub fn new(reader: R) -> Result<Self, PackError> {
+        Self::with_codec(reader, Uncompressed)
+    }
+}
+
+impl<R: Read, C: Codec> PackReader<R, C> {
+    /// Reads the header of a pack compressed with the specified codec and
+    /// returns a reader positioned at the first record.
+    ///
+    /// Returns an error if the header could not be read or if the pack was
+    /// compressed with a different codec.
+    pub fn with_codec(mut reader: R, codec: C) -> Result<Self, PackError> {
+        let mut bytes = [0; HEADER_SIZE];
+        reader.read_exact(&mut bytes)?;
+        let header = Header::parse(&bytes)?;
+        if header.codec != codec.id() {
+            return Err(PackError::UnsupportedCodec(header.codec));
+        }
+        Ok(Self {
+            reader,
+            codec,
+            header,
+            index: 0,
+            block: Vec::new(),
+            offset: 0,
+        })
+    }
+
+    /// Returns the header of the pack.
+    #[must_use]
+    pub fn header(&self) -> Header {
+        self.header
+    }
+
+    fn read_record(&mut self) -> Result<PackRecord, PackError> {
+        let mut bytes = [0; RECORD_SIZE];
+        if self.header.codec == Uncompressed.id() {
+            self.reader.read_exact(&mut bytes)?;
+        } else {
+            if self.offset == self.block.len() {
+                self.read_block()?;
+            }
+            bytes.copy_from_slice(&self.block[self.offset..self.offset + RECORD_SIZE]);
+            self.offset += RECORD_SIZE;
+        }
+        PackRecord::from_bytes(&bytes).ok_or(PackError::InvalidRecord(self.index))
+    }
+
+    fn read_block(&mut self) -> Result<(), PackError> {
+        let mut len = [0; 4];
+        self.reader.read_exact(&mut len)?;
+        let mut compressed = vec![0; u32::from_le_bytes(len) as usize];
+        self.reader.read_exact(&mut compressed)?;
+        self.block = self.codec.decompress(&compressed)?;
+        self.offset = 0;
+        let records = self.block.len() / RECORD_SIZE;
+        if !self.block.len().is_multiple_of(RECORD_SIZE) || !(1..=BLOCK_RECORDS).contains(&records)
+        {
+            return Err(PackError::InvalidBlock);
+        }
+        Ok(())
+    }
+}
+
+impl<R: Read, C: Codec> Iterator for PackReader<R, C> {
+    type Item = Result<PackRecord, PackError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index == self.header.count {
+            return None;
+        }
+        let record = self.read_record();
+        self.index = if record.is_ok() {
+            self.index + 1
+        } else {
+            self.header.count
+        };
+        Some(record)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.header.count - self.index;
+        (0, usize::try_from(remaining).ok())
+    }
+}
+
+/// Random access to the records of an uncompressed pack held in memory.
+///
+/// Records are decoded on demand, so a view over a memory-mapped file
+/// gives access to datasets that are too large to be loaded at once.
+///
+/// # Examples
+/// ```rust
+/// use magpie::datapack::{PackRecord, PackView, PackWriter};
+/// use magpie::othello::{Board, Position, Stone};
+/// use std::io::Cursor;
+///
+/// let mut writer = PackWriter::new(Cursor::new(Vec::new())).unwrap();
+/// for result in -2..=2 {
+///     let record = PackRecord {
+///         board: Board::standard(),
+///         stone: Stone::Black,
+///         mv: Position::try_from("c4").unwrap(),
+///         result,
+///         eval: None,
+///     };
+///     writer.write(&record).unwrap();
+/// }
+/// let bytes = writer.finish().unwrap().into_inner();
+///
+/// let view = PackView::new(&bytes).unwrap();
+/// assert_eq!(5, view.len());
+/// assert_eq!(1, view.get(3).unwrap().unwrap().result);
+/// assert!(view.get(5).is_none());
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct PackView<'a> {
+    header: Header,
+    records: &'a [u8],
+}
+
+impl<'a> PackView<'a> {
+    /// Parses the header of an uncompressed pack.
+    ///
+    /// Returns an error if the header is invalid, if the pack is compressed
+    /// or if it is shorter than its header claims.
+    pub fn new(bytes: &'a [u8]) -> Result<Self, PackError> {
+        let header = Header::parse(bytes)?;
+        if header.codec != Uncompressed.id() {
+            return Err(PackError::UnsupportedCodec(header.codec));
+        }
+        let len = usize::try_from(header.count)
+            .ok()
+            .and_then(|count| count.checked_mul(RECORD_SIZE))
+            .ok_or(PackError::Truncated)?;
+        let records = bytes[HEADER_SIZE..]
+            .get(..len)
+            .ok_or(PackError::Truncated)?;
+        Ok(Self { header, records })
+    }
+
+    /// Returns the header of the pack.
+    #[must_use]
+    pub fn header(&self) -> Header {
+        self.header
+    }
+
+    /// Returns the number of records.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.records.len() / RECORD_SIZE
+    }
+
+    /// Returns true if the pack does not contain any records.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
+
+    /// Decodes the record at the specified index, or returns `None` if the
+    /// index is out of bounds.
+    #[must_use]
+    pub fn get(&self, index: usize) -> Option<Result<PackRecord, PackError>> {
+        let start = index.checked_mul(RECORD_SIZE)?;
+        let bytes = self.records.get(start..start + RECORD_SIZE)?;
+        let record = PackRecord::from_bytes(bytes.try_into().unwrap())
+            .ok_or(PackError::InvalidRecord(index as u64));
+        Some(record)
+    }
+
+    /// Returns an iterator over all records, in order.
+    pub fn iter(&self) -> impl Iterator<Item = Result<PackRecord, PackError>> + 'a {
+        let view = *self;
+        (0..self.len()).map(move |index| view.get(index).unwrap())
+    }
+}
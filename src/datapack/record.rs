@@ -0,0 +1,166 @@
+use crate::{
+    othello::{Board, Position, Stone},
+    selfplay::Record,
+};
+
+/// The size of a single record, in bytes.
+pub(crate) const RECORD_SIZE: usize = 24;
+
+/// The flag set when a record carries an evaluation.
+const HAS_EVAL: u8 = 1;
+
+/// A single training position, along with the move that was played in it
+/// and the final result of the game.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PackRecord {
+    /// The board before the move was made.
+    pub board: Board,
+    /// The player to move.
+    pub stone: Stone,
+    /// The move that was made.
+    pub mv: Position,
+    /// The final score difference of the game from the perspective of the
+    /// player to move, which is positive if they went on to win.
+    pub result: i8,
+    /// An optional evaluation of the position from the perspective of the
+    /// player to move, such as the score of a search.
+    pub eval: Option<f32>,
+}
+
+impl PackRecord {
+    /// Encodes the record as 24 bytes.
+    pub(crate) fn to_bytes(self) -> [u8; RECORD_SIZE] {
+        let mut bytes = [0; RECORD_SIZE];
+        bytes[..8].copy_from_slice(&self.board.bits_for(Stone::Black).raw().to_le_bytes());
+        bytes[8..16].copy_from_slice(&self.board.bits_for(Stone::White).raw().to_le_bytes());
+        bytes[16] = match self.stone {
+            Stone::Black => 0,
+            Stone::White => 1,
+        };
+        bytes[17] = self.mv.rank() * 8 + self.mv.file();
+        bytes[18] = self.result.to_le_bytes()[0];
+        if let Some(eval) = self.eval {
+            bytes[19] = HAS_EVAL;
+            bytes[20..].copy_from_slice(&eval.to_le_bytes());
+        }
+        bytes
+    }
+
+    /// Decodes a record from 24 bytes, returning `None` if the bytes do not
+    /// describe a valid record.
+    pub(crate) fn from_bytes(bytes: &[u8; RECORD_SIZE]) -> Option<Self> {
+        let black = u64::from_le_bytes(bytes[..8].try_into().unwrap());
+        let white = u64::from_le_bytes(bytes[8..16].try_into().unwrap());
+        let stone = match bytes[16] {
+            0 => Stone::Black,
+            1 => Stone::White,
+            _ => return None,
+        };
+        let eval = (bytes[19] & HAS_EVAL != 0)
+            .then(|| f32::from_le_bytes(bytes[20..].try_into().unwrap()));
+        Some(Self {
+            board: Board::try_from((black, white)).ok()?,
+            stone,
+            mv: Position::from_rank_file(bytes[17] / 8, bytes[17] % 8).ok()?,
+            result: i8::from_le_bytes([bytes[18]]),
+            eval,
+        })
+    }
+
+    /// Returns whether the record could have come from a real game: the
+    /// board has to be reachable from the opening position with the player
+    /// to move, who has to be able to make the move.
+    ///
+    /// Records that fail this check are usually corrupt. See
+    /// [`Board::is_reachable`] for how thoroughly boards are checked.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::datapack::PackRecord;
+    /// use magpie::othello::{Board, Stone};
+    ///
+    /// let mut record = PackRecord {
+    ///     board: Board::standard(),
+    ///     stone: Stone::Black,
+    ///     mv: "f5".parse().unwrap(),
+    ///     result: 0,
+    ///     eval: None,
+    /// };
+    /// assert!(record.is_consistent());
+    /// record.stone = Stone::White;
+    /// assert!(!record.is_consistent());
+    /// ```
+    #[must_use]
+    pub fn is_consistent(&self) -> bool {
+        self.board.is_reachable_with(self.stone) && self.board.is_legal_move(self.stone, self.mv)
+    }
+
+    /// Turns every move of a WTHOR game into a record, in the order they
+    /// were made.
+    ///
+    /// The result is derived from the final number of black stones stored
+    /// in the game record. Returns an error if any of the recorded moves are
+    /// illegal.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::datapack::PackRecord;
+    /// use magpie::othello::{Position, Stone};
+    /// use magpie::wthor::read_games;
+    ///
+    /// let mut bytes = vec![0; 16 + 68];
+    /// bytes[4] = 1; // Number of games
+    /// bytes[16 + 6] = 40; // Black score
+    /// bytes[16 + 8] = 56; // F5
+    /// bytes[16 + 9] = 66; // F6
+    ///
+    /// let (_, games) = read_games(&bytes).unwrap();
+    /// let records = PackRecord::from_wthor(&games[0]).unwrap();
+    /// assert_eq!(2, records.len());
+    /// assert_eq!(Stone::White, records[1].stone);
+    /// assert_eq!(Position::try_from("f6").unwrap(), records[1].mv);
+    /// assert_eq!(-16, records[1].result);
+    /// ```
+    #[cfg(feature = "wthor")]
+    pub fn from_wthor(
+        game: &crate::wthor::GameRecord,
+    ) -> Result<Vec<Self>, crate::othello::TranscriptError> {
+        use crate::othello::Move;
+
+        let history = game.replay()?;
+        // Empty squares are already awarded to the winner in WTHOR files
+        let black_result = (i16::from(game.black_score) * 2 - 64).clamp(-64, 64) as i8;
+        let mut board = Board::standard();
+        let mut records = Vec::new();
+        for played in history.history() {
+            let (stone, Move::Play(mv)) = (played.stone(), played.mv()) else {
+                continue;
+            };
+            records.push(Self {
+                board,
+                stone,
+                mv,
+                result: match stone {
+                    Stone::Black => black_result,
+                    Stone::White => -black_result,
+                },
+                eval: None,
+            });
+            board.play(stone, mv);
+        }
+        Ok(records)
+    }
+}
+
+impl From<Record> for PackRecord {
+    /// Converts a self-play record, which does not carry an evaluation.
+    fn from(record: Record) -> Self {
+        Self {
+            board: record.board,
+            stone: record.stone,
+            mv: record.mv,
+            result: record.score,
+            eval: None,
+        }
+    }
+}
@@ -0,0 +1,127 @@
+use crate::datapack::{
+    Codec, PackRecord, Uncompressed,
+    header::{Header, PackError, VERSION},
+    record::RECORD_SIZE,
+};
+use std::io::{Seek, SeekFrom, Write};
+
+/// The number of records compressed together.
+pub(crate) const BLOCK_RECORDS: usize = 4096;
+
+/// Writes records to a pack.
+///
+/// The number of records is only known once the last one has been written,
+/// which is why the destination must implement [`Seek`]: [`finish`] goes
+/// back to fill it in. Packs that are not finished cannot be read.
+///
+/// [`finish`]: crate::datapack::PackWriter::finish
+///
+/// # Examples
+/// ```rust
+/// use magpie::datapack::{PackRecord, PackView, PackWriter};
+/// use magpie::othello::{Board, Position, Stone};
+/// use std::io::Cursor;
+///
+/// let record = PackRecord {
+///     board: Board::standard(),
+///     stone: Stone::Black,
+///     mv: Position::try_from("d3").unwrap(),
+///     result: 0,
+///     eval: None,
+/// };
+///
+/// let mut writer = PackWriter::new(Cursor::new(Vec::new())).unwrap();
+/// for _ in 0..3 {
+///     writer.write(&record).unwrap();
+/// }
+/// let bytes = writer.finish().unwrap().into_inner();
+/// assert_eq!(3, PackView::new(&bytes).unwrap().len());
+/// ```
+#[derive(Debug)]
+pub struct PackWriter<W, C = Uncompressed> {
+    writer: W,
+    codec: C,
+    start: u64,
+    block: Vec<u8>,
+    count: u64,
+}
+
+impl<W: Write + Seek> PackWriter<W> {
+    /// Writes the header of an uncompressed pack and returns a writer for
+    /// its records.
+    pub fn new(writer: W) -> Result<Self, PackError> {
+        Self::with_codec(writer, Uncompressed)
+    }
+}
+
+impl<W: Write + Seek, C: Codec> PackWriter<W, C> {
+    /// Writes the header of a pack whose records are compressed with the
+    /// specified codec and returns a writer for its records.
+    pub fn with_codec(mut writer: W, codec: C) -> Result<Self, PackError> {
+        let start = writer.stream_position()?;
+        let header = Header {
+            version: VERSION,
+            codec: codec.id(),
+            count: 0,
+        };
+        writer.write_all(&header.to_bytes())?;
+        Ok(Self {
+            writer,
+            codec,
+            start,
+            block: Vec::new(),
+            count: 0,
+        })
+    }
+
+    /// Writes a single record.
+    pub fn write(&mut self, record: &PackRecord) -> Result<(), PackError> {
+        if self.codec.id() == Uncompressed.id() {
+            self.writer.write_all(&record.to_bytes())?;
+        } else {
+            self.block.extend_from_slice(&record.to_bytes());
+            if self.block.len() == BLOCK_RECORDS * RECORD_SIZE {
+                self.flush_block()?;
+            }
+        }
+        self.count += 1;
+        Ok(())
+    }
+
+    /// Returns the number of records written so far.
+    #[must_use]
+    pub fn len(&self) -> u64 {
+        self.count
+    }
+
+    /// Returns true if no records have been written yet.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    /// Writes any buffered records, fills in the number of records in the
+    /// header and returns the destination, positioned at the end of the
+    /// pack.
+    pub fn finish(mut self) -> Result<W, PackError> {
+        self.flush_block()?;
+        let end = self.writer.stream_position()?;
+        self.writer.seek(SeekFrom::Start(self.start + 8))?;
+        self.writer.write_all(&self.count.to_le_bytes())?;
+        self.writer.seek(SeekFrom::Start(end))?;
+        self.writer.flush()?;
+        Ok(self.writer)
+    }
+
+    fn flush_block(&mut self) -> Result<(), PackError> {
+        if self.block.is_empty() {
+            return Ok(());
+        }
+        let compressed = self.codec.compress(&self.block);
+        let len = u32::try_from(compressed.len()).map_err(|_| PackError::InvalidBlock)?;
+        self.writer.write_all(&len.to_le_bytes())?;
+        self.writer.write_all(&compressed)?;
+        self.block.clear();
+        Ok(())
+    }
+}
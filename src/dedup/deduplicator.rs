@@ -0,0 +1,210 @@
+use crate::{
+    othello::{Board, Game, Stone},
+    search::hash,
+};
+use std::collections::{HashMap, hash_map::Entry};
+
+/// Whether a game added to a [`Deduplicator`] was seen before.
+///
+/// [`Deduplicator`]: crate::dedup::Deduplicator
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum GameStatus {
+    /// The game was not seen before.
+    Unique,
+    /// The game, or a symmetric variant of it, was seen before. Carries the
+    /// index of the first game it is a copy of.
+    Duplicate(usize),
+}
+
+/// Counters describing a collection of games scanned by a [`Deduplicator`].
+///
+/// Positions are only counted for games that are not duplicates.
+///
+/// [`Deduplicator`]: crate::dedup::Deduplicator
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+pub struct DedupStats {
+    /// The number of games added.
+    pub games: u64,
+    /// The number of games that were copies of earlier games.
+    pub duplicate_games: u64,
+    /// The number of positions in all unique games, including their
+    /// starting positions.
+    pub positions: u64,
+    /// The number of distinct positions, up to symmetry.
+    pub distinct_positions: u64,
+    /// The number of times a position was reached again through a different
+    /// sequence of positions than the first time it was seen.
+    pub transpositions: u64,
+}
+
+impl DedupStats {
+    /// Returns the share of positions that were seen before, in the same or
+    /// another game, or 0 if there are no positions.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn repeated_position_rate(&self) -> f64 {
+        if self.positions == 0 {
+            0.0
+        } else {
+            (self.positions - self.distinct_positions) as f64 / self.positions as f64
+        }
+    }
+}
+
+/// Finds duplicate games and transpositions in a stream of games.
+///
+/// Positions are identified by a hash of their [canonical] board and the
+/// player to move, so that symmetric positions are considered the same. Two
+/// games are duplicates if they pass through the same positions in the same
+/// order, which includes games that are symmetric variants of each other.
+/// Games are only compared by hashes, so the memory needed is a few words
+/// per distinct position and game, no matter how long the games are. Hash
+/// collisions are possible, but extremely unlikely for any realistic number
+/// of games.
+///
+/// Games are added one at a time, which fits the streaming readers of the
+/// [`ggf`] and [`wthor`] modules.
+///
+/// [canonical]: crate::othello::Board::canonical
+/// [`ggf`]: crate::ggf
+/// [`wthor`]: crate::wthor
+///
+/// # Examples
+/// ```rust
+/// use magpie::dedup::{Deduplicator, GameStatus};
+/// use magpie::ggf::{GgfGame, GgfReader};
+/// use magpie::othello::Transcript;
+///
+/// let text: String = ["f5d6c3", "c4e3f6", "f5f6e6"]
+///     .iter()
+///     .map(|moves| {
+///         let transcript: Transcript = moves.parse().unwrap();
+///         GgfGame::from_transcript(&transcript).unwrap().to_string()
+///     })
+///     .collect();
+///
+/// let mut dedup = Deduplicator::new();
+/// let statuses: Vec<_> = GgfReader::new(text.as_bytes())
+///     .map(|game| dedup.add_game(&game.unwrap().replay().unwrap()))
+///     .collect();
+/// // C4 E3 F6 is F5 D6 C3 rotated by 180 degrees
+/// assert_eq!(
+///     vec![GameStatus::Unique, GameStatus::Duplicate(0), GameStatus::Unique],
+///     statuses
+/// );
+/// assert_eq!(1, dedup.stats().duplicate_games);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Deduplicator {
+    /// The hash of the sequence of positions first leading to every
+    /// position.
+    positions: HashMap<u64, u64>,
+    /// The index of the first game with every sequence of positions.
+    games: HashMap<u64, usize>,
+    duplicates: Vec<(usize, usize)>,
+    stats: DedupStats,
+}
+
+impl Deduplicator {
+    /// Returns a deduplicator that has not seen any games.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a game, passing through every position from its start to its
+    /// current position, and returns whether it was seen before.
+    ///
+    /// Moves that were undone are not part of the game.
+    pub fn add_game(&mut self, game: &Game) -> GameStatus {
+        let mut replay = game.clone();
+        while replay.undo().is_some() {}
+        let mut positions = vec![(replay.board(), replay.current_turn())];
+        for _ in game.history() {
+            replay.redo();
+            positions.push((replay.board(), replay.current_turn()));
+        }
+        self.add_positions(positions)
+    }
+
+    /// Adds a game given as the sequence of positions it passes through,
+    /// each with the player to move, and returns whether it was seen before.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::dedup::{Deduplicator, GameStatus};
+    /// use magpie::othello::{Board, Stone};
+    ///
+    /// let mut dedup = Deduplicator::new();
+    /// let start = [(Board::standard(), Stone::Black)];
+    /// assert_eq!(GameStatus::Unique, dedup.add_positions(start));
+    /// assert_eq!(GameStatus::Duplicate(0), dedup.add_positions(start));
+    /// ```
+    pub fn add_positions(
+        &mut self,
+        positions: impl IntoIterator<Item = (Board, Stone)>,
+    ) -> GameStatus {
+        let index = self.stats.games as usize;
+        self.stats.games += 1;
+
+        let keys: Vec<u64> = positions
+            .into_iter()
+            .map(|(board, stone)| key(&board, stone))
+            .collect();
+        let game = keys.iter().fold(0, |path, key| extend(path, *key));
+        match self.games.entry(game) {
+            Entry::Occupied(first) => {
+                self.stats.duplicate_games += 1;
+                self.duplicates.push((index, *first.get()));
+                return GameStatus::Duplicate(*first.get());
+            }
+            Entry::Vacant(entry) => {
+                entry.insert(index);
+            }
+        }
+
+        let mut path = 0;
+        for key in keys {
+            self.stats.positions += 1;
+            match self.positions.entry(key) {
+                Entry::Occupied(first) => {
+                    self.stats.transpositions += u64::from(*first.get() != path);
+                }
+                Entry::Vacant(entry) => {
+                    entry.insert(path);
+                    self.stats.distinct_positions += 1;
+                }
+            }
+            path = extend(path, key);
+        }
+        GameStatus::Unique
+    }
+
+    /// Returns the counters of all games added so far.
+    #[must_use]
+    pub fn stats(&self) -> DedupStats {
+        self.stats
+    }
+
+    /// Returns every duplicate game found so far, as pairs of the index of
+    /// the duplicate and the index of the first game it is a copy of.
+    #[must_use]
+    pub fn duplicates(&self) -> &[(usize, usize)] {
+        &self.duplicates
+    }
+}
+
+/// Returns the hash of the canonical position.
+fn key(board: &Board, stone: Stone) -> u64 {
+    let (board, _) = board.canonical();
+    hash(
+        board.bits_for(Stone::Black).raw(),
+        board.bits_for(Stone::White).raw(),
+        stone,
+    )
+}
+
+/// Returns the hash of a sequence of positions extended by one more.
+fn extend(path: u64, key: u64) -> u64 {
+    hash(path, key, Stone::Black)
+}
@@ -0,0 +1,32 @@
+//! Finds duplicate games and transpositions in collections of games.
+//!
+//! Game collections such as WTHOR archives and GGF files often contain the
+//! same game several times, either as exact copies or as symmetric
+//! variants, which skews datasets built from them. The [`Deduplicator`]
+//! scans games one at a time, reports the games it has seen before and
+//! gathers [`DedupStats`] on how often positions recur across games.
+//!
+//! [`Deduplicator`]: crate::dedup::Deduplicator
+//! [`DedupStats`]: crate::dedup::DedupStats
+//!
+//! # Examples
+//! ```rust
+//! use magpie::dedup::Deduplicator;
+//! use magpie::othello::Transcript;
+//!
+//! let mut dedup = Deduplicator::new();
+//! for moves in ["f5d6c3d3c4", "f5d6c4d3c3", "f5d6c3d3c4"] {
+//!     let transcript: Transcript = moves.parse().unwrap();
+//!     dedup.add_game(&transcript.replay().unwrap());
+//! }
+//! let stats = dedup.stats();
+//! assert_eq!(1, stats.duplicate_games);
+//! assert_eq!(&[(2, 0)], dedup.duplicates());
+//! // Both move orders lead to the same position
+//! assert_eq!(1, stats.transpositions);
+//! ```
+
+/// Tracks the games and positions seen so far.
+mod deduplicator;
+
+pub use deduplicator::{DedupStats, Deduplicator, GameStatus};
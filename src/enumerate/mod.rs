@@ -0,0 +1,28 @@
+//! Enumerates the positions that can arise after a number of moves.
+//!
+//! Counting the distinct positions reachable from the opening position is a
+//! classic problem in Othello research, and the published numbers make a
+//! thorough test of move generation. Unlike [`perft`], which counts move
+//! sequences, every position is only counted once no matter how many move
+//! orders lead to it.
+//!
+//! A position is a board along with the player to move. The ply of a
+//! position is the number of stones placed since the opening position, so
+//! passes do not count as plies. The player to move is always one who has a
+//! legal move, unless the game has concluded.
+//!
+//! [`perft`]: crate::othello::perft
+//!
+//! # Examples
+//! ```rust
+//! use magpie::enumerate::{positions_at_ply, unique_positions_at_ply};
+//!
+//! assert_eq!(12, positions_at_ply(2, |_, _| {}));
+//! // The four opening moves are symmetric
+//! assert_eq!(1, unique_positions_at_ply(1, |_, _| {}));
+//! ```
+
+/// Breadth-first enumeration of positions.
+mod positions;
+
+pub use positions::{positions_at_ply, unique_positions_at_ply};
@@ -0,0 +1,94 @@
+use crate::othello::{Board, Stone};
+use std::collections::HashSet;
+
+/// Calls the closure with every distinct position reachable from the
+/// opening position at the specified ply, and returns their number.
+///
+/// Positions are found ply by ply, keeping every position of a ply in
+/// memory, and the positions of the last ply are passed to the closure as
+/// soon as they are found. Enumerating beyond 10 plies requires several
+/// gigabytes of memory, so [`unique_positions_at_ply`] is preferable when
+/// symmetric positions do not need to be told apart.
+///
+/// [`unique_positions_at_ply`]: crate::enumerate::unique_positions_at_ply
+///
+/// # Examples
+/// ```rust
+/// use magpie::enumerate::positions_at_ply;
+/// use magpie::othello::{Board, Stone, perft};
+///
+/// let mut positions = Vec::new();
+/// let count = positions_at_ply(3, |board, stone| positions.push((*board, stone)));
+/// assert_eq!(count, positions.len() as u64);
+/// assert!(positions.iter().all(|(_, stone)| *stone == Stone::White));
+///
+/// // Some move sequences transpose into the same position
+/// assert!(count < perft(&Board::standard(), Stone::Black, 3));
+/// ```
+pub fn positions_at_ply(ply: u8, visit: impl FnMut(&Board, Stone)) -> u64 {
+    enumerate(ply, false, visit)
+}
+
+/// Calls the closure with one position of every set of symmetric positions
+/// reachable from the opening position at the specified ply, and returns
+/// their number.
+///
+/// The positions passed to the closure are [canonical]. Since symmetric
+/// positions are merged at every ply, this needs up to eight times less
+/// memory and time than [`positions_at_ply`].
+///
+/// [canonical]: crate::othello::Board::canonical
+/// [`positions_at_ply`]: crate::enumerate::positions_at_ply
+///
+/// # Examples
+/// ```rust
+/// use magpie::enumerate::{positions_at_ply, unique_positions_at_ply};
+///
+/// let unique = unique_positions_at_ply(4, |board, _| {
+///     assert_eq!(*board, board.canonical().0);
+/// });
+/// assert!(unique * 8 >= positions_at_ply(4, |_, _| {}));
+/// ```
+pub fn unique_positions_at_ply(ply: u8, visit: impl FnMut(&Board, Stone)) -> u64 {
+    enumerate(ply, true, visit)
+}
+
+fn enumerate(ply: u8, reduce: bool, mut visit: impl FnMut(&Board, Stone)) -> u64 {
+    let key = |board: Board, stone| {
+        if reduce {
+            (board.canonical().0, stone)
+        } else {
+            (board, stone)
+        }
+    };
+    let mut positions = HashSet::from([key(Board::standard(), Stone::Black)]);
+    if ply == 0 {
+        visit(&Board::standard(), Stone::Black);
+        return 1;
+    }
+    for current in 1..=ply {
+        let mut next_positions = HashSet::new();
+        for (board, stone) in &positions {
+            for pos in board.moves_for(*stone).hot_bits() {
+                let mut next = *board;
+                next.play(*stone, pos);
+                let position = key(next, to_move(&next, stone.flip()));
+                if next_positions.insert(position) && current == ply {
+                    visit(&position.0, position.1);
+                }
+            }
+        }
+        positions = next_positions;
+    }
+    positions.len() as u64
+}
+
+/// Returns the player to move next, which is the opponent of the player
+/// who just moved unless they have to pass.
+fn to_move(board: &Board, stone: Stone) -> Stone {
+    if board.moves_for(stone).is_empty() && !board.moves_for(stone.flip()).is_empty() {
+        stone.flip()
+    } else {
+        stone
+    }
+}
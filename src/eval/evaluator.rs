@@ -0,0 +1,72 @@
+use crate::othello::{Board, Stone};
+
+/// Statically evaluates a position from the perspective of the player to
+/// move.
+///
+/// Higher scores are better for the player to move. Search code in this
+/// crate accepts any evaluator, which makes it possible to swap in custom
+/// evaluation functions.
+///
+/// Scores should stay well within `±1_000_000`, since the search reserves
+/// values beyond that range for positions where the game has concluded.
+///
+/// # Examples
+/// ```rust
+/// use magpie::eval::Evaluator;
+/// use magpie::othello::{Board, Stone};
+///
+/// struct Corners;
+///
+/// impl Evaluator for Corners {
+///     fn evaluate(&self, board: &Board, to_move: Stone) -> i32 {
+///         let corners = 0x81_00_00_00_00_00_00_81;
+///         let own = (board.bits_for(to_move) & corners).count_set();
+///         let opponent = (board.bits_for(to_move.flip()) & corners).count_set();
+///         i32::from(own) - i32::from(opponent)
+///     }
+/// }
+///
+/// assert_eq!(0, Corners.evaluate(&Board::standard(), Stone::Black));
+/// ```
+pub trait Evaluator {
+    /// Returns the score of the position for the player to move.
+    fn evaluate(&self, board: &Board, to_move: Stone) -> i32;
+}
+
+impl<E: Evaluator + ?Sized> Evaluator for &E {
+    fn evaluate(&self, board: &Board, to_move: Stone) -> i32 {
+        (**self).evaluate(board, to_move)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<E: Evaluator + ?Sized> Evaluator for alloc::boxed::Box<E> {
+    fn evaluate(&self, board: &Board, to_move: Stone) -> i32 {
+        (**self).evaluate(board, to_move)
+    }
+}
+
+/// Evaluates a position by the difference in the number of stones.
+///
+/// This is a poor evaluation function during most of the game but it is
+/// exact once the game has concluded.
+///
+/// # Examples
+/// ```rust
+/// use magpie::eval::{DiscDifference, Evaluator};
+/// use magpie::othello::{Board, Stone};
+///
+/// let mut board = Board::standard();
+/// board.play(Stone::Black, "f5".parse().unwrap());
+/// assert_eq!(3, DiscDifference.evaluate(&board, Stone::Black));
+/// assert_eq!(-3, DiscDifference.evaluate(&board, Stone::White));
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+pub struct DiscDifference;
+
+impl Evaluator for DiscDifference {
+    fn evaluate(&self, board: &Board, to_move: Stone) -> i32 {
+        i32::from(board.bits_for(to_move).count_set())
+            - i32::from(board.bits_for(to_move.flip()).count_set())
+    }
+}
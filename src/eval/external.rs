@@ -0,0 +1,80 @@
+use crate::othello::Planes;
+use alloc::vec::Vec;
+
+/// The output of an [`ExternalEvaluator`] for a single position.
+///
+/// [`ExternalEvaluator`]: crate::eval::ExternalEvaluator
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Prediction {
+    /// The expected outcome for the player to move, between -1 (loss) and
+    /// 1 (win).
+    pub value: f32,
+    /// The prior probability of every square being the best move, indexed
+    /// the same way as [`Planes`], that is `rank * 8 + file`.
+    ///
+    /// The probabilities of illegal moves are ignored and the rest are
+    /// normalized, so the output of a softmax can be used directly.
+    ///
+    /// [`Planes`]: crate::othello::Planes
+    pub policy: [f32; 64],
+}
+
+/// Evaluates batches of positions with an external model, such as a neural
+/// network.
+///
+/// Unlike [`Evaluator`], positions are passed as encoded [`Planes`] and the
+/// model predicts both the value of a position and which moves are worth
+/// considering, as AlphaZero-style networks do. Positions are batched, as
+/// models typically run much faster on batches than on single inputs.
+/// [`Puct`] searches with an external evaluator.
+///
+/// Magpie does not depend on any machine learning library, so implementing
+/// this trait is how a model served by any of them is plugged in. Closures
+/// with the same signature as [`predict`] implement this trait as well.
+/// With the `onnx` feature, [`OnnxEvaluator`] runs models exported in the
+/// ONNX format.
+///
+/// [`Evaluator`]: crate::eval::Evaluator
+/// [`OnnxEvaluator`]: crate::eval::OnnxEvaluator
+/// [`Planes`]: crate::othello::Planes
+/// [`Puct`]: crate::search::Puct
+/// [`predict`]: crate::eval::ExternalEvaluator::predict
+///
+/// # Examples
+/// ```rust
+/// use magpie::eval::{ExternalEvaluator, Prediction};
+/// use magpie::othello::{Board, Planes, Stone};
+///
+/// // Prefers positions with more stones, and has no opinion on moves
+/// let mut model = |batch: &[Planes]| -> Vec<Prediction> {
+///     batch
+///         .iter()
+///         .map(|planes| {
+///             let own: f32 = planes[0].iter().flatten().sum();
+///             let opponent: f32 = planes[1].iter().flatten().sum();
+///             Prediction {
+///                 value: (own - opponent) / (own + opponent),
+///                 policy: [1.0 / 64.0; 64],
+///             }
+///         })
+///         .collect()
+/// };
+///
+/// let planes = Board::standard().to_planes(Stone::Black);
+/// let predictions = model.predict(&[planes]);
+/// assert_eq!(0.0, predictions[0].value);
+/// ```
+pub trait ExternalEvaluator {
+    /// Evaluates a batch of positions, each encoded from the perspective of
+    /// the player to move.
+    ///
+    /// Exactly one prediction must be returned for every position, in the
+    /// same order.
+    fn predict(&mut self, batch: &[Planes]) -> Vec<Prediction>;
+}
+
+impl<F: FnMut(&[Planes]) -> Vec<Prediction>> ExternalEvaluator for F {
+    fn predict(&mut self, batch: &[Planes]) -> Vec<Prediction> {
+        self(batch)
+    }
+}
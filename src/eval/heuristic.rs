@@ -0,0 +1,122 @@
+use crate::{
+    eval::Evaluator,
+    othello::{Board, Stone, constants::CORNERS},
+};
+
+/// Pairs of corners and their diagonally adjacent X-squares.
+const X_SQUARES: [(u64, u64); 4] = [
+    (1 << 63, 1 << 54),
+    (1 << 56, 1 << 49),
+    (1 << 7, 1 << 14),
+    (1 << 0, 1 << 9),
+];
+
+/// A hand-tuned evaluator combining a few classic Othello heuristics.
+///
+/// The evaluation is a weighted sum of the following features, each
+/// computed as the difference between the player to move and the opponent:
+///
+/// - **Mobility**: The number of legal moves.
+/// - **Frontier**: The number of stones adjacent to empty squares, which
+///   counts against the player since such stones are easy to flip.
+/// - **Corners**: The number of occupied corners.
+/// - **X-squares**: The number of stones diagonally adjacent to empty
+///   corners, which counts against the player since they give away the
+///   corner.
+/// - **Discs**: The number of stones.
+///
+/// The weights are public and may be tuned freely.
+///
+/// # Examples
+/// ```rust
+/// use magpie::eval::{Evaluator, Heuristic};
+/// use magpie::othello::{Board, Stone};
+///
+/// let heuristic = Heuristic::default();
+/// assert_eq!(0, heuristic.evaluate(&Board::standard(), Stone::Black));
+///
+/// let mut board = Board::standard();
+/// board.play(Stone::Black, "f5".parse().unwrap());
+/// let score = heuristic.evaluate(&board, Stone::White);
+/// assert_eq!(-score, heuristic.evaluate(&board, Stone::Black));
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct Heuristic {
+    /// The weight of each legal move.
+    pub mobility: i32,
+    /// The penalty of each frontier stone.
+    pub frontier: i32,
+    /// The weight of each corner.
+    pub corners: i32,
+    /// The penalty of each stone on an X-square next to an empty corner.
+    pub x_squares: i32,
+    /// The weight of each stone.
+    pub discs: i32,
+}
+
+impl Default for Heuristic {
+    fn default() -> Self {
+        Self {
+            mobility: 10,
+            frontier: 4,
+            corners: 100,
+            x_squares: 40,
+            discs: 1,
+        }
+    }
+}
+
+impl Heuristic {
+    /// Returns the features in the order of the weights, where penalties
+    /// are negated so that every feature is multiplied by its weight.
+    pub(crate) fn terms(board: &Board, to_move: Stone) -> [i32; 5] {
+        let own = board.bits_for(to_move).raw();
+        let opponent = board.bits_for(to_move.flip()).raw();
+        let empty = board.empty_squares().raw();
+
+        let mobility =
+            i32::from(board.mobility(to_move)) - i32::from(board.mobility(to_move.flip()));
+
+        let frontier = diff(
+            board.frontier(to_move).raw(),
+            board.frontier(to_move.flip()).raw(),
+        );
+
+        let corners = diff(own & CORNERS, opponent & CORNERS);
+
+        let x_squares = X_SQUARES
+            .iter()
+            .filter(|(corner, _)| empty & corner != 0)
+            .map(|(_, x_square)| diff(own & x_square, opponent & x_square))
+            .sum::<i32>();
+
+        let discs = diff(own, opponent);
+
+        [mobility, -frontier, corners, -x_squares, discs]
+    }
+
+    /// Returns the weights in the order of the features.
+    pub(crate) fn weights(&self) -> [i32; 5] {
+        [
+            self.mobility,
+            self.frontier,
+            self.corners,
+            self.x_squares,
+            self.discs,
+        ]
+    }
+}
+
+impl Evaluator for Heuristic {
+    fn evaluate(&self, board: &Board, to_move: Stone) -> i32 {
+        Self::terms(board, to_move)
+            .iter()
+            .zip(self.weights())
+            .map(|(feature, weight)| feature * weight)
+            .sum()
+    }
+}
+
+fn diff(own: u64, opponent: u64) -> i32 {
+    own.count_ones() as i32 - opponent.count_ones() as i32
+}
@@ -0,0 +1,189 @@
+use crate::{
+    eval::Evaluator,
+    othello::{Board, Position, Stone, Undo},
+};
+
+/// An evaluator whose features can be updated move by move instead of being
+/// recomputed from scratch for every position.
+///
+/// The features are kept in an accumulator, which is computed once for the
+/// root of a search and then updated whenever a move is made with
+/// [`Board::make`] or taken back with [`Board::unmake`]. Since only the
+/// squares touched by a move change, updating the accumulator is much
+/// cheaper than looking at the whole board again. [`TrackedBoard`] keeps a
+/// board and its accumulator in step.
+///
+/// Implementations must return the same score from [`evaluate_accumulated`]
+/// as from [`evaluate`] for every board.
+///
+/// [`Board::make`]: crate::othello::Board::make
+/// [`Board::unmake`]: crate::othello::Board::unmake
+/// [`evaluate_accumulated`]: crate::eval::IncrementalEvaluator::evaluate_accumulated
+/// [`evaluate`]: crate::eval::Evaluator::evaluate
+///
+/// # Examples
+/// ```rust
+/// use magpie::eval::{Evaluator, IncrementalEvaluator};
+/// use magpie::othello::{Board, Stone, Undo};
+///
+/// /// Counts the stones of each player, which a move changes by its flips.
+/// struct Discs;
+///
+/// impl Evaluator for Discs {
+///     fn evaluate(&self, board: &Board, to_move: Stone) -> i32 {
+///         let (black, white) = board.count_stones();
+///         let difference = i32::from(black) - i32::from(white);
+///         if to_move == Stone::Black { difference } else { -difference }
+///     }
+/// }
+///
+/// impl IncrementalEvaluator for Discs {
+///     type Accumulator = i32;
+///
+///     fn accumulate(&self, board: &Board) -> i32 {
+///         self.evaluate(board, Stone::Black)
+///     }
+///
+///     fn apply(&self, black_lead: &mut i32, undo: &Undo) {
+///         let change = 1 + 2 * i32::from(undo.flips().count_set());
+///         *black_lead += if undo.stone() == Stone::Black { change } else { -change };
+///     }
+///
+///     fn revert(&self, black_lead: &mut i32, undo: &Undo) {
+///         let change = 1 + 2 * i32::from(undo.flips().count_set());
+///         *black_lead -= if undo.stone() == Stone::Black { change } else { -change };
+///     }
+///
+///     fn evaluate_accumulated(&self, black_lead: &i32, _: &Board, to_move: Stone) -> i32 {
+///         if to_move == Stone::Black { *black_lead } else { -black_lead }
+///     }
+/// }
+///
+/// let mut board = Board::standard();
+/// let mut black_lead = Discs.accumulate(&board);
+/// let undo = board.make(Stone::Black, "f5".parse().unwrap());
+/// Discs.apply(&mut black_lead, &undo);
+/// assert_eq!(3, Discs.evaluate_accumulated(&black_lead, &board, Stone::Black));
+/// ```
+pub trait IncrementalEvaluator: Evaluator {
+    /// The features of a board that are kept up to date as moves are made.
+    type Accumulator: Clone;
+
+    /// Computes the accumulator of the board from scratch.
+    fn accumulate(&self, board: &Board) -> Self::Accumulator;
+
+    /// Updates the accumulator after the move has been made.
+    fn apply(&self, accumulator: &mut Self::Accumulator, undo: &Undo);
+
+    /// Restores the accumulator after the move has been taken back.
+    fn revert(&self, accumulator: &mut Self::Accumulator, undo: &Undo);
+
+    /// Returns the score of the board for the player to move, using the
+    /// accumulator of the board.
+    fn evaluate_accumulated(
+        &self,
+        accumulator: &Self::Accumulator,
+        board: &Board,
+        to_move: Stone,
+    ) -> i32;
+}
+
+impl<E: IncrementalEvaluator + ?Sized> IncrementalEvaluator for &E {
+    type Accumulator = E::Accumulator;
+
+    fn accumulate(&self, board: &Board) -> Self::Accumulator {
+        (**self).accumulate(board)
+    }
+
+    fn apply(&self, accumulator: &mut Self::Accumulator, undo: &Undo) {
+        (**self).apply(accumulator, undo);
+    }
+
+    fn revert(&self, accumulator: &mut Self::Accumulator, undo: &Undo) {
+        (**self).revert(accumulator, undo);
+    }
+
+    fn evaluate_accumulated(
+        &self,
+        accumulator: &Self::Accumulator,
+        board: &Board,
+        to_move: Stone,
+    ) -> i32 {
+        (**self).evaluate_accumulated(accumulator, board, to_move)
+    }
+}
+
+/// A board along with the accumulator of an [`IncrementalEvaluator`], which
+/// is updated on every move made or taken back.
+///
+/// # Examples
+/// ```rust
+/// use magpie::eval::{Evaluator, Pattern, PatternEvaluator, TrackedBoard};
+/// use magpie::othello::{Board, Stone};
+///
+/// let mut evaluator = PatternEvaluator::new(1);
+/// evaluator.weights_mut(0, Pattern::Diagonal8)[0] = 1;
+///
+/// let mut tracked = TrackedBoard::new(&evaluator, Board::standard());
+/// let undo = tracked.make(Stone::Black, "f5".parse().unwrap());
+/// assert_eq!(
+///     evaluator.evaluate(tracked.board(), Stone::White),
+///     tracked.evaluate(Stone::White)
+/// );
+///
+/// tracked.unmake(undo);
+/// assert_eq!(&Board::standard(), tracked.board());
+/// ```
+pub struct TrackedBoard<'a, E: IncrementalEvaluator> {
+    evaluator: &'a E,
+    board: Board,
+    accumulator: E::Accumulator,
+}
+
+impl<'a, E: IncrementalEvaluator> TrackedBoard<'a, E> {
+    /// Returns the board, along with its accumulator computed from scratch.
+    #[must_use]
+    pub fn new(evaluator: &'a E, board: Board) -> Self {
+        Self {
+            evaluator,
+            accumulator: evaluator.accumulate(&board),
+            board,
+        }
+    }
+
+    /// Returns the current board.
+    #[must_use]
+    pub fn board(&self) -> &Board {
+        &self.board
+    }
+
+    /// Returns the accumulator of the current board.
+    #[must_use]
+    pub fn accumulator(&self) -> &E::Accumulator {
+        &self.accumulator
+    }
+
+    /// Makes the move and updates the accumulator, as [`Board::make`].
+    ///
+    /// [`Board::make`]: crate::othello::Board::make
+    pub fn make(&mut self, stone: Stone, pos: Position) -> Undo {
+        let undo = self.board.make(stone, pos);
+        self.evaluator.apply(&mut self.accumulator, &undo);
+        undo
+    }
+
+    /// Takes back a move made with [`make`] and restores the accumulator.
+    ///
+    /// [`make`]: crate::eval::TrackedBoard::make
+    pub fn unmake(&mut self, undo: Undo) {
+        self.board.unmake(undo);
+        self.evaluator.revert(&mut self.accumulator, &undo);
+    }
+
+    /// Returns the score of the current board for the player to move.
+    #[must_use]
+    pub fn evaluate(&self, to_move: Stone) -> i32 {
+        self.evaluator
+            .evaluate_accumulated(&self.accumulator, &self.board, to_move)
+    }
+}
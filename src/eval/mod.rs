@@ -0,0 +1,37 @@
+//! Static evaluation of Othello positions.
+
+/// The trait shared by all evaluation functions.
+mod evaluator;
+/// Batched evaluation by external models such as neural networks.
+#[cfg(feature = "alloc")]
+mod external;
+/// A hand-tuned evaluator built from classic Othello heuristics.
+mod heuristic;
+/// Evaluators that update their features move by move.
+mod incremental;
+/// Runs neural networks in the ONNX format as external evaluators.
+#[cfg(feature = "onnx")]
+mod onnx;
+/// An evaluator built from weighted board patterns.
+#[cfg(feature = "std")]
+mod pattern;
+/// Blends evaluators tuned for different phases of the game.
+#[cfg(feature = "alloc")]
+mod tapered;
+/// Converts scores into the probabilities of winning, drawing and losing.
+#[cfg(feature = "std")]
+mod wdl;
+
+pub use evaluator::{DiscDifference, Evaluator};
+#[cfg(feature = "alloc")]
+pub use external::{ExternalEvaluator, Prediction};
+pub use heuristic::Heuristic;
+pub use incremental::{IncrementalEvaluator, TrackedBoard};
+#[cfg(feature = "onnx")]
+pub use onnx::{OnnxError, OnnxEvaluator};
+#[cfg(feature = "std")]
+pub use pattern::{Pattern, PatternAccumulator, PatternError, PatternEvaluator};
+#[cfg(feature = "alloc")]
+pub use tapered::Tapered;
+#[cfg(feature = "std")]
+pub use wdl::{WdlModel, WdlProbabilities, wdl_model};
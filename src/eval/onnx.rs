@@ -0,0 +1,143 @@
+use crate::{
+    eval::{ExternalEvaluator, Prediction},
+    othello::Planes,
+};
+use std::{io::Read, path::Path};
+use tract_onnx::prelude::{
+    Datum, Framework, InferenceFact, InferenceModelExt, TDim, Tensor, TractError, TypedModel,
+    TypedRunnableModel, tvec,
+};
+
+/// An [`ExternalEvaluator`] that runs a neural network in the ONNX format
+/// with [`tract`], a pure Rust inference engine.
+///
+/// The model must take a single input of shape `[N, 2, 8, 8]`, holding a
+/// batch of [`Planes`], and have two outputs:
+///
+/// - The value of every position, of shape `[N, 1]` or `[N]`, between -1
+///   and 1.
+/// - The policy of every position, of shape `[N, 64]`, indexed the same
+///   way as [`Prediction::policy`].
+///
+/// The batch size is left open, so that any number of positions can be
+/// evaluated at once. Models exported from PyTorch or TensorFlow with a
+/// dynamic batch axis fit this description.
+///
+/// [`ExternalEvaluator`]: crate::eval::ExternalEvaluator
+/// [`Planes`]: crate::othello::Planes
+/// [`Prediction::policy`]: crate::eval::Prediction::policy
+/// [`tract`]: https://docs.rs/tract-onnx
+///
+/// # Examples
+/// ```rust,no_run
+/// use magpie::eval::{ExternalEvaluator, OnnxEvaluator};
+/// use magpie::othello::{Board, Stone};
+///
+/// let mut evaluator = OnnxEvaluator::load("model.onnx").unwrap();
+/// let planes = Board::standard().to_planes(Stone::Black);
+/// let predictions = evaluator.predict(&[planes]);
+/// println!("{}", predictions[0].value);
+/// ```
+#[derive(Debug)]
+pub struct OnnxEvaluator {
+    model: TypedRunnableModel<TypedModel>,
+}
+
+impl OnnxEvaluator {
+    /// Reads a model from the ONNX file at the specified path.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, OnnxError> {
+        let file = std::fs::File::open(path).map_err(|error| OnnxError(error.into()))?;
+        Self::read(std::io::BufReader::new(file))
+    }
+
+    /// Reads a model in the ONNX format.
+    ///
+    /// The model is optimized for the shape of its input, which may take a
+    /// while for large models.
+    pub fn read<R: Read>(mut reader: R) -> Result<Self, OnnxError> {
+        let mut model = tract_onnx::onnx().model_for_read(&mut reader)?;
+        let batch = model.symbol_table.sym("N");
+        let shape: [TDim; 4] = [batch.into(), 2.into(), 8.into(), 8.into()];
+        model.set_input_fact(0, InferenceFact::dt_shape(f32::datum_type(), shape))?;
+        let model = model.into_optimized()?;
+        if model.output_outlets()?.len() != 2 {
+            return Err(OnnxError(TractError::msg(
+                "the model must have a value and a policy output",
+            )));
+        }
+        Ok(Self {
+            model: model.into_runnable()?,
+        })
+    }
+
+    /// Evaluates a batch of positions, returning an error instead of
+    /// panicking if the model fails or returns outputs of the wrong shape.
+    pub fn try_predict(&self, batch: &[Planes]) -> Result<Vec<Prediction>, OnnxError> {
+        if batch.is_empty() {
+            return Ok(Vec::new());
+        }
+        let input: Vec<f32> = batch
+            .iter()
+            .flatten()
+            .flatten()
+            .flatten()
+            .copied()
+            .collect();
+        let input = Tensor::from_shape(&[batch.len(), 2, 8, 8], &input)?;
+        let outputs = self.model.run(tvec!(input.into()))?;
+
+        let values = outputs[0].as_slice::<f32>()?;
+        let policies = outputs[1].as_slice::<f32>()?;
+        if values.len() != batch.len() || policies.len() != batch.len() * 64 {
+            return Err(OnnxError(TractError::msg(
+                "the outputs of the model do not match the batch",
+            )));
+        }
+        Ok(values
+            .iter()
+            .zip(policies.chunks_exact(64))
+            .map(|(&value, policy)| Prediction {
+                value,
+                policy: policy.try_into().expect("policies have 64 squares"),
+            })
+            .collect())
+    }
+}
+
+impl ExternalEvaluator for OnnxEvaluator {
+    /// Evaluates a batch of positions.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the model fails, see [`try_predict`] for a fallible
+    /// version.
+    ///
+    /// [`try_predict`]: crate::eval::OnnxEvaluator::try_predict
+    fn predict(&mut self, batch: &[Planes]) -> Vec<Prediction> {
+        self.try_predict(batch)
+            .unwrap_or_else(|error| panic!("failed to evaluate the batch: {error}"))
+    }
+}
+
+/// This struct represents errors that may occur when loading or running an
+/// ONNX model.
+#[derive(Debug)]
+pub struct OnnxError(TractError);
+
+impl From<TractError> for OnnxError {
+    fn from(error: TractError) -> Self {
+        OnnxError(error)
+    }
+}
+
+impl std::fmt::Display for OnnxError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "onnx error: {:#}", self.0)
+    }
+}
+
+impl std::error::Error for OnnxError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(self.0.as_ref())
+    }
+}
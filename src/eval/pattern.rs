@@ -0,0 +1,563 @@
+use crate::{
+    eval::{Evaluator, IncrementalEvaluator},
+    othello::{Bitboard, Board, Position, Stone, Undo},
+};
+use std::io::{Read, Write};
+
+/// Identifies weight files written by [`PatternEvaluator::save`].
+///
+/// [`PatternEvaluator::save`]: crate::eval::PatternEvaluator::save
+const MAGIC: &[u8; 4] = b"MPPW";
+const VERSION: u8 = 1;
+
+/// A group of squares whose contents are evaluated as a whole.
+///
+/// Every pattern is evaluated in all four rotations of the board, and the
+/// rotations share the same weight table.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum Pattern {
+    /// An edge along with the two adjacent X-squares.
+    Edge2X,
+    /// The 3x3 block of squares in a corner.
+    Corner3x3,
+    /// The 2x5 block of squares in a corner, in both orientations.
+    Corner2x5,
+    /// The second row from an edge.
+    Row2,
+    /// The third row from an edge.
+    Row3,
+    /// The fourth row from an edge.
+    Row4,
+    /// A main diagonal.
+    Diagonal8,
+    /// A diagonal of seven squares.
+    Diagonal7,
+    /// A diagonal of six squares.
+    Diagonal6,
+    /// A diagonal of five squares.
+    Diagonal5,
+    /// A diagonal of four squares.
+    Diagonal4,
+}
+
+impl Pattern {
+    /// All patterns, in the order their weights are stored.
+    pub const ALL: [Pattern; 11] = [
+        Pattern::Edge2X,
+        Pattern::Corner3x3,
+        Pattern::Corner2x5,
+        Pattern::Row2,
+        Pattern::Row3,
+        Pattern::Row4,
+        Pattern::Diagonal8,
+        Pattern::Diagonal7,
+        Pattern::Diagonal6,
+        Pattern::Diagonal5,
+        Pattern::Diagonal4,
+    ];
+
+    /// Returns the number of squares in the pattern.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::eval::Pattern;
+    ///
+    /// assert_eq!(10, Pattern::Edge2X.len());
+    /// assert_eq!(4, Pattern::Diagonal4.len());
+    /// ```
+    #[must_use]
+    #[allow(clippy::len_without_is_empty)]
+    pub fn len(self) -> usize {
+        self.bases()[0].len() / 2
+    }
+
+    /// Returns the number of distinct configurations of the pattern, which
+    /// is the size of its weight table.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::eval::Pattern;
+    ///
+    /// assert_eq!(81, Pattern::Diagonal4.table_size());
+    /// ```
+    #[must_use]
+    pub fn table_size(self) -> usize {
+        3_usize.pow(self.len() as u32)
+    }
+
+    /// Returns the squares of the pattern in all its instances on the board.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::eval::Pattern;
+    ///
+    /// let instances = Pattern::Corner3x3.instances();
+    /// assert_eq!(4, instances.len());
+    /// assert_eq!("a1", instances[0][0].to_string());
+    /// ```
+    #[must_use]
+    pub fn instances(self) -> Vec<Vec<Position>> {
+        let mut instances = Vec::new();
+        for base in self.bases() {
+            let mut squares: Vec<Position> = base
+                .as_bytes()
+                .chunks(2)
+                .map(|chunk| Position::try_from(std::str::from_utf8(chunk).unwrap()).unwrap())
+                .collect();
+            for _ in 0..4 {
+                instances.push(squares.clone());
+                squares = squares.into_iter().map(Position::rotate_cw).collect();
+            }
+        }
+        instances
+    }
+
+    fn bases(self) -> &'static [&'static str] {
+        match self {
+            Pattern::Edge2X => &["a1b1c1d1e1f1g1h1b2g2"],
+            Pattern::Corner3x3 => &["a1b1c1a2b2c2a3b3c3"],
+            Pattern::Corner2x5 => &["a1b1c1d1e1a2b2c2d2e2", "a1a2a3a4a5b1b2b3b4b5"],
+            Pattern::Row2 => &["a2b2c2d2e2f2g2h2"],
+            Pattern::Row3 => &["a3b3c3d3e3f3g3h3"],
+            Pattern::Row4 => &["a4b4c4d4e4f4g4h4"],
+            Pattern::Diagonal8 => &["a1b2c3d4e5f6g7h8"],
+            Pattern::Diagonal7 => &["b1c2d3e4f5g6h7"],
+            Pattern::Diagonal6 => &["c1d2e3f4g5h6"],
+            Pattern::Diagonal5 => &["d1e2f3g4h5"],
+            Pattern::Diagonal4 => &["e1f2g3h4"],
+        }
+    }
+}
+
+/// Evaluates positions by looking up the contents of standard Othello
+/// patterns in tables of weights.
+///
+/// Each instance of a [`Pattern`] on the board is converted to an index by
+/// reading its squares in order as base 3 digits, where empty squares are
+/// 0, stones of the player to move are 1 and stones of the opponent are 2.
+/// The evaluation is the sum of the weights at those indices.
+///
+/// The game is divided into a number of stages by the number of stones on
+/// the board, and every stage has its own set of weight tables. A new
+/// evaluator has all weights set to zero. The weights are meant to be
+/// trained, for example by regression on positions from a game database,
+/// after which they can be saved and loaded again.
+///
+/// The indices can also be updated move by move, as an
+/// [`IncrementalEvaluator`], which is much faster than computing them from
+/// scratch in every position.
+///
+/// [`Pattern`]: crate::eval::Pattern
+/// [`IncrementalEvaluator`]: crate::eval::IncrementalEvaluator
+///
+/// # Examples
+/// ```rust
+/// use magpie::eval::{Evaluator, Pattern, PatternEvaluator};
+/// use magpie::othello::{Board, Stone};
+///
+/// let mut evaluator = PatternEvaluator::new(1);
+/// let board = Board::standard();
+/// assert_eq!(0, evaluator.evaluate(&board, Stone::Black));
+///
+/// // Reward the player for an empty edge, which all four instances share
+/// evaluator.weights_mut(0, Pattern::Edge2X)[0] = 5;
+/// assert_eq!(20, evaluator.evaluate(&board, Stone::Black));
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct PatternEvaluator {
+    stages: usize,
+    tables: Vec<Vec<i32>>,
+    instances: Vec<(usize, Vec<u64>)>,
+    /// For every square, the instances it belongs to along with the value
+    /// of its digit in their indices.
+    digits: Vec<Vec<(usize, u32)>>,
+}
+
+impl PatternEvaluator {
+    /// Returns an evaluator with the specified number of stages, where all
+    /// weights are zero.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of stages is zero or greater than 61.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::eval::PatternEvaluator;
+    ///
+    /// let evaluator = PatternEvaluator::new(4);
+    /// assert_eq!(4, evaluator.stages());
+    /// ```
+    #[must_use]
+    pub fn new(stages: usize) -> Self {
+        assert!((1..=61).contains(&stages), "invalid number of stages");
+        let tables = (0..stages)
+            .flat_map(|_| {
+                Pattern::ALL
+                    .iter()
+                    .map(|pattern| vec![0; pattern.table_size()])
+            })
+            .collect();
+        let instances = Pattern::ALL
+            .iter()
+            .enumerate()
+            .flat_map(|(index, pattern)| {
+                pattern
+                    .instances()
+                    .into_iter()
+                    .map(move |squares| (index, squares.iter().map(|pos| pos.raw()).collect()))
+            })
+            .collect::<Vec<(usize, Vec<u64>)>>();
+        let mut digits = vec![Vec::new(); 64];
+        for (instance, (_, squares)) in instances.iter().enumerate() {
+            for (digit, square) in squares.iter().enumerate() {
+                digits[square.leading_zeros() as usize].push((instance, 3_u32.pow(digit as u32)));
+            }
+        }
+        Self {
+            stages,
+            tables,
+            instances,
+            digits,
+        }
+    }
+
+    /// Returns the number of stages the game is divided into.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::eval::PatternEvaluator;
+    ///
+    /// assert_eq!(1, PatternEvaluator::new(1).stages());
+    /// ```
+    #[must_use]
+    pub fn stages(&self) -> usize {
+        self.stages
+    }
+
+    /// Returns the stage of the evaluator the board belongs to, found by
+    /// dividing the range of [`Board::stage`] evenly between the stages.
+    ///
+    /// [`Board::stage`]: crate::othello::Board::stage
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::eval::PatternEvaluator;
+    /// use magpie::othello::Board;
+    ///
+    /// let evaluator = PatternEvaluator::new(4);
+    /// assert_eq!(0, evaluator.stage(&Board::standard()));
+    /// ```
+    #[must_use]
+    pub fn stage(&self, board: &Board) -> usize {
+        (usize::from(board.stage()) * self.stages / 61).min(self.stages - 1)
+    }
+
+    /// Returns the weight table of the pattern in the specified stage.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::eval::{Pattern, PatternEvaluator};
+    ///
+    /// let evaluator = PatternEvaluator::new(1);
+    /// assert_eq!(59049, evaluator.weights(0, Pattern::Edge2X).len());
+    /// ```
+    #[must_use]
+    pub fn weights(&self, stage: usize, pattern: Pattern) -> &[i32] {
+        &self.tables[self.table_index(stage, pattern)]
+    }
+
+    /// Returns a mutable reference to the weight table of the pattern in the
+    /// specified stage.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::eval::{Pattern, PatternEvaluator};
+    ///
+    /// let mut evaluator = PatternEvaluator::new(1);
+    /// evaluator.weights_mut(0, Pattern::Row2)[42] = 7;
+    /// assert_eq!(7, evaluator.weights(0, Pattern::Row2)[42]);
+    /// ```
+    pub fn weights_mut(&mut self, stage: usize, pattern: Pattern) -> &mut [i32] {
+        let index = self.table_index(stage, pattern);
+        &mut self.tables[index]
+    }
+
+    /// Returns the pattern and index of every pattern instance on the board,
+    /// from the perspective of the player to move.
+    ///
+    /// These are the features used by the evaluation, which is useful when
+    /// training the weights.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::eval::{Pattern, PatternEvaluator};
+    /// use magpie::othello::{Board, Stone};
+    ///
+    /// let evaluator = PatternEvaluator::new(1);
+    /// let indices = evaluator.indices(&Board::standard(), Stone::Black);
+    /// assert!(indices.contains(&(Pattern::Edge2X, 0)));
+    /// ```
+    #[must_use]
+    pub fn indices(&self, board: &Board, to_move: Stone) -> Vec<(Pattern, usize)> {
+        let own = board.bits_for(to_move).raw();
+        let opponent = board.bits_for(to_move.flip()).raw();
+        self.instances
+            .iter()
+            .map(|(pattern, squares)| (Pattern::ALL[*pattern], index(squares, own, opponent)))
+            .collect()
+    }
+
+    /// Reads an evaluator from weights previously written by [`save`].
+    ///
+    /// [`save`]: crate::eval::PatternEvaluator::save
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::eval::{Pattern, PatternEvaluator};
+    ///
+    /// let mut evaluator = PatternEvaluator::new(2);
+    /// evaluator.weights_mut(1, Pattern::Corner3x3)[100] = -3;
+    ///
+    /// let mut bytes = Vec::new();
+    /// evaluator.save(&mut bytes).unwrap();
+    /// assert_eq!(evaluator, PatternEvaluator::load(bytes.as_slice()).unwrap());
+    /// ```
+    pub fn load<R: Read>(mut reader: R) -> Result<Self, PatternError> {
+        let mut header = [0; 9];
+        reader.read_exact(&mut header)?;
+        if &header[..4] != MAGIC || header[4] != VERSION {
+            return Err(PatternError::InvalidFormat);
+        }
+        let stages = u32::from_le_bytes(header[5..9].try_into().unwrap()) as usize;
+        if !(1..=61).contains(&stages) {
+            return Err(PatternError::InvalidFormat);
+        }
+
+        let mut evaluator = Self::new(stages);
+        let mut buffer = [0; 4];
+        for table in &mut evaluator.tables {
+            for weight in table.iter_mut() {
+                reader.read_exact(&mut buffer)?;
+                *weight = i32::from_le_bytes(buffer);
+            }
+        }
+        Ok(evaluator)
+    }
+
+    /// Writes the weights of the evaluator.
+    ///
+    /// The format consists of a short header followed by every weight as a
+    /// little-endian 32-bit integer, ordered by stage, then by the order of
+    /// [`Pattern::ALL`] and finally by index.
+    ///
+    /// [`Pattern::ALL`]: crate::eval::Pattern::ALL
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::eval::PatternEvaluator;
+    ///
+    /// let mut bytes = Vec::new();
+    /// PatternEvaluator::new(1).save(&mut bytes).unwrap();
+    /// assert_eq!(b"MPPW", &bytes[..4]);
+    /// ```
+    pub fn save<W: Write>(&self, mut writer: W) -> Result<(), PatternError> {
+        writer.write_all(MAGIC)?;
+        writer.write_all(&[VERSION])?;
+        writer.write_all(&(self.stages as u32).to_le_bytes())?;
+        for table in &self.tables {
+            for weight in table {
+                writer.write_all(&weight.to_le_bytes())?;
+            }
+        }
+        Ok(())
+    }
+
+    fn table_index(&self, stage: usize, pattern: Pattern) -> usize {
+        assert!(stage < self.stages, "stage out of bounds");
+        let pattern = Pattern::ALL.iter().position(|p| *p == pattern).unwrap();
+        stage * Pattern::ALL.len() + pattern
+    }
+}
+
+impl Evaluator for PatternEvaluator {
+    fn evaluate(&self, board: &Board, to_move: Stone) -> i32 {
+        let own = board.bits_for(to_move).raw();
+        let opponent = board.bits_for(to_move.flip()).raw();
+        let tables = &self.tables[self.stage(board) * Pattern::ALL.len()..];
+        self.instances
+            .iter()
+            .map(|(pattern, squares)| tables[*pattern][index(squares, own, opponent)])
+            .sum()
+    }
+}
+
+/// The indices of every pattern instance on a board, from the perspective of
+/// each player, as kept up to date by the [`PatternEvaluator`].
+///
+/// [`PatternEvaluator`]: crate::eval::PatternEvaluator
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct PatternAccumulator {
+    black: Vec<u32>,
+    white: Vec<u32>,
+}
+
+impl PatternAccumulator {
+    fn indices(&self, stone: Stone) -> &[u32] {
+        match stone {
+            Stone::Black => &self.black,
+            Stone::White => &self.white,
+        }
+    }
+
+    /// Returns the indices from the perspective of the player and of the
+    /// opponent.
+    fn split_mut(&mut self, stone: Stone) -> (&mut [u32], &mut [u32]) {
+        match stone {
+            Stone::Black => (&mut self.black, &mut self.white),
+            Stone::White => (&mut self.white, &mut self.black),
+        }
+    }
+}
+
+impl PatternEvaluator {
+    /// Adds the digits of a stone placed in the position to the indices of
+    /// the player and the opponent, or subtracts them if the stone is
+    /// removed.
+    fn place(
+        &self,
+        accumulator: &mut PatternAccumulator,
+        stone: Stone,
+        pos: Position,
+        removed: bool,
+    ) {
+        let (own, opponent) = accumulator.split_mut(stone);
+        for &(instance, value) in &self.digits[square(pos)] {
+            if removed {
+                own[instance] -= value;
+                opponent[instance] -= 2 * value;
+            } else {
+                own[instance] += value;
+                opponent[instance] += 2 * value;
+            }
+        }
+    }
+
+    /// Changes the digits of the flipped stones, which now belong to the
+    /// player, or to the opponent again if the flips are taken back.
+    fn flip(
+        &self,
+        accumulator: &mut PatternAccumulator,
+        stone: Stone,
+        flips: Bitboard,
+        reverted: bool,
+    ) {
+        let (own, opponent) = accumulator.split_mut(stone);
+        let (own, opponent) = if reverted {
+            (opponent, own)
+        } else {
+            (own, opponent)
+        };
+        for pos in flips.hot_bits() {
+            for &(instance, value) in &self.digits[square(pos)] {
+                own[instance] -= value;
+                opponent[instance] += value;
+            }
+        }
+    }
+}
+
+impl IncrementalEvaluator for PatternEvaluator {
+    type Accumulator = PatternAccumulator;
+
+    fn accumulate(&self, board: &Board) -> PatternAccumulator {
+        let indices = |stone: Stone| {
+            let own = board.bits_for(stone).raw();
+            let opponent = board.bits_for(stone.flip()).raw();
+            self.instances
+                .iter()
+                .map(|(_, squares)| index(squares, own, opponent) as u32)
+                .collect()
+        };
+        PatternAccumulator {
+            black: indices(Stone::Black),
+            white: indices(Stone::White),
+        }
+    }
+
+    fn apply(&self, accumulator: &mut PatternAccumulator, undo: &Undo) {
+        self.place(accumulator, undo.stone(), undo.pos(), false);
+        self.flip(accumulator, undo.stone(), undo.flips(), false);
+    }
+
+    fn revert(&self, accumulator: &mut PatternAccumulator, undo: &Undo) {
+        self.flip(accumulator, undo.stone(), undo.flips(), true);
+        self.place(accumulator, undo.stone(), undo.pos(), true);
+    }
+
+    fn evaluate_accumulated(
+        &self,
+        accumulator: &PatternAccumulator,
+        board: &Board,
+        to_move: Stone,
+    ) -> i32 {
+        let tables = &self.tables[self.stage(board) * Pattern::ALL.len()..];
+        self.instances
+            .iter()
+            .zip(accumulator.indices(to_move))
+            .map(|((pattern, _), index)| tables[*pattern][*index as usize])
+            .sum()
+    }
+}
+
+/// Returns the number of the square, counting from A1.
+fn square(pos: Position) -> usize {
+    pos.raw().leading_zeros() as usize
+}
+
+fn index(squares: &[u64], own: u64, opponent: u64) -> usize {
+    squares.iter().rev().fold(0, |index, square| {
+        let digit = if own & square != 0 {
+            1
+        } else if opponent & square != 0 {
+            2
+        } else {
+            0
+        };
+        index * 3 + digit
+    })
+}
+
+/// This enum represents errors that may occur when loading or saving
+/// pattern weights.
+#[derive(Debug)]
+pub enum PatternError {
+    /// Indicates that reading or writing the weights failed.
+    Io(std::io::Error),
+    /// Indicates that the weights are not in the expected format.
+    InvalidFormat,
+}
+
+impl From<std::io::Error> for PatternError {
+    fn from(error: std::io::Error) -> Self {
+        PatternError::Io(error)
+    }
+}
+
+impl std::fmt::Display for PatternError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PatternError::Io(error) => write!(f, "i/o error: {error}"),
+            PatternError::InvalidFormat => f.write_str("invalid pattern weight format"),
+        }
+    }
+}
+
+impl std::error::Error for PatternError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            PatternError::Io(error) => Some(error),
+            PatternError::InvalidFormat => None,
+        }
+    }
+}
@@ -0,0 +1,104 @@
+use crate::{
+    eval::Evaluator,
+    othello::{Board, Stone},
+};
+use alloc::vec::Vec;
+
+/// The last stage of the game, as returned by [`Board::stage`].
+///
+/// [`Board::stage`]: crate::othello::Board::stage
+const LAST_STAGE: i64 = 60;
+
+/// Blends several evaluators, each tuned for a different phase of the game,
+/// depending on the stage of the game.
+///
+/// The phases are spread evenly over the game, from the first one at the
+/// standard opening position to the last one on a full board, as measured
+/// by [`Board::stage`]. Positions between two phases are scored by linear
+/// interpolation between the evaluations of both, which avoids the sudden
+/// jumps in the evaluation that come with switching weights at fixed
+/// stages.
+///
+/// Blending two [`Heuristic`]s, for example, makes it possible to value
+/// mobility in the opening and stones at the end of the game.
+///
+/// [`Board::stage`]: crate::othello::Board::stage
+/// [`Heuristic`]: crate::eval::Heuristic
+///
+/// # Examples
+/// ```rust
+/// use magpie::eval::{Evaluator, Heuristic, Tapered};
+/// use magpie::othello::{Board, Stone};
+///
+/// let opening = Heuristic { mobility: 20, discs: 0, ..Heuristic::default() };
+/// let endgame = Heuristic { mobility: 0, discs: 10, ..Heuristic::default() };
+/// let tapered = Tapered::new(vec![opening, endgame]);
+///
+/// let board = Board::standard();
+/// assert_eq!(opening.evaluate(&board, Stone::Black), tapered.evaluate(&board, Stone::Black));
+///
+/// // A full board, where white only owns A1
+/// let board = Board::try_from((u64::MAX >> 1, 1 << 63)).unwrap();
+/// assert_eq!(endgame.evaluate(&board, Stone::Black), tapered.evaluate(&board, Stone::Black));
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Tapered<E> {
+    phases: Vec<E>,
+}
+
+impl<E: Evaluator> Tapered<E> {
+    /// Returns an evaluator that blends the specified phases, in the order
+    /// they occur in the game.
+    ///
+    /// # Panics
+    /// Panics if there are no phases.
+    #[must_use]
+    pub fn new(phases: Vec<E>) -> Self {
+        assert!(!phases.is_empty(), "at least one phase is required");
+        Self { phases }
+    }
+
+    /// Returns the evaluators of every phase.
+    #[must_use]
+    pub fn phases(&self) -> &[E] {
+        &self.phases
+    }
+
+    /// Returns a mutable reference to the evaluators of every phase.
+    pub fn phases_mut(&mut self) -> &mut [E] {
+        &mut self.phases
+    }
+
+    /// Returns the two phases the board lies between, along with the weight
+    /// of the later one out of 60.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::eval::{DiscDifference, Tapered};
+    /// use magpie::othello::Board;
+    ///
+    /// let tapered = Tapered::new(vec![DiscDifference; 3]);
+    /// assert_eq!((0, 1, 0), tapered.blend(&Board::standard()));
+    /// ```
+    #[must_use]
+    pub fn blend(&self, board: &Board) -> (usize, usize, i32) {
+        let last = self.phases.len() - 1;
+        let position = usize::from(board.stage()) * last;
+        let earlier = position / LAST_STAGE as usize;
+        let weight = (position % LAST_STAGE as usize) as i32;
+        (earlier, (earlier + 1).min(last), weight)
+    }
+}
+
+impl<E: Evaluator> Evaluator for Tapered<E> {
+    fn evaluate(&self, board: &Board, to_move: Stone) -> i32 {
+        let (earlier, later, weight) = self.blend(board);
+        let earlier = i64::from(self.phases[earlier].evaluate(board, to_move));
+        if weight == 0 {
+            return earlier as i32;
+        }
+        let later = i64::from(self.phases[later].evaluate(board, to_move));
+        let weight = i64::from(weight);
+        ((earlier * (LAST_STAGE - weight) + later * weight) / LAST_STAGE) as i32
+    }
+}
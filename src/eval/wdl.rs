@@ -0,0 +1,216 @@
+use crate::search::Wdl;
+
+/// The spread of the default model on a full board, in discs.
+const DEFAULT_BASE: f64 = 0.25;
+/// The growth of the spread of the default model per empty square, in
+/// discs.
+const DEFAULT_PER_EMPTY: f64 = 0.2;
+/// The number of candidates per parameter in every round of fitting.
+const FIT_STEPS: usize = 24;
+/// The number of times the grid is narrowed around the best candidate.
+const FIT_ROUNDS: usize = 4;
+
+/// The probabilities of winning, drawing and losing a game.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct WdlProbabilities {
+    /// The probability of winning.
+    pub win: f64,
+    /// The probability of drawing.
+    pub draw: f64,
+    /// The probability of losing.
+    pub loss: f64,
+}
+
+impl WdlProbabilities {
+    /// Returns the expected result, where a win counts as 1 and a draw as
+    /// 0.5.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::eval::WdlProbabilities;
+    ///
+    /// let probabilities = WdlProbabilities { win: 0.5, draw: 0.2, loss: 0.3 };
+    /// assert_eq!(0.6, probabilities.expected_score());
+    /// ```
+    #[must_use]
+    pub fn expected_score(&self) -> f64 {
+        self.win + self.draw / 2.0
+    }
+
+    /// Returns the probability of the specified outcome.
+    #[must_use]
+    pub fn of(&self, outcome: Wdl) -> f64 {
+        match outcome {
+            Wdl::Win => self.win,
+            Wdl::Draw => self.draw,
+            Wdl::Loss => self.loss,
+        }
+    }
+}
+
+/// Converts scores in discs into the probabilities of winning, drawing and
+/// losing the game.
+///
+/// The final disc differential is modeled as the score plus an error that
+/// follows a logistic distribution, whose spread grows linearly with the
+/// number of empty squares: the more moves are left, the less a lead of a
+/// few discs means. A game is won if the differential is above half a disc,
+/// lost if it is below minus half a disc and drawn otherwise.
+///
+/// The default model is a conservative prior, with a spread of 0.25 discs
+/// on a full board that grows by 0.2 discs per empty square. Since the
+/// reliability of a score depends on the evaluator and search behind it,
+/// [`fit`] calibrates a model to the scores an engine gives the positions
+/// of a game collection, such as a WTHOR archive, and the results of those
+/// games.
+///
+/// [`fit`]: crate::eval::WdlModel::fit
+///
+/// # Examples
+/// ```rust
+/// use magpie::eval::WdlModel;
+///
+/// let model = WdlModel::default();
+/// // A lead of 4 discs is safer near the end of the game
+/// let opening = model.probabilities(4.0, 50);
+/// let endgame = model.probabilities(4.0, 10);
+/// assert!(opening.win < endgame.win);
+/// assert!(opening.win > opening.loss);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct WdlModel {
+    /// The spread of the error on a full board, in discs.
+    pub base: f64,
+    /// The growth of the spread per empty square, in discs.
+    pub per_empty: f64,
+}
+
+impl WdlModel {
+    /// Returns the spread of the error with the specified number of empty
+    /// squares, in discs.
+    #[must_use]
+    pub fn spread(&self, empties: u8) -> f64 {
+        self.base + self.per_empty * f64::from(empties)
+    }
+
+    /// Returns the probabilities of the outcomes of a position with the
+    /// specified number of empty squares and score in discs, from the
+    /// perspective of the player the score belongs to.
+    #[must_use]
+    pub fn probabilities(&self, score: f64, empties: u8) -> WdlProbabilities {
+        let spread = self.spread(empties).max(f64::EPSILON);
+        let win = logistic((score - 0.5) / spread);
+        let loss = logistic((-score - 0.5) / spread);
+        WdlProbabilities {
+            win,
+            draw: (1.0 - win - loss).max(0.0),
+            loss,
+        }
+    }
+
+    /// Fits a model to scores in discs, paired with the number of empty
+    /// squares of their positions and the outcome of the game from the
+    /// perspective of the player the score belongs to.
+    ///
+    /// The parameters are chosen to minimize the cross-entropy between the
+    /// predicted probabilities and the actual outcomes, by searching a grid
+    /// that is repeatedly narrowed around the best candidate. Returns the
+    /// default model if there are no samples.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::eval::WdlModel;
+    /// use magpie::search::Wdl;
+    ///
+    /// // Leads of 2 discs in the opening are only won about half the time
+    /// let samples: Vec<_> = (0..100)
+    ///     .map(|game| (2.0, 50, if game % 2 == 0 { Wdl::Win } else { Wdl::Loss }))
+    ///     .collect();
+    /// let model = WdlModel::fit(&samples);
+    /// assert!(model.spread(50) > WdlModel::default().spread(50));
+    /// ```
+    #[must_use]
+    pub fn fit(samples: &[(f64, u8, Wdl)]) -> Self {
+        if samples.is_empty() {
+            return Self::default();
+        }
+        let mut best = Self::default();
+        let mut best_loss = best.loss(samples);
+        let (mut base_range, mut per_empty_range) = ((0.0, 8.0), (0.0, 2.0));
+        for _ in 0..FIT_ROUNDS {
+            for base in grid(base_range) {
+                for per_empty in grid(per_empty_range) {
+                    let candidate = Self { base, per_empty };
+                    let loss = candidate.loss(samples);
+                    if loss < best_loss {
+                        best = candidate;
+                        best_loss = loss;
+                    }
+                }
+            }
+            base_range = narrow(base_range, best.base);
+            per_empty_range = narrow(per_empty_range, best.per_empty);
+        }
+        best
+    }
+
+    /// Returns the average cross-entropy of the predictions of the model.
+    #[allow(clippy::cast_precision_loss)]
+    fn loss(&self, samples: &[(f64, u8, Wdl)]) -> f64 {
+        let total: f64 = samples
+            .iter()
+            .map(|(score, empties, outcome)| {
+                let p = self.probabilities(*score, *empties).of(*outcome);
+                -p.clamp(1e-12, 1.0).ln()
+            })
+            .sum();
+        total / samples.len() as f64
+    }
+}
+
+impl Default for WdlModel {
+    fn default() -> Self {
+        Self {
+            base: DEFAULT_BASE,
+            per_empty: DEFAULT_PER_EMPTY,
+        }
+    }
+}
+
+/// Returns the probabilities of winning, drawing and losing a position
+/// with the specified number of empty squares and score in discs, using
+/// the default [`WdlModel`].
+///
+/// [`WdlModel`]: crate::eval::WdlModel
+///
+/// # Examples
+/// ```rust
+/// use magpie::eval::wdl_model;
+///
+/// let even = wdl_model(0.0, 30);
+/// assert!((even.win - even.loss).abs() < 1e-9);
+/// assert!(wdl_model(10.0, 30).win > 0.8);
+/// // With no empty squares left, the score is the final result
+/// assert!(wdl_model(-2.0, 0).loss > 0.99);
+/// ```
+#[must_use]
+pub fn wdl_model(score: f64, empties: u8) -> WdlProbabilities {
+    WdlModel::default().probabilities(score, empties)
+}
+
+fn logistic(x: f64) -> f64 {
+    1.0 / (1.0 + (-x).exp())
+}
+
+/// Returns evenly spaced candidates covering the range.
+#[allow(clippy::cast_precision_loss)]
+fn grid((low, high): (f64, f64)) -> impl Iterator<Item = f64> {
+    (0..=FIT_STEPS).map(move |step| low + (high - low) * step as f64 / FIT_STEPS as f64)
+}
+
+/// Returns a range around the value spanning two steps of the grid.
+#[allow(clippy::cast_precision_loss)]
+fn narrow((low, high): (f64, f64), value: f64) -> (f64, f64) {
+    let step = (high - low) / FIT_STEPS as f64;
+    ((value - 2.0 * step).max(0.0), value + 2.0 * step)
+}
@@ -0,0 +1,196 @@
+use crate::othello::{Board, Position, Stone};
+use core::ffi::{CStr, c_char};
+
+/// Represents the black stone.
+pub const MAGPIE_BLACK: u8 = 0;
+/// Represents the white stone.
+pub const MAGPIE_WHITE: u8 = 1;
+
+/// The outcome of operations that may fail.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum MagpieResult {
+    /// The operation succeeded.
+    Ok = 0,
+    /// The move was illegal.
+    IllegalMove = 1,
+    /// An argument was invalid, such as a null pointer, an unknown stone or
+    /// a position without exactly one bit set.
+    InvalidArgument = 2,
+}
+
+/// A board passed by value, as the bitboards of both players.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct MagpieBoard {
+    /// The black stones.
+    pub black: u64,
+    /// The white stones.
+    pub white: u64,
+}
+
+impl From<Board> for MagpieBoard {
+    fn from(board: Board) -> Self {
+        Self {
+            black: board.bits_for(Stone::Black).raw(),
+            white: board.bits_for(Stone::White).raw(),
+        }
+    }
+}
+
+/// Returns a board with the standard opening position configured.
+///
+/// # Examples
+/// ```rust
+/// use magpie::ffi::magpie_board_standard;
+///
+/// let board = magpie_board_standard();
+/// assert_eq!(0x00_00_00_08_10_00_00_00, board.black);
+/// ```
+#[unsafe(no_mangle)]
+pub extern "C" fn magpie_board_standard() -> MagpieBoard {
+    Board::standard().into()
+}
+
+/// Returns a completely empty board.
+///
+/// # Examples
+/// ```rust
+/// use magpie::ffi::magpie_board_empty;
+///
+/// let board = magpie_board_empty();
+/// assert_eq!(0, board.black | board.white);
+/// ```
+#[unsafe(no_mangle)]
+pub extern "C" fn magpie_board_empty() -> MagpieBoard {
+    Board::empty().into()
+}
+
+/// Returns the legal moves of the specified player as a bitboard.
+///
+/// Returns zero if the board or the stone is invalid.
+///
+/// # Examples
+/// ```rust
+/// use magpie::ffi::{MAGPIE_BLACK, magpie_board_moves, magpie_board_standard};
+///
+/// let moves = magpie_board_moves(magpie_board_standard(), MAGPIE_BLACK);
+/// assert_eq!(4, moves.count_ones());
+/// ```
+#[unsafe(no_mangle)]
+pub extern "C" fn magpie_board_moves(board: MagpieBoard, stone: u8) -> u64 {
+    match (to_board(board), to_stone(stone)) {
+        (Some(board), Some(stone)) => board.moves_for(stone).raw(),
+        _ => 0,
+    }
+}
+
+/// Returns true if and only if the move is legal for the specified player.
+///
+/// Returns false if any of the arguments are invalid.
+///
+/// # Examples
+/// ```rust
+/// use magpie::ffi::{MAGPIE_BLACK, magpie_board_is_legal_move, magpie_board_standard};
+///
+/// let d3 = 1 << 44;
+/// assert!(magpie_board_is_legal_move(magpie_board_standard(), MAGPIE_BLACK, d3));
+/// ```
+#[unsafe(no_mangle)]
+pub extern "C" fn magpie_board_is_legal_move(board: MagpieBoard, stone: u8, position: u64) -> bool {
+    match (to_board(board), to_stone(stone), to_position(position)) {
+        (Some(board), Some(stone), Some(pos)) => board.is_legal_move(stone, pos),
+        _ => false,
+    }
+}
+
+/// Plays the move for the specified player, updating the board in place.
+///
+/// The board is left untouched if the move is illegal or if any of the
+/// arguments are invalid.
+///
+/// # Safety
+///
+/// The board pointer must either be null or valid for reads and writes.
+///
+/// # Examples
+/// ```rust
+/// use magpie::ffi::{MAGPIE_BLACK, MagpieResult, magpie_board_play, magpie_board_standard};
+///
+/// let mut board = magpie_board_standard();
+/// let d3 = 1 << 44;
+/// let result = unsafe { magpie_board_play(&mut board, MAGPIE_BLACK, d3) };
+/// assert_eq!(MagpieResult::Ok, result);
+/// assert_eq!(4, board.black.count_ones());
+/// ```
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn magpie_board_play(
+    board: *mut MagpieBoard,
+    stone: u8,
+    position: u64,
+) -> MagpieResult {
+    // SAFETY: The caller guarantees that the pointer is null or valid
+    let Some(board) = (unsafe { board.as_mut() }) else {
+        return MagpieResult::InvalidArgument;
+    };
+    let (Some(mut inner), Some(stone), Some(pos)) =
+        (to_board(*board), to_stone(stone), to_position(position))
+    else {
+        return MagpieResult::InvalidArgument;
+    };
+    if !inner.is_legal_move(stone, pos) {
+        return MagpieResult::IllegalMove;
+    }
+    inner.play(stone, pos);
+    *board = inner.into();
+    MagpieResult::Ok
+}
+
+/// Parses a position from a null-terminated string in human-readable
+/// notation, such as `d3`.
+///
+/// Returns the position as a bitboard with a single bit set, or zero if the
+/// notation is invalid.
+///
+/// # Safety
+///
+/// The string must either be null or point to a valid null-terminated
+/// string.
+///
+/// # Examples
+/// ```rust
+/// use magpie::ffi::magpie_position_from_notation;
+///
+/// let a1 = unsafe { magpie_position_from_notation(c"A1".as_ptr()) };
+/// assert_eq!(1 << 63, a1);
+/// assert_eq!(0, unsafe { magpie_position_from_notation(c"z9".as_ptr()) });
+/// ```
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn magpie_position_from_notation(notation: *const c_char) -> u64 {
+    if notation.is_null() {
+        return 0;
+    }
+    // SAFETY: The caller guarantees that the string is valid
+    let notation = unsafe { CStr::from_ptr(notation) };
+    notation
+        .to_str()
+        .ok()
+        .and_then(|text| text.parse::<Position>().ok())
+        .map_or(0, Position::raw)
+}
+
+pub(crate) fn to_board(board: MagpieBoard) -> Option<Board> {
+    Board::try_from((board.black, board.white)).ok()
+}
+
+pub(crate) fn to_stone(stone: u8) -> Option<Stone> {
+    match stone {
+        MAGPIE_BLACK => Some(Stone::Black),
+        MAGPIE_WHITE => Some(Stone::White),
+        _ => None,
+    }
+}
+
+pub(crate) fn to_position(position: u64) -> Option<Position> {
+    Position::try_from(position).ok()
+}
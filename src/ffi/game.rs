@@ -0,0 +1,237 @@
+use crate::{
+    ffi::board::{MAGPIE_BLACK, MAGPIE_WHITE, MagpieBoard, MagpieResult, to_position},
+    othello::{Game, Status, Stone},
+};
+use alloc::boxed::Box;
+
+/// An opaque handle to a game.
+pub struct MagpieGame(Game);
+
+/// The status of a game.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum MagpieStatus {
+    /// The game is still in progress.
+    Progressing = 0,
+    /// The game has concluded with black as the winner.
+    BlackWins = 1,
+    /// The game has concluded with white as the winner.
+    WhiteWins = 2,
+    /// The game has concluded in a draw.
+    Draw = 3,
+}
+
+/// Returns a new game with the standard opening position configured.
+///
+/// The game must be released with [`magpie_game_free`].
+///
+/// [`magpie_game_free`]: crate::ffi::magpie_game_free
+///
+/// # Examples
+/// ```rust
+/// use magpie::ffi::{magpie_game_free, magpie_game_new};
+///
+/// let game = magpie_game_new();
+/// unsafe { magpie_game_free(game) };
+/// ```
+#[unsafe(no_mangle)]
+pub extern "C" fn magpie_game_new() -> *mut MagpieGame {
+    Box::into_raw(Box::new(MagpieGame(Game::new())))
+}
+
+/// Releases a game created by [`magpie_game_new`].
+///
+/// [`magpie_game_new`]: crate::ffi::magpie_game_new
+///
+/// # Safety
+///
+/// The game must either be null or have been created by
+/// [`magpie_game_new`], and it must not be used afterwards.
+///
+/// # Examples
+/// ```rust
+/// use magpie::ffi::magpie_game_free;
+///
+/// // Releasing null does nothing
+/// unsafe { magpie_game_free(std::ptr::null_mut()) };
+/// ```
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn magpie_game_free(game: *mut MagpieGame) {
+    if !game.is_null() {
+        // SAFETY: The caller guarantees that the game was created by Box
+        drop(unsafe { Box::from_raw(game) });
+    }
+}
+
+/// Returns the current board of the game.
+///
+/// Returns an empty board if the game is null.
+///
+/// # Safety
+///
+/// The game must either be null or valid for reads.
+///
+/// # Examples
+/// ```rust
+/// use magpie::ffi::{magpie_board_standard, magpie_game_board, magpie_game_free, magpie_game_new};
+///
+/// let game = magpie_game_new();
+/// assert_eq!(magpie_board_standard(), unsafe { magpie_game_board(game) });
+/// unsafe { magpie_game_free(game) };
+/// ```
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn magpie_game_board(game: *const MagpieGame) -> MagpieBoard {
+    // SAFETY: The caller guarantees that the pointer is null or valid
+    match unsafe { game.as_ref() } {
+        Some(game) => game.0.board().into(),
+        None => MagpieBoard { black: 0, white: 0 },
+    }
+}
+
+/// Returns the stone of the player to move.
+///
+/// Returns [`MAGPIE_BLACK`] if the game is null.
+///
+/// [`MAGPIE_BLACK`]: crate::ffi::MAGPIE_BLACK
+///
+/// # Safety
+///
+/// The game must either be null or valid for reads.
+///
+/// # Examples
+/// ```rust
+/// use magpie::ffi::{MAGPIE_BLACK, magpie_game_current_turn, magpie_game_free, magpie_game_new};
+///
+/// let game = magpie_game_new();
+/// assert_eq!(MAGPIE_BLACK, unsafe { magpie_game_current_turn(game) });
+/// unsafe { magpie_game_free(game) };
+/// ```
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn magpie_game_current_turn(game: *const MagpieGame) -> u8 {
+    // SAFETY: The caller guarantees that the pointer is null or valid
+    match unsafe { game.as_ref() }.map(|game| game.0.current_turn()) {
+        Some(Stone::White) => MAGPIE_WHITE,
+        _ => MAGPIE_BLACK,
+    }
+}
+
+/// Returns the legal moves of the player to move as a bitboard.
+///
+/// Returns zero if the game is null.
+///
+/// # Safety
+///
+/// The game must either be null or valid for reads.
+///
+/// # Examples
+/// ```rust
+/// use magpie::ffi::{magpie_game_free, magpie_game_moves, magpie_game_new};
+///
+/// let game = magpie_game_new();
+/// assert_eq!(4, unsafe { magpie_game_moves(game) }.count_ones());
+/// unsafe { magpie_game_free(game) };
+/// ```
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn magpie_game_moves(game: *const MagpieGame) -> u64 {
+    // SAFETY: The caller guarantees that the pointer is null or valid
+    unsafe { game.as_ref() }.map_or(0, |game| game.0.moves().raw())
+}
+
+/// Plays the move for the player to move.
+///
+/// # Safety
+///
+/// The game must either be null or valid for reads and writes.
+///
+/// # Examples
+/// ```rust
+/// use magpie::ffi::{MagpieResult, magpie_game_free, magpie_game_new, magpie_game_play};
+///
+/// let game = magpie_game_new();
+/// let d3 = 1 << 44;
+/// assert_eq!(MagpieResult::Ok, unsafe { magpie_game_play(game, d3) });
+/// assert_eq!(MagpieResult::IllegalMove, unsafe { magpie_game_play(game, d3) });
+/// unsafe { magpie_game_free(game) };
+/// ```
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn magpie_game_play(game: *mut MagpieGame, position: u64) -> MagpieResult {
+    // SAFETY: The caller guarantees that the pointer is null or valid
+    let (Some(game), Some(pos)) = (unsafe { game.as_mut() }, to_position(position)) else {
+        return MagpieResult::InvalidArgument;
+    };
+    match game.0.play(pos) {
+        Ok(()) => MagpieResult::Ok,
+        Err(_) => MagpieResult::IllegalMove,
+    }
+}
+
+/// Passes the turn if the player to move has no legal moves.
+///
+/// Returns whether or not the turn was passed.
+///
+/// # Safety
+///
+/// The game must either be null or valid for reads and writes.
+///
+/// # Examples
+/// ```rust
+/// use magpie::ffi::{magpie_game_free, magpie_game_new, magpie_game_pass_if_forced};
+///
+/// let game = magpie_game_new();
+/// assert!(!unsafe { magpie_game_pass_if_forced(game) });
+/// unsafe { magpie_game_free(game) };
+/// ```
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn magpie_game_pass_if_forced(game: *mut MagpieGame) -> bool {
+    // SAFETY: The caller guarantees that the pointer is null or valid
+    unsafe { game.as_mut() }.is_some_and(|game| game.0.pass_if_forced())
+}
+
+/// Takes back the last move. Returns false if there was nothing to undo.
+///
+/// # Safety
+///
+/// The game must either be null or valid for reads and writes.
+///
+/// # Examples
+/// ```rust
+/// use magpie::ffi::{magpie_game_free, magpie_game_new, magpie_game_undo};
+///
+/// let game = magpie_game_new();
+/// assert!(!unsafe { magpie_game_undo(game) });
+/// unsafe { magpie_game_free(game) };
+/// ```
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn magpie_game_undo(game: *mut MagpieGame) -> bool {
+    // SAFETY: The caller guarantees that the pointer is null or valid
+    unsafe { game.as_mut() }.is_some_and(|game| game.0.undo().is_some())
+}
+
+/// Returns the status of the game.
+///
+/// Returns [`MagpieStatus::Progressing`] if the game is null.
+///
+/// [`MagpieStatus::Progressing`]: crate::ffi::MagpieStatus::Progressing
+///
+/// # Safety
+///
+/// The game must either be null or valid for reads.
+///
+/// # Examples
+/// ```rust
+/// use magpie::ffi::{MagpieStatus, magpie_game_free, magpie_game_new, magpie_game_status};
+///
+/// let game = magpie_game_new();
+/// assert_eq!(MagpieStatus::Progressing, unsafe { magpie_game_status(game) });
+/// unsafe { magpie_game_free(game) };
+/// ```
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn magpie_game_status(game: *const MagpieGame) -> MagpieStatus {
+    // SAFETY: The caller guarantees that the pointer is null or valid
+    match unsafe { game.as_ref() }.map(|game| game.0.status()) {
+        Some(Status::Win(Stone::Black)) => MagpieStatus::BlackWins,
+        Some(Status::Win(Stone::White)) => MagpieStatus::WhiteWins,
+        Some(Status::Draw) => MagpieStatus::Draw,
+        _ => MagpieStatus::Progressing,
+    }
+}
@@ -0,0 +1,40 @@
+//! A C-compatible interface to the core operations.
+//!
+//! The interface is meant for embedding magpie as the rules engine of
+//! programs written in other languages. A matching header is provided in
+//! `include/magpie.h`, which is generated from this module by [cbindgen]
+//! according to `cbindgen.toml`. The tests of the feature fail if the header
+//! is out of date, and rewrite it when run with `MAGPIE_UPDATE_HEADER=1`.
+//! Since the feature does not change the crate type, the library is built
+//! as a C library with `cargo rustc`:
+//!
+//! ```text
+//! cargo rustc --release --lib --features ffi --crate-type cdylib
+//! ```
+//!
+//! Boards are passed by value as a pair of bitboards, while games are
+//! heap-allocated and handed out as opaque pointers that must be released
+//! with [`magpie_game_free`]. Stones are represented by [`MAGPIE_BLACK`]
+//! and [`MAGPIE_WHITE`], and positions are bitboards with exactly one bit
+//! set, where the most significant bit represents A1.
+//!
+//! [`magpie_game_free`]: crate::ffi::magpie_game_free
+//! [`MAGPIE_BLACK`]: crate::ffi::MAGPIE_BLACK
+//! [`MAGPIE_WHITE`]: crate::ffi::MAGPIE_WHITE
+//! [cbindgen]: https://github.com/mozilla/cbindgen
+
+/// Functions operating on boards.
+mod board;
+/// Functions operating on games.
+mod game;
+
+pub use board::{
+    MAGPIE_BLACK, MAGPIE_WHITE, MagpieBoard, MagpieResult, magpie_board_empty,
+    magpie_board_is_legal_move, magpie_board_moves, magpie_board_play, magpie_board_standard,
+    magpie_position_from_notation,
+};
+pub use game::{
+    MagpieGame, MagpieStatus, magpie_game_board, magpie_game_current_turn, magpie_game_free,
+    magpie_game_moves, magpie_game_new, magpie_game_pass_if_forced, magpie_game_play,
+    magpie_game_status, magpie_game_undo,
+};
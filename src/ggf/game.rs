@@ -0,0 +1,383 @@
+use crate::{
+    ggf::parser::GgfError,
+    othello::{Board, Game, Move, Stone, Transcript},
+};
+use alloc::{
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use core::fmt::Write;
+
+/// Represents a single move in a GGF game, optionally annotated with an
+/// evaluation and the time spent on the move.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GgfMove {
+    /// The player who made the move.
+    pub stone: Stone,
+    /// The move itself.
+    pub mv: Move,
+    /// The evaluation of the move, if recorded.
+    pub eval: Option<f32>,
+    /// The time spent on the move in seconds, if recorded.
+    pub time: Option<f32>,
+}
+
+/// Represents a single game stored in the Generic Game Format.
+///
+/// Apart from the starting position and the moves, a GGF game carries a
+/// number of tags such as the names of the players (`PB` and `PW`), the
+/// time control (`TI`), the board type (`TY`) and the result (`RE`). Tags
+/// are kept in the order they were encountered, and unknown tags are
+/// preserved.
+#[derive(Clone, Debug, PartialEq)]
+pub struct GgfGame {
+    pub(crate) tags: Vec<(String, String)>,
+    pub(crate) board: Board,
+    pub(crate) to_move: Stone,
+    pub(crate) moves: Vec<GgfMove>,
+}
+
+impl GgfGame {
+    /// Returns a game starting from the standard opening position without
+    /// any moves.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::ggf::GgfGame;
+    ///
+    /// let game = GgfGame::new();
+    /// assert_eq!(Some(8), game.board_size());
+    /// assert!(game.moves().is_empty());
+    /// ```
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            tags: vec![
+                ("GM".to_string(), "Othello".to_string()),
+                ("TY".to_string(), "8".to_string()),
+            ],
+            board: Board::standard(),
+            to_move: Stone::Black,
+            moves: Vec::new(),
+        }
+    }
+
+    /// Returns a game starting from the standard opening position with the
+    /// moves of the specified transcript.
+    ///
+    /// Returns an error if the transcript contains illegal moves. Games that
+    /// start from any other position should be exported with
+    /// [`Game::to_ggf`] instead.
+    ///
+    /// [`Game::to_ggf`]: crate::othello::Game::to_ggf
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::{ggf::GgfGame, othello::Transcript};
+    ///
+    /// let transcript: Transcript = "f5d6c3".parse().unwrap();
+    /// let game = GgfGame::from_transcript(&transcript).unwrap();
+    /// assert!(game.to_string().contains("B[F5]W[D6]B[C3]"));
+    /// ```
+    pub fn from_transcript(transcript: &Transcript) -> Result<Self, GgfError> {
+        let mut game = Self::new();
+        let mut replay = Game::new();
+        for (index, mv) in transcript.moves().iter().enumerate() {
+            if let Move::Play(pos) = mv
+                && !replay.is_legal_move(*pos)
+                && replay.must_pass()
+            {
+                // Passes are always recorded explicitly in GGF
+                game.push(GgfMove::new(replay.current_turn(), Move::Pass));
+                replay.pass_turn();
+            }
+            let stone = replay.current_turn();
+            play(&mut replay, *mv).ok_or(GgfError::IllegalMove(index))?;
+            game.push(GgfMove::new(stone, *mv));
+        }
+        Ok(game)
+    }
+
+    /// Returns the value of the specified tag, if present.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::ggf::GgfGame;
+    ///
+    /// let game = GgfGame::new();
+    /// assert_eq!(Some("Othello"), game.tag("GM"));
+    /// ```
+    #[must_use]
+    pub fn tag(&self, name: &str) -> Option<&str> {
+        self.tags
+            .iter()
+            .find(|(tag, _)| tag == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Sets the value of the specified tag, replacing any previous value.
+    ///
+    /// The tags `BO`, `B` and `W` are reserved for the starting position and
+    /// the moves and cannot be set this way.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::ggf::GgfGame;
+    ///
+    /// let mut game = GgfGame::new();
+    /// game.set_tag("PB", "Alice");
+    /// assert_eq!(Some("Alice"), game.black_player());
+    /// ```
+    pub fn set_tag(&mut self, name: &str, value: &str) {
+        if matches!(name, "BO" | "B" | "W") {
+            return;
+        }
+        match self.tags.iter_mut().find(|(tag, _)| tag == name) {
+            Some((_, old)) => *old = value.to_string(),
+            None => self.tags.push((name.to_string(), value.to_string())),
+        }
+    }
+
+    /// Returns all tags in the order they appear, excluding the starting
+    /// position and the moves.
+    #[must_use]
+    pub fn tags(&self) -> &[(String, String)] {
+        &self.tags
+    }
+
+    /// Returns the name of the player with the black stones (`PB`).
+    #[must_use]
+    pub fn black_player(&self) -> Option<&str> {
+        self.tag("PB")
+    }
+
+    /// Returns the name of the player with the white stones (`PW`).
+    #[must_use]
+    pub fn white_player(&self) -> Option<&str> {
+        self.tag("PW")
+    }
+
+    /// Returns the place the game was played at (`PC`).
+    #[must_use]
+    pub fn place(&self) -> Option<&str> {
+        self.tag("PC")
+    }
+
+    /// Returns the date the game was played (`DT`).
+    #[must_use]
+    pub fn date(&self) -> Option<&str> {
+        self.tag("DT")
+    }
+
+    /// Returns the time control of the game (`TI`), such as `05:00//02:00`.
+    #[must_use]
+    pub fn time_control(&self) -> Option<&str> {
+        self.tag("TI")
+    }
+
+    /// Returns the size of the board, as specified by the game type (`TY`).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::ggf::GgfGame;
+    ///
+    /// let mut game = GgfGame::new();
+    /// game.set_tag("TY", "8r");
+    /// assert_eq!(Some(8), game.board_size());
+    /// ```
+    #[must_use]
+    pub fn board_size(&self) -> Option<u8> {
+        let value = self.tag("TY")?;
+        let digits: String = value.chars().take_while(char::is_ascii_digit).collect();
+        digits.parse().ok()
+    }
+
+    /// Returns the result of the game (`RE`) as the disc differential from
+    /// black's point of view.
+    ///
+    /// Returns `None` if the result is missing or unknown (`?`).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::ggf::GgfGame;
+    ///
+    /// let mut game = GgfGame::new();
+    /// game.set_tag("RE", "-4.000:r");
+    /// assert_eq!(Some(-4.0), game.result());
+    /// ```
+    #[must_use]
+    pub fn result(&self) -> Option<f32> {
+        let value = self.tag("RE")?;
+        value.split(':').next()?.parse().ok()
+    }
+
+    /// Returns the starting position of the game and the player to move.
+    #[must_use]
+    pub fn start_position(&self) -> (Board, Stone) {
+        (self.board, self.to_move)
+    }
+
+    /// Returns the moves of the game, including any recorded passes.
+    #[must_use]
+    pub fn moves(&self) -> &[GgfMove] {
+        &self.moves
+    }
+
+    /// Appends a move to the game.
+    pub fn push(&mut self, mv: GgfMove) {
+        self.moves.push(mv);
+    }
+
+    /// Returns the moves of the game as a transcript.
+    #[must_use]
+    pub fn transcript(&self) -> Transcript {
+        self.moves.iter().map(|mv| mv.mv).collect()
+    }
+
+    /// Replays the game from its starting position.
+    ///
+    /// Returns an error if any of the moves are illegal or made by the wrong
+    /// player.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::{ggf::GgfGame, othello::Transcript};
+    ///
+    /// let transcript: Transcript = "f5d6".parse().unwrap();
+    /// let game = GgfGame::from_transcript(&transcript).unwrap();
+    /// assert_eq!(transcript.replay(), Ok(game.replay().unwrap()));
+    /// ```
+    pub fn replay(&self) -> Result<Game, GgfError> {
+        let mut game = Game::from_state(self.board, self.to_move, false)
+            .map_err(|_| GgfError::InvalidBoard)?;
+        for (index, mv) in self.moves.iter().enumerate() {
+            if mv.stone != game.current_turn() {
+                return Err(GgfError::IllegalMove(index));
+            }
+            play(&mut game, mv.mv).ok_or(GgfError::IllegalMove(index))?;
+        }
+        Ok(game)
+    }
+}
+
+impl GgfMove {
+    /// Returns a move without any evaluation or time annotations.
+    #[must_use]
+    pub fn new(stone: Stone, mv: Move) -> Self {
+        Self {
+            stone,
+            mv,
+            eval: None,
+            time: None,
+        }
+    }
+}
+
+impl Game {
+    /// Returns the game in the Generic Game Format, starting from the
+    /// position the game started from and followed by every move in its
+    /// history, including passes.
+    ///
+    /// Moves that have been undone are left out. Since GGF has no notion of
+    /// the placements that open a game of Reversi, those are made part of
+    /// the starting position.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::ggf;
+    /// use magpie::othello::{Game, Stone};
+    ///
+    /// let mut game = Game::with_handicap(Stone::White, 1).unwrap();
+    /// game.play("f5".parse().unwrap()).unwrap();
+    /// let parsed = ggf::parse_game(&game.to_ggf().to_string()).unwrap();
+    /// assert_eq!(game, parsed.replay().unwrap());
+    /// ```
+    #[must_use]
+    pub fn to_ggf(&self) -> GgfGame {
+        GgfGame::from(self)
+    }
+}
+
+impl From<&Game> for GgfGame {
+    fn from(game: &Game) -> Self {
+        let mut start = game.clone();
+        while start.undo().is_some() {}
+        let mut placements = 0;
+        while start.is_placing() && start.redo().is_some() {
+            placements += 1;
+        }
+
+        let mut ggf = Self::new();
+        ggf.board = start.board();
+        ggf.to_move = start.current_turn();
+        for played in &game.history()[placements..] {
+            ggf.push(GgfMove::new(played.stone(), played.mv()));
+        }
+        ggf
+    }
+}
+
+impl Default for GgfGame {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl core::fmt::Display for GgfGame {
+    /// Formats the game in the Generic Game Format.
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("(;")?;
+        for (name, value) in &self.tags {
+            write!(f, "{name}[{value}]")?;
+        }
+        let mut board = String::with_capacity(64);
+        for pos in crate::othello::Bitboard::from(u64::MAX).hot_bits() {
+            board.push(match self.board.stone_at(pos) {
+                Some(Stone::Black) => '*',
+                Some(Stone::White) => 'O',
+                None => '-',
+            });
+        }
+        write!(f, "BO[8 {board} {}]", stone_symbol(self.to_move))?;
+        for mv in &self.moves {
+            let mut text = match mv.mv {
+                Move::Play(pos) => pos.to_string().to_uppercase(),
+                Move::Pass => "PA".to_string(),
+            };
+            match (mv.eval, mv.time) {
+                (None, None) => {}
+                (eval, time) => {
+                    text.push('/');
+                    if let Some(eval) = eval {
+                        write!(text, "{eval}")?;
+                    }
+                    if let Some(time) = time {
+                        write!(text, "/{time}")?;
+                    }
+                }
+            }
+            let player = match mv.stone {
+                Stone::Black => "B",
+                Stone::White => "W",
+            };
+            write!(f, "{player}[{text}]")?;
+        }
+        f.write_str(";)")
+    }
+}
+
+// Plays the move, only allowing passes when they are forced.
+fn play(game: &mut Game, mv: Move) -> Option<()> {
+    match mv {
+        Move::Play(pos) => game.play(pos).ok(),
+        Move::Pass => game.pass_if_forced().then_some(()),
+    }
+}
+
+pub(crate) fn stone_symbol(stone: Stone) -> char {
+    match stone {
+        Stone::Black => '*',
+        Stone::White => 'O',
+    }
+}
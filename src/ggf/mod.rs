@@ -0,0 +1,35 @@
+//! Import and export of games in the Generic Game Format (GGF).
+//!
+//! GGF is the format used by the Generic Game Server (GGS) and by NBoard to
+//! store Othello games. A game is written as a sequence of tags enclosed in
+//! `(;` and `;)`, where each tag consists of a name and a value in brackets:
+//!
+//! ```text
+//! (;GM[Othello]PB[Alice]PW[Bob]TI[05:00]TY[8]RE[+2.000]BO[8 ---------------------------O*------*O--------------------------- *]B[F5]W[D6//2.5];)
+//! ```
+//!
+//! # Examples
+//! ```rust
+//! use magpie::ggf;
+//!
+//! let text = "(;GM[Othello]PB[Alice]PW[Bob]TY[8]RE[?]\
+//!             BO[8 ---------------------------O*------*O--------------------------- *]\
+//!             B[F5]W[D6];)";
+//! let game = ggf::parse_game(text).unwrap();
+//! assert_eq!(Some("Alice"), game.black_player());
+//! assert_eq!("f5d6", game.transcript().to_string());
+//! assert_eq!(game, ggf::parse_game(&game.to_string()).unwrap());
+//! ```
+
+/// Represents a single game in the Generic Game Format.
+mod game;
+/// Parses games in the Generic Game Format.
+mod parser;
+/// Reads games lazily from any source.
+#[cfg(feature = "std")]
+mod reader;
+
+pub use game::{GgfGame, GgfMove};
+pub use parser::{GgfError, parse_game, parse_games};
+#[cfg(feature = "std")]
+pub use reader::GgfReader;
@@ -0,0 +1,170 @@
+use crate::{
+    ggf::game::{GgfGame, GgfMove},
+    othello::{Bitboard, Board, Move, Position, Stone},
+};
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+
+/// Parses a single game in the Generic Game Format.
+///
+/// Returns an error if the text is not a well-formed GGF game. The moves are
+/// not validated, use [`GgfGame::replay`] for that.
+///
+/// [`GgfGame::replay`]: crate::ggf::GgfGame::replay
+///
+/// # Examples
+/// ```rust
+/// use magpie::{ggf, othello::Stone};
+///
+/// let game = ggf::parse_game("(;GM[Othello]TY[8]BO[8 ---------------------------O*------*O--------------------------- *]B[f5//1.5];)").unwrap();
+/// assert_eq!(Stone::Black, game.moves()[0].stone);
+/// assert_eq!(Some(1.5), game.moves()[0].time);
+/// ```
+pub fn parse_game(text: &str) -> Result<GgfGame, GgfError> {
+    let body = text
+        .trim()
+        .strip_prefix("(;")
+        .and_then(|text| text.strip_suffix(";)"))
+        .ok_or(GgfError::Syntax)?;
+
+    let mut game = GgfGame::new();
+    game.tags.clear();
+
+    let mut rest = body;
+    loop {
+        rest = rest.trim_start();
+        if rest.is_empty() {
+            break;
+        }
+        let open = rest.find('[').ok_or(GgfError::Syntax)?;
+        let close = open + rest[open..].find(']').ok_or(GgfError::Syntax)?;
+        let name = rest[..open].trim();
+        let value = &rest[open + 1..close];
+        rest = &rest[close + 1..];
+
+        match name {
+            "" => return Err(GgfError::Syntax),
+            "BO" => (game.board, game.to_move) = parse_board(value)?,
+            "B" => game
+                .moves
+                .push(parse_move(Stone::Black, value, game.moves.len())?),
+            "W" => game
+                .moves
+                .push(parse_move(Stone::White, value, game.moves.len())?),
+            _ => game.tags.push((name.to_string(), value.to_string())),
+        }
+    }
+    Ok(game)
+}
+
+/// Parses all games in the Generic Game Format found in the text.
+///
+/// GGF files typically contain one game per line, but any text between
+/// games is ignored.
+///
+/// # Examples
+/// ```rust
+/// use magpie::ggf::{self, GgfGame};
+///
+/// let text = format!("{}\n{}\n", GgfGame::new(), GgfGame::new());
+/// assert_eq!(2, ggf::parse_games(&text).unwrap().len());
+/// ```
+pub fn parse_games(text: &str) -> Result<Vec<GgfGame>, GgfError> {
+    let mut games = Vec::new();
+    let mut rest = text;
+    while let Some(start) = rest.find("(;") {
+        let end = start + rest[start..].find(";)").ok_or(GgfError::Syntax)? + 2;
+        games.push(parse_game(&rest[start..end])?);
+        rest = &rest[end..];
+    }
+    Ok(games)
+}
+
+fn parse_board(value: &str) -> Result<(Board, Stone), GgfError> {
+    let mut tokens = value.split_whitespace();
+    let size = tokens.next().ok_or(GgfError::InvalidBoard)?;
+    if size != "8" {
+        let size = size.parse().map_err(|_| GgfError::InvalidBoard)?;
+        return Err(GgfError::UnsupportedBoardSize(size));
+    }
+    let mut tokens: Vec<&str> = tokens.collect();
+    let to_move = match tokens.pop() {
+        Some("*") => Stone::Black,
+        Some("O") => Stone::White,
+        _ => return Err(GgfError::InvalidBoard),
+    };
+    let squares: String = tokens.concat();
+    if squares.chars().count() != 64 {
+        return Err(GgfError::InvalidBoard);
+    }
+
+    let mut board = Board::empty();
+    for (pos, square) in Bitboard::from(u64::MAX).hot_bits().zip(squares.chars()) {
+        match square {
+            '*' => board.place_stone_unchecked(Stone::Black, pos.into()),
+            'O' => board.place_stone_unchecked(Stone::White, pos.into()),
+            '-' => {}
+            _ => return Err(GgfError::InvalidBoard),
+        }
+    }
+    Ok((board, to_move))
+}
+
+fn parse_move(stone: Stone, value: &str, index: usize) -> Result<GgfMove, GgfError> {
+    let mut parts = value.split('/');
+    let mv = match parts.next().map(str::to_lowercase).as_deref() {
+        Some("pa" | "pass") => Move::Pass,
+        Some(notation) => Position::try_from(notation)
+            .map(Move::Play)
+            .map_err(|_| GgfError::InvalidMove(index))?,
+        None => return Err(GgfError::InvalidMove(index)),
+    };
+    let eval = parts.next().and_then(|eval| eval.trim().parse().ok());
+    let time = parts.next().and_then(|time| time.trim().parse().ok());
+    Ok(GgfMove {
+        stone,
+        mv,
+        eval,
+        time,
+    })
+}
+
+/// This enum represents errors that may occur when handling GGF games.
+#[derive(Debug, Eq, PartialEq, Hash)]
+pub enum GgfError {
+    /// Indicates that the text is not a well-formed GGF game.
+    Syntax,
+    /// Indicates that the starting position could not be parsed.
+    InvalidBoard,
+    /// Indicates that the game was played on a board that is not 8x8.
+    UnsupportedBoardSize(u8),
+    /// Indicates that a move could not be parsed. Carries the index of the
+    /// offending move.
+    InvalidMove(usize),
+    /// Indicates that a move was illegal when replayed. Carries the index of
+    /// the offending move.
+    IllegalMove(usize),
+    /// Indicates that the underlying source could not be read.
+    #[cfg(feature = "std")]
+    Io(std::io::ErrorKind),
+}
+
+impl core::fmt::Display for GgfError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            GgfError::Syntax => f.write_str("malformed GGF game"),
+            GgfError::InvalidBoard => f.write_str("invalid starting position"),
+            GgfError::UnsupportedBoardSize(size) => {
+                write!(f, "unsupported board size {size}x{size}")
+            }
+            GgfError::InvalidMove(index) => write!(f, "move {} could not be parsed", index + 1),
+            GgfError::IllegalMove(index) => write!(f, "move {} is illegal", index + 1),
+            #[cfg(feature = "std")]
+            GgfError::Io(kind) => write!(f, "failed to read GGF games: {kind}"),
+        }
+    }
+}
+
+impl core::error::Error for GgfError {}
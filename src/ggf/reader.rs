@@ -0,0 +1,93 @@
+use crate::ggf::{GgfError, GgfGame, parse_game};
+use std::io::{BufRead, ErrorKind};
+
+/// Reads games one at a time from text in the Generic Game Format.
+///
+/// Unlike [`parse_games`], the reader only buffers the game currently being
+/// read, which makes it suitable for iterating over very large files. Any
+/// text between games is skipped, just as with [`parse_games`]. The reader
+/// stops after the first error.
+///
+/// [`parse_games`]: crate::ggf::parse_games
+///
+/// # Examples
+/// ```rust
+/// use magpie::ggf::{GgfGame, GgfReader};
+///
+/// let text = format!("{}\n{}\n", GgfGame::new(), GgfGame::new());
+/// let games: Vec<GgfGame> = GgfReader::new(text.as_bytes())
+///     .collect::<Result<_, _>>()
+///     .unwrap();
+/// assert_eq!(2, games.len());
+/// ```
+#[derive(Debug)]
+pub struct GgfReader<R> {
+    reader: R,
+    buffer: Vec<u8>,
+    done: bool,
+}
+
+impl<R: BufRead> GgfReader<R> {
+    /// Returns a reader that reads games from the source.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::ggf::GgfReader;
+    ///
+    /// let mut reader = GgfReader::new("no games here".as_bytes());
+    /// assert!(reader.next().is_none());
+    /// ```
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader,
+            buffer: Vec::new(),
+            done: false,
+        }
+    }
+
+    fn read_game(&mut self) -> Option<Result<GgfGame, GgfError>> {
+        loop {
+            let start = self.buffer.windows(2).position(|w| w == b"(;");
+            if let Some(start) = start {
+                // Only the two characters after the start may not be part of
+                // the end marker
+                let end = self.buffer[start + 2..]
+                    .windows(2)
+                    .position(|w| w == b";)")
+                    .map(|end| start + 2 + end + 2);
+                if let Some(end) = end {
+                    let game = match core::str::from_utf8(&self.buffer[start..end]) {
+                        Ok(text) => parse_game(text),
+                        Err(_) => Err(GgfError::Syntax),
+                    };
+                    self.buffer.drain(..end);
+                    return Some(game);
+                }
+            } else {
+                // Text before a game is skipped, keeping a trailing '(' that
+                // may start the next game
+                let keep = usize::from(self.buffer.last() == Some(&b'('));
+                self.buffer.drain(..self.buffer.len() - keep);
+            }
+            match self.reader.read_until(b')', &mut self.buffer) {
+                Ok(0) => return start.map(|_| Err(GgfError::Syntax)),
+                Ok(_) => {}
+                Err(error) if error.kind() == ErrorKind::Interrupted => {}
+                Err(error) => return Some(Err(GgfError::Io(error.kind()))),
+            }
+        }
+    }
+}
+
+impl<R: BufRead> Iterator for GgfReader<R> {
+    type Item = Result<GgfGame, GgfError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let game = self.read_game();
+        self.done = !matches!(game, Some(Ok(_)));
+        game
+    }
+}
@@ -0,0 +1,166 @@
+use crate::ggs::{Command, Event, Parser};
+use std::{
+    io::{self, Read, Write},
+    net::{TcpStream, ToSocketAddrs},
+    ops::ControlFlow,
+};
+
+/// A blocking client that logs in to a server and exchanges commands and
+/// events with it.
+///
+/// Login prompts are answered automatically using the credentials given on
+/// creation, and are never returned as events.
+pub struct Client<S> {
+    stream: S,
+    parser: Parser,
+    handle: String,
+    password: String,
+}
+
+impl Client<TcpStream> {
+    /// Connects to the server at the specified address.
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use magpie::ggs::Client;
+    ///
+    /// let client = Client::connect("skatgame.net:5000", "handle", "password").unwrap();
+    /// ```
+    pub fn connect(address: impl ToSocketAddrs, handle: &str, password: &str) -> io::Result<Self> {
+        Ok(Self::new(TcpStream::connect(address)?, handle, password))
+    }
+}
+
+impl<S: Read + Write> Client<S> {
+    /// Returns a client communicating over the specified stream.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::ggs::Client;
+    /// use std::io::Cursor;
+    ///
+    /// // Anything written to the cursor ends up after the data read from it
+    /// let stream = Cursor::new(b"Enter your handle: ".to_vec());
+    /// let mut client = Client::new(stream, "alice", "secret");
+    /// assert_eq!(None, client.next_event().unwrap());
+    /// assert!(client.stream().get_ref().ends_with(b"alice\n"));
+    /// ```
+    pub fn new(stream: S, handle: &str, password: &str) -> Self {
+        Self {
+            stream,
+            parser: Parser::new(),
+            handle: handle.to_string(),
+            password: password.to_string(),
+        }
+    }
+
+    /// Sends a command to the server.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::ggs::{Client, Command};
+    /// use std::io::Cursor;
+    ///
+    /// let mut client = Client::new(Cursor::new(Vec::new()), "alice", "secret");
+    /// client.send(&Command::Open(1)).unwrap();
+    /// assert_eq!(b"tell /os open 1\n", client.stream().get_ref().as_slice());
+    /// ```
+    pub fn send(&mut self, command: &Command) -> io::Result<()> {
+        self.send_line(&command.to_string())
+    }
+
+    /// Blocks until the next event arrives.
+    ///
+    /// Returns `None` once the server has closed the connection.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::ggs::{Client, Event};
+    /// use std::io::Cursor;
+    ///
+    /// let stream = Cursor::new(b"/os: - match .1.2 alice 40 bob 24\n".to_vec());
+    /// let mut client = Client::new(stream, "alice", "secret");
+    /// let Some(Event::MatchEnded { id, .. }) = client.next_event().unwrap() else {
+    ///     panic!("expected the match to end");
+    /// };
+    /// assert_eq!(".1.2", id);
+    /// ```
+    pub fn next_event(&mut self) -> io::Result<Option<Event>> {
+        let mut bytes = [0; 4096];
+        loop {
+            while let Some(event) = self.parser.next_event() {
+                match event {
+                    Event::HandlePrompt => {
+                        let handle = self.handle.clone();
+                        self.send_line(&handle)?;
+                    }
+                    Event::PasswordPrompt => {
+                        let password = self.password.clone();
+                        self.send_line(&password)?;
+                    }
+                    event => return Ok(Some(event)),
+                }
+            }
+            match self.stream.read(&mut bytes) {
+                Ok(0) => return Ok(None),
+                Ok(read) => self.parser.push(&bytes[..read]),
+                Err(error) if error.kind() == io::ErrorKind::Interrupted => {}
+                Err(error) => return Err(error),
+            }
+        }
+    }
+
+    /// Calls the handler with every event until either the handler breaks or
+    /// the server closes the connection.
+    ///
+    /// The handler receives the client as well, which allows it to respond
+    /// to events by sending commands.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::ggs::{Client, Command, Event};
+    /// use std::{io::Cursor, ops::ControlFlow};
+    ///
+    /// let stream = Cursor::new(b"hello\nworld\n".to_vec());
+    /// let mut client = Client::new(stream, "alice", "secret");
+    /// let mut messages = Vec::new();
+    /// client
+    ///     .run(|_, event| {
+    ///         messages.push(event);
+    ///         Ok(ControlFlow::Break(()))
+    ///     })
+    ///     .unwrap();
+    /// assert_eq!(vec![Event::Message(String::from("hello"))], messages);
+    /// ```
+    pub fn run<F>(&mut self, mut handler: F) -> io::Result<()>
+    where
+        F: FnMut(&mut Self, Event) -> io::Result<ControlFlow<()>>,
+    {
+        while let Some(event) = self.next_event()? {
+            if handler(self, event)?.is_break() {
+                break;
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the underlying stream.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::ggs::Client;
+    /// use std::io::Cursor;
+    ///
+    /// let client = Client::new(Cursor::new(Vec::new()), "alice", "secret");
+    /// assert!(client.stream().get_ref().is_empty());
+    /// ```
+    pub fn stream(&self) -> &S {
+        &self.stream
+    }
+
+    fn send_line(&mut self, line: &str) -> io::Result<()> {
+        self.stream.write_all(line.as_bytes())?;
+        self.stream.write_all(b"\n")?;
+        self.stream.flush()
+    }
+}
@@ -0,0 +1,77 @@
+use crate::othello::Move;
+use std::time::Duration;
+
+/// Represents a command sent to the server.
+///
+/// Commands are formatted as the line of text sent to the server by their
+/// [`Display`] implementation, without the trailing newline.
+///
+/// [`Display`]: core::fmt::Display
+///
+/// # Examples
+/// ```rust
+/// use magpie::{ggs::Command, othello::Move};
+///
+/// let mv = Move::Play("f5".parse().unwrap());
+/// let command = Command::Play {
+///     id: String::from(".1.2"),
+///     mv,
+///     eval: Some(1.5),
+///     time: None,
+/// };
+/// assert_eq!("tell /os play .1.2 F5/1.50", command.to_string());
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub enum Command {
+    /// Accepts up to the specified number of simultaneous matches.
+    Open(u8),
+    /// Plays a move in the specified game, optionally annotated with an
+    /// evaluation and the time spent.
+    Play {
+        /// The identifier of the game, such as `.1.2`.
+        id: String,
+        /// The move to play.
+        mv: Move,
+        /// The evaluation of the move, in discs.
+        eval: Option<f32>,
+        /// The time spent on the move.
+        time: Option<Duration>,
+    },
+    /// Accepts the match request with the specified identifier.
+    Accept(String),
+    /// Declines the match request with the specified identifier.
+    Decline(String),
+    /// Any other command, sent verbatim.
+    Raw(String),
+}
+
+impl core::fmt::Display for Command {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Command::Open(count) => write!(f, "tell /os open {count}"),
+            Command::Play { id, mv, eval, time } => {
+                write!(f, "tell /os play {id} ")?;
+                match mv {
+                    Move::Play(pos) => write!(f, "{}", pos.to_string().to_uppercase())?,
+                    Move::Pass => f.write_str("PA")?,
+                }
+                match (eval, time) {
+                    (None, None) => Ok(()),
+                    (eval, time) => {
+                        f.write_str("/")?;
+                        if let Some(eval) = eval {
+                            write!(f, "{eval:.2}")?;
+                        }
+                        if let Some(time) = time {
+                            write!(f, "/{:.2}", time.as_secs_f32())?;
+                        }
+                        Ok(())
+                    }
+                }
+            }
+            Command::Accept(id) => write!(f, "tell /os accept {id}"),
+            Command::Decline(id) => write!(f, "tell /os decline {id}"),
+            Command::Raw(text) => f.write_str(text),
+        }
+    }
+}
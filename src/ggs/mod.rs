@@ -0,0 +1,40 @@
+//! A client for the Generic Game Server (GGS).
+//!
+//! GGS is a telnet-based game server where Othello programs play each other.
+//! Communication happens through plain lines of text. Othello related
+//! messages are sent by the `/os` service and are prefixed with `/os:`, while
+//! commands for it are sent with `tell /os`.
+//!
+//! The protocol is implemented without performing any I/O in [`Parser`] and
+//! [`Command`], which makes it usable with any I/O model, including
+//! asynchronous runtimes. The [`Client`] builds on them to talk to a server
+//! over any blocking stream, such as a [`TcpStream`].
+//!
+//! [`TcpStream`]: std::net::TcpStream
+//! [`Parser`]: crate::ggs::Parser
+//! [`Command`]: crate::ggs::Command
+//! [`Client`]: crate::ggs::Client
+//!
+//! # Examples
+//! ```rust,no_run
+//! use magpie::ggs::{Client, Command, Event};
+//!
+//! let mut client = Client::connect("skatgame.net:5000", "handle", "password").unwrap();
+//! client.send(&Command::Open(1)).unwrap();
+//! while let Some(event) = client.next_event().unwrap() {
+//!     if let Event::Update(update) = event {
+//!         println!("{}", update.board.display());
+//!     }
+//! }
+//! ```
+
+/// A blocking client built on top of the parser.
+mod client;
+/// Commands sent to the server.
+mod command;
+/// Turns the text sent by the server into events.
+mod parser;
+
+pub use client::Client;
+pub use command::Command;
+pub use parser::{Event, GameUpdate, MatchInfo, Parser};
@@ -0,0 +1,274 @@
+use crate::othello::{Board, Position, Stone};
+use std::{collections::VecDeque, time::Duration};
+
+/// Represents something that happened on the server.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Event {
+    /// The server asked for the handle to log in with. The [`Client`]
+    /// answers it automatically.
+    ///
+    /// [`Client`]: crate::ggs::Client
+    HandlePrompt,
+    /// The server asked for the password to log in with. The [`Client`]
+    /// answers it automatically.
+    ///
+    /// [`Client`]: crate::ggs::Client
+    PasswordPrompt,
+    /// A match has started.
+    MatchStarted(MatchInfo),
+    /// A match has ended. Carries the identifier of the match and the rest
+    /// of the message, which describes the result.
+    MatchEnded {
+        /// The identifier of the match.
+        id: String,
+        /// The rest of the message.
+        result: String,
+    },
+    /// A game was joined or a move was made in it.
+    Update(GameUpdate),
+    /// Any other message sent by the server.
+    Message(String),
+}
+
+/// Describes a match that has started.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MatchInfo {
+    /// The identifier of the match, such as `.1.2`.
+    pub id: String,
+    /// The names and ratings of both players.
+    pub players: [(String, f32); 2],
+    /// The type of the match, such as `8 R` for a rated game on a 8x8 board.
+    pub kind: String,
+}
+
+/// Describes the state of a game after it was joined or a move was made.
+#[derive(Clone, Debug, PartialEq)]
+pub struct GameUpdate {
+    /// The identifier of the game, such as `.1.2`.
+    pub id: String,
+    /// The current board.
+    pub board: Board,
+    /// The player to move.
+    pub to_move: Stone,
+    /// The remaining time of black and white, if it could be read.
+    pub clocks: [Option<Duration>; 2],
+}
+
+/// Turns the text sent by the server into events.
+///
+/// The parser does not perform any I/O. Data is pushed into it as it
+/// arrives and events are pulled out once they are complete, which makes it
+/// suitable for both blocking and asynchronous clients.
+///
+/// # Examples
+/// ```rust
+/// use magpie::ggs::{Event, Parser};
+///
+/// let mut parser = Parser::new();
+/// parser.push(b"/os: + match .1.2 1800.0 alice 1700.0 ");
+/// assert_eq!(None, parser.next_event());
+/// parser.push(b"bob 8 R\n");
+/// let Some(Event::MatchStarted(info)) = parser.next_event() else {
+///     panic!("expected a match");
+/// };
+/// assert_eq!(".1.2", info.id);
+/// assert_eq!("bob", info.players[1].0);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Parser {
+    buffer: Vec<u8>,
+    events: VecDeque<Event>,
+    update: Option<UpdateBuilder>,
+}
+
+impl Parser {
+    /// Returns a parser that has not received any data.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Pushes data received from the server into the parser.
+    pub fn push(&mut self, bytes: &[u8]) {
+        self.buffer.extend_from_slice(bytes);
+        while let Some(end) = self.buffer.iter().position(|b| *b == b'\n') {
+            let line: Vec<u8> = self.buffer.drain(..=end).collect();
+            let line = String::from_utf8_lossy(&line);
+            self.line(line.trim_end());
+        }
+        // Login prompts are not terminated by a newline
+        let prompt = String::from_utf8_lossy(&self.buffer).to_lowercase();
+        if prompt.starts_with("enter your handle") {
+            self.buffer.clear();
+            self.events.push_back(Event::HandlePrompt);
+        } else if prompt.starts_with("enter your password") {
+            self.buffer.clear();
+            self.events.push_back(Event::PasswordPrompt);
+        }
+    }
+
+    /// Returns the next complete event, if any.
+    pub fn next_event(&mut self) -> Option<Event> {
+        self.events.pop_front()
+    }
+
+    fn line(&mut self, line: &str) {
+        if let Some(update) = &mut self.update {
+            if let Some(row) = line.strip_prefix('|') {
+                if update.row(row) {
+                    self.finish_update();
+                }
+                return;
+            }
+            self.finish_update();
+        }
+
+        let lower = line.to_lowercase();
+        if lower.starts_with("enter your handle") {
+            self.events.push_back(Event::HandlePrompt);
+            return;
+        }
+        if lower.starts_with("enter your password") {
+            self.events.push_back(Event::PasswordPrompt);
+            return;
+        }
+        if line.trim().is_empty() || line.trim() == "READY" {
+            return;
+        }
+        let Some(message) = line.strip_prefix("/os:").map(str::trim) else {
+            self.events.push_back(Event::Message(line.to_string()));
+            return;
+        };
+        let tokens: Vec<&str> = message.split_whitespace().collect();
+        match tokens.as_slice() {
+            [
+                "+",
+                "match",
+                id,
+                rating1,
+                player1,
+                rating2,
+                player2,
+                kind @ ..,
+            ] => {
+                self.events.push_back(Event::MatchStarted(MatchInfo {
+                    id: (*id).to_string(),
+                    players: [
+                        ((*player1).to_string(), rating1.parse().unwrap_or_default()),
+                        ((*player2).to_string(), rating2.parse().unwrap_or_default()),
+                    ],
+                    kind: kind.join(" "),
+                }));
+            }
+            ["-", "match", id, result @ ..] => {
+                self.events.push_back(Event::MatchEnded {
+                    id: (*id).to_string(),
+                    result: result.join(" "),
+                });
+            }
+            ["join" | "update", id, ..] => self.update = Some(UpdateBuilder::new(id)),
+            _ => self.events.push_back(Event::Message(line.to_string())),
+        }
+    }
+
+    fn finish_update(&mut self) {
+        let Some(update) = self.update.take() else {
+            return;
+        };
+        let event = match update.build() {
+            Some(update) => Event::Update(update),
+            None => Event::Message(format!("/os: update {} could not be parsed", update.id)),
+        };
+        self.events.push_back(event);
+    }
+}
+
+/// Collects the lines of a board display that follow a join or update.
+#[derive(Clone, Debug)]
+struct UpdateBuilder {
+    id: String,
+    board: Board,
+    rows: u8,
+    to_move: Option<Stone>,
+    clocks: [Option<Duration>; 2],
+}
+
+impl UpdateBuilder {
+    fn new(id: &str) -> Self {
+        Self {
+            id: id.to_string(),
+            board: Board::empty(),
+            rows: 0,
+            to_move: None,
+            clocks: [None; 2],
+        }
+    }
+
+    /// Parses a single line of the display. Returns true once the player to
+    /// move, which ends the display, has been read.
+    fn row(&mut self, line: &str) -> bool {
+        let tokens: Vec<&str> = line.split_whitespace().collect();
+        match tokens.as_slice() {
+            [symbol, "to", "move"] => {
+                self.to_move = stone(symbol);
+                true
+            }
+            [rank, cells @ .., end] if cells.len() == 8 && rank == end => {
+                if let Ok(rank @ 1..=8) = rank.parse::<u8>() {
+                    for (file, cell) in (0..).zip(cells) {
+                        let pos = Position::from_rank_file(rank - 1, file);
+                        if let (Ok(pos), Some(stone)) = (pos, stone(cell)) {
+                            self.board.place_stone_unchecked(stone, pos.into());
+                        }
+                    }
+                    self.rows += 1;
+                }
+                false
+            }
+            [first, ..] => {
+                // Player lines start with the symbol of the player, such as
+                // *alice or O bob, and contain the remaining time
+                let symbol = first.get(..1).unwrap_or_default();
+                if let Some(stone) = stone(symbol) {
+                    let clock = tokens.iter().find_map(|token| parse_clock(token));
+                    self.clocks[usize::from(stone == Stone::White)] = clock;
+                }
+                false
+            }
+            [] => false,
+        }
+    }
+
+    fn build(&self) -> Option<GameUpdate> {
+        (self.rows == 8).then_some(())?;
+        Some(GameUpdate {
+            id: self.id.clone(),
+            board: self.board,
+            to_move: self.to_move?,
+            clocks: self.clocks,
+        })
+    }
+}
+
+fn stone(symbol: &str) -> Option<Stone> {
+    match symbol {
+        "*" => Some(Stone::Black),
+        "O" => Some(Stone::White),
+        _ => None,
+    }
+}
+
+/// Parses clocks such as `04:59` or `1:02:03`, ignoring anything after the
+/// first slash.
+fn parse_clock(token: &str) -> Option<Duration> {
+    let clock = token.split('/').next()?;
+    if !clock.contains(':') {
+        return None;
+    }
+    clock
+        .split(':')
+        .try_fold(0_u64, |seconds, part| {
+            Some(seconds * 60 + part.parse::<u64>().ok()?)
+        })
+        .map(Duration::from_secs)
+}
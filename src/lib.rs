@@ -1,3 +1,4 @@
+#![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::missing_panics_doc)]
 #![allow(clippy::missing_errors_doc)]
 #![doc(html_logo_url = "https://cdn.github.emileng.se/repo/magpie/logo.svg")]
@@ -19,9 +20,178 @@
 //!
 //! The [`othello`] module contains core structures and functions for playing Othello.
 //!
+//! The [`ggf`] module imports and exports games in the Generic Game Format,
+//! used by GGS and NBoard. The [`obf`] module reads and writes the problem
+//! files of Edax and Egaroucid. The [`pgn`] module stores games along with
+//! their analysis, such as comments, evaluations and variations.
+//!
+//! The [`search`] module contains an alpha-beta searcher along with the
+//! building blocks it is made of, while the [`eval`] module contains
+//! evaluation functions for it to use.
+//!
+//! The [`clock`] module models time controls and tracks the time left for
+//! both players, for engines and match runners alike.
+//!
+//! The [`protocol`] module serves engines built with the [`search`] module
+//! to user interfaces such as NBoard and UCI-style GUIs, or to any program
+//! through JSON-RPC with the `jsonrpc` feature.
+//!
+//! The [`selfplay`] module plays games between two agents and records every
+//! move, which is useful for tuning and training evaluation functions. The
+//! [`datapack`] module stores such records in a compact binary format. The
+//! [`tournament`] module plays matches between agents to measure whether a
+//! change makes an engine stronger. The [`tuning`] module fits the weights
+//! of evaluation functions to the results of recorded games.
+//!
+//! The [`tree`] module stores analyzed lines as a graph of positions, in
+//! which transpositions and symmetric variants share a single node.
+//! The [`tablebase`] module solves the endgames of small board variants
+//! and stores their outcomes on disk.
+//! The [`enumerate`] module counts the distinct positions reachable after a
+//! number of moves, as in published enumeration results.
+//! The [`dedup`] module finds duplicate games and transpositions in game
+//! collections, for cleaning datasets.
+//! The [`analysis`] module reviews finished games with the engine, labeling
+//! mistakes and summarizing the accuracy of both players. The [`puzzles`]
+//! module finds positions in games where a single move is clearly best.
+//!
+//! ## `no_std` Support
+//!
+//! Magpie depends on the standard library through the `std` feature, which
+//! is enabled by default. Disabling default features leaves the bitboards,
+//! boards and move generation, which only depend on `core`. The `alloc`
+//! feature additionally enables everything that needs to allocate, such as
+//! [`Game`], transcripts, the [`ggf`] module and parts of the [`search`]
+//! module. File formats and searchers that keep track of time still require
+//! `std`.
+//!
+//! ```toml
+//! magpie = { version = "0.11", default-features = false, features = ["alloc"] }
+//! ```
+//!
+//! The following modules are available behind feature flags:
+//!
+//! - `cli`: Builds the `magpie-cli` binary, which plays against the
+//!   built-in engine in the terminal.
+//! - `config`: Reads engine settings from JSON and TOML files, such as
+//!   with [`EngineConfig::load`]. Enables `serde`.
+//! - `ffi`: The [`ffi`] module exposes the core operations through a C
+//!   interface.
+//! - `ffo`: Embeds positions from the FFO endgame test suite in the
+//!   [`obf`] module, for validating endgame solvers.
+//! - `jsonrpc`: The [`jsonrpc`] module serves engines over JSON-RPC.
+//!   Enables `serde`.
+//! - `net`: The [`ggs`] module connects to the Generic Game Server.
+//! - `onnx`: Adds [`OnnxEvaluator`], which runs neural networks in the
+//!   ONNX format as external evaluators through `tract`.
+//! - `openings`: Embeds the standard named opening lines in the [`book`]
+//!   module, and a set of balanced openings for engine matches in the
+//!   [`openings`] module.
+//! - `proptest`: Implements [`proptest`]'s `Arbitrary` for [`Bitboard`],
+//!   [`Position`], [`Stone`] and [`Board`]. Boards are generated by
+//!   random playouts, so only boards reachable by legal play are produced.
+//! - `python`: The [`python`] module exports boards, games and searchers
+//!   to Python through `pyo3`.
+//! - `rand`: Adds random playouts and random reachable boards, such as
+//!   [`Game::random_playout`], driven by any [`rand`] generator.
+//! - `render`: The [`render`] module draws boards as SVG and PNG images.
+//! - `simd`: Adds batched move generation, such as
+//!   [`Board::moves_for_x4`], which uses AVX2 when available.
+//! - `tracing`: Instruments the [`search`] and [`protocol`] modules with
+//!   [`tracing`] spans, and reports the nodes, transposition table hit
+//!   rate, cutoffs and time of every completed search iteration.
+//! - `wasm`: The [`wasm`] module exports boards and games to JavaScript
+//!   through `wasm-bindgen`.
+//! - `wthor`: The [`wthor`] module reads game archives in the WTHOR format.
+//!
+//! [`analysis`]: crate::analysis
+//! [`Bitboard`]: crate::othello::Bitboard
 //! [`Board`]: crate::othello::Board
+//! [`clock`]: crate::clock
+//! [`datapack`]: crate::datapack
+//! [`Board::moves_for_x4`]: crate::othello::Board::moves_for_x4
+//! [`Game::random_playout`]: crate::othello::Game::random_playout
+//! [`rand`]: https://docs.rs/rand
+//! [`tracing`]: https://docs.rs/tracing
+//! [`book`]: crate::othello::book
+//! [`dedup`]: crate::dedup
+//! [`enumerate`]: crate::enumerate
+//! [`EngineConfig::load`]: crate::protocol::EngineConfig::load
+//! [`eval`]: crate::eval
+//! [`ffi`]: crate::ffi
+//! [`ggf`]: crate::ggf
+//! [`ggs`]: crate::ggs
 //! [`Game`]: crate::othello::Game
+//! [`jsonrpc`]: crate::protocol::jsonrpc
+//! [`obf`]: crate::obf
+//! [`OnnxEvaluator`]: crate::eval::OnnxEvaluator
+//! [`openings`]: crate::openings
 //! [`othello`]: crate::othello
+//! [`pgn`]: crate::pgn
+//! [`Position`]: crate::othello::Position
+//! [`proptest`]: https://docs.rs/proptest
+//! [`protocol`]: crate::protocol
+//! [`puzzles`]: crate::puzzles
+//! [`python`]: crate::python
+//! [`render`]: crate::render
+//! [`search`]: crate::search
+//! [`selfplay`]: crate::selfplay
+//! [`Stone`]: crate::othello::Stone
+//! [`tablebase`]: crate::tablebase
+//! [`tournament`]: crate::tournament
+//! [`tree`]: crate::tree
+//! [`tuning`]: crate::tuning
+//! [`wasm`]: crate::wasm
+//! [`wthor`]: crate::wthor
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
 
+#[cfg(feature = "std")]
+pub mod analysis;
+pub mod clock;
+#[cfg(feature = "std")]
+pub mod datapack;
+#[cfg(feature = "std")]
+pub mod dedup;
+#[cfg(feature = "std")]
+pub mod enumerate;
+pub mod eval;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+#[cfg(feature = "alloc")]
+pub mod ggf;
+#[cfg(feature = "net")]
+pub mod ggs;
+#[cfg(feature = "alloc")]
+pub mod obf;
+#[cfg(feature = "openings")]
+pub mod openings;
 /// Contains core structures and functions for playing Othello
 pub mod othello;
+#[cfg(feature = "alloc")]
+pub mod pgn;
+#[cfg(feature = "std")]
+pub mod protocol;
+#[cfg(feature = "std")]
+pub mod puzzles;
+#[cfg(feature = "python")]
+pub mod python;
+#[cfg(feature = "render")]
+pub mod render;
+#[cfg(feature = "alloc")]
+pub mod search;
+#[cfg(feature = "std")]
+pub mod selfplay;
+#[cfg(feature = "std")]
+pub mod tablebase;
+#[cfg(feature = "std")]
+pub mod tournament;
+#[cfg(feature = "alloc")]
+pub mod tree;
+#[cfg(feature = "std")]
+pub mod tuning;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+#[cfg(feature = "wthor")]
+pub mod wthor;
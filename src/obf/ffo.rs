@@ -0,0 +1,29 @@
+use crate::obf::{Problem, parse_problems};
+use alloc::vec::Vec;
+
+/// The embedded positions returned by [`ffo`].
+const FFO: &str = include_str!("ffo.obf");
+
+/// Returns positions from the FFO endgame test suite, each with its best
+/// move and exact score, for validating endgame solvers.
+///
+/// The suite was published by the French Othello Federation and is the
+/// standard benchmark for endgame solvers. Only positions #1 and #40 are
+/// embedded, with 14 and 20 empty squares respectively. The complete suite
+/// is distributed with Edax as `.obf` files, which can be read with
+/// [`parse_problems`].
+///
+/// [`parse_problems`]: crate::obf::parse_problems
+///
+/// # Examples
+/// ```rust
+/// use magpie::obf;
+/// use magpie::othello::{Move, Position};
+///
+/// let problems = obf::ffo();
+/// assert_eq!(Some((Move::Play(Position::G8), 18)), problems[0].best_move());
+/// ```
+#[must_use]
+pub fn ffo() -> Vec<Problem> {
+    parse_problems(FFO).expect("embedded problems are valid")
+}
@@ -0,0 +1,39 @@
+//! Import and export of problem files in the format used by Edax.
+//!
+//! Edax and Egaroucid store test positions one per line, as the 64 squares
+//! from A1 to H8 followed by the player to move. In files with the `.obf`
+//! extension, the position is followed by a list of moves and their exact
+//! scores, separated by semicolons:
+//!
+//! ```text
+//! --XXXXX--OOOXX-O-OOOXXOX-OXOXOXXOXXXOXXX--XOXOXX-XXXOOO--OOOOO-- X; G8:+18;
+//! ```
+//!
+//! Script files list positions without any scores. Both are read by the
+//! same parser, so suites such as the FFO endgame positions distributed
+//! with Edax can be loaded directly.
+//!
+//! # Examples
+//! ```rust
+//! use magpie::obf;
+//! use magpie::othello::{Move, Position, Stone};
+//!
+//! let text = "--XXXXX--OOOXX-O-OOOXXOX-OXOXOXXOXXXOXXX--XOXOXX-XXXOOO--OOOOO-- X; G8:+18;";
+//! let problem = obf::parse_problem(text).unwrap();
+//! assert_eq!(Stone::Black, problem.stone);
+//! assert_eq!(Some((Move::Play(Position::G8), 18)), problem.best_move());
+//! assert_eq!(problem, obf::parse_problem(&problem.to_string()).unwrap());
+//! ```
+
+/// A small embedded set of FFO endgame positions.
+#[cfg(feature = "ffo")]
+mod ffo;
+/// Parses problems in the format used by Edax.
+mod parser;
+/// Represents a single test position and the scores of its moves.
+mod problem;
+
+#[cfg(feature = "ffo")]
+pub use ffo::ffo;
+pub use parser::{ObfError, parse_problem, parse_problems};
+pub use problem::Problem;
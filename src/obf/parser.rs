@@ -0,0 +1,113 @@
+use crate::{
+    obf::problem::Problem,
+    othello::{Board, Move, Position, PositionStrError},
+};
+use alloc::vec::Vec;
+
+/// Parses a single problem from a line of a `.obf` or script file.
+///
+/// The position may be followed by any number of moves and their scores,
+/// such as `A2:+38`, each terminated or separated by a semicolon. Passes are
+/// written as `PS`. The moves are not validated against the position.
+///
+/// # Examples
+/// ```rust
+/// use magpie::obf;
+///
+/// let problem = obf::parse_problem(
+///     "O--OOOOX-OOOOOOXOOXXOOOXOOXOOOXXOOOOOOXX---OOOOX----O--X-------- X; A2:+38; H7:+4",
+/// )
+/// .unwrap();
+/// assert_eq!(20, problem.board.empty_squares().count_set());
+/// assert_eq!(2, problem.scores.len());
+/// ```
+pub fn parse_problem(line: &str) -> Result<Problem, ObfError> {
+    let mut fields = line.split(';');
+    let position = fields.next().unwrap_or_default();
+    let (board, stone) = Board::from_position_str(position)?;
+
+    let mut scores = Vec::new();
+    for field in fields.map(str::trim).filter(|field| !field.is_empty()) {
+        let index = scores.len();
+        let (mv, score) = field.split_once(':').ok_or(ObfError::InvalidMove(index))?;
+        let mv = match mv.trim().to_lowercase().as_str() {
+            "ps" | "pa" | "pass" => Move::Pass,
+            notation => Position::try_from(notation)
+                .map(Move::Play)
+                .map_err(|_| ObfError::InvalidMove(index))?,
+        };
+        let score = score
+            .trim()
+            .parse()
+            .map_err(|_| ObfError::InvalidScore(index))?;
+        scores.push((mv, score));
+    }
+    Ok(Problem {
+        board,
+        stone,
+        scores,
+    })
+}
+
+/// Parses every problem in a `.obf` or script file, one per line.
+///
+/// Blank lines and lines starting with `%` or `#` are skipped as comments.
+///
+/// # Examples
+/// ```rust
+/// use magpie::obf;
+///
+/// let text = "% Two positions\n\
+///             ---------------------------OX------XO--------------------------- X\n\
+///             \n\
+///             ---------------------------OX------XO--------------------------- O\n";
+/// assert_eq!(2, obf::parse_problems(text).unwrap().len());
+/// ```
+pub fn parse_problems(text: &str) -> Result<Vec<Problem>, ObfError> {
+    text.lines()
+        .map(str::trim)
+        .filter(|line| !(line.is_empty() || line.starts_with('%') || line.starts_with('#')))
+        .map(parse_problem)
+        .collect()
+}
+
+/// This enum represents errors that may occur when parsing problems.
+#[derive(Debug, Eq, PartialEq, Hash)]
+pub enum ObfError {
+    /// Indicates that the position or the player to move could not be
+    /// parsed.
+    InvalidPosition(PositionStrError),
+    /// Indicates that a move could not be parsed. Carries the index of the
+    /// offending move.
+    InvalidMove(usize),
+    /// Indicates that the score of a move could not be parsed. Carries the
+    /// index of the offending move.
+    InvalidScore(usize),
+}
+
+impl From<PositionStrError> for ObfError {
+    fn from(error: PositionStrError) -> Self {
+        ObfError::InvalidPosition(error)
+    }
+}
+
+impl core::fmt::Display for ObfError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ObfError::InvalidPosition(error) => write!(f, "invalid position: {error}"),
+            ObfError::InvalidMove(index) => write!(f, "move {} could not be parsed", index + 1),
+            ObfError::InvalidScore(index) => {
+                write!(f, "the score of move {} could not be parsed", index + 1)
+            }
+        }
+    }
+}
+
+impl core::error::Error for ObfError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            ObfError::InvalidPosition(error) => Some(error),
+            _ => None,
+        }
+    }
+}
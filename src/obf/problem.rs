@@ -0,0 +1,84 @@
+use crate::othello::{Board, Move, Stone};
+use alloc::{string::ToString, vec::Vec};
+
+/// Represents a single test position, along with the exact scores of some
+/// or all of its moves.
+///
+/// Scores are final disc differentials under perfect play, from the
+/// perspective of the player to move.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Problem {
+    /// The position to solve.
+    pub board: Board,
+    /// The player to move.
+    pub stone: Stone,
+    /// The moves and their scores, in the order they were listed.
+    pub scores: Vec<(Move, i32)>,
+}
+
+impl Problem {
+    /// Returns a problem without any scores, as found in script files.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::obf::Problem;
+    /// use magpie::othello::{Board, Stone};
+    ///
+    /// let problem = Problem::new(Board::standard(), Stone::Black);
+    /// assert_eq!(
+    ///     "---------------------------OX------XO--------------------------- X",
+    ///     problem.to_string()
+    /// );
+    /// ```
+    #[must_use]
+    pub fn new(board: Board, stone: Stone) -> Self {
+        Self {
+            board,
+            stone,
+            scores: Vec::new(),
+        }
+    }
+
+    /// Returns the move with the highest score along with its score, or
+    /// `None` if no scores are listed.
+    ///
+    /// If several moves share the highest score, the first one listed is
+    /// returned.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::obf;
+    /// use magpie::othello::{Move, Position};
+    ///
+    /// let text = "---------------------------OX------XO--------------------------- X; \
+    ///             D3:+0; C4:+0; F5:+0; E6:+0;";
+    /// let problem = obf::parse_problem(text).unwrap();
+    /// assert_eq!(Some((Move::Play(Position::D3), 0)), problem.best_move());
+    /// ```
+    #[must_use]
+    pub fn best_move(&self) -> Option<(Move, i32)> {
+        self.scores
+            .iter()
+            .copied()
+            .reduce(|best, next| if next.1 > best.1 { next } else { best })
+    }
+}
+
+impl core::fmt::Display for Problem {
+    /// Formats the problem as a single line, with the scores in the format
+    /// of `.obf` files.
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(&self.board.position_str(self.stone))?;
+        if !self.scores.is_empty() {
+            f.write_str(";")?;
+        }
+        for (mv, score) in &self.scores {
+            let mv = match mv {
+                Move::Play(pos) => pos.to_string().to_uppercase(),
+                Move::Pass => "PS".to_string(),
+            };
+            write!(f, " {mv}:{score:+};")?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,76 @@
+//! Sets of opening positions for testing engines against each other.
+//!
+//! Matches between deterministic engines that always start from the initial
+//! position tend to repeat the same few games, which says little about their
+//! relative strength. Starting every game from a different, roughly even
+//! position avoids this, which is what [`Match::with_openings`] is for.
+//!
+//! [`Match::with_openings`]: crate::tournament::Match::with_openings
+//!
+//! # Examples
+//! ```rust
+//! use magpie::openings;
+//! use magpie::search::RandomRollout;
+//! use magpie::tournament::Match;
+//!
+//! let report = Match::new(|| RandomRollout::new(1), || RandomRollout::new(2))
+//!     .with_openings(openings::xot())
+//!     .with_games(4)
+//!     .run();
+//! assert_eq!(4, report.games());
+//! ```
+
+use crate::othello::{Board, Stone, Transcript};
+
+/// The openings returned by [`xot_transcripts`], one per line.
+const XOT: &str = include_str!("xot.txt");
+
+/// Returns the positions of [`xot_transcripts`] along with the player to
+/// move, in the same order.
+///
+/// # Examples
+/// ```rust
+/// use magpie::openings;
+/// use magpie::othello::Stone;
+///
+/// let openings = openings::xot();
+/// assert_eq!(1000, openings.len());
+/// // Eight moves have been played, so black is always to move
+/// assert!(openings.iter().all(|(board, stone)| {
+///     board.empty_squares().count_set() == 52 && *stone == Stone::Black
+/// }));
+/// ```
+#[must_use]
+pub fn xot() -> Vec<(Board, Stone)> {
+    xot_transcripts()
+        .iter()
+        .map(|transcript| {
+            let game = transcript.replay().expect("embedded openings are legal");
+            (game.board(), game.current_turn())
+        })
+        .collect()
+}
+
+/// Returns 1000 openings of eight moves each, in the style of the XOT
+/// openings used in engine tournaments.
+///
+/// This is not the official XOT list. The openings were chosen among random
+/// games starting with F5, as the ones whose positions are distinct up to
+/// symmetry and closest to even according to a depth 8 search with the
+/// [`Heuristic`] evaluator.
+///
+/// [`Heuristic`]: crate::eval::Heuristic
+///
+/// # Examples
+/// ```rust
+/// use magpie::openings;
+///
+/// let transcripts = openings::xot_transcripts();
+/// assert!(transcripts.iter().all(|transcript| transcript.moves().len() == 8));
+/// ```
+#[must_use]
+pub fn xot_transcripts() -> Vec<Transcript> {
+    XOT.lines()
+        .map(|line| line.parse().expect("embedded openings are valid"))
+        .collect()
+}
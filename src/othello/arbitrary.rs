@@ -0,0 +1,82 @@
+use crate::othello::{Bitboard, Board, Position, Stone};
+use proptest::{
+    arbitrary::{Arbitrary, any},
+    collection::vec,
+    strategy::{BoxedStrategy, Just, Strategy},
+};
+
+/// The most moves that can be played in a game.
+const MAX_PLIES: usize = 60;
+
+/// Generates both players, shrinking towards black.
+impl Arbitrary for Stone {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with((): Self::Parameters) -> Self::Strategy {
+        proptest::prop_oneof![Just(Stone::Black), Just(Stone::White)].boxed()
+    }
+}
+
+/// Generates any set of squares, shrinking towards the empty set.
+impl Arbitrary for Bitboard {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with((): Self::Parameters) -> Self::Strategy {
+        any::<u64>().prop_map(Bitboard::from).boxed()
+    }
+}
+
+/// Generates every square, shrinking towards A1.
+impl Arbitrary for Position {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with((): Self::Parameters) -> Self::Strategy {
+        (0..64u8)
+            .prop_map(|index| Position::from_index(index).expect("indices are on the board"))
+            .boxed()
+    }
+}
+
+/// Generates boards that are reachable from the standard opening position,
+/// by playing a random number of random legal moves.
+///
+/// Boards shrink towards shorter games and towards the moves that come
+/// first in the order of [`hot_bits`], ending at the opening position.
+///
+/// [`hot_bits`]: crate::othello::Bitboard::hot_bits
+impl Arbitrary for Board {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with((): Self::Parameters) -> Self::Strategy {
+        vec(any::<u8>(), 0..=MAX_PLIES)
+            .prop_map(|choices| playout(&choices))
+            .boxed()
+    }
+}
+
+/// Plays one move for every choice, picking the move at the index of the
+/// choice modulo the number of legal moves. Forced passes are made
+/// automatically, and the playout stops early if the game ends.
+fn playout(choices: &[u8]) -> Board {
+    let mut board = Board::standard();
+    let mut stone = Stone::Black;
+    for &choice in choices {
+        let mut moves = board.moves_for(stone);
+        if moves.is_empty() {
+            stone = stone.flip();
+            moves = board.moves_for(stone);
+            if moves.is_empty() {
+                break;
+            }
+        }
+        let index = usize::from(choice) % usize::from(moves.count_set());
+        let pos = moves.hot_bits().nth(index).expect("the index is in range");
+        board.play(stone, pos);
+        stone = stone.flip();
+    }
+    board
+}
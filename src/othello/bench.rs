@@ -0,0 +1,43 @@
+//! The hot paths of move generation on raw bitboards, without any of the
+//! checks or conversions of the public API.
+//!
+//! Only meant for the benchmarks of this crate and for comparing kernels on
+//! different hardware, so none of it is covered by semantic versioning.
+//! Every bitboard uses the same layout as [`Bitboard`], and all functions
+//! expect the two players' stones not to overlap.
+//!
+//! [`Bitboard`]: crate::othello::Bitboard
+
+use crate::othello::{Bitboard, Kernel, Symmetry, board, kernel};
+
+/// Returns the stones flipped if the player owning `own` places a stone on
+/// the single bit set in `pos`, calculated by the specified kernel.
+///
+/// Kernels the CPU does not support fall back to the Kogge-Stone kernel.
+#[must_use]
+#[inline]
+pub fn flips(kernel: Kernel, own: u64, opponent: u64, pos: u64) -> u64 {
+    kernel::flips(kernel, own, opponent, pos)
+}
+
+/// Returns the legal moves of the player owning `own`, generated with
+/// Kogge-Stone fills.
+#[must_use]
+#[inline]
+pub fn moves(own: u64, opponent: u64) -> u64 {
+    board::raw_moves(own, opponent)
+}
+
+/// Returns every square adjacent to a set bit, in all eight directions.
+#[must_use]
+#[inline]
+pub fn neighbours(bits: u64) -> u64 {
+    board::neighbours(bits)
+}
+
+/// Rotates or reflects the bits by the specified symmetry.
+#[must_use]
+#[inline]
+pub fn transform(symmetry: Symmetry, bits: u64) -> u64 {
+    Bitboard(bits).transform(symmetry).raw()
+}
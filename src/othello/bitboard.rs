@@ -1,6 +1,9 @@
 use crate::othello::{
-    Position,
-    constants::{CCW_ROTATION_TABLE, CW_ROTATION_TABLE, POSITIONS},
+    Direction, Position,
+    constants::{
+        ANTI_DIAGONALS, CCW_ROTATION_TABLE, CENTER, CORNERS, CW_ROTATION_TABLE, DIAGONALS, EDGES,
+        FILES, POSITIONS, RANKS, SHIFT_RAYS,
+    },
 };
 
 #[cfg(feature = "serde")]
@@ -11,13 +14,137 @@ use serde::{Deserialize, Serialize};
 /// There are no restrictions placed on the bits represented, unlike the
 /// similar [`Position`] where only a single bit may be set.
 ///
+/// # Layout
+///
+/// The layout of the bits is part of the stable API and will not change
+/// between versions. The most significant bit is A1 and the least
+/// significant bit is H8. Squares are laid out rank by rank, so every byte
+/// holds one rank: the most significant byte is rank 1 and the least
+/// significant byte is rank 8. Within a byte, the most significant bit is
+/// the A file and the least significant bit is the H file.
+///
+/// ```text
+///        A  B  C  D  E  F  G  H
+/// 1     63 62 61 60 59 58 57 56   <- most significant byte
+/// 2     55 54 53 52 51 50 49 48
+/// ...
+/// 8      7  6  5  4  3  2  1  0   <- least significant byte
+/// ```
+///
+/// [`ranks`] and [`files`] split a bitboard into bytes according to this
+/// layout, which makes it possible to extract patterns and write custom
+/// transformations without shifting and masking by hand.
+///
 /// [`Position`]: crate::othello::Position
+/// [`ranks`]: crate::othello::Bitboard::ranks
+/// [`files`]: crate::othello::Bitboard::files
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(kani, derive(kani::Arbitrary))]
 #[derive(Clone, Copy, Debug, Default)]
 pub struct Bitboard(pub(crate) u64);
 
 impl Bitboard {
+    /// A bitboard with no bits set.
+    pub const EMPTY: Bitboard = Bitboard(0);
+
+    /// A bitboard with every bit set.
+    pub const FULL: Bitboard = Bitboard(u64::MAX);
+
+    /// Constructs a bitboard from a `u64`.
+    ///
+    /// This is equivalent to the [`From<u64>`] implementation, but can be
+    /// used in constant expressions, along with the other `const`
+    /// functions on bitboards. This makes it possible to build lookup
+    /// tables at compile time.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::Bitboard;
+    ///
+    /// // The squares adjacent to the corners that are not on an edge
+    /// const X_SQUARES: Bitboard = Bitboard::new(0x00_42_00_00_00_00_42_00);
+    /// const SAFE: Bitboard = X_SQUARES.union(Bitboard::edges()).complement();
+    /// assert_eq!(32, SAFE.count_set());
+    /// ```
+    ///
+    /// [`From<u64>`]: #impl-From<u64>-for-Bitboard
+    #[must_use]
+    pub const fn new(bits: u64) -> Bitboard {
+        Bitboard(bits)
+    }
+
+    /// Returns the squares set in either bitboard, like `|` but usable in
+    /// constant expressions.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::Bitboard;
+    ///
+    /// const SIDES: Bitboard = Bitboard::file(0).union(Bitboard::file(7));
+    /// assert_eq!(Bitboard::file(0) | Bitboard::file(7), SIDES);
+    /// ```
+    #[must_use]
+    pub const fn union(self, other: Bitboard) -> Bitboard {
+        Bitboard(self.0 | other.0)
+    }
+
+    /// Returns the squares set in both bitboards, like `&` but usable in
+    /// constant expressions.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::Bitboard;
+    ///
+    /// const A1: Bitboard = Bitboard::file(0).intersection(Bitboard::rank(0));
+    /// assert_eq!(1, A1.count_set());
+    /// ```
+    #[must_use]
+    pub const fn intersection(self, other: Bitboard) -> Bitboard {
+        Bitboard(self.0 & other.0)
+    }
+
+    /// Returns the squares set in this bitboard but not in the other.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::Bitboard;
+    ///
+    /// const INNER_EDGES: Bitboard = Bitboard::edges().difference(Bitboard::corners());
+    /// assert_eq!(24, INNER_EDGES.count_set());
+    /// ```
+    #[must_use]
+    pub const fn difference(self, other: Bitboard) -> Bitboard {
+        Bitboard(self.0 & !other.0)
+    }
+
+    /// Returns the squares not set in this bitboard, like `!` but usable in
+    /// constant expressions.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::Bitboard;
+    ///
+    /// assert_eq!(Bitboard::FULL, Bitboard::EMPTY.complement());
+    /// ```
+    #[must_use]
+    pub const fn complement(self) -> Bitboard {
+        Bitboard(!self.0)
+    }
+
+    /// Returns true if the square is set.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Bitboard, Position};
+    ///
+    /// assert!(Bitboard::corners().contains(Position::H8));
+    /// assert!(!Bitboard::corners().contains(Position::G7));
+    /// ```
+    #[must_use]
+    pub const fn contains(self, pos: Position) -> bool {
+        self.0 & pos.0 != 0
+    }
+
     /// Retrieves the underlying u64.
     ///
     /// # Examples
@@ -28,7 +155,7 @@ impl Bitboard {
     /// assert_eq!(b.raw(), 0);
     /// ```
     #[must_use]
-    pub fn raw(self) -> u64 {
+    pub const fn raw(self) -> u64 {
         self.0
     }
 
@@ -42,7 +169,7 @@ impl Bitboard {
     /// assert!(b.is_empty());
     /// ```
     #[must_use]
-    pub fn is_empty(self) -> bool {
+    pub const fn is_empty(self) -> bool {
         self.0 == 0
     }
 
@@ -57,7 +184,7 @@ impl Bitboard {
     /// ```
     #[must_use]
     #[allow(clippy::cast_possible_truncation)]
-    pub fn count_set(self) -> u8 {
+    pub const fn count_set(self) -> u8 {
         self.0.count_ones() as u8
     }
 
@@ -71,8 +198,85 @@ impl Bitboard {
     /// assert_eq!(b.count_empty(), 0);
     /// ```
     #[must_use]
-    pub fn count_empty(self) -> u8 {
-        self.0.count_zeros().try_into().unwrap()
+    #[allow(clippy::cast_possible_truncation)]
+    pub const fn count_empty(self) -> u8 {
+        self.0.count_zeros() as u8
+    }
+
+    /// Constructs a bitboard from its ranks, as returned by [`ranks`].
+    ///
+    /// [`ranks`]: crate::othello::Bitboard::ranks
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::Bitboard;
+    ///
+    /// let mut ranks = Bitboard::corners().ranks();
+    /// ranks.reverse();
+    /// // The corners are symmetric, so mirroring them changes nothing
+    /// assert_eq!(Bitboard::corners(), Bitboard::from_ranks(ranks));
+    /// ```
+    #[must_use]
+    pub const fn from_ranks(ranks: [u8; 8]) -> Bitboard {
+        Bitboard(u64::from_be_bytes(ranks))
+    }
+
+    /// Returns the eight ranks of the bitboard as bytes, from rank 1 to
+    /// rank 8.
+    ///
+    /// The most significant bit of every byte is the A file, as described
+    /// in the [layout] of bitboards.
+    ///
+    /// [layout]: crate::othello::Bitboard#layout
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Bitboard, Position};
+    ///
+    /// let ranks = Bitboard::from(Position::C2).ranks();
+    /// assert_eq!([0, 0b0010_0000, 0, 0, 0, 0, 0, 0], ranks);
+    /// ```
+    #[must_use]
+    pub const fn ranks(self) -> [u8; 8] {
+        self.0.to_be_bytes()
+    }
+
+    /// Returns the eight files of the bitboard as bytes, from the A file to
+    /// the H file.
+    ///
+    /// The most significant bit of every byte is rank 1, so the files are
+    /// the [`ranks`] of the bitboard mirrored along its diagonal.
+    ///
+    /// [`ranks`]: crate::othello::Bitboard::ranks
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Bitboard, Position};
+    ///
+    /// let files = Bitboard::from(Position::C2).files();
+    /// assert_eq!([0, 0, 0b0100_0000, 0, 0, 0, 0, 0], files);
+    /// ```
+    #[must_use]
+    pub fn files(self) -> [u8; 8] {
+        self.mirror_diagonal().ranks()
+    }
+
+    /// Returns an iterator over the index of every rank, starting at zero
+    /// for rank 1, and the rank as a byte.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::Bitboard;
+    ///
+    /// let occupied: Vec<u8> = Bitboard::rank(2)
+    ///     .bytes()
+    ///     .filter(|(_, byte)| *byte != 0)
+    ///     .map(|(rank, _)| rank)
+    ///     .collect();
+    /// assert_eq!(vec![2], occupied);
+    /// ```
+    pub fn bytes(self) -> impl ExactSizeIterator<Item = (u8, u8)> {
+        (0..8).zip(self.ranks())
     }
 
     /// Extracts each bit as its own bitboard.
@@ -138,6 +342,162 @@ impl Bitboard {
         positions.into_iter()
     }
 
+    /// Returns every square from the specified position to the edge of the
+    /// board in the specified direction.
+    ///
+    /// The position itself is not part of the ray.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Bitboard, Direction, Position};
+    ///
+    /// let c3 = Position::try_from("c3").unwrap();
+    /// let ray = Bitboard::ray(c3, Direction::NorthWest);
+    /// let expected = Position::try_from("b2").unwrap() | Position::try_from("a1").unwrap();
+    /// assert_eq!(expected, ray);
+    ///
+    /// let h1 = Position::try_from("h1").unwrap();
+    /// assert!(Bitboard::ray(h1, Direction::East).is_empty());
+    /// ```
+    #[must_use]
+    pub fn ray(pos: Position, direction: Direction) -> Bitboard {
+        Bitboard(SHIFT_RAYS[pos.raw().leading_zeros() as usize][direction.index()])
+    }
+
+    /// Returns every square on the specified zero-indexed file, where file
+    /// 0 is the A file.
+    ///
+    /// # Panics
+    /// Panics if the file is not between 0 and 7.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Bitboard, Position};
+    ///
+    /// let c_file = Bitboard::file(2);
+    /// assert_eq!(8, c_file.count_set());
+    /// assert!(c_file & Position::try_from("c5").unwrap() != 0);
+    /// ```
+    #[must_use]
+    pub const fn file(file: u8) -> Bitboard {
+        assert!(file < 8, "file must be between 0 and 7");
+        Bitboard(FILES[file as usize])
+    }
+
+    /// Returns every square on the specified zero-indexed rank, where rank
+    /// 0 is the first rank.
+    ///
+    /// # Panics
+    /// Panics if the rank is not between 0 and 7.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Bitboard, Position};
+    ///
+    /// let fifth_rank = Bitboard::rank(4);
+    /// assert_eq!(8, fifth_rank.count_set());
+    /// assert!(fifth_rank & Position::try_from("c5").unwrap() != 0);
+    /// ```
+    #[must_use]
+    pub const fn rank(rank: u8) -> Bitboard {
+        assert!(rank < 8, "rank must be between 0 and 7");
+        Bitboard(RANKS[rank as usize])
+    }
+
+    /// Returns a diagonal parallel to the one from A1 to H8.
+    ///
+    /// The diagonal contains every square where the zero-indexed file minus
+    /// the zero-indexed rank equals `offset`. Offset 0 is the diagonal from
+    /// A1 to H8, positive offsets lie towards H1 and negative offsets lie
+    /// towards A8.
+    ///
+    /// # Panics
+    /// Panics if the offset is not between -7 and 7.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Bitboard, Position};
+    ///
+    /// assert_eq!(8, Bitboard::diagonal(0).count_set());
+    ///
+    /// let g1 = Position::try_from("g1").unwrap();
+    /// let h2 = Position::try_from("h2").unwrap();
+    /// assert_eq!(g1 | h2, Bitboard::diagonal(6));
+    /// ```
+    #[must_use]
+    pub const fn diagonal(offset: i8) -> Bitboard {
+        Bitboard(DIAGONALS[diagonal_index(offset)])
+    }
+
+    /// Returns a diagonal parallel to the one from H1 to A8.
+    ///
+    /// The diagonal contains every square where the zero-indexed file plus
+    /// the zero-indexed rank equals `7 + offset`. Offset 0 is the diagonal
+    /// from H1 to A8, positive offsets lie towards H8 and negative offsets
+    /// lie towards A1.
+    ///
+    /// # Panics
+    /// Panics if the offset is not between -7 and 7.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Bitboard, Position};
+    ///
+    /// assert_eq!(8, Bitboard::anti_diagonal(0).count_set());
+    ///
+    /// let a1 = Position::try_from("a1").unwrap();
+    /// assert_eq!(Bitboard::from(a1), Bitboard::anti_diagonal(-7));
+    /// ```
+    #[must_use]
+    pub const fn anti_diagonal(offset: i8) -> Bitboard {
+        Bitboard(ANTI_DIAGONALS[diagonal_index(offset)])
+    }
+
+    /// Returns the 28 squares along the edges of the board, corners
+    /// included.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::Bitboard;
+    ///
+    /// assert_eq!(28, Bitboard::edges().count_set());
+    /// assert_eq!(Bitboard::corners(), Bitboard::edges() & Bitboard::corners());
+    /// ```
+    #[must_use]
+    pub const fn edges() -> Bitboard {
+        Bitboard(EDGES)
+    }
+
+    /// Returns the four corners of the board.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Bitboard, Position};
+    ///
+    /// let corners = Bitboard::corners();
+    /// assert_eq!(4, corners.count_set());
+    /// assert!(corners & Position::try_from("h8").unwrap() != 0);
+    /// ```
+    #[must_use]
+    pub const fn corners() -> Bitboard {
+        Bitboard(CORNERS)
+    }
+
+    /// Returns the four center squares, which are occupied from the start
+    /// of the game.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Bitboard, Board};
+    ///
+    /// let board = Board::standard();
+    /// assert_eq!(Bitboard::center4(), !board.empty_squares());
+    /// ```
+    #[must_use]
+    pub const fn center4() -> Bitboard {
+        Bitboard(CENTER)
+    }
+
     // Bitboard format is:
     // a1, b1, c1, d1, e1, f1, g1, h1
     // a2, b2, c2, d2, e2, f2, g2, h2
@@ -157,17 +517,17 @@ impl Bitboard {
 
     pub fn ccw(&self) -> Self {
         let mut out = 0u64;
-        for row in 0..8 {
+        for (row, table) in CCW_ROTATION_TABLE.iter().enumerate() {
             let byte = ((self.0 >> (row * 8)) & 0xFF) as usize;
-            out |= CCW_ROTATION_TABLE[row][byte];
+            out |= table[byte];
         }
         Bitboard(out)
     }
     pub fn cw(&self) -> Self {
         let mut out = 0u64;
-        for row in 0..8 {
+        for (row, table) in CW_ROTATION_TABLE.iter().enumerate() {
             let byte = ((self.0 >> (row * 8)) & 0xFF) as usize;
-            out |= CW_ROTATION_TABLE[row][byte];
+            out |= table[byte];
         }
         Bitboard(out)
     }
@@ -180,21 +540,90 @@ impl Bitboard {
     pub fn rotations(&self) -> (Self, Self, Self) {
         (self.ccw(), self.flip180(), self.cw())
     }
-}
 
-impl std::fmt::Display for Bitboard {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        for rank in (0..8).rev() {
-            for file in 0..8 {
-                let pos = rank * 8 + file;
-                let mask = 1u64 << (63 - pos);
-                let ch = if self.0 & mask != 0 { '1' } else { '.' };
-                write!(f, "{} ", ch)?;
-            }
-            writeln!(f)?;
-        }
-        Ok(())
+    /// Mirrors the bitboard horizontally, exchanging the A and H files.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Bitboard, Position};
+    ///
+    /// let a1 = Bitboard::from(Position::try_from("a1").unwrap());
+    /// let h1 = Bitboard::from(Position::try_from("h1").unwrap());
+    /// assert_eq!(h1, a1.mirror_horizontal());
+    /// ```
+    #[must_use]
+    pub fn mirror_horizontal(self) -> Self {
+        Bitboard(self.0.swap_bytes().reverse_bits())
     }
+
+    /// Mirrors the bitboard vertically, exchanging the first and eighth
+    /// ranks.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Bitboard, Position};
+    ///
+    /// let a1 = Bitboard::from(Position::try_from("a1").unwrap());
+    /// let a8 = Bitboard::from(Position::try_from("a8").unwrap());
+    /// assert_eq!(a8, a1.mirror_vertical());
+    /// ```
+    #[must_use]
+    pub fn mirror_vertical(self) -> Self {
+        Bitboard(self.0.swap_bytes())
+    }
+
+    /// Mirrors the bitboard along the diagonal from A1 to H8.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Bitboard, Position};
+    ///
+    /// let b1 = Bitboard::from(Position::try_from("b1").unwrap());
+    /// let a2 = Bitboard::from(Position::try_from("a2").unwrap());
+    /// assert_eq!(a2, b1.mirror_diagonal());
+    /// ```
+    #[must_use]
+    pub fn mirror_diagonal(self) -> Self {
+        let mut x = self.0;
+        let mut t = 0x0F_0F_0F_0F_00_00_00_00 & (x ^ (x << 28));
+        x ^= t ^ (t >> 28);
+        t = 0x33_33_00_00_33_33_00_00 & (x ^ (x << 14));
+        x ^= t ^ (t >> 14);
+        t = 0x55_00_55_00_55_00_55_00 & (x ^ (x << 7));
+        x ^= t ^ (t >> 7);
+        Bitboard(x)
+    }
+
+    /// Mirrors the bitboard along the diagonal from H1 to A8.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Bitboard, Position};
+    ///
+    /// let a1 = Bitboard::from(Position::try_from("a1").unwrap());
+    /// let h8 = Bitboard::from(Position::try_from("h8").unwrap());
+    /// assert_eq!(h8, a1.mirror_antidiagonal());
+    /// ```
+    #[must_use]
+    pub fn mirror_antidiagonal(self) -> Self {
+        let mut x = self.0;
+        let mut t = x ^ (x << 36);
+        x ^= 0xF0_F0_F0_F0_0F_0F_0F_0F & (t ^ (x >> 36));
+        t = 0xCC_CC_00_00_CC_CC_00_00 & (x ^ (x << 18));
+        x ^= t ^ (t >> 18);
+        t = 0xAA_00_AA_00_AA_00_AA_00 & (x ^ (x << 9));
+        x ^= t ^ (t >> 9);
+        Bitboard(x)
+    }
+}
+
+/// Converts a diagonal offset into an index into the diagonal tables.
+const fn diagonal_index(offset: i8) -> usize {
+    assert!(
+        matches!(offset, -7..=7),
+        "diagonal offset must be between -7 and 7"
+    );
+    (offset + 7).unsigned_abs() as usize
 }
 
 #[cfg(test)]
@@ -1,5 +1,5 @@
 use crate::othello::{Bitboard, Position};
-use std::{
+use core::{
     cmp::Ordering,
     hash::{Hash, Hasher},
     ops::{
@@ -44,6 +44,19 @@ macro_rules! newtype_transform_with_number {
     };
 }
 
+macro_rules! newtype_transform_with_number_into {
+    (impl $trait: ident <$num_type: ident> for $self_type: ident { fn $method: ident -> $return_type: ident }) => {
+        impl $trait<$num_type> for $self_type {
+            type Output = $return_type;
+
+            fn $method(self, b: $num_type) -> Self::Output {
+                let $self_type(a) = self;
+                $return_type(a.$method(&b))
+            }
+        }
+    };
+}
+
 macro_rules! newtype_mutate_with_number {
     (impl $trait: ident <$num_type: ident> for $self_type: ident { fn $method: ident }) => {
         impl $trait<$num_type> for $self_type {
@@ -70,6 +83,58 @@ macro_rules! newtype_bitshift {
     )*)
 }
 
+// Mirrors the reference impls of the primitive operators, so that
+// `&a | &b` works the same for bitboards as it does for integers
+macro_rules! forward_ref_transform {
+    (impl $trait: ident <$other_type: ident> for $self_type: ident { fn $method: ident }) => {
+        impl $trait<$other_type> for &$self_type {
+            type Output = <$self_type as $trait<$other_type>>::Output;
+
+            fn $method(self, b: $other_type) -> Self::Output {
+                $trait::$method(*self, b)
+            }
+        }
+
+        impl $trait<&$other_type> for $self_type {
+            type Output = <$self_type as $trait<$other_type>>::Output;
+
+            fn $method(self, b: &$other_type) -> Self::Output {
+                $trait::$method(self, *b)
+            }
+        }
+
+        impl $trait<&$other_type> for &$self_type {
+            type Output = <$self_type as $trait<$other_type>>::Output;
+
+            fn $method(self, b: &$other_type) -> Self::Output {
+                $trait::$method(*self, *b)
+            }
+        }
+    };
+}
+
+macro_rules! forward_ref_mutate {
+    (impl $trait: ident <$other_type: ident> for $self_type: ident { fn $method: ident }) => {
+        impl $trait<&$other_type> for $self_type {
+            fn $method(&mut self, b: &$other_type) {
+                $trait::$method(self, *b)
+            }
+        }
+    };
+}
+
+macro_rules! forward_ref_bitwise {
+    ($($t:ident)*) => ($(
+        forward_ref_transform! {impl BitAnd<$t> for Bitboard { fn bitand }}
+        forward_ref_transform! {impl BitOr<$t> for Bitboard { fn bitor }}
+        forward_ref_transform! {impl BitXor<$t> for Bitboard { fn bitxor }}
+
+        forward_ref_mutate! {impl BitAndAssign<$t> for Bitboard { fn bitand_assign }}
+        forward_ref_mutate! {impl BitOrAssign<$t> for Bitboard { fn bitor_assign }}
+        forward_ref_mutate! {impl BitXorAssign<$t> for Bitboard { fn bitxor_assign }}
+    )*)
+}
+
 macro_rules! number_transform_with_newtype {
     (impl $trait: ident <$new_type: ident> for $num_type: ident { fn $method: ident }) => {
         impl $trait<$new_type> for $num_type {
@@ -154,7 +219,12 @@ macro_rules! newtype_ord_and_eq {
         partial_for_newtype_with_number!{impl PartialEq<u64> for $t {fn eq -> bool}}
         partial_for_number_with_newtype!{impl PartialEq<$t> for u64 {fn eq -> bool}}
 
-        partial_for_newtype!{impl PartialOrd<$t> for $t {fn partial_cmp -> Option<Ordering>}}
+        impl PartialOrd for $t {
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
         partial_for_newtype_with_number!{impl PartialOrd<u64> for $t {fn partial_cmp -> Option<Ordering>}}
         partial_for_number_with_newtype!{impl PartialOrd<$t> for u64 {fn partial_cmp -> Option<Ordering>}}
     )*)
@@ -177,6 +247,8 @@ newtype_mutate! { impl BitXorAssign<Bitboard> for Bitboard { fn bitxor_assign }
 
 // Position operations
 newtype_transform! { impl BitAnd<Position> for Position { fn bitand -> Position } }
+newtype_transform! { impl BitOr<Position> for Position { fn bitor -> Bitboard } }
+newtype_transform! { impl BitXor<Position> for Position { fn bitxor -> Bitboard } }
 
 // Bitboard and Position operations
 newtype_transform! { impl BitAnd<Bitboard> for Position { fn bitand -> Bitboard } }
@@ -208,9 +280,31 @@ newtype_mutate_with_number! {impl BitAndAssign<u64> for Bitboard { fn bitand_ass
 newtype_mutate_with_number! {impl BitOrAssign<u64> for Bitboard { fn bitor_assign }}
 newtype_mutate_with_number! {impl BitXorAssign<u64> for Bitboard { fn bitxor_assign }}
 
+// Position and u64
+newtype_transform_with_number_into! {impl BitAnd<u64> for Position { fn bitand -> Bitboard }}
+newtype_transform_with_number_into! {impl BitOr<u64> for Position { fn bitor -> Bitboard }}
+newtype_transform_with_number_into! {impl BitXor<u64> for Position { fn bitxor -> Bitboard }}
+
+// References to bitboards
+forward_ref_bitwise! {Bitboard Position u64}
+
 impl Not for Bitboard {
     type Output = Self;
     fn not(self) -> Self::Output {
         Self(!self.0)
     }
 }
+
+impl Not for &Bitboard {
+    type Output = Bitboard;
+    fn not(self) -> Self::Output {
+        Bitboard(!self.0)
+    }
+}
+
+impl Not for Position {
+    type Output = Bitboard;
+    fn not(self) -> Self::Output {
+        Bitboard(!self.0)
+    }
+}
@@ -1,10 +1,12 @@
 use crate::othello::{
-    Bitboard, Position, Stone,
+    Bitboard, Direction, Kernel, Position, PositionError, PositionStrError, Square, Stone,
+    Symmetry,
     constants::{
         BLACK_START_POS, FILE_A, FILE_H, RANK_1, RANK_8, SHIFT_DIRS, SHIFT_MASKS, SHIFT_RAYS,
         WHITE_START_POS,
     },
     display::BoardDisplay,
+    kernel,
 };
 
 #[cfg(feature = "serde")]
@@ -49,6 +51,37 @@ pub struct Board {
     white_stones: Bitboard,
 }
 
+/// The information required to take back a move made with [`Board::make`].
+///
+/// [`Board::make`]: crate::othello::Board::make
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+#[must_use = "a move cannot be taken back without its undo information"]
+pub struct Undo {
+    stone: Stone,
+    pos: Position,
+    flips: Bitboard,
+}
+
+impl Undo {
+    /// Returns the stone of the player who made the move.
+    #[must_use]
+    pub fn stone(&self) -> Stone {
+        self.stone
+    }
+
+    /// Returns the position the stone was placed in.
+    #[must_use]
+    pub fn pos(&self) -> Position {
+        self.pos
+    }
+
+    /// Returns the stones that were flipped by the move.
+    #[must_use]
+    pub fn flips(&self) -> Bitboard {
+        self.flips
+    }
+}
+
 impl Board {
     /// Returns a completely empty board.
     ///
@@ -104,6 +137,24 @@ impl Board {
         }
     }
 
+    /// Returns a board built from the two specified bitboards.
+    ///
+    /// Returns an error if the two bitboards intersect. This is equivalent
+    /// to the `TryFrom` implementations, but easier to discover.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Bitboard, Board, OthelloError};
+    ///
+    /// let black = Bitboard::from(0x00_00_00_08_10_00_00_00);
+    /// let white = Bitboard::from(0x00_00_00_10_08_00_00_00);
+    /// assert_eq!(Ok(Board::standard()), Board::try_new(black, white));
+    /// assert_eq!(Err(OthelloError::PiecesOverlapping), Board::try_new(black, black));
+    /// ```
+    pub fn try_new(black: Bitboard, white: Bitboard) -> Result<Self, OthelloError> {
+        Board::try_from((black, white))
+    }
+
     /// Evaluates if the board is in a consistent state
     ///
     /// Consistency is defined as whether or not multiple stones occupy
@@ -183,6 +234,30 @@ impl Board {
         }
     }
 
+    /// Checks that the board is valid in debug builds, and does nothing in
+    /// release builds.
+    ///
+    /// Every mutation of a board during play is followed by this check, so
+    /// that bugs such as playing on occupied squares are caught close to
+    /// their source during development.
+    ///
+    /// # Panics
+    /// Panics in debug builds if any stones overlap.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::Board;
+    ///
+    /// Board::standard().debug_validate();
+    /// ```
+    pub fn debug_validate(&self) {
+        debug_assert!(
+            self.is_valid(),
+            "black and white stones overlap: {:#018x}",
+            (self.black_stones & self.white_stones).raw()
+        );
+    }
+
     /// Places a stone in the specified position and updates the board accordingly.
     ///
     /// It is the responsibility of the caller to ensure that the move is legal.
@@ -203,38 +278,233 @@ impl Board {
     /// assert_ne!(board, Board::standard());
     /// ```
     pub fn play(&mut self, stone: Stone, pos: Position) {
-        let pos: Bitboard = pos.into();
-        let current_bits = self.bits_for(stone);
-        let opponent_bits = self.bits_for(stone.flip());
+        let flips = self.flips_for(stone, pos);
+        self.play_with_flips(stone, pos, flips);
+    }
 
-        let mut mask = 0;
-        for (i, shift) in SHIFT_DIRS.iter().enumerate() {
-            let mut dir_mask = 0;
-            let shift_mask = SHIFT_MASKS[i] & SHIFT_RAYS[pos.raw().leading_zeros() as usize][i];
-            let opponent_bits = opponent_bits & shift_mask;
+    /// Places a stone in the specified position and flips the captured
+    /// stones, after checking that the move is legal.
+    ///
+    /// Returns the flipped stones, or an error if the move is illegal, in
+    /// which case the board is left unchanged.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Board, OthelloError, Position, Stone};
+    ///
+    /// let mut board = Board::standard();
+    /// let f5 = Position::try_from("f5").unwrap();
+    /// assert_eq!(1, board.try_play(Stone::Black, f5).unwrap().count_set());
+    /// assert_eq!(Err(OthelloError::IllegalMove), board.try_play(Stone::Black, f5));
+    /// ```
+    pub fn try_play(&mut self, stone: Stone, pos: Position) -> Result<Bitboard, OthelloError> {
+        if !self.is_legal_move(stone, pos) {
+            return Err(OthelloError::IllegalMove);
+        }
+        let flips = self.flips_for(stone, pos);
+        self.play_with_flips(stone, pos, flips);
+        Ok(flips)
+    }
 
-            let mut current = pos;
-            let mut next = current;
-            while current != 0 {
-                dir_mask |= current;
-                next = dir_shift(current, *shift);
-                current = next & opponent_bits;
+    /// Places a stone in the specified position and flips the specified
+    /// stones, as previously calculated by [`flips_for`] or
+    /// [`moves_with_flips`].
+    ///
+    /// This avoids calculating the flips of a move twice. It is the
+    /// responsibility of the caller to ensure that the move is legal and
+    /// that the flips belong to it.
+    ///
+    /// [`flips_for`]: crate::othello::Board::flips_for
+    /// [`moves_with_flips`]: crate::othello::Board::moves_with_flips
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Board, Stone};
+    ///
+    /// let board = Board::standard();
+    /// for (pos, flips) in board.moves_with_flips(Stone::Black) {
+    ///     let mut next = board;
+    ///     next.play_with_flips(Stone::Black, pos, flips);
+    ///
+    ///     let mut expected = board;
+    ///     expected.play(Stone::Black, pos);
+    ///     assert_eq!(expected, next);
+    /// }
+    /// ```
+    pub fn play_with_flips(&mut self, stone: Stone, pos: Position, flips: Bitboard) {
+        let pos: Bitboard = pos.into();
+
+        match stone {
+            Stone::Black => {
+                self.black_stones |= flips | pos;
+                self.white_stones ^= flips;
             }
-            if next & current_bits != 0 {
-                mask |= dir_mask ^ pos;
+            Stone::White => {
+                self.white_stones |= flips | pos;
+                self.black_stones ^= flips;
             }
         }
+        self.debug_validate();
+    }
 
-        match stone {
+    /// Places a stone in the specified position like [`play`], returning
+    /// what is needed to take the move back with [`unmake`].
+    ///
+    /// Making and unmaking moves modifies a single board in place, which
+    /// lets deep searches avoid copying the board at every node.
+    ///
+    /// It is the responsibility of the caller to ensure that the move is
+    /// legal, and that moves are unmade in the reverse order they were made.
+    ///
+    /// [`play`]: crate::othello::Board::play
+    /// [`unmake`]: crate::othello::Board::unmake
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Board, Position, Stone};
+    ///
+    /// let mut board = Board::standard();
+    /// let f5 = Position::try_from("f5").unwrap();
+    /// let undo = board.make(Stone::Black, f5);
+    /// assert_eq!(1, undo.flips().count_set());
+    /// assert_eq!((4, 1), board.count_stones());
+    ///
+    /// board.unmake(undo);
+    /// assert_eq!(Board::standard(), board);
+    /// ```
+    pub fn make(&mut self, stone: Stone, pos: Position) -> Undo {
+        let flips = self.flips_for(stone, pos);
+        self.play_with_flips(stone, pos, flips);
+        Undo { stone, pos, flips }
+    }
+
+    /// Takes back a move made with [`make`].
+    ///
+    /// See [`make`] for an example.
+    ///
+    /// [`make`]: crate::othello::Board::make
+    pub fn unmake(&mut self, undo: Undo) {
+        let placed: Bitboard = undo.pos.into();
+        match undo.stone {
             Stone::Black => {
-                self.black_stones |= mask | pos;
-                self.white_stones ^= mask;
+                self.black_stones ^= undo.flips | placed;
+                self.white_stones |= undo.flips;
             }
             Stone::White => {
-                self.white_stones |= mask | pos;
-                self.black_stones ^= mask;
+                self.white_stones ^= undo.flips | placed;
+                self.black_stones |= undo.flips;
             }
         }
+        self.debug_validate();
+    }
+
+    /// Returns the stones that would be flipped if the specified player
+    /// placed a stone in the specified position, without modifying the
+    /// board.
+    ///
+    /// The placed stone itself is not included. An empty bitboard is returned
+    /// if the move would not flip anything, which is the case for all
+    /// illegal moves on empty squares.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Board, Position, Stone};
+    ///
+    /// let board = Board::standard();
+    /// let d3 = Position::try_from("d3").unwrap();
+    /// let d4 = Position::try_from("d4").unwrap();
+    /// assert_eq!(d4, board.flips_for(Stone::Black, d3));
+    ///
+    /// let a1 = Position::try_from("a1").unwrap();
+    /// assert!(board.flips_for(Stone::Black, a1).is_empty());
+    /// ```
+    ///
+    /// The flips are calculated by the [current] [`Kernel`].
+    ///
+    /// [current]: crate::othello::Kernel::current
+    /// [`Kernel`]: crate::othello::Kernel
+    #[must_use]
+    #[inline]
+    pub fn flips_for(&self, stone: Stone, pos: Position) -> Bitboard {
+        self.flips_with(Kernel::current(), stone, pos)
+    }
+
+    /// Returns the stones that would be flipped if the specified player
+    /// placed a stone in the specified position, calculated by the
+    /// specified kernel.
+    ///
+    /// Kernels the CPU does not support fall back to the Kogge-Stone
+    /// kernel.
+    ///
+    /// See [`Kernel`] for an example.
+    ///
+    /// [`Kernel`]: crate::othello::Kernel
+    #[must_use]
+    #[inline]
+    pub fn flips_with(&self, kernel: Kernel, stone: Stone, pos: Position) -> Bitboard {
+        let own = self.bits_for(stone).raw();
+        let opponent = self.bits_for(stone.flip()).raw();
+        kernel::flips(kernel, own, opponent, pos.raw()).into()
+    }
+
+    /// Calculates and returns the set of all legal moves for the specified
+    /// player with the specified kernel.
+    ///
+    /// The rays kernel shifts one square at a time in every direction, as
+    /// [`moves_for`] does. The other kernels fill every direction with a
+    /// Kogge-Stone parallel prefix, which takes three steps instead of six.
+    /// The BMI2 kernel only has flips of its own and shares this move
+    /// generator with the Kogge-Stone kernel.
+    ///
+    /// [`moves_for`]: crate::othello::Board::moves_for
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Board, Kernel, Stone};
+    ///
+    /// let board = Board::standard();
+    /// for kernel in Kernel::ALL {
+    ///     assert_eq!(board.moves_for(Stone::Black), board.moves_with(kernel, Stone::Black));
+    /// }
+    /// ```
+    #[must_use]
+    pub fn moves_with(&self, kernel: Kernel, stone: Stone) -> Bitboard {
+        match kernel {
+            Kernel::Rays => self.moves_for(stone),
+            Kernel::KoggeStone | Kernel::Bmi2 => raw_moves(
+                self.bits_for(stone).raw(),
+                self.bits_for(stone.flip()).raw(),
+            )
+            .into(),
+        }
+    }
+
+    /// Returns every legal move of the specified player along with the
+    /// stones it would flip.
+    ///
+    /// The flips are calculated lazily, one move at a time, in the same
+    /// order as [`hot_bits`] yields the legal moves. Together with
+    /// [`play_with_flips`], this avoids calculating the flips of a move
+    /// twice, such as when moves are ordered by the number of flips before
+    /// they are played.
+    ///
+    /// [`hot_bits`]: crate::othello::Bitboard::hot_bits
+    /// [`play_with_flips`]: crate::othello::Board::play_with_flips
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Board, Stone};
+    ///
+    /// let board = Board::standard();
+    /// let moves: Vec<_> = board.moves_with_flips(Stone::Black).collect();
+    /// assert_eq!(4, moves.len());
+    /// assert!(moves.iter().all(|(_, flips)| flips.count_set() == 1));
+    /// ```
+    pub fn moves_with_flips(&self, stone: Stone) -> impl Iterator<Item = (Position, Bitboard)> {
+        let board = *self;
+        self.moves_for(stone)
+            .hot_bits()
+            .map(move |pos| (pos, board.flips_for(stone, pos)))
     }
 
     /// Returns the bitboard representation of the specified player.
@@ -339,6 +609,66 @@ impl Board {
         moves | move_in_dir(exclude_left_right, -9)
     }
 
+    /// Calculates and returns the set of all legal moves for the specified
+    /// player by sliding along the precomputed rays of the player's stones.
+    ///
+    /// This is equivalent to [`moves_for`], but instead of shifting all
+    /// stones one square at a time, every ray from a stone of the player to
+    /// the edge of the board is cut at the first square that is not an
+    /// opponent stone with a single bit scan. That square is a legal move if
+    /// it is empty and not adjacent to the stone. The work grows with the
+    /// number of stones of the player rather than with the length of the
+    /// longest line, so this generator beats [`moves_for`] when the player
+    /// has few stones, such as in the opening, and falls several times
+    /// behind it on crowded boards, as the `legal_moves_fast` benchmark
+    /// shows.
+    ///
+    /// [`moves_for`]: crate::othello::Board::moves_for
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Board, Stone};
+    ///
+    /// let board = Board::standard();
+    /// let stone = Stone::Black;
+    /// assert_eq!(board.moves_for(stone), board.legal_moves_fast(stone));
+    /// ```
+    #[must_use]
+    pub fn legal_moves_fast(&self, stone: Stone) -> Bitboard {
+        ray_moves(
+            self.bits_for(stone).raw(),
+            self.bits_for(stone.flip()).raw(),
+        )
+        .into()
+    }
+
+    /// Returns the number of legal moves for the specified player.
+    ///
+    /// This is equivalent to counting the moves returned by [`moves_for`],
+    /// but stays on raw integers from the generation of the moves to the
+    /// population count, which makes it the cheapest way for evaluation
+    /// functions to measure mobility.
+    ///
+    /// [`moves_for`]: crate::othello::Board::moves_for
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Board, Stone};
+    ///
+    /// let board = Board::standard();
+    /// assert_eq!(4, board.mobility(Stone::Black));
+    /// assert_eq!(board.moves_for(Stone::White).count_set(), board.mobility(Stone::White));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn mobility(&self, stone: Stone) -> u8 {
+        raw_moves(
+            self.bits_for(stone).raw(),
+            self.bits_for(stone.flip()).raw(),
+        )
+        .count_ones() as u8
+    }
+
     /// Returns the set of all empty squares on the board.
     ///
     /// # Examples
@@ -353,6 +683,192 @@ impl Board {
         !(self.black_stones | self.white_stones)
     }
 
+    /// Returns how far the game has progressed, as the number of stones
+    /// placed since the standard opening position, from 0 to 60.
+    ///
+    /// Evaluation functions use the stage to weigh features differently in
+    /// the opening, the midgame and the endgame. Boards with fewer than four
+    /// stones are at stage 0.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Board, Stone};
+    ///
+    /// let mut board = Board::standard();
+    /// assert_eq!(0, board.stage());
+    /// board.play(Stone::Black, "f5".parse().unwrap());
+    /// assert_eq!(1, board.stage());
+    /// ```
+    #[must_use]
+    pub fn stage(&self) -> u8 {
+        (64 - self.empty_squares().count_set()).saturating_sub(4)
+    }
+
+    /// Returns the stones of the specified player that are adjacent to at
+    /// least one empty square.
+    ///
+    /// Frontier stones are easy for the opponent to flip and tend to open up
+    /// new moves for them, so fewer frontier stones are usually better.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Board, Stone};
+    ///
+    /// let board = Board::standard();
+    /// assert_eq!(board.bits_for(Stone::Black), board.frontier(Stone::Black));
+    /// ```
+    #[must_use]
+    pub fn frontier(&self, stone: Stone) -> Bitboard {
+        self.bits_for(stone) & neighbours(self.empty_squares().raw())
+    }
+
+    /// Counts the empty squares adjacent to at least one of the opponent's
+    /// stones.
+    ///
+    /// This is an estimate of how many moves the specified player may have
+    /// in the future, as every move has to be placed next to an opposing
+    /// stone.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Board, Stone};
+    ///
+    /// let board = Board::standard();
+    /// assert_eq!(10, board.potential_mobility(Stone::Black));
+    /// ```
+    #[must_use]
+    pub fn potential_mobility(&self, stone: Stone) -> u8 {
+        (self.empty_squares() & neighbours(self.bits_for(stone.flip()).raw())).count_set()
+    }
+
+    /// Splits the empty squares into connected regions.
+    ///
+    /// Two empty squares belong to the same region if they are adjacent,
+    /// diagonals included. Regions are yielded in order of their first
+    /// square, starting from A1.
+    ///
+    /// Towards the end of the game, the parity of each region matters a lot:
+    /// the player who fills the last square of a region often gains an edge,
+    /// so it is usually better to move in regions with an odd number of
+    /// empty squares.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::Board;
+    ///
+    /// let board = Board::standard();
+    /// assert_eq!(1, board.empty_regions().count());
+    ///
+    /// // Only H4 and H8 are empty, and they are far apart
+    /// let board = Board::try_from((0xFF_FF_FF_FE_00_00_00_00, 0x00_00_00_00_FF_FF_FF_FE)).unwrap();
+    /// let regions: Vec<_> = board.empty_regions().collect();
+    /// assert_eq!(2, regions.len());
+    /// assert_eq!([1, 1], [regions[0].count_set() % 2, regions[1].count_set() % 2]);
+    /// ```
+    pub fn empty_regions(&self) -> impl Iterator<Item = Bitboard> {
+        let mut remaining = self.empty_squares().raw();
+        core::iter::from_fn(move || {
+            if remaining == 0 {
+                return None;
+            }
+            let mut region = 1 << (63 - remaining.leading_zeros());
+            loop {
+                let next = (region | neighbours(region)) & remaining;
+                if next == region {
+                    break;
+                }
+                region = next;
+            }
+            remaining ^= region;
+            Some(Bitboard(region))
+        })
+    }
+
+    /// Returns the empty squares that belong to regions with an odd number
+    /// of empty squares.
+    ///
+    /// See [`empty_regions`] for how regions are formed.
+    ///
+    /// [`empty_regions`]: crate::othello::Board::empty_regions
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::Board;
+    ///
+    /// // Only A8 and H8 are empty
+    /// let board = Board::try_from((0xFF_FF_FF_FF_00_00_00_00, 0x00_00_00_00_FF_FF_FF_7E)).unwrap();
+    /// assert_eq!(0x00_00_00_00_00_00_00_81, board.odd_regions());
+    /// ```
+    #[must_use]
+    pub fn odd_regions(&self) -> Bitboard {
+        self.empty_regions()
+            .filter(|region| region.count_set() % 2 == 1)
+            .fold(Bitboard(0), |acc, region| acc | region)
+    }
+
+    /// Returns the stones of the specified player that can never be flipped
+    /// for the rest of the game.
+    ///
+    /// A stone is stable if it cannot be flipped along any of the four lines
+    /// passing through it. That is the case for a line if it contains no
+    /// empty squares, or if one of the neighbouring squares along the line is
+    /// either off the board or holds a stable stone of the same color.
+    /// Starting from the edges and the corners, stability is propagated until
+    /// no more stable stones are found.
+    ///
+    /// Every returned stone is guaranteed to be stable, but some stones that
+    /// are stable due to more complex patterns may be missing.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Board, Stone};
+    ///
+    /// let board = Board::standard();
+    /// assert!(board.stable_discs(Stone::Black).is_empty());
+    ///
+    /// // Black owns A1 and B1, while white owns C1 and A2
+    /// let board = Board::try_from((0xC0_00_00_00_00_00_00_00, 0x20_80_00_00_00_00_00_00)).unwrap();
+    /// assert_eq!(0xC0_00_00_00_00_00_00_00, board.stable_discs(Stone::Black));
+    /// // Black can still flip C1 from D1 and A2 from A3
+    /// assert!(board.stable_discs(Stone::White).is_empty());
+    /// ```
+    #[must_use]
+    pub fn stable_discs(&self, stone: Stone) -> Bitboard {
+        let current_bits = self.bits_for(stone).raw();
+        let empty_squares = self.empty_squares().raw();
+
+        // One direction for each of the four lines through a square
+        let lines = [
+            Direction::North,
+            Direction::NorthEast,
+            Direction::East,
+            Direction::SouthEast,
+        ];
+
+        // Stones on lines without empty squares cannot be flipped along them
+        let mut full_lines = [0; 4];
+        for pos in Bitboard(current_bits).hot_bits() {
+            for (full, direction) in full_lines.iter_mut().zip(lines) {
+                let line = Bitboard::ray(pos, direction) | Bitboard::ray(pos, direction.opposite());
+                if line & empty_squares == 0 {
+                    *full |= pos.raw();
+                }
+            }
+        }
+
+        let mut stable = 0;
+        loop {
+            let mut next = current_bits;
+            for (full, direction) in full_lines.iter().zip(lines) {
+                next &= full | anchored(stable, direction) | anchored(stable, direction.opposite());
+            }
+            if next == stable {
+                return Bitboard(stable);
+            }
+            stable = next;
+        }
+    }
+
     /// Queries the board at the specified position for the presence of a stone.
     ///
     /// # Examples
@@ -374,9 +890,61 @@ impl Board {
         }
     }
 
+    /// Returns the stone at the specified position, if any.
+    ///
+    /// Equivalent to [`stone_at`], for those used to indexing boards.
+    /// Indexing a board with a position returns the [`Square`] instead.
+    ///
+    /// [`stone_at`]: crate::othello::Board::stone_at
+    /// [`Square`]: crate::othello::Square
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Board, Position, Square, Stone};
+    ///
+    /// let board = Board::standard();
+    /// assert_eq!(Some(Stone::Black), board.get(Position::D5));
+    /// assert_eq!(None, board.get(Position::A1));
+    /// assert_eq!(Square::White, board[Position::D4]);
+    /// ```
+    #[must_use]
+    pub fn get(&self, pos: Position) -> Option<Stone> {
+        self.stone_at(pos)
+    }
+
+    /// Returns an iterator over every position of the board and the contents
+    /// of its square.
+    ///
+    /// Squares are visited rank by rank, from A1 to H8, which is the order
+    /// most text formats list them in.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Board, Position, Square};
+    ///
+    /// let board = Board::standard();
+    /// let text: String = board
+    ///     .squares()
+    ///     .map(|(_, square)| match square {
+    ///         Square::Empty => '-',
+    ///         Square::Black => 'X',
+    ///         Square::White => 'O',
+    ///     })
+    ///     .collect();
+    /// assert_eq!(&text[24..40], "---OX------XO---");
+    ///
+    /// let (pos, square) = board.squares().next().unwrap();
+    /// assert_eq!((Position::A1, Square::Empty), (pos, square));
+    /// ```
+    pub fn squares(&self) -> impl Iterator<Item = (Position, Square)> + '_ {
+        Bitboard::from(u64::MAX)
+            .hot_bits()
+            .map(|pos| (pos, self[pos]))
+    }
+
     /// Returns a struct that implements [`Display`] for customizing the display of Othello boards.
     ///
-    /// [`Display`]: std::fmt::Display
+    /// [`Display`]: core::fmt::Display
     ///
     /// # Examples
     /// ```rust
@@ -426,6 +994,53 @@ impl Board {
         }
     }
 
+    /// Returns the board rotated or reflected by the specified symmetry.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Board, Stone, Symmetry};
+    ///
+    /// let mut board = Board::standard();
+    /// board.play(Stone::Black, "d3".parse().unwrap());
+    /// let mut transformed = Board::standard().transform(Symmetry::MirrorDiagonal);
+    /// transformed.play(Stone::Black, "c4".parse().unwrap());
+    /// assert_eq!(board.transform(Symmetry::MirrorDiagonal), transformed);
+    /// ```
+    #[must_use]
+    pub fn transform(&self, symmetry: Symmetry) -> Self {
+        Self {
+            black_stones: self.black_stones.transform(symmetry),
+            white_stones: self.white_stones.transform(symmetry),
+        }
+    }
+
+    /// Returns the lexicographically smallest of the eight symmetric
+    /// variants of the board, along with the symmetry that produces it.
+    ///
+    /// Symmetric positions are equivalent in Othello, so the canonical board
+    /// is useful as a key in opening books and transposition tables. Boards
+    /// are compared by their black stones first and their white stones
+    /// second.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Board, Stone};
+    ///
+    /// let mut a = Board::standard();
+    /// a.play(Stone::Black, "f5".parse().unwrap());
+    /// let mut b = Board::standard();
+    /// b.play(Stone::Black, "c4".parse().unwrap());
+    /// assert_eq!(a.canonical().0, b.canonical().0);
+    /// ```
+    #[must_use]
+    pub fn canonical(&self) -> (Self, Symmetry) {
+        Symmetry::ALL
+            .iter()
+            .map(|symmetry| (self.transform(*symmetry), *symmetry))
+            .min_by_key(|(board, _)| (board.black_stones.raw(), board.white_stones.raw()))
+            .unwrap()
+    }
+
     /// Concats both u64 bitboards into a single u128. Black comes first, then white.
     pub fn concat(&self) -> u128 {
         (self.black_stones.raw() as u128) << 64 | (self.white_stones.raw() as u128)
@@ -440,7 +1055,7 @@ struct ShadowBoard {
 }
 
 #[cfg(feature = "serde")]
-impl std::convert::TryFrom<ShadowBoard> for Board {
+impl core::convert::TryFrom<ShadowBoard> for Board {
     type Error = &'static str;
 
     fn try_from(unchecked: ShadowBoard) -> Result<Self, Self::Error> {
@@ -472,6 +1087,83 @@ impl Default for Board {
     }
 }
 
+impl core::ops::Index<Position> for Board {
+    type Output = Square;
+
+    /// Returns the contents of the square at the specified position.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Board, Position, Square};
+    ///
+    /// let board = Board::standard();
+    /// assert_eq!(Square::Black, board[Position::E4]);
+    /// assert_eq!(Square::Empty, board[Position::H8]);
+    /// ```
+    fn index(&self, pos: Position) -> &Self::Output {
+        match self.stone_at(pos) {
+            Some(Stone::Black) => &Square::Black,
+            Some(Stone::White) => &Square::White,
+            None => &Square::Empty,
+        }
+    }
+}
+
+impl From<[[Square; 8]; 8]> for Board {
+    /// Returns a board built from an array of ranks, each holding the
+    /// squares of the files A to H.
+    ///
+    /// The first rank is rank 1, at the top of the board.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Board, Square};
+    ///
+    /// let mut squares = [[Square::Empty; 8]; 8];
+    /// squares[3][3] = Square::White;
+    /// squares[3][4] = Square::Black;
+    /// squares[4][3] = Square::Black;
+    /// squares[4][4] = Square::White;
+    /// assert_eq!(Board::standard(), Board::from(squares));
+    /// ```
+    fn from(squares: [[Square; 8]; 8]) -> Self {
+        let mut board = Self::empty();
+        for (index, square) in squares.iter().flatten().enumerate() {
+            let bit = Bitboard(1 << (63 - index));
+            match square {
+                Square::Empty => {}
+                Square::Black => board.black_stones |= bit,
+                Square::White => board.white_stones |= bit,
+            }
+        }
+        board
+    }
+}
+
+impl From<Board> for [[Square; 8]; 8] {
+    /// Returns the squares of the board as an array of ranks, each holding
+    /// the squares of the files A to H.
+    ///
+    /// The first rank is rank 1, at the top of the board.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Board, Square};
+    ///
+    /// let squares: [[Square; 8]; 8] = Board::standard().into();
+    /// assert_eq!(Square::White, squares[3][3]);
+    /// assert_eq!(Square::Black, squares[3][4]);
+    /// assert_eq!(Square::Empty, squares[0][0]);
+    /// ```
+    fn from(board: Board) -> Self {
+        let mut squares = [[Square::Empty; 8]; 8];
+        for (pos, square) in board.squares() {
+            squares[pos.rank() as usize][pos.file() as usize] = square;
+        }
+        squares
+    }
+}
+
 impl TryFrom<(u64, u64)> for Board {
     type Error = OthelloError;
 
@@ -530,12 +1222,77 @@ impl TryFrom<(Bitboard, Bitboard)> for Board {
 }
 
 /// This enum represents errors that may occur when using the Othello board.
+///
+/// Errors from parsing squares and positions convert into this error, so
+/// that code handling boards and games can propagate all of them with `?`.
+///
+/// # Examples
+/// ```rust
+/// use magpie::othello::{Game, OthelloError};
+///
+/// fn play(game: &mut Game, notation: &str) -> Result<(), OthelloError> {
+///     game.play(notation.parse()?)?;
+///     Ok(())
+/// }
+///
+/// let mut game = Game::new();
+/// assert_eq!(Ok(()), play(&mut game, "f5"));
+/// assert_eq!(Err(OthelloError::IllegalMove), play(&mut game, "a1"));
+/// assert!(matches!(play(&mut game, "z9"), Err(OthelloError::InvalidSquare(_))));
+/// ```
 #[derive(Debug, Eq, PartialEq, Hash)]
 pub enum OthelloError {
     /// Indicates that an illegal move was attempted.
     IllegalMove,
     /// Indicates that the operation would have resulted in two or more stones overlapping.
     PiecesOverlapping,
+    /// Indicates that a position cannot occur in a game of Othello.
+    ImpossiblePosition,
+    /// Indicates that a move was attempted by the player whose turn it is
+    /// not.
+    WrongTurn,
+    /// Indicates that a move was attempted after the game has concluded.
+    GameOver,
+    /// Indicates that a square could not be parsed or converted.
+    InvalidSquare(PositionError),
+    /// Indicates that a board written as text could not be parsed.
+    InvalidNotation(PositionStrError),
+}
+
+impl From<PositionError> for OthelloError {
+    fn from(error: PositionError) -> Self {
+        OthelloError::InvalidSquare(error)
+    }
+}
+
+impl From<PositionStrError> for OthelloError {
+    fn from(error: PositionStrError) -> Self {
+        OthelloError::InvalidNotation(error)
+    }
+}
+
+impl core::fmt::Display for OthelloError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            OthelloError::IllegalMove => write!(f, "illegal move"),
+            OthelloError::PiecesOverlapping => write!(f, "stones are overlapping"),
+            OthelloError::ImpossiblePosition => write!(f, "position cannot occur in a game"),
+            OthelloError::WrongTurn => write!(f, "it is not the turn of the player"),
+            OthelloError::GameOver => write!(f, "the game is over"),
+            OthelloError::InvalidSquare(error) => write!(f, "invalid square: {error}"),
+            OthelloError::InvalidNotation(error) => write!(f, "invalid board: {error}"),
+        }
+    }
+}
+
+impl core::error::Error for OthelloError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            OthelloError::InvalidSquare(error) => Some(error),
+            OthelloError::InvalidNotation(error) => Some(error),
+            _ => None,
+        }
+    }
 }
 
 // https://www.chessprogramming.org/General_Setwise_Operations#Generalized%20Shift
@@ -543,6 +1300,97 @@ fn dir_shift(x: Bitboard, shift: i8) -> Bitboard {
     if shift > 0 { x >> shift } else { x << -shift }
 }
 
+fn raw_shift(x: u64, shift: i8) -> u64 {
+    if shift > 0 { x >> shift } else { x << -shift }
+}
+
+/// Moves every bit one square in the specified direction, dropping the bits
+/// that would leave the board.
+fn step(x: u64, direction: Direction) -> u64 {
+    let i = direction.index();
+    raw_shift(x & SHIFT_MASKS[i], SHIFT_DIRS[i])
+}
+
+/// Generates the legal moves of the player owning `current` against the
+/// opponent owning `opponent` with a Kogge-Stone fill in every direction.
+#[inline]
+pub(crate) fn raw_moves(current: u64, opponent: u64) -> u64 {
+    let empty_squares = !(current | opponent);
+
+    // https://www.chessprogramming.org/Kogge-Stone_Algorithm#Occluded_Fill
+    let move_in_dir = |mask: u64, shift: i8| {
+        let mut propagator = opponent & mask;
+        let mut generator = raw_shift(current, shift) & propagator;
+        generator |= propagator & raw_shift(generator, shift);
+        propagator &= raw_shift(propagator, shift);
+        generator |= propagator & raw_shift(generator, 2 * shift);
+        propagator &= raw_shift(propagator, 2 * shift);
+        generator |= propagator & raw_shift(generator, 4 * shift);
+        raw_shift(generator, shift) & empty_squares
+    };
+
+    let exclude_top_bottom = !(RANK_1 | RANK_8);
+    let exclude_left_right = !(FILE_A | FILE_H);
+
+    move_in_dir(exclude_top_bottom, -8)
+        | move_in_dir(exclude_left_right, -7)
+        | move_in_dir(exclude_left_right, 1)
+        | move_in_dir(exclude_left_right, 9)
+        | move_in_dir(exclude_top_bottom, 8)
+        | move_in_dir(exclude_left_right, 7)
+        | move_in_dir(exclude_left_right, -1)
+        | move_in_dir(exclude_left_right, -9)
+}
+
+/// Generates the legal moves of the player owning `current` against the
+/// opponent owning `opponent` by scanning the shift rays of every stone of
+/// the player.
+fn ray_moves(current: u64, opponent: u64) -> u64 {
+    let mut moves = 0;
+    let mut stones = current;
+    while stones != 0 {
+        let index = stones.leading_zeros() as usize;
+        let stone = 1 << (63 - index);
+        stones ^= stone;
+        for (ray, shift) in SHIFT_RAYS[index].into_iter().zip(SHIFT_DIRS) {
+            // Positive shifts move towards less significant bits, so the
+            // nearest square of those rays is the most significant one
+            let blockers = ray & !opponent;
+            let nearest = if shift > 0 {
+                highest_bit(blockers)
+            } else {
+                blockers & blockers.wrapping_neg()
+            };
+            // The square is only a move if an opponent stone lies between
+            moves |= nearest & !raw_shift(stone, shift);
+        }
+    }
+    moves & !(current | opponent)
+}
+
+/// Returns the most significant set bit, or 0 if no bit is set.
+#[inline(always)]
+fn highest_bit(x: u64) -> u64 {
+    if x == 0 {
+        0
+    } else {
+        1 << (63 - x.leading_zeros())
+    }
+}
+
+/// Returns all squares adjacent to any of the specified squares.
+pub(crate) fn neighbours(x: u64) -> u64 {
+    Direction::ALL
+        .iter()
+        .fold(0, |acc, direction| acc | step(x, *direction))
+}
+
+/// Returns the squares whose neighbour in the specified direction is either
+/// one of the anchors or off the board.
+fn anchored(anchors: u64, direction: Direction) -> u64 {
+    !SHIFT_MASKS[direction.index()] | step(anchors, direction.opposite())
+}
+
 #[cfg(feature = "serde")]
 #[test]
 fn serde_legal_deserialization() -> serde_json::Result<()> {
@@ -0,0 +1,316 @@
+use crate::othello::{
+    Bitboard, Board, Kernel, OthelloError, Position, Stone,
+    board::raw_moves,
+    constants::{BLACK_START_POS, WHITE_START_POS},
+    kernel,
+};
+
+/// An Othello board packed into a single `u128`.
+///
+/// The upper 64 bits hold the black stones and the lower 64 bits hold the
+/// white stones, which is the same layout as [`Board::concat`]. Within each
+/// half, MSB denotes A1 while LSB denotes H8, just like [`Board`].
+///
+/// The API mirrors the most commonly used parts of [`Board`], and boards
+/// convert losslessly between the two representations. Keeping both halves
+/// in one value makes the board a single 16-byte load, which can matter when
+/// many boards are stored in tables or arrays.
+///
+/// Benchmarks on x86-64 show no consistent advantage over the two-`u64`
+/// layout of [`Board`]: the compiler keeps both halves in general-purpose
+/// registers either way, and selecting the half of the player to move costs
+/// a shift instead of a field access. `Board` therefore stays the internal
+/// representation used throughout the crate. Run `cargo bench --features
+/// board128 --bench board128` to compare the layouts on other targets.
+///
+/// [`Board`]: crate::othello::Board
+/// [`Board::concat`]: crate::othello::Board::concat
+///
+/// # Examples
+/// ```rust
+/// use magpie::othello::{Board, Board128, Position, Stone};
+///
+/// let mut board = Board128::standard();
+/// board.play(Stone::Black, Position::F5);
+///
+/// let mut unpacked = Board::standard();
+/// unpacked.play(Stone::Black, Position::F5);
+/// assert_eq!(unpacked, Board::from(board));
+/// assert_eq!(board, Board128::from(unpacked));
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+pub struct Board128(u128);
+
+impl Board128 {
+    /// Returns a board without any stones on it.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::Board128;
+    ///
+    /// assert_eq!(64, Board128::empty().empty_squares().count_set());
+    /// ```
+    #[must_use]
+    pub fn empty() -> Self {
+        Self(0)
+    }
+
+    /// Returns a board with the standard opening position configured.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Board, Board128};
+    ///
+    /// assert_eq!(Board::standard(), Board::from(Board128::standard()));
+    /// ```
+    #[must_use]
+    pub fn standard() -> Self {
+        Self(pack(BLACK_START_POS, WHITE_START_POS))
+    }
+
+    /// Returns a board built from the two specified bitboards.
+    ///
+    /// Returns an error if the two bitboards intersect.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Bitboard, Board128, OthelloError};
+    ///
+    /// let black = Bitboard::from(0x00_00_00_08_10_00_00_00);
+    /// let white = Bitboard::from(0x00_00_00_10_08_00_00_00);
+    /// assert_eq!(Ok(Board128::standard()), Board128::try_new(black, white));
+    /// assert_eq!(
+    ///     Err(OthelloError::PiecesOverlapping),
+    ///     Board128::try_new(black, black)
+    /// );
+    /// ```
+    pub fn try_new(black: Bitboard, white: Bitboard) -> Result<Self, OthelloError> {
+        if black & white == 0 {
+            Ok(Self(pack(black.raw(), white.raw())))
+        } else {
+            Err(OthelloError::PiecesOverlapping)
+        }
+    }
+
+    /// Retrieves the underlying u128, with black in the upper half.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Board, Board128};
+    ///
+    /// assert_eq!(Board::standard().concat(), Board128::standard().raw());
+    /// ```
+    #[must_use]
+    pub fn raw(self) -> u128 {
+        self.0
+    }
+
+    /// Retrieves the bitboard of the specified player.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Board128, Stone};
+    ///
+    /// let board = Board128::standard();
+    /// assert_eq!(0, board.bits_for(Stone::Black) & board.bits_for(Stone::White));
+    /// ```
+    #[must_use]
+    #[inline]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn bits_for(&self, stone: Stone) -> Bitboard {
+        Bitboard::from((self.0 >> shift(stone)) as u64)
+    }
+
+    /// Returns every square without a stone.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::Board128;
+    ///
+    /// assert_eq!(60, Board128::standard().empty_squares().count_set());
+    /// ```
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn empty_squares(&self) -> Bitboard {
+        Bitboard::from(!((self.0 >> 64) as u64 | self.0 as u64))
+    }
+
+    /// Returns the stone on the specified square, if any.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Board128, Position, Stone};
+    ///
+    /// let board = Board128::standard();
+    /// assert_eq!(Some(Stone::White), board.stone_at(Position::D4));
+    /// assert_eq!(None, board.stone_at(Position::A1));
+    /// ```
+    #[must_use]
+    pub fn stone_at(&self, pos: Position) -> Option<Stone> {
+        if self.bits_for(Stone::Black) & pos != 0 {
+            Some(Stone::Black)
+        } else if self.bits_for(Stone::White) & pos != 0 {
+            Some(Stone::White)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the count of stones for both players.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::Board128;
+    ///
+    /// assert_eq!((2, 2), Board128::standard().count_stones());
+    /// ```
+    #[must_use]
+    pub fn count_stones(&self) -> (u8, u8) {
+        (
+            self.bits_for(Stone::Black).count_set(),
+            self.bits_for(Stone::White).count_set(),
+        )
+    }
+
+    /// Calculates the legal moves of the specified player.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Board128, Stone};
+    ///
+    /// assert_eq!(4, Board128::standard().moves_for(Stone::Black).count_set());
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn moves_for(&self, stone: Stone) -> Bitboard {
+        let (current, opponent) = self.split(stone);
+        Bitboard::from(raw_moves(current, opponent))
+    }
+
+    /// Checks whether or not a move is legal for the specified player.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Board128, Position, Stone};
+    ///
+    /// let board = Board128::standard();
+    /// assert!(board.is_legal_move(Stone::Black, Position::F5));
+    /// assert!(!board.is_legal_move(Stone::Black, Position::A1));
+    /// ```
+    #[must_use]
+    pub fn is_legal_move(&self, stone: Stone, pos: Position) -> bool {
+        self.empty_squares() & pos != 0 && !self.flips_for(stone, pos).is_empty()
+    }
+
+    /// Returns the stones that would be flipped if the specified player
+    /// placed a stone in the specified position.
+    ///
+    /// The flips are calculated by the [current] [`Kernel`].
+    ///
+    /// [current]: crate::othello::Kernel::current
+    /// [`Kernel`]: crate::othello::Kernel
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Board128, Position, Stone};
+    ///
+    /// let board = Board128::standard();
+    /// assert_eq!(Position::E5, board.flips_for(Stone::Black, Position::F5));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn flips_for(&self, stone: Stone, pos: Position) -> Bitboard {
+        let (current, opponent) = self.split(stone);
+        Bitboard::from(kernel::flips(
+            Kernel::current(),
+            current,
+            opponent,
+            pos.raw(),
+        ))
+    }
+
+    /// Places a stone in the specified position and flips the captured
+    /// stones.
+    ///
+    /// Like [`Board::play`], the move is not checked for legality.
+    ///
+    /// [`Board::play`]: crate::othello::Board::play
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Board128, Position, Stone};
+    ///
+    /// let mut board = Board128::standard();
+    /// board.play(Stone::Black, Position::F5);
+    /// assert_eq!((4, 1), board.count_stones());
+    /// ```
+    #[inline]
+    pub fn play(&mut self, stone: Stone, pos: Position) {
+        let flips = u128::from(self.flips_for(stone, pos).raw());
+        let placed = flips | u128::from(pos.raw());
+        // Flipped stones change owner, so they toggle in both halves
+        self.0 ^= (placed << shift(stone)) | (flips << shift(stone.flip()));
+    }
+
+    /// Places a stone in the specified position and flips the captured
+    /// stones, after checking that the move is legal.
+    ///
+    /// Returns the flipped stones, or an error if the move is illegal, in
+    /// which case the board is left unchanged.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Board128, OthelloError, Position, Stone};
+    ///
+    /// let mut board = Board128::standard();
+    /// assert_eq!(1, board.try_play(Stone::Black, Position::F5).unwrap().count_set());
+    /// assert_eq!(
+    ///     Err(OthelloError::IllegalMove),
+    ///     board.try_play(Stone::Black, Position::F5)
+    /// );
+    /// ```
+    pub fn try_play(&mut self, stone: Stone, pos: Position) -> Result<Bitboard, OthelloError> {
+        if !self.is_legal_move(stone, pos) {
+            return Err(OthelloError::IllegalMove);
+        }
+        let flips = self.flips_for(stone, pos);
+        self.play(stone, pos);
+        Ok(flips)
+    }
+
+    /// Returns the bitboards of the specified player and their opponent.
+    #[inline]
+    fn split(&self, stone: Stone) -> (u64, u64) {
+        (
+            self.bits_for(stone).raw(),
+            self.bits_for(stone.flip()).raw(),
+        )
+    }
+}
+
+impl From<Board> for Board128 {
+    fn from(board: Board) -> Self {
+        Self(board.concat())
+    }
+}
+
+impl From<Board128> for Board {
+    fn from(board: Board128) -> Self {
+        // Both halves of a packed board never intersect
+        Board::try_new(board.bits_for(Stone::Black), board.bits_for(Stone::White)).unwrap()
+    }
+}
+
+/// Packs the two bitboards with black in the upper half.
+fn pack(black: u64, white: u64) -> u128 {
+    u128::from(black) << 64 | u128::from(white)
+}
+
+/// Returns how far the bitboard of the specified player is shifted.
+#[inline]
+fn shift(stone: Stone) -> u32 {
+    match stone {
+        Stone::Black => 64,
+        Stone::White => 0,
+    }
+}
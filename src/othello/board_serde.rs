@@ -0,0 +1,245 @@
+//! Compact serialization formats for [`Board`], for use with
+//! `#[serde(with = "...")]`.
+//!
+//! By default, boards serialize as a struct holding both bitboards. When
+//! storing many positions, one of the following formats may be preferable:
+//!
+//! - [`bytes`]: 16 bytes, the black bitboard followed by the white one, both
+//!   in big-endian byte order. This is the smallest format, intended for
+//!   binary formats and databases.
+//! - [`text`]: A string of 64 squares from A1 to H8, in the same notation as
+//!   [`Board::position_str`] but without the player to move. This format is
+//!   easy to read and to search for.
+//!
+//! Both formats reject boards with overlapping stones when deserializing.
+//!
+//! [`Board`]: crate::othello::Board
+//! [`Board::position_str`]: crate::othello::Board::position_str
+//!
+//! # Examples
+//! ```rust
+//! use magpie::othello::Board;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Record {
+//!     #[serde(with = "magpie::othello::board_serde::text")]
+//!     board: Board,
+//!     score: i8,
+//! }
+//!
+//! let record = Record { board: Board::standard(), score: 2 };
+//! let json = serde_json::to_string(&record).unwrap();
+//! assert_eq!(
+//!     r#"{"board":"---------------------------OX------XO---------------------------","score":2}"#,
+//!     json
+//! );
+//! let record: Record = serde_json::from_str(&json).unwrap();
+//! assert_eq!(Board::standard(), record.board);
+//! ```
+
+use crate::othello::Board;
+use serde::de::Error;
+
+/// Checks that the stones do not overlap.
+fn validate<E: Error>(black: u64, white: u64) -> Result<Board, E> {
+    Board::try_from((black, white)).map_err(E::custom)
+}
+
+/// Serializes boards as 16 bytes, the black bitboard followed by the white
+/// one, both in big-endian byte order.
+///
+/// See the [module documentation] for an example.
+///
+/// [module documentation]: crate::othello::board_serde
+pub mod bytes {
+    use crate::othello::{Board, Stone};
+    use core::fmt;
+    use serde::{
+        Deserializer, Serializer,
+        de::{self, SeqAccess, Visitor},
+    };
+
+    /// Serializes a board as 16 bytes.
+    pub fn serialize<S: Serializer>(board: &Board, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut bytes = [0; 16];
+        bytes[..8].copy_from_slice(&board.bits_for(Stone::Black).raw().to_be_bytes());
+        bytes[8..].copy_from_slice(&board.bits_for(Stone::White).raw().to_be_bytes());
+        serializer.serialize_bytes(&bytes)
+    }
+
+    /// Deserializes a board from 16 bytes.
+    ///
+    /// Formats without native support for bytes, such as JSON, may also
+    /// provide the bytes as a sequence of numbers.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Board, D::Error> {
+        deserializer.deserialize_bytes(BytesVisitor)
+    }
+
+    struct BytesVisitor;
+
+    impl<'de> Visitor<'de> for BytesVisitor {
+        type Value = Board;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.write_str("16 bytes")
+        }
+
+        fn visit_bytes<E: de::Error>(self, bytes: &[u8]) -> Result<Board, E> {
+            let bytes: [u8; 16] = bytes
+                .try_into()
+                .map_err(|_| E::invalid_length(bytes.len(), &self))?;
+            let black = u64::from_be_bytes(bytes[..8].try_into().unwrap());
+            let white = u64::from_be_bytes(bytes[8..].try_into().unwrap());
+            super::validate(black, white)
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Board, A::Error> {
+            let mut bytes = [0; 16];
+            for (index, byte) in bytes.iter_mut().enumerate() {
+                *byte = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(index, &self))?;
+            }
+            if seq.next_element::<u8>()?.is_some() {
+                return Err(de::Error::invalid_length(17, &self));
+            }
+            self.visit_bytes(&bytes)
+        }
+    }
+}
+
+/// Serializes boards as a string of 64 squares from A1 to H8, where black
+/// stones are written as `X`, white stones as `O` and empty squares as `-`.
+///
+/// See the [module documentation] for an example.
+///
+/// [module documentation]: crate::othello::board_serde
+pub mod text {
+    use crate::othello::{Bitboard, Board, Square};
+    use core::fmt;
+    use serde::{
+        Deserializer, Serializer,
+        de::{self, Visitor},
+    };
+
+    /// Serializes a board as a string of 64 squares.
+    pub fn serialize<S: Serializer>(board: &Board, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&Squares(board))
+    }
+
+    /// Deserializes a board from a string of 64 squares.
+    ///
+    /// Like [`Board::from_position_str`], the parser is case-insensitive,
+    /// also accepts `*` for black and `.` for empty squares and ignores all
+    /// whitespace.
+    ///
+    /// [`Board::from_position_str`]: crate::othello::Board::from_position_str
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Board, D::Error> {
+        deserializer.deserialize_str(TextVisitor)
+    }
+
+    struct Squares<'a>(&'a Board);
+
+    impl fmt::Display for Squares<'_> {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            for (_, square) in self.0.squares() {
+                let square = match square {
+                    Square::Black => 'X',
+                    Square::White => 'O',
+                    Square::Empty => '-',
+                };
+                write!(f, "{square}")?;
+            }
+            Ok(())
+        }
+    }
+
+    struct TextVisitor;
+
+    impl Visitor<'_> for TextVisitor {
+        type Value = Board;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.write_str("a string of 64 squares")
+        }
+
+        fn visit_str<E: de::Error>(self, text: &str) -> Result<Board, E> {
+            let mut squares = text.chars().filter(|c| !c.is_whitespace());
+            let (mut black, mut white) = (0, 0);
+            for pos in Bitboard::from(u64::MAX).hot_bits() {
+                let square = squares
+                    .next()
+                    .ok_or_else(|| E::invalid_length(text.len(), &self))?;
+                match square {
+                    'X' | 'x' | '*' => black |= pos.raw(),
+                    'O' | 'o' => white |= pos.raw(),
+                    '-' | '.' => {}
+                    _ => return Err(E::invalid_value(de::Unexpected::Char(square), &self)),
+                }
+            }
+            if squares.next().is_some() {
+                return Err(E::invalid_length(text.len(), &self));
+            }
+            super::validate(black, white)
+        }
+    }
+}
+
+#[cfg(test)]
+#[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq)]
+struct Compact {
+    #[serde(with = "bytes")]
+    bytes: Board,
+    #[serde(with = "text")]
+    text: Board,
+}
+
+#[test]
+fn compact_round_trip() -> serde_json::Result<()> {
+    let board = Board::try_from((0x8000_0000_0000_0001, 0x0000_0018_1800_0000)).unwrap();
+    let compact = Compact {
+        bytes: board,
+        text: board,
+    };
+    let json = serde_json::to_string(&compact)?;
+    assert_eq!(compact, serde_json::from_str(&json)?);
+
+    Ok(())
+}
+
+#[test]
+fn compact_formats() -> serde_json::Result<()> {
+    let compact = Compact {
+        bytes: Board::standard(),
+        text: Board::standard(),
+    };
+    let json = serde_json::to_value(&compact)?;
+
+    assert_eq!(
+        serde_json::json!([0, 0, 0, 8, 16, 0, 0, 0, 0, 0, 0, 16, 8, 0, 0, 0]),
+        json["bytes"]
+    );
+    assert_eq!(
+        "---------------------------OX------XO---------------------------",
+        json["text"]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn compact_illegal_deserialization() {
+    let empty = "-".repeat(64);
+    let overlapping = serde_json::json!({
+        "bytes": [0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1],
+        "text": empty,
+    });
+    assert!(serde_json::from_value::<Compact>(overlapping).is_err());
+
+    let short = serde_json::json!({ "bytes": vec![0; 15], "text": empty });
+    assert!(serde_json::from_value::<Compact>(short).is_err());
+
+    let invalid = serde_json::json!({ "bytes": vec![0; 16], "text": "-".repeat(63) + "Z" });
+    assert!(serde_json::from_value::<Compact>(invalid).is_err());
+}
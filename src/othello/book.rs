@@ -0,0 +1,693 @@
+//! Opening books mapping positions to recommended moves.
+//!
+//! Positions are stored in a canonical orientation, so a line entered once
+//! is recognized in all eight symmetric variants of the board.
+
+use crate::othello::{Board, Game, Move, Position, Stone, Symmetry, Transcript, TranscriptError};
+use std::{
+    collections::HashMap,
+    io::{Read, Write},
+};
+
+/// Identifies books written by [`Book::save`].
+///
+/// [`Book::save`]: crate::othello::book::Book::save
+const MAGIC: &[u8; 4] = b"MPBK";
+const VERSION: u8 = 2;
+
+/// Identifies books written by Edax, which are the words `EDAX` and `BOOK`
+/// stored as little-endian integers.
+const EDAX_MAGIC: &[u8; 8] = b"XADEKOOB";
+/// The size of the header of Edax books, which ends with the number of
+/// positions.
+const EDAX_HEADER_SIZE: usize = 42;
+
+/// Well-known named opening lines, paired with their transcripts.
+///
+/// Every line starts with the conventional first move F5, but since books
+/// are symmetric the names are recognized regardless of the first move.
+#[cfg(feature = "openings")]
+pub const NAMED_OPENINGS: &[(&str, &str)] = &[
+    ("Diagonal Opening", "f5f6"),
+    ("Perpendicular Opening", "f5d6"),
+    ("Parallel Opening", "f5f4"),
+    ("Tiger", "f5d6c3d3c4"),
+    ("Leader's Tiger", "f5d6c3d3c4f4f6"),
+    ("Rose-bill", "f5d6c3d3c4f4f6f3e6e7"),
+    ("Aubrey", "f5d6c3d3c4f4c5b3c2"),
+    ("Brightwell", "f5d6c3d3c4f4c5b3c2e6"),
+    ("Cow", "f5d6c5"),
+    ("Rabbit", "f5f6e6f4e3"),
+    ("Buffalo", "f5f6e6f4c3"),
+    ("Heath", "f5f6e6f4g5"),
+];
+
+/// A move recommended by a book, along with its score.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct BookMove {
+    /// The recommended move.
+    pub position: Position,
+    /// The score of the move from the perspective of the player making it.
+    pub score: i32,
+    /// The number of times the move has been recorded, such as the number
+    /// of games it was played in.
+    pub games: u32,
+}
+
+/// Statistics about the positions in a book, as returned by
+/// [`Book::stats`].
+///
+/// [`Book::stats`]: crate::othello::book::Book::stats
+#[derive(Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct BookStats {
+    /// The number of positions in the book.
+    pub positions: usize,
+    /// The number of moves recorded over all positions.
+    pub moves: usize,
+    /// The number of positions by ply, where the starting position is at
+    /// ply 0.
+    pub positions_per_ply: Vec<usize>,
+    /// The number of positions reached by more than one book move.
+    pub transpositions: usize,
+}
+
+/// A collection of positions mapped to recommended moves.
+///
+/// # Examples
+/// ```rust
+/// use magpie::othello::{Board, Position, Stone};
+/// use magpie::othello::book::Book;
+///
+/// let mut book = Book::new();
+/// let mut board = Board::standard();
+/// board.play(Stone::Black, "f5".parse().unwrap());
+/// book.insert(&board, Stone::White, "f6".parse().unwrap(), 0);
+///
+/// // D3 is a reflection of F5, so the reflected reply C3 is recommended
+/// let mut reflected = Board::standard();
+/// reflected.play(Stone::Black, "d3".parse().unwrap());
+/// let c3: Position = "c3".parse().unwrap();
+/// assert_eq!(c3, book.best_move(&reflected, Stone::White).unwrap().position);
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Book {
+    entries: HashMap<(u64, u64, Stone), Vec<BookMove>>,
+}
+
+impl Book {
+    /// Returns an empty book.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::book::Book;
+    ///
+    /// assert!(Book::new().is_empty());
+    /// ```
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            entries: HashMap::new(),
+        }
+    }
+
+    /// Returns a book containing every position of the [`NAMED_OPENINGS`],
+    /// with all moves scored as zero.
+    ///
+    /// [`NAMED_OPENINGS`]: crate::othello::book::NAMED_OPENINGS
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Board, Stone};
+    /// use magpie::othello::book::Book;
+    ///
+    /// let book = Book::with_named_openings();
+    /// assert_eq!(1, book.moves(&Board::standard(), Stone::Black).len());
+    /// ```
+    #[cfg(feature = "openings")]
+    #[must_use]
+    pub fn with_named_openings() -> Self {
+        let mut book = Self::new();
+        for (_, line) in NAMED_OPENINGS {
+            let transcript: Transcript = line.parse().unwrap();
+            book.insert_line(&transcript, 0).unwrap();
+        }
+        book
+    }
+
+    /// Records a move for the specified position.
+    ///
+    /// If the move has already been recorded for the position, or any of its
+    /// symmetric variants, its score is updated. Either way, the number of
+    /// games of the move is incremented.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Board, Stone};
+    /// use magpie::othello::book::Book;
+    ///
+    /// let mut book = Book::new();
+    /// let board = Board::standard();
+    /// book.insert(&board, Stone::Black, "f5".parse().unwrap(), 1);
+    /// book.insert(&board, Stone::Black, "f5".parse().unwrap(), 2);
+    /// assert_eq!(2, book.moves(&board, Stone::Black)[0].score);
+    /// assert_eq!(2, book.moves(&board, Stone::Black)[0].games);
+    /// ```
+    pub fn insert(&mut self, board: &Board, stone: Stone, position: Position, score: i32) {
+        let (key, symmetry) = canonical(board, stone);
+        let position = position.transform(symmetry);
+        let moves = self.entries.entry(key).or_default();
+        match moves.iter_mut().find(|mv| mv.position == position) {
+            Some(mv) => {
+                mv.score = score;
+                mv.games = mv.games.saturating_add(1);
+            }
+            None => moves.push(BookMove {
+                position,
+                score,
+                games: 1,
+            }),
+        }
+    }
+
+    /// Records every move of the transcript, replayed from the standard
+    /// opening position, with the same score.
+    ///
+    /// Passes are skipped. Returns an error if the transcript contains an
+    /// illegal move, in which case the moves preceding it are still
+    /// recorded.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::Transcript;
+    /// use magpie::othello::book::Book;
+    ///
+    /// let mut book = Book::new();
+    /// let line: Transcript = "f5d6c3".parse().unwrap();
+    /// book.insert_line(&line, 0).unwrap();
+    /// assert_eq!(3, book.len());
+    /// ```
+    pub fn insert_line(
+        &mut self,
+        transcript: &Transcript,
+        score: i32,
+    ) -> Result<(), TranscriptError> {
+        let mut game = Game::new();
+        for (index, mv) in transcript.moves().iter().enumerate() {
+            let illegal = TranscriptError::IllegalMove(index);
+            match mv {
+                Move::Play(position) => {
+                    game.pass_if_forced();
+                    let board = game.board();
+                    let stone = game.current_turn();
+                    game.play(*position).map_err(|_| illegal)?;
+                    self.insert(&board, stone, *position, score);
+                }
+                Move::Pass => {
+                    if !game.pass_if_forced() {
+                        return Err(illegal);
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns all moves recorded for the position.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Board, Stone};
+    /// use magpie::othello::book::Book;
+    ///
+    /// let book = Book::new();
+    /// assert!(book.moves(&Board::standard(), Stone::Black).is_empty());
+    /// ```
+    #[must_use]
+    pub fn moves(&self, board: &Board, stone: Stone) -> Vec<BookMove> {
+        let (key, symmetry) = canonical(board, stone);
+        self.entries
+            .get(&key)
+            .map(|moves| {
+                moves
+                    .iter()
+                    .map(|mv| BookMove {
+                        position: mv.position.transform(symmetry.inverse()),
+                        ..*mv
+                    })
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Returns the highest scoring move recorded for the position, if any.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Board, Stone};
+    /// use magpie::othello::book::Book;
+    ///
+    /// let mut book = Book::new();
+    /// let board = Board::standard();
+    /// book.insert(&board, Stone::Black, "f5".parse().unwrap(), 1);
+    /// book.insert(&board, Stone::Black, "e6".parse().unwrap(), 3);
+    /// assert_eq!(3, book.best_move(&board, Stone::Black).unwrap().score);
+    /// ```
+    #[must_use]
+    pub fn best_move(&self, board: &Board, stone: Stone) -> Option<BookMove> {
+        self.moves(board, stone)
+            .into_iter()
+            .max_by_key(|mv| mv.score)
+    }
+
+    /// Returns the number of positions in the book.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::book::Book;
+    ///
+    /// assert_eq!(0, Book::new().len());
+    /// ```
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns true if and only if the book contains no positions.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::book::Book;
+    ///
+    /// assert!(Book::new().is_empty());
+    /// ```
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Adds every move of the other book to this one.
+    ///
+    /// Moves recorded in both books have their games added together and
+    /// their scores averaged, weighted by the number of games.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Board, Stone};
+    /// use magpie::othello::book::Book;
+    ///
+    /// let board = Board::standard();
+    /// let mut book = Book::new();
+    /// book.insert(&board, Stone::Black, "f5".parse().unwrap(), 4);
+    /// let mut other = Book::new();
+    /// other.insert(&board, Stone::Black, "f5".parse().unwrap(), 0);
+    /// other.insert(&board, Stone::Black, "f5".parse().unwrap(), 1);
+    /// other.insert(&board, Stone::Black, "e6".parse().unwrap(), 1);
+    ///
+    /// book.merge(&other);
+    /// let best = book.best_move(&board, Stone::Black).unwrap();
+    /// assert_eq!((2, 3), (best.score, best.games));
+    /// assert_eq!(2, book.moves(&board, Stone::Black).len());
+    /// ```
+    pub fn merge(&mut self, other: &Book) {
+        for (key, other_moves) in &other.entries {
+            let moves = self.entries.entry(*key).or_default();
+            for other_mv in other_moves {
+                match moves.iter_mut().find(|mv| mv.position == other_mv.position) {
+                    Some(mv) => {
+                        let games = u64::from(mv.games) + u64::from(other_mv.games);
+                        if games > 0 {
+                            let total = i64::from(mv.score) * i64::from(mv.games)
+                                + i64::from(other_mv.score) * i64::from(other_mv.games);
+                            mv.score = i32::try_from(total / games as i64).unwrap();
+                        }
+                        mv.games = u32::try_from(games).unwrap_or(u32::MAX);
+                    }
+                    None => moves.push(*other_mv),
+                }
+            }
+        }
+    }
+
+    /// Removes every move recorded in fewer than `min_games` games, along
+    /// with positions left without moves.
+    ///
+    /// Returns the number of moves removed.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::Transcript;
+    /// use magpie::othello::book::Book;
+    ///
+    /// let mut book = Book::new();
+    /// for line in ["f5d6c3", "f5d6c5", "f5f6"] {
+    ///     book.insert_line(&line.parse::<Transcript>().unwrap(), 0).unwrap();
+    /// }
+    /// assert_eq!(3, book.prune_by_games(2));
+    /// // Only F5 and D6 remain
+    /// assert_eq!(2, book.len());
+    /// ```
+    pub fn prune_by_games(&mut self, min_games: u32) -> usize {
+        self.retain_moves(|_, mv| mv.games >= min_games)
+    }
+
+    /// Removes every move scoring more than `max_error` below the best move
+    /// of its position.
+    ///
+    /// Returns the number of moves removed.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Board, Stone};
+    /// use magpie::othello::book::Book;
+    ///
+    /// let mut book = Book::new();
+    /// let board = Board::standard();
+    /// book.insert(&board, Stone::Black, "f5".parse().unwrap(), 4);
+    /// book.insert(&board, Stone::Black, "e6".parse().unwrap(), 2);
+    /// book.insert(&board, Stone::Black, "d3".parse().unwrap(), -6);
+    ///
+    /// assert_eq!(1, book.prune_by_error(2));
+    /// assert_eq!(2, book.moves(&board, Stone::Black).len());
+    /// ```
+    pub fn prune_by_error(&mut self, max_error: i32) -> usize {
+        self.retain_moves(|moves, mv| {
+            let best = moves.iter().map(|mv| mv.score).max().unwrap_or(mv.score);
+            i64::from(best) - i64::from(mv.score) <= i64::from(max_error)
+        })
+    }
+
+    /// Returns statistics about the coverage of the book.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::Transcript;
+    /// use magpie::othello::book::Book;
+    ///
+    /// let mut book = Book::new();
+    /// for line in ["f5d6c3d3", "f5d6c3"] {
+    ///     book.insert_line(&line.parse::<Transcript>().unwrap(), 0).unwrap();
+    /// }
+    /// let stats = book.stats();
+    /// assert_eq!(vec![1, 1, 1, 1], stats.positions_per_ply);
+    /// assert_eq!(4, stats.moves);
+    /// ```
+    #[must_use]
+    pub fn stats(&self) -> BookStats {
+        let mut stats = BookStats {
+            positions: self.entries.len(),
+            ..BookStats::default()
+        };
+        let mut parents: HashMap<(u64, u64, Stone), usize> = HashMap::new();
+        for ((black, white, stone), moves) in &self.entries {
+            let ply = ((black | white).count_ones() as usize).saturating_sub(4);
+            if stats.positions_per_ply.len() <= ply {
+                stats.positions_per_ply.resize(ply + 1, 0);
+            }
+            stats.positions_per_ply[ply] += 1;
+            stats.moves += moves.len();
+
+            let Ok(board) = Board::try_from((*black, *white)) else {
+                continue;
+            };
+            for mv in moves {
+                if board.moves_for(*stone) & mv.position == 0 {
+                    continue;
+                }
+                let mut child = board;
+                child.play(*stone, mv.position);
+                let next = if child.moves_for(stone.flip()) > 0 {
+                    stone.flip()
+                } else {
+                    *stone
+                };
+                let key = canonical(&child, next).0;
+                if self.entries.contains_key(&key) {
+                    *parents.entry(key).or_default() += 1;
+                }
+            }
+        }
+        stats.transpositions = parents.values().filter(|count| **count > 1).count();
+        stats
+    }
+
+    /// Keeps the moves for which the predicate returns true, given all moves
+    /// of the position, and removes positions left without moves.
+    fn retain_moves(&mut self, keep: impl Fn(&[BookMove], &BookMove) -> bool) -> usize {
+        let mut removed = 0;
+        self.entries.retain(|_, moves| {
+            let kept: Vec<BookMove> = moves.iter().filter(|mv| keep(moves, mv)).copied().collect();
+            removed += moves.len() - kept.len();
+            *moves = kept;
+            !moves.is_empty()
+        });
+        removed
+    }
+
+    /// Reads a book previously written by [`save`].
+    ///
+    /// [`save`]: crate::othello::book::Book::save
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Board, Stone};
+    /// use magpie::othello::book::Book;
+    ///
+    /// let mut book = Book::new();
+    /// book.insert(&Board::standard(), Stone::Black, "f5".parse().unwrap(), 4);
+    ///
+    /// let mut bytes = Vec::new();
+    /// book.save(&mut bytes).unwrap();
+    /// assert_eq!(book, Book::load(bytes.as_slice()).unwrap());
+    /// ```
+    pub fn load<R: Read>(mut reader: R) -> Result<Self, BookError> {
+        let mut header = [0; 9];
+        reader.read_exact(&mut header)?;
+        // Books written before game counts were recorded count every move
+        // as a single game
+        let version = header[4];
+        if &header[..4] != MAGIC || !(1..=VERSION).contains(&version) {
+            return Err(BookError::InvalidFormat);
+        }
+        let count = u32::from_le_bytes(header[5..9].try_into().unwrap());
+
+        let mut book = Self::new();
+        for _ in 0..count {
+            let mut entry = [0; 18];
+            reader.read_exact(&mut entry)?;
+            let black = u64::from_le_bytes(entry[0..8].try_into().unwrap());
+            let white = u64::from_le_bytes(entry[8..16].try_into().unwrap());
+            let stone = match entry[16] {
+                0 => Stone::Black,
+                1 => Stone::White,
+                _ => return Err(BookError::InvalidFormat),
+            };
+            if black & white != 0 {
+                return Err(BookError::InvalidFormat);
+            }
+            let mut moves = Vec::with_capacity(usize::from(entry[17]));
+            for _ in 0..entry[17] {
+                let mut mv = [0; 9];
+                let len = if version == 1 { 5 } else { 9 };
+                reader.read_exact(&mut mv[..len])?;
+                let position = Position::from_index(mv[0]).map_err(|_| BookError::InvalidFormat)?;
+                let score = i32::from_le_bytes(mv[1..5].try_into().unwrap());
+                let games = if version == 1 {
+                    1
+                } else {
+                    u32::from_le_bytes(mv[5..9].try_into().unwrap())
+                };
+                moves.push(BookMove {
+                    position,
+                    score,
+                    games,
+                });
+            }
+            book.entries.insert((black, white, stone), moves);
+        }
+        Ok(book)
+    }
+
+    /// Reads a book in the binary format of the [Edax] engine, as found in
+    /// its `.book` files.
+    ///
+    /// Every move linked from a position is imported with its score, along
+    /// with the best move found outside the book, if any. Since Edax does not
+    /// keep track of how often moves were played, each move counts as a
+    /// single game.
+    ///
+    /// Edax stores positions from the perspective of the player to move
+    /// without recording their color. The color is inferred from the number
+    /// of stones on the board, which assigns the wrong color to positions
+    /// reached after a pass.
+    ///
+    /// [Edax]: https://github.com/abulmo/edax-reversi
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use magpie::othello::{Board, Stone};
+    /// use magpie::othello::book::Book;
+    /// use std::fs::File;
+    ///
+    /// let book = Book::load_edax(File::open("data/book.dat").unwrap()).unwrap();
+    /// let mv = book.best_move(&Board::standard(), Stone::Black).unwrap();
+    /// println!("{} ({})", mv.position, mv.score);
+    /// ```
+    pub fn load_edax<R: Read>(mut reader: R) -> Result<Self, BookError> {
+        let mut header = [0; EDAX_HEADER_SIZE];
+        reader.read_exact(&mut header)?;
+        if &header[..8] != EDAX_MAGIC {
+            return Err(BookError::InvalidFormat);
+        }
+        let count = u32::from_le_bytes(header[38..42].try_into().unwrap());
+
+        let mut book = Self::new();
+        for _ in 0..count {
+            let mut position = [0; 40];
+            reader.read_exact(&mut position)?;
+            // Edax stores A1 in the least significant bit
+            let player = u64::from_le_bytes(position[0..8].try_into().unwrap()).reverse_bits();
+            let opponent = u64::from_le_bytes(position[8..16].try_into().unwrap()).reverse_bits();
+            let (stone, black, white) = if (player | opponent).count_ones() % 2 == 0 {
+                (Stone::Black, player, opponent)
+            } else {
+                (Stone::White, opponent, player)
+            };
+            let board = Board::try_from((black, white)).map_err(|_| BookError::InvalidFormat)?;
+
+            // The moves in the book are followed by the best move outside of it
+            let links = usize::from(position[38]) + 1;
+            let mut moves = vec![0; links * 2];
+            reader.read_exact(&mut moves)?;
+            for link in moves.chunks(2) {
+                let score = i32::from(i8::from_le_bytes([link[0]]));
+                // Passes and missing moves are stored as 64 and 65
+                if let Ok(position) = Position::from_index(link[1]) {
+                    book.insert(&board, stone, position, score);
+                }
+            }
+        }
+        Ok(book)
+    }
+
+    /// Writes the book in a compact binary format.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::book::Book;
+    ///
+    /// let mut bytes = Vec::new();
+    /// Book::new().save(&mut bytes).unwrap();
+    /// assert_eq!(b"MPBK", &bytes[..4]);
+    /// ```
+    pub fn save<W: Write>(&self, mut writer: W) -> Result<(), BookError> {
+        writer.write_all(MAGIC)?;
+        writer.write_all(&[VERSION])?;
+        writer.write_all(&(self.entries.len() as u32).to_le_bytes())?;
+        for ((black, white, stone), moves) in &self.entries {
+            writer.write_all(&black.to_le_bytes())?;
+            writer.write_all(&white.to_le_bytes())?;
+            let stone = match stone {
+                Stone::Black => 0,
+                Stone::White => 1,
+            };
+            let count = u8::try_from(moves.len()).map_err(|_| BookError::InvalidFormat)?;
+            writer.write_all(&[stone, count])?;
+            for mv in moves {
+                let index = mv.position.raw().leading_zeros() as u8;
+                writer.write_all(&[index])?;
+                writer.write_all(&mv.score.to_le_bytes())?;
+                writer.write_all(&mv.games.to_le_bytes())?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Returns the name of the deepest named opening reached by the transcript,
+/// in any of its symmetric variants.
+///
+/// # Examples
+/// ```rust
+/// use magpie::othello::Transcript;
+/// use magpie::othello::book::opening_name;
+///
+/// let transcript: Transcript = "f5d6c3d3c4f4".parse().unwrap();
+/// assert_eq!(Some("Tiger"), opening_name(&transcript));
+///
+/// // The same opening, mirrored
+/// let transcript: Transcript = "c4e3f6e6f5".parse().unwrap();
+/// assert_eq!(Some("Tiger"), opening_name(&transcript));
+/// ```
+#[cfg(feature = "openings")]
+#[must_use]
+pub fn opening_name(transcript: &Transcript) -> Option<&'static str> {
+    let named: Vec<(&str, (u64, u64, Stone))> = NAMED_OPENINGS
+        .iter()
+        .map(|(name, line)| {
+            let game = line.parse::<Transcript>().unwrap().replay().unwrap();
+            (*name, canonical(&game.board(), game.current_turn()).0)
+        })
+        .collect();
+
+    let mut game = Game::new();
+    let mut name = None;
+    for mv in transcript.moves() {
+        let legal = match mv {
+            Move::Play(position) => {
+                game.pass_if_forced();
+                game.play(*position).is_ok()
+            }
+            Move::Pass => game.pass_if_forced(),
+        };
+        if !legal {
+            break;
+        }
+        let key = canonical(&game.board(), game.current_turn()).0;
+        if let Some((found, _)) = named.iter().find(|(_, named)| *named == key) {
+            name = Some(*found);
+        }
+    }
+    name
+}
+
+/// Returns the key of the canonical variant of the position, along with the
+/// symmetry producing it.
+fn canonical(board: &Board, stone: Stone) -> ((u64, u64, Stone), Symmetry) {
+    let (board, symmetry) = board.canonical();
+    let black = board.bits_for(Stone::Black).raw();
+    let white = board.bits_for(Stone::White).raw();
+    ((black, white, stone), symmetry)
+}
+
+/// This enum represents errors that may occur when loading or saving books.
+#[derive(Debug)]
+pub enum BookError {
+    /// Indicates that reading or writing the book failed.
+    Io(std::io::Error),
+    /// Indicates that the book is not in the expected format.
+    InvalidFormat,
+}
+
+impl From<std::io::Error> for BookError {
+    fn from(error: std::io::Error) -> Self {
+        BookError::Io(error)
+    }
+}
+
+impl std::fmt::Display for BookError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BookError::Io(error) => write!(f, "i/o error: {error}"),
+            BookError::InvalidFormat => f.write_str("invalid book format"),
+        }
+    }
+}
+
+impl std::error::Error for BookError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            BookError::Io(error) => Some(error),
+            BookError::InvalidFormat => None,
+        }
+    }
+}
@@ -0,0 +1,127 @@
+use crate::othello::{Bitboard, Board, Position, Stone};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The squares that differ between two boards, as returned by
+/// [`Board::diff`].
+///
+/// [`Board::diff`]: crate::othello::Board::diff
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+pub struct BoardDiff {
+    /// Squares that were empty and now hold a black stone.
+    pub placed_black: Bitboard,
+    /// Squares that were empty and now hold a white stone.
+    pub placed_white: Bitboard,
+    /// Squares that held a stone and are now empty.
+    pub removed: Bitboard,
+    /// Squares whose stone turned from white to black.
+    pub flipped_to_black: Bitboard,
+    /// Squares whose stone turned from black to white.
+    pub flipped_to_white: Bitboard,
+}
+
+impl BoardDiff {
+    /// Returns true if the boards are equal.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::Board;
+    ///
+    /// let board = Board::standard();
+    /// assert!(board.diff(&board).is_empty());
+    /// ```
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.changed().is_empty()
+    }
+
+    /// Returns every square that differs between the boards.
+    #[must_use]
+    pub fn changed(&self) -> Bitboard {
+        self.placed_black
+            | self.placed_white
+            | self.removed
+            | self.flipped_to_black
+            | self.flipped_to_white
+    }
+}
+
+impl Board {
+    /// Compares the board to a later board, square by square.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Board, Position, Stone};
+    ///
+    /// let board = Board::standard();
+    /// let mut next = board;
+    /// let f5 = Position::try_from("f5").unwrap();
+    /// next.play(Stone::Black, f5);
+    ///
+    /// let diff = board.diff(&next);
+    /// assert_eq!(Ok(f5), Position::try_from(diff.placed_black));
+    /// assert_eq!(1, diff.flipped_to_black.count_set());
+    /// assert!(diff.removed.is_empty() && diff.placed_white.is_empty());
+    /// ```
+    #[must_use]
+    pub fn diff(&self, other: &Board) -> BoardDiff {
+        let (black, white) = (self.bits_for(Stone::Black), self.bits_for(Stone::White));
+        let (next_black, next_white) = (other.bits_for(Stone::Black), other.bits_for(Stone::White));
+        let empty = self.empty_squares();
+        BoardDiff {
+            placed_black: empty & next_black,
+            placed_white: empty & next_white,
+            removed: !empty & other.empty_squares(),
+            flipped_to_black: white & next_black,
+            flipped_to_white: black & next_white,
+        }
+    }
+
+    /// Reconstructs the move that turned the board into the next board.
+    ///
+    /// Returns the player who moved and the square they played, or `None`
+    /// if the next board cannot follow from this board by a single legal
+    /// move. That is the case if the boards are equal, as after a pass, if
+    /// stones were removed or more than one stone was placed, or if the
+    /// stones that were flipped are not exactly those the move flips.
+    ///
+    /// This is useful for following games through a sequence of boards,
+    /// such as those read from a camera or sent by a server.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Board, Position, Stone};
+    ///
+    /// let board = Board::standard();
+    /// let mut next = board;
+    /// let f5 = Position::try_from("f5").unwrap();
+    /// next.play(Stone::Black, f5);
+    /// assert_eq!(Some((Stone::Black, f5)), board.infer_move(&next));
+    ///
+    /// // A board that skips the flips of the move
+    /// let mut skipped = board;
+    /// skipped.place_stone_unchecked(Stone::Black, f5.into());
+    /// assert_eq!(None, board.infer_move(&skipped));
+    /// ```
+    #[must_use]
+    pub fn infer_move(&self, next: &Board) -> Option<(Stone, Position)> {
+        let diff = self.diff(next);
+        if !diff.removed.is_empty() {
+            return None;
+        }
+        let (stone, placed, flipped) = match (diff.placed_black, diff.placed_white) {
+            (placed, white) if white.is_empty() && diff.flipped_to_white.is_empty() => {
+                (Stone::Black, placed, diff.flipped_to_black)
+            }
+            (black, placed) if black.is_empty() && diff.flipped_to_black.is_empty() => {
+                (Stone::White, placed, diff.flipped_to_white)
+            }
+            _ => return None,
+        };
+        let pos = Position::try_from(placed).ok()?;
+        let flips = self.flips_for(stone, pos);
+        (!flips.is_empty() && flips == flipped).then_some((stone, pos))
+    }
+}
@@ -0,0 +1,64 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Enum that represents the eight directions a line can extend in on the
+/// board.
+///
+/// North points towards rank 1 and east points towards file H, matching the
+/// orientation used when boards are displayed.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(kani, derive(kani::Arbitrary))]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Direction {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction {
+    /// All eight directions, clockwise starting from north.
+    pub const ALL: [Direction; 8] = [
+        Direction::North,
+        Direction::NorthEast,
+        Direction::East,
+        Direction::SouthEast,
+        Direction::South,
+        Direction::SouthWest,
+        Direction::West,
+        Direction::NorthWest,
+    ];
+
+    /// Returns the direction pointing the opposite way.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::Direction;
+    ///
+    /// assert_eq!(Direction::SouthWest, Direction::NorthEast.opposite());
+    /// ```
+    #[must_use]
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self.index() + 4) % 8]
+    }
+
+    /// Returns the index of the direction in [`ALL`], which is also the
+    /// order used by the generated lookup tables.
+    ///
+    /// [`ALL`]: crate::othello::Direction::ALL
+    pub(crate) fn index(self) -> usize {
+        self as usize
+    }
+}
+
+#[cfg(kani)]
+#[kani::proof]
+fn direction_opposite_equality() {
+    let direction: Direction = kani::any();
+    assert_ne!(direction, direction.opposite());
+    assert_eq!(direction, direction.opposite().opposite());
+}
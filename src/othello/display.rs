@@ -1,13 +1,15 @@
 use crate::othello::{
+    Bitboard, Board, Position, Stone,
     constants::{FILES, RANKS},
-    Board, Position, Stone,
 };
 
 /// Helper struct to customize the printing of Othello boards.
 ///
-/// Printing and thus visualizing the board is useful for both debugging. The
-/// output can be customized by choosing formatting options and whether or not
-/// legal moves should be shown for a specific player.
+/// Printing and thus visualizing the board is useful for both debugging and
+/// terminal front-ends. The output can be customized by choosing formatting
+/// options, whether or not rank and file labels are shown, whether or not
+/// legal moves should be shown for a specific player and whether or not the
+/// output is colored with ANSI escape codes.
 ///
 /// # Examples
 /// ```rust
@@ -16,13 +18,15 @@ use crate::othello::{
 /// let board = Board::standard();
 /// println!("{}", board.display());
 /// println!("{}", board.display().with_format(Format::Compact));
-/// println!("{}", board.display().with_stone(Stone::Black));
+/// println!("{}", board.display().with_legal_moves(Stone::Black));
 /// println!(
 ///     "{}",
 ///     board
 ///         .display()
-///         .with_stone(Stone::White)
-///         .with_format(Format::Standard)
+///         .with_format(Format::Unicode)
+///         .with_coordinates(true)
+///         .with_legal_moves(Stone::White)
+///         .ansi_colors()
 /// );
 /// ```
 #[derive(Clone)]
@@ -30,6 +34,8 @@ pub struct BoardDisplay<'a> {
     board: &'a Board,
     display: Format,
     stone: Option<Stone>,
+    coordinates: bool,
+    colors: bool,
 }
 
 /// Represents the different formatting options available when displaying an
@@ -40,6 +46,9 @@ pub enum Format {
     Compact,
     /// Standard formatting.
     Standard,
+    /// Formats the board with box-drawing characters and round stones,
+    /// which requires a terminal capable of displaying Unicode.
+    Unicode,
 }
 
 impl<'a> BoardDisplay<'a> {
@@ -48,6 +57,8 @@ impl<'a> BoardDisplay<'a> {
             board,
             display: Format::Standard,
             stone: None,
+            coordinates: true,
+            colors: false,
         }
     }
 
@@ -58,17 +69,23 @@ impl<'a> BoardDisplay<'a> {
     /// use magpie::othello::{Board, Stone};
     ///
     /// let board = Board::standard();
-    /// println!("{}", board.display().with_stone(Stone::Black));
+    /// println!("{}", board.display().with_legal_moves(Stone::Black));
     /// ```
     #[must_use]
-    pub fn with_stone(&self, stone: Stone) -> Self {
+    pub fn with_legal_moves(&self, stone: Stone) -> Self {
         Self {
-            board: self.board,
-            display: self.display,
             stone: Some(stone),
+            ..self.clone()
         }
     }
 
+    /// Displays the board with the legal moves for the specified stone.
+    #[deprecated(since = "0.11.3", note = "use `with_legal_moves` instead")]
+    #[must_use]
+    pub fn with_stone(&self, stone: Stone) -> Self {
+        self.with_legal_moves(stone)
+    }
+
     /// Displays the board with the specified formatting.
     ///
     /// # Examples
@@ -81,75 +98,210 @@ impl<'a> BoardDisplay<'a> {
     #[must_use]
     pub fn with_format(&self, display: Format) -> Self {
         Self {
-            board: self.board,
             display,
-            stone: self.stone,
+            ..self.clone()
+        }
+    }
+
+    /// Chooses whether or not the ranks and files are labeled, which they
+    /// are by default.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Format, Board};
+    ///
+    /// let board = Board::standard();
+    /// let text = board
+    ///     .display()
+    ///     .with_format(Format::Compact)
+    ///     .with_coordinates(false)
+    ///     .to_string();
+    /// assert!(text.lines().all(|line| line.len() == 10));
+    /// ```
+    #[must_use]
+    pub fn with_coordinates(&self, coordinates: bool) -> Self {
+        Self {
+            coordinates,
+            ..self.clone()
+        }
+    }
+
+    /// Colors the board with ANSI escape codes, for terminals that support
+    /// them.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::Board;
+    ///
+    /// let board = Board::standard();
+    /// assert!(board.display().ansi_colors().to_string().contains("\x1b["));
+    /// ```
+    #[must_use]
+    pub fn ansi_colors(&self) -> Self {
+        Self {
+            colors: true,
+            ..self.clone()
         }
     }
 }
 
-impl std::fmt::Display for BoardDisplay<'_> {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        display(f, self.board, self.stone, self.display)
+impl core::fmt::Display for BoardDisplay<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        let legal_moves = self
+            .stone
+            .map_or(0.into(), |stone| self.board.moves_for(stone));
+        let cell = |pos: Position| match self.board.stone_at(pos) {
+            Some(stone) => Cell::Stone(stone),
+            None if legal_moves & pos > 0 => Cell::Move,
+            None => Cell::Empty,
+        };
+        let style = Style {
+            format: self.display,
+            coordinates: self.coordinates,
+            colors: self.colors,
+        };
+        render(f, style, cell)
     }
 }
 
-fn display(
-    f: &mut std::fmt::Formatter,
-    board: &Board,
-    stone: Option<Stone>,
-    display: Format,
-) -> std::fmt::Result {
-    let legal_moves = stone.map_or(0.into(), |stone| board.moves_for(stone));
-    let char_at = |rank: usize, file: usize| {
+impl core::fmt::Display for Bitboard {
+    /// Formats the bitboard compactly, in the same orientation as boards,
+    /// where set squares are marked with `X`.
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let style = Style {
+            format: Format::Compact,
+            coordinates: true,
+            colors: false,
+        };
+        render(f, style, |pos| {
+            if *self & pos > 0 {
+                Cell::Marked
+            } else {
+                Cell::Empty
+            }
+        })
+    }
+}
+
+/// The contents of a single square when rendered.
+#[derive(Clone, Copy)]
+enum Cell {
+    Stone(Stone),
+    Move,
+    Marked,
+    Empty,
+}
+
+/// The options shared by all renderings.
+#[derive(Clone, Copy)]
+struct Style {
+    format: Format,
+    coordinates: bool,
+    colors: bool,
+}
+
+impl Style {
+    fn symbol(self, cell: Cell) -> &'static str {
+        match (self.format, cell) {
+            (Format::Unicode, Cell::Stone(Stone::Black)) => "●",
+            (Format::Unicode, Cell::Stone(Stone::White)) => "○",
+            (Format::Unicode, Cell::Move) => "×",
+            (Format::Unicode, Cell::Marked) => "■",
+            (Format::Unicode, Cell::Empty) => "·",
+            (_, Cell::Stone(Stone::Black)) => "B",
+            (_, Cell::Stone(Stone::White)) => "W",
+            (_, Cell::Move) => "*",
+            (_, Cell::Marked) => "X",
+            (Format::Compact, Cell::Empty) => ".",
+            (Format::Standard, Cell::Empty) => " ",
+        }
+    }
+
+    fn write_cell(self, f: &mut core::fmt::Formatter, cell: Cell) -> core::fmt::Result {
+        let symbol = self.symbol(cell);
+        if !self.colors {
+            return f.write_str(symbol);
+        }
+        // Stones on a green background, legal moves in yellow
+        let foreground = match cell {
+            Cell::Stone(Stone::Black) => "30",
+            Cell::Stone(Stone::White) => "97",
+            Cell::Move => "93",
+            Cell::Marked | Cell::Empty => "37",
+        };
+        write!(f, "\x1b[{foreground};42m{symbol}\x1b[0m")
+    }
+}
+
+fn render(
+    f: &mut core::fmt::Formatter,
+    style: Style,
+    cell: impl Fn(Position) -> Cell,
+) -> core::fmt::Result {
+    let cell_at = |rank: usize, file: usize| {
         let pos = RANKS[rank] & FILES[file];
-        let pos = Position::new_unchecked(pos);
-        board
-            .stone_at(pos)
-            .map(|stone| match stone {
-                Stone::White => "W",
-                Stone::Black => "B",
-            })
-            .or({
-                if legal_moves & pos > 0 {
-                    Some("*")
-                } else {
-                    None
-                }
-            })
-            .unwrap_or(match display {
-                Format::Compact => ".",
-                Format::Standard => " ",
-            })
+        cell(Position::new_unchecked(pos))
     };
+    // The rank labels are replaced by nothing when coordinates are hidden
+    let label = |f: &mut core::fmt::Formatter, rank: usize| {
+        if style.coordinates {
+            write!(f, "{} ", rank + 1)
+        } else {
+            Ok(())
+        }
+    };
+    let margin = if style.coordinates { "  " } else { "" };
 
-    match display {
+    match style.format {
         Format::Compact => {
-            writeln!(f, "   ABCDEFGH")?;
-            writeln!(f, "  +--------+")?;
+            if style.coordinates {
+                writeln!(f, "   ABCDEFGH")?;
+            }
+            writeln!(f, "{margin}+--------+")?;
             for rank in 0..8 {
-                write!(f, "{} |", rank + 1)?;
+                label(f, rank)?;
+                f.write_str("|")?;
                 for file in 0..8 {
-                    write!(f, "{}", char_at(rank, file))?;
+                    style.write_cell(f, cell_at(rank, file))?;
                 }
                 writeln!(f, "|")?;
             }
-            writeln!(f, "  +--------+")
+            writeln!(f, "{margin}+--------+")
         }
         Format::Standard => {
-            let top_row = "    A   B   C   D   E   F   G   H";
-            let horizontal = format!("  +{}", "---+".repeat(8));
+            let horizontal = "+---+---+---+---+---+---+---+---+";
 
-            writeln!(f, "{top_row}")?;
+            if style.coordinates {
+                writeln!(f, "    A   B   C   D   E   F   G   H")?;
+            }
             for rank in 0..8 {
-                writeln!(f, "{horizontal}")?;
-                write!(f, "{} |", rank + 1)?;
+                writeln!(f, "{margin}{horizontal}")?;
+                label(f, rank)?;
+                f.write_str("|")?;
                 for file in 0..8 {
-                    write!(f, " {} |", char_at(rank, file))?;
+                    f.write_str(" ")?;
+                    style.write_cell(f, cell_at(rank, file))?;
+                    f.write_str(" |")?;
                 }
                 writeln!(f)?;
             }
-            writeln!(f, "{horizontal}")
+            writeln!(f, "{margin}{horizontal}")
+        }
+        Format::Unicode => {
+            if style.coordinates {
+                writeln!(f, "    A B C D E F G H")?;
+            }
+            writeln!(f, "{margin}┌─────────────────┐")?;
+            for rank in 0..8 {
+                label(f, rank)?;
+                f.write_str("│")?;
+                for file in 0..8 {
+                    f.write_str(" ")?;
+                    style.write_cell(f, cell_at(rank, file))?;
+                }
+                writeln!(f, " │")?;
+            }
+            writeln!(f, "{margin}└─────────────────┘")
         }
     }
 }
@@ -1,5 +1,9 @@
-use crate::othello::{Bitboard, Board, BoardDisplay, OthelloError, Position, Stone};
-use std::cmp::Ordering;
+use crate::othello::{
+    Bitboard, Board, BoardDisplay, Move, OthelloError, Position, Scoring, Stone, Transcript,
+    constants::CENTER,
+};
+use alloc::vec::Vec;
+use core::cmp::Ordering;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -8,14 +12,121 @@ use serde::{Deserialize, Serialize};
 ///
 /// To interact with the game it is useful to understand the bitboards that
 /// the board uses. The [`Board`]-struct documents these.
+///
+/// Every move played is recorded in a history, which makes it possible to
+/// undo and redo moves without keeping copies of the game around.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
 pub struct Game {
+    #[cfg_attr(feature = "serde", serde(default))]
+    rules: Rules,
     board: Board,
     next_player: Stone,
     passed_last_turn: bool,
+    history: Vec<PlayedMove>,
+    undone: Vec<PlayedMove>,
+}
+
+/// Represents a move that has been played in a [`Game`], along with the
+/// information required to undo it.
+///
+/// [`Game`]: crate::othello::Game
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct PlayedMove {
+    stone: Stone,
+    mv: Move,
+    flipped: Bitboard,
+    passed_before: bool,
+}
+
+impl PlayedMove {
+    /// Returns the stone of the player who made the move.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Game, Stone};
+    ///
+    /// let mut game = Game::new();
+    /// game.play("f5".parse().unwrap()).unwrap();
+    /// assert_eq!(Stone::Black, game.history()[0].stone());
+    /// ```
+    #[must_use]
+    pub fn stone(&self) -> Stone {
+        self.stone
+    }
+
+    /// Returns the move that was made, which is either a placed stone or a
+    /// pass.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Game, Move};
+    ///
+    /// let mut game = Game::new();
+    /// game.pass_turn();
+    /// assert_eq!(Move::Pass, game.history()[0].mv());
+    /// ```
+    #[must_use]
+    pub fn mv(&self) -> Move {
+        self.mv
+    }
+
+    /// Returns the stones that were flipped by the move.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::Game;
+    ///
+    /// let mut game = Game::new();
+    /// game.play("f5".parse().unwrap()).unwrap();
+    /// assert_eq!(1, game.history()[0].flipped().count_set());
+    /// ```
+    #[must_use]
+    pub fn flipped(&self) -> Bitboard {
+        self.flipped
+    }
+}
+
+/// The rule sets a [`Game`] can be played with.
+///
+/// [`Game`]: crate::othello::Game
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+pub enum Rules {
+    /// Modern Othello, where the game starts with four stones already
+    /// placed in the center.
+    #[default]
+    Othello,
+    /// Classic Reversi, where the game starts on an empty board and the
+    /// players take turns placing the first four stones on any of the empty
+    /// center squares. These placements flip nothing, and regular play
+    /// begins once the center is filled.
+    Reversi,
+}
+
+impl Rules {
+    /// Returns the board the game starts with under these rules.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Board, Rules};
+    ///
+    /// assert_eq!(Board::standard(), Rules::Othello.starting_board());
+    /// assert_eq!(Board::empty(), Rules::Reversi.starting_board());
+    /// ```
+    #[must_use]
+    pub fn starting_board(self) -> Board {
+        match self {
+            Rules::Othello => Board::standard(),
+            Rules::Reversi => Board::empty(),
+        }
+    }
 }
 
+/// The corners given away as a handicap, in order: A1, H8, H1 and A8.
+const HANDICAP_CORNERS: [u64; 4] = [1 << 63, 1, 1 << 56, 1 << 7];
+
 /// This enum represents all states the game can be in.
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
 pub enum Status {
@@ -57,6 +168,61 @@ impl Game {
         Game::from_state(Board::standard(), Stone::Black, false).unwrap()
     }
 
+    /// Returns a game at the start of the specified rules, with black to
+    /// move.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Game, Rules};
+    ///
+    /// assert_eq!(Game::new(), Game::with_rules(Rules::Othello));
+    ///
+    /// let mut game = Game::with_rules(Rules::Reversi);
+    /// assert_eq!(64, game.empty_squares().count_set());
+    /// // Any of the four center squares may be taken first
+    /// assert_eq!(4, game.moves().count_set());
+    /// game.play("e5".parse().unwrap()).unwrap();
+    /// assert_eq!(3, game.moves().count_set());
+    /// ```
+    #[must_use]
+    pub fn with_rules(rules: Rules) -> Self {
+        Self {
+            rules,
+            ..Game::from_state(rules.starting_board(), Stone::Black, false).unwrap()
+        }
+    }
+
+    /// Returns the rules the game is played with.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Game, Rules};
+    ///
+    /// assert_eq!(Rules::Othello, Game::new().rules());
+    /// ```
+    #[must_use]
+    pub fn rules(&self) -> Rules {
+        self.rules
+    }
+
+    /// Returns whether or not the players are still placing the first four
+    /// stones in the center, which only happens under [`Rules::Reversi`].
+    ///
+    /// [`Rules::Reversi`]: crate::othello::Rules::Reversi
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Game, Rules};
+    ///
+    /// assert!(!Game::new().is_placing());
+    /// assert!(Game::with_rules(Rules::Reversi).is_placing());
+    /// ```
+    #[must_use]
+    pub fn is_placing(&self) -> bool {
+        let occupied = !self.board.empty_squares();
+        self.rules == Rules::Reversi && occupied & !CENTER == 0 && occupied != CENTER
+    }
+
     /// Returns a game with the specified parameters set.
     ///
     /// If the supplied board is invalid an error will be returned instead.
@@ -78,15 +244,78 @@ impl Game {
     ) -> Result<Self, OthelloError> {
         if board.is_valid() {
             Ok(Self {
+                rules: Rules::Othello,
                 board,
                 next_player,
                 passed_last_turn,
+                history: Vec::new(),
+                undone: Vec::new(),
             })
         } else {
             Err(OthelloError::PiecesOverlapping)
         }
     }
 
+    /// Returns a game starting from an arbitrary position, with the
+    /// specified player to move.
+    ///
+    /// Unlike [`from_state`], the board is checked for being a position that
+    /// can occur in a game: no two stones may overlap, and the four center
+    /// squares must be occupied, as they are filled from the start and can
+    /// never be emptied again.
+    ///
+    /// [`from_state`]: crate::othello::Game::from_state
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Board, Game, OthelloError, Stone};
+    ///
+    /// let mut board = Board::standard();
+    /// board.play(Stone::Black, "f5".parse().unwrap());
+    /// let game = Game::from_position(board, Stone::White).unwrap();
+    /// assert_eq!(3, game.moves().count_set());
+    ///
+    /// let result = Game::from_position(Board::empty(), Stone::Black);
+    /// assert_eq!(Err(OthelloError::ImpossiblePosition), result);
+    /// ```
+    pub fn from_position(board: Board, to_move: Stone) -> Result<Self, OthelloError> {
+        let game = Game::from_state(board, to_move, false)?;
+        if board.empty_squares() & CENTER == 0 {
+            Ok(game)
+        } else {
+            Err(OthelloError::ImpossiblePosition)
+        }
+    }
+
+    /// Returns a game from the standard opening position, where the
+    /// specified player is given a number of corners as a handicap.
+    ///
+    /// Corners are handed out in the order A1, H8, H1 and A8, so that two
+    /// corners are diagonally opposite. Black moves first as usual.
+    ///
+    /// Returns an error if more than four corners are requested.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Game, Stone};
+    ///
+    /// let game = Game::with_handicap(Stone::Black, 2).unwrap();
+    /// assert_eq!(Some(Stone::Black), game.stone_at("a1".parse().unwrap()));
+    /// assert_eq!(Some(Stone::Black), game.stone_at("h8".parse().unwrap()));
+    /// assert_eq!(None, game.stone_at("h1".parse().unwrap()));
+    /// assert!(Game::with_handicap(Stone::Black, 5).is_err());
+    /// ```
+    pub fn with_handicap(stone: Stone, corners: u8) -> Result<Self, OthelloError> {
+        if usize::from(corners) > HANDICAP_CORNERS.len() {
+            return Err(OthelloError::ImpossiblePosition);
+        }
+        let mut board = Board::standard();
+        for corner in &HANDICAP_CORNERS[..usize::from(corners)] {
+            board.place_stone_unchecked(stone, Bitboard(*corner));
+        }
+        Game::from_position(board, Stone::Black)
+    }
+
     /// Returns the stone of the current player.
     ///
     /// # Examples
@@ -118,12 +347,65 @@ impl Game {
     /// assert!(game.current_turn() == Stone::White);
     /// ```
     pub fn pass_turn(&mut self) {
-        self.next_player = self.next_player.flip();
-        self.passed_last_turn = true;
+        self.undone.clear();
+        self.apply(PlayedMove {
+            stone: self.next_player,
+            mv: Move::Pass,
+            flipped: Bitboard(0),
+            passed_before: self.passed_last_turn,
+        });
+    }
+
+    /// Passes the turn if, and only if, the current player has no legal moves
+    /// while the game is still in progress.
+    ///
+    /// Returns whether or not the turn was passed. This makes it possible to
+    /// drive a game forward without having to handle forced passes manually.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Board, Game, Stone};
+    ///
+    /// // Black has no legal moves on this board, but white does
+    /// let board = Board::try_from((0x40_00_00_00_00_00_00_00, 0x80_00_00_00_00_00_00_00)).unwrap();
+    /// let mut game = Game::from_state(board, Stone::Black, false).unwrap();
+    /// assert!(game.must_pass());
+    /// assert!(game.pass_if_forced());
+    /// assert_eq!(game.current_turn(), Stone::White);
+    /// // White is not forced to pass
+    /// assert!(!game.pass_if_forced());
+    /// ```
+    pub fn pass_if_forced(&mut self) -> bool {
+        let forced = self.must_pass();
+        if forced {
+            self.pass_turn();
+        }
+        forced
+    }
+
+    /// Returns whether or not the current player is forced to pass their turn.
+    ///
+    /// This is the case when the current player has no legal moves while the
+    /// game is still in progress.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::Game;
+    ///
+    /// let game = Game::new();
+    /// assert!(!game.must_pass());
+    /// ```
+    #[must_use]
+    pub fn must_pass(&self) -> bool {
+        self.moves().is_empty() && self.status() == Status::Progressing
     }
 
     /// Reports the status of the game.
     ///
+    /// The game is considered concluded if the previous player passed their
+    /// turn and the current player has no legal moves, or if neither player
+    /// has any legal moves left.
+    ///
     /// # Examples
     /// ```rust
     /// use magpie::othello::{Game, Status, Stone};
@@ -133,7 +415,11 @@ impl Game {
     /// ```
     #[must_use]
     pub fn status(&self) -> Status {
-        let finished = self.passed_last_turn && self.board.moves_for(self.next_player).is_empty();
+        if self.is_placing() {
+            return Status::Progressing;
+        }
+        let finished = self.board.moves_for(self.next_player).is_empty()
+            && (self.passed_last_turn || self.board.moves_for(self.next_player.flip()).is_empty());
         if finished {
             let black_stones = self.board.bits_for(Stone::Black).count_set();
             let white_stones = self.board.bits_for(Stone::White).count_set();
@@ -148,10 +434,38 @@ impl Game {
         }
     }
 
+    /// Returns the final scores of black and white, in that order, using the
+    /// specified convention, or `None` if the game has not concluded.
+    ///
+    /// The winner reported by [`status`] is the same under both
+    /// conventions.
+    ///
+    /// [`status`]: crate::othello::Game::status
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Board, Game, Scoring, Stone};
+    ///
+    /// assert_eq!(None, Game::new().final_score(Scoring::DiscCount));
+    ///
+    /// // Neither player can move, with 60 empty squares left
+    /// let board = Board::try_from((0xE0 << 56, 1)).unwrap();
+    /// let game = Game::from_state(board, Stone::Black, false).unwrap();
+    /// assert_eq!(Some((3, 1)), game.final_score(Scoring::DiscCount));
+    /// assert_eq!(Some((63, 1)), game.final_score(Scoring::WinnerTakesEmpties));
+    /// ```
+    #[must_use]
+    pub fn final_score(&self, scoring: Scoring) -> Option<(u8, u8)> {
+        match self.status() {
+            Status::Progressing => None,
+            Status::Win(_) | Status::Draw => Some(scoring.score(&self.board)),
+        }
+    }
+
     /// Places a stone in the specified position and updates the board accordingly.
     ///
-    /// If the move is illegal an error will be returned leaving the game
-    /// untouched.
+    /// If the game has concluded or the move is illegal an error will be
+    /// returned leaving the game untouched.
     ///
     /// # Examples
     /// ```rust
@@ -166,15 +480,175 @@ impl Game {
     /// assert!(game.play(pos).is_ok());
     /// ```
     pub fn play(&mut self, pos: Position) -> Result<(), OthelloError> {
+        if self.status() != Status::Progressing {
+            return Err(OthelloError::GameOver);
+        }
         if self.is_legal_move(pos) {
-            self.board.play(self.next_player, pos);
-            self.next_player = self.next_player.flip();
+            let flipped = self.board.flips_for(self.next_player, pos);
+
+            self.undone.clear();
+            self.apply(PlayedMove {
+                stone: self.next_player,
+                mv: Move::Play(pos),
+                flipped,
+                passed_before: self.passed_last_turn,
+            });
             Ok(())
         } else {
             Err(OthelloError::IllegalMove)
         }
     }
 
+    /// Makes the move for the specified player, which is either a placed
+    /// stone or a pass.
+    ///
+    /// Unlike [`play`] and [`pass_turn`], this checks everything that can go
+    /// wrong when following a game from an outside source: the game must
+    /// still be in progress, it must be the turn of the player, placed
+    /// stones must be legal and passes are only allowed when the player has
+    /// no legal moves. On error the game is left untouched.
+    ///
+    /// [`play`]: crate::othello::Game::play
+    /// [`pass_turn`]: crate::othello::Game::pass_turn
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Game, Move, OthelloError, Stone};
+    ///
+    /// let mut game = Game::new();
+    /// let f5 = Move::Play("f5".parse().unwrap());
+    /// assert_eq!(Err(OthelloError::WrongTurn), game.play_move(Stone::White, f5));
+    /// assert_eq!(Err(OthelloError::IllegalMove), game.play_move(Stone::Black, Move::Pass));
+    /// assert_eq!(Ok(()), game.play_move(Stone::Black, f5));
+    /// ```
+    pub fn play_move(&mut self, stone: Stone, mv: Move) -> Result<(), OthelloError> {
+        if self.status() != Status::Progressing {
+            return Err(OthelloError::GameOver);
+        }
+        if stone != self.next_player {
+            return Err(OthelloError::WrongTurn);
+        }
+        match mv {
+            Move::Play(pos) => self.play(pos),
+            Move::Pass if self.must_pass() => {
+                self.pass_turn();
+                Ok(())
+            }
+            Move::Pass => Err(OthelloError::IllegalMove),
+        }
+    }
+
+    /// Takes back the last move, including passes.
+    ///
+    /// Returns the move that was undone, or `None` if there are no moves to
+    /// undo. Undone moves can be replayed with [`redo`] until a new move is
+    /// played.
+    ///
+    /// [`redo`]: crate::othello::Game::redo
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::Game;
+    ///
+    /// let mut game = Game::new();
+    /// game.play("f5".parse().unwrap()).unwrap();
+    /// assert!(game.undo().is_some());
+    /// assert_eq!(Game::new().board(), game.board());
+    /// assert!(game.undo().is_none());
+    /// ```
+    pub fn undo(&mut self) -> Option<PlayedMove> {
+        let played = self.history.pop()?;
+        if let Move::Play(pos) = played.mv {
+            let placed = Bitboard::from(pos);
+            self.board
+                .remove_stone_unchecked(played.stone, placed | played.flipped);
+            self.board
+                .place_stone_unchecked(played.stone.flip(), played.flipped);
+        }
+        self.board.debug_validate();
+        self.next_player = played.stone;
+        self.passed_last_turn = played.passed_before;
+        self.undone.push(played);
+        Some(played)
+    }
+
+    /// Replays the last move taken back by [`undo`].
+    ///
+    /// Returns the move that was replayed, or `None` if there are no moves
+    /// to redo.
+    ///
+    /// [`undo`]: crate::othello::Game::undo
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::Game;
+    ///
+    /// let mut game = Game::new();
+    /// game.play("f5".parse().unwrap()).unwrap();
+    /// let played = game.clone();
+    /// game.undo();
+    /// game.redo();
+    /// assert_eq!(played.board(), game.board());
+    /// assert!(game.redo().is_none());
+    /// ```
+    pub fn redo(&mut self) -> Option<PlayedMove> {
+        let played = self.undone.pop()?;
+        self.apply(played);
+        Some(played)
+    }
+
+    /// Returns every move played so far, oldest first.
+    ///
+    /// Games created with [`from_state`] start with an empty history.
+    ///
+    /// [`from_state`]: crate::othello::Game::from_state
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Game, Move};
+    ///
+    /// let mut game = Game::new();
+    /// game.play("f5".parse().unwrap()).unwrap();
+    /// game.play("d6".parse().unwrap()).unwrap();
+    /// assert_eq!(2, game.history().len());
+    /// assert_eq!(Move::Play("d6".parse().unwrap()), game.history()[1].mv());
+    /// ```
+    #[must_use]
+    pub fn history(&self) -> &[PlayedMove] {
+        &self.history
+    }
+
+    /// Returns the moves in the history of the game as a transcript, which
+    /// formats as a compact string such as `f5d6c3`.
+    ///
+    /// The transcript only replays to the same game if the game started
+    /// from the standard opening position.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::Transcript;
+    ///
+    /// let game = "f5d6c3".parse::<Transcript>().unwrap().replay().unwrap();
+    /// assert_eq!("f5d6c3", game.transcript().to_string());
+    /// ```
+    #[must_use]
+    pub fn transcript(&self) -> Transcript {
+        Transcript::from(self)
+    }
+
+    fn apply(&mut self, played: PlayedMove) {
+        match played.mv {
+            Move::Play(pos) => {
+                self.board
+                    .play_with_flips(played.stone, pos, played.flipped);
+                self.passed_last_turn = false;
+            }
+            Move::Pass => self.passed_last_turn = true,
+        }
+        self.next_player = played.stone.flip();
+        self.history.push(played);
+    }
+
     /// Returns a copy of the internal board used in this game.
     ///
     /// # Examples
@@ -220,7 +694,11 @@ impl Game {
     /// ```
     #[must_use]
     pub fn is_legal_move(&self, pos: Position) -> bool {
-        self.board.is_legal_move(self.next_player, pos)
+        if self.is_placing() {
+            self.board.empty_squares() & CENTER & pos != 0
+        } else {
+            self.board.is_legal_move(self.next_player, pos)
+        }
     }
 
     /// Calculates and returns the set of all legal moves for the current player.
@@ -234,7 +712,11 @@ impl Game {
     /// ```
     #[must_use]
     pub fn moves(&self) -> Bitboard {
-        self.board.moves_for(self.next_player)
+        if self.is_placing() {
+            self.board.empty_squares() & CENTER
+        } else {
+            self.board.moves_for(self.next_player)
+        }
     }
 
     /// Returns a bitboard representing all stones for the specified player.
@@ -287,7 +769,7 @@ impl Game {
 
     /// Returns a struct that implements [`Display`] for customizing the display of Othello boards.
     ///
-    /// [`Display`]: std::fmt::Display
+    /// [`Display`]: core::fmt::Display
     ///
     /// # Examples
     /// ```rust
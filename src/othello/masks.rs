@@ -0,0 +1,41 @@
+//! Masks of the named classes of squares on the board.
+//!
+//! Othello players refer to squares by their role near the corners and
+//! edges. The masks are generated by the build script and can be combined
+//! with the set operations of [`Bitboard`] in constant contexts.
+//!
+//! [`Bitboard`]: crate::othello::Bitboard
+//!
+//! # Examples
+//! ```rust
+//! use magpie::othello::{Bitboard, Position, masks};
+//!
+//! // Squares that are risky to play before the adjacent corner is taken
+//! const DANGER: Bitboard = masks::X_SQUARES.union(masks::C_SQUARES);
+//! assert_eq!(12, DANGER.count_set());
+//! assert!(masks::X_SQUARES.contains(Position::B2));
+//! assert!(masks::SWEET_16.contains(Position::C3));
+//! ```
+
+use crate::othello::{Bitboard, constants};
+
+/// The four corners: A1, H1, A8 and H8.
+pub const CORNERS: Bitboard = Bitboard::new(constants::CORNERS);
+/// The eight squares orthogonally adjacent to the corners, such as B1 and
+/// A2.
+pub const C_SQUARES: Bitboard = Bitboard::new(constants::C_SQUARES);
+/// The four squares diagonally adjacent to the corners: B2, G2, B7 and G7.
+pub const X_SQUARES: Bitboard = Bitboard::new(constants::X_SQUARES);
+/// The eight squares on the edges two steps away from the corners, such as
+/// C1 and A3.
+pub const A_SQUARES: Bitboard = Bitboard::new(constants::A_SQUARES);
+/// The eight squares in the middle of the edges, such as D1 and E1.
+pub const B_SQUARES: Bitboard = Bitboard::new(constants::B_SQUARES);
+/// The 28 squares along the edges, corners included.
+pub const EDGES: Bitboard = Bitboard::new(constants::EDGES);
+/// The 24 squares along the edges, without the corners.
+pub const INNER_EDGES: Bitboard = Bitboard::new(constants::INNER_EDGES);
+/// The 16 squares from C3 to F6, where most of the opening is played.
+pub const SWEET_16: Bitboard = Bitboard::new(constants::SWEET_16);
+/// The four center squares, which are occupied from the start of the game.
+pub const CENTER: Bitboard = Bitboard::new(constants::CENTER);
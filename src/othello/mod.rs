@@ -1,23 +1,86 @@
+/// Strategies for generating values in property tests with proptest.
+#[cfg(feature = "proptest")]
+mod arbitrary;
+#[cfg(feature = "bench")]
+#[doc(hidden)]
+pub mod bench;
 /// Represents a 8x8 board.
 mod bitboard;
 /// Implements various useful traits for Bitboards and Positions
 mod bitboard_position_impl;
 /// Represents an Othello board and provides convenient functions to manipulate it.
 mod board;
+/// An Othello board packed into a single 128-bit integer.
+#[cfg(feature = "board128")]
+mod board128;
+#[cfg(feature = "serde")]
+pub mod board_serde;
+#[cfg(feature = "std")]
+pub mod book;
 /// Collection of constants useful for various calculations.
-mod constants;
+pub(crate) mod constants;
+/// Compares boards and reconstructs the moves between them.
+mod diff;
+/// The eight directions a line can extend in on the board.
+mod direction;
 /// Structs and functions that format Othello boards.
 mod display;
 /// Represents an Othello game.
+#[cfg(feature = "alloc")]
 mod game;
+/// Interchangeable implementations of flip calculation and move generation.
+mod kernel;
+pub mod masks;
+/// Counts move sequences to validate move generation.
+mod perft;
+/// Encodes boards as planes for neural networks.
+mod planes;
 /// Represents a single position on a 8x8 board.
 mod position;
+/// Parses and formats boards as single-line position strings.
+mod position_str;
+/// Random playouts and random reachable boards.
+#[cfg(feature = "rand")]
+mod random;
+/// Checks whether boards can arise from the opening position.
+mod reachability;
+/// Conventions for scoring finished games.
+mod scoring;
+/// Batched move generation for several boards at once.
+#[cfg(feature = "simd")]
+mod simd;
+/// Othello boards of sizes other than 8x8.
+mod sized;
+/// The contents of a single square on the board.
+mod square;
 /// An enum that represents the two stone colors players can play with.
 mod stone;
+/// The eight symmetries of the board.
+mod symmetry;
+/// Parses and serializes sequences of moves.
+#[cfg(feature = "alloc")]
+mod transcript;
 
 pub use bitboard::Bitboard;
-pub use board::{Board, OthelloError};
+pub use board::{Board, OthelloError, Undo};
+#[cfg(feature = "board128")]
+pub use board128::Board128;
+pub use diff::BoardDiff;
+pub use direction::Direction;
 pub use display::{BoardDisplay, Format};
-pub use game::{Game, Status};
+#[cfg(feature = "alloc")]
+pub use game::{Game, PlayedMove, Rules, Status};
+pub use kernel::{Kernel, KernelError};
+pub use perft::perft;
+#[cfg(feature = "alloc")]
+pub use perft::perft_divide;
+pub use planes::Planes;
 pub use position::{Position, PositionError};
+pub use position_str::PositionStrError;
+pub use scoring::Scoring;
+pub use sized::{Board6x6, Board10x10, SizedBoard};
+pub use square::Square;
 pub use stone::Stone;
+pub use symmetry::Symmetry;
+#[cfg(feature = "alloc")]
+pub use transcript::{Move, Transcript, TranscriptError};
@@ -0,0 +1,98 @@
+use crate::othello::{Board, Stone};
+
+#[cfg(feature = "alloc")]
+use crate::othello::Move;
+#[cfg(feature = "alloc")]
+use alloc::{vec, vec::Vec};
+
+/// Counts the number of move sequences of the specified length.
+///
+/// Passes count as moves, and concluded games count as a single sequence
+/// even if they end before the depth is reached. These are the conventions
+/// used by the commonly cited Othello perft numbers, which makes this
+/// function useful for validating move generation.
+///
+/// # Examples
+/// ```rust
+/// use magpie::othello::{Board, Stone, perft};
+///
+/// let board = Board::standard();
+/// assert_eq!(4, perft(&board, Stone::Black, 1));
+/// assert_eq!(244, perft(&board, Stone::Black, 4));
+/// ```
+#[must_use]
+pub fn perft(board: &Board, stone: Stone, depth: u8) -> u64 {
+    count(board, stone, false, depth)
+}
+
+/// Counts the number of move sequences of the specified length, split by
+/// the first move.
+///
+/// The counts sum to the result of [`perft`] for any depth but 0, at which
+/// no move is made and the result is empty. Listing them per move makes it
+/// easier to track down the source of a discrepancy.
+///
+/// If the player has no legal moves, the only entry is a pass. This
+/// includes concluded games, whose single sequence is reported as a pass,
+/// matching the way [`perft`] counts them.
+///
+/// [`perft`]: crate::othello::perft
+///
+/// # Examples
+/// ```rust
+/// use magpie::othello::{Board, Move, Stone, perft, perft_divide};
+///
+/// let board = Board::standard();
+/// let divide = perft_divide(&board, Stone::Black, 3);
+/// assert_eq!(4, divide.len());
+/// assert!(divide.iter().all(|(_, count)| *count == 14));
+/// let total: u64 = divide.iter().map(|(_, count)| count).sum();
+/// assert_eq!(perft(&board, Stone::Black, 3), total);
+/// ```
+#[cfg(feature = "alloc")]
+#[must_use]
+pub fn perft_divide(board: &Board, stone: Stone, depth: u8) -> Vec<(Move, u64)> {
+    if depth == 0 {
+        return Vec::new();
+    }
+    let moves = board.moves_for(stone);
+    if moves.is_empty() {
+        return vec![(Move::Pass, count(board, stone.flip(), true, depth - 1))];
+    }
+    moves
+        .hot_bits()
+        .map(|pos| {
+            let mut next = *board;
+            next.play(stone, pos);
+            (
+                Move::Play(pos),
+                count(&next, stone.flip(), false, depth - 1),
+            )
+        })
+        .collect()
+}
+
+fn count(board: &Board, stone: Stone, passed: bool, depth: u8) -> u64 {
+    if depth == 0 {
+        return 1;
+    }
+    let moves = board.moves_for(stone);
+    if moves.is_empty() {
+        if passed {
+            1
+        } else {
+            count(board, stone.flip(), true, depth - 1)
+        }
+    } else if depth == 1 {
+        moves.count_set().into()
+    } else {
+        moves
+            .hot_bits()
+            .map(|pos| {
+                let mut next = *board;
+                next.play(stone, pos);
+                count(&next, stone.flip(), false, depth - 1)
+            })
+            .sum()
+    }
+}
@@ -0,0 +1,85 @@
+use crate::othello::{Bitboard, Board, Stone, Symmetry};
+
+/// A board encoded as two 8x8 planes of floats, indexed by plane, rank and
+/// file.
+///
+/// The first plane holds the stones of the player to move and the second
+/// plane the stones of their opponent, where `1.0` marks a stone and `0.0`
+/// an empty square. This is the usual input of neural network evaluators.
+pub type Planes = [[[f32; 8]; 8]; 2];
+
+impl Board {
+    /// Encodes the board as two bitboards, from the perspective of the
+    /// specified player.
+    ///
+    /// The first bitboard holds the stones of the player and the second one
+    /// those of their opponent.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Board, Stone};
+    ///
+    /// let board = Board::standard();
+    /// let [own, opponent] = board.to_bit_planes(Stone::White);
+    /// assert_eq!(board.bits_for(Stone::White), own);
+    /// assert_eq!(board.bits_for(Stone::Black), opponent);
+    /// ```
+    #[must_use]
+    pub fn to_bit_planes(&self, stone: Stone) -> [Bitboard; 2] {
+        [self.bits_for(stone), self.bits_for(stone.flip())]
+    }
+
+    /// Encodes the board as [`Planes`], from the perspective of the
+    /// specified player.
+    ///
+    /// [`Planes`]: crate::othello::Planes
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Board, Stone};
+    ///
+    /// let planes = Board::standard().to_planes(Stone::Black);
+    /// // D4 is white and E4 is black, on the fourth rank
+    /// assert_eq!(0.0, planes[0][3][3]);
+    /// assert_eq!(1.0, planes[1][3][3]);
+    /// assert_eq!(1.0, planes[0][3][4]);
+    /// ```
+    #[must_use]
+    pub fn to_planes(&self, stone: Stone) -> Planes {
+        encode(self.to_bit_planes(stone))
+    }
+
+    /// Encodes all eight symmetric variants of the board as [`Planes`],
+    /// from the perspective of the specified player.
+    ///
+    /// The variants are returned in the same order as [`Symmetry::ALL`],
+    /// which makes it easy to augment training data with every equivalent
+    /// position at once.
+    ///
+    /// [`Planes`]: crate::othello::Planes
+    /// [`Symmetry::ALL`]: crate::othello::Symmetry::ALL
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Board, Stone};
+    ///
+    /// let board = Board::standard();
+    /// let variants = board.symmetric_planes(Stone::Black);
+    /// assert_eq!(board.to_planes(Stone::Black), variants[0]);
+    /// ```
+    #[must_use]
+    pub fn symmetric_planes(&self, stone: Stone) -> [Planes; 8] {
+        let bit_planes = self.to_bit_planes(stone);
+        Symmetry::ALL.map(|symmetry| encode(bit_planes.map(|bits| bits.transform(symmetry))))
+    }
+}
+
+fn encode(bit_planes: [Bitboard; 2]) -> Planes {
+    let mut planes = [[[0.0; 8]; 8]; 2];
+    for (plane, bits) in planes.iter_mut().zip(bit_planes) {
+        for pos in bits.hot_bits() {
+            plane[usize::from(pos.rank())][usize::from(pos.file())] = 1.0;
+        }
+    }
+    planes
+}
@@ -1,8 +1,12 @@
 use crate::othello::{
     Bitboard,
-    constants::{FILES, POSITIONS, POSITIONS_AS_NOTATION, RANKS},
+    board::neighbours,
+    constants::{C_SQUARES, CORNERS, FILES, POSITIONS, POSITIONS_AS_NOTATION, RANKS, X_SQUARES},
 };
 
+#[cfg(feature = "alloc")]
+use alloc::string::{String, ToString};
+
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -53,10 +57,37 @@ impl Position {
     /// let b: Position = (1 << 32).try_into().unwrap();
     /// assert_eq!(b.raw(), (1 << 32));
     /// ```
-    pub(crate) fn new_unchecked(bitboard: u64) -> Self {
+    pub(crate) const fn new_unchecked(bitboard: u64) -> Self {
         Self(bitboard)
     }
 
+    /// Constructs a position from a `u64`.
+    ///
+    /// Returns an error if the `u64` does not have exactly one bit set.
+    /// Unlike the [`TryFrom<u64>`] implementation, this can be used in
+    /// constant expressions.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::Position;
+    ///
+    /// const E5: Position = match Position::try_new(1 << 27) {
+    ///     Ok(pos) => pos,
+    ///     Err(_) => panic!(),
+    /// };
+    /// assert_eq!(Position::E5, E5);
+    /// assert!(Position::try_new(0b11).is_err());
+    /// ```
+    ///
+    /// [`TryFrom<u64>`]: #impl-TryFrom<u64>-for-Position
+    pub const fn try_new(bitboard: u64) -> Result<Self, PositionError> {
+        if bitboard.is_power_of_two() {
+            Ok(Position::new_unchecked(bitboard))
+        } else {
+            Err(PositionError::NotOneHotBitboard)
+        }
+    }
+
     /// Retrieves the underlying u64.
     ///
     /// # Examples
@@ -67,7 +98,7 @@ impl Position {
     /// assert_eq!(p.raw(), (1 << 32));
     /// ```
     #[must_use]
-    pub fn raw(self) -> u64 {
+    pub const fn raw(self) -> u64 {
         self.0
     }
 
@@ -129,6 +160,7 @@ impl Position {
     ///
     /// [`Position`]: crate::othello::Position
     #[must_use]
+    #[cfg(feature = "alloc")]
     pub fn to_notation(self) -> String {
         POSITIONS_AS_NOTATION[self.0.leading_zeros() as usize].to_string()
     }
@@ -154,6 +186,110 @@ impl Position {
         }
     }
 
+    /// Constructs a position from a zero-indexed rank and file.
+    ///
+    /// Returns an error if either the rank or file does not fit
+    /// into a 8x8 board.
+    ///
+    /// How ranks and files are represented can be found in the top-level
+    /// documentation for [`Position`].
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::Position;
+    ///
+    /// let p = Position::from_rank_file(2, 3).unwrap();
+    /// assert_eq!(p.to_notation(), "d3");
+    /// assert!(Position::from_rank_file(8, 0).is_err());
+    /// ```
+    ///
+    /// [`Position`]: crate::othello::Position
+    pub fn from_rank_file(rank: u8, file: u8) -> Result<Self, PositionError> {
+        Position::try_from((rank, file))
+    }
+
+    /// Returns the up to eight squares adjacent to the position.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::Position;
+    ///
+    /// let a1 = Position::try_from("a1").unwrap();
+    /// assert_eq!(3, a1.neighbors().count_set());
+    ///
+    /// let d4 = Position::try_from("d4").unwrap();
+    /// assert_eq!(8, d4.neighbors().count_set());
+    /// ```
+    #[must_use]
+    pub fn neighbors(self) -> Bitboard {
+        Bitboard(neighbours(self.0))
+    }
+
+    /// Returns the number of king moves between two positions, which is
+    /// the larger of the rank and file distances.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::Position;
+    ///
+    /// let a1 = Position::try_from("a1").unwrap();
+    /// let c2 = Position::try_from("c2").unwrap();
+    /// let h8 = Position::try_from("h8").unwrap();
+    /// assert_eq!(2, a1.distance(c2));
+    /// assert_eq!(7, a1.distance(h8));
+    /// assert_eq!(0, a1.distance(a1));
+    /// ```
+    #[must_use]
+    pub fn distance(self, other: Position) -> u8 {
+        let ranks = self.rank().abs_diff(other.rank());
+        let files = self.file().abs_diff(other.file());
+        ranks.max(files)
+    }
+
+    /// Returns true if and only if the position is one of the four corners.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::Position;
+    ///
+    /// assert!(Position::try_from("h1").unwrap().is_corner());
+    /// assert!(!Position::try_from("g1").unwrap().is_corner());
+    /// ```
+    #[must_use]
+    pub fn is_corner(self) -> bool {
+        self.0 & CORNERS != 0
+    }
+
+    /// Returns true if and only if the position is an X-square, one of the
+    /// four squares diagonally adjacent to a corner.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::Position;
+    ///
+    /// assert!(Position::try_from("g7").unwrap().is_x_square());
+    /// assert!(!Position::try_from("g8").unwrap().is_x_square());
+    /// ```
+    #[must_use]
+    pub fn is_x_square(self) -> bool {
+        self.0 & X_SQUARES != 0
+    }
+
+    /// Returns true if and only if the position is a C-square, one of the
+    /// eight edge squares adjacent to a corner.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::Position;
+    ///
+    /// assert!(Position::try_from("g8").unwrap().is_c_square());
+    /// assert!(!Position::try_from("g7").unwrap().is_c_square());
+    /// ```
+    #[must_use]
+    pub fn is_c_square(self) -> bool {
+        self.0 & C_SQUARES != 0
+    }
+
     pub fn rotate_cw(self) -> Self {
         Self(Bitboard::from(self.0).cw().0)
     }
@@ -165,6 +301,85 @@ impl Position {
     }
 }
 
+/// Declares a constant for every square, with its index from the top-left.
+macro_rules! squares {
+    ($($name: ident = $index: literal;)*) => {
+        impl Position {
+            $(
+                #[doc = concat!("The ", stringify!($name), " square.")]
+                pub const $name: Position = Position::new_unchecked(1 << (63 - $index));
+            )*
+        }
+    };
+}
+
+squares! {
+    A1 = 0;
+    B1 = 1;
+    C1 = 2;
+    D1 = 3;
+    E1 = 4;
+    F1 = 5;
+    G1 = 6;
+    H1 = 7;
+    A2 = 8;
+    B2 = 9;
+    C2 = 10;
+    D2 = 11;
+    E2 = 12;
+    F2 = 13;
+    G2 = 14;
+    H2 = 15;
+    A3 = 16;
+    B3 = 17;
+    C3 = 18;
+    D3 = 19;
+    E3 = 20;
+    F3 = 21;
+    G3 = 22;
+    H3 = 23;
+    A4 = 24;
+    B4 = 25;
+    C4 = 26;
+    D4 = 27;
+    E4 = 28;
+    F4 = 29;
+    G4 = 30;
+    H4 = 31;
+    A5 = 32;
+    B5 = 33;
+    C5 = 34;
+    D5 = 35;
+    E5 = 36;
+    F5 = 37;
+    G5 = 38;
+    H5 = 39;
+    A6 = 40;
+    B6 = 41;
+    C6 = 42;
+    D6 = 43;
+    E6 = 44;
+    F6 = 45;
+    G6 = 46;
+    H6 = 47;
+    A7 = 48;
+    B7 = 49;
+    C7 = 50;
+    D7 = 51;
+    E7 = 52;
+    F7 = 53;
+    G7 = 54;
+    H7 = 55;
+    A8 = 56;
+    B8 = 57;
+    C8 = 58;
+    D8 = 59;
+    E8 = 60;
+    F8 = 61;
+    G8 = 62;
+    H8 = 63;
+}
+
 impl From<Position> for Bitboard {
     fn from(position: Position) -> Self {
         Bitboard::from(position.0)
@@ -177,6 +392,41 @@ impl From<Position> for u64 {
     }
 }
 
+impl core::fmt::Display for Position {
+    /// Formats the position in human-readable notation, such as `d3`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::Position;
+    ///
+    /// let p = Position::try_from("E6").unwrap();
+    /// assert_eq!(p.to_string(), "e6");
+    /// ```
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(POSITIONS_AS_NOTATION[self.0.leading_zeros() as usize])
+    }
+}
+
+impl core::str::FromStr for Position {
+    type Err = PositionError;
+
+    /// Parses a position from human-readable notation.
+    ///
+    /// The conversion is case-insensitive.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::Position;
+    ///
+    /// let p: Position = "D3".parse().unwrap();
+    /// assert_eq!(p, "d3".parse::<Position>().unwrap());
+    /// assert!("i9".parse::<Position>().is_err());
+    /// ```
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        Position::try_from(text)
+    }
+}
+
 impl TryFrom<(u8, u8)> for Position {
     type Error = PositionError;
 
@@ -210,6 +460,7 @@ impl TryFrom<(u8, u8)> for Position {
     }
 }
 
+#[cfg(feature = "alloc")]
 impl TryFrom<String> for Position {
     type Error = PositionError;
 
@@ -260,10 +511,9 @@ impl TryFrom<&str> for Position {
     ///
     /// [`Position`]: crate::othello::Position
     fn try_from(text: &str) -> Result<Self, Self::Error> {
-        let text = text.to_lowercase();
         let bitboard = POSITIONS_AS_NOTATION
             .iter()
-            .position(|position| position == &text)
+            .position(|position| position.eq_ignore_ascii_case(text))
             .map(|index| POSITIONS[index])
             .ok_or(PositionError::InvalidPosition)?;
         Ok(Position::new_unchecked(bitboard))
@@ -286,14 +536,7 @@ impl TryFrom<u64> for Position {
     /// assert_eq!(p.raw(), num);
     /// ```
     fn try_from(bitboard: u64) -> Result<Self, Self::Error> {
-        // Equivalent to bitboard.count_ones() == 1
-        // Suggested improvement from Clippy that might
-        // lead to negligible performance gains ¯\_(ツ)_/¯
-        if bitboard.is_power_of_two() {
-            Ok(Position::new_unchecked(bitboard))
-        } else {
-            Err(PositionError::NotOneHotBitboard)
-        }
+        Position::try_new(bitboard)
     }
 }
 
@@ -328,6 +571,19 @@ pub enum PositionError {
     InvalidPosition,
 }
 
+impl core::fmt::Display for PositionError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            PositionError::NotOneHotBitboard => {
+                f.write_str("bitboard does not contain exactly one set bit")
+            }
+            PositionError::InvalidPosition => f.write_str("invalid board position"),
+        }
+    }
+}
+
+impl core::error::Error for PositionError {}
+
 #[cfg(kani)]
 impl kani::Arbitrary for Position {
     fn any() -> Self {
@@ -0,0 +1,123 @@
+use crate::othello::{Bitboard, Board, Stone};
+
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+impl Board {
+    /// Parses a board and the player to move from a single-line position
+    /// string.
+    ///
+    /// A position string consists of 64 squares from A1 to H8, row by row,
+    /// followed by the player to move. Black stones are written as `X`,
+    /// white stones as `O` and empty squares as `-`. The parser is
+    /// case-insensitive, also accepts `*` for black and `.` for empty squares
+    /// and ignores all whitespace.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Board, Stone};
+    ///
+    /// let text = "---------------------------OX------XO--------------------------- X";
+    /// let (board, stone) = Board::from_position_str(text).unwrap();
+    /// assert_eq!(Board::standard(), board);
+    /// assert_eq!(Stone::Black, stone);
+    /// ```
+    pub fn from_position_str(text: &str) -> Result<(Board, Stone), PositionStrError> {
+        let mut chars = text.chars().filter(|c| !c.is_whitespace());
+        let mut board = Board::empty();
+        for (index, pos) in Bitboard::from(u64::MAX).hot_bits().enumerate() {
+            let stone = match chars.next() {
+                Some(square) => {
+                    parse_square(square).ok_or(PositionStrError::InvalidSquare(index))?
+                }
+                None => return Err(PositionStrError::InvalidLength),
+            };
+            if let Some(stone) = stone {
+                board.place_stone_unchecked(stone, pos.into());
+            }
+        }
+        let stone = match chars.next().map(parse_square) {
+            Some(Some(Some(stone))) => stone,
+            Some(_) => return Err(PositionStrError::InvalidSideToMove),
+            None => return Err(PositionStrError::InvalidLength),
+        };
+        if chars.next().is_some() {
+            return Err(PositionStrError::InvalidLength);
+        }
+        Ok((board, stone))
+    }
+
+    /// Formats the board and the player to move as a single-line position
+    /// string.
+    ///
+    /// See [`from_position_str`] for a description of the format. The output
+    /// consists of the 64 squares, a space and the player to move.
+    ///
+    /// [`from_position_str`]: crate::othello::Board::from_position_str
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Board, Stone};
+    ///
+    /// let text = Board::standard().position_str(Stone::White);
+    /// assert_eq!(
+    ///     "---------------------------OX------XO--------------------------- O",
+    ///     text
+    /// );
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn position_str(&self, stone: Stone) -> String {
+        let mut text: String = self
+            .squares()
+            .map(|(_, square)| square.stone().map_or('-', stone_symbol))
+            .collect();
+        text.push(' ');
+        text.push(stone_symbol(stone));
+        text
+    }
+}
+
+fn parse_square(square: char) -> Option<Option<Stone>> {
+    match square {
+        'X' | 'x' | '*' => Some(Some(Stone::Black)),
+        'O' | 'o' => Some(Some(Stone::White)),
+        '-' | '.' => Some(None),
+        _ => None,
+    }
+}
+
+#[cfg(feature = "alloc")]
+fn stone_symbol(stone: Stone) -> char {
+    match stone {
+        Stone::Black => 'X',
+        Stone::White => 'O',
+    }
+}
+
+/// This enum represents errors that may occur when parsing position strings.
+#[derive(Debug, Eq, PartialEq, Hash)]
+pub enum PositionStrError {
+    /// Indicates that the text did not contain exactly 64 squares followed by
+    /// the player to move.
+    InvalidLength,
+    /// Indicates that a square could not be parsed. Carries the zero-based
+    /// index of the offending square, where 0 is A1 and 63 is H8.
+    InvalidSquare(usize),
+    /// Indicates that the player to move could not be parsed.
+    InvalidSideToMove,
+}
+
+impl core::fmt::Display for PositionStrError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            PositionStrError::InvalidLength => {
+                f.write_str("expected 64 squares followed by the player to move")
+            }
+            PositionStrError::InvalidSquare(index) => write!(f, "square {index} is invalid"),
+            PositionStrError::InvalidSideToMove => f.write_str("invalid player to move"),
+        }
+    }
+}
+
+impl core::error::Error for PositionStrError {}
@@ -0,0 +1,79 @@
+use crate::othello::{Bitboard, Board, Game, Position, Status, Stone};
+use rand::Rng;
+
+impl Game {
+    /// Plays uniformly random legal moves until the game is over, and
+    /// returns how it ended.
+    ///
+    /// Forced passes are made automatically. Every move is recorded in the
+    /// history as usual, so the playout can be taken back with [`undo`].
+    ///
+    /// [`undo`]: crate::othello::Game::undo
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Game, Status};
+    /// use rand::{SeedableRng, rngs::StdRng};
+    ///
+    /// let mut rng = StdRng::seed_from_u64(5);
+    /// let mut game = Game::new();
+    /// let status = game.random_playout(&mut rng);
+    /// assert_ne!(Status::Progressing, status);
+    /// assert_eq!(status, game.status());
+    /// ```
+    pub fn random_playout(&mut self, rng: &mut impl Rng) -> Status {
+        loop {
+            let status = self.status();
+            if status != Status::Progressing {
+                return status;
+            }
+            match random_move(self.moves(), rng) {
+                Some(pos) => self.play(pos).expect("generated moves are legal"),
+                None => self.pass_turn(),
+            }
+        }
+    }
+}
+
+impl Board {
+    /// Plays the specified number of uniformly random legal moves from the
+    /// standard opening position, and returns the resulting board along
+    /// with the player to move.
+    ///
+    /// Forced passes are made automatically and do not count as moves. If
+    /// the game ends early, the final position is returned.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::Board;
+    /// use rand::{SeedableRng, rngs::StdRng};
+    ///
+    /// let mut rng = StdRng::seed_from_u64(5);
+    /// let (board, _) = Board::random_reachable(&mut rng, 10);
+    /// assert_eq!(50, board.empty_squares().count_set());
+    /// ```
+    pub fn random_reachable(rng: &mut impl Rng, plies: u8) -> (Board, Stone) {
+        let mut board = Board::standard();
+        let mut stone = Stone::Black;
+        let mut played = 0;
+        while played < plies {
+            if let Some(pos) = random_move(board.moves_for(stone), rng) {
+                board.play(stone, pos);
+                played += 1;
+            } else if board.moves_for(stone.flip()).is_empty() {
+                break;
+            }
+            stone = stone.flip();
+        }
+        (board, stone)
+    }
+}
+
+/// Returns one of the moves picked uniformly at random.
+fn random_move(moves: Bitboard, rng: &mut impl Rng) -> Option<Position> {
+    if moves.is_empty() {
+        return None;
+    }
+    let index = rng.random_range(0..moves.count_set());
+    moves.hot_bits().nth(usize::from(index))
+}
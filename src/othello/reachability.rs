@@ -0,0 +1,106 @@
+use crate::othello::{Board, Stone, board::neighbours, constants::CENTER};
+
+/// Boards with at most this many stones placed since the opening position
+/// are checked exactly, by replaying every sequence of moves that fills the
+/// same squares.
+const EXACT_STAGE: u8 = 10;
+
+impl Board {
+    /// Returns whether the board can arise from the standard opening
+    /// position by legal play.
+    ///
+    /// Every board has to be consistent, have the four center squares
+    /// filled and have all of its stones connected to the center, since
+    /// stones are only ever placed next to others and never removed. Boards
+    /// with up to 10 stones placed since the opening are checked exactly.
+    /// Later boards that pass these checks are assumed to be reachable, so
+    /// a few unreachable boards are not detected.
+    ///
+    /// This is meant for flagging corrupt records when importing databases,
+    /// not for validating moves during play.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Board, Position, Stone};
+    ///
+    /// let mut board = Board::standard();
+    /// assert!(board.is_reachable());
+    /// board.play(Stone::Black, "f5".parse().unwrap());
+    /// assert!(board.is_reachable());
+    ///
+    /// // A stone that is not connected to the others
+    /// let a1 = Position::try_from("a1").unwrap();
+    /// board.place_stone_unchecked(Stone::White, a1.into());
+    /// assert!(!board.is_reachable());
+    /// // An empty center
+    /// assert!(!Board::empty().is_reachable());
+    /// ```
+    #[must_use]
+    pub fn is_reachable(&self) -> bool {
+        reachable(self, None)
+    }
+
+    /// Returns whether the board can arise from the standard opening
+    /// position by legal play, with the specified player to move.
+    ///
+    /// The checks are the same as for [`is_reachable`], and boards checked
+    /// exactly are checked with the player to move as well. Since no player
+    /// can be forced to pass before the 8th move, the player to move is
+    /// determined by the number of stones until then.
+    ///
+    /// [`is_reachable`]: crate::othello::Board::is_reachable
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Board, Stone};
+    ///
+    /// let mut board = Board::standard();
+    /// board.play(Stone::Black, "f5".parse().unwrap());
+    /// assert!(board.is_reachable_with(Stone::White));
+    /// assert!(!board.is_reachable_with(Stone::Black));
+    /// ```
+    #[must_use]
+    pub fn is_reachable_with(&self, stone: Stone) -> bool {
+        reachable(self, Some(stone))
+    }
+}
+
+fn reachable(board: &Board, stone: Option<Stone>) -> bool {
+    let occupied = !board.empty_squares().raw();
+    if !board.is_valid() || occupied & CENTER != CENTER {
+        return false;
+    }
+    // Stones are always placed next to others, so they form a single group
+    let mut group = CENTER;
+    loop {
+        let next = (group | neighbours(group)) & occupied;
+        if next == group {
+            break;
+        }
+        group = next;
+    }
+    if group != occupied {
+        return false;
+    }
+    board.stage() > EXACT_STAGE || replay(&Board::standard(), Stone::Black, board, stone)
+}
+
+/// Plays every move onto squares that are filled in the target, until the
+/// target is found or no such moves are left.
+fn replay(board: &Board, stone: Stone, target: &Board, to_move: Option<Stone>) -> bool {
+    let moves = board.moves_for(stone);
+    if board.stage() == target.stage() {
+        // A player without moves passes, or the game is over
+        return board == target
+            && to_move.is_none_or(|to_move| to_move == stone || moves.is_empty());
+    }
+    if moves.is_empty() {
+        return !board.moves_for(stone.flip()).is_empty()
+            && replay(board, stone.flip(), target, to_move);
+    }
+    (moves & !target.empty_squares()).hot_bits().any(|pos| {
+        let mut next = *board;
+        next.play(stone, pos);
+        replay(&next, stone.flip(), target, to_move)
+    })
+}
@@ -0,0 +1,75 @@
+use crate::othello::{Board, Stone};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The conventions for scoring a finished game.
+///
+/// The two conventions only differ for games that end before the board is
+/// full, which happens when neither player can move.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+pub enum Scoring {
+    /// Every player scores the number of stones they have on the board.
+    #[default]
+    DiscCount,
+    /// The tournament rule, where the remaining empty squares are awarded
+    /// to the winner, or split evenly in case of a draw. This is the
+    /// convention used by WTHOR databases.
+    WinnerTakesEmpties,
+}
+
+impl Scoring {
+    /// Returns the scores of black and white on the board, in that order.
+    ///
+    /// The board is scored as it is, regardless of whether or not the game
+    /// has concluded.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Board, Scoring};
+    ///
+    /// // Black has 3 stones and white has 1, with 60 empty squares
+    /// let board = Board::try_from((0xE0 << 56, 1)).unwrap();
+    /// assert_eq!((3, 1), Scoring::DiscCount.score(&board));
+    /// assert_eq!((63, 1), Scoring::WinnerTakesEmpties.score(&board));
+    /// ```
+    #[must_use]
+    pub fn score(self, board: &Board) -> (u8, u8) {
+        let black = board.bits_for(Stone::Black).count_set();
+        let white = board.bits_for(Stone::White).count_set();
+        match self {
+            Scoring::DiscCount => (black, white),
+            Scoring::WinnerTakesEmpties => {
+                let empty = board.empty_squares().count_set();
+                match black.cmp(&white) {
+                    core::cmp::Ordering::Greater => (black + empty, white),
+                    core::cmp::Ordering::Less => (black, white + empty),
+                    // Equal counts leave an even number of empty squares
+                    core::cmp::Ordering::Equal => (black + empty / 2, white + empty / 2),
+                }
+            }
+        }
+    }
+
+    /// Returns the score difference on the board from the perspective of
+    /// the specified player, which is positive if they are ahead.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Board, Scoring, Stone};
+    ///
+    /// let board = Board::try_from((0xE0 << 56, 1)).unwrap();
+    /// assert_eq!(-2, Scoring::DiscCount.difference(&board, Stone::White));
+    /// assert_eq!(62, Scoring::WinnerTakesEmpties.difference(&board, Stone::Black));
+    /// ```
+    #[must_use]
+    pub fn difference(self, board: &Board, stone: Stone) -> i8 {
+        let (black, white) = self.score(board);
+        let difference = black.cast_signed() - white.cast_signed();
+        match stone {
+            Stone::Black => difference,
+            Stone::White => -difference,
+        }
+    }
+}
@@ -0,0 +1,215 @@
+use crate::othello::{
+    Bitboard, Board, Stone,
+    constants::{FILE_A, FILE_H, RANK_1, RANK_8},
+};
+
+/// The number of boards processed by a single vector operation.
+const LANES: usize = 4;
+
+/// Each direction as a pair of the mask applied to the opponent stones and
+/// the shift amount, in the same order as the scalar move generators.
+const DIRECTIONS: [(u64, i8); 8] = {
+    let top_bottom = !(RANK_1 | RANK_8);
+    let left_right = !(FILE_A | FILE_H);
+    [
+        (top_bottom, -8),
+        (left_right, -7),
+        (left_right, 1),
+        (left_right, 9),
+        (top_bottom, 8),
+        (left_right, 7),
+        (left_right, -1),
+        (left_right, -9),
+    ]
+};
+
+impl Board {
+    /// Calculates the legal moves of four boards at once.
+    ///
+    /// Each board is paired with the stone at the same index. The result is
+    /// identical to calling [`moves_for`] on every board, but the boards are
+    /// processed together using AVX2 instructions when the CPU supports
+    /// them. Otherwise, a portable implementation is used that the compiler
+    /// is free to vectorize.
+    ///
+    /// [`moves_for`]: crate::othello::Board::moves_for
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Board, Stone};
+    ///
+    /// let board = Board::standard();
+    /// let boards = [board, Board::empty(), board, board];
+    /// let stones = [Stone::Black, Stone::Black, Stone::White, Stone::Black];
+    ///
+    /// let moves = Board::moves_for_x4(&boards, stones);
+    /// assert_eq!(board.moves_for(Stone::Black), moves[0]);
+    /// assert!(moves[1].is_empty());
+    /// assert_eq!(board.moves_for(Stone::White), moves[2]);
+    /// ```
+    #[must_use]
+    pub fn moves_for_x4(boards: &[Board; 4], stones: [Stone; 4]) -> [Bitboard; 4] {
+        let mut current = [0; LANES];
+        let mut opponent = [0; LANES];
+        for lane in 0..LANES {
+            current[lane] = boards[lane].bits_for(stones[lane]).raw();
+            opponent[lane] = boards[lane].bits_for(stones[lane].flip()).raw();
+        }
+        moves_x4(current, opponent).map(Bitboard::from)
+    }
+
+    /// Calculates the legal moves of eight boards at once.
+    ///
+    /// See [`moves_for_x4`] for details.
+    ///
+    /// [`moves_for_x4`]: crate::othello::Board::moves_for_x4
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Board, Stone};
+    ///
+    /// let boards = [Board::standard(); 8];
+    /// let moves = Board::moves_for_x8(&boards, [Stone::Black; 8]);
+    /// assert!(moves.iter().all(|moves| moves.count_set() == 4));
+    /// ```
+    #[must_use]
+    pub fn moves_for_x8(boards: &[Board; 8], stones: [Stone; 8]) -> [Bitboard; 8] {
+        let (low_boards, high_boards) = boards.split_at(LANES);
+        let (low_stones, high_stones) = stones.split_at(LANES);
+        let low = Self::moves_for_x4(
+            low_boards.try_into().unwrap(),
+            low_stones.try_into().unwrap(),
+        );
+        let high = Self::moves_for_x4(
+            high_boards.try_into().unwrap(),
+            high_stones.try_into().unwrap(),
+        );
+        core::array::from_fn(|lane| {
+            if lane < LANES {
+                low[lane]
+            } else {
+                high[lane - LANES]
+            }
+        })
+    }
+}
+
+fn moves_x4(current: [u64; LANES], opponent: [u64; LANES]) -> [u64; LANES] {
+    #[cfg(all(target_arch = "x86_64", target_feature = "avx2"))]
+    {
+        // SAFETY: The target is compiled with AVX2 enabled
+        unsafe { avx2::moves_x4(current, opponent) }
+    }
+    #[cfg(not(all(target_arch = "x86_64", target_feature = "avx2")))]
+    {
+        #[cfg(all(target_arch = "x86_64", feature = "std"))]
+        if std::is_x86_feature_detected!("avx2") {
+            // SAFETY: The CPU supports AVX2
+            return unsafe { avx2::moves_x4(current, opponent) };
+        }
+        portable_moves_x4(current, opponent)
+    }
+}
+
+/// Kogge-Stone move generation, written lane by lane to allow the compiler
+/// to vectorize it.
+#[cfg_attr(all(target_arch = "x86_64", target_feature = "avx2"), allow(dead_code))]
+fn portable_moves_x4(current: [u64; LANES], opponent: [u64; LANES]) -> [u64; LANES] {
+    let shift = |x: u64, shift: i8| if shift > 0 { x >> shift } else { x << -shift };
+    let mut moves = [0; LANES];
+    for (mask, dir) in DIRECTIONS {
+        for lane in 0..LANES {
+            let empty = !(current[lane] | opponent[lane]);
+            let mut propagator = opponent[lane] & mask;
+            let mut generator = shift(current[lane], dir) & propagator;
+            generator |= propagator & shift(generator, dir);
+            propagator &= shift(propagator, dir);
+            generator |= propagator & shift(generator, 2 * dir);
+            propagator &= shift(propagator, 2 * dir);
+            generator |= propagator & shift(generator, 4 * dir);
+            moves[lane] |= shift(generator, dir) & empty;
+        }
+    }
+    moves
+}
+
+#[cfg(all(target_arch = "x86_64", any(feature = "std", target_feature = "avx2")))]
+mod avx2 {
+    use super::{DIRECTIONS, LANES};
+    use core::arch::x86_64::{
+        __m256i, _mm_cvtsi32_si128, _mm256_and_si256, _mm256_andnot_si256, _mm256_loadu_si256,
+        _mm256_or_si256, _mm256_set1_epi64x, _mm256_setzero_si256, _mm256_sll_epi64,
+        _mm256_srl_epi64, _mm256_storeu_si256,
+    };
+
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    fn shift(x: __m256i, shift: i8) -> __m256i {
+        if shift > 0 {
+            _mm256_srl_epi64(x, _mm_cvtsi32_si128(i32::from(shift)))
+        } else {
+            _mm256_sll_epi64(x, _mm_cvtsi32_si128(-i32::from(shift)))
+        }
+    }
+
+    /// The AVX2 counterpart of [`portable_moves_x4`], processing all four
+    /// boards in a single 256-bit register.
+    ///
+    /// [`portable_moves_x4`]: crate::othello::simd::portable_moves_x4
+    #[target_feature(enable = "avx2")]
+    pub(super) unsafe fn moves_x4(current: [u64; LANES], opponent: [u64; LANES]) -> [u64; LANES] {
+        // SAFETY: The arrays are exactly 256 bits wide and unaligned loads
+        // are used
+        let (current, opponent) = unsafe {
+            (
+                _mm256_loadu_si256(current.as_ptr().cast()),
+                _mm256_loadu_si256(opponent.as_ptr().cast()),
+            )
+        };
+        let empty = _mm256_andnot_si256(_mm256_or_si256(current, opponent), _mm256_set1_epi64x(-1));
+        let mut moves = _mm256_setzero_si256();
+        for (mask, dir) in DIRECTIONS {
+            let mask = _mm256_set1_epi64x(mask.cast_signed());
+            let mut propagator = _mm256_and_si256(opponent, mask);
+            let mut generator = _mm256_and_si256(shift(current, dir), propagator);
+            generator = _mm256_or_si256(
+                generator,
+                _mm256_and_si256(propagator, shift(generator, dir)),
+            );
+            propagator = _mm256_and_si256(propagator, shift(propagator, dir));
+            generator = _mm256_or_si256(
+                generator,
+                _mm256_and_si256(propagator, shift(generator, 2 * dir)),
+            );
+            propagator = _mm256_and_si256(propagator, shift(propagator, 2 * dir));
+            generator = _mm256_or_si256(
+                generator,
+                _mm256_and_si256(propagator, shift(generator, 4 * dir)),
+            );
+            moves = _mm256_or_si256(moves, _mm256_and_si256(shift(generator, dir), empty));
+        }
+        let mut result = [0; LANES];
+        // SAFETY: The array is exactly 256 bits wide and an unaligned store
+        // is used
+        unsafe { _mm256_storeu_si256(result.as_mut_ptr().cast(), moves) };
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::portable_moves_x4;
+    use crate::othello::{Board, Stone};
+
+    #[test]
+    fn portable_fallback_matches_moves_for() {
+        let board =
+            Board::try_from((0x00_11_66_0c_3c_2c_00_00, 0x00_66_00_52_40_52_56_00)).unwrap();
+        let black = board.bits_for(Stone::Black).raw();
+        let white = board.bits_for(Stone::White).raw();
+        let moves = portable_moves_x4([black, white, black, 0], [white, black, 0, white]);
+        assert_eq!(board.moves_for(Stone::Black).raw(), moves[0]);
+        assert_eq!(board.moves_for(Stone::White).raw(), moves[1]);
+        assert_eq!([0, 0], [moves[2], moves[3]]);
+    }
+}
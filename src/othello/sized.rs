@@ -0,0 +1,363 @@
+use crate::othello::{OthelloError, Stone};
+
+/// A 6x6 Othello board.
+pub type Board6x6 = SizedBoard<6>;
+
+/// A 10x10 Othello board.
+pub type Board10x10 = SizedBoard<10>;
+
+/// Represents an Othello board of any even size from 4x4 up to 10x10.
+///
+/// [`Board`] is specialized for the standard 8x8 board and should be
+/// preferred for it, as it is considerably faster. This type exists for
+/// variants such as 6x6 Othello, which is solved and popular for teaching,
+/// and the larger 10x10 Grand Othello. All sizes share the same move
+/// generator.
+///
+/// That generator is a separate implementation from the one of [`Board`],
+/// written for `u128` sets of any width rather than for the fixed layout of
+/// the 8x8 bitboards. The two are checked against each other for `N = 8`
+/// in the tests of this crate, so they follow the same rules.
+///
+/// Squares are identified by their index, where the index of the square on
+/// a zero-indexed `rank` and `file` is `rank * N + file`. Index 0 is A1 and
+/// index `N * N - 1` is the bottom right corner. Sets of squares are
+/// returned as a `u128` where bit `i` represents the square with index `i`.
+///
+/// # Examples
+/// ```rust
+/// use magpie::othello::{Board6x6, Stone};
+///
+/// let mut board = Board6x6::standard();
+/// assert_eq!(4, board.moves_for(Stone::Black).count_ones());
+///
+/// // C2 is on rank 1 and file 2
+/// let c2 = Board6x6::square(1, 2);
+/// assert!(board.is_legal_move(Stone::Black, c2));
+/// board.play(Stone::Black, c2).unwrap();
+/// assert_eq!((4, 1), board.count_stones());
+/// ```
+///
+/// [`Board`]: crate::othello::Board
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct SizedBoard<const N: usize> {
+    black_stones: u128,
+    white_stones: u128,
+}
+
+impl<const N: usize> SizedBoard<N> {
+    /// The number of squares on the board.
+    pub const SQUARES: usize = N * N;
+
+    /// Fails to compile for unsupported board sizes when referenced.
+    const SUPPORTED: () = assert!(
+        N >= 4 && N <= 10 && N.is_multiple_of(2),
+        "board size must be 4, 6, 8 or 10"
+    );
+
+    /// Every square on the board.
+    const FULL: u128 = if Self::SQUARES == 128 {
+        u128::MAX
+    } else {
+        (1 << Self::SQUARES) - 1
+    };
+
+    /// Every square except the ones on the first file.
+    const NOT_FIRST_FILE: u128 = Self::FULL & !file_mask(N, 0);
+
+    /// Every square except the ones on the last file.
+    const NOT_LAST_FILE: u128 = Self::FULL & !file_mask(N, N - 1);
+
+    /// Pairs of shifts and the squares that can be shifted without leaving
+    /// the board, in the following order: N, NE, E, SE, S, SW, W, NW.
+    #[allow(clippy::cast_possible_wrap)]
+    const DIRECTIONS: [(i32, u128); 8] = [
+        (-(N as i32), Self::FULL),
+        (-(N as i32) + 1, Self::NOT_LAST_FILE),
+        (1, Self::NOT_LAST_FILE),
+        (N as i32 + 1, Self::NOT_LAST_FILE),
+        (N as i32, Self::FULL),
+        (N as i32 - 1, Self::NOT_FIRST_FILE),
+        (-1, Self::NOT_FIRST_FILE),
+        (-(N as i32) - 1, Self::NOT_FIRST_FILE),
+    ];
+
+    /// Returns a board without any placed stones.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::Board10x10;
+    ///
+    /// let board = Board10x10::empty();
+    /// assert_eq!(100, board.empty_squares().count_ones());
+    /// ```
+    #[must_use]
+    pub fn empty() -> Self {
+        let () = Self::SUPPORTED;
+        Self {
+            black_stones: 0,
+            white_stones: 0,
+        }
+    }
+
+    /// Returns a board with the four center squares occupied, in the same
+    /// arrangement as the standard 8x8 opening position.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Board6x6, Stone};
+    ///
+    /// let board = Board6x6::standard();
+    /// assert_eq!(32, board.empty_squares().count_ones());
+    /// assert_eq!(Some(Stone::White), board.stone_at(Board6x6::square(2, 2)));
+    /// assert_eq!(Some(Stone::Black), board.stone_at(Board6x6::square(2, 3)));
+    /// ```
+    #[must_use]
+    pub fn standard() -> Self {
+        let () = Self::SUPPORTED;
+        let low = N / 2 - 1;
+        let high = N / 2;
+        let bit = |rank, file| 1 << Self::square(rank, file);
+        Self {
+            black_stones: bit(low, high) | bit(high, low),
+            white_stones: bit(low, low) | bit(high, high),
+        }
+    }
+
+    /// Returns the index of the square on the specified zero-indexed rank
+    /// and file.
+    ///
+    /// # Panics
+    /// Panics if either the rank or the file does not fit on the board.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::Board6x6;
+    ///
+    /// assert_eq!(0, Board6x6::square(0, 0));
+    /// assert_eq!(35, Board6x6::square(5, 5));
+    /// ```
+    #[must_use]
+    pub fn square(rank: usize, file: usize) -> usize {
+        assert!(rank < N && file < N, "square is outside of the board");
+        rank * N + file
+    }
+
+    /// Returns the set of squares occupied by the specified player.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Board6x6, Stone};
+    ///
+    /// let board = Board6x6::standard();
+    /// assert_eq!(0, board.bits_for(Stone::Black) & board.bits_for(Stone::White));
+    /// ```
+    #[must_use]
+    pub fn bits_for(&self, stone: Stone) -> u128 {
+        match stone {
+            Stone::Black => self.black_stones,
+            Stone::White => self.white_stones,
+        }
+    }
+
+    /// Returns the set of all empty squares on the board.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::Board10x10;
+    ///
+    /// assert_eq!(96, Board10x10::standard().empty_squares().count_ones());
+    /// ```
+    #[must_use]
+    pub fn empty_squares(&self) -> u128 {
+        Self::FULL & !(self.black_stones | self.white_stones)
+    }
+
+    /// Queries the board at the specified square for the presence of a
+    /// stone.
+    ///
+    /// Returns `None` for squares that are not on the board.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::Board6x6;
+    ///
+    /// assert_eq!(None, Board6x6::standard().stone_at(0));
+    /// assert_eq!(None, Board6x6::standard().stone_at(36));
+    /// ```
+    #[must_use]
+    pub fn stone_at(&self, square: usize) -> Option<Stone> {
+        if square >= Self::SQUARES {
+            return None;
+        }
+        let bit = 1 << square;
+        if self.black_stones & bit != 0 {
+            Some(Stone::Black)
+        } else if self.white_stones & bit != 0 {
+            Some(Stone::White)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the number of black and white stones, in that order.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::Board6x6;
+    ///
+    /// assert_eq!((2, 2), Board6x6::standard().count_stones());
+    /// ```
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn count_stones(&self) -> (u8, u8) {
+        (
+            self.black_stones.count_ones() as u8,
+            self.white_stones.count_ones() as u8,
+        )
+    }
+
+    /// Calculates and returns the set of all legal moves for the specified
+    /// player.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Board10x10, Stone};
+    ///
+    /// let board = Board10x10::standard();
+    /// assert_eq!(4, board.moves_for(Stone::White).count_ones());
+    /// ```
+    #[must_use]
+    pub fn moves_for(&self, stone: Stone) -> u128 {
+        let current_bits = self.bits_for(stone);
+        let opponent_bits = self.bits_for(stone.flip());
+        let empty_squares = self.empty_squares();
+
+        let mut moves = 0;
+        for direction in Self::DIRECTIONS {
+            let mut candidates = Self::step(current_bits, direction) & opponent_bits;
+            // A line holds at most N - 2 stones that can be flipped
+            for _ in 1..N - 2 {
+                candidates |= Self::step(candidates, direction) & opponent_bits;
+            }
+            moves |= Self::step(candidates, direction) & empty_squares;
+        }
+        moves
+    }
+
+    /// Checks whether or not a move is legal for the specified player.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Board6x6, Stone};
+    ///
+    /// let board = Board6x6::standard();
+    /// assert!(!board.is_legal_move(Stone::Black, 0));
+    /// ```
+    #[must_use]
+    pub fn is_legal_move(&self, stone: Stone, square: usize) -> bool {
+        square < Self::SQUARES && self.moves_for(stone) & (1 << square) != 0
+    }
+
+    /// Returns the stones that would be flipped if the specified player
+    /// placed a stone on the specified square, without modifying the board.
+    ///
+    /// An empty set is returned if the move would not flip anything, which
+    /// is the case for all illegal moves on empty squares and for squares
+    /// that are not on the board.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Board6x6, Stone};
+    ///
+    /// let board = Board6x6::standard();
+    /// let flips = board.flips_for(Stone::Black, Board6x6::square(1, 2));
+    /// assert_eq!(1 << Board6x6::square(2, 2), flips);
+    /// ```
+    #[must_use]
+    pub fn flips_for(&self, stone: Stone, square: usize) -> u128 {
+        if square >= Self::SQUARES {
+            return 0;
+        }
+        let current_bits = self.bits_for(stone);
+        let opponent_bits = self.bits_for(stone.flip());
+
+        let mut flips = 0;
+        for direction in Self::DIRECTIONS {
+            let mut line = 0;
+            let mut current = Self::step(1 << square, direction);
+            while current & opponent_bits != 0 {
+                line |= current;
+                current = Self::step(current, direction);
+            }
+            if current & current_bits != 0 {
+                flips |= line;
+            }
+        }
+        flips
+    }
+
+    /// Places a stone on the specified square and flips the captured
+    /// stones.
+    ///
+    /// Returns an error if the square is not on the board, in which case
+    /// the board is left unchanged. Otherwise it is the responsibility of
+    /// the caller to ensure that the move is legal, as no further checks
+    /// are performed.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Board6x6, OthelloError, Stone};
+    ///
+    /// let mut board = Board6x6::standard();
+    /// board.play(Stone::Black, Board6x6::square(1, 2)).unwrap();
+    /// assert_eq!((4, 1), board.count_stones());
+    /// assert_eq!(Err(OthelloError::IllegalMove), board.play(Stone::White, 36));
+    /// ```
+    pub fn play(&mut self, stone: Stone, square: usize) -> Result<(), OthelloError> {
+        if square >= Self::SQUARES {
+            return Err(OthelloError::IllegalMove);
+        }
+        let flips = self.flips_for(stone, square);
+        let placed = 1 << square;
+        match stone {
+            Stone::Black => {
+                self.black_stones |= flips | placed;
+                self.white_stones ^= flips;
+            }
+            Stone::White => {
+                self.white_stones |= flips | placed;
+                self.black_stones ^= flips;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<const N: usize> SizedBoard<N> {
+    /// Moves every bit one square in the specified direction, dropping the
+    /// bits that would leave the board.
+    fn step(x: u128, (shift, mask): (i32, u128)) -> u128 {
+        let x = x & mask;
+        let moved = if shift > 0 { x << shift } else { x >> -shift };
+        moved & Self::FULL
+    }
+}
+
+impl<const N: usize> Default for SizedBoard<N> {
+    fn default() -> Self {
+        Self::standard()
+    }
+}
+
+/// Returns the squares on the specified file of a board of the specified
+/// size.
+const fn file_mask(size: usize, file: usize) -> u128 {
+    let mut mask = 0;
+    let mut rank = 0;
+    while rank < size {
+        mask |= 1 << (rank * size + file);
+        rank += 1;
+    }
+    mask
+}
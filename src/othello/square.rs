@@ -0,0 +1,78 @@
+use crate::othello::Stone;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The contents of a single square on an Othello board.
+///
+/// # Examples
+/// ```rust
+/// use magpie::othello::{Square, Stone};
+///
+/// assert_eq!(Square::Black, Square::from(Stone::Black));
+/// assert_eq!(Square::Empty, Square::from(None));
+/// assert_eq!(Some(Stone::White), Square::White.stone());
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub enum Square {
+    #[default]
+    Empty,
+    Black,
+    White,
+}
+
+impl Square {
+    /// Returns the stone on the square, if any.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Square, Stone};
+    ///
+    /// assert_eq!(Some(Stone::Black), Square::Black.stone());
+    /// assert_eq!(None, Square::Empty.stone());
+    /// ```
+    #[must_use]
+    pub fn stone(self) -> Option<Stone> {
+        match self {
+            Self::Empty => None,
+            Self::Black => Some(Stone::Black),
+            Self::White => Some(Stone::White),
+        }
+    }
+
+    /// Returns true if the square holds no stone.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::Square;
+    ///
+    /// assert!(Square::Empty.is_empty());
+    /// assert!(!Square::White.is_empty());
+    /// ```
+    #[must_use]
+    pub fn is_empty(self) -> bool {
+        self == Self::Empty
+    }
+}
+
+impl From<Stone> for Square {
+    fn from(stone: Stone) -> Self {
+        match stone {
+            Stone::Black => Self::Black,
+            Stone::White => Self::White,
+        }
+    }
+}
+
+impl From<Option<Stone>> for Square {
+    fn from(stone: Option<Stone>) -> Self {
+        stone.map_or(Self::Empty, Self::from)
+    }
+}
+
+impl From<Square> for Option<Stone> {
+    fn from(square: Square) -> Self {
+        square.stone()
+    }
+}
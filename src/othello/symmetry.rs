@@ -0,0 +1,143 @@
+use crate::othello::{Bitboard, Position};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Represents one of the eight symmetries of the board.
+///
+/// Othello is played on a square board where the starting position is
+/// symmetric, which means that positions related by any of these rotations
+/// or reflections are equivalent.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum Symmetry {
+    /// Leaves the board unchanged.
+    Identity,
+    /// Rotates the board 90 degrees clockwise.
+    RotateCw,
+    /// Rotates the board 180 degrees.
+    Rotate180,
+    /// Rotates the board 90 degrees counterclockwise.
+    RotateCcw,
+    /// Exchanges the A and H files.
+    MirrorHorizontal,
+    /// Exchanges the first and eighth ranks.
+    MirrorVertical,
+    /// Reflects the board along the diagonal from A1 to H8.
+    MirrorDiagonal,
+    /// Reflects the board along the diagonal from H1 to A8.
+    MirrorAntidiagonal,
+}
+
+impl Symmetry {
+    /// All eight symmetries, starting with the identity.
+    pub const ALL: [Symmetry; 8] = [
+        Symmetry::Identity,
+        Symmetry::RotateCw,
+        Symmetry::Rotate180,
+        Symmetry::RotateCcw,
+        Symmetry::MirrorHorizontal,
+        Symmetry::MirrorVertical,
+        Symmetry::MirrorDiagonal,
+        Symmetry::MirrorAntidiagonal,
+    ];
+
+    /// Returns the symmetry that undoes this one.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Position, Symmetry};
+    ///
+    /// let c4 = Position::try_from("c4").unwrap();
+    /// for symmetry in Symmetry::ALL {
+    ///     assert_eq!(c4, c4.transform(symmetry).transform(symmetry.inverse()));
+    /// }
+    /// ```
+    #[must_use]
+    pub fn inverse(self) -> Self {
+        match self {
+            Symmetry::RotateCw => Symmetry::RotateCcw,
+            Symmetry::RotateCcw => Symmetry::RotateCw,
+            symmetry => symmetry,
+        }
+    }
+
+    /// Returns the symmetry that is equivalent to applying this symmetry
+    /// first and the other one second.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::Symmetry;
+    ///
+    /// let half_turn = Symmetry::RotateCw.compose(Symmetry::RotateCw);
+    /// assert_eq!(Symmetry::Rotate180, half_turn);
+    /// assert_eq!(
+    ///     Symmetry::MirrorAntidiagonal,
+    ///     Symmetry::MirrorHorizontal.compose(Symmetry::RotateCcw)
+    /// );
+    /// ```
+    #[must_use]
+    pub fn compose(self, other: Symmetry) -> Self {
+        // A1 and B1 together are only mapped onto themselves by the
+        // identity, so their image identifies the symmetry
+        let probe = Bitboard(0xC0 << 56);
+        let image = probe.transform(self).transform(other);
+        Symmetry::ALL
+            .into_iter()
+            .find(|symmetry| probe.transform(*symmetry) == image)
+            .expect("symmetries are closed under composition")
+    }
+}
+
+impl Bitboard {
+    /// Rotates or reflects the bitboard by the specified symmetry.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Bitboard, Symmetry};
+    ///
+    /// let bitboard = Bitboard::from(0x00_11_66_0c_3c_2c_00_00);
+    /// assert_eq!(bitboard.cw(), bitboard.transform(Symmetry::RotateCw));
+    /// ```
+    #[must_use]
+    pub fn transform(self, symmetry: Symmetry) -> Self {
+        match symmetry {
+            Symmetry::Identity => self,
+            Symmetry::RotateCw => self.cw(),
+            Symmetry::Rotate180 => self.flip180(),
+            Symmetry::RotateCcw => self.ccw(),
+            Symmetry::MirrorHorizontal => self.mirror_horizontal(),
+            Symmetry::MirrorVertical => self.mirror_vertical(),
+            Symmetry::MirrorDiagonal => self.mirror_diagonal(),
+            Symmetry::MirrorAntidiagonal => self.mirror_antidiagonal(),
+        }
+    }
+}
+
+impl Position {
+    /// Returns the position this one is moved to by the specified symmetry.
+    ///
+    /// Moves found on a transformed board, such as the [canonical] one, are
+    /// mapped back to the original board by the [inverse] symmetry.
+    ///
+    /// [canonical]: crate::othello::Board::canonical
+    /// [inverse]: crate::othello::Symmetry::inverse
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Board, Position, Stone};
+    ///
+    /// let mut board = Board::standard();
+    /// board.play(Stone::Black, "c4".parse().unwrap());
+    /// let (canonical, symmetry) = board.canonical();
+    ///
+    /// let c3: Position = "c3".parse().unwrap();
+    /// let moved = c3.transform(symmetry);
+    /// assert!(canonical.is_legal_move(Stone::White, moved));
+    /// assert_eq!(c3, moved.transform(symmetry.inverse()));
+    /// ```
+    #[must_use]
+    pub fn transform(self, symmetry: Symmetry) -> Self {
+        Position(Bitboard(self.0).transform(symmetry).0)
+    }
+}
@@ -0,0 +1,304 @@
+use crate::othello::{Game, Position};
+use alloc::{string::String, vec::Vec};
+use core::str::FromStr;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Represents a single move made during a game of Othello.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum Move {
+    /// A stone was placed at the specified position.
+    Play(Position),
+    /// The player passed their turn.
+    Pass,
+}
+
+/// Represents a sequence of moves, starting from the standard opening
+/// position.
+///
+/// Transcripts are commonly written as concatenated moves in human-readable
+/// notation, such as `f5d6c3d3c4`. This is the format used by most online
+/// Othello servers and game databases. Passes may optionally be marked with
+/// either `--` or `pa`, but they can also be left out entirely, in which case
+/// they are inferred when the transcript is replayed.
+///
+/// # Examples
+/// ```rust
+/// use magpie::othello::Transcript;
+///
+/// let transcript: Transcript = "F5d6C3d3".parse().unwrap();
+/// assert_eq!(4, transcript.len());
+/// assert_eq!("f5d6c3d3", transcript.to_string());
+///
+/// let game = transcript.replay().unwrap();
+/// assert_eq!(56, game.empty_squares().count_set());
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct Transcript {
+    moves: Vec<Move>,
+}
+
+impl Transcript {
+    /// Returns an empty transcript.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::Transcript;
+    ///
+    /// let transcript = Transcript::new();
+    /// assert!(transcript.is_empty());
+    /// ```
+    #[must_use]
+    pub fn new() -> Self {
+        Self { moves: Vec::new() }
+    }
+
+    /// Returns the moves contained in the transcript.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Move, Position, Transcript};
+    ///
+    /// let transcript: Transcript = "f5".parse().unwrap();
+    /// let f5 = Position::try_from("f5").unwrap();
+    /// assert_eq!(&[Move::Play(f5)], transcript.moves());
+    /// ```
+    #[must_use]
+    pub fn moves(&self) -> &[Move] {
+        &self.moves
+    }
+
+    /// Appends a move to the end of the transcript.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Move, Position, Transcript};
+    ///
+    /// let mut transcript = Transcript::new();
+    /// transcript.push(Move::Play(Position::try_from("f5").unwrap()));
+    /// assert_eq!("f5", transcript.to_string());
+    /// ```
+    pub fn push(&mut self, mv: Move) {
+        self.moves.push(mv);
+    }
+
+    /// Returns the number of moves in the transcript, passes included.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::Transcript;
+    ///
+    /// let transcript: Transcript = "f5d6".parse().unwrap();
+    /// assert_eq!(2, transcript.len());
+    /// ```
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.moves.len()
+    }
+
+    /// Returns true if and only if the transcript contains no moves.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::Transcript;
+    ///
+    /// assert!(Transcript::new().is_empty());
+    /// ```
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.moves.is_empty()
+    }
+
+    /// Returns the name of the deepest named opening the transcript passes
+    /// through, in any of its symmetric variants.
+    ///
+    /// The names are those of [`NAMED_OPENINGS`], recognized by position,
+    /// so transpositions into a named line are recognized as well. Moves
+    /// after the first illegal move are ignored.
+    ///
+    /// [`NAMED_OPENINGS`]: crate::othello::book::NAMED_OPENINGS
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::Transcript;
+    ///
+    /// let transcript: Transcript = "f5d6c3d3c4f4f6".parse().unwrap();
+    /// assert_eq!(Some("Leader's Tiger"), transcript.opening_name());
+    ///
+    /// // The same opening, reflected along the main diagonal
+    /// let transcript: Transcript = "e6f4c3c4d3".parse().unwrap();
+    /// assert_eq!(Some("Tiger"), transcript.opening_name());
+    /// ```
+    #[cfg(feature = "openings")]
+    #[must_use]
+    pub fn opening_name(&self) -> Option<&'static str> {
+        crate::othello::book::opening_name(self)
+    }
+
+    /// Replays the transcript from the standard opening position.
+    ///
+    /// Returns an error if any of the moves are illegal.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Stone, Transcript};
+    ///
+    /// let transcript: Transcript = "f5d6".parse().unwrap();
+    /// let game = transcript.replay().unwrap();
+    /// assert_eq!(Stone::Black, game.current_turn());
+    /// ```
+    pub fn replay(&self) -> Result<Game, TranscriptError> {
+        let mut game = Game::new();
+        self.replay_onto(&mut game)?;
+        Ok(game)
+    }
+
+    /// Replays the transcript onto the specified game.
+    ///
+    /// Passes that are forced but not marked in the transcript are inferred.
+    /// Explicitly marked passes are only accepted if the player has no legal
+    /// moves.
+    ///
+    /// Returns an error if any of the moves are illegal. In that case, the
+    /// game is left in the state it was in before the offending move.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Game, Transcript};
+    ///
+    /// let mut game = Game::new();
+    /// let transcript: Transcript = "f5d6".parse().unwrap();
+    /// transcript.replay_onto(&mut game).unwrap();
+    /// assert_eq!(58, game.empty_squares().count_set());
+    ///
+    /// // A3 is not a legal move
+    /// let transcript: Transcript = "a3".parse().unwrap();
+    /// assert!(transcript.replay_onto(&mut game).is_err());
+    /// ```
+    pub fn replay_onto(&self, game: &mut Game) -> Result<(), TranscriptError> {
+        for (index, mv) in self.moves.iter().enumerate() {
+            match mv {
+                Move::Play(pos) => {
+                    let illegal = TranscriptError::IllegalMove(index);
+                    if game.is_legal_move(*pos) {
+                        game.play(*pos).map_err(|_| illegal)?;
+                    } else if game.must_pass() {
+                        // The pass was left out of the transcript
+                        let mut next = game.clone();
+                        next.pass_turn();
+                        next.play(*pos).map_err(|_| illegal)?;
+                        *game = next;
+                    } else {
+                        return Err(illegal);
+                    }
+                }
+                Move::Pass => {
+                    if !game.pass_if_forced() {
+                        return Err(TranscriptError::IllegalMove(index));
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl From<&Game> for Transcript {
+    /// Returns the moves in the history of the game, including passes.
+    ///
+    /// Moves that have been undone are left out. The transcript only
+    /// replays to the same game if the game started from the standard
+    /// opening position.
+    fn from(game: &Game) -> Self {
+        game.history().iter().map(|played| played.mv()).collect()
+    }
+}
+
+impl From<Vec<Move>> for Transcript {
+    fn from(moves: Vec<Move>) -> Self {
+        Self { moves }
+    }
+}
+
+impl FromIterator<Move> for Transcript {
+    fn from_iter<T: IntoIterator<Item = Move>>(iter: T) -> Self {
+        Self {
+            moves: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl FromStr for Transcript {
+    type Err = TranscriptError;
+
+    /// Parses a transcript of concatenated moves.
+    ///
+    /// The conversion is case-insensitive and ignores any whitespace between
+    /// moves. Passes may be marked with either `--` or `pa`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Move, Transcript};
+    ///
+    /// let transcript: Transcript = "f5 D6 -- c3".parse().unwrap();
+    /// assert_eq!(Move::Pass, transcript.moves()[2]);
+    /// assert!("f5d".parse::<Transcript>().is_err());
+    /// ```
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let chars: Vec<char> = text.chars().filter(|c| !c.is_whitespace()).collect();
+        chars
+            .chunks(2)
+            .enumerate()
+            .map(|(index, chunk)| {
+                let token: String = chunk.iter().collect::<String>().to_lowercase();
+                match token.as_str() {
+                    "--" | "pa" => Ok(Move::Pass),
+                    _ => Position::try_from(token.as_str())
+                        .map(Move::Play)
+                        .map_err(|_| TranscriptError::InvalidNotation(index)),
+                }
+            })
+            .collect()
+    }
+}
+
+impl core::fmt::Display for Transcript {
+    /// Formats the transcript as concatenated lowercase moves, where passes
+    /// are marked with `--`.
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        for mv in &self.moves {
+            match mv {
+                Move::Play(pos) => write!(f, "{pos}")?,
+                Move::Pass => f.write_str("--")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// This enum represents errors that may occur when handling transcripts.
+///
+/// Each variant carries the zero-based index of the offending move.
+#[derive(Debug, Eq, PartialEq, Hash)]
+pub enum TranscriptError {
+    /// Indicates that a move could not be parsed.
+    InvalidNotation(usize),
+    /// Indicates that a move was illegal when replayed.
+    IllegalMove(usize),
+}
+
+impl core::fmt::Display for TranscriptError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            TranscriptError::InvalidNotation(index) => {
+                write!(f, "move {} could not be parsed", index + 1)
+            }
+            TranscriptError::IllegalMove(index) => write!(f, "move {} is illegal", index + 1),
+        }
+    }
+}
+
+impl core::error::Error for TranscriptError {}
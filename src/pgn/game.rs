@@ -0,0 +1,366 @@
+use crate::{
+    othello::{Game, Move, Transcript},
+    pgn::parser::PgnError,
+};
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::{fmt::Write, time::Duration};
+
+/// The column after which the moves of a game are wrapped onto a new line.
+const LINE_WIDTH: usize = 79;
+
+/// Represents a single move of an annotated game, along with its
+/// annotations and the alternatives to it.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PgnMove {
+    /// The move itself.
+    pub mv: Move,
+    /// The numeric annotation glyphs of the move, such as 1 for a good move
+    /// and 2 for a mistake.
+    pub nags: Vec<u8>,
+    /// The comment on the move, if any.
+    pub comment: Option<String>,
+    /// The evaluation of the position after the move, if recorded.
+    pub eval: Option<f32>,
+    /// The time spent on the move, if recorded.
+    pub time: Option<Duration>,
+    /// Lines that could have been played instead of this move.
+    pub variations: Vec<Line>,
+}
+
+/// A sequence of moves, optionally preceded by a comment.
+///
+/// The main line of a game starts at the standard opening position, while
+/// a variation starts at the position before the move it is attached to.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Line {
+    /// The comment before the first move, if any.
+    pub comment: Option<String>,
+    /// The moves of the line.
+    pub moves: Vec<PgnMove>,
+}
+
+/// Represents a single annotated game, starting from the standard opening
+/// position.
+///
+/// Apart from the moves, a game carries tags such as the names of the
+/// players (`Black` and `White`), the event (`Event`), the date (`Date`)
+/// and the result (`Result`). Tags are kept in the order they were
+/// encountered.
+///
+/// See the [module documentation] for the text format.
+///
+/// [module documentation]: crate::pgn
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PgnGame {
+    pub(crate) tags: Vec<(String, String)>,
+    pub(crate) line: Line,
+}
+
+impl PgnGame {
+    /// Returns a game without any tags or moves.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::pgn::PgnGame;
+    ///
+    /// let game = PgnGame::new();
+    /// assert!(game.tags().is_empty());
+    /// assert_eq!("*\n", game.to_string());
+    /// ```
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns a game with the moves of the specified transcript and no
+    /// annotations.
+    ///
+    /// Forced passes are added even if the transcript omits them. Returns
+    /// an error if the transcript contains illegal moves.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::{othello::Transcript, pgn::PgnGame};
+    ///
+    /// let transcript: Transcript = "f5d6c3".parse().unwrap();
+    /// let game = PgnGame::from_transcript(&transcript).unwrap();
+    /// assert_eq!("1. f5 d6 2. c3 *\n", game.to_string());
+    /// ```
+    pub fn from_transcript(transcript: &Transcript) -> Result<Self, PgnError> {
+        let mut game = Self::new();
+        let mut replay = Game::new();
+        for (index, mv) in transcript.moves().iter().enumerate() {
+            if let Move::Play(pos) = mv
+                && !replay.is_legal_move(*pos)
+                && replay.must_pass()
+            {
+                game.push(PgnMove::new(Move::Pass));
+                replay.pass_turn();
+            }
+            play(&mut replay, *mv).ok_or(PgnError::IllegalMove(index))?;
+            game.push(PgnMove::new(*mv));
+        }
+        Ok(game)
+    }
+
+    /// Returns the value of the specified tag, if present.
+    #[must_use]
+    pub fn tag(&self, name: &str) -> Option<&str> {
+        self.tags
+            .iter()
+            .find(|(tag, _)| tag == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Sets the value of the specified tag, replacing any previous value.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::pgn::PgnGame;
+    ///
+    /// let mut game = PgnGame::new();
+    /// game.set_tag("Black", "Alice");
+    /// assert_eq!(Some("Alice"), game.black_player());
+    /// ```
+    pub fn set_tag(&mut self, name: &str, value: &str) {
+        match self.tags.iter_mut().find(|(tag, _)| tag == name) {
+            Some((_, old)) => *old = value.to_string(),
+            None => self.tags.push((name.to_string(), value.to_string())),
+        }
+    }
+
+    /// Returns all tags in the order they appear.
+    #[must_use]
+    pub fn tags(&self) -> &[(String, String)] {
+        &self.tags
+    }
+
+    /// Returns the name of the player with the black stones (`Black`).
+    #[must_use]
+    pub fn black_player(&self) -> Option<&str> {
+        self.tag("Black")
+    }
+
+    /// Returns the name of the player with the white stones (`White`).
+    #[must_use]
+    pub fn white_player(&self) -> Option<&str> {
+        self.tag("White")
+    }
+
+    /// Returns the name of the event the game was played at (`Event`).
+    #[must_use]
+    pub fn event(&self) -> Option<&str> {
+        self.tag("Event")
+    }
+
+    /// Returns the date the game was played (`Date`).
+    #[must_use]
+    pub fn date(&self) -> Option<&str> {
+        self.tag("Date")
+    }
+
+    /// Returns the final number of stones of black and white (`Result`).
+    ///
+    /// Returns `None` if the result is missing or unknown (`*`).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::pgn::PgnGame;
+    ///
+    /// let mut game = PgnGame::new();
+    /// game.set_tag("Result", "36-28");
+    /// assert_eq!(Some((36, 28)), game.result());
+    /// ```
+    #[must_use]
+    pub fn result(&self) -> Option<(u8, u8)> {
+        parse_result(self.tag("Result")?)
+    }
+
+    /// Returns the main line of the game.
+    #[must_use]
+    pub fn line(&self) -> &Line {
+        &self.line
+    }
+
+    /// Returns a mutable reference to the main line of the game.
+    pub fn line_mut(&mut self) -> &mut Line {
+        &mut self.line
+    }
+
+    /// Returns the moves of the main line.
+    #[must_use]
+    pub fn moves(&self) -> &[PgnMove] {
+        &self.line.moves
+    }
+
+    /// Appends a move to the main line.
+    pub fn push(&mut self, mv: PgnMove) {
+        self.line.moves.push(mv);
+    }
+
+    /// Returns the moves of the main line as a transcript.
+    #[must_use]
+    pub fn transcript(&self) -> Transcript {
+        self.line.moves.iter().map(|mv| mv.mv).collect()
+    }
+
+    /// Replays the main line from the standard opening position.
+    ///
+    /// Returns an error if any of the moves are illegal. Variations are
+    /// not replayed.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::pgn;
+    ///
+    /// let game = pgn::parse_game("1. f5 d6 2. c3 *").unwrap();
+    /// assert_eq!(3, game.replay().unwrap().history().len());
+    /// assert!(pgn::parse_game("1. f5 f5 *").unwrap().replay().is_err());
+    /// ```
+    pub fn replay(&self) -> Result<Game, PgnError> {
+        let mut game = Game::new();
+        for (index, mv) in self.line.moves.iter().enumerate() {
+            play(&mut game, mv.mv).ok_or(PgnError::IllegalMove(index))?;
+        }
+        Ok(game)
+    }
+}
+
+impl PgnMove {
+    /// Returns a move without any annotations or variations.
+    #[must_use]
+    pub fn new(mv: Move) -> Self {
+        Self {
+            mv,
+            nags: Vec::new(),
+            comment: None,
+            eval: None,
+            time: None,
+            variations: Vec::new(),
+        }
+    }
+}
+
+impl core::fmt::Display for PgnGame {
+    /// Formats the game as text, with one tag per line followed by the
+    /// moves wrapped at 80 columns.
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        for (name, value) in &self.tags {
+            let value = value.replace('\\', "\\\\").replace('"', "\\\"");
+            writeln!(f, "[{name} \"{value}\"]")?;
+        }
+        if !self.tags.is_empty() {
+            writeln!(f)?;
+        }
+
+        let mut tokens = Vec::new();
+        write_line(&self.line, 0, &mut tokens);
+        tokens.push(self.tag("Result").unwrap_or("*").to_string());
+
+        let mut width = 0;
+        for token in tokens {
+            if width > 0 && width + 1 + token.len() > LINE_WIDTH {
+                writeln!(f)?;
+                width = 0;
+            }
+            if width > 0 {
+                f.write_char(' ')?;
+                width += 1;
+            }
+            f.write_str(&token)?;
+            width += token.len();
+        }
+        writeln!(f)
+    }
+}
+
+/// Appends the tokens of the line, whose first move is made at the
+/// specified ply.
+fn write_line(line: &Line, ply: usize, tokens: &mut Vec<String>) {
+    if let Some(comment) = &line.comment {
+        tokens.push(comment_token(comment));
+    }
+    // The move number is repeated for white after any interruption
+    let mut interrupted = true;
+    for (offset, mv) in line.moves.iter().enumerate() {
+        let ply = ply + offset;
+        let number = ply / 2 + 1;
+        if ply.is_multiple_of(2) {
+            tokens.push(alloc::format!("{number}."));
+        } else if interrupted {
+            tokens.push(alloc::format!("{number}..."));
+        }
+        tokens.push(match mv.mv {
+            Move::Play(pos) => pos.to_string(),
+            Move::Pass => "--".to_string(),
+        });
+        tokens.extend(mv.nags.iter().map(|nag| alloc::format!("${nag}")));
+
+        let mut comment = String::new();
+        if let Some(eval) = mv.eval {
+            let _ = write!(comment, "[%eval {eval}] ");
+        }
+        if let Some(time) = mv.time {
+            let _ = write!(comment, "[%emt {}] ", format_duration(time));
+        }
+        if let Some(text) = &mv.comment {
+            comment.push_str(text);
+        }
+        let comment = comment.trim_end();
+        interrupted = !comment.is_empty() || !mv.variations.is_empty();
+        if !comment.is_empty() {
+            tokens.push(comment_token(comment));
+        }
+
+        for variation in &mv.variations {
+            let start = tokens.len();
+            write_line(variation, ply, tokens);
+            tokens[start].insert(0, '(');
+            if let Some(last) = tokens.last_mut() {
+                last.push(')');
+            }
+        }
+    }
+}
+
+/// Returns the comment enclosed in braces, without any closing braces in
+/// it, which cannot be represented.
+fn comment_token(comment: &str) -> String {
+    alloc::format!("{{{}}}", comment.replace('}', ""))
+}
+
+/// Formats the duration as hours, minutes and seconds, such as `0:01:05.5`.
+fn format_duration(duration: Duration) -> String {
+    let seconds = duration.as_secs();
+    let mut text = alloc::format!(
+        "{}:{:02}:{:02}",
+        seconds / 3600,
+        seconds / 60 % 60,
+        seconds % 60
+    );
+    let millis = duration.subsec_millis();
+    if millis > 0 {
+        let fraction = alloc::format!("{millis:03}");
+        text.push('.');
+        text.push_str(fraction.trim_end_matches('0'));
+    }
+    text
+}
+
+/// Parses a result such as `36-28` into the number of stones of both
+/// players.
+pub(crate) fn parse_result(text: &str) -> Option<(u8, u8)> {
+    let (black, white) = text.split_once('-')?;
+    Some((black.parse().ok()?, white.parse().ok()?))
+}
+
+// Plays the move, only allowing passes when they are forced.
+fn play(game: &mut Game, mv: Move) -> Option<()> {
+    match mv {
+        Move::Play(pos) => game.play(pos).ok(),
+        Move::Pass => game.pass_if_forced().then_some(()),
+    }
+}
@@ -0,0 +1,52 @@
+//! Annotated games in a text format modeled after the Portable Game
+//! Notation (PGN) of chess.
+//!
+//! Unlike plain [transcripts], annotated games carry analysis: comments,
+//! numeric annotation glyphs (NAGs), evaluations, the time spent on every
+//! move and alternative lines. A game starts with tags in brackets, followed
+//! by the moves and a termination marker, which is either the final score
+//! or `*` for games without a result:
+//!
+//! ```text
+//! [Event "Club championship"]
+//! [Black "Alice"]
+//! [White "Bob"]
+//! [Result "36-28"]
+//!
+//! {Alice opens} 1. f5 d6 {[%eval -0.5] [%emt 0:00:04] The Tiger} 2. c3 $1
+//! (2. c5 $6 {Too passive}) 2... d3 36-28
+//! ```
+//!
+//! Moves are written in lowercase notation and passes as `--`. Move numbers
+//! count pairs of moves, like in chess, and are optional when parsing.
+//! Comments are enclosed in braces or run from a semicolon to the end of
+//! the line, NAGs are written as `$` followed by a number and the suffixes
+//! `!`, `?`, `!!`, `??`, `!?` and `?!` are read as NAGs 1 to 6. Evaluations
+//! and move times are stored in comments as the commands `[%eval]` and
+//! `[%emt]`. Variations are enclosed in parentheses after the move they
+//! replace and may be nested.
+//!
+//! [transcripts]: crate::othello::Transcript
+//!
+//! # Examples
+//! ```rust
+//! use magpie::pgn;
+//!
+//! let text = r#"[Black "Alice"]
+//! 1. f5 {[%eval 0.5] Book} d6 (1... f6 $2) 2. c3 *"#;
+//! let game = pgn::parse_game(text).unwrap();
+//! assert_eq!(Some("Alice"), game.black_player());
+//! assert_eq!("f5d6c3", game.transcript().to_string());
+//! assert_eq!(Some("Book"), game.moves()[0].comment.as_deref());
+//! assert_eq!(Some(0.5), game.moves()[0].eval);
+//! assert_eq!(1, game.moves()[1].variations.len());
+//! assert_eq!(game, pgn::parse_game(&game.to_string()).unwrap());
+//! ```
+
+/// Represents a single annotated game.
+mod game;
+/// Parses annotated games.
+mod parser;
+
+pub use game::{Line, PgnGame, PgnMove};
+pub use parser::{PgnError, parse_game, parse_games};
@@ -0,0 +1,334 @@
+use crate::{
+    othello::{Move, Position},
+    pgn::game::{Line, PgnGame, PgnMove, parse_result},
+};
+use alloc::{
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use core::time::Duration;
+
+/// Characters that end a move or a move number.
+const DELIMITERS: &str = "{}();$!?[";
+
+/// Parses a single annotated game.
+///
+/// Returns an error if the text is not a well-formed game. The moves are
+/// not validated, use [`PgnGame::replay`] for that.
+///
+/// [`PgnGame::replay`]: crate::pgn::PgnGame::replay
+///
+/// # Examples
+/// ```rust
+/// use magpie::pgn;
+/// use std::time::Duration;
+///
+/// let game = pgn::parse_game("1. f5!? {[%emt 0:00:01.5]} d6 *").unwrap();
+/// assert_eq!(vec![5], game.moves()[0].nags);
+/// assert_eq!(Some(Duration::from_millis(1500)), game.moves()[0].time);
+/// ```
+pub fn parse_game(text: &str) -> Result<PgnGame, PgnError> {
+    let mut parser = Parser { text, offset: 0 };
+    let game = parser.game()?;
+    parser.skip_whitespace();
+    if parser.offset < text.len() {
+        return Err(PgnError::Syntax);
+    }
+    Ok(game)
+}
+
+/// Parses all annotated games in the text, one after the other.
+///
+/// # Examples
+/// ```rust
+/// use magpie::pgn::{self, PgnGame};
+///
+/// let text = format!("{}\n{}", PgnGame::new(), PgnGame::new());
+/// assert_eq!(2, pgn::parse_games(&text).unwrap().len());
+/// ```
+pub fn parse_games(text: &str) -> Result<Vec<PgnGame>, PgnError> {
+    let mut parser = Parser { text, offset: 0 };
+    let mut games = Vec::new();
+    loop {
+        parser.skip_whitespace();
+        if parser.offset == text.len() {
+            return Ok(games);
+        }
+        games.push(parser.game()?);
+    }
+}
+
+struct Parser<'a> {
+    text: &'a str,
+    offset: usize,
+}
+
+impl<'a> Parser<'a> {
+    /// Parses the tags and moves of a game, up to and including the
+    /// termination marker or the end of the text.
+    fn game(&mut self) -> Result<PgnGame, PgnError> {
+        let mut game = PgnGame::new();
+        loop {
+            self.skip_whitespace();
+            if self.peek() != Some('[') {
+                break;
+            }
+            let (name, value) = self.tag()?;
+            game.tags.push((name, value));
+        }
+
+        // The main line followed by the variations being parsed
+        let mut lines = vec![Line::default()];
+        let result = loop {
+            self.skip_whitespace();
+            let start = self.offset;
+            let Some(next) = self.peek() else {
+                break None;
+            };
+            let depth = lines.len();
+            let line = lines.last_mut().unwrap();
+            match next {
+                '{' => {
+                    self.offset += 1;
+                    let comment = self.take_until('}').ok_or(PgnError::Syntax)?;
+                    self.offset += 1;
+                    add_comment(line, comment);
+                }
+                ';' => {
+                    self.offset += 1;
+                    let comment = self.take_while(|c| c != '\n');
+                    add_comment(line, comment);
+                }
+                '(' => {
+                    if line.moves.is_empty() {
+                        return Err(PgnError::Syntax);
+                    }
+                    self.offset += 1;
+                    lines.push(Line::default());
+                }
+                ')' => {
+                    self.offset += 1;
+                    let variation = lines.pop().unwrap();
+                    let parent = lines.last_mut().ok_or(PgnError::Syntax)?;
+                    if variation.moves.is_empty() {
+                        return Err(PgnError::Syntax);
+                    }
+                    parent.moves.last_mut().unwrap().variations.push(variation);
+                }
+                '$' => {
+                    self.offset += 1;
+                    let digits = self.take_while(|c| c.is_ascii_digit());
+                    let nag = digits.parse().map_err(|_| PgnError::Syntax)?;
+                    last_move(line)?.nags.push(nag);
+                }
+                '!' | '?' => {
+                    let suffix = self.take_while(|c| c == '!' || c == '?');
+                    let nag = match suffix {
+                        "!" => 1,
+                        "?" => 2,
+                        "!!" => 3,
+                        "??" => 4,
+                        "!?" => 5,
+                        "?!" => 6,
+                        _ => return Err(PgnError::Syntax),
+                    };
+                    last_move(line)?.nags.push(nag);
+                }
+                // The tags of the next game, which lacks a termination marker
+                '[' if depth == 1 => break None,
+                _ => {
+                    let word = self.take_while(|c| !c.is_whitespace() && !DELIMITERS.contains(c));
+                    if word.is_empty() {
+                        return Err(PgnError::Syntax);
+                    }
+                    if word == "*" || parse_result(word).is_some() {
+                        break Some(word);
+                    }
+                    // Move numbers may be followed by a move without a space
+                    let mv = word.trim_start_matches(|c: char| c.is_ascii_digit());
+                    let mv = match mv.strip_prefix('.') {
+                        Some(mv) => mv.trim_start_matches('.'),
+                        None => word,
+                    };
+                    if !mv.is_empty() {
+                        let offset = start + word.len() - mv.len();
+                        line.moves.push(PgnMove::new(parse_move(mv, offset)?));
+                    }
+                }
+            }
+        };
+        if lines.len() > 1 {
+            return Err(PgnError::Syntax);
+        }
+        game.line = lines.pop().unwrap();
+        if let Some(result) = result
+            && game.tag("Result").is_none()
+        {
+            game.set_tag("Result", result);
+        }
+        Ok(game)
+    }
+
+    /// Parses a tag such as `[Black "Alice"]`.
+    fn tag(&mut self) -> Result<(String, String), PgnError> {
+        self.offset += 1;
+        self.skip_whitespace();
+        let name = self.take_while(|c| c.is_ascii_alphanumeric() || c == '_');
+        if name.is_empty() {
+            return Err(PgnError::Syntax);
+        }
+        let name = name.to_string();
+        self.skip_whitespace();
+        if self.peek() != Some('"') {
+            return Err(PgnError::Syntax);
+        }
+        self.offset += 1;
+
+        let mut value = String::new();
+        let mut chars = self.text[self.offset..].char_indices();
+        loop {
+            match chars.next() {
+                Some((index, '"')) => {
+                    self.offset += index + 1;
+                    break;
+                }
+                Some((_, '\\')) => match chars.next() {
+                    Some((_, c)) => value.push(c),
+                    None => return Err(PgnError::Syntax),
+                },
+                Some((_, c)) => value.push(c),
+                None => return Err(PgnError::Syntax),
+            }
+        }
+        self.skip_whitespace();
+        if self.peek() != Some(']') {
+            return Err(PgnError::Syntax);
+        }
+        self.offset += 1;
+        Ok((name, value))
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.text[self.offset..].chars().next()
+    }
+
+    fn skip_whitespace(&mut self) {
+        self.take_while(char::is_whitespace);
+    }
+
+    fn take_while(&mut self, predicate: impl Fn(char) -> bool) -> &'a str {
+        let rest = &self.text[self.offset..];
+        let len = rest.find(|c| !predicate(c)).unwrap_or(rest.len());
+        self.offset += len;
+        &rest[..len]
+    }
+
+    /// Returns the text up to the specified character without consuming
+    /// it, or `None` if it does not occur.
+    fn take_until(&mut self, end: char) -> Option<&'a str> {
+        let rest = &self.text[self.offset..];
+        let len = rest.find(end)?;
+        self.offset += len;
+        Some(&rest[..len])
+    }
+}
+
+fn last_move(line: &mut Line) -> Result<&mut PgnMove, PgnError> {
+    line.moves.last_mut().ok_or(PgnError::Syntax)
+}
+
+fn parse_move(text: &str, offset: usize) -> Result<Move, PgnError> {
+    match text.to_lowercase().as_str() {
+        "--" | "pa" | "pass" => Ok(Move::Pass),
+        notation => Position::try_from(notation)
+            .map(Move::Play)
+            .map_err(|_| PgnError::InvalidMove(offset)),
+    }
+}
+
+/// Adds the comment to the last move of the line, or to the line itself if
+/// it has no moves yet, extracting any evaluation and move time.
+fn add_comment(line: &mut Line, comment: &str) {
+    let mut text = String::new();
+    let mut rest = comment;
+    let mut eval = None;
+    let mut time = None;
+    while let Some(start) = rest.find("[%") {
+        let Some(len) = rest[start..].find(']') else {
+            break;
+        };
+        let command = &rest[start + 2..start + len];
+        let parsed = match command.split_once(char::is_whitespace) {
+            Some(("eval", value)) => value.trim().parse().ok().map(|value| eval = Some(value)),
+            Some(("emt", value)) => parse_duration(value.trim()).map(|value| time = Some(value)),
+            _ => None,
+        };
+        let end = start + len + 1;
+        text.push_str(&rest[..if parsed.is_some() { start } else { end }]);
+        rest = &rest[end..];
+    }
+    text.push_str(rest);
+
+    let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    let target = match line.moves.last_mut() {
+        Some(mv) => {
+            mv.eval = eval.or(mv.eval);
+            mv.time = time.or(mv.time);
+            &mut mv.comment
+        }
+        None => &mut line.comment,
+    };
+    if text.is_empty() {
+        return;
+    }
+    match target {
+        Some(existing) => {
+            existing.push(' ');
+            existing.push_str(&text);
+        }
+        None => *target = Some(text),
+    }
+}
+
+/// Parses a duration such as `1:02:03.5`, `02:03` or `3.5`.
+fn parse_duration(text: &str) -> Option<Duration> {
+    let (whole, fraction) = text.split_once('.').unwrap_or((text, ""));
+    let mut seconds: u64 = 0;
+    for part in whole.split(':') {
+        seconds = seconds.checked_mul(60)?.checked_add(part.parse().ok()?)?;
+    }
+    if fraction.len() > 9 || !fraction.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    let nanos = fraction.parse::<u32>().unwrap_or(0) * 10_u32.pow(9 - fraction.len() as u32);
+    Some(Duration::new(seconds, nanos))
+}
+
+/// This enum represents errors that may occur when handling annotated
+/// games.
+#[derive(Debug, Eq, PartialEq, Hash)]
+pub enum PgnError {
+    /// Indicates that the text is not a well-formed game.
+    Syntax,
+    /// Indicates that a move could not be parsed. Carries the byte offset
+    /// of the move in the text.
+    InvalidMove(usize),
+    /// Indicates that a move was illegal when replayed. Carries the index of
+    /// the offending move.
+    IllegalMove(usize),
+}
+
+impl core::fmt::Display for PgnError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            PgnError::Syntax => f.write_str("malformed annotated game"),
+            PgnError::InvalidMove(offset) => {
+                write!(f, "move at byte {offset} could not be parsed")
+            }
+            PgnError::IllegalMove(index) => write!(f, "move {} is illegal", index + 1),
+        }
+    }
+}
+
+impl core::error::Error for PgnError {}
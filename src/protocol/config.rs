@@ -0,0 +1,418 @@
+use crate::{
+    eval::Evaluator,
+    protocol::uoi::{OptionValue, UoiOption},
+    search::{ParallelSearcher, Replacement, SearchLimits, SharedTranspositionTable},
+};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::{collections::hash_map::RandomState, fmt, hash::BuildHasher, path::PathBuf};
+#[cfg(feature = "config")]
+use std::{fs, io, path::Path};
+
+/// The largest transposition table that can be configured, in megabytes.
+const MAX_HASH_MB: usize = 1 << 16;
+/// The most threads that can be configured.
+const MAX_THREADS: usize = 256;
+/// The deepest search that can be configured.
+const MAX_DEPTH: u8 = 60;
+/// The value of string options that are not set, as in UCI.
+const EMPTY: &str = "<empty>";
+/// The seed of every random choice in deterministic configurations.
+const DETERMINISTIC_SEED: u64 = 0x6d61_6770_6965;
+
+// The names of the options that expose the configuration, following UCI
+const HASH_OPTION: &str = "Hash";
+const THREADS_OPTION: &str = "Threads";
+const BOOK_OPTION: &str = "OwnBook";
+const WEIGHTS_OPTION: &str = "EvalWeights";
+const DEPTH_OPTION: &str = "Depth";
+const DETERMINISTIC_OPTION: &str = "Deterministic";
+
+/// The settings of an engine, as read from a configuration file.
+///
+/// With the `config` feature, configurations can be read from JSON and TOML
+/// files with [`load`]. With the `serde` feature alone, they can be read
+/// from any format supported by serde. Fields
+/// that are not specified keep their default values, while unknown fields
+/// are rejected.
+///
+/// Once loaded, the configuration is exposed to user interfaces as options
+/// by [`UoiServer::with_config`], so that it can still be changed with
+/// `setoption`.
+///
+/// [`load`]: crate::protocol::EngineConfig::load
+/// [`UoiServer::with_config`]: crate::protocol::uoi::UoiServer::with_config
+///
+/// # Examples
+/// ```rust
+/// use magpie::protocol::EngineConfig;
+///
+/// let config = EngineConfig {
+///     hash_mb: 256,
+///     threads: 4,
+///     ..EngineConfig::default()
+/// };
+/// assert!(config.validate().is_ok());
+///
+/// let config = EngineConfig {
+///     threads: 0,
+///     ..EngineConfig::default()
+/// };
+/// assert!(config.validate().is_err());
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, deny_unknown_fields))]
+pub struct EngineConfig {
+    /// The size of the transposition table in megabytes.
+    pub hash_mb: usize,
+    /// The number of threads to search with.
+    pub threads: usize,
+    /// Whether moves are played from an opening book while possible.
+    pub book: bool,
+    /// The file to read the weights of the evaluator from, if any.
+    pub eval_weights: Option<PathBuf>,
+    /// The depth searched unless a search specifies its own limits.
+    pub depth: u8,
+    /// Whether the same position always yields the same result, for tests
+    /// and for reproducing reports from users.
+    ///
+    /// Deterministic engines search with a single thread, replace time
+    /// limits with node budgets and use a fixed seed for random choices.
+    pub deterministic: bool,
+}
+
+impl EngineConfig {
+    /// Reads a configuration from a file, which is parsed as JSON if its
+    /// extension is `json` and as TOML otherwise, and validates it.
+    #[cfg(feature = "config")]
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, ConfigError> {
+        let path = path.as_ref();
+        let text = fs::read_to_string(path)?;
+        let config = if path
+            .extension()
+            .is_some_and(|extension| extension == "json")
+        {
+            Self::from_json(&text)?
+        } else {
+            Self::from_toml(&text)?
+        };
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// Parses a configuration from a JSON object.
+    ///
+    /// The configuration is not validated.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::protocol::EngineConfig;
+    ///
+    /// let config = EngineConfig::from_json(r#"{"threads": 4, "eval_weights": null}"#).unwrap();
+    /// assert_eq!(4, config.threads);
+    /// assert!(EngineConfig::from_json(r#"{"ponder": true}"#).is_err());
+    /// ```
+    #[cfg(feature = "config")]
+    pub fn from_json(text: &str) -> Result<Self, ConfigError> {
+        Ok(serde_json::from_str(text)?)
+    }
+
+    /// Parses a configuration from a TOML document.
+    ///
+    /// The configuration is not validated.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::protocol::EngineConfig;
+    ///
+    /// let config = EngineConfig::from_toml(
+    ///     "# Settings for tournaments\n\
+    ///      hash_mb = 256\n\
+    ///      threads = 4 # one per core\n\
+    ///      book = true\n",
+    /// )
+    /// .unwrap();
+    /// assert_eq!(256, config.hash_mb);
+    /// assert_eq!(4, config.threads);
+    /// assert!(config.book);
+    /// assert_eq!(EngineConfig::default().depth, config.depth);
+    /// ```
+    #[cfg(feature = "config")]
+    pub fn from_toml(text: &str) -> Result<Self, ConfigError> {
+        Ok(toml::from_str(text)?)
+    }
+
+    /// Checks that every setting is within its valid range, and that the
+    /// weights of the evaluator exist.
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        if !(1..=MAX_HASH_MB).contains(&self.hash_mb) {
+            return Err(ConfigError::OutOfRange("hash_mb"));
+        }
+        if !(1..=MAX_THREADS).contains(&self.threads) {
+            return Err(ConfigError::OutOfRange("threads"));
+        }
+        if !(1..=MAX_DEPTH).contains(&self.depth) {
+            return Err(ConfigError::OutOfRange("depth"));
+        }
+        match &self.eval_weights {
+            Some(path) if !path.is_file() => Err(ConfigError::MissingWeights(path.clone())),
+            _ => Ok(()),
+        }
+    }
+
+    /// Returns the options that expose the configuration to user
+    /// interfaces, with the current settings as their defaults.
+    ///
+    /// The options are `Hash`, `Threads`, `OwnBook`, `EvalWeights`, `Depth`
+    /// and `Deterministic`, following the names used by UCI engines where
+    /// there are any.
+    #[must_use]
+    pub fn options(&self) -> Vec<UoiOption> {
+        let weights = self
+            .eval_weights
+            .as_ref()
+            .map_or_else(|| EMPTY.to_string(), |path| path.display().to_string());
+        vec![
+            UoiOption::spin(HASH_OPTION, spin(self.hash_mb), 1, spin(MAX_HASH_MB)),
+            UoiOption::spin(THREADS_OPTION, spin(self.threads), 1, spin(MAX_THREADS)),
+            UoiOption::check(BOOK_OPTION, self.book),
+            UoiOption::string(WEIGHTS_OPTION, &weights),
+            UoiOption::spin(DEPTH_OPTION, i64::from(self.depth), 1, i64::from(MAX_DEPTH)),
+            UoiOption::check(DETERMINISTIC_OPTION, self.deterministic),
+        ]
+    }
+
+    /// Changes the setting exposed by the option with the specified name,
+    /// which is matched without regard to case.
+    ///
+    /// Returns an error if there is no such option, or if the changed
+    /// configuration is invalid, in which case the configuration is left
+    /// unchanged.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::protocol::{EngineConfig, uoi::OptionValue};
+    ///
+    /// let mut config = EngineConfig::default();
+    /// config.set_option("hash", &OptionValue::Spin(64)).unwrap();
+    /// assert_eq!(64, config.hash_mb);
+    ///
+    /// let missing = OptionValue::String(String::from("missing.weights"));
+    /// assert!(config.set_option("EvalWeights", &missing).is_err());
+    /// assert_eq!(None, config.eval_weights);
+    /// ```
+    pub fn set_option(&mut self, name: &str, value: &OptionValue) -> Result<(), ConfigError> {
+        let mut config = self.clone();
+        match (name.to_ascii_lowercase().as_str(), value) {
+            ("hash", &OptionValue::Spin(hash_mb)) => config.hash_mb = count(hash_mb, "hash_mb")?,
+            ("threads", &OptionValue::Spin(threads)) => config.threads = count(threads, "threads")?,
+            ("depth", &OptionValue::Spin(depth)) => config.depth = count(depth, "depth")?,
+            ("ownbook", &OptionValue::Check(book)) => config.book = book,
+            ("deterministic", &OptionValue::Check(deterministic)) => {
+                config.deterministic = deterministic;
+            }
+            ("evalweights", OptionValue::String(path)) if path.is_empty() || path == EMPTY => {
+                config.eval_weights = None;
+            }
+            ("evalweights", OptionValue::String(path)) => {
+                config.eval_weights = Some(PathBuf::from(path));
+            }
+            ("hash" | "threads" | "depth" | "ownbook" | "evalweights" | "deterministic", _) => {
+                return Err(ConfigError::InvalidValue(option_key(name)));
+            }
+            _ => return Err(ConfigError::UnknownKey(name.to_string())),
+        }
+        config.validate()?;
+        *self = config;
+        Ok(())
+    }
+
+    /// Returns the number of threads to search with, which is always one
+    /// for deterministic configurations.
+    #[must_use]
+    pub fn search_threads(&self) -> usize {
+        if self.deterministic { 1 } else { self.threads }
+    }
+
+    /// Returns the limits of a search, which are made independent of the
+    /// clock for deterministic configurations.
+    ///
+    /// [`SearchLimits::deterministic`] describes how time limits are
+    /// replaced.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::{protocol::EngineConfig, search::SearchLimits};
+    /// use std::time::Duration;
+    ///
+    /// let config = EngineConfig {
+    ///     deterministic: true,
+    ///     ..EngineConfig::default()
+    /// };
+    /// let limits = config.limits(SearchLimits::time(Duration::from_secs(1)));
+    /// assert_eq!(None, limits.time);
+    /// assert!(limits.nodes.is_some());
+    /// ```
+    #[must_use]
+    pub fn limits(&self, limits: SearchLimits) -> SearchLimits {
+        if self.deterministic {
+            limits.deterministic()
+        } else {
+            limits
+        }
+    }
+
+    /// Returns the seed for random choices of the engine, such as the
+    /// rollouts of [`Mcts`]. Deterministic configurations always return the
+    /// same seed, others a different one every time.
+    ///
+    /// [`Mcts`]: crate::search::Mcts
+    #[must_use]
+    pub fn seed(&self) -> u64 {
+        if self.deterministic {
+            DETERMINISTIC_SEED
+        } else {
+            RandomState::new().hash_one(DETERMINISTIC_SEED)
+        }
+    }
+
+    /// Returns a searcher with the configured size of the transposition
+    /// table and number of threads, which is one if deterministic.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::{eval::DiscDifference, protocol::EngineConfig, search::SharedTranspositionTable};
+    ///
+    /// let config = EngineConfig {
+    ///     hash_mb: 1,
+    ///     ..EngineConfig::default()
+    /// };
+    /// let searcher = config.searcher(DiscDifference);
+    /// let capacity = searcher.transposition_table().capacity();
+    /// assert!(capacity * SharedTranspositionTable::ENTRY_SIZE <= 1 << 20);
+    /// ```
+    #[must_use]
+    pub fn searcher<E: Evaluator + Sync>(&self, evaluator: E) -> ParallelSearcher<E> {
+        let bytes = self.hash_mb.saturating_mul(1 << 20);
+        let tt = SharedTranspositionTable::with_memory(bytes, Replacement::DepthPreferred);
+        ParallelSearcher::new(evaluator)
+            .with_threads(self.search_threads())
+            .with_transposition_table(tt)
+    }
+}
+
+impl Default for EngineConfig {
+    /// Returns a configuration with a 16 megabyte transposition table, a
+    /// single thread, no opening book, the built-in evaluator and a depth
+    /// of 8, which is not deterministic.
+    fn default() -> Self {
+        Self {
+            hash_mb: 16,
+            threads: 1,
+            book: false,
+            eval_weights: None,
+            depth: 8,
+            deterministic: false,
+        }
+    }
+}
+
+/// Converts the value of a spin option into a setting.
+fn count<T: TryFrom<i64>>(value: i64, key: &'static str) -> Result<T, ConfigError> {
+    T::try_from(value).map_err(|_| ConfigError::OutOfRange(key))
+}
+
+/// Returns the setting exposed by the option with the specified name.
+fn option_key(name: &str) -> &'static str {
+    match name.to_ascii_lowercase().as_str() {
+        "hash" => "hash_mb",
+        "threads" => "threads",
+        "depth" => "depth",
+        "ownbook" => "book",
+        "deterministic" => "deterministic",
+        _ => "eval_weights",
+    }
+}
+
+fn spin(value: usize) -> i64 {
+    i64::try_from(value).unwrap_or(i64::MAX)
+}
+
+/// This enum represents errors that may occur when loading configurations.
+#[derive(Debug)]
+pub enum ConfigError {
+    /// Indicates that reading the configuration failed.
+    #[cfg(feature = "config")]
+    Io(io::Error),
+    /// Indicates that a JSON configuration is malformed or does not match
+    /// the settings.
+    #[cfg(feature = "config")]
+    Json(serde_json::Error),
+    /// Indicates that a TOML configuration is malformed or does not match
+    /// the settings.
+    #[cfg(feature = "config")]
+    Toml(toml::de::Error),
+    /// Indicates that there is no setting with the specified name.
+    UnknownKey(String),
+    /// Indicates that the value of the named setting has the wrong type.
+    InvalidValue(&'static str),
+    /// Indicates that the value of the named setting is out of range.
+    OutOfRange(&'static str),
+    /// Indicates that the weights of the evaluator do not exist.
+    MissingWeights(PathBuf),
+}
+
+#[cfg(feature = "config")]
+impl From<io::Error> for ConfigError {
+    fn from(error: io::Error) -> Self {
+        ConfigError::Io(error)
+    }
+}
+
+#[cfg(feature = "config")]
+impl From<serde_json::Error> for ConfigError {
+    fn from(error: serde_json::Error) -> Self {
+        ConfigError::Json(error)
+    }
+}
+
+#[cfg(feature = "config")]
+impl From<toml::de::Error> for ConfigError {
+    fn from(error: toml::de::Error) -> Self {
+        ConfigError::Toml(error)
+    }
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            #[cfg(feature = "config")]
+            ConfigError::Io(error) => write!(f, "failed to read configuration: {error}"),
+            #[cfg(feature = "config")]
+            ConfigError::Json(error) => write!(f, "invalid JSON configuration: {error}"),
+            #[cfg(feature = "config")]
+            ConfigError::Toml(error) => write!(f, "invalid TOML configuration: {error}"),
+            ConfigError::UnknownKey(key) => write!(f, "unknown setting {key}"),
+            ConfigError::InvalidValue(key) => write!(f, "invalid value for {key}"),
+            ConfigError::OutOfRange(key) => write!(f, "{key} is out of range"),
+            ConfigError::MissingWeights(path) => {
+                write!(f, "evaluator weights {} do not exist", path.display())
+            }
+        }
+    }
+}
+
+impl core::error::Error for ConfigError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            #[cfg(feature = "config")]
+            ConfigError::Io(error) => Some(error),
+            #[cfg(feature = "config")]
+            ConfigError::Json(error) => Some(error),
+            #[cfg(feature = "config")]
+            ConfigError::Toml(error) => Some(error),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,129 @@
+use crate::{
+    eval::Evaluator,
+    othello::{Board, Stone},
+    search::{ParallelSearcher, SearchLimits, SearchReport, Searcher, WIN_SCORE},
+};
+use std::sync::{atomic::AtomicBool, mpsc};
+
+/// A search engine that can be driven by the protocol adapters.
+///
+/// # Examples
+/// ```rust
+/// use magpie::{
+///     eval::DiscDifference,
+///     othello::{Board, Stone},
+///     protocol::Engine,
+///     search::Searcher,
+/// };
+///
+/// let mut engine = Searcher::new(DiscDifference);
+/// let report = Engine::search(&mut engine, &Board::standard(), Stone::Black, 3);
+/// assert!(report.best_move.is_some());
+/// ```
+pub trait Engine {
+    /// Returns the name the engine reports to the user interface.
+    fn name(&self) -> &str {
+        "magpie"
+    }
+
+    /// Searches the position for the best move of the specified player, to
+    /// at most the specified depth.
+    fn search(&mut self, board: &Board, stone: Stone, depth: u8) -> SearchReport;
+
+    /// Searches the position within the limits, sending the report of every
+    /// completed iteration to the channel and aborting once the stop flag is
+    /// raised. The report of the last completed iteration is returned.
+    ///
+    /// Adapters that search in the background, such as the [`uoi`] module,
+    /// rely on this method. The default implementation searches to the depth
+    /// limit with [`search`], ignoring the time limits and the stop flag.
+    ///
+    /// [`search`]: crate::protocol::Engine::search
+    /// [`uoi`]: crate::protocol::uoi
+    fn search_with(
+        &mut self,
+        board: &Board,
+        stone: Stone,
+        limits: SearchLimits,
+        stop: &AtomicBool,
+        updates: mpsc::Sender<SearchReport>,
+    ) -> SearchReport {
+        let _ = stop;
+        let report = self.search(board, stone, limits.depth);
+        // The receiving end may have lost interest, which is fine
+        let _ = updates.send(report.clone());
+        report
+    }
+}
+
+impl<E: Evaluator> Engine for Searcher<E> {
+    fn search(&mut self, board: &Board, stone: Stone, depth: u8) -> SearchReport {
+        Searcher::search(self, board, stone, SearchLimits::depth(depth))
+    }
+
+    fn search_with(
+        &mut self,
+        board: &Board,
+        stone: Stone,
+        limits: SearchLimits,
+        stop: &AtomicBool,
+        updates: mpsc::Sender<SearchReport>,
+    ) -> SearchReport {
+        Searcher::search_with(self, board, stone, limits, stop, updates)
+    }
+}
+
+impl<E: Evaluator + Sync> Engine for ParallelSearcher<E> {
+    fn search(&mut self, board: &Board, stone: Stone, depth: u8) -> SearchReport {
+        ParallelSearcher::search(self, board, stone, SearchLimits::depth(depth))
+    }
+
+    fn search_with(
+        &mut self,
+        board: &Board,
+        stone: Stone,
+        limits: SearchLimits,
+        stop: &AtomicBool,
+        updates: mpsc::Sender<SearchReport>,
+    ) -> SearchReport {
+        ParallelSearcher::search_with(self, board, stone, limits, stop, Some(updates))
+    }
+}
+
+impl<T: Engine + ?Sized> Engine for &mut T {
+    fn name(&self) -> &str {
+        (**self).name()
+    }
+
+    fn search(&mut self, board: &Board, stone: Stone, depth: u8) -> SearchReport {
+        (**self).search(board, stone, depth)
+    }
+
+    fn search_with(
+        &mut self,
+        board: &Board,
+        stone: Stone,
+        limits: SearchLimits,
+        stop: &AtomicBool,
+        updates: mpsc::Sender<SearchReport>,
+    ) -> SearchReport {
+        (**self).search_with(board, stone, limits, stop, updates)
+    }
+}
+
+/// Converts a score to discs. Scores of solved positions carry the exact
+/// disc differential, while other scores are reported as returned by the
+/// evaluator.
+pub(crate) fn disc_score(score: i32) -> f32 {
+    if is_solved(score) {
+        (score - score.signum() * WIN_SCORE) as f32
+    } else {
+        score as f32
+    }
+}
+
+/// Returns whether the score belongs to a position solved to the end.
+pub(crate) fn is_solved(score: i32) -> bool {
+    // The disc differential of a finished game is at most 64
+    score.abs() > WIN_SCORE - 64
+}
@@ -0,0 +1,63 @@
+//! A JSON-RPC protocol for driving engines from any language.
+//!
+//! User interfaces that implement neither NBoard nor GGS can drive an engine
+//! through its standard input and output with [JSON-RPC 2.0] messages, one
+//! per line. Every request carries an `id`, which is echoed in the response,
+//! so a client may send several requests without waiting for each response.
+//! Requests are answered in the order they are received. Requests without an
+//! `id` are notifications, which are handled but never answered. The
+//! protocol requires the `jsonrpc` feature.
+//!
+//! ```text
+//! --> {"jsonrpc":"2.0","id":1,"method":"position","params":{"moves":"f5d6c3"}}
+//! <-- {"jsonrpc":"2.0","id":1,"result":{"toMove":"white","empties":57,"moves":["b3","c4","d3","e3","f4"]}}
+//! --> {"jsonrpc":"2.0","id":2,"method":"go","params":{"depth":6}}
+//! <-- {"jsonrpc":"2.0","id":2,"result":{"move":"c4","score":1.0,"solved":false,"depth":6,"nodes":1524,"time":0.002,"pv":["c4","b3"]}}
+//! ```
+//!
+//! # Methods
+//!
+//! - `engine`: Returns the `name` of the engine.
+//! - `position`: Sets the position. The optional `board` is a string of 64
+//!   squares from A1 to H8, where `X` or `*` is a black stone, `O` is a white
+//!   stone and `-` or `.` is an empty square. The optional `toMove` is either
+//!   `black` or `white`, and the optional `moves` are played from the board
+//!   in the notation of [transcripts]. Without a board, the moves are played
+//!   from the standard opening position. Returns the state of the position.
+//! - `play`: Plays a single `move`, written as a square such as `f5` or as
+//!   `pass`. Returns the state of the position.
+//! - `go`: Searches the position to the optional `depth` and returns the
+//!   best `move`, its `score` and the principal variation as `pv`. The move
+//!   is not played.
+//! - `analyze`: Searches every legal move to the optional `depth` and
+//!   returns the best `count` of them, one by default, as `moves`.
+//! - `quit`: Stops the server after answering the request.
+//!
+//! The state of a position consists of the player to move (`toMove`), the
+//! number of empty squares (`empties`) and the legal `moves`. Once the game
+//! has ended, the player to move is `null`. Scores are given in discs for
+//! positions that were `solved` to the end, and in the units of the engine's
+//! evaluator otherwise, always from the perspective of the player to move.
+//!
+//! # Errors
+//!
+//! Errors carry the standard codes of JSON-RPC: `-32700` for lines that are
+//! not valid JSON, `-32600` for invalid requests, `-32601` for unknown
+//! methods and `-32602` for invalid parameters. Illegal moves are reported
+//! with the code `-32000`, and leave the position unchanged.
+//!
+//! [JSON-RPC 2.0]: https://www.jsonrpc.org/specification
+//! [transcripts]: crate::othello::Transcript
+//!
+//! # Examples
+//! ```rust,no_run
+//! use magpie::{eval::Heuristic, protocol::jsonrpc::JsonRpcServer, search::Searcher};
+//!
+//! let engine = Searcher::new(Heuristic::default());
+//! JsonRpcServer::new(engine).run_stdio().unwrap();
+//! ```
+
+/// Serves engines over the protocol.
+mod server;
+
+pub use server::JsonRpcServer;
@@ -0,0 +1,464 @@
+use crate::{
+    othello::{Board, Game, Move, Position, Status, Stone, Transcript},
+    protocol::{
+        Engine,
+        engine::{disc_score, is_solved},
+        notation::{parse_board, parse_move, parse_stone},
+    },
+    search::{SearchReport, rank_moves},
+};
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+use serde_json::{Map, Value};
+use std::io::{self, BufRead, Write};
+
+/// The depth searched unless a request specifies one.
+const DEFAULT_DEPTH: u8 = 8;
+
+/// The line is not valid JSON.
+const PARSE_ERROR: i64 = -32700;
+/// The message is not a valid request.
+const INVALID_REQUEST: i64 = -32600;
+/// The requested method does not exist.
+const METHOD_NOT_FOUND: i64 = -32601;
+/// The parameters of the request are invalid.
+const INVALID_PARAMS: i64 = -32602;
+/// The move is illegal, or the game has already ended.
+const ILLEGAL_MOVE: i64 = -32000;
+
+/// An error to report to the client.
+#[derive(Serialize)]
+struct RpcError {
+    code: i64,
+    message: String,
+}
+
+/// The response to a request, with either a result or an error.
+#[derive(Serialize)]
+struct Response<'a, T> {
+    jsonrpc: &'static str,
+    id: &'a Value,
+    #[serde(flatten)]
+    outcome: Outcome<T>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "lowercase")]
+enum Outcome<T> {
+    Result(T),
+    Error(RpcError),
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PositionParams {
+    board: Option<String>,
+    to_move: Option<String>,
+    moves: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct PlayParams {
+    #[serde(rename = "move")]
+    mv: String,
+}
+
+#[derive(Deserialize)]
+struct SearchParams {
+    depth: Option<u8>,
+    count: Option<usize>,
+}
+
+#[derive(Serialize)]
+struct EngineInfo<'a> {
+    name: &'a str,
+}
+
+/// The player to move, the number of empty squares and the legal moves.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct State {
+    to_move: Option<&'static str>,
+    empties: u8,
+    moves: Vec<String>,
+}
+
+#[derive(Serialize)]
+struct BestMove {
+    #[serde(rename = "move")]
+    mv: String,
+    score: f32,
+    solved: bool,
+    depth: u8,
+    nodes: u64,
+    time: f64,
+    pv: Vec<String>,
+}
+
+#[derive(Serialize)]
+struct Hint {
+    #[serde(rename = "move")]
+    mv: String,
+    score: f32,
+    solved: bool,
+    pv: Vec<String>,
+}
+
+#[derive(Serialize)]
+struct Hints {
+    moves: Vec<Hint>,
+}
+
+impl RpcError {
+    fn new(code: i64, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+        }
+    }
+}
+
+/// Serves an engine over the JSON-RPC protocol.
+///
+/// See the [module documentation] for the protocol.
+///
+/// [module documentation]: crate::protocol::jsonrpc
+///
+/// # Examples
+/// ```rust
+/// use magpie::{eval::DiscDifference, protocol::jsonrpc::JsonRpcServer, search::Searcher};
+///
+/// let mut server = JsonRpcServer::new(Searcher::new(DiscDifference));
+/// let input = r#"{"jsonrpc":"2.0","id":1,"method":"engine"}"#;
+/// let mut output = Vec::new();
+/// server.run(input.as_bytes(), &mut output).unwrap();
+/// assert_eq!(
+///     "{\"jsonrpc\":\"2.0\",\"id\":1,\"result\":{\"name\":\"magpie\"}}\n",
+///     String::from_utf8(output).unwrap()
+/// );
+/// ```
+pub struct JsonRpcServer<E: Engine> {
+    engine: E,
+    game: Game,
+    depth: u8,
+}
+
+impl<E: Engine> JsonRpcServer<E> {
+    /// Returns a server for the engine, starting from the standard opening
+    /// position.
+    #[must_use]
+    pub fn new(engine: E) -> Self {
+        Self {
+            engine,
+            game: Game::new(),
+            depth: DEFAULT_DEPTH,
+        }
+    }
+
+    /// Sets the depth searched unless a request specifies one.
+    #[must_use]
+    pub fn with_depth(self, depth: u8) -> Self {
+        Self { depth, ..self }
+    }
+
+    /// Returns the engine being served.
+    #[must_use]
+    pub fn engine(&self) -> &E {
+        &self.engine
+    }
+
+    /// Returns the game as currently known by the server.
+    #[must_use]
+    pub fn game(&self) -> &Game {
+        &self.game
+    }
+
+    /// Returns the depth searched unless a request specifies one.
+    #[must_use]
+    pub fn depth(&self) -> u8 {
+        self.depth
+    }
+
+    /// Reads requests from standard input and writes the responses to
+    /// standard output until the client quits.
+    pub fn run_stdio(&mut self) -> io::Result<()> {
+        self.run(io::stdin().lock(), io::stdout().lock())
+    }
+
+    /// Reads requests from the input and writes the responses to the output
+    /// until either the input ends or the client quits.
+    pub fn run<R: BufRead, W: Write>(&mut self, input: R, mut output: W) -> io::Result<()> {
+        for line in input.lines() {
+            if !self.handle(&line?, &mut output)? {
+                break;
+            }
+        }
+        Ok(())
+    }
+
+    /// Handles a single line, writing the response to the output unless the
+    /// request is a notification.
+    ///
+    /// Returns false if the client asked the server to quit. Empty lines are
+    /// ignored.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::{eval::DiscDifference, protocol::jsonrpc::JsonRpcServer, search::Searcher};
+    ///
+    /// let mut server = JsonRpcServer::new(Searcher::new(DiscDifference));
+    /// let mut output = Vec::new();
+    /// let request = r#"{"jsonrpc":"2.0","method":"play","params":{"move":"f5"}}"#;
+    /// assert!(server.handle(request, &mut output).unwrap());
+    /// assert_eq!(59, server.game().empty_squares().count_set());
+    /// // Notifications are not answered
+    /// assert!(output.is_empty());
+    /// ```
+    pub fn handle<W: Write>(&mut self, line: &str, output: &mut W) -> io::Result<bool> {
+        let line = line.trim();
+        if line.is_empty() {
+            return Ok(true);
+        }
+        let Ok(mut request) = serde_json::from_str::<Value>(line) else {
+            let error = RpcError::new(PARSE_ERROR, "Parse error");
+            return respond::<_, ()>(output, &Value::Null, Err(error)).map(|()| true);
+        };
+
+        let id = request.get_mut("id").map(Value::take);
+        if id
+            .as_ref()
+            .is_some_and(|id| matches!(id, Value::Array(_) | Value::Object(_) | Value::Bool(_)))
+        {
+            let error = RpcError::new(INVALID_REQUEST, "Invalid id");
+            return respond::<_, ()>(output, &Value::Null, Err(error)).map(|()| true);
+        }
+        let method = request.get("method").and_then(Value::as_str);
+        let version = request.get("jsonrpc").and_then(Value::as_str);
+        let (Some(method), Some("2.0")) = (method, version) else {
+            let error = RpcError::new(INVALID_REQUEST, "Invalid request");
+            let id = id.as_ref().unwrap_or(&Value::Null);
+            return respond::<_, ()>(output, id, Err(error)).map(|()| true);
+        };
+        let method = method.to_string();
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!("jsonrpc", method, id = ?id).entered();
+        let params = match request.get_mut("params").map(Value::take) {
+            None => Ok(Value::Object(Map::new())),
+            Some(params @ Value::Object(_)) => Ok(params),
+            Some(_) => Err(RpcError::new(
+                INVALID_PARAMS,
+                "Parameters must be an object",
+            )),
+        };
+
+        match id {
+            Some(id) => self.call(&method, params, output, &id)?,
+            // Notifications are handled the same way, without a response
+            None => self.call(&method, params, &mut io::sink(), &Value::Null)?,
+        }
+        Ok(method != "quit")
+    }
+
+    /// Calls the method and writes its response to the output.
+    fn call<W: Write>(
+        &mut self,
+        method: &str,
+        params: Result<Value, RpcError>,
+        output: &mut W,
+        id: &Value,
+    ) -> io::Result<()> {
+        let params = match params {
+            Ok(params) => params,
+            Err(error) => return respond::<_, ()>(output, id, Err(error)),
+        };
+        match method {
+            "engine" => {
+                let name = self.engine.name().to_string();
+                respond(output, id, Ok(EngineInfo { name: &name }))
+            }
+            "position" => respond(output, id, self.position(params)),
+            "play" => respond(output, id, self.play(params)),
+            "go" => respond(output, id, self.go(params)),
+            "analyze" => respond(output, id, self.analyze(params)),
+            "quit" => respond(output, id, Ok(())),
+            _ => respond::<_, ()>(
+                output,
+                id,
+                Err(RpcError::new(
+                    METHOD_NOT_FOUND,
+                    format!("Unknown method {method}"),
+                )),
+            ),
+        }
+    }
+
+    fn position(&mut self, params: Value) -> Result<State, RpcError> {
+        let params: PositionParams = parse_params(params)?;
+        let board = params
+            .board
+            .map(|board| parse_board(&board).ok_or_else(|| invalid("board")))
+            .transpose()?;
+        let to_move = params
+            .to_move
+            .map(|stone| parse_stone(&stone).ok_or_else(|| invalid("toMove")))
+            .transpose()?;
+        let moves = params
+            .moves
+            .map(|moves| moves.parse::<Transcript>().map_err(|_| invalid("moves")))
+            .transpose()?;
+
+        let mut game = match (board, to_move) {
+            (None, None) => Game::new(),
+            (board, to_move) => Game::from_position(
+                board.unwrap_or_else(Board::standard),
+                to_move.unwrap_or(Stone::Black),
+            )
+            .map_err(|error| RpcError::new(INVALID_PARAMS, error.to_string()))?,
+        };
+        if let Some(moves) = moves {
+            moves
+                .replay_onto(&mut game)
+                .map_err(|error| RpcError::new(ILLEGAL_MOVE, error.to_string()))?;
+        }
+        self.game = game;
+        Ok(self.state())
+    }
+
+    fn play(&mut self, params: Value) -> Result<State, RpcError> {
+        let PlayParams { mv } = parse_params(params)?;
+        let legal = match parse_move(&mv) {
+            Some(Move::Play(pos)) => self.game.play(pos).is_ok(),
+            Some(Move::Pass) => self.game.pass_if_forced(),
+            None => return Err(invalid("move")),
+        };
+        if !legal {
+            return Err(RpcError::new(ILLEGAL_MOVE, format!("Illegal move {mv}")));
+        }
+        Ok(self.state())
+    }
+
+    fn go(&mut self, params: Value) -> Result<BestMove, RpcError> {
+        let params: SearchParams = parse_params(params)?;
+        let depth = self.search_depth(&params);
+        self.check_progressing()?;
+        let report = self
+            .engine
+            .search(&self.game.board(), self.game.current_turn(), depth);
+        let mv = match report.best_move {
+            Some(pos) if self.game.is_legal_move(pos) => notation(pos),
+            _ => String::from("pass"),
+        };
+        Ok(BestMove {
+            mv,
+            score: disc_score(report.score),
+            solved: is_solved(report.score),
+            depth: report.depth,
+            nodes: report.nodes,
+            time: report.elapsed.as_secs_f64(),
+            pv: pv(&report.pv),
+        })
+    }
+
+    fn analyze(&mut self, params: Value) -> Result<Hints, RpcError> {
+        let params: SearchParams = parse_params(params)?;
+        let depth = self.search_depth(&params);
+        let count = params.count.unwrap_or(1);
+        self.check_progressing()?;
+
+        let board = self.game.board();
+        let stone = self.game.current_turn();
+        let mut reports = rank_moves(&board, stone, depth, |board, stone, depth| {
+            self.engine.search(board, stone, depth)
+        });
+        if reports.is_empty() {
+            reports.push(self.engine.search(&board, stone, depth));
+        }
+        let moves = reports
+            .into_iter()
+            .take(count)
+            .map(|report| hint(&report))
+            .collect();
+        Ok(Hints { moves })
+    }
+
+    fn search_depth(&self, params: &SearchParams) -> u8 {
+        params
+            .depth
+            .filter(|&depth| depth > 0)
+            .unwrap_or(self.depth)
+    }
+
+    fn check_progressing(&self) -> Result<(), RpcError> {
+        if self.game.status() == Status::Progressing {
+            Ok(())
+        } else {
+            Err(RpcError::new(ILLEGAL_MOVE, "The game has ended"))
+        }
+    }
+
+    /// Returns the player to move, the number of empty squares and the legal
+    /// moves.
+    fn state(&self) -> State {
+        let to_move = match (self.game.status(), self.game.current_turn()) {
+            (Status::Progressing, Stone::Black) => Some("black"),
+            (Status::Progressing, Stone::White) => Some("white"),
+            _ => None,
+        };
+        let moves: Vec<Position> = self.game.moves().hot_bits().collect();
+        State {
+            to_move,
+            empties: self.game.empty_squares().count_set(),
+            moves: pv(&moves),
+        }
+    }
+}
+
+/// Writes the response to a request, on a single line.
+fn respond<W: Write, T: Serialize>(
+    output: &mut W,
+    id: &Value,
+    result: Result<T, RpcError>,
+) -> io::Result<()> {
+    let outcome = match result {
+        Ok(result) => Outcome::Result(result),
+        Err(error) => Outcome::Error(error),
+    };
+    let response = Response {
+        jsonrpc: "2.0",
+        id,
+        outcome,
+    };
+    serde_json::to_writer(&mut *output, &response)?;
+    writeln!(output)?;
+    output.flush()
+}
+
+/// Parses the parameters of a request, returning an error if they do not
+/// match the method.
+fn parse_params<T: DeserializeOwned>(params: Value) -> Result<T, RpcError> {
+    serde_json::from_value(params).map_err(|error| RpcError::new(INVALID_PARAMS, error.to_string()))
+}
+
+fn invalid(name: &str) -> RpcError {
+    RpcError::new(INVALID_PARAMS, format!("Invalid {name}"))
+}
+
+/// Describes a move ranked by the engine, or the forced pass if the report
+/// has no best move.
+fn hint(report: &SearchReport) -> Hint {
+    Hint {
+        mv: report
+            .best_move
+            .map_or_else(|| String::from("pass"), notation),
+        score: disc_score(report.score),
+        solved: is_solved(report.score),
+        pv: pv(&report.pv),
+    }
+}
+
+fn notation(pos: Position) -> String {
+    pos.to_string()
+}
+
+fn pv(moves: &[Position]) -> Vec<String> {
+    moves.iter().map(|&pos| notation(pos)).collect()
+}
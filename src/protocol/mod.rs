@@ -0,0 +1,23 @@
+//! Adapters that let engines built with magpie talk to graphical user
+//! interfaces and other programs.
+//!
+//! Every adapter drives an [`Engine`], which is implemented for the
+//! [`Searcher`] of the search module. Custom engines only need to implement
+//! the trait to be usable with all adapters.
+//!
+//! [`Searcher`]: crate::search::Searcher
+//! [`Engine`]: crate::protocol::Engine
+
+/// Engine settings read from configuration files.
+mod config;
+/// The interface between protocol adapters and search engines.
+mod engine;
+#[cfg(feature = "jsonrpc")]
+pub mod jsonrpc;
+pub mod nboard;
+/// Text notation shared by the adapters.
+mod notation;
+pub mod uoi;
+
+pub use config::{ConfigError, EngineConfig};
+pub use engine::Engine;
@@ -0,0 +1,231 @@
+//! An implementation of the NBoard engine protocol.
+//!
+//! NBoard is a graphical user interface for Othello that communicates with
+//! engines through their standard input and output. The GUI sends one
+//! command per line, such as `set game` followed by a game in the Generic
+//! Game Format, `move` to append a move to the game, `hint` to request an
+//! analysis of the best moves and `go` to request a move. The engine answers
+//! `ping` with `pong`, which the GUI uses to discard stale responses.
+//!
+//! # Examples
+//! ```rust,no_run
+//! use magpie::{eval::Heuristic, protocol::nboard::NboardServer, search::Searcher};
+//!
+//! let engine = Searcher::new(Heuristic::default());
+//! NboardServer::new(engine).run_stdio().unwrap();
+//! ```
+
+use crate::{
+    ggf,
+    othello::{Game, Move, Position},
+    protocol::{
+        Engine,
+        engine::{disc_score, is_solved},
+        notation::parse_move,
+    },
+    search::{SearchReport, rank_moves},
+};
+use std::io::{self, BufRead, Write};
+
+/// The depth searched until the GUI specifies one with `set depth`.
+const DEFAULT_DEPTH: u8 = 8;
+
+/// Serves an engine over the NBoard protocol.
+///
+/// # Examples
+/// ```rust
+/// use magpie::{eval::DiscDifference, protocol::nboard::NboardServer, search::Searcher};
+///
+/// let mut server = NboardServer::new(Searcher::new(DiscDifference));
+/// let input = "nboard 2\nping 1\nquit\n";
+/// let mut output = Vec::new();
+/// server.run(input.as_bytes(), &mut output).unwrap();
+/// assert_eq!("set myname magpie\npong 1\n", String::from_utf8(output).unwrap());
+/// ```
+pub struct NboardServer<E: Engine> {
+    engine: E,
+    game: Game,
+    depth: u8,
+}
+
+impl<E: Engine> NboardServer<E> {
+    /// Returns a server for the engine, starting from the standard opening
+    /// position.
+    #[must_use]
+    pub fn new(engine: E) -> Self {
+        Self {
+            engine,
+            game: Game::new(),
+            depth: DEFAULT_DEPTH,
+        }
+    }
+
+    /// Sets the depth searched until the GUI specifies one with `set depth`.
+    #[must_use]
+    pub fn with_depth(self, depth: u8) -> Self {
+        Self { depth, ..self }
+    }
+
+    /// Returns the engine being served.
+    #[must_use]
+    pub fn engine(&self) -> &E {
+        &self.engine
+    }
+
+    /// Returns the game as currently known by the server.
+    #[must_use]
+    pub fn game(&self) -> &Game {
+        &self.game
+    }
+
+    /// Returns the depth the engine searches to.
+    #[must_use]
+    pub fn depth(&self) -> u8 {
+        self.depth
+    }
+
+    /// Reads commands from standard input and writes the responses to
+    /// standard output until the GUI quits.
+    pub fn run_stdio(&mut self) -> io::Result<()> {
+        self.run(io::stdin().lock(), io::stdout().lock())
+    }
+
+    /// Reads commands from the input and writes the responses to the output
+    /// until either the input ends or the GUI quits.
+    pub fn run<R: BufRead, W: Write>(&mut self, input: R, mut output: W) -> io::Result<()> {
+        for line in input.lines() {
+            if !self.handle(&line?, &mut output)? {
+                break;
+            }
+        }
+        Ok(())
+    }
+
+    /// Handles a single command, writing any responses to the output.
+    ///
+    /// Returns false if the GUI asked the engine to quit. Unknown commands
+    /// are ignored, as required by the protocol.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::{eval::DiscDifference, protocol::nboard::NboardServer, search::Searcher};
+    ///
+    /// let mut server = NboardServer::new(Searcher::new(DiscDifference));
+    /// let mut output = Vec::new();
+    /// assert!(server.handle("move F5", &mut output).unwrap());
+    /// assert_eq!(59, server.game().empty_squares().count_set());
+    /// assert!(!server.handle("quit", &mut output).unwrap());
+    /// ```
+    pub fn handle<W: Write>(&mut self, line: &str, output: &mut W) -> io::Result<bool> {
+        let line = line.trim();
+        let (command, args) = line.split_once(char::is_whitespace).unwrap_or((line, ""));
+        let args = args.trim();
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!("nboard", command).entered();
+        match command {
+            "nboard" => writeln!(output, "set myname {}", self.engine.name())?,
+            "ping" => writeln!(output, "pong {args}")?,
+            "learn" => writeln!(output, "learned")?,
+            "quit" => return Ok(false),
+            "set" => self.set(args, output)?,
+            "move" => self.play(args, output)?,
+            "hint" => self.hint(args.parse().unwrap_or(1), output)?,
+            "go" => self.go(output)?,
+            _ => {}
+        }
+        output.flush()?;
+        Ok(true)
+    }
+
+    fn set<W: Write>(&mut self, args: &str, output: &mut W) -> io::Result<()> {
+        let (name, value) = args.split_once(char::is_whitespace).unwrap_or((args, ""));
+        match name {
+            "depth" => match value.trim().parse() {
+                Ok(depth) => self.depth = depth,
+                Err(_) => writeln!(output, "status Invalid depth {value}")?,
+            },
+            "game" => match ggf::parse_game(value).and_then(|game| game.replay()) {
+                Ok(game) => self.game = game,
+                Err(error) => writeln!(output, "status Invalid game: {error}")?,
+            },
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn play<W: Write>(&mut self, args: &str, output: &mut W) -> io::Result<()> {
+        // Moves may be annotated with an evaluation and time, as in F5/1.0/2
+        let notation = args.split('/').next().unwrap_or_default().trim();
+        let legal = match parse_move(notation) {
+            Some(Move::Play(pos)) => self.game.play(pos).is_ok(),
+            Some(Move::Pass) => self.game.pass_if_forced(),
+            None => false,
+        };
+        if !legal {
+            writeln!(output, "status Illegal move {args}")?;
+        }
+        Ok(())
+    }
+
+    fn hint<W: Write>(&mut self, count: usize, output: &mut W) -> io::Result<()> {
+        writeln!(output, "status Analyzing")?;
+        let board = self.game.board();
+        let stone = self.game.current_turn();
+        let moves = self.game.moves();
+        if moves.is_empty() {
+            let report = self.engine.search(&board, stone, self.depth);
+            let depth = depth_text(&report);
+            writeln!(
+                output,
+                "search PA {:.2} 0 {depth}",
+                disc_score(report.score)
+            )?;
+        } else {
+            let reports = rank_moves(&board, stone, self.depth, |board, stone, depth| {
+                self.engine.search(board, stone, depth)
+            });
+            for report in reports.into_iter().take(count) {
+                let mv = report
+                    .best_move
+                    .map_or_else(|| String::from("PA"), notation);
+                let depth = depth_text(&report);
+                writeln!(
+                    output,
+                    "search {mv} {:.2} 0 {depth}",
+                    disc_score(report.score)
+                )?;
+            }
+        }
+        writeln!(output, "status")
+    }
+
+    fn go<W: Write>(&mut self, output: &mut W) -> io::Result<()> {
+        writeln!(output, "status Thinking")?;
+        let report = self
+            .engine
+            .search(&self.game.board(), self.game.current_turn(), self.depth);
+        let mv = match report.best_move {
+            Some(pos) if self.game.is_legal_move(pos) => notation(pos),
+            _ => String::from("PA"),
+        };
+        writeln!(output, "status")?;
+        writeln!(
+            output,
+            "=== {mv}/{:.2}/{:.3}",
+            disc_score(report.score),
+            report.elapsed.as_secs_f32()
+        )
+    }
+}
+
+fn notation(pos: Position) -> String {
+    pos.to_string().to_uppercase()
+}
+
+fn depth_text(report: &SearchReport) -> String {
+    if is_solved(report.score) {
+        String::from("100%")
+    } else {
+        report.depth.to_string()
+    }
+}
@@ -0,0 +1,39 @@
+use crate::othello::{Bitboard, Board, Move, Position, Stone};
+
+/// Parses a move such as `f5`, or a pass written as `pass`, `pa` or `--`,
+/// ignoring case.
+pub(crate) fn parse_move(notation: &str) -> Option<Move> {
+    match notation.to_lowercase().as_str() {
+        "pass" | "pa" | "--" => Some(Move::Pass),
+        notation => Position::try_from(notation).ok().map(Move::Play),
+    }
+}
+
+/// Parses a board written as 64 squares from A1 to H8, where `X` or `*` is
+/// a black stone, `O` is a white stone and `-` or `.` is an empty square.
+/// Whitespace between squares is ignored.
+pub(crate) fn parse_board(text: &str) -> Option<Board> {
+    let squares: Vec<char> = text.chars().filter(|c| !c.is_whitespace()).collect();
+    if squares.len() != 64 {
+        return None;
+    }
+    let mut board = Board::empty();
+    for (pos, square) in Bitboard::from(u64::MAX).hot_bits().zip(squares) {
+        match square.to_ascii_uppercase() {
+            'X' | '*' => board.place_stone_unchecked(Stone::Black, pos.into()),
+            'O' => board.place_stone_unchecked(Stone::White, pos.into()),
+            '-' | '.' => {}
+            _ => return None,
+        }
+    }
+    Some(board)
+}
+
+/// Parses a player written as `black` or `white`, ignoring case.
+pub(crate) fn parse_stone(text: &str) -> Option<Stone> {
+    match text.to_lowercase().as_str() {
+        "black" => Some(Stone::Black),
+        "white" => Some(Stone::White),
+        _ => None,
+    }
+}
@@ -0,0 +1,76 @@
+//! An implementation of UOI, the Universal Othello Interface.
+//!
+//! UOI is a text protocol modeled after the Universal Chess Interface
+//! (UCI), spoken by several Othello user interfaces. The user interface
+//! sends one command per line to the standard input of the engine, which
+//! answers on its standard output. Searches run in the background, so the
+//! engine keeps responding while it is thinking.
+//!
+//! ```text
+//! --> uoi
+//! <-- id name magpie
+//! <-- option name Depth type spin default 8 min 1 max 60
+//! <-- uoiok
+//! --> position startpos moves f5 d6
+//! --> go movetime 1000
+//! <-- info depth 1 score cp 3 nodes 4 time 0 pv c3
+//! <-- info depth 2 score cp -1 nodes 21 time 0 pv c3 d3
+//! <-- ...
+//! <-- bestmove c3
+//! ```
+//!
+//! # Commands
+//!
+//! - `uoi`: The engine identifies itself with `id name`, announces its
+//!   options and finishes with `uoiok`.
+//! - `isready`: The engine answers `readyok`, even while searching.
+//! - `uoinewgame`: Stops any search and resets the position.
+//! - `setoption name <name> [value <value>]`: Sets an option of the engine.
+//! - `position startpos [moves <moves>]` and `position board <squares>
+//!   <player> [moves <moves>]`: Sets the position, either the standard
+//!   opening position or a board written as 64 squares from A1 to H8, where
+//!   `X` is a black stone, `O` a white stone and `-` an empty square, with
+//!   `black` or `white` to move. The moves are then played from it, with
+//!   passes written as `pass`.
+//! - `go [depth <plies>] [nodes <count>] [movetime <ms>] [btime <ms>]
+//!   [wtime <ms>] [binc <ms>] [winc <ms>] [infinite]`: Starts searching the
+//!   position. The search is limited to a depth, a number of nodes, a fixed
+//!   time or a share of the time on the clock of the player to move, and to
+//!   the depth of the `Depth` option if no limits are given. Infinite
+//!   searches only report their best move once stopped.
+//! - `stop`: Stops the search, which reports its best move.
+//! - `quit`: Stops any search and exits.
+//!
+//! While searching, the engine reports every completed iteration with
+//! `info`, giving the score in the units of the evaluator as `cp`, or in
+//! discs as `disc` once the position is solved. The search ends with
+//! `bestmove`, followed by the move, `pass` or `none` if the game has
+//! ended. Problems with commands are reported with `info string`, while
+//! unknown commands are ignored.
+//!
+//! Engine parameters are exposed to the user interface through an
+//! [`OptionRegistry`], which announces them in response to `uoi` and applies
+//! new values with `setoption`. Custom options are added with
+//! [`UoiServer::with_option`], while the settings of an [`EngineConfig`] are
+//! exposed with [`UoiServer::with_config`].
+//!
+//! [`EngineConfig`]: crate::protocol::EngineConfig
+//! [`OptionRegistry`]: crate::protocol::uoi::OptionRegistry
+//! [`UoiServer::with_option`]: crate::protocol::uoi::UoiServer::with_option
+//! [`UoiServer::with_config`]: crate::protocol::uoi::UoiServer::with_config
+//!
+//! # Examples
+//! ```rust,no_run
+//! use magpie::{eval::Heuristic, protocol::uoi::UoiServer, search::Searcher};
+//!
+//! let engine = Searcher::new(Heuristic::default());
+//! UoiServer::new(engine).run_stdio().unwrap();
+//! ```
+
+/// Engine parameters that the user interface can set.
+mod options;
+/// Serves engines over the protocol.
+mod server;
+
+pub use options::{OptionError, OptionKind, OptionRegistry, OptionValue, UoiOption};
+pub use server::UoiServer;
@@ -0,0 +1,336 @@
+use std::fmt;
+
+/// Applies a new value of an option to the engine, or rejects it.
+type Handler<E> = Box<dyn FnMut(&mut E, &OptionValue) -> Result<(), OptionError> + Send>;
+
+/// The type of an option, along with its default value and valid range.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum OptionKind {
+    /// A boolean option.
+    Check {
+        /// The value of the option until it is set.
+        default: bool,
+    },
+    /// An integer option within a range.
+    Spin {
+        /// The value of the option until it is set.
+        default: i64,
+        /// The smallest valid value.
+        min: i64,
+        /// The largest valid value.
+        max: i64,
+    },
+    /// An option with one of several predefined values.
+    Combo {
+        /// The value of the option until it is set.
+        default: String,
+        /// The valid values.
+        choices: Vec<String>,
+    },
+    /// A free-form text option.
+    String {
+        /// The value of the option until it is set.
+        default: String,
+    },
+    /// An action without a value, such as clearing the hash table.
+    Button,
+}
+
+/// The value of an option.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum OptionValue {
+    /// The value of a check option.
+    Check(bool),
+    /// The value of a spin option.
+    Spin(i64),
+    /// The value of a combo or string option.
+    String(String),
+    /// Indicates that a button was pressed.
+    Button,
+}
+
+/// An option the user interface can set with `setoption`.
+///
+/// Options are announced to the user interface in response to `uoi`, in
+/// the same format as options in UCI.
+///
+/// # Examples
+/// ```rust
+/// use magpie::protocol::uoi::{OptionValue, UoiOption};
+///
+/// let option = UoiOption::spin("Hash", 16, 1, 1024);
+/// assert_eq!(
+///     "option name Hash type spin default 16 min 1 max 1024",
+///     option.to_string()
+/// );
+/// assert_eq!(Ok(OptionValue::Spin(64)), option.parse(Some("64")));
+/// assert!(option.parse(Some("4096")).is_err());
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct UoiOption {
+    name: String,
+    kind: OptionKind,
+}
+
+impl UoiOption {
+    /// Returns an option of the specified kind.
+    #[must_use]
+    pub fn new(name: &str, kind: OptionKind) -> Self {
+        Self {
+            name: name.to_string(),
+            kind,
+        }
+    }
+
+    /// Returns a boolean option.
+    #[must_use]
+    pub fn check(name: &str, default: bool) -> Self {
+        Self::new(name, OptionKind::Check { default })
+    }
+
+    /// Returns an integer option within the inclusive range.
+    #[must_use]
+    pub fn spin(name: &str, default: i64, min: i64, max: i64) -> Self {
+        Self::new(name, OptionKind::Spin { default, min, max })
+    }
+
+    /// Returns an option with one of the specified values.
+    #[must_use]
+    pub fn combo(name: &str, default: &str, choices: &[&str]) -> Self {
+        let default = default.to_string();
+        let choices = choices.iter().map(ToString::to_string).collect();
+        Self::new(name, OptionKind::Combo { default, choices })
+    }
+
+    /// Returns a free-form text option.
+    #[must_use]
+    pub fn string(name: &str, default: &str) -> Self {
+        let default = default.to_string();
+        Self::new(name, OptionKind::String { default })
+    }
+
+    /// Returns an action without a value.
+    #[must_use]
+    pub fn button(name: &str) -> Self {
+        Self::new(name, OptionKind::Button)
+    }
+
+    /// Returns the name of the option.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the type of the option.
+    #[must_use]
+    pub fn kind(&self) -> &OptionKind {
+        &self.kind
+    }
+
+    /// Returns the value of the option until it is set.
+    #[must_use]
+    pub fn default_value(&self) -> OptionValue {
+        match &self.kind {
+            OptionKind::Check { default } => OptionValue::Check(*default),
+            OptionKind::Spin { default, .. } => OptionValue::Spin(*default),
+            OptionKind::Combo { default, .. } | OptionKind::String { default } => {
+                OptionValue::String(default.clone())
+            }
+            OptionKind::Button => OptionValue::Button,
+        }
+    }
+
+    /// Parses a value of the option, as sent with `setoption`.
+    ///
+    /// Returns an error if the value is missing or invalid. Buttons are the
+    /// only options without a value, and combo options are matched without
+    /// regard to case.
+    pub fn parse(&self, value: Option<&str>) -> Result<OptionValue, OptionError> {
+        let invalid = || OptionError::InvalidValue(self.name.clone());
+        match (&self.kind, value.map(str::trim)) {
+            (OptionKind::Button, _) => Ok(OptionValue::Button),
+            (_, None) => Err(invalid()),
+            (OptionKind::Check { .. }, Some(value)) => match value.to_lowercase().as_str() {
+                "true" => Ok(OptionValue::Check(true)),
+                "false" => Ok(OptionValue::Check(false)),
+                _ => Err(invalid()),
+            },
+            (OptionKind::Spin { min, max, .. }, Some(value)) => value
+                .parse()
+                .ok()
+                .filter(|value| (min..=max).contains(&value))
+                .map(OptionValue::Spin)
+                .ok_or_else(invalid),
+            (OptionKind::Combo { choices, .. }, Some(value)) => choices
+                .iter()
+                .find(|choice| choice.eq_ignore_ascii_case(value))
+                .map(|choice| OptionValue::String(choice.clone()))
+                .ok_or_else(invalid),
+            (OptionKind::String { .. }, Some(value)) => Ok(OptionValue::String(value.to_string())),
+        }
+    }
+}
+
+impl fmt::Display for UoiOption {
+    /// Formats the option as announced to the user interface.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "option name {} type ", self.name)?;
+        match &self.kind {
+            OptionKind::Check { default } => write!(f, "check default {default}"),
+            OptionKind::Spin { default, min, max } => {
+                write!(f, "spin default {default} min {min} max {max}")
+            }
+            OptionKind::Combo { default, choices } => {
+                write!(f, "combo default {default}")?;
+                for choice in choices {
+                    write!(f, " var {choice}")?;
+                }
+                Ok(())
+            }
+            OptionKind::String { default } => write!(f, "string default {default}"),
+            OptionKind::Button => f.write_str("button"),
+        }
+    }
+}
+
+/// The options of an engine, along with their current values and the
+/// handlers that apply new values to the engine.
+///
+/// Names are matched without regard to case, as in UCI.
+///
+/// # Examples
+/// ```rust
+/// use magpie::protocol::uoi::{OptionRegistry, OptionValue, UoiOption};
+///
+/// let mut registry = OptionRegistry::new();
+/// registry.register(UoiOption::check("Ponder", false), |enabled: &mut bool, value| {
+///     *enabled = *value == OptionValue::Check(true);
+/// });
+///
+/// let mut enabled = false;
+/// registry.set(&mut enabled, "ponder", Some("true")).unwrap();
+/// assert!(enabled);
+/// assert_eq!(Some(&OptionValue::Check(true)), registry.value("Ponder"));
+/// assert!(registry.set(&mut enabled, "Threads", Some("4")).is_err());
+/// ```
+pub struct OptionRegistry<E> {
+    options: Vec<(UoiOption, OptionValue, Option<Handler<E>>)>,
+}
+
+impl<E> OptionRegistry<E> {
+    /// Returns a registry without any options.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            options: Vec::new(),
+        }
+    }
+
+    /// Adds an option, which is applied to the engine by the handler every
+    /// time it is set. Replaces any option with the same name.
+    pub fn register(
+        &mut self,
+        option: UoiOption,
+        handler: impl FnMut(&mut E, &OptionValue) + Send + 'static,
+    ) {
+        let mut handler = handler;
+        self.insert(
+            option,
+            Some(Box::new(move |engine: &mut E, value: &OptionValue| {
+                handler(engine, value);
+                Ok(())
+            })),
+        );
+    }
+
+    /// Adds an option whose handler may reject values that are within the
+    /// range of the option but still unusable, such as paths to files that
+    /// do not exist. Rejected values are not applied, and the option keeps
+    /// its previous value. Replaces any option with the same name.
+    pub fn register_checked(
+        &mut self,
+        option: UoiOption,
+        handler: impl FnMut(&mut E, &OptionValue) -> Result<(), OptionError> + Send + 'static,
+    ) {
+        self.insert(option, Some(Box::new(handler)));
+    }
+
+    /// Adds an option without a handler, whose value is only read back with
+    /// [`value`]. Replaces any option with the same name.
+    ///
+    /// [`value`]: crate::protocol::uoi::OptionRegistry::value
+    pub fn register_value(&mut self, option: UoiOption) {
+        self.insert(option, None);
+    }
+
+    fn insert(&mut self, option: UoiOption, handler: Option<Handler<E>>) {
+        self.options
+            .retain(|(other, _, _)| !other.name.eq_ignore_ascii_case(&option.name));
+        let value = option.default_value();
+        self.options.push((option, value, handler));
+    }
+
+    /// Returns the current value of the option, if it exists.
+    #[must_use]
+    pub fn value(&self, name: &str) -> Option<&OptionValue> {
+        self.options
+            .iter()
+            .find(|(option, _, _)| option.name.eq_ignore_ascii_case(name))
+            .map(|(_, value, _)| value)
+    }
+
+    /// Parses and sets the value of the option, applying it to the engine.
+    ///
+    /// Returns an error if there is no such option, or the value is invalid
+    /// or rejected by the handler, in which case the option keeps its
+    /// previous value.
+    pub fn set(
+        &mut self,
+        engine: &mut E,
+        name: &str,
+        value: Option<&str>,
+    ) -> Result<(), OptionError> {
+        let (option, current, handler) = self
+            .options
+            .iter_mut()
+            .find(|(option, _, _)| option.name.eq_ignore_ascii_case(name))
+            .ok_or_else(|| OptionError::Unknown(name.to_string()))?;
+        let value = option.parse(value)?;
+        if let Some(handler) = handler {
+            handler(engine, &value)?;
+        }
+        *current = value;
+        Ok(())
+    }
+
+    /// Returns an iterator over the options in the order they were added.
+    pub fn options(&self) -> impl Iterator<Item = &UoiOption> {
+        self.options.iter().map(|(option, _, _)| option)
+    }
+}
+
+impl<E> Default for OptionRegistry<E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// This enum represents errors that may occur when setting options.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum OptionError {
+    /// Indicates that there is no option with the specified name.
+    Unknown(String),
+    /// Indicates that the value of the named option is missing or invalid.
+    InvalidValue(String),
+}
+
+impl fmt::Display for OptionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OptionError::Unknown(name) => write!(f, "unknown option {name}"),
+            OptionError::InvalidValue(name) => write!(f, "invalid value for option {name}"),
+        }
+    }
+}
+
+impl core::error::Error for OptionError {}
@@ -0,0 +1,577 @@
+use crate::{
+    othello::{Game, Status, Stone, Transcript},
+    protocol::{
+        Engine, EngineConfig,
+        engine::{disc_score, is_solved},
+        notation::{parse_board, parse_move, parse_stone},
+        uoi::{OptionError, OptionRegistry, OptionValue, UoiOption},
+    },
+    search::{ClockState, SearchLimits, SearchReport, TimeManager},
+};
+use std::{
+    fmt::Write as _,
+    io::{self, BufRead, BufReader, Write},
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicBool, Ordering},
+        mpsc::{self, Receiver, RecvTimeoutError},
+    },
+    thread::{self, JoinHandle},
+    time::Duration,
+};
+
+/// The name of the built-in option that sets the default search depth.
+const DEPTH_OPTION: &str = "Depth";
+/// The name of the option that makes searches independent of the clock,
+/// which is registered by [`EngineConfig`].
+const DETERMINISTIC_OPTION: &str = "Deterministic";
+/// The depth searched until the user interface sets the `Depth` option.
+const DEFAULT_DEPTH: u8 = 8;
+/// The deepest search that can be requested.
+const MAX_DEPTH: u8 = 60;
+/// How often a running search is checked for progress while waiting for
+/// input.
+const POLL_INTERVAL: Duration = Duration::from_millis(5);
+
+/// A search running on a background thread.
+struct RunningSearch<E> {
+    stop: Arc<AtomicBool>,
+    updates: Receiver<SearchReport>,
+    thread: JoinHandle<(E, SearchReport)>,
+    /// Whether the best move is held back until the search is stopped.
+    infinite: bool,
+    game: Game,
+}
+
+/// Serves an engine over the UOI protocol.
+///
+/// Searches run on a background thread, so commands such as `stop` and
+/// `isready` are handled while the engine is thinking. The engine is moved
+/// to the background thread for the duration of the search, which is why it
+/// has to be [`Send`].
+///
+/// See the [module documentation] for the protocol.
+///
+/// [module documentation]: crate::protocol::uoi
+///
+/// # Examples
+/// ```rust
+/// use magpie::{eval::DiscDifference, protocol::uoi::UoiServer, search::Searcher};
+/// use std::io::Cursor;
+///
+/// let mut server = UoiServer::new(Searcher::new(DiscDifference));
+/// let input = "uoi\nposition startpos moves f5 d6\ngo depth 3\n";
+/// let mut output = Vec::new();
+/// server.run(Cursor::new(input), &mut output).unwrap();
+///
+/// let output = String::from_utf8(output).unwrap();
+/// assert!(output.starts_with("id name magpie\n"));
+/// assert!(output.lines().last().unwrap().starts_with("bestmove "));
+/// ```
+pub struct UoiServer<E: Engine> {
+    engine: Option<E>,
+    game: Game,
+    options: OptionRegistry<E>,
+    search: Option<RunningSearch<E>>,
+}
+
+impl<E: Engine + Send + 'static> UoiServer<E> {
+    /// Returns a server for the engine, starting from the standard opening
+    /// position.
+    ///
+    /// The server registers the `Depth` option, which sets the depth
+    /// searched when `go` specifies neither a depth nor a time limit.
+    #[must_use]
+    pub fn new(engine: E) -> Self {
+        let mut options = OptionRegistry::new();
+        options.register_value(UoiOption::spin(
+            DEPTH_OPTION,
+            i64::from(DEFAULT_DEPTH),
+            1,
+            i64::from(MAX_DEPTH),
+        ));
+        Self {
+            engine: Some(engine),
+            game: Game::new(),
+            options,
+            search: None,
+        }
+    }
+
+    /// Sets the depth searched when `go` specifies neither a depth nor a
+    /// time limit, until the user interface sets the `Depth` option.
+    #[must_use]
+    pub fn with_depth(mut self, depth: u8) -> Self {
+        let option = UoiOption::spin(DEPTH_OPTION, i64::from(depth), 1, i64::from(MAX_DEPTH));
+        self.options.register_value(option);
+        self
+    }
+
+    /// Adds an engine parameter that the user interface can set, which is
+    /// applied to the engine by the handler. Replaces any option with the
+    /// same name.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::{
+    ///     eval::DiscDifference,
+    ///     protocol::uoi::{OptionValue, UoiOption, UoiServer},
+    ///     search::{Replacement, Searcher, TranspositionTable},
+    /// };
+    /// use std::io::Cursor;
+    ///
+    /// let mut server = UoiServer::new(Searcher::new(DiscDifference)).with_option(
+    ///     UoiOption::spin("Hash", 1, 1, 64),
+    ///     |searcher: &mut Searcher<DiscDifference>, value| {
+    ///         if let OptionValue::Spin(megabytes) = *value {
+    ///             let bytes = (megabytes as usize) << 20;
+    ///             let tt = TranspositionTable::with_memory(bytes, Replacement::DepthPreferred);
+    ///             *searcher = Searcher::new(DiscDifference).with_transposition_table(tt);
+    ///         }
+    ///     },
+    /// );
+    /// let mut output = Vec::new();
+    /// server
+    ///     .run(Cursor::new("setoption name Hash value 2\n"), &mut output)
+    ///     .unwrap();
+    /// assert_eq!(Some(&OptionValue::Spin(2)), server.options().value("hash"));
+    /// ```
+    #[must_use]
+    pub fn with_option(
+        mut self,
+        option: UoiOption,
+        handler: impl FnMut(&mut E, &OptionValue) + Send + 'static,
+    ) -> Self {
+        self.options.register(option, handler);
+        self
+    }
+
+    /// Exposes the configuration as options, so that the user interface can
+    /// change it with `setoption`. Every change is applied to the engine by
+    /// the handler, which receives the whole changed configuration.
+    ///
+    /// Changes that make the configuration invalid, such as weights that do
+    /// not exist, are reported to the user interface and not applied. The
+    /// `Depth` of the configuration replaces the depth of the server.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::{
+    ///     eval::DiscDifference,
+    ///     protocol::{EngineConfig, uoi::UoiServer},
+    ///     search::ParallelSearcher,
+    /// };
+    /// use std::io::Cursor;
+    ///
+    /// let config = EngineConfig {
+    ///     threads: 2,
+    ///     ..EngineConfig::default()
+    /// };
+    /// let mut server = UoiServer::new(config.searcher(DiscDifference)).with_config(
+    ///     config,
+    ///     |searcher: &mut ParallelSearcher<DiscDifference>, config| {
+    ///         *searcher = config.searcher(DiscDifference);
+    ///     },
+    /// );
+    /// let mut output = Vec::new();
+    /// server
+    ///     .run(Cursor::new("uoi\nsetoption name Threads value 4\n"), &mut output)
+    ///     .unwrap();
+    /// let output = String::from_utf8(output).unwrap();
+    /// assert!(output.contains("option name Threads type spin default 2 min 1 max 256\n"));
+    /// ```
+    #[must_use]
+    pub fn with_config(
+        mut self,
+        config: EngineConfig,
+        apply: impl FnMut(&mut E, &EngineConfig) + Send + 'static,
+    ) -> Self {
+        let options = config.options();
+        let shared = Arc::new(Mutex::new((config, apply)));
+        for option in options {
+            let shared = Arc::clone(&shared);
+            let name = option.name().to_string();
+            self.options.register_checked(option, move |engine, value| {
+                let (config, apply) = &mut *shared.lock().unwrap();
+                config
+                    .set_option(&name, value)
+                    .map_err(|_| OptionError::InvalidValue(name.clone()))?;
+                apply(engine, config);
+                Ok(())
+            });
+        }
+        self
+    }
+
+    /// Returns the engine being served, or `None` while it is searching.
+    #[must_use]
+    pub fn engine(&self) -> Option<&E> {
+        self.engine.as_ref()
+    }
+
+    /// Returns the game as currently known by the server.
+    #[must_use]
+    pub fn game(&self) -> &Game {
+        &self.game
+    }
+
+    /// Returns the options of the engine and their current values.
+    #[must_use]
+    pub fn options(&self) -> &OptionRegistry<E> {
+        &self.options
+    }
+
+    /// Returns true while a search is running, or has ended without its
+    /// best move being reported yet.
+    #[must_use]
+    pub fn is_searching(&self) -> bool {
+        self.search.is_some()
+    }
+
+    /// Reads commands from standard input and writes the responses to
+    /// standard output until the user interface quits.
+    pub fn run_stdio(&mut self) -> io::Result<()> {
+        self.run(BufReader::new(io::stdin()), io::stdout().lock())
+    }
+
+    /// Reads commands from the input and writes the responses to the output
+    /// until either the input ends or the user interface quits.
+    ///
+    /// The input is read on a separate thread, so that the progress of a
+    /// search is reported while waiting for commands. Once the input ends,
+    /// a running search is completed and its best move reported, unless the
+    /// search is infinite, in which case it is stopped.
+    pub fn run<R, W>(&mut self, input: R, mut output: W) -> io::Result<()>
+    where
+        R: BufRead + Send + 'static,
+        W: Write,
+    {
+        let (sender, lines) = mpsc::channel();
+        thread::spawn(move || {
+            for line in input.lines() {
+                if sender.send(line).is_err() {
+                    break;
+                }
+            }
+        });
+        loop {
+            let line = if self.is_searching() {
+                match lines.recv_timeout(POLL_INTERVAL) {
+                    Ok(line) => Some(line),
+                    Err(RecvTimeoutError::Timeout) => None,
+                    Err(RecvTimeoutError::Disconnected) => return self.wait(&mut output),
+                }
+            } else {
+                match lines.recv() {
+                    Ok(line) => Some(line),
+                    Err(_) => return Ok(()),
+                }
+            };
+            if let Some(line) = line
+                && !self.handle(&line?, &mut output)?
+            {
+                return Ok(());
+            }
+            self.poll(&mut output)?;
+        }
+    }
+
+    /// Handles a single command, writing any responses to the output.
+    ///
+    /// Returns false if the user interface asked the engine to quit. `go`
+    /// only starts a search, whose progress is written by [`poll`] and
+    /// [`wait`]. Unknown commands are ignored.
+    ///
+    /// [`poll`]: crate::protocol::uoi::UoiServer::poll
+    /// [`wait`]: crate::protocol::uoi::UoiServer::wait
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::{eval::DiscDifference, protocol::uoi::UoiServer, search::Searcher};
+    ///
+    /// let mut server = UoiServer::new(Searcher::new(DiscDifference));
+    /// let mut output = Vec::new();
+    /// server.handle("position startpos moves f5", &mut output).unwrap();
+    /// server.handle("go depth 2", &mut output).unwrap();
+    /// server.wait(&mut output).unwrap();
+    ///
+    /// let output = String::from_utf8(output).unwrap();
+    /// assert!(output.contains("info depth 2 "));
+    /// assert!(output.lines().last().unwrap().starts_with("bestmove "));
+    /// ```
+    pub fn handle<W: Write>(&mut self, line: &str, output: &mut W) -> io::Result<bool> {
+        let line = line.trim();
+        let (command, args) = line.split_once(char::is_whitespace).unwrap_or((line, ""));
+        let args = args.trim();
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!("uoi", command).entered();
+        match command {
+            "uoi" => {
+                let name = self
+                    .engine
+                    .as_ref()
+                    .map_or("magpie", |engine| engine.name());
+                writeln!(output, "id name {name}")?;
+                for option in self.options.options() {
+                    writeln!(output, "{option}")?;
+                }
+                writeln!(output, "uoiok")?;
+            }
+            "isready" => writeln!(output, "readyok")?,
+            "uoinewgame" => {
+                self.abort();
+                self.game = Game::new();
+            }
+            "position" => self.position(args, output)?,
+            "go" => self.go(args, output)?,
+            "stop" => {
+                if let Some(search) = &self.search {
+                    search.stop.store(true, Ordering::Relaxed);
+                }
+                self.wait(output)?;
+            }
+            "setoption" => self.set_option(args, output)?,
+            "quit" => {
+                self.abort();
+                return Ok(false);
+            }
+            _ => {}
+        }
+        output.flush()?;
+        Ok(true)
+    }
+
+    /// Writes the progress of the running search, along with its best move
+    /// once it has ended, without waiting.
+    pub fn poll<W: Write>(&mut self, output: &mut W) -> io::Result<()> {
+        let Some(search) = &self.search else {
+            return Ok(());
+        };
+        while let Ok(update) = search.updates.try_recv() {
+            writeln!(output, "{}", info(&update))?;
+        }
+        if search.thread.is_finished() && !search.infinite {
+            self.finish(output)?;
+        }
+        output.flush()
+    }
+
+    /// Waits for the running search to end and writes its progress and best
+    /// move. Infinite searches are stopped first.
+    pub fn wait<W: Write>(&mut self, output: &mut W) -> io::Result<()> {
+        let Some(search) = &self.search else {
+            return Ok(());
+        };
+        if search.infinite {
+            search.stop.store(true, Ordering::Relaxed);
+        }
+        // The channel disconnects once the background thread has ended
+        while let Ok(update) = search.updates.recv() {
+            writeln!(output, "{}", info(&update))?;
+        }
+        self.finish(output)?;
+        output.flush()
+    }
+
+    /// Joins the background thread and writes the best move.
+    fn finish<W: Write>(&mut self, output: &mut W) -> io::Result<()> {
+        let Some(search) = self.search.take() else {
+            return Ok(());
+        };
+        let (engine, report) = search.thread.join().expect("the search thread panicked");
+        self.engine = Some(engine);
+        let best = match report.best_move {
+            Some(pos) if search.game.is_legal_move(pos) => pos.to_string(),
+            // The search may be stopped before its first iteration completes
+            _ => match search.game.moves().hot_bits().next() {
+                Some(pos) => pos.to_string(),
+                None => String::from("pass"),
+            },
+        };
+        writeln!(output, "bestmove {best}")
+    }
+
+    /// Stops the running search without reporting its best move.
+    fn abort(&mut self) {
+        if let Some(search) = self.search.take() {
+            search.stop.store(true, Ordering::Relaxed);
+            let (engine, _) = search.thread.join().expect("the search thread panicked");
+            self.engine = Some(engine);
+        }
+    }
+
+    fn position<W: Write>(&mut self, args: &str, output: &mut W) -> io::Result<()> {
+        if self.is_searching() {
+            return writeln!(
+                output,
+                "info string Cannot set the position while searching"
+            );
+        }
+        let (setup, moves) = match args.split_once("moves") {
+            Some((setup, moves)) => (setup.trim(), Some(moves)),
+            None => (args, None),
+        };
+        let mut tokens = setup.split_whitespace();
+        let game = match tokens.next() {
+            Some("startpos") => Some(Game::new()),
+            Some("board") => {
+                let board = tokens.next().and_then(parse_board);
+                let stone = tokens.next().and_then(parse_stone);
+                board
+                    .zip(stone)
+                    .and_then(|(board, stone)| Game::from_position(board, stone).ok())
+            }
+            _ => None,
+        };
+        let Some(mut game) = game else {
+            return writeln!(output, "info string Invalid position {args}");
+        };
+        if let Some(moves) = moves {
+            let transcript: Option<Transcript> = moves.split_whitespace().map(parse_move).collect();
+            let replayed = transcript.is_some_and(|moves| moves.replay_onto(&mut game).is_ok());
+            if !replayed {
+                return writeln!(output, "info string Invalid moves {}", moves.trim());
+            }
+        }
+        self.game = game;
+        Ok(())
+    }
+
+    fn go<W: Write>(&mut self, args: &str, output: &mut W) -> io::Result<()> {
+        if self.is_searching() {
+            return writeln!(output, "info string Already searching");
+        }
+        let Some(limits) = self.limits(args) else {
+            return writeln!(output, "info string Invalid limits {args}");
+        };
+        if self.game.status() != Status::Progressing {
+            return writeln!(output, "bestmove none");
+        }
+        if self.game.must_pass() {
+            return writeln!(output, "bestmove pass");
+        }
+
+        let mut engine = self.engine.take().expect("the engine is idle");
+        let stop = Arc::new(AtomicBool::new(false));
+        let (sender, updates) = mpsc::channel();
+        let board = self.game.board();
+        let stone = self.game.current_turn();
+        let thread = {
+            let stop = Arc::clone(&stop);
+            thread::spawn(move || {
+                let report = engine.search_with(&board, stone, limits, &stop, sender);
+                (engine, report)
+            })
+        };
+        self.search = Some(RunningSearch {
+            stop,
+            updates,
+            thread,
+            infinite: args.split_whitespace().any(|token| token == "infinite"),
+            game: self.game.clone(),
+        });
+        Ok(())
+    }
+
+    /// Parses the limits of `go`, such as `depth 8` or `movetime 1000`.
+    ///
+    /// Without any limits, the search is limited to the depth set by the
+    /// `Depth` option. Time limits are replaced by node budgets while the
+    /// `Deterministic` option is set.
+    fn limits(&self, args: &str) -> Option<SearchLimits> {
+        let mut depth = None;
+        let mut nodes = None;
+        let mut movetime = None;
+        let mut infinite = false;
+        let mut times = [None; 2];
+        let mut increments = [None; 2];
+
+        let mut tokens = args.split_whitespace();
+        while let Some(token) = tokens.next() {
+            let mut value = || tokens.next()?.parse::<u64>().ok();
+            match token {
+                "depth" => depth = Some(u8::try_from(value()?).ok()?.clamp(1, MAX_DEPTH)),
+                "nodes" => nodes = Some(value()?),
+                "movetime" => movetime = Some(Duration::from_millis(value()?)),
+                "btime" => times[0] = Some(Duration::from_millis(value()?)),
+                "wtime" => times[1] = Some(Duration::from_millis(value()?)),
+                "binc" => increments[0] = Some(Duration::from_millis(value()?)),
+                "winc" => increments[1] = Some(Duration::from_millis(value()?)),
+                "infinite" => infinite = true,
+                _ => return None,
+            }
+        }
+
+        let player = usize::from(self.game.current_turn() == Stone::White);
+        let mut limits = if let Some(movetime) = movetime {
+            SearchLimits::time(movetime)
+        } else if let Some(remaining) = times[player] {
+            let clock = ClockState {
+                remaining,
+                increment: increments[player].unwrap_or_default(),
+                moves_played: u32::try_from(self.game.history().len()).unwrap_or(u32::MAX),
+                empties: self.game.empty_squares().count_set(),
+            };
+            SearchLimits::budget(TimeManager::new().budget(&clock))
+        } else if infinite || nodes.is_some() {
+            SearchLimits::depth(MAX_DEPTH)
+        } else {
+            let depth = match self.options.value(DEPTH_OPTION) {
+                Some(&OptionValue::Spin(depth)) => u8::try_from(depth).unwrap_or(DEFAULT_DEPTH),
+                _ => DEFAULT_DEPTH,
+            };
+            SearchLimits::depth(depth)
+        };
+        if let Some(depth) = depth {
+            limits.depth = depth;
+        }
+        if let Some(nodes) = nodes {
+            limits = limits.with_nodes(nodes);
+        }
+        if self.options.value(DETERMINISTIC_OPTION) == Some(&OptionValue::Check(true)) {
+            limits = limits.deterministic();
+        }
+        Some(limits)
+    }
+
+    fn set_option<W: Write>(&mut self, args: &str, output: &mut W) -> io::Result<()> {
+        let Some(args) = args.strip_prefix("name") else {
+            return writeln!(output, "info string Invalid option {args}");
+        };
+        let (name, value) = match args.split_once(" value ") {
+            Some((name, value)) => (name.trim(), Some(value.trim())),
+            None => (args.trim(), None),
+        };
+        let Some(engine) = &mut self.engine else {
+            return writeln!(output, "info string Cannot set options while searching");
+        };
+        if let Err(error) = self.options.set(engine, name, value) {
+            writeln!(output, "info string {error}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Formats the report of a completed iteration, such as
+/// `info depth 6 score cp 12 nodes 1524 time 3 pv f5 d6`.
+fn info(report: &SearchReport) -> String {
+    let score = if is_solved(report.score) {
+        format!("disc {}", disc_score(report.score))
+    } else {
+        format!("cp {}", report.score)
+    };
+    let mut line = format!(
+        "info depth {} score {score} nodes {} time {}",
+        report.depth,
+        report.nodes,
+        report.elapsed.as_millis()
+    );
+    if !report.pv.is_empty() {
+        line.push_str(" pv");
+        for pos in &report.pv {
+            // Writing to a string cannot fail
+            let _ = write!(line, " {pos}");
+        }
+    }
+    line
+}
@@ -0,0 +1,214 @@
+use crate::{
+    analysis::{CENTIDISCS, centidiscs},
+    eval::Evaluator,
+    othello::{Board, Game, Move, Position, Stone},
+    search::{SearchLimits, Searcher, solve_endgame},
+};
+use core::cmp::Reverse;
+
+/// A position where exactly one move is clearly best, along with its
+/// solution.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Puzzle {
+    /// The position of the puzzle.
+    pub board: Board,
+    /// The player to move.
+    pub stone: Stone,
+    /// The index of the move in the history of the game that was about to
+    /// be played in the position.
+    pub ply: usize,
+    /// The only move that is clearly best.
+    pub solution: Position,
+    /// The expected continuation, starting with the solution. Passes are
+    /// left out.
+    pub line: Vec<Position>,
+    /// The score of the solution in centidiscs, from the perspective of the
+    /// player to move.
+    pub score: i32,
+    /// How much better the solution is than the second best move, in
+    /// centidiscs.
+    pub margin: i32,
+    /// Whether the scores were proven by solving the position exactly,
+    /// rather than estimated by a search.
+    pub solved: bool,
+}
+
+/// Scans games for positions where exactly one move is clearly best.
+///
+/// Every position is searched for its two best moves within the limits of
+/// the finder, and becomes a puzzle if the best move is better than the
+/// second by at least the margin. Positions with few enough empty squares
+/// are solved exactly instead, which proves both the solution and the
+/// margin. Positions with a single legal move are never puzzles.
+///
+/// # Examples
+/// ```rust
+/// use magpie::eval::DiscDifference;
+/// use magpie::othello::{Board, Position, Stone};
+/// use magpie::puzzles::PuzzleFinder;
+/// use magpie::search::{SearchLimits, Searcher};
+///
+/// let black = 0x01_f8_d5_6d_63_75_f8_01;
+/// let white = 0x7e_06_2a_12_9c_0a_07_3e;
+/// let board = Board::try_from((black, white)).unwrap();
+///
+/// let mut finder = PuzzleFinder::new(Searcher::new(DiscDifference), SearchLimits::depth(4));
+/// let puzzle = finder.find(&board, Stone::Black).unwrap();
+/// // Taking the corner wins by 12 discs more than any other move
+/// assert_eq!(Position::A1, puzzle.solution);
+/// assert_eq!(1200, puzzle.margin);
+/// assert!(puzzle.solved);
+/// ```
+pub struct PuzzleFinder<E: Evaluator> {
+    searcher: Searcher<E>,
+    limits: SearchLimits,
+    margin: i32,
+    solve_empties: u8,
+}
+
+impl<E: Evaluator> PuzzleFinder<E> {
+    /// Returns a finder that searches positions within the specified
+    /// limits.
+    ///
+    /// By default, the best move has to be 4 discs better than the second
+    /// best, and positions with up to 14 empty squares are solved exactly.
+    #[must_use]
+    pub fn new(searcher: Searcher<E>, limits: SearchLimits) -> Self {
+        Self {
+            searcher,
+            limits,
+            margin: 400,
+            solve_empties: 14,
+        }
+    }
+
+    /// Returns a copy of the finder that requires the specified margin in
+    /// centidiscs between the best and the second best move.
+    #[must_use]
+    pub fn with_margin(self, margin: i32) -> Self {
+        Self { margin, ..self }
+    }
+
+    /// Returns a copy of the finder that solves positions with up to the
+    /// specified number of empty squares exactly.
+    #[must_use]
+    pub fn with_solve_empties(self, solve_empties: u8) -> Self {
+        Self {
+            solve_empties,
+            ..self
+        }
+    }
+
+    /// Returns the margin in centidiscs between the best and the second
+    /// best move.
+    #[must_use]
+    pub fn margin(&self) -> i32 {
+        self.margin
+    }
+
+    /// Returns the number of empty squares from which positions are solved
+    /// exactly.
+    #[must_use]
+    pub fn solve_empties(&self) -> u8 {
+        self.solve_empties
+    }
+
+    /// Returns every puzzle found in the history of the game, in the order
+    /// the positions occurred.
+    ///
+    /// Passes and the placements that open a game of Reversi are skipped.
+    pub fn scan(&mut self, game: &Game) -> Vec<Puzzle> {
+        let mut replay = game.clone();
+        while replay.undo().is_some() {}
+
+        let mut puzzles = Vec::new();
+        for (ply, played) in game.history().iter().enumerate() {
+            let board = replay.board();
+            let placing = replay.is_placing();
+            replay.redo();
+            if placing || played.mv() == Move::Pass {
+                continue;
+            }
+            if let Some(puzzle) = self.find(&board, played.stone()) {
+                puzzles.push(Puzzle { ply, ..puzzle });
+            }
+        }
+        puzzles
+    }
+
+    /// Returns the puzzle the position makes for the specified player, if
+    /// exactly one move is clearly best.
+    ///
+    /// The ply of the returned puzzle is 0.
+    pub fn find(&mut self, board: &Board, stone: Stone) -> Option<Puzzle> {
+        let moves = board.moves_for(stone);
+        if moves.count_set() < 2 {
+            return None;
+        }
+        if board.empty_squares().count_set() <= self.solve_empties {
+            return self.solve(board, stone);
+        }
+
+        let report = self.searcher.multipv(board, stone, self.limits, 2);
+        let [best, second] = &report.lines[..] else {
+            return None;
+        };
+        let score = centidiscs(best.score);
+        let margin = score - centidiscs(second.score);
+        (margin >= self.margin).then(|| Puzzle {
+            board: *board,
+            stone,
+            ply: 0,
+            solution: best.mv,
+            line: best.pv.clone(),
+            score,
+            margin,
+            solved: false,
+        })
+    }
+
+    /// Finds the puzzle by solving the outcome of every move exactly.
+    fn solve(&self, board: &Board, stone: Stone) -> Option<Puzzle> {
+        let mut scores: Vec<(i32, Position)> = board
+            .moves_for(stone)
+            .hot_bits()
+            .map(|pos| {
+                let mut child = *board;
+                child.play(stone, pos);
+                (-solve_endgame(&child, stone.flip()).0, pos)
+            })
+            .collect();
+        scores.sort_unstable_by_key(|(score, _)| Reverse(*score));
+        let (best, solution) = scores[0];
+        let margin = (best - scores[1].0) * CENTIDISCS;
+        if margin < self.margin {
+            return None;
+        }
+
+        let mut line = vec![solution];
+        let mut child = *board;
+        child.play(stone, solution);
+        let mut to_move = stone.flip();
+        loop {
+            match solve_endgame(&child, to_move).1 {
+                Some(pos) => {
+                    child.play(to_move, pos);
+                    line.push(pos);
+                }
+                None if child.moves_for(to_move.flip()).is_empty() => break,
+                None => {}
+            }
+            to_move = to_move.flip();
+        }
+        Some(Puzzle {
+            board: *board,
+            stone,
+            ply: 0,
+            solution,
+            line,
+            score: best * CENTIDISCS,
+            margin,
+            solved: true,
+        })
+    }
+}
@@ -0,0 +1,30 @@
+//! Finds tactical puzzles in played games.
+//!
+//! A position makes a good puzzle when exactly one move is clearly better
+//! than every other: the [`PuzzleFinder`] searches every position of a game
+//! and keeps those where the best move beats the second best by at least a
+//! configurable margin. Close to the end of the game, the candidates are
+//! verified by solving them exactly, so that the solution of an endgame
+//! puzzle is never in doubt.
+//!
+//! [`PuzzleFinder`]: crate::puzzles::PuzzleFinder
+//!
+//! # Examples
+//! ```rust
+//! use magpie::eval::Heuristic;
+//! use magpie::othello::Transcript;
+//! use magpie::puzzles::PuzzleFinder;
+//! use magpie::search::{SearchLimits, Searcher};
+//!
+//! let game = "f5d6c3d3c4f4f6f3e6e7".parse::<Transcript>().unwrap().replay().unwrap();
+//! let mut finder = PuzzleFinder::new(Searcher::new(Heuristic::default()), SearchLimits::depth(3));
+//! for puzzle in finder.scan(&game) {
+//!     assert_eq!(puzzle.solution, puzzle.line[0]);
+//!     assert!(puzzle.margin >= finder.margin());
+//! }
+//! ```
+
+/// Searches positions for moves that are clearly best.
+mod finder;
+
+pub use finder::{Puzzle, PuzzleFinder};
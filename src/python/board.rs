@@ -0,0 +1,173 @@
+use crate::othello::{Board, Format, Position, Stone};
+use core::fmt::Display;
+use pyo3::{exceptions::PyValueError, prelude::*};
+
+/// The stone colors, exported to Python as `Stone`.
+#[pyclass(name = "Stone", module = "magpie", eq, eq_int, frozen, hash)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum PyStone {
+    /// The black stone, which moves first.
+    Black = 0,
+    /// The white stone.
+    White = 1,
+}
+
+#[pymethods]
+impl PyStone {
+    /// Returns the stone of the opponent.
+    #[must_use]
+    pub fn flip(&self) -> Self {
+        Stone::from(*self).flip().into()
+    }
+}
+
+impl From<PyStone> for Stone {
+    fn from(stone: PyStone) -> Self {
+        match stone {
+            PyStone::Black => Stone::Black,
+            PyStone::White => Stone::White,
+        }
+    }
+}
+
+impl From<Stone> for PyStone {
+    fn from(stone: Stone) -> Self {
+        match stone {
+            Stone::Black => PyStone::Black,
+            Stone::White => PyStone::White,
+        }
+    }
+}
+
+/// An Othello board, exported to Python as `Board`.
+///
+/// # Examples
+/// ```rust
+/// use magpie::python::{PyBoard, PyStone};
+///
+/// let mut board = PyBoard::new();
+/// board.play(PyStone::Black, "f5").unwrap();
+/// assert_eq!(4, board.count(PyStone::Black));
+/// ```
+#[pyclass(name = "Board", module = "magpie", eq)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct PyBoard(pub(crate) Board);
+
+#[pymethods]
+impl PyBoard {
+    /// Returns a board with the standard opening position configured.
+    #[new]
+    #[must_use]
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        Self(Board::standard())
+    }
+
+    /// Returns a completely empty board.
+    #[staticmethod]
+    #[must_use]
+    pub fn empty() -> Self {
+        Self(Board::empty())
+    }
+
+    /// Returns a board with the specified stones, given as bitboards.
+    ///
+    /// Raises `ValueError` if the stones overlap.
+    #[staticmethod]
+    pub fn from_bits(black: u64, white: u64) -> PyResult<Self> {
+        Board::try_from((black, white))
+            .map(Self)
+            .map_err(to_py_error)
+    }
+
+    /// Returns the stones of the specified player as a bitboard.
+    #[must_use]
+    pub fn bits(&self, stone: PyStone) -> u64 {
+        self.0.bits_for(stone.into()).raw()
+    }
+
+    /// Returns the 64 squares of the board, starting with A1 and ending with
+    /// H8, where 0 is empty, 1 is black and 2 is white.
+    #[must_use]
+    pub fn cells(&self) -> Vec<u8> {
+        (0..64)
+            .map(|index| Position::from_index(index).unwrap())
+            .map(|pos| match self.0.stone_at(pos) {
+                None => 0,
+                Some(Stone::Black) => 1,
+                Some(Stone::White) => 2,
+            })
+            .collect()
+    }
+
+    /// Returns the number of stones of the specified player.
+    #[must_use]
+    pub fn count(&self, stone: PyStone) -> u8 {
+        self.0.bits_for(stone.into()).count_set()
+    }
+
+    /// Returns the legal moves of the specified player as a bitboard.
+    #[must_use]
+    pub fn legal_moves(&self, stone: PyStone) -> u64 {
+        self.0.moves_for(stone.into()).raw()
+    }
+
+    /// Returns the legal moves of the specified player in notation.
+    #[must_use]
+    pub fn moves(&self, stone: PyStone) -> Vec<String> {
+        self.0
+            .moves_for(stone.into())
+            .hot_bits()
+            .map(|pos| pos.to_string())
+            .collect()
+    }
+
+    /// Returns true if and only if the move is legal for the specified
+    /// player.
+    ///
+    /// Raises `ValueError` if the position cannot be parsed.
+    pub fn is_legal_move(&self, stone: PyStone, position: &str) -> PyResult<bool> {
+        let pos = parse_position(position)?;
+        Ok(self.0.is_legal_move(stone.into(), pos))
+    }
+
+    /// Plays the move for the specified player.
+    ///
+    /// Raises `ValueError` if the position cannot be parsed or if the move
+    /// is illegal.
+    pub fn play(&mut self, stone: PyStone, position: &str) -> PyResult<()> {
+        let pos = parse_position(position)?;
+        self.0.try_play(stone.into(), pos).map_err(to_py_error)?;
+        Ok(())
+    }
+
+    /// Returns a copy of the board.
+    #[must_use]
+    pub fn copy(&self) -> Self {
+        *self
+    }
+
+    /// Returns a human-readable drawing of the board.
+    #[must_use]
+    pub fn __str__(&self) -> String {
+        self.0.display().with_format(Format::Compact).to_string()
+    }
+
+    /// Returns an expression that recreates the board.
+    #[must_use]
+    pub fn __repr__(&self) -> String {
+        format!(
+            "Board.from_bits({:#018x}, {:#018x})",
+            self.0.bits_for(Stone::Black).raw(),
+            self.0.bits_for(Stone::White).raw()
+        )
+    }
+}
+
+pub(crate) fn parse_position(position: &str) -> PyResult<Position> {
+    position.parse().map_err(to_py_error)
+}
+
+pub(crate) fn to_py_error(error: impl Display) -> PyErr {
+    PyValueError::new_err(error.to_string())
+}
@@ -0,0 +1,128 @@
+use crate::{
+    othello::{Game, Status, Stone},
+    python::board::{PyBoard, PyStone, parse_position, to_py_error},
+};
+use pyo3::prelude::*;
+
+/// The status of a game, exported to Python as `Status`.
+#[pyclass(name = "Status", module = "magpie", eq, eq_int, frozen, hash)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum PyStatus {
+    /// The game is still in progress.
+    Progressing = 0,
+    /// The game has concluded with black as the winner.
+    BlackWins = 1,
+    /// The game has concluded with white as the winner.
+    WhiteWins = 2,
+    /// The game has concluded in a draw.
+    Draw = 3,
+}
+
+/// An Othello game that enforces the rules, exported to Python as `Game`.
+///
+/// # Examples
+/// ```rust
+/// use magpie::python::{PyGame, PyStatus, PyStone};
+///
+/// let mut game = PyGame::new();
+/// game.play("f5").unwrap();
+/// assert_eq!(PyStone::White, game.current_turn());
+/// assert_eq!(PyStatus::Progressing, game.status());
+/// assert_eq!("f5", game.transcript());
+/// ```
+#[pyclass(name = "Game", module = "magpie", eq)]
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct PyGame(pub(crate) Game);
+
+#[pymethods]
+impl PyGame {
+    /// Returns a game with the standard opening position configured.
+    #[new]
+    #[must_use]
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        Self(Game::new())
+    }
+
+    /// Returns a game starting from the specified board.
+    ///
+    /// Raises `ValueError` if the board is invalid.
+    #[staticmethod]
+    pub fn from_board(board: &PyBoard, next: PyStone) -> PyResult<Self> {
+        Game::from_state(board.0, next.into(), false)
+            .map(Self)
+            .map_err(to_py_error)
+    }
+
+    /// Returns a copy of the board.
+    #[must_use]
+    pub fn board(&self) -> PyBoard {
+        PyBoard(self.0.board())
+    }
+
+    /// Returns the stone of the player to move.
+    #[must_use]
+    pub fn current_turn(&self) -> PyStone {
+        self.0.current_turn().into()
+    }
+
+    /// Returns the status of the game.
+    #[must_use]
+    pub fn status(&self) -> PyStatus {
+        match self.0.status() {
+            Status::Progressing => PyStatus::Progressing,
+            Status::Win(Stone::Black) => PyStatus::BlackWins,
+            Status::Win(Stone::White) => PyStatus::WhiteWins,
+            Status::Draw => PyStatus::Draw,
+        }
+    }
+
+    /// Returns the legal moves of the player to move in notation.
+    #[must_use]
+    pub fn moves(&self) -> Vec<String> {
+        self.0
+            .moves()
+            .hot_bits()
+            .map(|pos| pos.to_string())
+            .collect()
+    }
+
+    /// Returns the legal moves of the player to move as a bitboard.
+    #[must_use]
+    pub fn legal_moves(&self) -> u64 {
+        self.0.moves().raw()
+    }
+
+    /// Plays the move for the player to move.
+    ///
+    /// Raises `ValueError` if the position cannot be parsed or if the move
+    /// is illegal.
+    pub fn play(&mut self, position: &str) -> PyResult<()> {
+        let pos = parse_position(position)?;
+        self.0.play(pos).map_err(to_py_error)
+    }
+
+    /// Passes the turn if the player to move has no legal moves.
+    ///
+    /// Returns whether or not the turn was passed.
+    pub fn pass_if_forced(&mut self) -> bool {
+        self.0.pass_if_forced()
+    }
+
+    /// Takes back the last move. Returns false if there was nothing to undo.
+    pub fn undo(&mut self) -> bool {
+        self.0.undo().is_some()
+    }
+
+    /// Replays the last move taken back. Returns false if there was nothing
+    /// to redo.
+    pub fn redo(&mut self) -> bool {
+        self.0.redo().is_some()
+    }
+
+    /// Returns the moves played so far as a transcript, such as `f5d6c3`.
+    #[must_use]
+    pub fn transcript(&self) -> String {
+        self.0.transcript().to_string()
+    }
+}
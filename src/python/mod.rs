@@ -0,0 +1,65 @@
+//! Python bindings through `pyo3`.
+//!
+//! The bindings wrap the [`Board`] and [`Game`] types along with the
+//! [`search`] module in classes that are exported to Python under the same
+//! names, in an extension module called `magpie`. The types crossing the
+//! boundary are chosen to be convenient to use from Python:
+//!
+//! - Positions are passed as strings in human-readable notation, such as
+//!   `"d3"`.
+//! - Bitboards are passed as integers, where the most significant bit
+//!   represents A1 and the least significant bit represents H8.
+//! - The contents of a board can be retrieved as a list of 64 squares,
+//!   starting with A1.
+//!
+//! Errors are raised as `ValueError`. Searches release the global
+//! interpreter lock, so that other Python threads may run in the meantime.
+//!
+//! The extension module is built with [maturin] from the root of the
+//! repository, which enables this feature through `pyproject.toml`:
+//!
+//! ```sh
+//! maturin develop --release
+//! ```
+//!
+//! ```python
+//! from magpie import Game, Searcher
+//!
+//! game = Game()
+//! game.play("f5")
+//! print(game.moves())  # ['f4', 'd6', 'f6']
+//!
+//! report = Searcher().search(game.board(), game.current_turn(), depth=6)
+//! print(report.best_move, report.score)
+//! ```
+//!
+//! [`Board`]: crate::othello::Board
+//! [`Game`]: crate::othello::Game
+//! [`search`]: crate::search
+//! [maturin]: https://www.maturin.rs
+
+use pyo3::prelude::*;
+
+/// Exports boards and stones.
+mod board;
+/// Exports games.
+mod game;
+/// Exports searchers and the endgame solver.
+mod search;
+
+pub use board::{PyBoard, PyStone};
+pub use game::{PyGame, PyStatus};
+pub use search::{PySearchReport, PySearcher, solve_endgame};
+
+/// Initializes the `magpie` extension module.
+#[pymodule]
+pub fn magpie(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_class::<PyStone>()?;
+    module.add_class::<PyBoard>()?;
+    module.add_class::<PyStatus>()?;
+    module.add_class::<PyGame>()?;
+    module.add_class::<PySearcher>()?;
+    module.add_class::<PySearchReport>()?;
+    module.add_function(wrap_pyfunction!(solve_endgame, module)?)?;
+    Ok(())
+}
@@ -0,0 +1,138 @@
+use crate::{
+    eval::Heuristic,
+    othello::Position,
+    python::board::{PyBoard, PyStone, to_py_error},
+    search::{self, SearchLimits, SearchReport, Searcher},
+};
+use core::time::Duration;
+use pyo3::{exceptions::PyValueError, prelude::*};
+use std::sync::{Mutex, PoisonError};
+
+/// The outcome of a search, exported to Python as `SearchReport`.
+#[pyclass(name = "SearchReport", module = "magpie", frozen, get_all)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PySearchReport {
+    /// The best move found in notation, or `None` if the player has to
+    /// pass.
+    pub best_move: Option<String>,
+    /// The score of the best move from the perspective of the player to
+    /// move.
+    pub score: i32,
+    /// The depth of the last completed iteration.
+    pub depth: u8,
+    /// The number of nodes visited.
+    pub nodes: u64,
+    /// The principal variation in notation, starting with the best move.
+    pub pv: Vec<String>,
+    /// The time spent searching, in seconds.
+    pub elapsed: f64,
+}
+
+impl From<SearchReport> for PySearchReport {
+    fn from(report: SearchReport) -> Self {
+        Self {
+            best_move: report.best_move.map(|pos| pos.to_string()),
+            score: report.score,
+            depth: report.depth,
+            nodes: report.nodes,
+            pv: report.pv.iter().map(Position::to_string).collect(),
+            elapsed: report.elapsed.as_secs_f64(),
+        }
+    }
+}
+
+/// An alpha-beta searcher using the built-in [`Heuristic`] evaluator,
+/// exported to Python as `Searcher`.
+///
+/// The transposition table is kept between searches, so that searching
+/// the positions of a game in order reuses earlier work. Searches on the
+/// same searcher from several threads run one at a time.
+///
+/// [`Heuristic`]: crate::eval::Heuristic
+///
+/// # Examples
+/// ```rust
+/// use magpie::python::{PyBoard, PySearcher, PyStone};
+/// use pyo3::Python;
+///
+/// Python::initialize();
+/// let searcher = PySearcher::new();
+/// let report = Python::attach(|py| {
+///     searcher.search(py, &PyBoard::new(), PyStone::Black, Some(4), None, None)
+/// });
+/// let report = report.unwrap();
+/// assert_eq!(4, report.depth);
+/// assert!(report.best_move.is_some());
+/// ```
+#[pyclass(name = "Searcher", module = "magpie", frozen)]
+pub struct PySearcher(Mutex<Searcher<Heuristic>>);
+
+#[pymethods]
+impl PySearcher {
+    /// Returns a searcher with an empty transposition table.
+    #[new]
+    #[must_use]
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        Self(Mutex::new(Searcher::new(Heuristic::default())))
+    }
+
+    /// Searches the board for the best move of the specified player.
+    ///
+    /// The search stops at the first limit reached out of the depth, the
+    /// time in seconds and the number of nodes. The global interpreter lock
+    /// is released while searching.
+    ///
+    /// Raises `ValueError` if no limit is given or if the time is invalid.
+    #[pyo3(signature = (board, stone, depth = None, time = None, nodes = None))]
+    pub fn search(
+        &self,
+        py: Python<'_>,
+        board: &PyBoard,
+        stone: PyStone,
+        depth: Option<u8>,
+        time: Option<f64>,
+        nodes: Option<u64>,
+    ) -> PyResult<PySearchReport> {
+        let limits = limits(depth, time, nodes)?;
+        let board = board.0;
+        let report = py.detach(|| {
+            let mut searcher = self.0.lock().unwrap_or_else(PoisonError::into_inner);
+            searcher.search(&board, stone.into(), limits)
+        });
+        Ok(report.into())
+    }
+}
+
+/// Returns the limits of a search from the arguments given from Python.
+fn limits(depth: Option<u8>, time: Option<f64>, nodes: Option<u64>) -> PyResult<SearchLimits> {
+    let time = time
+        .map(Duration::try_from_secs_f64)
+        .transpose()
+        .map_err(to_py_error)?;
+    let limits = match (depth, time, nodes) {
+        (Some(depth), _, _) => SearchLimits::depth(depth),
+        (None, Some(time), _) => SearchLimits::time(time),
+        (None, None, Some(nodes)) => SearchLimits::nodes(nodes),
+        (None, None, None) => {
+            return Err(PyValueError::new_err(
+                "a depth, time or node limit is required",
+            ));
+        }
+    };
+    let limits = time.map_or(limits, |time| limits.with_time(time));
+    Ok(nodes.map_or(limits, |nodes| limits.with_nodes(nodes)))
+}
+
+/// Solves the board perfectly for the specified player, returning the
+/// final disc difference and the best move in notation.
+///
+/// The global interpreter lock is released while solving. Solving takes
+/// too long for boards with more than about 20 empty squares.
+#[pyfunction]
+#[must_use]
+pub fn solve_endgame(py: Python<'_>, board: &PyBoard, stone: PyStone) -> (i32, Option<String>) {
+    let board = board.0;
+    let (score, best_move) = py.detach(|| search::solve_endgame(&board, stone.into()));
+    (score, best_move.map(|pos| pos.to_string()))
+}
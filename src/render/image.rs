@@ -0,0 +1,273 @@
+use crate::{
+    othello::{Bitboard, Board, Position, Stone},
+    render::png,
+};
+use alloc::{string::String, vec::Vec};
+use core::fmt::Write;
+
+/// An RGB color.
+type Color = [u8; 3];
+
+const BOARD: Color = [0x2E, 0x8B, 0x57];
+const LINE: Color = [0x00, 0x00, 0x00];
+const BLACK: Color = [0x11, 0x11, 0x11];
+const WHITE: Color = [0xF4, 0xF4, 0xF4];
+const HINT: Color = [0x1F, 0x5F, 0x3B];
+const LAST_MOVE: Color = [0xE0, 0x30, 0x30];
+
+/// An image of a board, optionally highlighting the last move and the legal
+/// moves of a player.
+///
+/// See the [module documentation] for an example.
+///
+/// [module documentation]: crate::render
+#[derive(Clone, Copy, Debug)]
+pub struct BoardImage<'a> {
+    board: &'a Board,
+    last_move: Option<Position>,
+    stone: Option<Stone>,
+    square_size: u32,
+}
+
+/// A filled circle, optionally with a black outline.
+struct Circle {
+    x: f32,
+    y: f32,
+    radius: f32,
+    fill: Color,
+    outline: bool,
+}
+
+impl<'a> BoardImage<'a> {
+    /// Returns an image of the board with squares of 48 by 48 pixels.
+    #[must_use]
+    pub fn new(board: &'a Board) -> Self {
+        Self {
+            board,
+            last_move: None,
+            stone: None,
+            square_size: 48,
+        }
+    }
+
+    /// Marks the specified square as the last move made.
+    #[must_use]
+    pub fn with_last_move(self, pos: Position) -> Self {
+        Self {
+            last_move: Some(pos),
+            ..self
+        }
+    }
+
+    /// Marks the legal moves of the specified player with dots.
+    #[must_use]
+    pub fn with_legal_moves(self, stone: Stone) -> Self {
+        Self {
+            stone: Some(stone),
+            ..self
+        }
+    }
+
+    /// Sets the width and height of every square in pixels.
+    ///
+    /// # Panics
+    /// Panics if the size is not between 8 and 1024 pixels.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::Board;
+    /// use magpie::render::BoardImage;
+    ///
+    /// let board = Board::standard();
+    /// assert_eq!(160, BoardImage::new(&board).with_square_size(20).size());
+    /// ```
+    #[must_use]
+    pub fn with_square_size(self, square_size: u32) -> Self {
+        assert!(
+            (8..=1024).contains(&square_size),
+            "square size must be between 8 and 1024 pixels"
+        );
+        Self {
+            square_size,
+            ..self
+        }
+    }
+
+    /// Returns the width and height of the image in pixels.
+    #[must_use]
+    pub fn size(&self) -> u32 {
+        self.square_size * 8
+    }
+
+    /// Draws the image as SVG.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Board, Stone};
+    /// use magpie::render::BoardImage;
+    ///
+    /// let board = Board::standard();
+    /// let svg = BoardImage::new(&board).with_legal_moves(Stone::Black).to_svg();
+    /// // Four stones and four legal moves
+    /// assert_eq!(8, svg.matches("<circle").count());
+    /// ```
+    #[must_use]
+    pub fn to_svg(&self) -> String {
+        let size = self.size();
+        let mut svg = String::new();
+        // Writing to a string cannot fail
+        let _ = writeln!(
+            svg,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{size}" height="{size}" viewBox="0 0 {size} {size}">"#
+        );
+        let _ = writeln!(
+            svg,
+            r#"<rect width="{size}" height="{size}" fill="{}"/>"#,
+            hex(BOARD)
+        );
+        for offset in self.lines() {
+            let _ = writeln!(
+                svg,
+                r#"<rect x="{offset}" width="1" height="{size}" fill="{line}"/><rect y="{offset}" width="{size}" height="1" fill="{line}"/>"#,
+                line = hex(LINE)
+            );
+        }
+        for circle in self.circles() {
+            let _ = write!(
+                svg,
+                r#"<circle cx="{}" cy="{}" r="{}" fill="{}""#,
+                circle.x,
+                circle.y,
+                circle.radius,
+                hex(circle.fill)
+            );
+            if circle.outline {
+                let _ = write!(svg, r#" stroke="{}""#, hex(LINE));
+            }
+            let _ = writeln!(svg, "/>");
+        }
+        svg.push_str("</svg>\n");
+        svg
+    }
+
+    /// Draws the image as PNG.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::Board;
+    /// use magpie::render::BoardImage;
+    ///
+    /// let board = Board::standard();
+    /// let png = BoardImage::new(&board).to_png();
+    /// assert_eq!(b"\x89PNG\r\n\x1a\n", &png[..8]);
+    /// ```
+    #[must_use]
+    pub fn to_png(&self) -> Vec<u8> {
+        let size = self.size();
+        let lines = self.lines();
+        let circles = self.circles();
+
+        let mut pixels = Vec::with_capacity((size * size * 3) as usize);
+        for y in 0..size {
+            for x in 0..size {
+                let mut color = if lines.contains(&x) || lines.contains(&y) {
+                    LINE
+                } else {
+                    BOARD
+                };
+                // Sample the center of the pixel, with antialiased edges
+                #[allow(clippy::cast_precision_loss)]
+                let (px, py) = (x as f32 + 0.5, y as f32 + 0.5);
+                for circle in &circles {
+                    let distance = ((px - circle.x).powi(2) + (py - circle.y).powi(2)).sqrt();
+                    let coverage = |radius: f32| (radius - distance + 0.5).clamp(0.0, 1.0);
+                    if circle.outline {
+                        color = blend(color, LINE, coverage(circle.radius + 0.5));
+                        color = blend(color, circle.fill, coverage(circle.radius - 0.5));
+                    } else {
+                        color = blend(color, circle.fill, coverage(circle.radius));
+                    }
+                }
+                pixels.extend_from_slice(&color);
+            }
+        }
+        png::encode(size, size, &pixels)
+    }
+
+    /// Returns the offsets of the grid lines, which are the same
+    /// horizontally and vertically.
+    fn lines(&self) -> [u32; 9] {
+        let mut lines = [0; 9];
+        for (index, line) in lines.iter_mut().enumerate() {
+            *line = (self.square_size * index as u32).min(self.size() - 1);
+        }
+        lines
+    }
+
+    /// Returns the circles to draw, in order.
+    #[allow(clippy::cast_precision_loss)]
+    fn circles(&self) -> Vec<Circle> {
+        let square = self.square_size as f32;
+        let center = |pos: Position| {
+            let index = pos.raw().leading_zeros();
+            let (rank, file) = (index / 8, index % 8);
+            ((file as f32 + 0.5) * square, (rank as f32 + 0.5) * square)
+        };
+
+        let mut circles = Vec::new();
+        for (stones, fill) in [(Stone::Black, BLACK), (Stone::White, WHITE)] {
+            for pos in self.board.bits_for(stones).hot_bits() {
+                let (x, y) = center(pos);
+                circles.push(Circle {
+                    x,
+                    y,
+                    radius: square * 0.4,
+                    fill,
+                    outline: true,
+                });
+            }
+        }
+        let moves = self
+            .stone
+            .map_or(Bitboard::from(0), |stone| self.board.moves_for(stone));
+        for pos in moves.hot_bits() {
+            let (x, y) = center(pos);
+            circles.push(Circle {
+                x,
+                y,
+                radius: square * 0.1,
+                fill: HINT,
+                outline: false,
+            });
+        }
+        if let Some(pos) = self.last_move {
+            let (x, y) = center(pos);
+            circles.push(Circle {
+                x,
+                y,
+                radius: square * 0.08,
+                fill: LAST_MOVE,
+                outline: false,
+            });
+        }
+        circles
+    }
+}
+
+/// Formats a color in hexadecimal notation, such as `#2e8b57`.
+fn hex([r, g, b]: Color) -> String {
+    let mut text = String::new();
+    let _ = write!(text, "#{r:02x}{g:02x}{b:02x}");
+    text
+}
+
+/// Mixes two colors, where `amount` is the share of the second color.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn blend(under: Color, over: Color, amount: f32) -> Color {
+    let mut color = under;
+    for (channel, over) in color.iter_mut().zip(over) {
+        let mixed = f32::from(*channel) * (1.0 - amount) + f32::from(over) * amount;
+        *channel = mixed.round() as u8;
+    }
+    color
+}
@@ -0,0 +1,33 @@
+//! Draws boards as images.
+//!
+//! Boards are drawn the same way as they are printed by [`BoardDisplay`],
+//! with A1 in the top left corner. An image can be exported as SVG, which
+//! scales to any size, or as PNG. PNG images are drawn and encoded by magpie
+//! itself, so no image libraries are required.
+//!
+//! [`BoardDisplay`]: crate::othello::BoardDisplay
+//!
+//! # Examples
+//! ```rust
+//! use magpie::othello::{Board, Position, Stone};
+//! use magpie::render::BoardImage;
+//!
+//! let mut board = Board::standard();
+//! let f5 = Position::try_from("f5").unwrap();
+//! board.play(Stone::Black, f5);
+//!
+//! let image = BoardImage::new(&board)
+//!     .with_last_move(f5)
+//!     .with_legal_moves(Stone::White);
+//! let svg = image.to_svg();
+//! assert!(svg.starts_with("<svg"));
+//! let png = image.to_png();
+//! assert!(png.starts_with(b"\x89PNG"));
+//! ```
+
+/// Describes and draws images of boards.
+mod image;
+/// A minimal PNG encoder.
+mod png;
+
+pub use image::BoardImage;
@@ -0,0 +1,1095 @@
+use crate::{
+    eval::Evaluator,
+    othello::{Board, Position, Scoring, Stone},
+    search::{
+        Bound, Entry, Replacement, TimeBudget, TranspositionTable,
+        movelist::MoveList,
+        ordering::{MoveOrderer, NoOrdering},
+        probcut::ProbCut,
+    },
+};
+use std::{
+    ops::{Add, AddAssign},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        mpsc,
+    },
+    time::{Duration, Instant},
+};
+
+/// The score of a won game, before the final disc differential is added.
+///
+/// Concluded games are scored as `WIN_SCORE + difference` if the player to
+/// move won, `-WIN_SCORE + difference` if they lost and `0` for draws. This
+/// guarantees that proven results are always preferred over evaluations.
+pub const WIN_SCORE: i32 = 1_000_000;
+
+/// The maximum depth the searcher will ever search to.
+pub(crate) const MAX_DEPTH: u8 = 64;
+
+/// The initial distance of the aspiration window from the score of the
+/// previous iteration, doubled every time the search falls outside of it.
+const ASPIRATION_WINDOW: i32 = 16;
+
+/// The rate at which [`SearchLimits::deterministic`] converts time limits
+/// into node budgets, roughly that of a single thread on a modern CPU.
+const NODES_PER_SECOND: u64 = 1_000_000;
+
+/// Restricts how long a search may run.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct SearchLimits {
+    /// The maximum depth to search to.
+    pub depth: u8,
+    /// The maximum time to spend searching, if any.
+    pub time: Option<Duration>,
+    /// The soft and panic limits, if the search is managed by a
+    /// [`TimeManager`].
+    ///
+    /// [`TimeManager`]: crate::search::TimeManager
+    pub budget: Option<TimeBudget>,
+    /// The maximum number of nodes to visit, if any.
+    pub nodes: Option<u64>,
+}
+
+impl SearchLimits {
+    /// Returns limits that search to the specified depth.
+    #[must_use]
+    pub fn depth(depth: u8) -> Self {
+        Self {
+            depth,
+            time: None,
+            budget: None,
+            nodes: None,
+        }
+    }
+
+    /// Returns limits that search as deep as possible within the specified
+    /// time.
+    ///
+    /// The first iteration is always completed, so very small time budgets
+    /// may be exceeded.
+    #[must_use]
+    pub fn time(time: Duration) -> Self {
+        Self {
+            depth: MAX_DEPTH,
+            time: Some(time),
+            budget: None,
+            nodes: None,
+        }
+    }
+
+    /// Returns limits that search as deep as possible within the specified
+    /// budget.
+    ///
+    /// The hard limit of the budget aborts the search, while no new
+    /// iteration is started once the soft limit has passed. The soft limit
+    /// is extended to the panic limit when the score drops between two
+    /// iterations.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::search::{ClockState, SearchLimits, TimeManager};
+    /// use std::time::Duration;
+    ///
+    /// let clock = ClockState {
+    ///     remaining: Duration::from_secs(10),
+    ///     increment: Duration::ZERO,
+    ///     moves_played: 0,
+    ///     empties: 60,
+    /// };
+    /// let budget = TimeManager::new().budget(&clock);
+    /// let limits = SearchLimits::budget(budget);
+    /// assert_eq!(Some(budget.hard), limits.time);
+    /// ```
+    #[must_use]
+    pub fn budget(budget: TimeBudget) -> Self {
+        Self {
+            depth: MAX_DEPTH,
+            time: Some(budget.hard),
+            budget: Some(budget),
+            nodes: None,
+        }
+    }
+
+    /// Returns limits that search as deep as possible without visiting
+    /// more than the specified number of nodes.
+    ///
+    /// As with time limits, the first iteration is always completed.
+    /// Unlike them, a node budget stops the search at the same point every
+    /// time, regardless of the speed of the machine.
+    #[must_use]
+    pub fn nodes(nodes: u64) -> Self {
+        Self {
+            depth: MAX_DEPTH,
+            time: None,
+            budget: None,
+            nodes: Some(nodes),
+        }
+    }
+
+    /// Returns a copy of the limits with the specified time budget.
+    #[must_use]
+    pub fn with_time(self, time: Duration) -> Self {
+        Self {
+            time: Some(time),
+            ..self
+        }
+    }
+
+    /// Returns a copy of the limits with the specified node budget.
+    #[must_use]
+    pub fn with_nodes(self, nodes: u64) -> Self {
+        Self {
+            nodes: Some(nodes),
+            ..self
+        }
+    }
+
+    /// Returns a copy of the limits that does not depend on the clock, so
+    /// that a search within them always finds the same result.
+    ///
+    /// Time limits are replaced by a node budget that a single thread would
+    /// use up in about the same time, unless the limits already have a
+    /// smaller node budget.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::search::SearchLimits;
+    /// use std::time::Duration;
+    ///
+    /// let limits = SearchLimits::time(Duration::from_millis(50)).deterministic();
+    /// assert_eq!(None, limits.time);
+    /// assert_eq!(Some(50_000), limits.nodes);
+    ///
+    /// let limits = SearchLimits::depth(4).deterministic();
+    /// assert_eq!(SearchLimits::depth(4), limits);
+    /// ```
+    #[must_use]
+    pub fn deterministic(self) -> Self {
+        let budget = self.time.map(|time| {
+            let nodes = time.as_micros() * u128::from(NODES_PER_SECOND) / 1_000_000;
+            u64::try_from(nodes).unwrap_or(u64::MAX)
+        });
+        let nodes = match (self.nodes, budget) {
+            (Some(nodes), Some(budget)) => Some(nodes.min(budget)),
+            (nodes, budget) => nodes.or(budget),
+        };
+        Self {
+            depth: self.depth,
+            time: None,
+            budget: None,
+            nodes,
+        }
+    }
+}
+
+/// The result of a search.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct SearchReport {
+    /// The best move found, or `None` if the player has to pass.
+    pub best_move: Option<Position>,
+    /// The score of the best move from the perspective of the player to move.
+    pub score: i32,
+    /// The depth of the last completed iteration.
+    pub depth: u8,
+    /// The number of nodes visited.
+    pub nodes: u64,
+    /// The principal variation, starting with the best move.
+    pub pv: Vec<Position>,
+    /// The time spent searching.
+    pub elapsed: Duration,
+    /// Counters describing how the search went.
+    pub stats: SearchStats,
+}
+
+/// Counters collected during a search, which show how effective the
+/// transposition table and move ordering were.
+///
+/// The counters cover the whole search, across all iterations and, for
+/// parallel searches, all threads.
+///
+/// # Examples
+/// ```rust
+/// use magpie::eval::DiscDifference;
+/// use magpie::othello::{Board, Stone};
+/// use magpie::search::{SearchLimits, Searcher};
+///
+/// let mut searcher = Searcher::new(DiscDifference);
+/// let report = searcher.search(&Board::standard(), Stone::Black, SearchLimits::depth(6));
+/// let stats = report.stats;
+/// assert!(stats.leaf_nodes > 0 && stats.leaf_nodes < report.nodes);
+/// assert!(stats.tt_hits <= stats.tt_probes);
+/// assert!(stats.first_move_cutoffs <= stats.beta_cutoffs);
+/// assert!((0.0..=1.0).contains(&stats.tt_hit_rate()));
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+pub struct SearchStats {
+    /// The number of positions scored by the evaluator, or as concluded
+    /// games.
+    pub leaf_nodes: u64,
+    /// The number of times the transposition table was looked up.
+    pub tt_probes: u64,
+    /// The number of lookups that found an entry for the position.
+    pub tt_hits: u64,
+    /// The number of lookups whose entry was deep enough to end the search
+    /// of the position.
+    pub tt_cutoffs: u64,
+    /// The number of positions whose search ended early because a move
+    /// refuted the previous move.
+    pub beta_cutoffs: u64,
+    /// The number of beta cutoffs caused by the first move searched.
+    pub first_move_cutoffs: u64,
+    /// The number of positions cut by ProbCut without a deep search.
+    pub probcuts: u64,
+}
+
+impl SearchStats {
+    /// Returns the share of transposition table lookups that found an
+    /// entry, or 0 if there were none.
+    #[must_use]
+    pub fn tt_hit_rate(&self) -> f64 {
+        ratio(self.tt_hits, self.tt_probes)
+    }
+
+    /// Returns the share of beta cutoffs caused by the first move searched,
+    /// or 0 if there were none. The better the move ordering, the closer
+    /// this is to 1.
+    #[must_use]
+    pub fn first_move_cutoff_rate(&self) -> f64 {
+        ratio(self.first_move_cutoffs, self.beta_cutoffs)
+    }
+}
+
+impl Add for SearchStats {
+    type Output = Self;
+
+    fn add(mut self, other: Self) -> Self {
+        self += other;
+        self
+    }
+}
+
+impl AddAssign for SearchStats {
+    fn add_assign(&mut self, other: Self) {
+        self.leaf_nodes += other.leaf_nodes;
+        self.tt_probes += other.tt_probes;
+        self.tt_hits += other.tt_hits;
+        self.tt_cutoffs += other.tt_cutoffs;
+        self.beta_cutoffs += other.beta_cutoffs;
+        self.first_move_cutoffs += other.first_move_cutoffs;
+        self.probcuts += other.probcuts;
+    }
+}
+
+/// Emits an event describing the iteration that just completed, from the
+/// node count, the statistics and the time before it started.
+#[cfg(feature = "tracing")]
+fn trace_iteration(
+    depth: u8,
+    score: i32,
+    (nodes_before, stats_before, started): (u64, SearchStats, Instant),
+    (nodes, stats): (u64, SearchStats),
+) {
+    let tt_probes = stats.tt_probes - stats_before.tt_probes;
+    let tt_hits = stats.tt_hits - stats_before.tt_hits;
+    let beta_cutoffs = stats.beta_cutoffs - stats_before.beta_cutoffs;
+    let first_move_cutoffs = stats.first_move_cutoffs - stats_before.first_move_cutoffs;
+    tracing::debug!(
+        depth,
+        score,
+        nodes = nodes - nodes_before,
+        tt_hit_rate = ratio(tt_hits, tt_probes),
+        tt_cutoffs = stats.tt_cutoffs - stats_before.tt_cutoffs,
+        beta_cutoffs,
+        first_move_cutoff_rate = ratio(first_move_cutoffs, beta_cutoffs),
+        probcuts = stats.probcuts - stats_before.probcuts,
+        elapsed_ms = started.elapsed().as_secs_f64() * 1000.0,
+        "iteration completed"
+    );
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn ratio(count: u64, total: u64) -> f64 {
+    if total == 0 {
+        0.0
+    } else {
+        count as f64 / total as f64
+    }
+}
+
+/// One of the candidate moves found by [`Searcher::multipv`].
+///
+/// [`Searcher::multipv`]: crate::search::Searcher::multipv
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct PvLine {
+    /// The candidate move.
+    pub mv: Position,
+    /// The score of the move from the perspective of the player to move.
+    pub score: i32,
+    /// The principal variation, starting with the candidate move.
+    pub pv: Vec<Position>,
+}
+
+/// The result of a search for several candidate moves.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct MultiPvReport {
+    /// The candidate moves, best first. Empty if the player has to pass.
+    pub lines: Vec<PvLine>,
+    /// The depth of the last completed iteration.
+    pub depth: u8,
+    /// The number of nodes visited.
+    pub nodes: u64,
+    /// The time spent searching.
+    pub elapsed: Duration,
+    /// Counters describing how the search went.
+    pub stats: SearchStats,
+}
+
+/// A negamax searcher with alpha-beta pruning and iterative deepening.
+///
+/// The searcher is parameterized over an [`Evaluator`], which scores the
+/// positions at the leaves of the search. Results are cached in a
+/// [`TranspositionTable`] that is kept between searches, and the order in
+/// which moves are searched can be customized with a [`MoveOrderer`].
+///
+/// Every move after the first is searched with a null window first, which
+/// only proves that it is no better than the best move so far, and only
+/// searched again with the full window if it turns out to be better
+/// (principal variation search). From the second iteration on, the root is
+/// searched within a narrow window around the score of the previous
+/// iteration, which is widened whenever the score falls outside of it.
+///
+/// [`Evaluator`]: crate::eval::Evaluator
+/// [`MoveOrderer`]: crate::search::MoveOrderer
+/// [`TranspositionTable`]: crate::search::TranspositionTable
+///
+/// # Examples
+/// ```rust
+/// use magpie::eval::DiscDifference;
+/// use magpie::othello::{Board, Stone};
+/// use magpie::search::{SearchLimits, Searcher};
+///
+/// let mut searcher = Searcher::new(DiscDifference);
+/// let board = Board::standard();
+/// let report = searcher.search(&board, Stone::Black, SearchLimits::depth(4));
+/// assert_eq!(4, report.depth);
+/// assert!(board.is_legal_move(Stone::Black, report.best_move.unwrap()));
+/// ```
+pub struct Searcher<E: Evaluator> {
+    evaluator: E,
+    tt: TranspositionTable,
+    orderer: Box<dyn MoveOrderer + Send>,
+    probcut: ProbCut,
+}
+
+impl<E: Evaluator> Searcher<E> {
+    /// Returns a searcher using the specified evaluator.
+    ///
+    /// By default the searcher uses a depth-preferred transposition table
+    /// with 2<sup>16</sup> entries, does not reorder moves and does not
+    /// prune forward.
+    #[must_use]
+    pub fn new(evaluator: E) -> Self {
+        Self {
+            evaluator,
+            tt: TranspositionTable::new(1 << 16, Replacement::DepthPreferred),
+            orderer: Box::new(NoOrdering),
+            probcut: ProbCut::new(),
+        }
+    }
+
+    /// Replaces the transposition table used by the searcher.
+    #[must_use]
+    pub fn with_transposition_table(self, tt: TranspositionTable) -> Self {
+        Self { tt, ..self }
+    }
+
+    /// Replaces the move orderer used by the searcher.
+    #[must_use]
+    pub fn with_move_orderer(self, orderer: impl MoveOrderer + Send + 'static) -> Self {
+        Self {
+            orderer: Box::new(orderer),
+            ..self
+        }
+    }
+
+    /// Prunes positions forward with the specified ProbCut.
+    #[must_use]
+    pub fn with_probcut(self, probcut: ProbCut) -> Self {
+        Self { probcut, ..self }
+    }
+
+    /// Returns the evaluator used by the searcher.
+    #[must_use]
+    pub fn evaluator(&self) -> &E {
+        &self.evaluator
+    }
+
+    /// Returns the transposition table used by the searcher.
+    #[must_use]
+    pub fn transposition_table(&self) -> &TranspositionTable {
+        &self.tt
+    }
+
+    /// Searches the position for the best move of the specified player.
+    ///
+    /// The position is searched with increasing depth until either the
+    /// depth limit is reached, the time runs out or the result of the game
+    /// is known. The result of the last completed iteration is returned.
+    pub fn search(&mut self, board: &Board, stone: Stone, limits: SearchLimits) -> SearchReport {
+        let start = Instant::now();
+        self.tt.new_search();
+        self.orderer.new_search();
+        let mut worker = Worker::new(&self.evaluator, &mut self.tt, self.orderer.as_mut(), None)
+            .with_probcut(&self.probcut);
+        worker.iterate(board, stone, limits, start, 0)
+    }
+
+    /// Searches like [`search`], but aborts once the stop flag is raised and
+    /// sends the report of every completed iteration to the channel.
+    ///
+    /// [`search`]: crate::search::Searcher::search
+    pub(crate) fn search_with(
+        &mut self,
+        board: &Board,
+        stone: Stone,
+        limits: SearchLimits,
+        stop: &AtomicBool,
+        updates: mpsc::Sender<SearchReport>,
+    ) -> SearchReport {
+        let start = Instant::now();
+        self.tt.new_search();
+        self.orderer.new_search();
+        Worker::new(
+            &self.evaluator,
+            &mut self.tt,
+            self.orderer.as_mut(),
+            Some(stop),
+        )
+        .with_probcut(&self.probcut)
+        .with_updates(updates)
+        .iterate(board, stone, limits, start, 0)
+    }
+
+    /// Searches the position for the `n` best moves of the specified
+    /// player, each with its own score and principal variation.
+    ///
+    /// The search stops under the same conditions as [`search`]. Since
+    /// every candidate move has to be searched with an open window, the
+    /// search is slower than one for the best move only.
+    ///
+    /// [`search`]: crate::search::Searcher::search
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::eval::Heuristic;
+    /// use magpie::othello::{Board, Stone};
+    /// use magpie::search::{SearchLimits, Searcher};
+    ///
+    /// let mut searcher = Searcher::new(Heuristic::default());
+    /// let board = Board::standard();
+    /// let report = searcher.multipv(&board, Stone::Black, SearchLimits::depth(4), 3);
+    /// // All four opening moves are equivalent, so at most three are shown
+    /// assert_eq!(3, report.lines.len());
+    /// assert!(report.lines.windows(2).all(|pair| pair[0].score >= pair[1].score));
+    /// assert!(report.lines.iter().all(|line| line.pv[0] == line.mv));
+    /// ```
+    pub fn multipv(
+        &mut self,
+        board: &Board,
+        stone: Stone,
+        limits: SearchLimits,
+        n: usize,
+    ) -> MultiPvReport {
+        let start = Instant::now();
+        self.tt.new_search();
+        self.orderer.new_search();
+        let mut worker = Worker::new(&self.evaluator, &mut self.tt, self.orderer.as_mut(), None)
+            .with_probcut(&self.probcut);
+        worker.iterate_multipv(board, stone, limits, start, n)
+    }
+}
+
+/// Probes and stores search results, either in a table owned by a single
+/// searcher or in one shared between threads.
+pub(crate) trait Table {
+    fn probe(&self, board: &Board, stone: Stone) -> Option<Entry>;
+    fn store(&mut self, board: &Board, stone: Stone, entry: Entry);
+}
+
+impl Table for &mut TranspositionTable {
+    fn probe(&self, board: &Board, stone: Stone) -> Option<Entry> {
+        TranspositionTable::probe(self, board, stone)
+    }
+
+    fn store(&mut self, board: &Board, stone: Stone, entry: Entry) {
+        TranspositionTable::store(self, board, stone, entry);
+    }
+}
+
+/// The state of a single search, run by one thread.
+pub(crate) struct Worker<'a, E, T> {
+    evaluator: &'a E,
+    tt: T,
+    orderer: &'a mut (dyn MoveOrderer + Send),
+    probcut: Option<&'a ProbCut>,
+    nodes: u64,
+    stats: SearchStats,
+    deadline: Option<Instant>,
+    node_limit: Option<u64>,
+    aborted: bool,
+    stop: Option<&'a AtomicBool>,
+    updates: Option<mpsc::Sender<SearchReport>>,
+}
+
+impl<'a, E: Evaluator, T: Table> Worker<'a, E, T> {
+    /// Returns a worker that aborts once the stop flag is raised, if any.
+    pub(crate) fn new(
+        evaluator: &'a E,
+        tt: T,
+        orderer: &'a mut (dyn MoveOrderer + Send),
+        stop: Option<&'a AtomicBool>,
+    ) -> Self {
+        Self {
+            evaluator,
+            tt,
+            orderer,
+            probcut: None,
+            nodes: 0,
+            stats: SearchStats::default(),
+            deadline: None,
+            node_limit: None,
+            aborted: false,
+            stop,
+            updates: None,
+        }
+    }
+
+    /// Prunes positions forward with the ProbCut.
+    pub(crate) fn with_probcut(self, probcut: &'a ProbCut) -> Self {
+        Self {
+            probcut: Some(probcut),
+            ..self
+        }
+    }
+
+    /// Sends the report of every completed iteration to the channel.
+    pub(crate) fn with_updates(self, updates: mpsc::Sender<SearchReport>) -> Self {
+        Self {
+            updates: Some(updates),
+            ..self
+        }
+    }
+
+    /// Runs iterative deepening, searching every iteration the specified
+    /// number of plies deeper than its number.
+    pub(crate) fn iterate(
+        &mut self,
+        board: &Board,
+        stone: Stone,
+        limits: SearchLimits,
+        start: Instant,
+        offset: u8,
+    ) -> SearchReport {
+        let empties = board.empty_squares().count_set();
+        let max_depth = limits.depth.clamp(1, MAX_DEPTH);
+        let mut report = SearchReport {
+            best_move: board.moves_for(stone).hot_bits().next(),
+            score: 0,
+            depth: 0,
+            nodes: 0,
+            pv: Vec::new(),
+            elapsed: Duration::ZERO,
+            stats: SearchStats::default(),
+        };
+
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!("search", empties, max_depth, offset).entered();
+
+        for iteration in 1..=max_depth {
+            let depth = iteration.saturating_add(offset).min(max_depth);
+            // The first iteration always runs to completion
+            self.deadline = limits.time.filter(|_| depth > 1).map(|time| start + time);
+            self.node_limit = limits.nodes.filter(|_| depth > 1);
+            let guess = Some(report.score).filter(|_| iteration > 1);
+            #[cfg(feature = "tracing")]
+            let _iteration = tracing::trace_span!("iteration", depth).entered();
+            #[cfg(feature = "tracing")]
+            let before = (self.nodes, self.stats, Instant::now());
+            let (score, best_move) = self.aspirate(board, stone, depth, guess);
+            if self.aborted {
+                break;
+            }
+            #[cfg(feature = "tracing")]
+            trace_iteration(depth, score, before, (self.nodes, self.stats));
+            let previous = report.score;
+            report.score = score;
+            report.best_move = best_move;
+            report.depth = depth;
+            if let Some(updates) = &self.updates {
+                let update = SearchReport {
+                    pv: self.principal_variation(board, stone, best_move, depth),
+                    nodes: self.nodes,
+                    elapsed: start.elapsed(),
+                    stats: self.stats,
+                    ..report.clone()
+                };
+                // The receiving end may have lost interest, which is fine
+                let _ = updates.send(update);
+            }
+            if let Some(budget) = limits.budget {
+                // Starting an iteration that cannot finish is a waste
+                let limit = budget.soft_limit(previous, score);
+                if depth > 1 && start.elapsed() >= limit {
+                    break;
+                }
+            }
+            // Searching deeper than the number of empty squares is pointless
+            if depth >= empties || depth >= max_depth {
+                break;
+            }
+        }
+
+        report.pv = self.principal_variation(board, stone, report.best_move, report.depth);
+        report.nodes = self.nodes;
+        report.elapsed = start.elapsed();
+        report.stats = self.stats;
+        report
+    }
+
+    /// Runs iterative deepening for the `n` best moves.
+    fn iterate_multipv(
+        &mut self,
+        board: &Board,
+        stone: Stone,
+        limits: SearchLimits,
+        start: Instant,
+        n: usize,
+    ) -> MultiPvReport {
+        let empties = board.empty_squares().count_set();
+        let max_depth = limits.depth.clamp(1, MAX_DEPTH);
+        // Moves are searched in the order of the previous iteration
+        let mut ranked: Vec<Position> = board.moves_for(stone).hot_bits().collect();
+        let mut lines: Vec<(Position, i32)> = Vec::new();
+        let mut completed = 0;
+
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!("multipv", empties, max_depth, n).entered();
+
+        for depth in 1..=max_depth {
+            if ranked.is_empty() || n == 0 {
+                break;
+            }
+            self.deadline = limits.time.filter(|_| depth > 1).map(|time| start + time);
+            self.node_limit = limits.nodes.filter(|_| depth > 1);
+            #[cfg(feature = "tracing")]
+            let _iteration = tracing::trace_span!("iteration", depth).entered();
+            #[cfg(feature = "tracing")]
+            let before = (self.nodes, self.stats, Instant::now());
+            let scores = self.root_multipv(board, stone, depth, &ranked, n);
+            if self.aborted {
+                break;
+            }
+            #[cfg(feature = "tracing")]
+            trace_iteration(depth, scores[0].1, before, (self.nodes, self.stats));
+            let previous = lines.first().map_or(0, |(_, score)| *score);
+            ranked = scores.iter().map(|(pos, _)| *pos).collect();
+            lines = scores.into_iter().take(n).collect();
+            completed = depth;
+            if let Some(budget) = limits.budget {
+                let limit = budget.soft_limit(previous, lines[0].1);
+                if depth > 1 && start.elapsed() >= limit {
+                    break;
+                }
+            }
+            if depth >= empties {
+                break;
+            }
+        }
+
+        let lines = lines
+            .into_iter()
+            .map(|(mv, score)| PvLine {
+                mv,
+                score,
+                pv: self.principal_variation(board, stone, Some(mv), completed),
+            })
+            .collect();
+        MultiPvReport {
+            lines,
+            depth: completed,
+            nodes: self.nodes,
+            elapsed: start.elapsed(),
+            stats: self.stats,
+        }
+    }
+
+    /// Searches every root move, returning them sorted by score. Only the
+    /// scores of the `n` best moves are exact, the others are upper bounds.
+    fn root_multipv(
+        &mut self,
+        board: &Board,
+        stone: Stone,
+        depth: u8,
+        moves: &[Position],
+        n: usize,
+    ) -> Vec<(Position, i32)> {
+        let mut scores: Vec<(Position, i32)> = Vec::with_capacity(moves.len());
+        for pos in moves {
+            // Moves that cannot make it into the n best only need a bound
+            let alpha = if scores.len() >= n {
+                scores[n - 1].1
+            } else {
+                -i32::MAX
+            };
+            let mut next = *board;
+            next.play(stone, *pos);
+            let score = -self.negamax(&next, stone.flip(), depth - 1, 1, -i32::MAX, -alpha);
+            if self.aborted {
+                break;
+            }
+            let index = scores.partition_point(|(_, other)| *other >= score);
+            scores.insert(index, (*pos, score));
+        }
+        if let Some((best_move, score)) = scores.first().copied().filter(|_| !self.aborted) {
+            self.tt.store(
+                board,
+                stone,
+                Entry {
+                    depth,
+                    score,
+                    bound: Bound::Exact,
+                    best_move: Some(best_move),
+                },
+            );
+        }
+        scores
+    }
+
+    /// Searches the root within an aspiration window around the score of
+    /// the previous iteration, widening the window until the score falls
+    /// inside of it.
+    fn aspirate(
+        &mut self,
+        board: &Board,
+        stone: Stone,
+        depth: u8,
+        guess: Option<i32>,
+    ) -> (i32, Option<Position>) {
+        // Proven results are far from everything else, so a window around
+        // them would only have to be widened
+        let Some(guess) = guess.filter(|guess| guess.abs() < WIN_SCORE) else {
+            return self.root(board, stone, depth, -i32::MAX, i32::MAX);
+        };
+        let mut delta = ASPIRATION_WINDOW;
+        let mut alpha = guess - delta;
+        let mut beta = guess + delta;
+        loop {
+            let (score, best_move) = self.root(board, stone, depth, alpha, beta);
+            if self.aborted {
+                return (score, best_move);
+            }
+            delta = delta.saturating_mul(2);
+            if score <= alpha && alpha > -i32::MAX {
+                alpha = if delta < WIN_SCORE {
+                    guess - delta
+                } else {
+                    -i32::MAX
+                };
+            } else if score >= beta && beta < i32::MAX {
+                beta = if delta < WIN_SCORE {
+                    guess + delta
+                } else {
+                    i32::MAX
+                };
+            } else {
+                return (score, best_move);
+            }
+        }
+    }
+
+    fn root(
+        &mut self,
+        board: &Board,
+        stone: Stone,
+        depth: u8,
+        mut alpha: i32,
+        beta: i32,
+    ) -> (i32, Option<Position>) {
+        let mut moves = MoveList::new(board.moves_for(stone).hot_bits());
+        if moves.is_empty() {
+            let score = self.negamax(board, stone, depth, 0, alpha, beta);
+            return (score, None);
+        }
+        let tt_move = self
+            .tt
+            .probe(board, stone)
+            .and_then(|entry| entry.best_move);
+        self.order(board, stone, 0, tt_move, moves.as_mut_slice());
+
+        let original_alpha = alpha;
+        let mut best_score = -i32::MAX;
+        let mut best_move = None;
+        for (index, pos) in moves.iter().enumerate() {
+            let mut next = *board;
+            next.play(stone, pos);
+            let score = if index == 0 {
+                -self.negamax(&next, stone.flip(), depth - 1, 1, -beta, -alpha)
+            } else {
+                self.scout(&next, stone.flip(), depth - 1, 1, alpha, beta)
+            };
+            if self.aborted {
+                break;
+            }
+            if score > best_score {
+                best_score = score;
+                best_move = Some(pos);
+            }
+            alpha = alpha.max(score);
+            if alpha >= beta {
+                break;
+            }
+        }
+        if !self.aborted {
+            self.tt.store(
+                board,
+                stone,
+                Entry {
+                    depth,
+                    score: best_score,
+                    bound: bound(best_score, original_alpha, beta),
+                    best_move,
+                },
+            );
+        }
+        (best_score, best_move)
+    }
+
+    /// Searches the position after a move that is not expected to beat the
+    /// best move so far, from the perspective of the player who made it.
+    ///
+    /// The move is searched with a null window first, and only searched
+    /// again with the full window if it turns out to beat `alpha`.
+    fn scout(
+        &mut self,
+        next: &Board,
+        stone: Stone,
+        depth: u8,
+        ply: usize,
+        alpha: i32,
+        beta: i32,
+    ) -> i32 {
+        let score = -self.negamax(next, stone, depth, ply, -alpha - 1, -alpha);
+        if score <= alpha || score >= beta || self.aborted {
+            return score;
+        }
+        -self.negamax(next, stone, depth, ply, -beta, -alpha)
+    }
+
+    fn negamax(
+        &mut self,
+        board: &Board,
+        stone: Stone,
+        depth: u8,
+        ply: usize,
+        mut alpha: i32,
+        mut beta: i32,
+    ) -> i32 {
+        self.nodes += 1;
+        self.aborted |= self.node_limit.is_some_and(|limit| self.nodes > limit);
+        if self.nodes & 1023 == 0 {
+            let expired = self
+                .deadline
+                .is_some_and(|deadline| Instant::now() >= deadline);
+            let stopped = self.stop.is_some_and(|stop| stop.load(Ordering::Relaxed));
+            self.aborted |= expired || stopped;
+        }
+        if self.aborted {
+            return 0;
+        }
+
+        let moves = board.moves_for(stone);
+        if moves.is_empty() {
+            if board.moves_for(stone.flip()).is_empty() {
+                self.stats.leaf_nodes += 1;
+                return final_score(board, stone);
+            }
+            return -self.negamax(board, stone.flip(), depth, ply + 1, -beta, -alpha);
+        }
+        if depth == 0 {
+            self.stats.leaf_nodes += 1;
+            return self.evaluator.evaluate(board, stone);
+        }
+
+        let original_alpha = alpha;
+        let mut tt_move = None;
+        self.stats.tt_probes += 1;
+        if let Some(entry) = self.tt.probe(board, stone) {
+            self.stats.tt_hits += 1;
+            tt_move = entry.best_move;
+            if entry.depth >= depth {
+                match entry.bound {
+                    Bound::Exact => alpha = beta,
+                    Bound::Lower => alpha = alpha.max(entry.score),
+                    Bound::Upper => beta = beta.min(entry.score),
+                }
+                if alpha >= beta {
+                    self.stats.tt_cutoffs += 1;
+                    return entry.score;
+                }
+            }
+        }
+
+        if let Some(score) = self.probcut(board, stone, depth, ply, alpha, beta) {
+            return score;
+        }
+
+        let mut moves = MoveList::new(moves.hot_bits());
+        self.order(board, stone, ply, tt_move, moves.as_mut_slice());
+
+        let mut best_score = -i32::MAX;
+        let mut best_move = None;
+        for (index, pos) in moves.iter().enumerate() {
+            let mut next = *board;
+            next.play(stone, pos);
+            let score = if index == 0 {
+                -self.negamax(&next, stone.flip(), depth - 1, ply + 1, -beta, -alpha)
+            } else {
+                self.scout(&next, stone.flip(), depth - 1, ply + 1, alpha, beta)
+            };
+            if self.aborted {
+                return 0;
+            }
+            if score > best_score {
+                best_score = score;
+                best_move = Some(pos);
+            }
+            alpha = alpha.max(score);
+            if alpha >= beta {
+                self.stats.beta_cutoffs += 1;
+                self.stats.first_move_cutoffs += u64::from(index == 0);
+                self.orderer.on_cutoff(board, stone, ply, depth, pos);
+                break;
+            }
+        }
+
+        self.tt.store(
+            board,
+            stone,
+            Entry {
+                depth,
+                score: best_score,
+                bound: bound(best_score, original_alpha, beta),
+                best_move,
+            },
+        );
+        best_score
+    }
+
+    /// Tries to cut the position with shallow searches, returning the bound
+    /// it fails at if a cut is made.
+    fn probcut(
+        &mut self,
+        board: &Board,
+        stone: Stone,
+        depth: u8,
+        ply: usize,
+        alpha: i32,
+        beta: i32,
+    ) -> Option<i32> {
+        let probcut = self.probcut?;
+        // Only null-window searches are cut, and never once a result is
+        // proven
+        if alpha.abs() >= WIN_SCORE || beta.abs() >= WIN_SCORE || beta - alpha != 1 {
+            return None;
+        }
+        for (shallow, upper, lower) in probcut.bounds(depth, alpha, beta) {
+            let score = self.negamax(board, stone, shallow, ply, upper - 1, upper);
+            if self.aborted {
+                return Some(0);
+            }
+            if score >= upper {
+                self.stats.probcuts += 1;
+                return Some(beta);
+            }
+            let score = self.negamax(board, stone, shallow, ply, lower, lower + 1);
+            if self.aborted {
+                return Some(0);
+            }
+            if score <= lower {
+                self.stats.probcuts += 1;
+                return Some(alpha);
+            }
+        }
+        None
+    }
+
+    fn order(
+        &mut self,
+        board: &Board,
+        stone: Stone,
+        ply: usize,
+        tt_move: Option<Position>,
+        moves: &mut [Position],
+    ) {
+        let mut rest = &mut moves[..];
+        if let Some(tt_move) = tt_move
+            && let Some(index) = rest.iter().position(|pos| *pos == tt_move)
+        {
+            rest.swap(0, index);
+            rest = &mut rest[1..];
+        }
+        self.orderer.order(board, stone, ply, rest);
+    }
+
+    fn principal_variation(
+        &self,
+        board: &Board,
+        stone: Stone,
+        best_move: Option<Position>,
+        depth: u8,
+    ) -> Vec<Position> {
+        let mut pv = Vec::new();
+        let mut board = *board;
+        let mut stone = stone;
+        let mut next_move = best_move;
+        while let Some(pos) = next_move {
+            if pv.len() >= usize::from(depth) || !board.is_legal_move(stone, pos) {
+                break;
+            }
+            board.play(stone, pos);
+            pv.push(pos);
+            stone = stone.flip();
+            if board.moves_for(stone).is_empty() {
+                stone = stone.flip();
+            }
+            next_move = self
+                .tt
+                .probe(&board, stone)
+                .and_then(|entry| entry.best_move);
+        }
+        pv
+    }
+}
+
+/// Classifies a score found within the window between `alpha` and `beta`.
+fn bound(score: i32, alpha: i32, beta: i32) -> Bound {
+    if score <= alpha {
+        Bound::Upper
+    } else if score >= beta {
+        Bound::Lower
+    } else {
+        Bound::Exact
+    }
+}
+
+/// Scores a concluded game from the perspective of the specified player,
+/// awarding the empty squares to the winner as the endgame solver does.
+pub(crate) fn final_score(board: &Board, stone: Stone) -> i32 {
+    let difference = i32::from(Scoring::WinnerTakesEmpties.difference(board, stone));
+    match difference.signum() {
+        1 => WIN_SCORE + difference,
+        -1 => -WIN_SCORE + difference,
+        _ => 0,
+    }
+}
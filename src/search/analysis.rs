@@ -0,0 +1,114 @@
+use crate::{
+    eval::Evaluator,
+    othello::{Board, Stone},
+    search::{SearchLimits, SearchReport, Searcher},
+};
+use std::{
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+        mpsc::{self, Receiver},
+    },
+    thread::{self, JoinHandle},
+};
+
+/// A search running on a background thread, which can be queried and
+/// stopped at any time.
+///
+/// Every completed iteration of the search is reported as an update. The
+/// search ends by itself once it reaches the limits it was started with,
+/// which can be left out entirely for infinite analysis, but it can also be
+/// stopped early, for instance when pondering on the time of the opponent
+/// and the opponent makes a different move than expected.
+///
+/// Stopping the analysis returns the searcher, which keeps its
+/// transposition table for the next search.
+///
+/// # Examples
+/// ```rust
+/// use magpie::eval::Heuristic;
+/// use magpie::othello::{Board, Stone};
+/// use magpie::search::{Analysis, SearchLimits, Searcher};
+///
+/// let board = Board::standard();
+/// let mut analysis = Analysis::start(
+///     Searcher::new(Heuristic::default()),
+///     &board,
+///     Stone::Black,
+///     SearchLimits::depth(60),
+/// );
+///
+/// // Wait for the first few iterations to complete
+/// while analysis.recv().is_some_and(|update| update.depth < 4) {}
+///
+/// let (searcher, report) = analysis.stop();
+/// assert!(report.depth >= 4);
+/// assert!(board.is_legal_move(Stone::Black, report.best_move.unwrap()));
+/// # drop(searcher);
+/// ```
+pub struct Analysis<E: Evaluator> {
+    stop: Arc<AtomicBool>,
+    updates: Receiver<SearchReport>,
+    latest: Option<SearchReport>,
+    thread: JoinHandle<(Searcher<E>, SearchReport)>,
+}
+
+impl<E: Evaluator + Send + 'static> Analysis<E> {
+    /// Starts searching the position for the best move of the specified
+    /// player on a background thread.
+    #[must_use]
+    pub fn start(searcher: Searcher<E>, board: &Board, stone: Stone, limits: SearchLimits) -> Self {
+        let stop = Arc::new(AtomicBool::new(false));
+        let (sender, updates) = mpsc::channel();
+        let board = *board;
+        let thread = {
+            let stop = Arc::clone(&stop);
+            thread::spawn(move || {
+                let mut searcher = searcher;
+                let report = searcher.search_with(&board, stone, limits, &stop, sender);
+                (searcher, report)
+            })
+        };
+        Self {
+            stop,
+            updates,
+            latest: None,
+            thread,
+        }
+    }
+}
+
+impl<E: Evaluator> Analysis<E> {
+    /// Waits for the next completed iteration and returns its report, or
+    /// `None` if the search has ended.
+    pub fn recv(&mut self) -> Option<SearchReport> {
+        let update = self.updates.recv().ok()?;
+        self.latest = Some(update.clone());
+        Some(update)
+    }
+
+    /// Returns the report of the last completed iteration without waiting,
+    /// or `None` if the first iteration has not completed yet.
+    pub fn latest(&mut self) -> Option<&SearchReport> {
+        while let Ok(update) = self.updates.try_recv() {
+            self.latest = Some(update);
+        }
+        self.latest.as_ref()
+    }
+
+    /// Returns true if the search has ended by itself.
+    #[must_use]
+    pub fn is_finished(&self) -> bool {
+        self.thread.is_finished()
+    }
+
+    /// Stops the search and waits for the background thread, returning the
+    /// searcher along with the report of the last completed iteration.
+    ///
+    /// # Panics
+    /// Panics if the background thread panicked.
+    pub fn stop(self) -> (Searcher<E>, SearchReport) {
+        self.stop.store(true, Ordering::Relaxed);
+        self.thread.join().expect("the analysis thread panicked")
+    }
+}
@@ -0,0 +1,308 @@
+use crate::{
+    othello::{Board, Position, Scoring, Stone},
+    search::{Bound, Entry, Replacement, TranspositionTable, movelist::MoveList},
+};
+
+/// The four quadrants of the board, used for parity ordering.
+const QUADRANTS: [u64; 4] = [
+    0xF0_F0_F0_F0_00_00_00_00,
+    0x0F_0F_0F_0F_00_00_00_00,
+    0x00_00_00_00_F0_F0_F0_F0,
+    0x00_00_00_00_0F_0F_0F_0F,
+];
+
+/// Positions with fewer empty squares than this skip the hash table and the
+/// more expensive move ordering.
+const SHALLOW_EMPTIES: u8 = 7;
+
+/// Solves the position perfectly, assuming that both players play optimally.
+///
+/// Returns the final disc differential from the perspective of the specified
+/// player along with the move that achieves it. The move is `None` if the
+/// player has to pass or if the game has already concluded. Games that end
+/// before the board is full are scored as in tournaments, with
+/// [`Scoring::WinnerTakesEmpties`].
+///
+/// [`Scoring::WinnerTakesEmpties`]: crate::othello::Scoring::WinnerTakesEmpties
+///
+/// The solver searches the entire remaining game tree, which makes it
+/// practical only for positions with few empty squares. Positions with up
+/// to twenty or so empty squares are usually solved within seconds.
+///
+/// # Examples
+/// ```rust
+/// use magpie::othello::{Board, Stone};
+/// use magpie::search::solve_endgame;
+///
+/// // Black owns every square except A1 and B1, where white has a stone
+/// let black = 0x3F_FF_FF_FF_FF_FF_FF_FF;
+/// let white = 0x40_00_00_00_00_00_00_00;
+/// let board = Board::try_from((black, white)).unwrap();
+///
+/// let (score, best_move) = solve_endgame(&board, Stone::Black);
+/// assert_eq!(64, score);
+/// assert_eq!(Some("a1".parse().unwrap()), best_move);
+/// ```
+#[must_use]
+pub fn solve_endgame(board: &Board, stone: Stone) -> (i32, Option<Position>) {
+    let mut solver = Solver {
+        tt: TranspositionTable::new(1 << 16, Replacement::DepthPreferred),
+    };
+    solver.root(board, stone)
+}
+
+/// The outcome of a game played perfectly, from the perspective of one of
+/// the players.
+///
+/// Outcomes are ordered from worst to best, so that `Wdl::Win` is the
+/// greatest.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum Wdl {
+    /// The player loses the game.
+    Loss,
+    /// The game ends in a draw.
+    Draw,
+    /// The player wins the game.
+    Win,
+}
+
+impl Wdl {
+    /// Returns the outcome from the perspective of the opponent.
+    #[must_use]
+    pub fn flip(self) -> Self {
+        match self {
+            Wdl::Loss => Wdl::Win,
+            Wdl::Draw => Wdl::Draw,
+            Wdl::Win => Wdl::Loss,
+        }
+    }
+
+    fn from_score(score: i32) -> Self {
+        match score.signum() {
+            1 => Wdl::Win,
+            -1 => Wdl::Loss,
+            _ => Wdl::Draw,
+        }
+    }
+}
+
+/// Determines whether the position is won, drawn or lost, assuming that
+/// both players play optimally.
+///
+/// Returns the outcome from the perspective of the specified player along
+/// with a move that achieves it, which is `None` if the player has to pass
+/// or if the game has already concluded.
+///
+/// Unlike [`solve_endgame`], the solver never searches with a window wider
+/// than a single disc around zero, so it only proves the sign of the final
+/// disc differential. This prunes far more of the game tree, which usually
+/// makes it several times faster than solving for the exact score.
+///
+/// # Examples
+/// ```rust
+/// use magpie::othello::{Board, Stone};
+/// use magpie::search::{Wdl, solve_wdl};
+///
+/// // Black owns every square except A1 and B1, where white has a stone
+/// let black = 0x3F_FF_FF_FF_FF_FF_FF_FF;
+/// let white = 0x40_00_00_00_00_00_00_00;
+/// let board = Board::try_from((black, white)).unwrap();
+///
+/// assert_eq!((Wdl::Win, Some("a1".parse().unwrap())), solve_wdl(&board, Stone::Black));
+/// assert_eq!(Wdl::Loss, solve_wdl(&board, Stone::White).0);
+/// ```
+#[must_use]
+pub fn solve_wdl(board: &Board, stone: Stone) -> (Wdl, Option<Position>) {
+    let mut solver = Solver {
+        tt: TranspositionTable::new(1 << 16, Replacement::DepthPreferred),
+    };
+    solver.root_wdl(board, stone)
+}
+
+struct Solver {
+    tt: TranspositionTable,
+}
+
+impl Solver {
+    fn root(&mut self, board: &Board, stone: Stone) -> (i32, Option<Position>) {
+        let mut moves = MoveList::new(board.moves_for(stone).hot_bits());
+        if moves.is_empty() {
+            return (self.solve(board, stone, -64, 64, false), None);
+        }
+        order(board, stone, moves.as_mut_slice());
+
+        let mut alpha = -65;
+        let mut best_move = None;
+        for pos in moves.iter() {
+            let mut next = *board;
+            next.play(stone, pos);
+            let score = -self.solve(&next, stone.flip(), -64, -alpha, false);
+            if score > alpha {
+                alpha = score;
+                best_move = Some(pos);
+            }
+        }
+        (alpha, best_move)
+    }
+
+    fn root_wdl(&mut self, board: &Board, stone: Stone) -> (Wdl, Option<Position>) {
+        let mut moves = MoveList::new(board.moves_for(stone).hot_bits());
+        if moves.is_empty() {
+            let score = self.solve(board, stone, -1, 1, false);
+            return (Wdl::from_score(score), None);
+        }
+        order(board, stone, moves.as_mut_slice());
+
+        // Every move is searched with a window that only tells whether it
+        // beats the best outcome so far, stopping at the first win
+        let mut alpha = -1;
+        let mut best = -65;
+        let mut best_move = None;
+        for pos in moves.iter() {
+            let mut next = *board;
+            next.play(stone, pos);
+            let score = -self.solve(&next, stone.flip(), -1, -alpha, false);
+            if score > best {
+                best = score;
+                best_move = Some(pos);
+            }
+            alpha = alpha.max(score);
+            if alpha >= 1 {
+                break;
+            }
+        }
+        (Wdl::from_score(best), best_move)
+    }
+
+    fn solve(
+        &mut self,
+        board: &Board,
+        stone: Stone,
+        mut alpha: i32,
+        mut beta: i32,
+        passed: bool,
+    ) -> i32 {
+        let moves = board.moves_for(stone);
+        if moves.is_empty() {
+            if passed {
+                return i32::from(Scoring::WinnerTakesEmpties.difference(board, stone));
+            }
+            return -self.solve(board, stone.flip(), -beta, -alpha, true);
+        }
+
+        let empties = board.empty_squares().count_set();
+        if empties < SHALLOW_EMPTIES {
+            // Parity ordering alone is cheap and good enough near the end
+            let mut best = -65;
+            for pos in parity_order(board, moves.raw()) {
+                let mut next = *board;
+                next.play(stone, pos);
+                let score = -self.solve(&next, stone.flip(), -beta, -alpha, false);
+                best = best.max(score);
+                alpha = alpha.max(score);
+                if alpha >= beta {
+                    break;
+                }
+            }
+            return best;
+        }
+
+        let original_alpha = alpha;
+        let mut tt_move = None;
+        if let Some(entry) = self.tt.probe(board, stone) {
+            tt_move = entry.best_move;
+            match entry.bound {
+                Bound::Exact => return entry.score,
+                Bound::Lower => alpha = alpha.max(entry.score),
+                Bound::Upper => beta = beta.min(entry.score),
+            }
+            if alpha >= beta {
+                return entry.score;
+            }
+        }
+
+        let mut moves = MoveList::new(moves.hot_bits());
+        let mut rest = moves.as_mut_slice();
+        if let Some(tt_move) = tt_move
+            && let Some(index) = rest.iter().position(|pos| *pos == tt_move)
+        {
+            rest.swap(0, index);
+            rest = &mut rest[1..];
+        }
+        order(board, stone, rest);
+
+        let mut best = -65;
+        let mut best_move = None;
+        for pos in moves.iter() {
+            let mut next = *board;
+            next.play(stone, pos);
+            let score = -self.solve(&next, stone.flip(), -beta, -alpha, false);
+            if score > best {
+                best = score;
+                best_move = Some(pos);
+            }
+            alpha = alpha.max(score);
+            if alpha >= beta {
+                break;
+            }
+        }
+
+        let bound = if best <= original_alpha {
+            Bound::Upper
+        } else if best >= beta {
+            Bound::Lower
+        } else {
+            Bound::Exact
+        };
+        self.tt.store(
+            board,
+            stone,
+            Entry {
+                depth: empties,
+                score: best,
+                bound,
+                best_move,
+            },
+        );
+        best
+    }
+}
+
+/// Orders moves by the number of replies left to the opponent, fewest first,
+/// breaking ties by parity.
+fn order(board: &Board, stone: Stone, moves: &mut [Position]) {
+    let odd = odd_quadrants(board);
+    moves.sort_by_cached_key(|pos| {
+        let mut next = *board;
+        next.play(stone, *pos);
+        let mobility = next.mobility(stone.flip());
+        (mobility, pos.raw() & odd == 0)
+    });
+}
+
+/// Returns the moves in squares belonging to quadrants with an odd number
+/// of empty squares first, followed by the rest.
+fn parity_order(board: &Board, moves: u64) -> impl Iterator<Item = Position> {
+    let odd = odd_quadrants(board);
+    hot_bits(moves & odd).chain(hot_bits(moves & !odd))
+}
+
+/// Returns a mask of all quadrants with an odd number of empty squares.
+fn odd_quadrants(board: &Board) -> u64 {
+    let empty = board.empty_squares().raw();
+    QUADRANTS
+        .iter()
+        .filter(|quadrant| (empty & **quadrant).count_ones() % 2 == 1)
+        .fold(0, |mask, quadrant| mask | quadrant)
+}
+
+fn hot_bits(mut bits: u64) -> impl Iterator<Item = Position> {
+    core::iter::from_fn(move || {
+        if bits == 0 {
+            return None;
+        }
+        let bit = 1 << bits.trailing_zeros();
+        bits ^= bit;
+        Some(Position::new_unchecked(bit))
+    })
+}
@@ -0,0 +1,349 @@
+use crate::othello::{Bitboard, Board, Position, Stone};
+use std::time::{Duration, Instant};
+
+/// Chooses moves during the random playouts of a Monte Carlo tree search.
+///
+/// Playouts are typically played with cheap, randomized policies. Better
+/// policies make each playout more informative at the cost of fewer
+/// playouts per second.
+///
+/// # Examples
+/// ```rust
+/// use magpie::othello::{Bitboard, Board, Position, Stone};
+/// use magpie::search::RolloutPolicy;
+///
+/// // Always plays the first legal move
+/// struct FirstMove;
+///
+/// impl RolloutPolicy for FirstMove {
+///     fn choose(&mut self, _board: &Board, _stone: Stone, moves: Bitboard) -> Position {
+///         moves.hot_bits().next().unwrap()
+///     }
+/// }
+///
+/// let board = Board::standard();
+/// let pos = FirstMove.choose(&board, Stone::Black, board.moves_for(Stone::Black));
+/// assert!(board.is_legal_move(Stone::Black, pos));
+/// ```
+pub trait RolloutPolicy {
+    /// Chooses one of the legal moves, which are guaranteed to be non-empty.
+    fn choose(&mut self, board: &Board, stone: Stone, moves: Bitboard) -> Position;
+}
+
+/// Plays uniformly random moves.
+///
+/// The policy uses a small, deterministic pseudorandom number generator, so
+/// searches using the same seed produce the same results.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct RandomRollout {
+    state: u64,
+}
+
+impl RandomRollout {
+    /// Returns a random policy seeded with the specified value.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Board, Stone};
+    /// use magpie::search::{RandomRollout, RolloutPolicy};
+    ///
+    /// let board = Board::standard();
+    /// let moves = board.moves_for(Stone::Black);
+    /// let a = RandomRollout::new(7).choose(&board, Stone::Black, moves);
+    /// let b = RandomRollout::new(7).choose(&board, Stone::Black, moves);
+    /// assert_eq!(a, b);
+    /// ```
+    #[must_use]
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        // SplitMix64
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+}
+
+impl Default for RandomRollout {
+    fn default() -> Self {
+        Self::new(0x6D61_6770_6965)
+    }
+}
+
+impl RolloutPolicy for RandomRollout {
+    fn choose(&mut self, _board: &Board, _stone: Stone, moves: Bitboard) -> Position {
+        let count = u64::from(moves.count_set());
+        let index = (self.next_u64() % count) as usize;
+        moves.hot_bits().nth(index).unwrap()
+    }
+}
+
+/// Restricts how long a Monte Carlo tree search may run.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct MctsLimits {
+    /// The maximum number of playouts.
+    pub playouts: u32,
+    /// The maximum time to spend searching, if any.
+    pub time: Option<Duration>,
+}
+
+impl MctsLimits {
+    /// Returns limits that run the specified number of playouts.
+    #[must_use]
+    pub fn playouts(playouts: u32) -> Self {
+        Self {
+            playouts,
+            time: None,
+        }
+    }
+
+    /// Returns limits that run as many playouts as possible within the
+    /// specified time.
+    #[must_use]
+    pub fn time(time: Duration) -> Self {
+        Self {
+            playouts: u32::MAX,
+            time: Some(time),
+        }
+    }
+
+    /// Returns a copy of the limits with the specified time budget.
+    #[must_use]
+    pub fn with_time(self, time: Duration) -> Self {
+        Self {
+            time: Some(time),
+            ..self
+        }
+    }
+}
+
+/// The result of a Monte Carlo tree search.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MctsReport {
+    /// The most visited move, or `None` if the player has to pass or the game
+    /// has concluded.
+    pub best_move: Option<Position>,
+    /// The number of visits of every legal move at the root.
+    ///
+    /// Normalized, these form a policy suitable as a training target for
+    /// neural networks.
+    pub visits: Vec<(Position, u32)>,
+    /// The expected score of the best move, between 0 (loss) and 1 (win).
+    pub win_rate: f64,
+    /// The number of playouts run.
+    pub playouts: u32,
+    /// The time spent searching.
+    pub elapsed: Duration,
+}
+
+/// A Monte Carlo tree searcher using the UCT selection rule.
+///
+/// The search grows a tree of positions, guided by the results of playouts
+/// run from its leaves. Playouts are played with a pluggable
+/// [`RolloutPolicy`].
+///
+/// [`RolloutPolicy`]: crate::search::RolloutPolicy
+///
+/// # Examples
+/// ```rust
+/// use magpie::othello::{Board, Stone};
+/// use magpie::search::{Mcts, MctsLimits, RandomRollout};
+///
+/// let mut mcts = Mcts::new(RandomRollout::new(1));
+/// let board = Board::standard();
+/// let report = mcts.search(&board, Stone::Black, MctsLimits::playouts(200));
+/// assert_eq!(200, report.playouts);
+/// assert_eq!(4, report.visits.len());
+/// assert!(board.is_legal_move(Stone::Black, report.best_move.unwrap()));
+/// ```
+pub struct Mcts<P: RolloutPolicy> {
+    policy: P,
+    exploration: f64,
+}
+
+impl<P: RolloutPolicy> Mcts<P> {
+    /// Returns a searcher using the specified rollout policy and an
+    /// exploration constant of √2.
+    #[must_use]
+    pub fn new(policy: P) -> Self {
+        Self {
+            policy,
+            exploration: std::f64::consts::SQRT_2,
+        }
+    }
+
+    /// Replaces the exploration constant used when selecting nodes.
+    ///
+    /// Larger values spread the playouts more evenly between moves.
+    #[must_use]
+    pub fn with_exploration(self, exploration: f64) -> Self {
+        Self {
+            exploration,
+            ..self
+        }
+    }
+
+    /// Returns the rollout policy used by the searcher.
+    #[must_use]
+    pub fn policy(&self) -> &P {
+        &self.policy
+    }
+
+    /// Searches the position for the best move of the specified player.
+    pub fn search(&mut self, board: &Board, stone: Stone, limits: MctsLimits) -> MctsReport {
+        let start = Instant::now();
+        let deadline = limits.time.map(|time| start + time);
+        let mut tree = vec![Node::new(*board, stone, None, None)];
+
+        let mut playouts = 0;
+        // Nothing to search if the player has to pass
+        if !board.moves_for(stone).is_empty() {
+            while playouts < limits.playouts
+                && deadline.is_none_or(|deadline| Instant::now() < deadline)
+            {
+                self.playout(&mut tree);
+                playouts += 1;
+            }
+        }
+
+        let root = &tree[0];
+        let visits: Vec<(Position, u32)> = root
+            .children
+            .iter()
+            .filter_map(|child| tree[*child].mv.map(|mv| (mv, tree[*child].visits)))
+            .collect();
+        let best = root
+            .children
+            .iter()
+            .max_by_key(|child| tree[**child].visits)
+            .map(|child| &tree[*child]);
+        MctsReport {
+            best_move: best.and_then(|node| node.mv),
+            visits,
+            win_rate: best.map_or(0.0, |node| node.wins / f64::from(node.visits.max(1))),
+            playouts,
+            elapsed: start.elapsed(),
+        }
+    }
+
+    fn playout(&mut self, tree: &mut Vec<Node>) {
+        // Selection
+        let mut current = 0;
+        while tree[current].untried == 0 && !tree[current].children.is_empty() {
+            current = self.select(tree, current);
+        }
+
+        // Expansion
+        if tree[current].untried != 0 {
+            let node = &mut tree[current];
+            let bit = 1 << node.untried.trailing_zeros();
+            node.untried ^= bit;
+            let pos = Position::new_unchecked(bit);
+            let mut board = node.board;
+            board.play(node.stone, pos);
+            let child = Node::new(board, node.stone.flip(), Some(node.stone), Some(pos));
+            let index = tree.len();
+            tree[current].children.push(index);
+            tree.push(Node {
+                parent: Some(current),
+                ..child
+            });
+            current = index;
+        }
+
+        // Simulation
+        let winner = self.rollout(tree[current].board, tree[current].stone);
+
+        // Backpropagation
+        let mut node = Some(current);
+        while let Some(index) = node {
+            let node_ref = &mut tree[index];
+            node_ref.visits += 1;
+            node_ref.wins += match (winner, node_ref.mover) {
+                (Some(winner), Some(mover)) if winner == mover => 1.0,
+                (None, _) => 0.5,
+                _ => 0.0,
+            };
+            node = node_ref.parent;
+        }
+    }
+
+    fn select(&self, tree: &[Node], parent: usize) -> usize {
+        let log_visits = f64::from(tree[parent].visits).ln();
+        let uct = |index: usize| {
+            let node = &tree[index];
+            let visits = f64::from(node.visits);
+            node.wins / visits + self.exploration * (log_visits / visits).sqrt()
+        };
+        tree[parent]
+            .children
+            .iter()
+            .copied()
+            .max_by(|a, b| uct(*a).total_cmp(&uct(*b)))
+            .unwrap()
+    }
+
+    fn rollout(&mut self, mut board: Board, mut stone: Stone) -> Option<Stone> {
+        loop {
+            let mut moves = board.moves_for(stone);
+            if moves.is_empty() {
+                stone = stone.flip();
+                moves = board.moves_for(stone);
+                if moves.is_empty() {
+                    break;
+                }
+            }
+            let pos = self.policy.choose(&board, stone, moves);
+            board.play(stone, pos);
+            stone = stone.flip();
+        }
+        let black = board.bits_for(Stone::Black).count_set();
+        let white = board.bits_for(Stone::White).count_set();
+        match black.cmp(&white) {
+            std::cmp::Ordering::Greater => Some(Stone::Black),
+            std::cmp::Ordering::Less => Some(Stone::White),
+            std::cmp::Ordering::Equal => None,
+        }
+    }
+}
+
+struct Node {
+    board: Board,
+    /// The player to move, after any forced pass.
+    stone: Stone,
+    /// The player who made the move leading to this node.
+    mover: Option<Stone>,
+    mv: Option<Position>,
+    parent: Option<usize>,
+    children: Vec<usize>,
+    untried: u64,
+    visits: u32,
+    wins: f64,
+}
+
+impl Node {
+    fn new(board: Board, stone: Stone, mover: Option<Stone>, mv: Option<Position>) -> Self {
+        let mut stone = stone;
+        let mut untried = board.moves_for(stone).raw();
+        if untried == 0 {
+            // Forced passes are made implicitly
+            stone = stone.flip();
+            untried = board.moves_for(stone).raw();
+        }
+        Self {
+            board,
+            stone,
+            mover,
+            mv,
+            parent: None,
+            children: Vec::new(),
+            untried,
+            visits: 0,
+            wins: 0.0,
+        }
+    }
+}
@@ -0,0 +1,69 @@
+//! Game tree search and the building blocks it is made of.
+//!
+//! The searchers that keep track of time require the `std` feature, while
+//! the transposition table and the endgame solver only require `alloc`.
+
+/// A negamax searcher with alpha-beta pruning and iterative deepening.
+#[cfg(feature = "std")]
+mod alphabeta;
+/// Searches on a background thread that can be stopped at any time.
+#[cfg(feature = "std")]
+mod analysis;
+/// An exact solver for positions close to the end of the game.
+mod endgame;
+/// A Monte Carlo tree searcher.
+#[cfg(feature = "std")]
+mod mcts;
+/// A fixed-capacity list of moves.
+mod movelist;
+/// Decides the order in which moves are searched.
+#[cfg(feature = "std")]
+mod ordering;
+/// A multi-threaded searcher built on the alpha-beta searcher.
+#[cfg(feature = "std")]
+mod parallel;
+/// Forward pruning by shallow searches.
+#[cfg(feature = "std")]
+mod probcut;
+/// A Monte Carlo tree searcher guided by an external model.
+#[cfg(feature = "std")]
+mod puct;
+/// Ranks every legal move by searching each of them.
+#[cfg(feature = "std")]
+mod rank;
+/// A transposition table that can be shared between threads.
+#[cfg(feature = "std")]
+mod shared_tt;
+/// Divides the time on the clock between moves.
+#[cfg(feature = "std")]
+mod time;
+/// A hash table caching the results of previous searches.
+mod tt;
+
+#[cfg(feature = "std")]
+pub use alphabeta::{
+    MultiPvReport, PvLine, SearchLimits, SearchReport, SearchStats, Searcher, WIN_SCORE,
+};
+#[cfg(feature = "std")]
+pub use analysis::Analysis;
+pub use endgame::{Wdl, solve_endgame, solve_wdl};
+#[cfg(feature = "std")]
+pub use mcts::{Mcts, MctsLimits, MctsReport, RandomRollout, RolloutPolicy};
+#[cfg(feature = "std")]
+pub use ordering::{Chain, CornersFirst, HistoryHeuristic, KillerMoves, MoveOrderer, NoOrdering};
+#[cfg(feature = "std")]
+pub use parallel::ParallelSearcher;
+#[cfg(feature = "std")]
+pub use probcut::{ProbCut, ProbCutPair};
+#[cfg(feature = "std")]
+pub use puct::Puct;
+#[cfg(feature = "std")]
+pub use rank::rank_moves;
+#[cfg(feature = "std")]
+pub use shared_tt::SharedTranspositionTable;
+#[cfg(feature = "std")]
+pub use time::{ClockState, TimeBudget, TimeManager};
+pub use tt::{Bound, Entry, Replacement, TranspositionTable};
+
+#[cfg(feature = "std")]
+pub(crate) use tt::hash;
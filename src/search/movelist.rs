@@ -0,0 +1,33 @@
+use crate::othello::Position;
+
+/// A fixed-capacity list of moves that avoids allocating during search.
+pub(crate) struct MoveList {
+    moves: [Position; 64],
+    len: usize,
+}
+
+impl MoveList {
+    pub(crate) fn new(positions: impl Iterator<Item = Position>) -> Self {
+        let mut list = Self {
+            moves: [Position::default(); 64],
+            len: 0,
+        };
+        for pos in positions {
+            list.moves[list.len] = pos;
+            list.len += 1;
+        }
+        list
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub(crate) fn as_mut_slice(&mut self) -> &mut [Position] {
+        &mut self.moves[..self.len]
+    }
+
+    pub(crate) fn iter(&self) -> impl Iterator<Item = Position> + '_ {
+        self.moves[..self.len].iter().copied()
+    }
+}
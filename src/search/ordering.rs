@@ -0,0 +1,293 @@
+use crate::othello::{Board, Position, Stone};
+use core::cmp::Reverse;
+
+/// Decides the order in which moves are searched.
+///
+/// Alpha-beta search prunes the most when the best move is searched first,
+/// which makes move ordering one of the most important parts of a searcher.
+/// The best move from the transposition table, if any, is always searched
+/// first. The remaining moves are handed to the orderer.
+///
+/// Closures with the same signature as [`order`] implement this trait as
+/// well. Orderers can be combined with [`then`], which is why they should
+/// keep the relative order of moves they consider equally promising.
+///
+/// [`order`]: crate::search::MoveOrderer::order
+/// [`then`]: crate::search::MoveOrderer::then
+///
+/// # Examples
+/// ```rust
+/// use magpie::othello::{Board, Position, Stone};
+/// use magpie::search::MoveOrderer;
+///
+/// // Searches the moves that leave the opponent with the fewest replies first
+/// let mut orderer = |board: &Board, stone: Stone, _ply: usize, moves: &mut [Position]| {
+///     moves.sort_by_key(|pos| {
+///         let mut next = *board;
+///         next.play(stone, *pos);
+///         next.mobility(stone.flip())
+///     });
+/// };
+///
+/// let board = Board::standard();
+/// let mut moves: Vec<Position> = board.moves_for(Stone::Black).hot_bits().collect();
+/// orderer.order(&board, Stone::Black, 0, &mut moves);
+/// ```
+pub trait MoveOrderer {
+    /// Reorders the moves in place so that the most promising moves come
+    /// first.
+    ///
+    /// The ply is the distance from the root of the search.
+    fn order(&mut self, board: &Board, stone: Stone, ply: usize, moves: &mut [Position]);
+
+    /// Called whenever a move causes a beta cutoff, with the remaining depth
+    /// of the search at that point.
+    fn on_cutoff(&mut self, board: &Board, stone: Stone, ply: usize, depth: u8, mv: Position) {
+        let _ = (board, stone, ply, depth, mv);
+    }
+
+    /// Called at the start of every new search.
+    fn new_search(&mut self) {}
+
+    /// Combines two orderers, where this orderer takes precedence and the
+    /// other one breaks ties.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::search::{CornersFirst, HistoryHeuristic, KillerMoves, MoveOrderer};
+    ///
+    /// let orderer = KillerMoves::new()
+    ///     .then(HistoryHeuristic::new())
+    ///     .then(CornersFirst);
+    /// ```
+    fn then<O: MoveOrderer>(self, next: O) -> Chain<Self, O>
+    where
+        Self: Sized,
+    {
+        Chain { first: self, next }
+    }
+}
+
+impl<F> MoveOrderer for F
+where
+    F: FnMut(&Board, Stone, usize, &mut [Position]),
+{
+    fn order(&mut self, board: &Board, stone: Stone, ply: usize, moves: &mut [Position]) {
+        self(board, stone, ply, moves);
+    }
+}
+
+/// Leaves the moves in the order they were generated.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+pub struct NoOrdering;
+
+impl MoveOrderer for NoOrdering {
+    fn order(&mut self, _board: &Board, _stone: Stone, _ply: usize, _moves: &mut [Position]) {}
+}
+
+/// Two orderers combined with [`MoveOrderer::then`].
+///
+/// [`MoveOrderer::then`]: crate::search::MoveOrderer::then
+#[derive(Clone, Debug, Default)]
+pub struct Chain<A, B> {
+    first: A,
+    next: B,
+}
+
+impl<A: MoveOrderer, B: MoveOrderer> MoveOrderer for Chain<A, B> {
+    fn order(&mut self, board: &Board, stone: Stone, ply: usize, moves: &mut [Position]) {
+        // The first orderer is applied last, so that ties keep the order
+        // established by the next one
+        self.next.order(board, stone, ply, moves);
+        self.first.order(board, stone, ply, moves);
+    }
+
+    fn on_cutoff(&mut self, board: &Board, stone: Stone, ply: usize, depth: u8, mv: Position) {
+        self.first.on_cutoff(board, stone, ply, depth, mv);
+        self.next.on_cutoff(board, stone, ply, depth, mv);
+    }
+
+    fn new_search(&mut self) {
+        self.first.new_search();
+        self.next.new_search();
+    }
+}
+
+/// Searches the moves that recently caused cutoffs at the same ply first.
+///
+/// Positions at the same distance from the root tend to be similar, so a
+/// move that refuted one of them is likely to refute its siblings as well.
+/// Two killer moves are kept per ply.
+///
+/// # Examples
+/// ```rust
+/// use magpie::othello::{Board, Position, Stone};
+/// use magpie::search::{KillerMoves, MoveOrderer};
+///
+/// let board = Board::standard();
+/// let mut moves: Vec<Position> = board.moves_for(Stone::Black).hot_bits().collect();
+/// let killer = moves[3];
+///
+/// let mut orderer = KillerMoves::new();
+/// orderer.on_cutoff(&board, Stone::Black, 2, 5, killer);
+/// orderer.order(&board, Stone::Black, 2, &mut moves);
+/// assert_eq!(killer, moves[0]);
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct KillerMoves {
+    killers: Vec<[Option<Position>; 2]>,
+}
+
+impl KillerMoves {
+    /// Returns an orderer without any killer moves.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl MoveOrderer for KillerMoves {
+    fn order(&mut self, _board: &Board, _stone: Stone, ply: usize, moves: &mut [Position]) {
+        let Some(killers) = self.killers.get(ply) else {
+            return;
+        };
+        let mut front = 0;
+        for killer in killers.iter().flatten() {
+            if let Some(index) = moves[front..].iter().position(|pos| pos == killer) {
+                moves[front..=front + index].rotate_right(1);
+                front += 1;
+            }
+        }
+    }
+
+    fn on_cutoff(&mut self, _board: &Board, _stone: Stone, ply: usize, _depth: u8, mv: Position) {
+        if self.killers.len() <= ply {
+            self.killers.resize(ply + 1, [None; 2]);
+        }
+        let killers = &mut self.killers[ply];
+        if killers[0] != Some(mv) {
+            killers[1] = killers[0];
+            killers[0] = Some(mv);
+        }
+    }
+
+    fn new_search(&mut self) {
+        self.killers.clear();
+    }
+}
+
+/// Searches the moves that caused the most cutoffs throughout the search
+/// first.
+///
+/// Every cutoff credits the move with the square of the remaining depth, as
+/// cutoffs close to the root save the most work. The scores are halved at
+/// the start of every search, so that old results gradually lose weight.
+///
+/// # Examples
+/// ```rust
+/// use magpie::othello::{Board, Position, Stone};
+/// use magpie::search::{HistoryHeuristic, MoveOrderer};
+///
+/// let board = Board::standard();
+/// let mut moves: Vec<Position> = board.moves_for(Stone::Black).hot_bits().collect();
+/// let (good, better) = (moves[1], moves[2]);
+///
+/// let mut orderer = HistoryHeuristic::new();
+/// orderer.on_cutoff(&board, Stone::Black, 0, 2, good);
+/// orderer.on_cutoff(&board, Stone::Black, 0, 3, better);
+/// orderer.order(&board, Stone::Black, 0, &mut moves);
+/// assert_eq!([better, good], moves[..2]);
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct HistoryHeuristic {
+    scores: [[u32; 64]; 2],
+}
+
+impl HistoryHeuristic {
+    /// Returns an orderer without any history.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            scores: [[0; 64]; 2],
+        }
+    }
+
+    /// Returns the score of the move for the specified player.
+    #[must_use]
+    pub fn score(&self, stone: Stone, mv: Position) -> u32 {
+        self.scores[stone_index(stone)][square_index(mv)]
+    }
+}
+
+impl Default for HistoryHeuristic {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MoveOrderer for HistoryHeuristic {
+    fn order(&mut self, _board: &Board, stone: Stone, _ply: usize, moves: &mut [Position]) {
+        moves.sort_by_key(|pos| Reverse(self.score(stone, *pos)));
+    }
+
+    fn on_cutoff(&mut self, _board: &Board, stone: Stone, _ply: usize, depth: u8, mv: Position) {
+        let score = &mut self.scores[stone_index(stone)][square_index(mv)];
+        *score = score.saturating_add(u32::from(depth) * u32::from(depth));
+    }
+
+    fn new_search(&mut self) {
+        for score in self.scores.iter_mut().flatten() {
+            *score /= 2;
+        }
+    }
+}
+
+/// Searches moves according to the static value of their squares, with
+/// corners first and the squares next to the corners last.
+///
+/// # Examples
+/// ```rust
+/// use magpie::othello::{Board, Position, Stone};
+/// use magpie::search::{CornersFirst, MoveOrderer};
+///
+/// let board = Board::standard();
+/// let mut moves: Vec<Position> = ["b2", "d3", "a1"]
+///     .iter()
+///     .map(|notation| Position::try_from(*notation).unwrap())
+///     .collect();
+/// CornersFirst.order(&board, Stone::Black, 0, &mut moves);
+/// assert_eq!(Position::try_from("a1").unwrap(), moves[0]);
+/// assert_eq!(Position::try_from("b2").unwrap(), moves[2]);
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+pub struct CornersFirst;
+
+/// The static value of every square, from A1 to H8.
+#[rustfmt::skip]
+const SQUARE_VALUES: [i8; 64] = [
+    100, -20,  10,   5,   5,  10, -20, 100,
+    -20, -50,  -2,  -2,  -2,  -2, -50, -20,
+     10,  -2,   1,   1,   1,   1,  -2,  10,
+      5,  -2,   1,   0,   0,   1,  -2,   5,
+      5,  -2,   1,   0,   0,   1,  -2,   5,
+     10,  -2,   1,   1,   1,   1,  -2,  10,
+    -20, -50,  -2,  -2,  -2,  -2, -50, -20,
+    100, -20,  10,   5,   5,  10, -20, 100,
+];
+
+impl MoveOrderer for CornersFirst {
+    fn order(&mut self, _board: &Board, _stone: Stone, _ply: usize, moves: &mut [Position]) {
+        moves.sort_by_key(|pos| Reverse(SQUARE_VALUES[square_index(*pos)]));
+    }
+}
+
+fn square_index(pos: Position) -> usize {
+    pos.raw().leading_zeros() as usize
+}
+
+fn stone_index(stone: Stone) -> usize {
+    match stone {
+        Stone::Black => 0,
+        Stone::White => 1,
+    }
+}
@@ -0,0 +1,196 @@
+use crate::{
+    eval::Evaluator,
+    othello::{Board, Stone},
+    search::{
+        MoveOrderer, NoOrdering, ProbCut, Replacement, SearchLimits, SearchReport,
+        SharedTranspositionTable, alphabeta::Worker,
+    },
+};
+use std::{
+    num::NonZero,
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+        mpsc,
+    },
+    thread,
+    time::Instant,
+};
+
+type OrdererFactory = dyn Fn() -> Box<dyn MoveOrderer + Send> + Send + Sync;
+
+/// A multi-threaded variant of the [`Searcher`], using Lazy SMP.
+///
+/// Every thread runs its own iterative deepening search of the same
+/// position, and the threads only communicate through a shared
+/// [`SharedTranspositionTable`]. Results found by one thread speed up the
+/// others, and half of the helper threads search one ply deeper to diversify
+/// the work. The result of the main thread is reported, and the helpers are
+/// stopped as soon as it finishes.
+///
+/// Since each thread needs its own move orderer, orderers are created by a
+/// factory.
+///
+/// [`Searcher`]: crate::search::Searcher
+/// [`SharedTranspositionTable`]: crate::search::SharedTranspositionTable
+///
+/// # Examples
+/// ```rust
+/// use magpie::{
+///     eval::DiscDifference,
+///     othello::{Board, Stone},
+///     search::{ParallelSearcher, SearchLimits},
+/// };
+///
+/// let mut searcher = ParallelSearcher::new(DiscDifference).with_threads(4);
+/// let board = Board::standard();
+/// let report = searcher.search(&board, Stone::Black, SearchLimits::depth(6));
+/// assert_eq!(6, report.depth);
+/// assert!(board.is_legal_move(Stone::Black, report.best_move.unwrap()));
+/// ```
+pub struct ParallelSearcher<E: Evaluator + Sync> {
+    evaluator: E,
+    tt: SharedTranspositionTable,
+    orderer: Arc<OrdererFactory>,
+    probcut: ProbCut,
+    threads: usize,
+}
+
+impl<E: Evaluator + Sync> ParallelSearcher<E> {
+    /// Returns a searcher using the specified evaluator.
+    ///
+    /// By default the searcher uses one thread per available CPU, a
+    /// depth-preferred transposition table with 2<sup>16</sup> entries, does
+    /// not reorder moves and does not prune forward.
+    #[must_use]
+    pub fn new(evaluator: E) -> Self {
+        Self {
+            evaluator,
+            tt: SharedTranspositionTable::new(1 << 16, Replacement::DepthPreferred),
+            orderer: Arc::new(|| Box::new(NoOrdering)),
+            probcut: ProbCut::new(),
+            threads: thread::available_parallelism().map_or(1, NonZero::get),
+        }
+    }
+
+    /// Sets the number of threads to search with, including the main
+    /// thread. At least one thread is always used.
+    #[must_use]
+    pub fn with_threads(self, threads: usize) -> Self {
+        Self {
+            threads: threads.max(1),
+            ..self
+        }
+    }
+
+    /// Replaces the transposition table used by the searcher.
+    #[must_use]
+    pub fn with_transposition_table(self, tt: SharedTranspositionTable) -> Self {
+        Self { tt, ..self }
+    }
+
+    /// Replaces the move orderer used by the searcher. The factory is called
+    /// once per thread and search.
+    #[must_use]
+    pub fn with_move_orderer<F, O>(self, factory: F) -> Self
+    where
+        F: Fn() -> O + Send + Sync + 'static,
+        O: MoveOrderer + Send + 'static,
+    {
+        Self {
+            orderer: Arc::new(move || Box::new(factory())),
+            ..self
+        }
+    }
+
+    /// Prunes positions forward with the specified ProbCut, on all threads.
+    #[must_use]
+    pub fn with_probcut(self, probcut: ProbCut) -> Self {
+        Self { probcut, ..self }
+    }
+
+    /// Returns the evaluator used by the searcher.
+    #[must_use]
+    pub fn evaluator(&self) -> &E {
+        &self.evaluator
+    }
+
+    /// Returns the transposition table used by the searcher.
+    #[must_use]
+    pub fn transposition_table(&self) -> &SharedTranspositionTable {
+        &self.tt
+    }
+
+    /// Returns the number of threads the searcher uses.
+    #[must_use]
+    pub fn threads(&self) -> usize {
+        self.threads
+    }
+
+    /// Searches the position for the best move of the specified player.
+    ///
+    /// The limits are interpreted as by [`Searcher::search`]. The number of
+    /// nodes and the statistics reported are the sums over all threads.
+    ///
+    /// [`Searcher::search`]: crate::search::Searcher::search
+    pub fn search(&mut self, board: &Board, stone: Stone, limits: SearchLimits) -> SearchReport {
+        self.search_with(board, stone, limits, &AtomicBool::new(false), None)
+    }
+
+    /// Searches like [`search`], but aborts once the stop flag is raised and
+    /// sends the report of every iteration completed by the main thread to
+    /// the channel, if any.
+    ///
+    /// [`search`]: crate::search::ParallelSearcher::search
+    pub(crate) fn search_with(
+        &mut self,
+        board: &Board,
+        stone: Stone,
+        limits: SearchLimits,
+        aborted: &AtomicBool,
+        updates: Option<mpsc::Sender<SearchReport>>,
+    ) -> SearchReport {
+        let start = Instant::now();
+        self.tt.new_search();
+        // Stops the helpers once the main thread finishes
+        let stop = AtomicBool::new(false);
+
+        thread::scope(|scope| {
+            let helpers: Vec<_> = (1..self.threads)
+                .map(|index| {
+                    let (stop, evaluator, tt) = (&stop, &self.evaluator, &self.tt);
+                    let probcut = &self.probcut;
+                    let factory = Arc::clone(&self.orderer);
+                    scope.spawn(move || {
+                        let mut orderer = factory();
+                        orderer.new_search();
+                        let mut worker = Worker::new(evaluator, tt, orderer.as_mut(), Some(stop))
+                            .with_probcut(probcut);
+                        let offset = u8::from(index % 2 == 1);
+                        let report = worker.iterate(board, stone, limits, start, offset);
+                        (report.nodes, report.stats)
+                    })
+                })
+                .collect();
+
+            let mut orderer = (self.orderer)();
+            orderer.new_search();
+            let worker = Worker::new(&self.evaluator, &self.tt, orderer.as_mut(), Some(aborted))
+                .with_probcut(&self.probcut);
+            let mut worker = match updates {
+                Some(updates) => worker.with_updates(updates),
+                None => worker,
+            };
+            let mut report = worker.iterate(board, stone, limits, start, 0);
+            stop.store(true, Ordering::Relaxed);
+
+            for helper in helpers {
+                let (nodes, stats) = helper.join().unwrap();
+                report.nodes += nodes;
+                report.stats += stats;
+            }
+            report.elapsed = start.elapsed();
+            report
+        })
+    }
+}
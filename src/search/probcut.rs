@@ -0,0 +1,156 @@
+use crate::search::WIN_SCORE;
+
+/// The default number of standard deviations by which a shallow score has
+/// to clear a bound before the deep search is skipped.
+const DEFAULT_THRESHOLD: f64 = 1.5;
+
+/// A linear model predicting the score of a search of one depth from the
+/// score of a shallower search of the same position.
+///
+/// The deep score is modeled as `slope * shallow + intercept`, with normally
+/// distributed errors of standard deviation `sigma`. Models are fitted from
+/// a set of positions with [`fit_probcut`].
+///
+/// [`fit_probcut`]: crate::tuning::fit_probcut
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ProbCutPair {
+    /// The depth whose search is predicted.
+    pub depth: u8,
+    /// The depth of the shallow search used for the prediction.
+    pub shallow: u8,
+    /// The factor by which the shallow score is multiplied.
+    pub slope: f64,
+    /// The offset added to the scaled shallow score.
+    pub intercept: f64,
+    /// The standard deviation of the prediction error.
+    pub sigma: f64,
+}
+
+impl ProbCutPair {
+    /// Returns the shallow score from which the deep score is expected to
+    /// reach `beta`, with the specified confidence in standard deviations.
+    fn upper(&self, beta: i32, threshold: f64) -> i32 {
+        self.shallow_score(f64::from(beta) + threshold * self.sigma)
+    }
+
+    /// Returns the shallow score up to which the deep score is expected to
+    /// stay at or below `alpha`, with the specified confidence in standard
+    /// deviations.
+    fn lower(&self, alpha: i32, threshold: f64) -> i32 {
+        self.shallow_score(f64::from(alpha) - threshold * self.sigma)
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    fn shallow_score(&self, deep: f64) -> i32 {
+        let bound = WIN_SCORE - 1;
+        ((deep - self.intercept) / self.slope)
+            .round()
+            .clamp(f64::from(-bound), f64::from(bound)) as i32
+    }
+}
+
+/// Forward pruning by ProbCut, which skips the search of positions whose
+/// result a shallow search predicts with enough confidence.
+///
+/// Before searching a position at a depth for which a [`ProbCutPair`] is
+/// set, the position is searched to the depth of its shallow search, with
+/// the window moved so that it decides whether the deep score is likely to
+/// fall outside of the window of the position. If it is, the position is
+/// cut without the deep search. Several pairs can be set for the same depth,
+/// which are then tried in turn as in Multi-ProbCut.
+///
+/// Cuts are only made at positions searched with a null window, so the
+/// principal variation is always searched in full, and never once a win or
+/// loss is proven. The higher the threshold, the fewer positions are cut
+/// and the fewer mistakes are made.
+///
+/// # Examples
+/// ```rust
+/// use magpie::eval::Heuristic;
+/// use magpie::othello::{Board, Stone};
+/// use magpie::search::{ProbCut, ProbCutPair, SearchLimits, Searcher};
+///
+/// let probcut = ProbCut::new().with_pair(ProbCutPair {
+///     depth: 4,
+///     shallow: 2,
+///     slope: 1.0,
+///     intercept: 0.0,
+///     sigma: 20.0,
+/// });
+/// let mut searcher = Searcher::new(Heuristic::default()).with_probcut(probcut);
+/// let board = Board::standard();
+/// let report = searcher.search(&board, Stone::Black, SearchLimits::depth(6));
+/// assert!(board.is_legal_move(Stone::Black, report.best_move.unwrap()));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProbCut {
+    pairs: Vec<ProbCutPair>,
+    threshold: f64,
+}
+
+impl ProbCut {
+    /// Returns a ProbCut without any pairs, which never cuts, with a
+    /// threshold of 1.5 standard deviations.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            pairs: Vec::new(),
+            threshold: DEFAULT_THRESHOLD,
+        }
+    }
+
+    /// Adds a pair, which is tried after the pairs already set for the same
+    /// depth. Pairs whose shallow search is not shallower than the deep one,
+    /// or whose slope is not positive, are ignored.
+    #[must_use]
+    pub fn with_pair(mut self, pair: ProbCutPair) -> Self {
+        if pair.shallow < pair.depth && pair.slope > 0.0 {
+            self.pairs.push(pair);
+        }
+        self
+    }
+
+    /// Sets the number of standard deviations by which a shallow score has
+    /// to clear a bound for a cut to be made.
+    #[must_use]
+    pub fn with_threshold(self, threshold: f64) -> Self {
+        Self { threshold, ..self }
+    }
+
+    /// Returns the pairs, in the order they are tried.
+    #[must_use]
+    pub fn pairs(&self) -> &[ProbCutPair] {
+        &self.pairs
+    }
+
+    /// Returns the threshold in standard deviations.
+    #[must_use]
+    pub fn threshold(&self) -> f64 {
+        self.threshold
+    }
+
+    /// Returns the pairs for the specified depth, along with the shallow
+    /// bounds above which a search with the window `[alpha, beta]` is cut
+    /// high, and at or below which it is cut low.
+    pub(crate) fn bounds(
+        &self,
+        depth: u8,
+        alpha: i32,
+        beta: i32,
+    ) -> impl Iterator<Item = (u8, i32, i32)> + '_ {
+        self.pairs
+            .iter()
+            .filter(move |pair| pair.depth == depth)
+            .map(move |pair| {
+                let upper = pair.upper(beta, self.threshold);
+                let lower = pair.lower(alpha, self.threshold);
+                (pair.shallow, upper, lower)
+            })
+    }
+}
+
+impl Default for ProbCut {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,316 @@
+use crate::eval::{ExternalEvaluator, Prediction};
+use crate::othello::{Board, Position, Stone};
+use crate::search::{MctsLimits, MctsReport};
+use std::time::Instant;
+
+/// A Monte Carlo tree searcher guided by an [`ExternalEvaluator`], using the
+/// PUCT selection rule popularized by AlphaZero.
+///
+/// Instead of running playouts, the leaves of the tree are evaluated by the
+/// model, whose policy decides which moves are explored first. Several
+/// leaves are collected before the model is queried, so that it can evaluate
+/// them in a single batch. Every evaluated leaf counts as one playout.
+///
+/// [`ExternalEvaluator`]: crate::eval::ExternalEvaluator
+///
+/// # Examples
+/// ```rust
+/// use magpie::eval::Prediction;
+/// use magpie::othello::{Board, Planes, Stone};
+/// use magpie::search::{MctsLimits, Puct};
+///
+/// // A model without any knowledge of the game
+/// let model = |batch: &[Planes]| -> Vec<Prediction> {
+///     batch
+///         .iter()
+///         .map(|_| Prediction { value: 0.0, policy: [1.0; 64] })
+///         .collect()
+/// };
+///
+/// let mut puct = Puct::new(model).with_batch_size(4);
+/// let board = Board::standard();
+/// let report = puct.search(&board, Stone::Black, MctsLimits::playouts(100));
+/// assert_eq!(100, report.playouts);
+/// assert!(board.is_legal_move(Stone::Black, report.best_move.unwrap()));
+/// ```
+pub struct Puct<X: ExternalEvaluator> {
+    evaluator: X,
+    exploration: f32,
+    batch_size: usize,
+}
+
+impl<X: ExternalEvaluator> Puct<X> {
+    /// Returns a searcher using the specified evaluator, an exploration
+    /// constant of 1.5 and batches of 8 positions.
+    #[must_use]
+    pub fn new(evaluator: X) -> Self {
+        Self {
+            evaluator,
+            exploration: 1.5,
+            batch_size: 8,
+        }
+    }
+
+    /// Replaces the exploration constant used when selecting nodes.
+    ///
+    /// Larger values rely more on the policy of the model and less on the
+    /// values found so far.
+    #[must_use]
+    pub fn with_exploration(self, exploration: f32) -> Self {
+        Self {
+            exploration,
+            ..self
+        }
+    }
+
+    /// Replaces the maximum number of positions evaluated at once.
+    ///
+    /// Larger batches make better use of the model, but the search is less
+    /// selective as the leaves of a batch are chosen without knowing the
+    /// values of the others. A batch size of 0 is treated as 1.
+    #[must_use]
+    pub fn with_batch_size(self, batch_size: usize) -> Self {
+        Self {
+            batch_size: batch_size.max(1),
+            ..self
+        }
+    }
+
+    /// Returns the evaluator used by the searcher.
+    #[must_use]
+    pub fn evaluator(&self) -> &X {
+        &self.evaluator
+    }
+
+    /// Searches the position for the best move of the specified player.
+    ///
+    /// # Panics
+    /// Panics if the evaluator does not return one prediction per position.
+    pub fn search(&mut self, board: &Board, stone: Stone, limits: MctsLimits) -> MctsReport {
+        let start = Instant::now();
+        let deadline = limits.time.map(|time| start + time);
+        let mut tree = vec![Node::new(*board, stone, None, None, 0.0)];
+
+        let mut playouts = 0;
+        // Nothing to search if the player has to pass
+        if !board.moves_for(stone).is_empty() {
+            while playouts < limits.playouts
+                && deadline.is_none_or(|deadline| Instant::now() < deadline)
+            {
+                let remaining = (limits.playouts - playouts) as usize;
+                playouts += self.batch(&mut tree, self.batch_size.min(remaining));
+            }
+        }
+
+        let root = &tree[0];
+        let visits: Vec<(Position, u32)> = root
+            .children
+            .iter()
+            .filter_map(|child| tree[*child].mv.map(|mv| (mv, tree[*child].visits)))
+            .collect();
+        let best = root
+            .children
+            .iter()
+            .max_by_key(|child| tree[**child].visits)
+            .map(|child| &tree[*child]);
+        MctsReport {
+            best_move: best.and_then(|node| node.mv),
+            visits,
+            win_rate: best.map_or(0.0, |node| f64::from(node.mean_value() + 1.0) / 2.0),
+            playouts,
+            elapsed: start.elapsed(),
+        }
+    }
+
+    /// Collects and evaluates up to `size` leaves, returning the number of
+    /// leaves evaluated.
+    #[allow(clippy::cast_possible_truncation)]
+    fn batch(&mut self, tree: &mut Vec<Node>, size: usize) -> u32 {
+        let mut leaves = Vec::with_capacity(size);
+        while leaves.len() < size {
+            let leaf = self.select(tree);
+            if let Some(value) = tree[leaf].outcome() {
+                // Finished games need no evaluation
+                backpropagate(tree, leaf, value);
+                leaves.push(leaf);
+            } else if leaves.contains(&leaf) {
+                // Every path leads to a leaf that is already in the batch
+                revert(tree, leaf);
+                break;
+            } else {
+                leaves.push(leaf);
+            }
+        }
+
+        let pending: Vec<usize> = leaves
+            .iter()
+            .copied()
+            .filter(|leaf| tree[*leaf].outcome().is_none())
+            .collect();
+        if !pending.is_empty() {
+            let batch: Vec<_> = pending
+                .iter()
+                .map(|leaf| tree[*leaf].board.to_planes(tree[*leaf].stone))
+                .collect();
+            let predictions = self.evaluator.predict(&batch);
+            assert_eq!(
+                pending.len(),
+                predictions.len(),
+                "evaluator must return one prediction per position"
+            );
+            for (leaf, prediction) in pending.into_iter().zip(predictions) {
+                expand(tree, leaf, &prediction);
+                backpropagate(tree, leaf, prediction.value);
+            }
+        }
+        leaves.len() as u32
+    }
+
+    /// Descends from the root to a leaf, applying a virtual loss to every
+    /// node on the way so that the rest of the batch prefers other paths.
+    fn select(&self, tree: &mut [Node]) -> usize {
+        let mut current = 0;
+        tree[current].visits += 1;
+        while !tree[current].children.is_empty() {
+            let sqrt_visits = (tree[current].visits as f32).sqrt();
+            let score = |index: usize| {
+                let node = &tree[index];
+                let exploration =
+                    self.exploration * node.prior * sqrt_visits / (1.0 + node.visits as f32);
+                node.mean_value() + exploration
+            };
+            current = tree[current]
+                .children
+                .iter()
+                .copied()
+                .max_by(|a, b| score(*a).total_cmp(&score(*b)))
+                .unwrap();
+            tree[current].visits += 1;
+            tree[current].value -= 1.0;
+        }
+        current
+    }
+}
+
+/// Adds the children of a leaf, with priors taken from the policy of the
+/// prediction and normalized over the legal moves.
+fn expand(tree: &mut Vec<Node>, leaf: usize, prediction: &Prediction) {
+    let node = &tree[leaf];
+    let (board, stone) = (node.board, node.stone);
+    let moves = board.moves_for(stone);
+    let weight = |pos: Position| prediction.policy[pos.raw().leading_zeros() as usize].max(0.0);
+    let total: f32 = moves.hot_bits().map(weight).sum();
+    #[allow(clippy::cast_precision_loss)]
+    let uniform = 1.0 / moves.count_set() as f32;
+
+    for pos in moves.hot_bits() {
+        let prior = if total > 0.0 {
+            weight(pos) / total
+        } else {
+            uniform
+        };
+        let mut next = board;
+        next.play(stone, pos);
+        let index = tree.len();
+        tree.push(Node {
+            parent: Some(leaf),
+            ..Node::new(next, stone.flip(), Some(stone), Some(pos), prior)
+        });
+        tree[leaf].children.push(index);
+    }
+}
+
+/// Propagates the value of a leaf, from the perspective of the player to
+/// move there, up to the root, replacing the virtual losses of the path.
+fn backpropagate(tree: &mut [Node], leaf: usize, value: f32) {
+    let stone = tree[leaf].stone;
+    let mut node = Some(leaf);
+    while let Some(index) = node {
+        let node_ref = &mut tree[index];
+        if let Some(mover) = node_ref.mover {
+            node_ref.value += 1.0 + if mover == stone { value } else { -value };
+        }
+        node = node_ref.parent;
+    }
+}
+
+/// Removes the virtual losses applied on the path to a leaf.
+fn revert(tree: &mut [Node], leaf: usize) {
+    let mut node = Some(leaf);
+    while let Some(index) = node {
+        let node_ref = &mut tree[index];
+        node_ref.visits -= 1;
+        if node_ref.mover.is_some() {
+            node_ref.value += 1.0;
+        }
+        node = node_ref.parent;
+    }
+}
+
+struct Node {
+    board: Board,
+    /// The player to move, after any forced pass.
+    stone: Stone,
+    /// The player who made the move leading to this node.
+    mover: Option<Stone>,
+    mv: Option<Position>,
+    parent: Option<usize>,
+    children: Vec<usize>,
+    prior: f32,
+    visits: u32,
+    /// The sum of the values found below this node, from the perspective of
+    /// the player who moved into it.
+    value: f32,
+}
+
+impl Node {
+    fn new(
+        board: Board,
+        stone: Stone,
+        mover: Option<Stone>,
+        mv: Option<Position>,
+        prior: f32,
+    ) -> Self {
+        // Forced passes are made implicitly
+        let stone = if board.moves_for(stone).is_empty() {
+            stone.flip()
+        } else {
+            stone
+        };
+        Self {
+            board,
+            stone,
+            mover,
+            mv,
+            parent: None,
+            children: Vec::new(),
+            prior,
+            visits: 0,
+            value: 0.0,
+        }
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    fn mean_value(&self) -> f32 {
+        if self.visits == 0 {
+            0.0
+        } else {
+            self.value / self.visits as f32
+        }
+    }
+
+    /// Returns the final result from the perspective of the player to move
+    /// if the game has concluded.
+    fn outcome(&self) -> Option<f32> {
+        if !self.board.moves_for(self.stone).is_empty() {
+            return None;
+        }
+        let own = self.board.bits_for(self.stone).count_set();
+        let opponent = self.board.bits_for(self.stone.flip()).count_set();
+        Some(match own.cmp(&opponent) {
+            std::cmp::Ordering::Greater => 1.0,
+            std::cmp::Ordering::Less => -1.0,
+            std::cmp::Ordering::Equal => 0.0,
+        })
+    }
+}
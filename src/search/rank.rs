@@ -0,0 +1,57 @@
+use crate::{
+    othello::{Board, Stone},
+    search::SearchReport,
+};
+
+/// Ranks every legal move of the specified player by searching each of them
+/// on its own, best first.
+///
+/// The position after each move is passed to `search` together with the
+/// depth left, which is one less than `depth` but at least one. The reports
+/// are returned from the perspective of the player to move: the best move
+/// is the move that was searched, the score is negated and the principal
+/// variation starts with the move. The other fields are left as returned by
+/// `search`.
+///
+/// Unlike [`Searcher::multipv`], any engine can be used to rank the moves,
+/// at the cost of searching every move with a full window. Returns no
+/// reports if the player has to pass.
+///
+/// [`Searcher::multipv`]: crate::search::Searcher::multipv
+///
+/// # Examples
+/// ```rust
+/// use magpie::eval::DiscDifference;
+/// use magpie::othello::{Board, Stone};
+/// use magpie::search::{SearchLimits, Searcher, rank_moves};
+///
+/// let mut searcher = Searcher::new(DiscDifference);
+/// let board = Board::standard();
+/// let reports = rank_moves(&board, Stone::Black, 3, |board, stone, depth| {
+///     searcher.search(board, stone, SearchLimits::depth(depth))
+/// });
+/// assert_eq!(4, reports.len());
+/// assert!(reports.windows(2).all(|pair| pair[0].score >= pair[1].score));
+/// assert!(reports.iter().all(|report| report.best_move == Some(report.pv[0])));
+/// ```
+pub fn rank_moves<F>(board: &Board, stone: Stone, depth: u8, mut search: F) -> Vec<SearchReport>
+where
+    F: FnMut(&Board, Stone, u8) -> SearchReport,
+{
+    let depth = depth.saturating_sub(1).max(1);
+    let mut reports: Vec<SearchReport> = board
+        .moves_for(stone)
+        .hot_bits()
+        .map(|pos| {
+            let mut next = *board;
+            next.play(stone, pos);
+            let mut report = search(&next, stone.flip(), depth);
+            report.best_move = Some(pos);
+            report.score = -report.score;
+            report.pv.insert(0, pos);
+            report
+        })
+        .collect();
+    reports.sort_by_key(|report| -report.score);
+    reports
+}
@@ -0,0 +1,172 @@
+use crate::{
+    othello::{Board, Stone},
+    search::{
+        Entry, Replacement,
+        alphabeta::Table,
+        tt::{Slot, index},
+    },
+};
+use std::sync::{
+    Mutex, MutexGuard, PoisonError,
+    atomic::{AtomicU8, Ordering},
+};
+
+/// A transposition table that can be shared between threads.
+///
+/// The table behaves exactly like a [`TranspositionTable`], but every entry
+/// is protected by its own lock so that all methods only require a shared
+/// reference. Since threads rarely access the same entry at the same time,
+/// the locks are almost never contended.
+///
+/// [`TranspositionTable`]: crate::search::TranspositionTable
+///
+/// # Examples
+/// ```rust
+/// use magpie::othello::{Board, Stone};
+/// use magpie::search::{Bound, Entry, Replacement, SharedTranspositionTable};
+///
+/// let tt = SharedTranspositionTable::new(1024, Replacement::DepthPreferred);
+/// let board = Board::standard();
+/// let entry = Entry {
+///     depth: 4,
+///     score: 2,
+///     bound: Bound::Exact,
+///     best_move: None,
+/// };
+/// std::thread::scope(|scope| {
+///     scope.spawn(|| tt.store(&board, Stone::Black, entry));
+/// });
+/// assert_eq!(Some(entry), tt.probe(&board, Stone::Black));
+/// ```
+#[derive(Debug)]
+pub struct SharedTranspositionTable {
+    slots: Vec<Mutex<Option<Slot>>>,
+    replacement: Replacement,
+    generation: AtomicU8,
+}
+
+impl SharedTranspositionTable {
+    /// Returns a table with room for at least the specified number of
+    /// entries.
+    ///
+    /// The capacity is rounded up to the nearest power of two.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::search::{Replacement, SharedTranspositionTable};
+    ///
+    /// let tt = SharedTranspositionTable::new(1000, Replacement::AlwaysReplace);
+    /// assert_eq!(1024, tt.capacity());
+    /// ```
+    #[must_use]
+    pub fn new(entries: usize, replacement: Replacement) -> Self {
+        Self {
+            slots: (0..entries.max(1).next_power_of_two())
+                .map(|_| Mutex::new(None))
+                .collect(),
+            replacement,
+            generation: AtomicU8::new(0),
+        }
+    }
+
+    /// Returns a table using at most the specified number of bytes.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::search::{Replacement, SharedTranspositionTable};
+    ///
+    /// let tt = SharedTranspositionTable::with_memory(1 << 20, Replacement::DepthPreferred);
+    /// assert!(tt.capacity() * SharedTranspositionTable::ENTRY_SIZE <= 1 << 20);
+    /// ```
+    #[must_use]
+    pub fn with_memory(bytes: usize, replacement: Replacement) -> Self {
+        let entries = (bytes / Self::ENTRY_SIZE).max(1);
+        // Round down to the nearest power of two to stay within budget
+        let entries = 1 << (usize::BITS - 1 - entries.leading_zeros());
+        Self::new(entries, replacement)
+    }
+
+    /// The number of bytes used by each entry in the table.
+    pub const ENTRY_SIZE: usize = core::mem::size_of::<Mutex<Option<Slot>>>();
+
+    /// Returns the number of entries the table can hold.
+    #[must_use]
+    pub fn capacity(&self) -> usize {
+        self.slots.len()
+    }
+
+    /// Returns the replacement policy of the table.
+    #[must_use]
+    pub fn replacement(&self) -> Replacement {
+        self.replacement
+    }
+
+    /// Looks up the entry for the specified position, if any.
+    #[must_use]
+    pub fn probe(&self, board: &Board, stone: Stone) -> Option<Entry> {
+        self.slot(board, stone)
+            .and_then(|slot| slot.entry_for(board, stone))
+    }
+
+    /// Stores an entry for the specified position, possibly replacing an
+    /// existing entry according to the replacement policy.
+    pub fn store(&self, board: &Board, stone: Stone, entry: Entry) {
+        let generation = self.generation.load(Ordering::Relaxed);
+        let slot = Slot::new(board, stone, generation, entry);
+        let mut old = self.slot(board, stone);
+        if Slot::replaced_by(old.as_ref(), &slot, self.replacement) {
+            *old = Some(slot);
+        }
+    }
+
+    /// Marks all current entries as belonging to a previous search.
+    ///
+    /// The entries can still be probed, but with the depth-preferred policy
+    /// they are replaced regardless of their depth.
+    pub fn new_search(&self) {
+        self.generation.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Removes all entries from the table.
+    pub fn clear(&self) {
+        for slot in &self.slots {
+            *lock(slot) = None;
+        }
+        self.generation.store(0, Ordering::Relaxed);
+    }
+
+    /// Returns the number of occupied entries in the table.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.slots
+            .iter()
+            .filter(|slot| lock(slot).is_some())
+            .count()
+    }
+
+    /// Returns true if and only if the table contains no entries.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.slots.iter().all(|slot| lock(slot).is_none())
+    }
+
+    fn slot(&self, board: &Board, stone: Stone) -> MutexGuard<'_, Option<Slot>> {
+        lock(&self.slots[index(board, stone, self.slots.len())])
+    }
+}
+
+impl Table for &SharedTranspositionTable {
+    fn probe(&self, board: &Board, stone: Stone) -> Option<Entry> {
+        SharedTranspositionTable::probe(self, board, stone)
+    }
+
+    fn store(&mut self, board: &Board, stone: Stone, entry: Entry) {
+        SharedTranspositionTable::store(self, board, stone, entry);
+    }
+}
+
+// A panic while holding a lock cannot leave a slot half-written, so the
+// poison flag carries no information.
+fn lock(slot: &Mutex<Option<Slot>>) -> MutexGuard<'_, Option<Slot>> {
+    slot.lock().unwrap_or_else(PoisonError::into_inner)
+}
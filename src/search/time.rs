@@ -0,0 +1,179 @@
+use std::time::Duration;
+
+/// The number of moves, by both players, during which the time spent is
+/// gradually increased.
+const OPENING_MOVES: u32 = 8;
+
+/// Describes the clock of the player to move.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct ClockState {
+    /// The time left on the clock.
+    pub remaining: Duration,
+    /// The time added to the clock after every move.
+    pub increment: Duration,
+    /// The number of moves played in the game so far, by both players.
+    pub moves_played: u32,
+    /// The number of empty squares on the board.
+    pub empties: u8,
+}
+
+/// The time a single move may take, as calculated by a [`TimeManager`].
+///
+/// The soft limit is the time the search should aim for. No new iteration
+/// is started once it has passed. If the score drops by at least
+/// `panic_drop` between two iterations, the soft limit is extended to the
+/// panic limit instead, to give the search a chance to find a way out. The
+/// hard limit is never exceeded.
+///
+/// [`TimeManager`]: crate::search::TimeManager
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct TimeBudget {
+    /// The time the search should aim for.
+    pub soft: Duration,
+    /// The extended soft limit used when the score drops.
+    pub panic: Duration,
+    /// The time the search must never exceed.
+    pub hard: Duration,
+    /// The drop in score between two iterations that triggers the panic
+    /// limit, in the units of the evaluator.
+    pub panic_drop: i32,
+}
+
+impl TimeBudget {
+    /// Returns the soft limit that applies after the score changed from
+    /// `previous` to `current` between two iterations.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::search::TimeBudget;
+    /// use std::time::Duration;
+    ///
+    /// let budget = TimeBudget {
+    ///     soft: Duration::from_secs(1),
+    ///     panic: Duration::from_secs(3),
+    ///     hard: Duration::from_secs(5),
+    ///     panic_drop: 50,
+    /// };
+    /// assert_eq!(budget.soft, budget.soft_limit(10, 0));
+    /// assert_eq!(budget.panic, budget.soft_limit(10, -40));
+    /// ```
+    #[must_use]
+    pub fn soft_limit(&self, previous: i32, current: i32) -> Duration {
+        if previous.saturating_sub(current) >= self.panic_drop {
+            self.panic
+        } else {
+            self.soft
+        }
+    }
+}
+
+/// Divides the time left on the clock between the remaining moves.
+///
+/// Since every move in Othello fills a square, the number of moves left is
+/// known up to passes. The remaining time, minus a safety margin that covers
+/// communication overhead, is split evenly between the moves the player has
+/// left, and most of the increment is added on top. The first few moves of
+/// the game are given less time, which is saved for the midgame where it
+/// matters most.
+///
+/// # Examples
+/// ```rust
+/// use magpie::search::{ClockState, TimeManager};
+/// use std::time::Duration;
+///
+/// let manager = TimeManager::new();
+/// let clock = ClockState {
+///     remaining: Duration::from_secs(60),
+///     increment: Duration::ZERO,
+///     moves_played: 20,
+///     empties: 40,
+/// };
+/// let budget = manager.budget(&clock);
+/// assert!(budget.soft <= budget.panic && budget.panic <= budget.hard);
+/// assert!(budget.hard < clock.remaining);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TimeManager {
+    safety_margin: Duration,
+    panic_factor: f32,
+    hard_factor: f32,
+    panic_drop: i32,
+}
+
+impl TimeManager {
+    /// Returns a time manager with a safety margin of 100 milliseconds, a
+    /// panic limit of twice and a hard limit of four times the soft limit.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            safety_margin: Duration::from_millis(100),
+            panic_factor: 2.0,
+            hard_factor: 4.0,
+            panic_drop: 30,
+        }
+    }
+
+    /// Sets the time kept in reserve for communication overhead.
+    #[must_use]
+    pub fn with_safety_margin(self, safety_margin: Duration) -> Self {
+        Self {
+            safety_margin,
+            ..self
+        }
+    }
+
+    /// Sets the panic limit as a multiple of the soft limit.
+    #[must_use]
+    pub fn with_panic_factor(self, panic_factor: f32) -> Self {
+        Self {
+            panic_factor,
+            ..self
+        }
+    }
+
+    /// Sets the hard limit as a multiple of the soft limit.
+    #[must_use]
+    pub fn with_hard_factor(self, hard_factor: f32) -> Self {
+        Self {
+            hard_factor,
+            ..self
+        }
+    }
+
+    /// Sets the drop in score that triggers the panic limit.
+    #[must_use]
+    pub fn with_panic_drop(self, panic_drop: i32) -> Self {
+        Self { panic_drop, ..self }
+    }
+
+    /// Calculates the time budget of the next move.
+    ///
+    /// The hard limit never exceeds half of the usable time, so a single
+    /// move can never flag the clock.
+    #[must_use]
+    pub fn budget(&self, clock: &ClockState) -> TimeBudget {
+        let usable = clock.remaining.saturating_sub(self.safety_margin);
+        // The player to move makes every other move, rounded up
+        let moves_left = u32::from(clock.empties).div_ceil(2).max(1);
+        let increment = clock.increment.mul_f32(0.75);
+        let cap = usable / 2;
+
+        let ramp = ((clock.moves_played + 1) as f32 / OPENING_MOVES as f32).min(1.0);
+
+        let soft = ((usable / moves_left).mul_f32(ramp) + increment).min(cap);
+        let panic = soft.mul_f32(self.panic_factor).min(cap);
+        let hard = soft.mul_f32(self.hard_factor).min(cap);
+        TimeBudget {
+            soft,
+            panic: panic.max(soft),
+            hard: hard.max(panic),
+            panic_drop: self.panic_drop,
+        }
+    }
+}
+
+impl Default for TimeManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,232 @@
+use crate::othello::{Board, Position, Stone};
+use alloc::{vec, vec::Vec};
+
+/// Describes how the score of an [`Entry`] relates to the true score of the
+/// position.
+///
+/// [`Entry`]: crate::search::Entry
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum Bound {
+    /// The score is exact.
+    Exact,
+    /// The true score is at least as high as the stored score.
+    Lower,
+    /// The true score is at most as high as the stored score.
+    Upper,
+}
+
+/// The result of a previous search of a position.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct Entry {
+    /// The remaining depth the position was searched to.
+    pub depth: u8,
+    /// The score of the position from the perspective of the player to move.
+    pub score: i32,
+    /// How the score relates to the true score of the position.
+    pub bound: Bound,
+    /// The best move found, if any.
+    pub best_move: Option<Position>,
+}
+
+/// Decides which entry is kept when two positions map to the same slot.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum Replacement {
+    /// Keeps the entry searched to the greatest depth, unless it stems from
+    /// a previous search.
+    DepthPreferred,
+    /// Always replaces the old entry.
+    AlwaysReplace,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Slot {
+    black: u64,
+    white: u64,
+    stone: Stone,
+    generation: u8,
+    entry: Entry,
+}
+
+impl Slot {
+    pub(crate) fn new(board: &Board, stone: Stone, generation: u8, entry: Entry) -> Self {
+        let (black, white) = raw(board);
+        Self {
+            black,
+            white,
+            stone,
+            generation,
+            entry,
+        }
+    }
+
+    /// Returns the entry if the slot belongs to the specified position.
+    pub(crate) fn entry_for(&self, board: &Board, stone: Stone) -> Option<Entry> {
+        let (black, white) = raw(board);
+        (self.black == black && self.white == white && self.stone == stone).then_some(self.entry)
+    }
+
+    /// Decides whether the new slot should replace the old one.
+    pub(crate) fn replaced_by(old: Option<&Slot>, new: &Slot, replacement: Replacement) -> bool {
+        match (replacement, old) {
+            (Replacement::AlwaysReplace, _) | (_, None) => true,
+            (Replacement::DepthPreferred, Some(old)) => {
+                let same =
+                    old.black == new.black && old.white == new.white && old.stone == new.stone;
+                same || old.generation != new.generation || new.entry.depth >= old.entry.depth
+            }
+        }
+    }
+}
+
+/// A fixed-size hash table mapping positions to the results of previous
+/// searches.
+///
+/// Each position is stored together with the player to move, and lookups
+/// verify the full position so that hash collisions never return entries
+/// belonging to other positions.
+///
+/// # Examples
+/// ```rust
+/// use magpie::othello::{Board, Stone};
+/// use magpie::search::{Bound, Entry, Replacement, TranspositionTable};
+///
+/// let mut tt = TranspositionTable::new(1024, Replacement::DepthPreferred);
+/// let board = Board::standard();
+/// let entry = Entry {
+///     depth: 4,
+///     score: 2,
+///     bound: Bound::Exact,
+///     best_move: None,
+/// };
+/// tt.store(&board, Stone::Black, entry);
+/// assert_eq!(Some(entry), tt.probe(&board, Stone::Black));
+/// assert_eq!(None, tt.probe(&board, Stone::White));
+/// ```
+#[derive(Clone, Debug)]
+pub struct TranspositionTable {
+    slots: Vec<Option<Slot>>,
+    replacement: Replacement,
+    generation: u8,
+}
+
+impl TranspositionTable {
+    /// Returns a table with room for at least the specified number of
+    /// entries.
+    ///
+    /// The capacity is rounded up to the nearest power of two.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::search::{Replacement, TranspositionTable};
+    ///
+    /// let tt = TranspositionTable::new(1000, Replacement::AlwaysReplace);
+    /// assert_eq!(1024, tt.capacity());
+    /// ```
+    #[must_use]
+    pub fn new(entries: usize, replacement: Replacement) -> Self {
+        Self {
+            slots: vec![None; entries.max(1).next_power_of_two()],
+            replacement,
+            generation: 0,
+        }
+    }
+
+    /// Returns a table using at most the specified number of bytes.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::search::{Replacement, TranspositionTable};
+    ///
+    /// let tt = TranspositionTable::with_memory(1 << 20, Replacement::DepthPreferred);
+    /// assert!(tt.capacity() * TranspositionTable::ENTRY_SIZE <= 1 << 20);
+    /// ```
+    #[must_use]
+    pub fn with_memory(bytes: usize, replacement: Replacement) -> Self {
+        let entries = (bytes / Self::ENTRY_SIZE).max(1);
+        // Round down to the nearest power of two to stay within budget
+        let entries = 1 << (usize::BITS - 1 - entries.leading_zeros());
+        Self::new(entries, replacement)
+    }
+
+    /// The number of bytes used by each entry in the table.
+    pub const ENTRY_SIZE: usize = core::mem::size_of::<Option<Slot>>();
+
+    /// Returns the number of entries the table can hold.
+    #[must_use]
+    pub fn capacity(&self) -> usize {
+        self.slots.len()
+    }
+
+    /// Returns the replacement policy of the table.
+    #[must_use]
+    pub fn replacement(&self) -> Replacement {
+        self.replacement
+    }
+
+    /// Looks up the entry for the specified position, if any.
+    #[must_use]
+    pub fn probe(&self, board: &Board, stone: Stone) -> Option<Entry> {
+        self.slots[index(board, stone, self.slots.len())]
+            .and_then(|slot| slot.entry_for(board, stone))
+    }
+
+    /// Stores an entry for the specified position, possibly replacing an
+    /// existing entry according to the replacement policy.
+    pub fn store(&mut self, board: &Board, stone: Stone, entry: Entry) {
+        let index = index(board, stone, self.slots.len());
+        let slot = Slot::new(board, stone, self.generation, entry);
+        if Slot::replaced_by(self.slots[index].as_ref(), &slot, self.replacement) {
+            self.slots[index] = Some(slot);
+        }
+    }
+
+    /// Marks all current entries as belonging to a previous search.
+    ///
+    /// The entries can still be probed, but with the depth-preferred policy
+    /// they are replaced regardless of their depth.
+    pub fn new_search(&mut self) {
+        self.generation = self.generation.wrapping_add(1);
+    }
+
+    /// Removes all entries from the table.
+    pub fn clear(&mut self) {
+        self.slots.fill(None);
+        self.generation = 0;
+    }
+
+    /// Returns the number of occupied entries in the table.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.slots.iter().filter(|slot| slot.is_some()).count()
+    }
+
+    /// Returns true if and only if the table contains no entries.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.slots.iter().all(Option::is_none)
+    }
+}
+
+/// Returns the slot of the position in a table with the specified number of
+/// slots, which must be a power of two.
+pub(crate) fn index(board: &Board, stone: Stone, slots: usize) -> usize {
+    let (black, white) = raw(board);
+    (hash(black, white, stone) as usize) & (slots - 1)
+}
+
+fn raw(board: &Board) -> (u64, u64) {
+    (
+        board.bits_for(Stone::Black).raw(),
+        board.bits_for(Stone::White).raw(),
+    )
+}
+
+// Multiplicative mixing that spreads the bits of both bitboards over the
+// whole hash. The constants are odd and have no particular meaning.
+pub(crate) fn hash(black: u64, white: u64, stone: Stone) -> u64 {
+    let mut h = black.wrapping_mul(0x9e37_79b9_7f4a_7c15);
+    h ^= white.rotate_left(29).wrapping_mul(0xc2b2_ae3d_27d4_eb4f);
+    h ^= u64::from(stone == Stone::White);
+    h ^= h >> 32;
+    h.wrapping_mul(0x1656_67b1_9e37_79f9) ^ (h >> 29)
+}
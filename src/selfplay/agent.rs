@@ -0,0 +1,138 @@
+use crate::{
+    eval::Evaluator,
+    othello::{Bitboard, Board, Position, Stone},
+    search::{ClockState, RandomRollout, RolloutPolicy, SearchLimits, Searcher, TimeManager},
+};
+
+/// A player that chooses moves during self-play.
+///
+/// Closures with the same signature as [`choose`] implement this trait as
+/// well.
+///
+/// [`choose`]: crate::selfplay::Agent::choose
+///
+/// # Examples
+/// ```rust
+/// use magpie::othello::{Bitboard, Board, Position, Stone};
+/// use magpie::selfplay::Agent;
+///
+/// // Always plays the last legal move
+/// let mut agent = |_: &Board, _: Stone, moves: Bitboard| moves.hot_bits().last().unwrap();
+///
+/// let board = Board::standard();
+/// let pos = agent.choose(&board, Stone::Black, board.moves_for(Stone::Black));
+/// assert!(board.is_legal_move(Stone::Black, pos));
+/// ```
+pub trait Agent {
+    /// Chooses one of the legal moves, which are guaranteed to be non-empty.
+    fn choose(&mut self, board: &Board, stone: Stone, moves: Bitboard) -> Position;
+
+    /// Chooses one of the legal moves while playing on the specified clock.
+    ///
+    /// Agents that do not manage their time ignore the clock, which is what
+    /// the default implementation does.
+    fn choose_timed(
+        &mut self,
+        board: &Board,
+        stone: Stone,
+        moves: Bitboard,
+        clock: &ClockState,
+    ) -> Position {
+        let _ = clock;
+        self.choose(board, stone, moves)
+    }
+}
+
+impl<F: FnMut(&Board, Stone, Bitboard) -> Position> Agent for F {
+    fn choose(&mut self, board: &Board, stone: Stone, moves: Bitboard) -> Position {
+        self(board, stone, moves)
+    }
+}
+
+impl Agent for RandomRollout {
+    fn choose(&mut self, board: &Board, stone: Stone, moves: Bitboard) -> Position {
+        RolloutPolicy::choose(self, board, stone, moves)
+    }
+}
+
+/// An agent that plays the best move found by a [`Searcher`].
+///
+/// [`Searcher`]: crate::search::Searcher
+///
+/// # Examples
+/// ```rust
+/// use magpie::eval::Heuristic;
+/// use magpie::othello::{Board, Stone};
+/// use magpie::search::{SearchLimits, Searcher};
+/// use magpie::selfplay::{Agent, SearchAgent};
+///
+/// let mut agent = SearchAgent::new(Searcher::new(Heuristic::default()), SearchLimits::depth(3));
+/// let board = Board::standard();
+/// let pos = agent.choose(&board, Stone::Black, board.moves_for(Stone::Black));
+/// assert!(board.is_legal_move(Stone::Black, pos));
+/// ```
+pub struct SearchAgent<E: Evaluator> {
+    searcher: Searcher<E>,
+    limits: SearchLimits,
+}
+
+impl<E: Evaluator> SearchAgent<E> {
+    /// Returns an agent that searches every move within the specified
+    /// limits.
+    #[must_use]
+    pub fn new(searcher: Searcher<E>, limits: SearchLimits) -> Self {
+        Self { searcher, limits }
+    }
+
+    /// Returns the searcher used by the agent.
+    #[must_use]
+    pub fn searcher(&self) -> &Searcher<E> {
+        &self.searcher
+    }
+
+    /// Returns the limits every search is run with.
+    #[must_use]
+    pub fn limits(&self) -> SearchLimits {
+        self.limits
+    }
+
+    fn search(
+        &mut self,
+        board: &Board,
+        stone: Stone,
+        moves: Bitboard,
+        limits: SearchLimits,
+    ) -> Position {
+        self.searcher
+            .search(board, stone, limits)
+            .best_move
+            .filter(|pos| moves & *pos != 0)
+            .unwrap_or_else(|| moves.hot_bits().next().unwrap())
+    }
+}
+
+impl<E: Evaluator> Agent for SearchAgent<E> {
+    fn choose(&mut self, board: &Board, stone: Stone, moves: Bitboard) -> Position {
+        self.search(board, stone, moves, self.limits)
+    }
+
+    /// Searches within the budget a [`TimeManager`] allots to the move, but
+    /// no deeper and no longer than the depth and node budget of the limits
+    /// of the agent.
+    ///
+    /// [`TimeManager`]: crate::search::TimeManager
+    fn choose_timed(
+        &mut self,
+        board: &Board,
+        stone: Stone,
+        moves: Bitboard,
+        clock: &ClockState,
+    ) -> Position {
+        let limits = SearchLimits {
+            depth: self.limits.depth,
+            nodes: self.limits.nodes,
+            ..SearchLimits::budget(TimeManager::new().budget(clock))
+        };
+        self.search(board, stone, moves, limits)
+    }
+}
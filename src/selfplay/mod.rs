@@ -0,0 +1,35 @@
+//! Plays games between two agents and records every move they make.
+//!
+//! Self-play is how evaluation functions are usually tuned and trained: the
+//! records pair every position with the move that was played and the final
+//! outcome of the game. Games can be spread over several threads, while the
+//! records are all delivered to a single [`Sink`] on the calling thread.
+//!
+//! # Examples
+//! ```rust
+//! use magpie::eval::DiscDifference;
+//! use magpie::search::{RandomRollout, SearchLimits, Searcher};
+//! use magpie::selfplay::{Record, SearchAgent, SelfPlay};
+//!
+//! let selfplay = SelfPlay::new(
+//!     || SearchAgent::new(Searcher::new(DiscDifference), SearchLimits::depth(1)),
+//!     || RandomRollout::new(3),
+//! )
+//! .with_games(4)
+//! .with_threads(2);
+//!
+//! let mut records: Vec<Record> = Vec::new();
+//! let summary = selfplay.run(&mut records);
+//! assert_eq!(4, summary.games());
+//! assert!(records.iter().all(|record| record.game < 4));
+//! ```
+//!
+//! [`Sink`]: crate::selfplay::Sink
+
+/// The players taking part in self-play.
+mod agent;
+/// Runs the games and delivers the records.
+mod runner;
+
+pub use agent::{Agent, SearchAgent};
+pub use runner::{Record, SelfPlay, Sink, Summary};
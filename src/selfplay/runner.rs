@@ -0,0 +1,274 @@
+use crate::{
+    othello::{Board, Position, Scoring, Stone},
+    search::{RandomRollout, RolloutPolicy},
+    selfplay::Agent,
+};
+use std::{
+    num::NonZeroUsize,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc,
+    },
+    thread,
+};
+
+/// A single move made by one of the agents during self-play.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct Record {
+    /// The index of the game the move was made in.
+    pub game: usize,
+    /// The board before the move was made.
+    pub board: Board,
+    /// The player who made the move.
+    pub stone: Stone,
+    /// The move that was made.
+    pub mv: Position,
+    /// The final score difference of the game from the perspective of the
+    /// player who made the move, which is positive if they went on to win.
+    ///
+    /// The difference is calculated with the [`Scoring`] convention of the
+    /// run.
+    ///
+    /// [`Scoring`]: crate::othello::Scoring
+    pub score: i8,
+}
+
+/// Receives the records produced during self-play.
+///
+/// Records of the same game are delivered together and in the order the
+/// moves were made, but games may finish in any order when several threads
+/// are used. `Vec<Record>` and closures taking a [`Record`] implement this
+/// trait as well.
+///
+/// [`Record`]: crate::selfplay::Record
+pub trait Sink {
+    /// Receives a single record.
+    fn record(&mut self, record: Record);
+}
+
+impl Sink for Vec<Record> {
+    fn record(&mut self, record: Record) {
+        self.push(record);
+    }
+}
+
+impl<F: FnMut(Record)> Sink for F {
+    fn record(&mut self, record: Record) {
+        self(record);
+    }
+}
+
+/// The results of a self-play run, from the perspective of the agents
+/// rather than the colors they played.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+pub struct Summary {
+    /// The number of games won by the first agent.
+    pub first_wins: usize,
+    /// The number of games won by the second agent.
+    pub second_wins: usize,
+    /// The number of drawn games.
+    pub draws: usize,
+}
+
+impl Summary {
+    /// Returns the total number of games played.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::selfplay::Summary;
+    ///
+    /// let summary = Summary { first_wins: 3, second_wins: 1, draws: 1 };
+    /// assert_eq!(5, summary.games());
+    /// ```
+    #[must_use]
+    pub fn games(&self) -> usize {
+        self.first_wins + self.second_wins + self.draws
+    }
+}
+
+/// Plays a number of games between two agents.
+///
+/// Agents are created through factories, once for every thread, so that
+/// they do not have to be shared between threads. By default, the agents
+/// take turns playing black, and every game starts from the standard
+/// opening position.
+///
+/// See the [module documentation] for an example.
+///
+/// [module documentation]: crate::selfplay
+pub struct SelfPlay<F, S> {
+    first: F,
+    second: S,
+    games: usize,
+    threads: NonZeroUsize,
+    alternate_colors: bool,
+    opening_plies: u8,
+    scoring: Scoring,
+}
+
+impl<F, S, A, B> SelfPlay<F, S>
+where
+    F: Fn() -> A + Sync,
+    S: Fn() -> B + Sync,
+    A: Agent,
+    B: Agent,
+{
+    /// Returns a run of a single game on a single thread, where the first
+    /// agent plays black.
+    #[must_use]
+    pub fn new(first: F, second: S) -> Self {
+        Self {
+            first,
+            second,
+            games: 1,
+            threads: NonZeroUsize::MIN,
+            alternate_colors: true,
+            opening_plies: 0,
+            scoring: Scoring::DiscCount,
+        }
+    }
+
+    /// Sets the number of games to play.
+    #[must_use]
+    pub fn with_games(self, games: usize) -> Self {
+        Self { games, ..self }
+    }
+
+    /// Sets the number of threads to play games on.
+    ///
+    /// # Panics
+    /// Panics if the number of threads is zero.
+    #[must_use]
+    pub fn with_threads(self, threads: usize) -> Self {
+        Self {
+            threads: NonZeroUsize::new(threads).expect("at least one thread is required"),
+            ..self
+        }
+    }
+
+    /// Sets whether or not the agents take turns playing black. If not, the
+    /// first agent always plays black.
+    #[must_use]
+    pub fn with_alternating_colors(self, alternate_colors: bool) -> Self {
+        Self {
+            alternate_colors,
+            ..self
+        }
+    }
+
+    /// Starts every game with the specified number of random moves, so that
+    /// deterministic agents do not play the same game over and over.
+    ///
+    /// The random moves are seeded with the index of the game, which makes
+    /// runs reproducible. They are not recorded.
+    #[must_use]
+    pub fn with_random_opening(self, plies: u8) -> Self {
+        Self {
+            opening_plies: plies,
+            ..self
+        }
+    }
+
+    /// Sets the convention used to score finished games, which decides the
+    /// scores of the records.
+    #[must_use]
+    pub fn with_scoring(self, scoring: Scoring) -> Self {
+        Self { scoring, ..self }
+    }
+
+    /// Plays all games and delivers their records to the sink.
+    ///
+    /// # Panics
+    /// Panics if an agent chooses an illegal move.
+    pub fn run(&self, sink: &mut impl Sink) -> Summary {
+        let next_game = AtomicUsize::new(0);
+        let (sender, receiver) = mpsc::channel();
+        thread::scope(|scope| {
+            for _ in 0..self.threads.get() {
+                let sender = sender.clone();
+                let next_game = &next_game;
+                scope.spawn(move || {
+                    let mut first = (self.first)();
+                    let mut second = (self.second)();
+                    loop {
+                        let game = next_game.fetch_add(1, Ordering::Relaxed);
+                        if game >= self.games {
+                            break;
+                        }
+                        let played = self.play(game, &mut first, &mut second);
+                        if sender.send(played).is_err() {
+                            break;
+                        }
+                    }
+                });
+            }
+            drop(sender);
+
+            let mut summary = Summary::default();
+            for (records, first_score) in receiver {
+                match first_score.signum() {
+                    1 => summary.first_wins += 1,
+                    -1 => summary.second_wins += 1,
+                    _ => summary.draws += 1,
+                }
+                for record in records {
+                    sink.record(record);
+                }
+            }
+            summary
+        })
+    }
+
+    /// Plays a single game, returning its records and the final score
+    /// difference from the perspective of the first agent.
+    fn play(&self, game: usize, first: &mut A, second: &mut B) -> (Vec<Record>, i8) {
+        let first_stone = if self.alternate_colors && game % 2 == 1 {
+            Stone::White
+        } else {
+            Stone::Black
+        };
+        let mut opening = RandomRollout::new(game as u64);
+        let mut board = Board::standard();
+        let mut stone = Stone::Black;
+        let mut records = Vec::new();
+        let mut plies = 0;
+
+        loop {
+            let moves = board.moves_for(stone);
+            if moves.is_empty() {
+                if board.moves_for(stone.flip()).is_empty() {
+                    break;
+                }
+                stone = stone.flip();
+                continue;
+            }
+            let mv = if plies < self.opening_plies {
+                RolloutPolicy::choose(&mut opening, &board, stone, moves)
+            } else {
+                let mv = if stone == first_stone {
+                    first.choose(&board, stone, moves)
+                } else {
+                    second.choose(&board, stone, moves)
+                };
+                assert!(moves & mv != 0, "agent chose the illegal move {mv}");
+                records.push(Record {
+                    game,
+                    board,
+                    stone,
+                    mv,
+                    score: 0,
+                });
+                mv
+            };
+            board.play(stone, mv);
+            stone = stone.flip();
+            plies = plies.saturating_add(1);
+        }
+
+        let score = |stone: Stone| self.scoring.difference(&board, stone);
+        for record in &mut records {
+            record.score = score(record.stone);
+        }
+        (records, score(first_stone))
+    }
+}
@@ -0,0 +1,171 @@
+use crate::{
+    othello::{SizedBoard, Stone},
+    search::Wdl,
+};
+
+/// The size of the header, in bytes.
+pub(crate) const HEADER_SIZE: usize = 16;
+
+/// The size of every entry, in bytes.
+pub(crate) const ENTRY_SIZE: usize = 8;
+
+/// The magic bytes every tablebase starts with.
+const MAGIC: &[u8; 4] = b"MGTB";
+
+/// The version of the format written by this library.
+const VERSION: u8 = 1;
+
+/// The header at the start of every tablebase file.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub(crate) struct Header {
+    pub(crate) size: u8,
+    pub(crate) max_empties: u8,
+    pub(crate) count: u64,
+}
+
+impl Header {
+    /// Parses the 16 byte header, checking that the tablebase is for boards
+    /// of the expected size.
+    pub(crate) fn parse(bytes: &[u8], size: usize) -> Result<Self, TablebaseError> {
+        let bytes = bytes.get(..HEADER_SIZE).ok_or(TablebaseError::Truncated)?;
+        if &bytes[..4] != MAGIC {
+            return Err(TablebaseError::InvalidMagic);
+        }
+        if bytes[4] != VERSION {
+            return Err(TablebaseError::UnsupportedVersion(bytes[4]));
+        }
+        if usize::from(bytes[5]) != size {
+            return Err(TablebaseError::SizeMismatch(bytes[5]));
+        }
+        Ok(Self {
+            size: bytes[5],
+            max_empties: bytes[6],
+            count: u64::from_le_bytes(bytes[8..16].try_into().unwrap()),
+        })
+    }
+
+    /// Encodes the header as 16 bytes.
+    pub(crate) fn to_bytes(self) -> [u8; HEADER_SIZE] {
+        let mut bytes = [0; HEADER_SIZE];
+        bytes[..4].copy_from_slice(MAGIC);
+        bytes[4] = VERSION;
+        bytes[5] = self.size;
+        bytes[6] = self.max_empties;
+        bytes[8..].copy_from_slice(&self.count.to_le_bytes());
+        bytes
+    }
+}
+
+/// Returns the index of the position, which is the same for all positions
+/// that are equal up to symmetry and for the position with the colors
+/// swapped and the other player to move.
+///
+/// Every square is a digit in base 3: 0 if empty, 1 if occupied by the
+/// player to move and 2 otherwise. The index is the smallest number formed
+/// by these digits under any of the 8 symmetries of the board.
+pub(crate) fn index<const N: usize>(board: &SizedBoard<N>, stone: Stone) -> u64 {
+    let own = board.bits_for(stone);
+    let opponent = board.bits_for(stone.flip());
+    let last = N - 1;
+    // Every symmetry optionally transposes the board, then mirrors it
+    // vertically and horizontally
+    (0..8)
+        .map(|symmetry| {
+            (0..N * N).fold(0, |index, square| {
+                let (mut rank, mut file) = (square / N, square % N);
+                if symmetry & 4 != 0 {
+                    (rank, file) = (file, rank);
+                }
+                if symmetry & 2 != 0 {
+                    rank = last - rank;
+                }
+                if symmetry & 1 != 0 {
+                    file = last - file;
+                }
+                let bit = 1 << (rank * N + file);
+                let digit = if own & bit != 0 {
+                    1
+                } else if opponent & bit != 0 {
+                    2
+                } else {
+                    0
+                };
+                index * 3 + digit
+            })
+        })
+        .min()
+        .unwrap()
+}
+
+/// Packs the index of a position and its outcome into an entry.
+pub(crate) fn entry(index: u64, wdl: Wdl) -> u64 {
+    index * 3 + wdl as u64
+}
+
+/// Returns the index of the position an entry is for.
+pub(crate) fn entry_index(entry: u64) -> u64 {
+    entry / 3
+}
+
+/// Returns the outcome stored in an entry.
+pub(crate) fn entry_wdl(entry: u64) -> Wdl {
+    match entry % 3 {
+        0 => Wdl::Loss,
+        1 => Wdl::Draw,
+        _ => Wdl::Win,
+    }
+}
+
+/// This enum represents errors that may occur when reading or writing
+/// tablebases.
+#[derive(Debug)]
+pub enum TablebaseError {
+    /// Indicates that reading or writing the tablebase failed.
+    Io(std::io::Error),
+    /// Indicates that the data does not start with the magic bytes of a
+    /// tablebase.
+    InvalidMagic,
+    /// Indicates that the tablebase was written in an unsupported version of
+    /// the format.
+    UnsupportedVersion(u8),
+    /// Indicates that the tablebase is for boards of another size. Carries
+    /// the size of the boards of the tablebase.
+    SizeMismatch(u8),
+    /// Indicates that the tablebase ended before all entries could be read.
+    Truncated,
+}
+
+impl From<std::io::Error> for TablebaseError {
+    fn from(error: std::io::Error) -> Self {
+        if error.kind() == std::io::ErrorKind::UnexpectedEof {
+            TablebaseError::Truncated
+        } else {
+            TablebaseError::Io(error)
+        }
+    }
+}
+
+impl std::fmt::Display for TablebaseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TablebaseError::Io(error) => write!(f, "i/o error: {error}"),
+            TablebaseError::InvalidMagic => f.write_str("not a tablebase"),
+            TablebaseError::UnsupportedVersion(version) => {
+                write!(f, "unsupported tablebase version {version}")
+            }
+            TablebaseError::SizeMismatch(size) => {
+                write!(f, "tablebase is for {size}x{size} boards")
+            }
+            TablebaseError::Truncated => f.write_str("unexpected end of tablebase"),
+        }
+    }
+}
+
+impl std::error::Error for TablebaseError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            TablebaseError::Io(error) => Some(error),
+            _ => None,
+        }
+    }
+}
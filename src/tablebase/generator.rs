@@ -0,0 +1,141 @@
+use crate::{
+    othello::{SizedBoard, Stone},
+    search::Wdl,
+    tablebase::{
+        Tablebase,
+        format::{entry, index},
+    },
+};
+use std::collections::{HashMap, HashSet};
+
+/// Generates a [`Tablebase`] by solving every position reachable from a
+/// starting position with at most a given number of empty squares.
+///
+/// The generator walks the game tree forward from the starting position,
+/// visiting every position only once up to symmetry. Once a position has
+/// few enough empty squares, it is solved exhaustively, and the outcome of
+/// it and of every position after it is stored. No moves are pruned, so the
+/// tablebase answers for every position of the endgame that can arise, not
+/// just for those on the lines of perfect play.
+///
+/// Positions with many empty squares take a long time to solve, so a limit
+/// of about 12 empty squares is practical for 6x6 boards, while 4x4 boards
+/// can be solved entirely.
+///
+/// # Examples
+/// ```rust
+/// use magpie::othello::{SizedBoard, Stone};
+/// use magpie::search::Wdl;
+/// use magpie::tablebase::TablebaseGenerator;
+///
+/// // 4x4 Othello is a win for the second player
+/// let board = SizedBoard::<4>::standard();
+/// let tablebase = TablebaseGenerator::new(12).generate(&board, Stone::Black);
+/// assert_eq!(Some(Wdl::Loss), tablebase.probe(&board, Stone::Black));
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct TablebaseGenerator {
+    max_empties: u8,
+}
+
+impl TablebaseGenerator {
+    /// Returns a generator that stores positions with at most the specified
+    /// number of empty squares.
+    #[must_use]
+    pub fn new(max_empties: u8) -> Self {
+        Self { max_empties }
+    }
+
+    /// Generates the tablebase of all positions reachable from the position
+    /// with the specified player to move.
+    #[must_use]
+    pub fn generate<const N: usize>(&self, board: &SizedBoard<N>, stone: Stone) -> Tablebase<N> {
+        let mut generation = Generation {
+            max_empties: self.max_empties,
+            visited: HashSet::new(),
+            solved: HashMap::new(),
+        };
+        generation.walk(board, stone);
+        let entries = generation
+            .solved
+            .into_iter()
+            .map(|(index, wdl)| entry(index, wdl))
+            .collect();
+        Tablebase::from_entries(self.max_empties, entries)
+    }
+}
+
+struct Generation {
+    max_empties: u8,
+    /// The positions with too many empty squares that were already walked.
+    visited: HashSet<u64>,
+    /// The outcomes of the positions solved so far, by index.
+    solved: HashMap<u64, Wdl>,
+}
+
+impl Generation {
+    fn walk<const N: usize>(&mut self, board: &SizedBoard<N>, stone: Stone) {
+        if board.empty_squares().count_ones() <= u32::from(self.max_empties) {
+            self.solve(board, stone);
+            return;
+        }
+        if !self.visited.insert(index(board, stone)) {
+            return;
+        }
+        let moves = board.moves_for(stone);
+        if moves == 0 {
+            if board.moves_for(stone.flip()) != 0 {
+                self.walk(board, stone.flip());
+            }
+            return;
+        }
+        for square in squares(moves) {
+            let mut next = *board;
+            next.play(stone, square)
+                .expect("legal moves are on the board");
+            self.walk(&next, stone.flip());
+        }
+    }
+
+    fn solve<const N: usize>(&mut self, board: &SizedBoard<N>, stone: Stone) -> Wdl {
+        let index = index(board, stone);
+        if let Some(wdl) = self.solved.get(&index) {
+            return *wdl;
+        }
+        let moves = board.moves_for(stone);
+        let wdl = if moves != 0 {
+            squares(moves)
+                .map(|square| {
+                    let mut next = *board;
+                    next.play(stone, square)
+                        .expect("legal moves are on the board");
+                    self.solve(&next, stone.flip()).flip()
+                })
+                .max()
+                .unwrap()
+        } else if board.moves_for(stone.flip()) != 0 {
+            self.solve(board, stone.flip()).flip()
+        } else {
+            let own = board.bits_for(stone).count_ones();
+            let opponent = board.bits_for(stone.flip()).count_ones();
+            match own.cmp(&opponent) {
+                std::cmp::Ordering::Less => Wdl::Loss,
+                std::cmp::Ordering::Equal => Wdl::Draw,
+                std::cmp::Ordering::Greater => Wdl::Win,
+            }
+        };
+        self.solved.insert(index, wdl);
+        wdl
+    }
+}
+
+/// Returns the indices of the set bits.
+fn squares(mut bits: u128) -> impl Iterator<Item = usize> {
+    std::iter::from_fn(move || {
+        (bits != 0).then(|| {
+            let square = bits.trailing_zeros() as usize;
+            bits &= bits - 1;
+            square
+        })
+    })
+}
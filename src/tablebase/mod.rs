@@ -0,0 +1,70 @@
+//! Perfect play tablebases for small Othello variants.
+//!
+//! A [`Tablebase`] stores whether positions on a 4x4 or 6x6 board are won,
+//! drawn or lost under perfect play. It is generated by a
+//! [`TablebaseGenerator`], which solves every position with few enough empty
+//! squares that is reachable from a starting position. Since the outcomes
+//! are exact, tablebases are useful for validating searchers and for
+//! teaching endgame play.
+//!
+//! Positions are stored by their index, which is the same for positions
+//! that are equal up to any of the 8 symmetries of the board. It is also the
+//! same for a position and the one with the colors swapped and the other
+//! player to move, so that every entry covers up to 16 positions.
+//!
+//! # Format
+//!
+//! All integers are stored in little-endian byte order. A tablebase starts
+//! with a 16 byte header:
+//!
+//! | Offset | Size | Contents                                        |
+//! |--------|------|-------------------------------------------------|
+//! | 0      | 4    | The magic bytes `MGTB`                          |
+//! | 4      | 1    | The format version, currently 1                 |
+//! | 5      | 1    | The size of the board, 4 or 6                   |
+//! | 6      | 1    | The greatest number of empty squares covered    |
+//! | 7      | 1    | Reserved, always 0                              |
+//! | 8      | 8    | The number of entries                           |
+//!
+//! The header is followed by the entries in ascending order, every entry
+//! being a 64-bit integer. An entry is `index * 3 + outcome`, where the
+//! outcome is 0 for a loss, 1 for a draw and 2 for a win of the player to
+//! move. The index reads the squares as a number in base 3, with 0 for an
+//! empty square, 1 for a stone of the player to move and 2 for a stone of
+//! the opponent, where A1 is the most significant digit. Of all symmetric
+//! variants of the position, the smallest index is used.
+//!
+//! Since the entries are sorted and have the same size, tablebases can be
+//! queried with a binary search directly on the bytes of a memory-mapped
+//! file, which [`TablebaseView`] does.
+//!
+//! [`Tablebase`]: crate::tablebase::Tablebase
+//! [`TablebaseGenerator`]: crate::tablebase::TablebaseGenerator
+//! [`TablebaseView`]: crate::tablebase::TablebaseView
+//!
+//! # Examples
+//! ```rust
+//! use magpie::othello::{SizedBoard, Stone};
+//! use magpie::search::Wdl;
+//! use magpie::tablebase::{TablebaseGenerator, TablebaseView};
+//!
+//! let board = SizedBoard::<4>::standard();
+//! let tablebase = TablebaseGenerator::new(12).generate(&board, Stone::Black);
+//!
+//! let mut bytes = Vec::new();
+//! tablebase.write_to(&mut bytes).unwrap();
+//! let view = TablebaseView::<4>::new(&bytes).unwrap();
+//! // 4x4 Othello is a win for the second player
+//! assert_eq!(Some(Wdl::Loss), view.probe(&board, Stone::Black));
+//! ```
+
+/// The file format, and the errors that may occur reading it.
+mod format;
+/// Solves positions to generate tablebases.
+mod generator;
+/// Queries tablebases in memory and on disk.
+mod table;
+
+pub use format::TablebaseError;
+pub use generator::TablebaseGenerator;
+pub use table::{Tablebase, TablebaseView};
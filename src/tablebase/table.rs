@@ -0,0 +1,219 @@
+use crate::{
+    othello::{SizedBoard, Stone},
+    search::Wdl,
+    tablebase::format::{
+        ENTRY_SIZE, HEADER_SIZE, Header, TablebaseError, entry_index, entry_wdl, index,
+    },
+};
+use std::io::{Read, Write};
+
+/// A table of the outcomes of positions on small boards under perfect play.
+///
+/// Tablebases are created by a [`TablebaseGenerator`] and cover every
+/// position reachable from the positions it was run on with at most a given
+/// number of empty squares. Positions that are symmetric, or equal with the
+/// colors swapped and the other player to move, share a single entry.
+///
+/// Tablebases are written to and read from files with [`write_to`] and
+/// [`read_from`], which loads every entry into memory. Large tablebases can
+/// instead be queried without loading them with a [`TablebaseView`] over a
+/// memory-mapped file.
+///
+/// Only boards up to 6x6 are supported, for which the index of every
+/// position fits in 64 bits.
+///
+/// [`TablebaseGenerator`]: crate::tablebase::TablebaseGenerator
+/// [`write_to`]: crate::tablebase::Tablebase::write_to
+/// [`read_from`]: crate::tablebase::Tablebase::read_from
+///
+/// # Examples
+/// ```rust
+/// use magpie::othello::{SizedBoard, Stone};
+/// use magpie::tablebase::{Tablebase, TablebaseGenerator};
+///
+/// let board = SizedBoard::<4>::standard();
+/// let tablebase = TablebaseGenerator::new(8).generate(&board, Stone::Black);
+/// assert_eq!(8, tablebase.max_empties());
+///
+/// let mut bytes = Vec::new();
+/// tablebase.write_to(&mut bytes).unwrap();
+/// let read = Tablebase::<4>::read_from(bytes.as_slice()).unwrap();
+/// assert_eq!(tablebase, read);
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Tablebase<const N: usize> {
+    max_empties: u8,
+    entries: Vec<u64>,
+}
+
+impl<const N: usize> Tablebase<N> {
+    /// Fails to compile for board sizes whose indices do not fit in 64 bits.
+    const SUPPORTED: () = assert!(N <= 6, "tablebases only support boards up to 6x6");
+
+    /// Returns a tablebase made of the entries, sorting them.
+    pub(crate) fn from_entries(max_empties: u8, mut entries: Vec<u64>) -> Self {
+        let () = Self::SUPPORTED;
+        entries.sort_unstable();
+        Self {
+            max_empties,
+            entries,
+        }
+    }
+
+    /// Returns the greatest number of empty squares of the positions in the
+    /// tablebase.
+    #[must_use]
+    pub fn max_empties(&self) -> u8 {
+        self.max_empties
+    }
+
+    /// Returns the number of entries.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns true if the tablebase does not contain any entries.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the outcome of the position for the specified player under
+    /// perfect play, or `None` if the position is not in the tablebase.
+    #[must_use]
+    pub fn probe(&self, board: &SizedBoard<N>, stone: Stone) -> Option<Wdl> {
+        let index = index(board, stone);
+        let position = self
+            .entries
+            .binary_search_by(|entry| entry_index(*entry).cmp(&index))
+            .ok()?;
+        Some(entry_wdl(self.entries[position]))
+    }
+
+    /// Writes the tablebase in the format described in the [module
+    /// documentation].
+    ///
+    /// [module documentation]: crate::tablebase
+    pub fn write_to(&self, mut writer: impl Write) -> Result<(), TablebaseError> {
+        let header = Header {
+            size: N as u8,
+            max_empties: self.max_empties,
+            count: self.entries.len() as u64,
+        };
+        writer.write_all(&header.to_bytes())?;
+        for entry in &self.entries {
+            writer.write_all(&entry.to_le_bytes())?;
+        }
+        writer.flush()?;
+        Ok(())
+    }
+
+    /// Reads a tablebase written by [`write_to`], loading every entry.
+    ///
+    /// Returns an error if the data is not a tablebase for boards of this
+    /// size or ends early.
+    ///
+    /// [`write_to`]: crate::tablebase::Tablebase::write_to
+    pub fn read_from(mut reader: impl Read) -> Result<Self, TablebaseError> {
+        let mut bytes = [0; HEADER_SIZE];
+        reader.read_exact(&mut bytes)?;
+        let header = Header::parse(&bytes, N)?;
+        let mut entries = Vec::new();
+        for _ in 0..header.count {
+            let mut bytes = [0; ENTRY_SIZE];
+            reader.read_exact(&mut bytes)?;
+            entries.push(u64::from_le_bytes(bytes));
+        }
+        Ok(Self::from_entries(header.max_empties, entries))
+    }
+}
+
+/// A tablebase queried directly from its encoded bytes, such as those of a
+/// memory-mapped file, without loading the entries.
+///
+/// Since the entries are sorted, every probe is a binary search that only
+/// touches a few pages of the file.
+///
+/// # Examples
+/// ```rust
+/// use magpie::othello::{SizedBoard, Stone};
+/// use magpie::tablebase::{TablebaseGenerator, TablebaseView};
+///
+/// let board = SizedBoard::<4>::standard();
+/// let tablebase = TablebaseGenerator::new(12).generate(&board, Stone::Black);
+/// let mut bytes = Vec::new();
+/// tablebase.write_to(&mut bytes).unwrap();
+///
+/// let view = TablebaseView::<4>::new(&bytes).unwrap();
+/// assert_eq!(tablebase.len(), view.len());
+/// assert_eq!(tablebase.probe(&board, Stone::Black), view.probe(&board, Stone::Black));
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct TablebaseView<'a, const N: usize> {
+    max_empties: u8,
+    entries: &'a [u8],
+}
+
+impl<'a, const N: usize> TablebaseView<'a, N> {
+    /// Parses the header of a tablebase.
+    ///
+    /// Returns an error if the header is invalid, if the tablebase is for
+    /// boards of another size or if it is shorter than its header claims.
+    pub fn new(bytes: &'a [u8]) -> Result<Self, TablebaseError> {
+        let header = Header::parse(bytes, N)?;
+        let len = usize::try_from(header.count)
+            .ok()
+            .and_then(|count| count.checked_mul(ENTRY_SIZE))
+            .ok_or(TablebaseError::Truncated)?;
+        let entries = bytes[HEADER_SIZE..]
+            .get(..len)
+            .ok_or(TablebaseError::Truncated)?;
+        Ok(Self {
+            max_empties: header.max_empties,
+            entries,
+        })
+    }
+
+    /// Returns the greatest number of empty squares of the positions in the
+    /// tablebase.
+    #[must_use]
+    pub fn max_empties(&self) -> u8 {
+        self.max_empties
+    }
+
+    /// Returns the number of entries.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.entries.len() / ENTRY_SIZE
+    }
+
+    /// Returns true if the tablebase does not contain any entries.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the outcome of the position for the specified player under
+    /// perfect play, or `None` if the position is not in the tablebase.
+    #[must_use]
+    pub fn probe(&self, board: &SizedBoard<N>, stone: Stone) -> Option<Wdl> {
+        let index = index(board, stone);
+        let (mut low, mut high) = (0, self.len());
+        while low < high {
+            let middle = low + (high - low) / 2;
+            let entry = self.get(middle);
+            match entry_index(entry).cmp(&index) {
+                std::cmp::Ordering::Less => low = middle + 1,
+                std::cmp::Ordering::Greater => high = middle,
+                std::cmp::Ordering::Equal => return Some(entry_wdl(entry)),
+            }
+        }
+        None
+    }
+
+    fn get(&self, index: usize) -> u64 {
+        let start = index * ENTRY_SIZE;
+        u64::from_le_bytes(self.entries[start..start + ENTRY_SIZE].try_into().unwrap())
+    }
+}
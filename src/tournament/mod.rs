@@ -0,0 +1,44 @@
+//! Plays matches between two agents to measure the difference in strength.
+//!
+//! A [`Match`] plays games between two [`Agent`]s, optionally from a list of
+//! opening positions and under a [time control], and reports the results from
+//! the perspective of the first agent. The [`MatchReport`] estimates the
+//! difference in Elo rating along with its error bars.
+//!
+//! Matches that test whether a change improves an engine are best run with
+//! a [sequential probability ratio test][`Sprt`], which stops the match as
+//! soon as the results are conclusive.
+//!
+//! [`Agent`]: crate::selfplay::Agent
+//! [time control]: crate::clock::TimeControl
+//! [`Match`]: crate::tournament::Match
+//! [`MatchReport`]: crate::tournament::MatchReport
+//! [`Sprt`]: crate::tournament::Sprt
+//!
+//! # Examples
+//! ```rust
+//! use magpie::eval::{DiscDifference, Heuristic};
+//! use magpie::search::{SearchLimits, Searcher};
+//! use magpie::selfplay::SearchAgent;
+//! use magpie::tournament::Match;
+//!
+//! let report = Match::new(
+//!     || SearchAgent::new(Searcher::new(Heuristic::default()), SearchLimits::depth(2)),
+//!     || SearchAgent::new(Searcher::new(DiscDifference), SearchLimits::depth(2)),
+//! )
+//! .with_games(4)
+//! .with_threads(2)
+//! .run();
+//!
+//! assert_eq!(4, report.games());
+//! let elo = report.elo();
+//! println!("{:+.0} Elo ({:+.0} to {:+.0})", elo.difference, elo.lower, elo.upper);
+//! ```
+
+/// Plays the games of a match.
+mod runner;
+/// Elo estimates and sequential probability ratio tests.
+mod stats;
+
+pub use runner::{Match, MatchReport};
+pub use stats::{Elo, Sprt, SprtOutcome};
@@ -0,0 +1,382 @@
+use crate::{
+    clock::{Clock, TimeControl},
+    othello::{Board, Scoring, Stone},
+    search::{Wdl, solve_wdl},
+    selfplay::Agent,
+    tournament::{Elo, Sprt, SprtOutcome},
+};
+use std::{
+    num::NonZeroUsize,
+    sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        mpsc,
+    },
+    thread,
+    time::Instant,
+};
+
+/// The results of a match, from the perspective of the first agent.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct MatchReport {
+    /// The number of games won by the first agent.
+    pub wins: usize,
+    /// The number of drawn games.
+    pub draws: usize,
+    /// The number of games lost by the first agent.
+    pub losses: usize,
+    /// The number of games the first agent lost because their time ran out.
+    pub first_timeouts: usize,
+    /// The number of games the second agent lost because their time ran
+    /// out.
+    pub second_timeouts: usize,
+    /// The number of games decided by the endgame solver rather than
+    /// played to the end.
+    pub adjudicated: usize,
+    /// The outcome of the sequential probability ratio test, if the match
+    /// was run with one.
+    pub sprt: Option<SprtOutcome>,
+}
+
+impl MatchReport {
+    /// Returns the total number of games played.
+    #[must_use]
+    pub fn games(&self) -> usize {
+        self.wins + self.draws + self.losses
+    }
+
+    /// Returns the average score of the first agent, where wins count as
+    /// one point and draws as half a point.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::tournament::MatchReport;
+    ///
+    /// let report = MatchReport { wins: 2, draws: 1, losses: 1, ..MatchReport::default() };
+    /// assert_eq!(0.625, report.score());
+    /// ```
+    #[must_use]
+    pub fn score(&self) -> f64 {
+        (self.wins as f64 + self.draws as f64 / 2.0) / self.games() as f64
+    }
+
+    /// Estimates the difference in rating between the agents.
+    #[must_use]
+    pub fn elo(&self) -> Elo {
+        Elo::from_report(self)
+    }
+}
+
+/// The result of a single game, from the perspective of the first agent.
+struct GameResult {
+    /// The sign of the final score difference.
+    outcome: i8,
+    /// The agent whose time ran out, where 0 is the first agent.
+    timeout: Option<usize>,
+    /// Whether the game was decided by the endgame solver.
+    adjudicated: bool,
+}
+
+/// Plays a match between two agents.
+///
+/// Agents are created through factories, once for every thread, just like
+/// in [`SelfPlay`]. Every opening is played twice, so that both agents play
+/// it with either color. By default, all games start from the standard
+/// opening position, there is no time control, every game is played to the
+/// end and the match is not stopped early.
+///
+/// See the [module documentation] for an example.
+///
+/// [`SelfPlay`]: crate::selfplay::SelfPlay
+/// [module documentation]: crate::tournament
+pub struct Match<F, S> {
+    first: F,
+    second: S,
+    games: usize,
+    threads: NonZeroUsize,
+    openings: Vec<(Board, Stone)>,
+    time_control: Option<TimeControl>,
+    adjudication: Option<u8>,
+    sprt: Option<Sprt>,
+}
+
+impl<F, S, A, B> Match<F, S>
+where
+    F: Fn() -> A + Sync,
+    S: Fn() -> B + Sync,
+    A: Agent,
+    B: Agent,
+{
+    /// Returns a match of two games on a single thread.
+    #[must_use]
+    pub fn new(first: F, second: S) -> Self {
+        Self {
+            first,
+            second,
+            games: 2,
+            threads: NonZeroUsize::MIN,
+            openings: vec![(Board::standard(), Stone::Black)],
+            time_control: None,
+            adjudication: None,
+            sprt: None,
+        }
+    }
+
+    /// Sets the maximum number of games to play.
+    #[must_use]
+    pub fn with_games(self, games: usize) -> Self {
+        Self { games, ..self }
+    }
+
+    /// Sets the number of threads to play games on.
+    ///
+    /// # Panics
+    /// Panics if the number of threads is zero.
+    #[must_use]
+    pub fn with_threads(self, threads: usize) -> Self {
+        Self {
+            threads: NonZeroUsize::new(threads).expect("at least one thread is required"),
+            ..self
+        }
+    }
+
+    /// Sets the positions games start from, along with the player to move.
+    ///
+    /// The openings are played in order, each of them twice, and repeated
+    /// if there are more games than openings.
+    ///
+    /// # Panics
+    /// Panics if there are no openings.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::Transcript;
+    /// use magpie::search::RandomRollout;
+    /// use magpie::tournament::Match;
+    ///
+    /// let openings = ["f5d6", "f5f6", "f5f4"]
+    ///     .iter()
+    ///     .map(|line| {
+    ///         let game = line.parse::<Transcript>().unwrap().replay().unwrap();
+    ///         (game.board(), game.current_turn())
+    ///     })
+    ///     .collect();
+    ///
+    /// let report = Match::new(|| RandomRollout::new(1), || RandomRollout::new(2))
+    ///     .with_openings(openings)
+    ///     .with_games(6)
+    ///     .run();
+    /// assert_eq!(6, report.games());
+    /// ```
+    #[must_use]
+    pub fn with_openings(self, openings: Vec<(Board, Stone)>) -> Self {
+        assert!(!openings.is_empty(), "at least one opening is required");
+        Self { openings, ..self }
+    }
+
+    /// Plays every game under the specified time control. An agent whose
+    /// time runs out loses the game.
+    ///
+    /// Agents learn about their clock through [`Agent::choose_timed`].
+    ///
+    /// [`Agent::choose_timed`]: crate::selfplay::Agent::choose_timed
+    #[must_use]
+    pub fn with_time_control(self, time_control: TimeControl) -> Self {
+        Self {
+            time_control: Some(time_control),
+            ..self
+        }
+    }
+
+    /// Decides every game with at most the specified number of empty
+    /// squares left with [`solve_wdl`], as if both agents played perfectly
+    /// from then on.
+    ///
+    /// Adjudication saves time at the end of games, where the outcome is
+    /// usually settled anyway, and removes the noise of endgame mistakes
+    /// from the results. Positions with more than 20 empty squares take too
+    /// long to solve to be practical.
+    ///
+    /// [`solve_wdl`]: crate::search::solve_wdl
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::search::RandomRollout;
+    /// use magpie::tournament::Match;
+    ///
+    /// let report = Match::new(|| RandomRollout::new(1), || RandomRollout::new(2))
+    ///     .with_games(4)
+    ///     .with_adjudication(12)
+    ///     .run();
+    /// assert_eq!(4, report.games());
+    /// ```
+    #[must_use]
+    pub fn with_adjudication(self, empties: u8) -> Self {
+        Self {
+            adjudication: Some(empties),
+            ..self
+        }
+    }
+
+    /// Stops the match as soon as the test reaches a conclusion, rather
+    /// than after all games have been played.
+    ///
+    /// Games already in progress on other threads are still finished and
+    /// counted.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::eval::Heuristic;
+    /// use magpie::search::{RandomRollout, SearchLimits, Searcher};
+    /// use magpie::selfplay::SearchAgent;
+    /// use magpie::tournament::{Match, Sprt, SprtOutcome};
+    ///
+    /// // Even a shallow search is much stronger than random moves
+    /// let search = || SearchAgent::new(Searcher::new(Heuristic::default()), SearchLimits::depth(2));
+    /// let report = Match::new(search, || RandomRollout::new(3))
+    ///     .with_games(1000)
+    ///     .with_sprt(Sprt::new(0.0, 50.0))
+    ///     .run();
+    /// assert_eq!(Some(SprtOutcome::AcceptH1), report.sprt);
+    /// assert!(report.games() < 1000);
+    /// ```
+    #[must_use]
+    pub fn with_sprt(self, sprt: Sprt) -> Self {
+        Self {
+            sprt: Some(sprt),
+            ..self
+        }
+    }
+
+    /// Plays the match and returns its results.
+    ///
+    /// # Panics
+    /// Panics if an agent chooses an illegal move.
+    pub fn run(&self) -> MatchReport {
+        let next_game = AtomicUsize::new(0);
+        let stop = AtomicBool::new(false);
+        let (sender, receiver) = mpsc::channel();
+        thread::scope(|scope| {
+            for _ in 0..self.threads.get() {
+                let sender = sender.clone();
+                let (next_game, stop) = (&next_game, &stop);
+                scope.spawn(move || {
+                    let mut first = (self.first)();
+                    let mut second = (self.second)();
+                    while !stop.load(Ordering::Relaxed) {
+                        let game = next_game.fetch_add(1, Ordering::Relaxed);
+                        if game >= self.games {
+                            break;
+                        }
+                        let result = self.play(game, &mut first, &mut second);
+                        if sender.send(result).is_err() {
+                            break;
+                        }
+                    }
+                });
+            }
+            drop(sender);
+
+            let mut report = MatchReport {
+                sprt: self.sprt.map(|_| SprtOutcome::Inconclusive),
+                ..MatchReport::default()
+            };
+            for result in receiver {
+                match result.outcome {
+                    1 => report.wins += 1,
+                    -1 => report.losses += 1,
+                    _ => report.draws += 1,
+                }
+                match result.timeout {
+                    Some(0) => report.first_timeouts += 1,
+                    Some(_) => report.second_timeouts += 1,
+                    None => {}
+                }
+                if result.adjudicated {
+                    report.adjudicated += 1;
+                }
+                if let Some(sprt) = self.sprt {
+                    let outcome = sprt.outcome(&report);
+                    report.sprt = Some(outcome);
+                    if outcome != SprtOutcome::Inconclusive {
+                        stop.store(true, Ordering::Relaxed);
+                    }
+                }
+            }
+            report
+        })
+    }
+
+    /// Plays a single game, where the agents swap colors every other game.
+    fn play(&self, game: usize, first: &mut A, second: &mut B) -> GameResult {
+        let (mut board, mut stone) = self.openings[(game / 2) % self.openings.len()];
+        let first_stone = if game.is_multiple_of(2) {
+            stone
+        } else {
+            stone.flip()
+        };
+        let mut clock = self.time_control.map(Clock::new);
+
+        loop {
+            if self
+                .adjudication
+                .is_some_and(|empties| board.empty_squares().count_set() <= empties)
+            {
+                let (wdl, _) = solve_wdl(&board, stone);
+                let wdl = if stone == first_stone {
+                    wdl
+                } else {
+                    wdl.flip()
+                };
+                return GameResult {
+                    outcome: match wdl {
+                        Wdl::Win => 1,
+                        Wdl::Draw => 0,
+                        Wdl::Loss => -1,
+                    },
+                    timeout: None,
+                    adjudicated: true,
+                };
+            }
+            let moves = board.moves_for(stone);
+            if moves.is_empty() {
+                if board.moves_for(stone.flip()).is_empty() {
+                    break;
+                }
+                stone = stone.flip();
+                continue;
+            }
+            let agent = usize::from(stone != first_stone);
+            let start = Instant::now();
+            let mv = match (&clock, agent) {
+                (None, 0) => first.choose(&board, stone, moves),
+                (None, _) => second.choose(&board, stone, moves),
+                (Some(clock), _) => {
+                    let state = clock.state(stone, board.empty_squares().count_set());
+                    if agent == 0 {
+                        first.choose_timed(&board, stone, moves, &state)
+                    } else {
+                        second.choose_timed(&board, stone, moves, &state)
+                    }
+                }
+            };
+            if let Some(clock) = &mut clock
+                && clock.record(stone, start.elapsed()).is_err()
+            {
+                return GameResult {
+                    outcome: if agent == 0 { -1 } else { 1 },
+                    timeout: Some(agent),
+                    adjudicated: false,
+                };
+            }
+            assert!(moves & mv != 0, "agent chose the illegal move {mv}");
+            board.play(stone, mv);
+            stone = stone.flip();
+        }
+
+        GameResult {
+            outcome: Scoring::DiscCount.difference(&board, first_stone).signum(),
+            timeout: None,
+            adjudicated: false,
+        }
+    }
+}
@@ -0,0 +1,190 @@
+use crate::tournament::MatchReport;
+
+/// The quantile of the standard normal distribution for a two-sided 95%
+/// confidence interval.
+const Z_95: f64 = 1.959_964;
+
+/// An estimate of the difference in Elo rating between two agents.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Elo {
+    /// The most likely difference in rating.
+    pub difference: f64,
+    /// The lower bound of the 95% confidence interval.
+    pub lower: f64,
+    /// The upper bound of the 95% confidence interval.
+    pub upper: f64,
+}
+
+impl Elo {
+    /// Estimates the difference in rating from the results of a match, from
+    /// the perspective of the first agent.
+    ///
+    /// The bounds are infinite if the first agent won or lost every game,
+    /// and all values are NaN if no games were played.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::tournament::{Elo, MatchReport};
+    ///
+    /// let report = MatchReport { wins: 60, draws: 10, losses: 30, ..MatchReport::default() };
+    /// let elo = Elo::from_report(&report);
+    /// assert_eq!(108, elo.difference.round() as i32);
+    /// assert!(elo.lower < elo.difference && elo.difference < elo.upper);
+    /// ```
+    #[must_use]
+    pub fn from_report(report: &MatchReport) -> Self {
+        let games = report.games() as f64;
+        let score = report.score();
+        let deviation = (variance(report) / games).sqrt();
+        Self {
+            difference: elo(score),
+            lower: elo(score - Z_95 * deviation),
+            upper: elo(score + Z_95 * deviation),
+        }
+    }
+}
+
+/// A sequential probability ratio test between two hypotheses about the
+/// difference in rating between the agents of a match.
+///
+/// The null hypothesis is that the first agent is `elo0` points stronger
+/// than the second, while the alternative hypothesis is that it is `elo1`
+/// points stronger. The test accepts the null hypothesis with a probability
+/// of at most `alpha` when the alternative one is true, and the alternative
+/// hypothesis with a probability of at most `beta` when the null hypothesis
+/// is true.
+///
+/// # Examples
+/// ```rust
+/// use magpie::tournament::{MatchReport, Sprt, SprtOutcome};
+///
+/// let sprt = Sprt::new(0.0, 10.0);
+/// let even = MatchReport { wins: 40, draws: 20, losses: 40, ..MatchReport::default() };
+/// assert_eq!(SprtOutcome::Inconclusive, sprt.outcome(&even));
+///
+/// let stronger = MatchReport { wins: 600, draws: 100, losses: 300, ..MatchReport::default() };
+/// assert_eq!(SprtOutcome::AcceptH1, sprt.outcome(&stronger));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Sprt {
+    /// The difference in rating under the null hypothesis.
+    pub elo0: f64,
+    /// The difference in rating under the alternative hypothesis.
+    pub elo1: f64,
+    /// The probability of falsely accepting the alternative hypothesis.
+    pub alpha: f64,
+    /// The probability of falsely accepting the null hypothesis.
+    pub beta: f64,
+}
+
+/// The conclusion of a sequential probability ratio test.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum SprtOutcome {
+    /// The results favor the null hypothesis.
+    AcceptH0,
+    /// The results favor the alternative hypothesis.
+    AcceptH1,
+    /// More games are needed to decide between the hypotheses.
+    Inconclusive,
+}
+
+impl Sprt {
+    /// Returns a test between the specified differences in rating, where
+    /// both error probabilities are 5%.
+    #[must_use]
+    pub fn new(elo0: f64, elo1: f64) -> Self {
+        Self {
+            elo0,
+            elo1,
+            alpha: 0.05,
+            beta: 0.05,
+        }
+    }
+
+    /// Sets the probabilities of accepting the wrong hypothesis.
+    #[must_use]
+    pub fn with_errors(self, alpha: f64, beta: f64) -> Self {
+        Self {
+            alpha,
+            beta,
+            ..self
+        }
+    }
+
+    /// Returns the log-likelihood ratio of the hypotheses, given the results
+    /// of a match.
+    ///
+    /// The ratio is approximated by modeling the score of every game as
+    /// normally distributed, which is accurate for all but the smallest
+    /// number of games.
+    #[must_use]
+    pub fn llr(&self, report: &MatchReport) -> f64 {
+        let variance = variance(report);
+        if report.games() == 0 || variance == 0.0 {
+            return 0.0;
+        }
+        let (s0, s1) = (expected_score(self.elo0), expected_score(self.elo1));
+        let games = report.games() as f64;
+        games * (s1 - s0) * (2.0 * report.score() - s0 - s1) / (2.0 * variance)
+    }
+
+    /// Returns the lower and upper bounds of the log-likelihood ratio, at
+    /// which the null and alternative hypothesis are accepted respectively.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::tournament::Sprt;
+    ///
+    /// let (lower, upper) = Sprt::new(0.0, 5.0).bounds();
+    /// assert!((lower + 2.94).abs() < 0.01 && (upper - 2.94).abs() < 0.01);
+    /// ```
+    #[must_use]
+    pub fn bounds(&self) -> (f64, f64) {
+        (
+            (self.beta / (1.0 - self.alpha)).ln(),
+            ((1.0 - self.beta) / self.alpha).ln(),
+        )
+    }
+
+    /// Decides between the hypotheses, given the results of a match.
+    #[must_use]
+    pub fn outcome(&self, report: &MatchReport) -> SprtOutcome {
+        let llr = self.llr(report);
+        let (lower, upper) = self.bounds();
+        if llr >= upper {
+            SprtOutcome::AcceptH1
+        } else if llr <= lower {
+            SprtOutcome::AcceptH0
+        } else {
+            SprtOutcome::Inconclusive
+        }
+    }
+}
+
+/// Returns the expected score of a player the specified number of points
+/// stronger than their opponent.
+fn expected_score(elo: f64) -> f64 {
+    1.0 / (1.0 + 10f64.powf(-elo / 400.0))
+}
+
+/// Returns the difference in rating that leads to the specified expected
+/// score.
+fn elo(score: f64) -> f64 {
+    -400.0 * (1.0 / score - 1.0).log10()
+}
+
+/// Returns the variance of the score of a single game.
+fn variance(report: &MatchReport) -> f64 {
+    let games = report.games() as f64;
+    let score = report.score();
+    let deviations = [
+        (report.wins, 1.0 - score),
+        (report.draws, 0.5 - score),
+        (report.losses, -score),
+    ];
+    deviations
+        .iter()
+        .map(|(count, deviation)| *count as f64 * deviation * deviation)
+        .sum::<f64>()
+        / games
+}
@@ -0,0 +1,312 @@
+use crate::othello::{Board, Move, OthelloError, Stone, Symmetry, Transcript, TranscriptError};
+use alloc::{collections::BTreeMap, vec::Vec};
+
+/// Identifies a node of a [`GameDag`].
+///
+/// Ids are only meaningful for the graph that returned them.
+///
+/// [`GameDag`]: crate::tree::GameDag
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct NodeId(usize);
+
+impl NodeId {
+    /// Returns the index of the node, in the order the nodes were added.
+    #[must_use]
+    pub fn index(self) -> usize {
+        self.0
+    }
+}
+
+/// A move leading from one node to another.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct Edge {
+    /// The move, relative to the canonical board of the node it is played
+    /// from.
+    pub mv: Move,
+    /// The node the move leads to.
+    pub child: NodeId,
+}
+
+/// A position in a [`GameDag`], along with the moves leading to and from
+/// it.
+///
+/// [`GameDag`]: crate::tree::GameDag
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Node<A> {
+    board: Board,
+    stone: Stone,
+    edges: Vec<Edge>,
+    parents: Vec<NodeId>,
+    annotation: A,
+}
+
+impl<A> Node<A> {
+    /// Returns the board of the position in its canonical orientation, as
+    /// returned by [`Board::canonical`].
+    ///
+    /// [`Board::canonical`]: crate::othello::Board::canonical
+    #[must_use]
+    pub fn board(&self) -> Board {
+        self.board
+    }
+
+    /// Returns the player to move.
+    #[must_use]
+    pub fn stone(&self) -> Stone {
+        self.stone
+    }
+
+    /// Returns the moves that have been added from this position, in the
+    /// order they were added.
+    #[must_use]
+    pub fn edges(&self) -> &[Edge] {
+        &self.edges
+    }
+
+    /// Returns the positions with a move leading to this one, in the order
+    /// the moves were added. A parent appears once for every such move.
+    #[must_use]
+    pub fn parents(&self) -> &[NodeId] {
+        &self.parents
+    }
+
+    /// Returns the annotation of the position.
+    #[must_use]
+    pub fn annotation(&self) -> &A {
+        &self.annotation
+    }
+}
+
+/// A directed acyclic graph of positions connected by moves.
+///
+/// Positions are keyed by their [canonical] board and the player to move,
+/// which merges transpositions as well as symmetric variants into a single
+/// node. Every node carries an annotation of type `A`, such as a score or a
+/// comment, which starts out as its default value.
+///
+/// Methods that take a board and a move work in the orientation of that
+/// board and translate to and from the canonical orientation of the node.
+/// The [`edges`] of a node, on the other hand, are relative to its
+/// canonical board.
+///
+/// [canonical]: crate::othello::Board::canonical
+/// [`edges`]: crate::tree::Node::edges
+///
+/// # Examples
+/// ```rust
+/// use magpie::othello::{Board, Move, Stone};
+/// use magpie::tree::GameDag;
+///
+/// let mut dag: GameDag = GameDag::default();
+/// let board = Board::standard();
+/// let f5 = dag.add_move(&board, Stone::Black, Move::Play("f5".parse().unwrap())).unwrap();
+/// let d3 = dag.add_move(&board, Stone::Black, Move::Play("d3".parse().unwrap())).unwrap();
+///
+/// // Both moves lead to the same position, up to symmetry
+/// assert_eq!(f5, d3);
+/// assert_eq!(2, dag.node_count());
+/// assert_eq!(2, dag.children(&board, Stone::Black).len());
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct GameDag<A = ()> {
+    nodes: Vec<Node<A>>,
+    index: BTreeMap<(u64, u64, bool), NodeId>,
+    root: NodeId,
+}
+
+impl<A: Default> GameDag<A> {
+    /// Returns a graph containing only the specified root position.
+    #[must_use]
+    pub fn new(board: Board, stone: Stone) -> Self {
+        let mut dag = Self {
+            nodes: Vec::new(),
+            index: BTreeMap::new(),
+            root: NodeId(0),
+        };
+        dag.root = dag.insert(&board, stone);
+        dag
+    }
+
+    /// Returns the node of the position, adding it if it is not part of the
+    /// graph yet.
+    ///
+    /// Positions added this way are not connected to the rest of the graph
+    /// until moves leading to or from them are added.
+    pub fn insert(&mut self, board: &Board, stone: Stone) -> NodeId {
+        let (board, _) = board.canonical();
+        let key = key(&board, stone);
+        if let Some(id) = self.index.get(&key) {
+            return *id;
+        }
+        let id = NodeId(self.nodes.len());
+        self.nodes.push(Node {
+            board,
+            stone,
+            edges: Vec::new(),
+            parents: Vec::new(),
+            annotation: A::default(),
+        });
+        self.index.insert(key, id);
+        id
+    }
+
+    /// Adds the move from the position, along with the position it leads
+    /// to, and returns the node of that position.
+    ///
+    /// The player to move after a move is always the opponent, who then
+    /// has to [pass] if they have no legal moves. Passing is only legal
+    /// when the player has no legal moves but the opponent does. Adding a
+    /// move that is already part of the graph leaves it unchanged.
+    ///
+    /// [pass]: crate::othello::Move::Pass
+    pub fn add_move(
+        &mut self,
+        board: &Board,
+        stone: Stone,
+        mv: Move,
+    ) -> Result<NodeId, OthelloError> {
+        let mut next = *board;
+        match mv {
+            Move::Play(position) => {
+                if !board.is_legal_move(stone, position) {
+                    return Err(OthelloError::IllegalMove);
+                }
+                next.play(stone, position);
+            }
+            Move::Pass => {
+                if !board.moves_for(stone).is_empty() || board.moves_for(stone.flip()).is_empty() {
+                    return Err(OthelloError::IllegalMove);
+                }
+            }
+        }
+        let parent = self.insert(board, stone);
+        let child = self.insert(&next, stone.flip());
+        let (_, symmetry) = board.canonical();
+        let edge = Edge {
+            mv: transform(mv, symmetry),
+            child,
+        };
+        if !self.nodes[parent.0].edges.contains(&edge) {
+            self.nodes[parent.0].edges.push(edge);
+            self.nodes[child.0].parents.push(parent);
+        }
+        Ok(child)
+    }
+
+    /// Adds every move of the transcript, replayed from the standard opening
+    /// position, and returns the node of the final position.
+    ///
+    /// Forced passes are added even if the transcript omits them. Returns
+    /// an error if the transcript contains an illegal move, in which case
+    /// the moves preceding it are still added.
+    pub fn insert_line(&mut self, transcript: &Transcript) -> Result<NodeId, TranscriptError> {
+        let mut board = Board::standard();
+        let mut stone = Stone::Black;
+        let mut node = self.insert(&board, stone);
+        for (index, mv) in transcript.moves().iter().enumerate() {
+            let illegal = TranscriptError::IllegalMove(index);
+            if let Move::Play(_) = mv
+                && self.add_move(&board, stone, Move::Pass).is_ok()
+            {
+                stone = stone.flip();
+            }
+            node = self.add_move(&board, stone, *mv).map_err(|_| illegal)?;
+            if let Move::Play(position) = mv {
+                board.play(stone, *position);
+            }
+            stone = stone.flip();
+        }
+        Ok(node)
+    }
+}
+
+impl<A: Default> Default for GameDag<A> {
+    /// Returns a graph rooted at the standard opening position.
+    fn default() -> Self {
+        Self::new(Board::standard(), Stone::Black)
+    }
+}
+
+impl<A> GameDag<A> {
+    /// Returns the node of the position the graph was created with.
+    #[must_use]
+    pub fn root(&self) -> NodeId {
+        self.root
+    }
+
+    /// Returns the node of the position, if it is part of the graph.
+    #[must_use]
+    pub fn get(&self, board: &Board, stone: Stone) -> Option<NodeId> {
+        let (board, _) = board.canonical();
+        self.index.get(&key(&board, stone)).copied()
+    }
+
+    /// Returns the node with the specified id.
+    ///
+    /// # Panics
+    /// Panics if the id does not belong to this graph.
+    #[must_use]
+    pub fn node(&self, id: NodeId) -> &Node<A> {
+        &self.nodes[id.0]
+    }
+
+    /// Returns a mutable reference to the annotation of the node.
+    ///
+    /// # Panics
+    /// Panics if the id does not belong to this graph.
+    pub fn annotation_mut(&mut self, id: NodeId) -> &mut A {
+        &mut self.nodes[id.0].annotation
+    }
+
+    /// Returns every move added from the position, relative to the
+    /// specified board, along with the node each of them leads to.
+    ///
+    /// Returns an empty vector if the position is not part of the graph.
+    #[must_use]
+    pub fn children(&self, board: &Board, stone: Stone) -> Vec<(Move, NodeId)> {
+        let Some(id) = self.get(board, stone) else {
+            return Vec::new();
+        };
+        let (_, symmetry) = board.canonical();
+        self.nodes[id.0]
+            .edges
+            .iter()
+            .map(|edge| (transform(edge.mv, symmetry.inverse()), edge.child))
+            .collect()
+    }
+
+    /// Returns every node along with its id, in the order they were added.
+    pub fn nodes(&self) -> impl ExactSizeIterator<Item = (NodeId, &Node<A>)> {
+        self.nodes
+            .iter()
+            .enumerate()
+            .map(|(index, node)| (NodeId(index), node))
+    }
+
+    /// Returns the number of positions in the graph.
+    #[must_use]
+    pub fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Returns the number of moves in the graph.
+    #[must_use]
+    pub fn edge_count(&self) -> usize {
+        self.nodes.iter().map(|node| node.edges.len()).sum()
+    }
+}
+
+fn key(board: &Board, stone: Stone) -> (u64, u64, bool) {
+    (
+        board.bits_for(Stone::Black).raw(),
+        board.bits_for(Stone::White).raw(),
+        stone == Stone::White,
+    )
+}
+
+fn transform(mv: Move, symmetry: Symmetry) -> Move {
+    match mv {
+        Move::Play(position) => Move::Play(position.transform(symmetry)),
+        Move::Pass => Move::Pass,
+    }
+}
@@ -0,0 +1,221 @@
+use crate::{
+    othello::{Move, Stone},
+    tree::{GameDag, Node, NodeId},
+};
+use alloc::{collections::VecDeque, format, string::String, vec, vec::Vec};
+use core::fmt::Write;
+
+/// Decides whether a node is included in a [`DotGraph`].
+///
+/// [`DotGraph`]: crate::tree::DotGraph
+type Filter<'a, A> = &'a dyn Fn(&Node<A>) -> bool;
+/// Returns the label of a node in a [`DotGraph`].
+///
+/// [`DotGraph`]: crate::tree::DotGraph
+type Label<'a, A> = &'a dyn Fn(&Node<A>) -> String;
+
+/// A view of a [`GameDag`] in the DOT language of Graphviz, optionally
+/// limited in depth or to the nodes accepted by a filter.
+///
+/// Nodes are labeled with the player to move and the number of stones of
+/// both players, unless a custom label is provided. Moves are labeled in
+/// the orientation of the canonical board of the node they are played from,
+/// where passes are written as `--`.
+///
+/// [`GameDag`]: crate::tree::GameDag
+///
+/// # Examples
+/// ```rust
+/// use magpie::tree::GameDag;
+///
+/// let mut dag: GameDag<i32> = GameDag::default();
+/// let node = dag.insert_line(&"f5d6c3".parse().unwrap()).unwrap();
+/// *dag.annotation_mut(node) = -2;
+///
+/// let dot = dag
+///     .dot()
+///     .with_max_depth(2)
+///     .with_label(&|node| format!("{:+}", node.annotation()))
+///     .to_dot();
+/// assert!(dot.starts_with("digraph {"));
+/// // The root and the positions after one and two moves
+/// assert_eq!(3, dot.matches("[label=\"+0\"]").count());
+/// assert!(!dot.contains("-2"));
+/// ```
+pub struct DotGraph<'a, A> {
+    dag: &'a GameDag<A>,
+    max_depth: Option<usize>,
+    filter: Option<Filter<'a, A>>,
+    label: Option<Label<'a, A>>,
+}
+
+impl<'a, A> DotGraph<'a, A> {
+    /// Returns a view of the entire graph.
+    #[must_use]
+    pub fn new(dag: &'a GameDag<A>) -> Self {
+        Self {
+            dag,
+            max_depth: None,
+            filter: None,
+            label: None,
+        }
+    }
+
+    /// Only includes nodes that can be reached from the root in at most the
+    /// specified number of moves.
+    #[must_use]
+    pub fn with_max_depth(self, max_depth: usize) -> Self {
+        Self {
+            max_depth: Some(max_depth),
+            ..self
+        }
+    }
+
+    /// Only includes nodes accepted by the filter, such as nodes whose
+    /// score lies within a window. Nodes that can only be reached through
+    /// rejected nodes are left out as well, while the root is always
+    /// included.
+    #[must_use]
+    pub fn with_filter(self, filter: Filter<'a, A>) -> Self {
+        Self {
+            filter: Some(filter),
+            ..self
+        }
+    }
+
+    /// Labels every node with the text returned by the function, which may
+    /// span several lines.
+    #[must_use]
+    pub fn with_label(self, label: Label<'a, A>) -> Self {
+        Self {
+            label: Some(label),
+            ..self
+        }
+    }
+
+    /// Writes the graph in the DOT language.
+    ///
+    /// Nodes are visited breadth-first from the root, so every node appears
+    /// at the depth of the shortest line leading to it.
+    #[must_use]
+    pub fn to_dot(&self) -> String {
+        let included = self.included();
+        let mut dot = String::from("digraph {\n");
+        // Writing to a string cannot fail
+        for (id, node) in self.dag.nodes() {
+            if included[id.index()] {
+                let _ = writeln!(
+                    dot,
+                    "    n{} [label=\"{}\"];",
+                    id.index(),
+                    escape(&self.label(node))
+                );
+            }
+        }
+        for (id, node) in self.dag.nodes() {
+            if !included[id.index()] {
+                continue;
+            }
+            for edge in node.edges() {
+                if included[edge.child.index()] {
+                    let label = match edge.mv {
+                        Move::Play(pos) => format!("{pos}"),
+                        Move::Pass => String::from("--"),
+                    };
+                    let _ = writeln!(
+                        dot,
+                        "    n{} -> n{} [label=\"{label}\"];",
+                        id.index(),
+                        edge.child.index()
+                    );
+                }
+            }
+        }
+        dot.push_str("}\n");
+        dot
+    }
+
+    /// Returns whether every node is included, indexed by its id.
+    fn included(&self) -> Vec<bool> {
+        let mut included = vec![false; self.dag.node_count()];
+        let root = self.dag.root();
+        included[root.index()] = true;
+        let mut queue: VecDeque<(NodeId, usize)> = VecDeque::from([(root, 0)]);
+        while let Some((id, depth)) = queue.pop_front() {
+            if self.max_depth.is_some_and(|max_depth| depth >= max_depth) {
+                continue;
+            }
+            for edge in self.dag.node(id).edges() {
+                let child = edge.child;
+                let accepted = self
+                    .filter
+                    .is_none_or(|filter| filter(self.dag.node(child)));
+                if !included[child.index()] && accepted {
+                    included[child.index()] = true;
+                    queue.push_back((child, depth + 1));
+                }
+            }
+        }
+        included
+    }
+
+    fn label(&self, node: &Node<A>) -> String {
+        if let Some(label) = self.label {
+            return label(node);
+        }
+        let board = node.board();
+        let to_move = match node.stone() {
+            Stone::Black => "Black",
+            Stone::White => "White",
+        };
+        format!(
+            "{to_move} to move\n{}-{}",
+            board.bits_for(Stone::Black).count_set(),
+            board.bits_for(Stone::White).count_set()
+        )
+    }
+}
+
+impl<A> GameDag<A> {
+    /// Returns a view of the graph in the DOT language of Graphviz, which
+    /// can be limited before it is written.
+    ///
+    /// See [`DotGraph`] for an example.
+    ///
+    /// [`DotGraph`]: crate::tree::DotGraph
+    #[must_use]
+    pub fn dot(&self) -> DotGraph<'_, A> {
+        DotGraph::new(self)
+    }
+
+    /// Writes the entire graph in the DOT language of Graphviz.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::tree::GameDag;
+    ///
+    /// let mut dag: GameDag = GameDag::default();
+    /// dag.insert_line(&"f5d6".parse().unwrap()).unwrap();
+    /// let dot = dag.to_dot();
+    /// assert!(dot.contains("n0 -> n1 [label=\"f5\"];"));
+    /// assert!(dot.contains("[label=\"Black to move\\n2-2\"]"));
+    /// ```
+    #[must_use]
+    pub fn to_dot(&self) -> String {
+        self.dot().to_dot()
+    }
+}
+
+/// Escapes the text for use in a quoted DOT string.
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
@@ -0,0 +1,36 @@
+//! Graphs of positions for analysis tools, such as opening preparation and
+//! visualizations of engine analysis.
+//!
+//! A [`GameDag`] stores every position once, in its canonical orientation,
+//! no matter how many move orders or symmetric variants lead to it. Lines
+//! that transpose into each other therefore share their nodes, along with
+//! any annotations attached to them. Graphs can be exported to Graphviz
+//! through [`DotGraph`] for visualization.
+//!
+//! [`GameDag`]: crate::tree::GameDag
+//! [`DotGraph`]: crate::tree::DotGraph
+//!
+//! # Examples
+//! ```rust
+//! use magpie::othello::Transcript;
+//! use magpie::tree::GameDag;
+//!
+//! let mut dag: GameDag<u32> = GameDag::default();
+//! let a = dag.insert_line(&"f5d6c3d3".parse::<Transcript>().unwrap()).unwrap();
+//! let b = dag.insert_line(&"f5d6c3d3".parse::<Transcript>().unwrap()).unwrap();
+//! // The same line from a symmetric first move
+//! let c = dag.insert_line(&"e6f4c3c4".parse::<Transcript>().unwrap()).unwrap();
+//! assert_eq!(a, b);
+//! assert_eq!(a, c);
+//!
+//! *dag.annotation_mut(a) += 1;
+//! assert_eq!(1, *dag.node(c).annotation());
+//! ```
+
+/// The graph and its nodes.
+mod dag;
+/// Writes graphs in the DOT language of Graphviz.
+mod dot;
+
+pub use dag::{Edge, GameDag, Node, NodeId};
+pub use dot::DotGraph;
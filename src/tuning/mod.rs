@@ -0,0 +1,60 @@
+//! Fits the weights of linear evaluators to the results of games.
+//!
+//! The [`Tuner`] implements the method popularized by the Texel chess
+//! engine: every position in a dataset is labeled with the result of the
+//! game it was played in, and the weights are fitted by logistic regression
+//! so that the evaluation of a position predicts the result as well as
+//! possible. Any evaluator that is a weighted sum of features can be tuned
+//! by implementing [`Tunable`], which [`Heuristic`] and [`PatternEvaluator`]
+//! already do.
+//!
+//! The same samples calibrate the forward pruning of the searcher:
+//! [`fit_probcut`] fits the constants of ProbCut to the scores of shallow
+//! and deep searches of the positions.
+//!
+//! Datasets are made of [`Sample`]s, which can be created from the records
+//! of the [`selfplay`] module, from [`datapack`] records and thereby from
+//! games in [`wthor`] archives.
+//!
+//! [`Heuristic`]: crate::eval::Heuristic
+//! [`PatternEvaluator`]: crate::eval::PatternEvaluator
+//! [`datapack`]: crate::datapack
+//! [`selfplay`]: crate::selfplay
+//! [`wthor`]: crate::wthor
+//! [`Tuner`]: crate::tuning::Tuner
+//! [`Tunable`]: crate::tuning::Tunable
+//! [`fit_probcut`]: crate::tuning::fit_probcut
+//! [`Sample`]: crate::tuning::Sample
+//!
+//! # Examples
+//! ```rust
+//! use magpie::eval::Heuristic;
+//! use magpie::search::RandomRollout;
+//! use magpie::selfplay::{Record, SelfPlay};
+//! use magpie::tuning::{Sample, Tuner};
+//!
+//! let mut records: Vec<Record> = Vec::new();
+//! SelfPlay::new(|| RandomRollout::new(1), || RandomRollout::new(2))
+//!     .with_games(20)
+//!     .run(&mut records);
+//! let samples: Vec<Sample> = records.into_iter().map(Sample::from).collect();
+//!
+//! let mut heuristic = Heuristic::default();
+//! let report = Tuner::new()
+//!     .with_epochs(20)
+//!     .with_validation(0.2)
+//!     .fit(&mut heuristic, &samples);
+//! assert_eq!(20, report.epoch);
+//! assert!(report.validation_loss.is_some());
+//! ```
+
+/// Calibrates ProbCut from searches of samples.
+mod probcut;
+/// Evaluators whose weights can be fitted.
+mod tunable;
+/// Logistic regression by gradient descent.
+mod tuner;
+
+pub use probcut::fit_probcut;
+pub use tunable::Tunable;
+pub use tuner::{EpochReport, Sample, Tuner};
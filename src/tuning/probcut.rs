@@ -0,0 +1,98 @@
+use crate::{
+    eval::Evaluator,
+    search::{ProbCutPair, SearchLimits, Searcher, WIN_SCORE},
+    tuning::Sample,
+};
+
+/// Fits a [`ProbCutPair`] predicting the score of a search of `depth` plies
+/// from that of a search of `shallow` plies, by searching every sample to
+/// both depths with the evaluator.
+///
+/// The slope and intercept are fitted by least squares, and the standard
+/// deviation is that of the remaining errors. Samples that are solved by
+/// either search, or too close to the end of the game to be searched to the
+/// full depth, are skipped. Returns `None` if the shallow search is not
+/// shallower, or if fewer than two samples with different shallow scores
+/// remain.
+///
+/// Since the results of the games are not needed, samples labeled with any
+/// result can be used. The samples should come from positions like those the
+/// searcher will encounter, such as positions from self-play.
+///
+/// # Examples
+/// ```rust
+/// use magpie::eval::Heuristic;
+/// use magpie::search::{ProbCut, RandomRollout, Searcher};
+/// use magpie::selfplay::{Record, SelfPlay};
+/// use magpie::tuning::{Sample, fit_probcut};
+///
+/// let mut records: Vec<Record> = Vec::new();
+/// SelfPlay::new(|| RandomRollout::new(1), || RandomRollout::new(2))
+///     .with_games(2)
+///     .run(&mut records);
+/// let samples: Vec<Sample> = records.into_iter().map(Sample::from).collect();
+///
+/// let pair = fit_probcut(&Heuristic::default(), &samples, 3, 1).unwrap();
+/// assert!(pair.slope > 0.0);
+/// let probcut = ProbCut::new().with_pair(pair);
+/// let searcher = Searcher::new(Heuristic::default()).with_probcut(probcut);
+/// ```
+#[must_use]
+pub fn fit_probcut<E: Evaluator>(
+    evaluator: &E,
+    samples: &[Sample],
+    depth: u8,
+    shallow: u8,
+) -> Option<ProbCutPair> {
+    if shallow >= depth {
+        return None;
+    }
+    let mut searcher = Searcher::new(evaluator);
+    let mut scores = Vec::with_capacity(samples.len());
+    for sample in samples {
+        let mut score = |depth| {
+            let report = searcher.search(&sample.board, sample.stone, SearchLimits::depth(depth));
+            Some(report.score).filter(|score| report.depth == depth && score.abs() < WIN_SCORE)
+        };
+        if let (Some(x), Some(y)) = (score(shallow), score(depth)) {
+            scores.push((f64::from(x), f64::from(y)));
+        }
+    }
+
+    let (slope, intercept) = least_squares(&scores)?;
+    #[allow(clippy::cast_precision_loss)]
+    let variance = scores
+        .iter()
+        .map(|(x, y)| (y - slope * x - intercept).powi(2))
+        .sum::<f64>()
+        / scores.len() as f64;
+    Some(ProbCutPair {
+        depth,
+        shallow,
+        slope,
+        intercept,
+        sigma: variance.sqrt(),
+    })
+}
+
+/// Fits `y = slope * x + intercept` to the points, returning `None` if the
+/// points do not determine a line.
+#[allow(clippy::cast_precision_loss)]
+fn least_squares(points: &[(f64, f64)]) -> Option<(f64, f64)> {
+    if points.len() < 2 {
+        return None;
+    }
+    let n = points.len() as f64;
+    let mean_x = points.iter().map(|(x, _)| x).sum::<f64>() / n;
+    let mean_y = points.iter().map(|(_, y)| y).sum::<f64>() / n;
+    let covariance: f64 = points
+        .iter()
+        .map(|(x, y)| (x - mean_x) * (y - mean_y))
+        .sum();
+    let variance: f64 = points.iter().map(|(x, _)| (x - mean_x).powi(2)).sum();
+    if variance == 0.0 {
+        return None;
+    }
+    let slope = covariance / variance;
+    Some((slope, mean_y - slope * mean_x))
+}
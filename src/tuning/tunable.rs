@@ -0,0 +1,147 @@
+use crate::{
+    eval::{Evaluator, Heuristic, Pattern, PatternEvaluator, Tapered},
+    othello::{Board, Stone},
+};
+
+/// An evaluator that is a weighted sum of features, whose weights can be
+/// fitted by a [`Tuner`].
+///
+/// The evaluation of a position must equal the sum of the products of its
+/// features and the corresponding weights, which allows the tuner to work
+/// on the features alone.
+///
+/// [`Tuner`]: crate::tuning::Tuner
+pub trait Tunable {
+    /// Returns the current weights.
+    fn parameters(&self) -> Vec<f64>;
+
+    /// Returns the number of weights.
+    fn parameter_count(&self) -> usize {
+        self.parameters().len()
+    }
+
+    /// Replaces the weights, rounding them if the evaluator stores integer
+    /// weights.
+    fn set_parameters(&mut self, weights: &[f64]);
+
+    /// Appends the features of the position that are not zero, as pairs of
+    /// the index of the weight and the value of the feature.
+    fn features(&self, board: &Board, to_move: Stone, features: &mut Vec<(usize, f64)>);
+}
+
+impl Tunable for Heuristic {
+    fn parameters(&self) -> Vec<f64> {
+        Heuristic::weights(self).map(f64::from).to_vec()
+    }
+
+    fn set_parameters(&mut self, weights: &[f64]) {
+        let [mobility, frontier, corners, x_squares, discs] = round(weights)[..] else {
+            panic!("expected 5 weights, got {}", weights.len());
+        };
+        *self = Self {
+            mobility,
+            frontier,
+            corners,
+            x_squares,
+            discs,
+        };
+    }
+
+    fn features(&self, board: &Board, to_move: Stone, features: &mut Vec<(usize, f64)>) {
+        let values = Heuristic::terms(board, to_move);
+        features.extend(
+            values
+                .iter()
+                .enumerate()
+                .filter(|(_, value)| **value != 0)
+                .map(|(index, value)| (index, f64::from(*value))),
+        );
+    }
+}
+
+/// The weights of a pattern evaluator are stored stage by stage, and within
+/// every stage in the order of [`Pattern::ALL`].
+impl Tunable for PatternEvaluator {
+    fn parameters(&self) -> Vec<f64> {
+        (0..self.stages())
+            .flat_map(|stage| Pattern::ALL.iter().map(move |pattern| (stage, *pattern)))
+            .flat_map(|(stage, pattern)| self.weights(stage, pattern))
+            .map(|weight| f64::from(*weight))
+            .collect()
+    }
+
+    fn parameter_count(&self) -> usize {
+        self.stages() * Pattern::ALL.iter().map(|p| p.table_size()).sum::<usize>()
+    }
+
+    fn set_parameters(&mut self, weights: &[f64]) {
+        let mut weights = round(weights).into_iter();
+        for stage in 0..self.stages() {
+            for pattern in Pattern::ALL {
+                for weight in self.weights_mut(stage, pattern) {
+                    *weight = weights.next().expect("too few weights");
+                }
+            }
+        }
+        assert!(weights.next().is_none(), "too many weights");
+    }
+
+    fn features(&self, board: &Board, to_move: Stone, features: &mut Vec<(usize, f64)>) {
+        let stage_size: usize = Pattern::ALL.iter().map(|p| p.table_size()).sum();
+        let stage_offset = self.stage(board) * stage_size;
+        for (pattern, index) in self.indices(board, to_move) {
+            let table_offset: usize = Pattern::ALL
+                .iter()
+                .take_while(|other| **other != pattern)
+                .map(|other| other.table_size())
+                .sum();
+            features.push((stage_offset + table_offset + index, 1.0));
+        }
+    }
+}
+
+/// The weights of every phase are stored one after the other, and all phases
+/// must have the same number of weights. Since evaluations are rounded when
+/// blending, they may differ slightly from the sum of the features.
+impl<E: Tunable + Evaluator> Tunable for Tapered<E> {
+    fn parameters(&self) -> Vec<f64> {
+        self.phases().iter().flat_map(E::parameters).collect()
+    }
+
+    fn parameter_count(&self) -> usize {
+        self.phases().iter().map(E::parameter_count).sum()
+    }
+
+    fn set_parameters(&mut self, weights: &[f64]) {
+        let phases = self.phases_mut();
+        assert!(
+            weights.len().is_multiple_of(phases.len()),
+            "the weights cannot be divided between the phases"
+        );
+        let len = weights.len() / phases.len();
+        for (phase, weights) in phases.iter_mut().zip(weights.chunks(len)) {
+            phase.set_parameters(weights);
+        }
+    }
+
+    fn features(&self, board: &Board, to_move: Stone, features: &mut Vec<(usize, f64)>) {
+        let len = self.phases()[0].parameter_count();
+        let (earlier, later, weight) = self.blend(board);
+        let weight = f64::from(weight) / 60.0;
+        for (phase, share) in [(earlier, 1.0 - weight), (later, weight)] {
+            if share == 0.0 {
+                continue;
+            }
+            let start = features.len();
+            self.phases()[phase].features(board, to_move, features);
+            for (index, value) in &mut features[start..] {
+                *index += phase * len;
+                *value *= share;
+            }
+        }
+    }
+}
+
+fn round(weights: &[f64]) -> Vec<i32> {
+    weights.iter().map(|weight| weight.round() as i32).collect()
+}
@@ -0,0 +1,331 @@
+use crate::{
+    datapack::PackRecord,
+    othello::{Board, Stone},
+    selfplay::Record,
+    tuning::Tunable,
+};
+use std::ops::ControlFlow;
+
+/// The decay rates of the moving averages of the gradient and its square.
+const BETA1: f64 = 0.9;
+const BETA2: f64 = 0.999;
+const EPSILON: f64 = 1e-8;
+
+/// A position labeled with the result of the game it was played in.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Sample {
+    /// The board.
+    pub board: Board,
+    /// The player to move.
+    pub stone: Stone,
+    /// The result of the game from the perspective of the player to move,
+    /// where 1 is a win, 0.5 a draw and 0 a loss.
+    pub result: f64,
+}
+
+impl Sample {
+    /// Returns a sample labeled with the result of a game that ended with
+    /// the specified score difference, from the perspective of the player
+    /// to move.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::othello::{Board, Stone};
+    /// use magpie::tuning::Sample;
+    ///
+    /// let sample = Sample::from_score(Board::standard(), Stone::Black, -6);
+    /// assert_eq!(0.0, sample.result);
+    /// ```
+    #[must_use]
+    pub fn from_score(board: Board, stone: Stone, score: i8) -> Self {
+        let result = match score.signum() {
+            1 => 1.0,
+            -1 => 0.0,
+            _ => 0.5,
+        };
+        Self {
+            board,
+            stone,
+            result,
+        }
+    }
+}
+
+impl From<Record> for Sample {
+    fn from(record: Record) -> Self {
+        Self::from_score(record.board, record.stone, record.score)
+    }
+}
+
+impl From<PackRecord> for Sample {
+    fn from(record: PackRecord) -> Self {
+        Self::from_score(record.board, record.stone, record.result)
+    }
+}
+
+/// The progress of a tuner after an epoch.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct EpochReport {
+    /// The number of completed epochs, which is 0 before the first one.
+    pub epoch: usize,
+    /// The average loss on the training samples.
+    pub training_loss: f64,
+    /// The average loss on the validation samples, if any samples are set
+    /// aside for validation.
+    pub validation_loss: Option<f64>,
+}
+
+/// Fits the weights of an evaluator to a dataset of samples by logistic
+/// regression.
+///
+/// The evaluation of a position is turned into the probability of winning
+/// with the logistic function `1 / (1 + e^(-scale * eval))`, and the weights
+/// are fitted to minimize the cross-entropy between these probabilities and
+/// the actual results by full-batch gradient descent with the Adam
+/// optimizer.
+///
+/// Every epoch takes a single step using the gradient of all training
+/// samples. Before training, a fraction of the samples can be set aside for
+/// validation, which are spread evenly across the dataset so that samples
+/// from the same game rarely end up on both sides.
+///
+/// By default, the tuner runs 100 epochs with a learning rate of 1, a scale
+/// of 0.01 and no validation samples.
+///
+/// See the [module documentation] for an example.
+///
+/// [module documentation]: crate::tuning
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Tuner {
+    epochs: usize,
+    learning_rate: f64,
+    scale: f64,
+    validation: f64,
+}
+
+impl Default for Tuner {
+    fn default() -> Self {
+        Self {
+            epochs: 100,
+            learning_rate: 1.0,
+            scale: 0.01,
+            validation: 0.0,
+        }
+    }
+}
+
+impl Tuner {
+    /// Returns a tuner with the default settings.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the number of epochs to train for.
+    #[must_use]
+    pub fn with_epochs(self, epochs: usize) -> Self {
+        Self { epochs, ..self }
+    }
+
+    /// Sets the learning rate, which is roughly the largest change of a
+    /// weight in a single epoch.
+    #[must_use]
+    pub fn with_learning_rate(self, learning_rate: f64) -> Self {
+        Self {
+            learning_rate,
+            ..self
+        }
+    }
+
+    /// Sets the factor evaluations are multiplied with before they are
+    /// turned into probabilities.
+    ///
+    /// The scale determines the units of the fitted weights. Evaluations of
+    /// `1 / scale` correspond to a winning probability of about 73%.
+    #[must_use]
+    pub fn with_scale(self, scale: f64) -> Self {
+        Self { scale, ..self }
+    }
+
+    /// Sets the fraction of samples set aside for validation.
+    ///
+    /// # Panics
+    /// Panics if the fraction is not at least 0 and less than 1.
+    #[must_use]
+    pub fn with_validation(self, fraction: f64) -> Self {
+        assert!(
+            (0.0..1.0).contains(&fraction),
+            "the validation fraction must be in [0, 1)"
+        );
+        Self {
+            validation: fraction,
+            ..self
+        }
+    }
+
+    /// Fits the weights of the evaluator to the samples, returning the
+    /// progress after the last epoch.
+    ///
+    /// # Panics
+    /// Panics if there are no training samples.
+    pub fn fit<T: Tunable>(&self, evaluator: &mut T, samples: &[Sample]) -> EpochReport {
+        self.fit_with(evaluator, samples, |_| ControlFlow::Continue(()))
+    }
+
+    /// Fits the weights of the evaluator to the samples, calling the
+    /// specified function after every epoch, which may stop the training
+    /// early. Returns the progress after the last epoch.
+    ///
+    /// The evaluator is only updated once training has stopped. Evaluators
+    /// with integer weights see them rounded, so the loss they achieve may
+    /// differ slightly from the reported one.
+    ///
+    /// # Panics
+    /// Panics if there are no training samples.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::eval::Heuristic;
+    /// use magpie::othello::{Board, Stone};
+    /// use magpie::tuning::{Sample, Tuner};
+    /// use std::ops::ControlFlow;
+    ///
+    /// let samples = [Sample::from_score(Board::standard(), Stone::Black, 0)];
+    /// let mut losses = Vec::new();
+    /// let report = Tuner::new().fit_with(&mut Heuristic::default(), &samples, |report| {
+    ///     losses.push(report.training_loss);
+    ///     if report.epoch < 5 {
+    ///         ControlFlow::Continue(())
+    ///     } else {
+    ///         ControlFlow::Break(())
+    ///     }
+    /// });
+    /// assert_eq!(5, report.epoch);
+    /// assert_eq!(6, losses.len());
+    /// ```
+    pub fn fit_with<T, F>(
+        &self,
+        evaluator: &mut T,
+        samples: &[Sample],
+        mut on_epoch: F,
+    ) -> EpochReport
+    where
+        T: Tunable,
+        F: FnMut(&EpochReport) -> ControlFlow<()>,
+    {
+        let (training, validation) = self.encode(evaluator, samples);
+        assert!(
+            !training.is_empty(),
+            "at least one training sample is required"
+        );
+
+        let mut weights = evaluator.parameters();
+        let mut first_moment = vec![0.0; weights.len()];
+        let mut second_moment = vec![0.0; weights.len()];
+        let mut gradient = vec![0.0; weights.len()];
+
+        let mut report = self.report(0, &training, &validation, &weights);
+        let mut epoch = 0;
+        while epoch < self.epochs && on_epoch(&report).is_continue() {
+            epoch += 1;
+            gradient.fill(0.0);
+            for encoded in &training {
+                let error = self.predict(encoded, &weights) - encoded.result;
+                let step = error * self.scale / training.len() as f64;
+                for (index, value) in &encoded.features {
+                    gradient[*index] += step * value;
+                }
+            }
+
+            let correction1 = 1.0 - BETA1.powi(epoch as i32);
+            let correction2 = 1.0 - BETA2.powi(epoch as i32);
+            for (index, weight) in weights.iter_mut().enumerate() {
+                let g = gradient[index];
+                first_moment[index] = BETA1 * first_moment[index] + (1.0 - BETA1) * g;
+                second_moment[index] = BETA2 * second_moment[index] + (1.0 - BETA2) * g * g;
+                let m = first_moment[index] / correction1;
+                let v = second_moment[index] / correction2;
+                *weight -= self.learning_rate * m / (v.sqrt() + EPSILON);
+            }
+
+            report = self.report(epoch, &training, &validation, &weights);
+        }
+        if epoch == self.epochs {
+            // The final report has not been seen yet
+            let _ = on_epoch(&report);
+        }
+
+        evaluator.set_parameters(&weights);
+        report
+    }
+
+    /// Extracts the features of every sample, splitting them into training
+    /// and validation samples.
+    fn encode<T: Tunable>(
+        &self,
+        evaluator: &T,
+        samples: &[Sample],
+    ) -> (Vec<Encoded>, Vec<Encoded>) {
+        let mut training = Vec::new();
+        let mut validation = Vec::new();
+        for (index, sample) in samples.iter().enumerate() {
+            let mut features = Vec::new();
+            evaluator.features(&sample.board, sample.stone, &mut features);
+            let encoded = Encoded {
+                features,
+                result: sample.result,
+            };
+            // Spreads the validation samples evenly across the dataset
+            let before = (index as f64 * self.validation).floor();
+            let after = ((index + 1) as f64 * self.validation).floor();
+            if after > before {
+                validation.push(encoded);
+            } else {
+                training.push(encoded);
+            }
+        }
+        (training, validation)
+    }
+
+    fn report(
+        &self,
+        epoch: usize,
+        training: &[Encoded],
+        validation: &[Encoded],
+        weights: &[f64],
+    ) -> EpochReport {
+        EpochReport {
+            epoch,
+            training_loss: self.loss(training, weights),
+            validation_loss: (!validation.is_empty()).then(|| self.loss(validation, weights)),
+        }
+    }
+
+    /// Returns the average cross-entropy of the predictions.
+    fn loss(&self, samples: &[Encoded], weights: &[f64]) -> f64 {
+        let total: f64 = samples
+            .iter()
+            .map(|encoded| {
+                let p = self.predict(encoded, weights).clamp(1e-12, 1.0 - 1e-12);
+                -(encoded.result * p.ln() + (1.0 - encoded.result) * (1.0 - p).ln())
+            })
+            .sum();
+        total / samples.len() as f64
+    }
+
+    /// Returns the predicted probability of winning.
+    fn predict(&self, encoded: &Encoded, weights: &[f64]) -> f64 {
+        let eval: f64 = encoded
+            .features
+            .iter()
+            .map(|(index, value)| weights[*index] * value)
+            .sum();
+        1.0 / (1.0 + (-self.scale * eval).exp())
+    }
+}
+
+/// The features of a sample.
+struct Encoded {
+    features: Vec<(usize, f64)>,
+    result: f64,
+}
@@ -0,0 +1,145 @@
+use crate::othello::{Board, Format, OthelloError, Position, Stone};
+use wasm_bindgen::prelude::*;
+
+/// The stone colors, exported to JavaScript as `Stone`.
+#[wasm_bindgen(js_name = Stone)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum WasmStone {
+    /// The black stone, which moves first.
+    Black = 0,
+    /// The white stone.
+    White = 1,
+}
+
+impl From<WasmStone> for Stone {
+    fn from(stone: WasmStone) -> Self {
+        match stone {
+            WasmStone::Black => Stone::Black,
+            WasmStone::White => Stone::White,
+        }
+    }
+}
+
+impl From<Stone> for WasmStone {
+    fn from(stone: Stone) -> Self {
+        match stone {
+            Stone::Black => WasmStone::Black,
+            Stone::White => WasmStone::White,
+        }
+    }
+}
+
+/// An Othello board, exported to JavaScript as `Board`.
+///
+/// # Examples
+/// ```rust
+/// use magpie::wasm::{WasmBoard, WasmStone};
+///
+/// let mut board = WasmBoard::new();
+/// board.play(WasmStone::Black, "f5").unwrap();
+/// assert_eq!(4, board.count(WasmStone::Black));
+/// ```
+#[wasm_bindgen(js_name = Board)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct WasmBoard(pub(crate) Board);
+
+#[wasm_bindgen(js_class = Board)]
+impl WasmBoard {
+    /// Returns a board with the standard opening position configured.
+    #[wasm_bindgen(constructor)]
+    #[must_use]
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        Self(Board::standard())
+    }
+
+    /// Returns a completely empty board.
+    #[must_use]
+    pub fn empty() -> Self {
+        Self(Board::empty())
+    }
+
+    /// Returns a board with the specified stones, given as bitboards.
+    ///
+    /// Throws if the stones overlap.
+    #[wasm_bindgen(js_name = fromBits)]
+    pub fn from_bits(black: u64, white: u64) -> Result<WasmBoard, JsError> {
+        Board::try_from((black, white))
+            .map(Self)
+            .map_err(to_js_error)
+    }
+
+    /// Returns the stones of the specified player as a bitboard.
+    #[must_use]
+    pub fn bits(&self, stone: WasmStone) -> u64 {
+        self.0.bits_for(stone.into()).raw()
+    }
+
+    /// Returns the 64 squares of the board, starting with A1 and ending with
+    /// H8, where 0 is empty, 1 is black and 2 is white.
+    #[must_use]
+    pub fn cells(&self) -> Vec<u8> {
+        (0..64)
+            .map(|index| Position::from_index(index).unwrap())
+            .map(|pos| match self.0.stone_at(pos) {
+                None => 0,
+                Some(Stone::Black) => 1,
+                Some(Stone::White) => 2,
+            })
+            .collect()
+    }
+
+    /// Returns the number of stones of the specified player.
+    #[must_use]
+    pub fn count(&self, stone: WasmStone) -> u8 {
+        self.0.bits_for(stone.into()).count_set()
+    }
+
+    /// Returns the legal moves of the specified player as a bitboard.
+    #[wasm_bindgen(js_name = legalMoves)]
+    #[must_use]
+    pub fn legal_moves(&self, stone: WasmStone) -> u64 {
+        self.0.moves_for(stone.into()).raw()
+    }
+
+    /// Returns the legal moves of the specified player in notation.
+    #[must_use]
+    pub fn moves(&self, stone: WasmStone) -> Vec<String> {
+        self.0
+            .moves_for(stone.into())
+            .hot_bits()
+            .map(|pos| pos.to_string())
+            .collect()
+    }
+
+    /// Returns true if and only if the move is legal for the specified
+    /// player.
+    ///
+    /// Throws if the position cannot be parsed.
+    #[wasm_bindgen(js_name = isLegalMove)]
+    pub fn is_legal_move(&self, stone: WasmStone, position: &str) -> Result<bool, JsError> {
+        let pos: Position = position.parse()?;
+        Ok(self.0.is_legal_move(stone.into(), pos))
+    }
+
+    /// Plays the move for the specified player.
+    ///
+    /// Throws if the position cannot be parsed or if the move is illegal.
+    pub fn play(&mut self, stone: WasmStone, position: &str) -> Result<(), JsError> {
+        let pos: Position = position.parse()?;
+        self.0.try_play(stone.into(), pos).map_err(to_js_error)?;
+        Ok(())
+    }
+
+    /// Returns a human-readable drawing of the board.
+    #[wasm_bindgen(js_name = toString)]
+    #[must_use]
+    #[allow(clippy::inherent_to_string)]
+    pub fn to_string(&self) -> String {
+        self.0.display().with_format(Format::Compact).to_string()
+    }
+}
+
+pub(crate) fn to_js_error(error: OthelloError) -> JsError {
+    JsError::new(&error.to_string())
+}
@@ -0,0 +1,131 @@
+use crate::{
+    othello::{Game, Position, Status, Stone},
+    wasm::board::{WasmBoard, WasmStone, to_js_error},
+};
+use wasm_bindgen::prelude::*;
+
+/// The status of a game, exported to JavaScript as `Status`.
+#[wasm_bindgen(js_name = Status)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum WasmStatus {
+    /// The game is still in progress.
+    Progressing = 0,
+    /// The game has concluded with black as the winner.
+    BlackWins = 1,
+    /// The game has concluded with white as the winner.
+    WhiteWins = 2,
+    /// The game has concluded in a draw.
+    Draw = 3,
+}
+
+/// An Othello game that enforces the rules, exported to JavaScript as
+/// `Game`.
+///
+/// # Examples
+/// ```rust
+/// use magpie::wasm::{WasmGame, WasmStatus, WasmStone};
+///
+/// let mut game = WasmGame::new();
+/// game.play("f5").unwrap();
+/// assert_eq!(WasmStone::White, game.current_turn());
+/// assert_eq!(WasmStatus::Progressing, game.status());
+/// assert_eq!("f5", game.transcript());
+/// ```
+#[wasm_bindgen(js_name = Game)]
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct WasmGame(pub(crate) Game);
+
+#[wasm_bindgen(js_class = Game)]
+impl WasmGame {
+    /// Returns a game with the standard opening position configured.
+    #[wasm_bindgen(constructor)]
+    #[must_use]
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        Self(Game::new())
+    }
+
+    /// Returns a game starting from the specified board.
+    ///
+    /// Throws if the board is invalid.
+    #[wasm_bindgen(js_name = fromBoard)]
+    pub fn from_board(board: &WasmBoard, next: WasmStone) -> Result<WasmGame, JsError> {
+        Game::from_state(board.0, next.into(), false)
+            .map(Self)
+            .map_err(to_js_error)
+    }
+
+    /// Returns a copy of the board.
+    #[must_use]
+    pub fn board(&self) -> WasmBoard {
+        WasmBoard(self.0.board())
+    }
+
+    /// Returns the stone of the player to move.
+    #[wasm_bindgen(js_name = currentTurn)]
+    #[must_use]
+    pub fn current_turn(&self) -> WasmStone {
+        self.0.current_turn().into()
+    }
+
+    /// Returns the status of the game.
+    #[must_use]
+    pub fn status(&self) -> WasmStatus {
+        match self.0.status() {
+            Status::Progressing => WasmStatus::Progressing,
+            Status::Win(Stone::Black) => WasmStatus::BlackWins,
+            Status::Win(Stone::White) => WasmStatus::WhiteWins,
+            Status::Draw => WasmStatus::Draw,
+        }
+    }
+
+    /// Returns the legal moves of the player to move in notation.
+    #[must_use]
+    pub fn moves(&self) -> Vec<String> {
+        self.0
+            .moves()
+            .hot_bits()
+            .map(|pos| pos.to_string())
+            .collect()
+    }
+
+    /// Returns the legal moves of the player to move as a bitboard.
+    #[wasm_bindgen(js_name = legalMoves)]
+    #[must_use]
+    pub fn legal_moves(&self) -> u64 {
+        self.0.moves().raw()
+    }
+
+    /// Plays the move for the player to move.
+    ///
+    /// Throws if the position cannot be parsed or if the move is illegal.
+    pub fn play(&mut self, position: &str) -> Result<(), JsError> {
+        let pos: Position = position.parse()?;
+        self.0.play(pos).map_err(to_js_error)
+    }
+
+    /// Passes the turn if the player to move has no legal moves.
+    ///
+    /// Returns whether or not the turn was passed.
+    #[wasm_bindgen(js_name = passIfForced)]
+    pub fn pass_if_forced(&mut self) -> bool {
+        self.0.pass_if_forced()
+    }
+
+    /// Takes back the last move. Returns false if there was nothing to undo.
+    pub fn undo(&mut self) -> bool {
+        self.0.undo().is_some()
+    }
+
+    /// Replays the last move taken back. Returns false if there was nothing
+    /// to redo.
+    pub fn redo(&mut self) -> bool {
+        self.0.redo().is_some()
+    }
+
+    /// Returns the moves played so far as a transcript, such as `f5d6c3`.
+    #[must_use]
+    pub fn transcript(&self) -> String {
+        self.0.transcript().to_string()
+    }
+}
@@ -0,0 +1,38 @@
+//! JavaScript bindings through `wasm-bindgen`.
+//!
+//! The bindings wrap the [`Board`], [`Game`] and [`Position`] types in
+//! classes that are exported to JavaScript under the same names. The types
+//! crossing the boundary are chosen to be convenient to use from
+//! JavaScript:
+//!
+//! - Positions are passed as strings in human-readable notation, such as
+//!   `"d3"`.
+//! - Bitboards are passed as `BigInt`s, where the most significant bit
+//!   represents A1 and the least significant bit represents H8.
+//! - The contents of a board can be retrieved as a `Uint8Array` of 64
+//!   squares, starting with A1.
+//!
+//! Errors are thrown as JavaScript `Error` objects.
+//!
+//! ```js
+//! import { Game } from "magpie";
+//!
+//! const game = new Game();
+//! game.play("f5");
+//! console.log(game.moves()); // ["d6", "f6", "f4"]
+//! ```
+//!
+//! [`Board`]: crate::othello::Board
+//! [`Game`]: crate::othello::Game
+//! [`Position`]: crate::othello::Position
+
+/// Exports boards and stones.
+mod board;
+/// Exports games.
+mod game;
+/// Exports positions.
+mod position;
+
+pub use board::{WasmBoard, WasmStone};
+pub use game::{WasmGame, WasmStatus};
+pub use position::WasmPosition;
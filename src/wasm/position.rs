@@ -0,0 +1,69 @@
+use crate::othello::Position;
+use wasm_bindgen::prelude::*;
+
+/// A single square on the board, exported to JavaScript as `Position`.
+///
+/// # Examples
+/// ```rust
+/// use magpie::wasm::WasmPosition;
+///
+/// let position = WasmPosition::new("D3").unwrap();
+/// assert_eq!("d3", position.to_string());
+/// assert_eq!(2, position.rank());
+/// assert_eq!(3, position.file());
+/// ```
+#[wasm_bindgen(js_name = Position)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct WasmPosition(pub(crate) Position);
+
+#[wasm_bindgen(js_class = Position)]
+impl WasmPosition {
+    /// Parses a position from human-readable notation.
+    ///
+    /// Throws if the notation is invalid.
+    #[wasm_bindgen(constructor)]
+    pub fn new(notation: &str) -> Result<WasmPosition, JsError> {
+        Ok(Self(notation.parse()?))
+    }
+
+    /// Returns the position with the specified index, where A1 is 0 and H8
+    /// is 63.
+    ///
+    /// Throws if the index is out of bounds.
+    #[wasm_bindgen(js_name = fromIndex)]
+    pub fn from_index(index: u8) -> Result<WasmPosition, JsError> {
+        Ok(Self(Position::from_index(index)?))
+    }
+
+    /// Returns the index of the position, where A1 is 0 and H8 is 63.
+    #[must_use]
+    pub fn index(&self) -> u8 {
+        self.0.raw().leading_zeros() as u8
+    }
+
+    /// Returns the zero-indexed rank of the position.
+    #[must_use]
+    pub fn rank(&self) -> u8 {
+        self.0.rank()
+    }
+
+    /// Returns the zero-indexed file of the position.
+    #[must_use]
+    pub fn file(&self) -> u8 {
+        self.0.file()
+    }
+
+    /// Returns the position as a bitboard with a single bit set.
+    #[must_use]
+    pub fn bits(&self) -> u64 {
+        self.0.raw()
+    }
+
+    /// Returns the position in human-readable notation.
+    #[wasm_bindgen(js_name = toString)]
+    #[must_use]
+    #[allow(clippy::inherent_to_string)]
+    pub fn to_string(&self) -> String {
+        self.0.to_notation()
+    }
+}
@@ -0,0 +1,98 @@
+use crate::wthor::{
+    game::{GameRecord, read_games},
+    header::WthorError,
+    names::{read_players, read_tournaments},
+};
+use alloc::{string::String, vec::Vec};
+
+/// Represents the games of a WTHOR archive together with the names of the
+/// players and tournaments they refer to.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct Database {
+    players: Vec<String>,
+    tournaments: Vec<String>,
+    games: Vec<GameRecord>,
+}
+
+impl Database {
+    /// Parses a database from the contents of a `.jou`, `.trn` and `.wtb`
+    /// file, in that order.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::wthor::Database;
+    ///
+    /// let mut players = vec![0; 16 + 2 * 20];
+    /// players[8] = 2;
+    /// players[16..21].copy_from_slice(b"Alice");
+    /// players[36..39].copy_from_slice(b"Bob");
+    ///
+    /// let tournaments = vec![0; 16];
+    ///
+    /// let mut games = vec![0; 16 + 68];
+    /// games[4] = 1;
+    /// games[16 + 4] = 1; // White player
+    ///
+    /// let database = Database::from_bytes(&players, &tournaments, &games).unwrap();
+    /// let game = &database.games()[0];
+    /// assert_eq!(Some("Alice"), database.black_name(game));
+    /// assert_eq!(Some("Bob"), database.white_name(game));
+    /// assert_eq!(None, database.tournament_name(game));
+    /// ```
+    pub fn from_bytes(
+        players: &[u8],
+        tournaments: &[u8],
+        games: &[u8],
+    ) -> Result<Self, WthorError> {
+        let (_, players) = read_players(players)?;
+        let (_, tournaments) = read_tournaments(tournaments)?;
+        let (_, games) = read_games(games)?;
+        Ok(Self {
+            players,
+            tournaments,
+            games,
+        })
+    }
+
+    /// Returns all games contained in the database.
+    #[must_use]
+    pub fn games(&self) -> &[GameRecord] {
+        &self.games
+    }
+
+    /// Returns the names of all players, indexed by player number.
+    #[must_use]
+    pub fn players(&self) -> &[String] {
+        &self.players
+    }
+
+    /// Returns the names of all tournaments, indexed by tournament number.
+    #[must_use]
+    pub fn tournaments(&self) -> &[String] {
+        &self.tournaments
+    }
+
+    /// Returns the name of the player with the black stones, if known.
+    #[must_use]
+    pub fn black_name(&self, game: &GameRecord) -> Option<&str> {
+        self.players
+            .get(usize::from(game.black_player))
+            .map(String::as_str)
+    }
+
+    /// Returns the name of the player with the white stones, if known.
+    #[must_use]
+    pub fn white_name(&self, game: &GameRecord) -> Option<&str> {
+        self.players
+            .get(usize::from(game.white_player))
+            .map(String::as_str)
+    }
+
+    /// Returns the name of the tournament the game was played in, if known.
+    #[must_use]
+    pub fn tournament_name(&self, game: &GameRecord) -> Option<&str> {
+        self.tournaments
+            .get(usize::from(game.tournament))
+            .map(String::as_str)
+    }
+}
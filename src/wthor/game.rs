@@ -0,0 +1,109 @@
+use crate::{
+    othello::{Game, Move, Position, Transcript, TranscriptError},
+    wthor::header::{HEADER_SIZE, Header, WthorError},
+};
+use alloc::vec::Vec;
+
+/// The size of a single game record, in bytes, for games played on a 8x8
+/// board.
+pub(crate) const GAME_RECORD_SIZE: usize = 68;
+
+/// Represents a single game stored in a `.wtb` file.
+///
+/// Players and tournaments are referred to by their index in the
+/// corresponding `.jou` and `.trn` files.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct GameRecord {
+    /// Index of the tournament the game was played in.
+    pub tournament: u16,
+    /// Index of the player with the black stones.
+    pub black_player: u16,
+    /// Index of the player with the white stones.
+    pub white_player: u16,
+    /// The number of black stones at the end of the game.
+    pub black_score: u8,
+    /// The number of black stones at the end of the game given perfect play
+    /// from the depth specified in the [`Header`].
+    ///
+    /// [`Header`]: crate::wthor::Header
+    pub theoretical_score: u8,
+    /// The moves played, in order. Passes are not recorded.
+    pub moves: Vec<Position>,
+}
+
+impl GameRecord {
+    /// Parses a single 68 byte game record.
+    pub(crate) fn parse(bytes: &[u8], index: usize) -> Result<Self, WthorError> {
+        let bytes = bytes.get(..GAME_RECORD_SIZE).ok_or(WthorError::Truncated)?;
+        let moves = bytes[8..]
+            .iter()
+            .take_while(|m| **m != 0)
+            .map(|m| {
+                let (rank, file) = (m / 10, m % 10);
+                if (1..=8).contains(&rank) && (1..=8).contains(&file) {
+                    Position::from_rank_file(rank - 1, file - 1)
+                        .map_err(|_| WthorError::InvalidMove(index))
+                } else {
+                    Err(WthorError::InvalidMove(index))
+                }
+            })
+            .collect::<Result<Vec<Position>, WthorError>>()?;
+        Ok(Self {
+            tournament: u16::from_le_bytes([bytes[0], bytes[1]]),
+            black_player: u16::from_le_bytes([bytes[2], bytes[3]]),
+            white_player: u16::from_le_bytes([bytes[4], bytes[5]]),
+            black_score: bytes[6],
+            theoretical_score: bytes[7],
+            moves,
+        })
+    }
+
+    /// Returns the moves of the game as a transcript.
+    ///
+    /// Passes are not recorded in WTHOR files, which is why the transcript
+    /// will not contain any either.
+    #[must_use]
+    pub fn transcript(&self) -> Transcript {
+        self.moves.iter().copied().map(Move::Play).collect()
+    }
+
+    /// Replays the game from the standard opening position.
+    ///
+    /// Returns an error if any of the recorded moves are illegal.
+    pub fn replay(&self) -> Result<Game, TranscriptError> {
+        self.transcript().replay()
+    }
+}
+
+/// Parses the contents of a `.wtb` file.
+///
+/// Returns an error if the file is truncated, contains games played on
+/// anything other than a 8x8 board, or if any moves could not be decoded.
+///
+/// # Examples
+/// ```rust
+/// use magpie::wthor::read_games;
+///
+/// let mut bytes = vec![0; 16 + 68];
+/// bytes[4] = 1; // Number of games
+/// bytes[16 + 6] = 33; // Black score
+/// bytes[16 + 8] = 56; // F5
+///
+/// let (header, games) = read_games(&bytes).unwrap();
+/// assert_eq!(1, header.game_count);
+/// assert_eq!("f5", games[0].transcript().to_string());
+/// ```
+pub fn read_games(bytes: &[u8]) -> Result<(Header, Vec<GameRecord>), WthorError> {
+    let header = Header::parse(bytes)?;
+    if header.board_size != 8 {
+        return Err(WthorError::UnsupportedBoardSize(header.board_size));
+    }
+    let games = (0..header.game_count as usize)
+        .map(|index| {
+            let start = HEADER_SIZE + index * GAME_RECORD_SIZE;
+            let record = bytes.get(start..).ok_or(WthorError::Truncated)?;
+            GameRecord::parse(record, index)
+        })
+        .collect::<Result<Vec<GameRecord>, WthorError>>()?;
+    Ok((header, games))
+}
@@ -0,0 +1,91 @@
+/// The size of the header, in bytes, shared by all WTHOR files.
+pub(crate) const HEADER_SIZE: usize = 16;
+
+/// Represents the header found at the start of every WTHOR file.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct Header {
+    /// The year the file was created.
+    pub created_year: u16,
+    /// The month the file was created.
+    pub created_month: u8,
+    /// The day the file was created.
+    pub created_day: u8,
+    /// The number of game records, only used by `.wtb` files.
+    pub game_count: u32,
+    /// The number of name records, only used by `.jou` and `.trn` files.
+    pub name_count: u16,
+    /// The year the games were played.
+    pub game_year: u16,
+    /// The size of the board the games were played on.
+    pub board_size: u8,
+    /// Whether or not the games are solitaires.
+    pub solitaire: bool,
+    /// The depth from which the theoretical scores were calculated.
+    pub depth: u8,
+}
+
+impl Header {
+    /// Parses a header from the first 16 bytes of a WTHOR file.
+    ///
+    /// Returns an error if fewer than 16 bytes are available.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::wthor::Header;
+    ///
+    /// let mut bytes = [0; 16];
+    /// bytes[0] = 20; // Century
+    /// bytes[1] = 4; // Year
+    /// bytes[4] = 1; // Number of games
+    /// bytes[12] = 8; // Board size
+    ///
+    /// let header = Header::parse(&bytes).unwrap();
+    /// assert_eq!(2004, header.created_year);
+    /// assert_eq!(1, header.game_count);
+    /// ```
+    pub fn parse(bytes: &[u8]) -> Result<Self, WthorError> {
+        let bytes = bytes.get(..HEADER_SIZE).ok_or(WthorError::Truncated)?;
+        Ok(Self {
+            created_year: u16::from(bytes[0]) * 100 + u16::from(bytes[1]),
+            created_month: bytes[2],
+            created_day: bytes[3],
+            game_count: u32::from_le_bytes([bytes[4], bytes[5], bytes[6], bytes[7]]),
+            name_count: u16::from_le_bytes([bytes[8], bytes[9]]),
+            game_year: u16::from_le_bytes([bytes[10], bytes[11]]),
+            board_size: if bytes[12] == 0 { 8 } else { bytes[12] },
+            solitaire: bytes[13] == 1,
+            depth: bytes[14],
+        })
+    }
+}
+
+/// This enum represents errors that may occur when reading WTHOR files.
+#[derive(Debug, Eq, PartialEq, Hash)]
+pub enum WthorError {
+    /// Indicates that the file ended before all records could be read.
+    Truncated,
+    /// Indicates that the games were played on a board that is not 8x8.
+    UnsupportedBoardSize(u8),
+    /// Indicates that a game record contained a move that could not be
+    /// decoded. Carries the index of the offending game.
+    InvalidMove(usize),
+    /// Indicates that the underlying source could not be read.
+    #[cfg(feature = "std")]
+    Io(std::io::ErrorKind),
+}
+
+impl core::fmt::Display for WthorError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            WthorError::Truncated => f.write_str("unexpected end of WTHOR file"),
+            WthorError::UnsupportedBoardSize(size) => {
+                write!(f, "unsupported board size {size}x{size}")
+            }
+            WthorError::InvalidMove(index) => write!(f, "game {index} contains an invalid move"),
+            #[cfg(feature = "std")]
+            WthorError::Io(kind) => write!(f, "failed to read WTHOR file: {kind}"),
+        }
+    }
+}
+
+impl core::error::Error for WthorError {}
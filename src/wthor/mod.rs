@@ -0,0 +1,54 @@
+//! Reader for the WTHOR database format.
+//!
+//! The French Othello Federation (FFO) publishes every recorded tournament
+//! game in the WTHOR format. An archive consists of three kinds of files:
+//!
+//! - `.wtb` files contain the games themselves, one file per year
+//! - `.jou` files contain the names of the players
+//! - `.trn` files contain the names of the tournaments
+//!
+//! Games refer to players and tournaments by their index in the respective
+//! name files. The [`Database`] struct ties all three together.
+//!
+//! Large archives do not need to be loaded into memory at once. The
+//! [`GameReader`] reads games one at a time from any source implementing
+//! [`Read`].
+//!
+//! [`Read`]: std::io::Read
+//! [`Database`]: crate::wthor::Database
+//! [`GameReader`]: crate::wthor::GameReader
+//!
+//! # Examples
+//! ```rust,no_run
+//! use magpie::wthor::Database;
+//!
+//! let players = std::fs::read("WTHOR.JOU").unwrap();
+//! let tournaments = std::fs::read("WTHOR.TRN").unwrap();
+//! let games = std::fs::read("WTH_2004.wtb").unwrap();
+//!
+//! let database = Database::from_bytes(&players, &tournaments, &games).unwrap();
+//! for game in database.games() {
+//!     let black = database.black_name(game).unwrap_or("?");
+//!     let white = database.white_name(game).unwrap_or("?");
+//!     println!("{black} vs {white}: {}", game.transcript());
+//! }
+//! ```
+
+/// Ties together games, players, and tournaments.
+mod database;
+/// Parses game records.
+mod game;
+/// Parses the header that is shared by all file types.
+mod header;
+/// Parses player and tournament names.
+mod names;
+/// Reads games lazily from any source.
+#[cfg(feature = "std")]
+mod reader;
+
+pub use database::Database;
+pub use game::{GameRecord, read_games};
+pub use header::{Header, WthorError};
+pub use names::{read_players, read_tournaments};
+#[cfg(feature = "std")]
+pub use reader::GameReader;
@@ -0,0 +1,70 @@
+use crate::wthor::header::{HEADER_SIZE, Header, WthorError};
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+
+/// The size of a single player record, in bytes.
+const PLAYER_RECORD_SIZE: usize = 20;
+/// The size of a single tournament record, in bytes.
+const TOURNAMENT_RECORD_SIZE: usize = 26;
+
+/// Parses the contents of a `.jou` file, which contains player names.
+///
+/// # Examples
+/// ```rust
+/// use magpie::wthor::read_players;
+///
+/// let mut bytes = vec![0; 16 + 20];
+/// bytes[8] = 1; // Number of players
+/// bytes[16..21].copy_from_slice(b"Tamaz");
+///
+/// let (_, players) = read_players(&bytes).unwrap();
+/// assert_eq!(vec!["Tamaz"], players);
+/// ```
+pub fn read_players(bytes: &[u8]) -> Result<(Header, Vec<String>), WthorError> {
+    read_names(bytes, PLAYER_RECORD_SIZE)
+}
+
+/// Parses the contents of a `.trn` file, which contains tournament names.
+///
+/// # Examples
+/// ```rust
+/// use magpie::wthor::read_tournaments;
+///
+/// let mut bytes = vec![0; 16 + 26];
+/// bytes[8] = 1; // Number of tournaments
+/// bytes[16..27].copy_from_slice(b"Paris Open ");
+///
+/// let (_, tournaments) = read_tournaments(&bytes).unwrap();
+/// assert_eq!(vec!["Paris Open"], tournaments);
+/// ```
+pub fn read_tournaments(bytes: &[u8]) -> Result<(Header, Vec<String>), WthorError> {
+    read_names(bytes, TOURNAMENT_RECORD_SIZE)
+}
+
+fn read_names(bytes: &[u8], record_size: usize) -> Result<(Header, Vec<String>), WthorError> {
+    let header = Header::parse(bytes)?;
+    let names = (0..usize::from(header.name_count))
+        .map(|index| {
+            let start = HEADER_SIZE + index * record_size;
+            bytes
+                .get(start..start + record_size)
+                .map(decode_name)
+                .ok_or(WthorError::Truncated)
+        })
+        .collect::<Result<Vec<String>, WthorError>>()?;
+    Ok((header, names))
+}
+
+// Names are null-terminated and encoded as ISO-8859-1, where every byte maps
+// directly to the Unicode code point with the same value.
+pub(crate) fn decode_name(bytes: &[u8]) -> String {
+    bytes
+        .iter()
+        .take_while(|b| **b != 0)
+        .map(|b| char::from(*b))
+        .collect::<String>()
+        .trim_end()
+        .to_string()
+}
@@ -0,0 +1,119 @@
+use crate::wthor::{
+    game::{GAME_RECORD_SIZE, GameRecord},
+    header::{HEADER_SIZE, Header, WthorError},
+};
+use std::io::{self, Read};
+
+/// Reads game records one at a time from a `.wtb` file.
+///
+/// Unlike [`read_games`], the reader never holds more than a single record
+/// in memory, which makes it suitable for iterating over very large
+/// archives. The reader stops after the first error.
+///
+/// [`read_games`]: crate::wthor::read_games
+///
+/// # Examples
+/// ```rust
+/// use magpie::wthor::GameReader;
+///
+/// let mut bytes = vec![0; 16 + 2 * 68];
+/// bytes[4] = 2; // Number of games
+/// bytes[16 + 8] = 56; // F5
+/// bytes[16 + 68 + 8] = 56; // F5
+/// bytes[16 + 68 + 9] = 66; // F6
+///
+/// let reader = GameReader::new(bytes.as_slice()).unwrap();
+/// assert_eq!(2, reader.header().game_count);
+/// let games: Vec<_> = reader.collect::<Result<_, _>>().unwrap();
+/// assert_eq!("f5f6", games[1].transcript().to_string());
+/// ```
+#[derive(Debug)]
+pub struct GameReader<R> {
+    reader: R,
+    header: Header,
+    index: usize,
+}
+
+impl<R: Read> GameReader<R> {
+    /// Reads the header from the source and returns a reader positioned at
+    /// the first game.
+    ///
+    /// Returns an error if the header could not be read or if the games were
+    /// played on anything other than a 8x8 board.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::wthor::{GameReader, WthorError};
+    ///
+    /// let bytes = [0; 8];
+    /// let reader = GameReader::new(bytes.as_slice());
+    /// assert_eq!(Some(WthorError::Truncated), reader.err());
+    /// ```
+    pub fn new(mut reader: R) -> Result<Self, WthorError> {
+        let mut bytes = [0; HEADER_SIZE];
+        reader.read_exact(&mut bytes).map_err(from_io)?;
+        let header = Header::parse(&bytes)?;
+        if header.board_size != 8 {
+            return Err(WthorError::UnsupportedBoardSize(header.board_size));
+        }
+        Ok(Self {
+            reader,
+            header,
+            index: 0,
+        })
+    }
+
+    /// Returns the header of the file.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use magpie::wthor::GameReader;
+    ///
+    /// let mut bytes = [0; 16];
+    /// bytes[10..12].copy_from_slice(&2004_u16.to_le_bytes());
+    ///
+    /// let reader = GameReader::new(bytes.as_slice()).unwrap();
+    /// assert_eq!(2004, reader.header().game_year);
+    /// ```
+    #[must_use]
+    pub fn header(&self) -> Header {
+        self.header
+    }
+
+    fn remaining(&self) -> usize {
+        (self.header.game_count as usize).saturating_sub(self.index)
+    }
+}
+
+impl<R: Read> Iterator for GameReader<R> {
+    type Item = Result<GameRecord, WthorError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining() == 0 {
+            return None;
+        }
+        let mut bytes = [0; GAME_RECORD_SIZE];
+        let record = self
+            .reader
+            .read_exact(&mut bytes)
+            .map_err(from_io)
+            .and_then(|()| GameRecord::parse(&bytes, self.index));
+        self.index = if record.is_ok() {
+            self.index + 1
+        } else {
+            self.header.game_count as usize
+        };
+        Some(record)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.remaining()))
+    }
+}
+
+fn from_io(error: io::Error) -> WthorError {
+    match error.kind() {
+        io::ErrorKind::UnexpectedEof => WthorError::Truncated,
+        kind => WthorError::Io(kind),
+    }
+}
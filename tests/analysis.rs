@@ -0,0 +1,233 @@
+use magpie::{
+    analysis::{
+        AnnotationSettings, MoveQuality, StagedThresholds, Thresholds, annotate, eval_curve,
+        write_curve_csv, write_curve_json,
+    },
+    eval::{DiscDifference, Heuristic},
+    othello::{Game, Move, Stone},
+    search::{SearchLimits, Searcher},
+};
+use rand::{SeedableRng, rngs::StdRng};
+
+mod common;
+
+use common::random_game;
+
+#[test]
+fn every_placed_stone_is_annotated() {
+    let mut rng = StdRng::seed_from_u64(3);
+    let game = random_game(&mut rng, 60);
+    let mut searcher = Searcher::new(Heuristic::default());
+    let annotation = annotate(
+        &game,
+        &mut searcher,
+        &AnnotationSettings::new(SearchLimits::depth(2)),
+    );
+
+    let placed = game
+        .history()
+        .iter()
+        .filter(|played| played.mv() != Move::Pass)
+        .count();
+    assert_eq!(placed, annotation.moves.len());
+    for annotated in &annotation.moves {
+        let played = game.history()[annotated.ply];
+        assert_eq!(Move::Play(annotated.played), played.mv());
+        assert_eq!(played.stone(), annotated.stone);
+        assert_eq!(
+            annotated.best_score - annotated.played_score,
+            annotated.loss
+        );
+        assert!(annotated.loss >= 0);
+    }
+    for stone in [Stone::Black, Stone::White] {
+        let summary = annotation.summary(stone);
+        let moves: Vec<_> = annotation
+            .moves
+            .iter()
+            .filter(|annotated| annotated.stone == stone)
+            .collect();
+        assert_eq!(moves.len(), summary.moves);
+        assert_eq!(
+            moves
+                .iter()
+                .map(|annotated| i64::from(annotated.loss))
+                .sum::<i64>(),
+            summary.total_loss
+        );
+        assert!(summary.best_moves <= summary.moves);
+        assert!((0.0..=1.0).contains(&summary.accuracy()));
+    }
+    assert_eq!(
+        annotation.black.moves + annotation.white.moves,
+        annotation.moves.len()
+    );
+}
+
+#[test]
+fn moves_are_labeled_by_thresholds() {
+    let mut rng = StdRng::seed_from_u64(5);
+    let game = random_game(&mut rng, 60);
+    let thresholds = Thresholds {
+        inaccuracy: 100,
+        mistake: 300,
+        blunder: 600,
+    };
+    let settings = AnnotationSettings::new(SearchLimits::depth(3)).with_thresholds(thresholds);
+    let annotation = annotate(&game, &mut Searcher::new(DiscDifference), &settings);
+
+    for annotated in &annotation.moves {
+        assert_eq!(thresholds.classify(annotated.loss), annotated.quality);
+        if annotated.played == annotated.best {
+            assert_eq!(MoveQuality::Best, annotated.quality);
+        }
+    }
+    // Random moves are rarely all best
+    let black = annotation.summary(Stone::Black);
+    assert!(black.inaccuracies + black.mistakes + black.blunders > 0);
+    assert_eq!(
+        black.blunders,
+        annotation
+            .with_quality(MoveQuality::Blunder)
+            .filter(|annotated| annotated.stone == Stone::Black)
+            .count()
+    );
+}
+
+#[test]
+fn thresholds_depend_on_stage() {
+    let mut rng = StdRng::seed_from_u64(13);
+    let game = random_game(&mut rng, 60);
+    let thresholds = StagedThresholds::new(vec![
+        Thresholds::discs(6, 12, 24),
+        Thresholds::discs(4, 8, 16),
+        Thresholds::discs(1, 2, 4),
+    ]);
+    let settings = AnnotationSettings::new(SearchLimits::depth(2)).with_thresholds(thresholds);
+    let annotation = annotate(&game, &mut Searcher::new(DiscDifference), &settings);
+
+    let mut replay = game.clone();
+    while replay.undo().is_some() {}
+    let mut stages = Vec::new();
+    for _ in game.history() {
+        stages.push(replay.board().stage());
+        replay.redo();
+    }
+    for annotated in &annotation.moves {
+        assert_eq!(stages[annotated.ply], annotated.stage);
+        assert_eq!(
+            settings
+                .thresholds
+                .classify(annotated.stage, annotated.loss),
+            annotated.quality
+        );
+    }
+    // Losing 4 discs is a blunder in the last third of the game
+    let late: Vec<_> = annotation
+        .moves
+        .iter()
+        .filter(|annotated| annotated.stage > 40 && annotated.loss >= 400)
+        .collect();
+    assert!(!late.is_empty());
+    assert!(
+        late.iter()
+            .all(|annotated| annotated.quality == MoveQuality::Blunder)
+    );
+}
+
+#[test]
+fn solved_endgame_losses_are_exact() {
+    let mut rng = StdRng::seed_from_u64(8);
+    let mut full = random_game(&mut rng, 60);
+    let mut endgame_moves = Vec::new();
+    while full.empty_squares().count_set() < 10 {
+        endgame_moves.push(full.undo().unwrap());
+    }
+    // Only the last few moves are annotated, which the searcher solves
+    let mut game = Game::from_position(full.board(), full.current_turn()).unwrap();
+    for played in endgame_moves.iter().rev() {
+        game.play_move(played.stone(), played.mv()).unwrap();
+    }
+
+    let annotation = annotate(
+        &game,
+        &mut Searcher::new(DiscDifference),
+        &AnnotationSettings::new(SearchLimits::depth(60)),
+    );
+    assert!(!annotation.moves.is_empty());
+    for annotated in &annotation.moves {
+        assert_eq!(0, annotated.loss % 100);
+        assert!(annotated.best_score.abs() <= 6400);
+    }
+}
+
+#[test]
+fn undone_moves_are_not_annotated() {
+    let mut game = Game::new();
+    for pos in ["f5", "d6", "c3"] {
+        game.play(pos.parse().unwrap()).unwrap();
+    }
+    game.undo();
+    let annotation = annotate(
+        &game,
+        &mut Searcher::new(DiscDifference),
+        &AnnotationSettings::new(SearchLimits::depth(1)),
+    );
+    assert_eq!(2, annotation.moves.len());
+    assert_eq!(1, annotation.summary(Stone::White).moves);
+}
+
+#[test]
+fn eval_curve_covers_every_position() {
+    let mut rng = StdRng::seed_from_u64(21);
+    let game = random_game(&mut rng, 60);
+    let curve = eval_curve(
+        &game,
+        &mut Searcher::new(DiscDifference),
+        SearchLimits::depth(1),
+    );
+    assert_eq!(game.history().len() + 1, curve.len());
+    assert!(
+        curve
+            .iter()
+            .enumerate()
+            .all(|(index, (ply, _))| index == *ply)
+    );
+    // The final position is scored by its exact disc differential
+    let (black, white) = game.board().count_stones();
+    let (_, last) = curve.last().unwrap();
+    assert_eq!((i32::from(black) - i32::from(white)) * 100, *last);
+
+    let mut csv = Vec::new();
+    write_curve_csv(&curve, &mut csv).unwrap();
+    assert_eq!(
+        curve.len() + 1,
+        String::from_utf8(csv).unwrap().lines().count()
+    );
+    let mut json = Vec::new();
+    write_curve_json(&curve, &mut json).unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&json).unwrap();
+    assert_eq!(curve.len(), json.as_array().unwrap().len());
+    assert_eq!(i64::from(*last), json[curve.len() - 1]["score"]);
+}
+
+#[test]
+fn expected_losses_are_probabilities() {
+    let mut rng = StdRng::seed_from_u64(34);
+    let game = random_game(&mut rng, 60);
+    let annotation = annotate(
+        &game,
+        &mut Searcher::new(DiscDifference),
+        &AnnotationSettings::new(SearchLimits::depth(2)),
+    );
+    for annotated in &annotation.moves {
+        let expected = annotated.expected_loss();
+        assert!((0.0..=1.0).contains(&expected));
+        if annotated.loss == 0 {
+            assert_eq!(0.0, expected);
+        }
+    }
+    for stone in [Stone::Black, Stone::White] {
+        assert!((0.0..=1.0).contains(&annotation.average_expected_loss(stone)));
+    }
+}
@@ -1,8 +1,9 @@
-use magpie::othello::{Bitboard, Position};
+use magpie::othello::{Bitboard, Direction, Position};
 
 mod common;
 
-use common::{ShadowBitboard, ShadowPosition};
+#[cfg(kani)]
+use common::ShadowBitboard;
 
 #[cfg(kani)]
 #[kani::proof]
@@ -112,6 +113,19 @@ fn position_notation_identity() {
     }
 }
 
+#[test]
+fn position_parse_display_identity() {
+    for pos in Bitboard::from(u64::MAX).hot_bits() {
+        let text = pos.to_string();
+        assert_eq!(pos, text.parse::<Position>().unwrap());
+        assert_eq!(pos, text.to_uppercase().parse::<Position>().unwrap());
+        assert_eq!(
+            pos,
+            Position::from_rank_file(pos.rank(), pos.file()).unwrap()
+        );
+    }
+}
+
 #[test]
 fn full_bitboard_bits_equal_hot_bits() {
     let v1 = Bitboard::from(u64::MAX).bits();
@@ -123,6 +137,101 @@ fn full_bitboard_bits_equal_hot_bits() {
     assert_eq!(v1, v2);
 }
 
+#[test]
+fn positions_combine_into_bitboards() {
+    let a1 = Position::try_from("a1").unwrap();
+    let h8 = Position::try_from("h8").unwrap();
+
+    let corners = a1 | h8;
+    assert_eq!(a1.raw() | h8.raw(), corners.raw());
+    assert_eq!(corners, a1 ^ h8);
+    assert_eq!(a1, corners & a1);
+    assert_eq!(Bitboard::from(!a1.raw()), !a1);
+    assert_eq!(corners, a1 | h8.raw());
+    assert_eq!(Bitboard::from(0), a1 & h8.raw());
+    assert_eq!(Bitboard::from(0), a1 ^ a1.raw());
+}
+
+#[test]
+fn bitboard_references_match_values() {
+    let a = Bitboard::from(0xF0F0_0000_0000_0F0F);
+    let b = Bitboard::from(0xFF00_0000_0000_00FF);
+
+    let (ref_a, ref_b) = (&a, &b);
+    assert_eq!(a & b, ref_a & ref_b);
+    assert_eq!(a | b, ref_a | b);
+    assert_eq!(a ^ b, a ^ ref_b);
+    assert_eq!(!a, !ref_a);
+
+    let mut c = a;
+    c |= &b;
+    c &= &0xFFFF_0000_0000_0000;
+    assert_eq!((a | b) & 0xFFFF_0000_0000_0000, c);
+
+    let combined = [a, b]
+        .iter()
+        .fold(Bitboard::from(0), |acc, board| acc | board);
+    assert_eq!(a | b, combined);
+}
+
+#[test]
+fn bitboards_work_as_keys() {
+    use std::collections::{BTreeSet, HashSet};
+
+    let boards = [0_u64, 1, 1 << 63, 1].map(Bitboard::from);
+    assert_eq!(3, boards.iter().collect::<HashSet<_>>().len());
+    let ordered: Vec<_> = boards
+        .iter()
+        .copied()
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect();
+    assert_eq!(vec![0, 1, 1 << 63], ordered);
+}
+
+#[test]
+fn rays_match_stepping() {
+    let steps = |direction| match direction {
+        Direction::North => (-1, 0),
+        Direction::NorthEast => (-1, 1),
+        Direction::East => (0, 1),
+        Direction::SouthEast => (1, 1),
+        Direction::South => (1, 0),
+        Direction::SouthWest => (1, -1),
+        Direction::West => (0, -1),
+        Direction::NorthWest => (-1, -1),
+    };
+    for pos in Bitboard::from(u64::MAX).hot_bits() {
+        for direction in Direction::ALL {
+            let (rank_step, file_step) = steps(direction);
+            let (mut rank, mut file) = (i16::from(pos.rank()), i16::from(pos.file()));
+            let mut expected = Bitboard::from(0);
+            loop {
+                rank += rank_step;
+                file += file_step;
+                // Negative ranks and files wrap around and are rejected
+                let Ok(square) = Position::try_from((rank as u8, file as u8)) else {
+                    break;
+                };
+                expected |= square;
+            }
+            assert_eq!(expected, Bitboard::ray(pos, direction));
+        }
+    }
+}
+
+#[test]
+fn rays_mirror_their_opposites() {
+    for from in Bitboard::from(u64::MAX).hot_bits() {
+        for direction in Direction::ALL {
+            for to in Bitboard::ray(from, direction).hot_bits() {
+                let back = Bitboard::ray(to, direction.opposite());
+                assert!(!(back & from).is_empty());
+            }
+        }
+    }
+}
+
 #[cfg(kani)]
 #[kani::proof]
 fn squares_bit_count() {
@@ -147,3 +256,151 @@ fn stones_bit_count() {
 
     assert_eq!(expected as usize, result);
 }
+
+#[test]
+fn line_masks_contain_their_squares() {
+    for rank in 0..8u8 {
+        for file in 0..8u8 {
+            let pos = Position::from_rank_file(rank, file).unwrap();
+            let offset = file.cast_signed() - rank.cast_signed();
+            let anti_offset = file.cast_signed() + rank.cast_signed() - 7;
+            for (index, mask) in (0..8).map(|i| (i, Bitboard::file(i))) {
+                assert_eq!(index == file, mask & pos != 0);
+            }
+            for (index, mask) in (0..8).map(|i| (i, Bitboard::rank(i))) {
+                assert_eq!(index == rank, mask & pos != 0);
+            }
+            for (index, mask) in (-7..=7).map(|i| (i, Bitboard::diagonal(i))) {
+                assert_eq!(index == offset, mask & pos != 0);
+            }
+            for (index, mask) in (-7..=7).map(|i| (i, Bitboard::anti_diagonal(i))) {
+                assert_eq!(index == anti_offset, mask & pos != 0);
+            }
+            let on_edge = [0, 7].contains(&rank) || [0, 7].contains(&file);
+            assert_eq!(on_edge, Bitboard::edges() & pos != 0);
+        }
+    }
+    // The corners and the center lie on both main diagonals
+    let diagonals = Bitboard::diagonal(0) | Bitboard::anti_diagonal(0);
+    let squares = Bitboard::corners() | Bitboard::center4();
+    assert_eq!(squares, diagonals & squares);
+}
+
+#[test]
+#[should_panic(expected = "diagonal offset must be between -7 and 7")]
+fn diagonal_offsets_are_checked() {
+    let _ = Bitboard::diagonal(8);
+}
+
+#[test]
+fn neighbors_are_one_step_away() {
+    let positions: Vec<Position> = Bitboard::from(u64::MAX).hot_bits().collect();
+    for pos in &positions {
+        for other in &positions {
+            let adjacent = pos.neighbors() & *other != 0;
+            assert_eq!(adjacent, pos.distance(*other) == 1);
+            assert_eq!(pos.distance(*other), other.distance(*pos));
+        }
+    }
+}
+
+#[test]
+fn special_squares_surround_the_corners() {
+    let all = Bitboard::from(u64::MAX);
+    let corners: Vec<Position> = all.hot_bits().filter(|pos| pos.is_corner()).collect();
+    assert_eq!(4, corners.len());
+    for pos in all.hot_bits() {
+        let next_to_corner = corners.iter().any(|corner| corner.distance(pos) == 1);
+        let on_edge = Bitboard::edges() & pos != 0;
+        assert_eq!(next_to_corner && !on_edge, pos.is_x_square());
+        assert_eq!(next_to_corner && on_edge, pos.is_c_square());
+    }
+}
+
+#[test]
+fn square_constants_match_their_notation() {
+    let squares = [
+        Position::A1,
+        Position::H1,
+        Position::D5,
+        Position::A8,
+        Position::H8,
+    ];
+    for (pos, notation) in squares.into_iter().zip(["a1", "h1", "d5", "a8", "h8"]) {
+        assert_eq!(notation, pos.to_notation());
+        assert_eq!(Ok(pos), Position::try_new(pos.raw()));
+    }
+    assert_eq!(Ok(Position::C4), Position::from_index(26));
+    assert!(Position::try_new(0).is_err());
+    assert!(Position::try_new(u64::MAX).is_err());
+}
+
+/// A lookup table built at compile time from the `const` bitboard API.
+const EDGE_FILES: [Bitboard; 8] = {
+    let mut table = [Bitboard::EMPTY; 8];
+    let mut file = 0;
+    while file < 8 {
+        table[file as usize] = Bitboard::file(file).intersection(Bitboard::edges());
+        file += 1;
+    }
+    table
+};
+
+#[test]
+fn const_operations_match_operators() {
+    assert_eq!(8, EDGE_FILES[0].count_set());
+    assert_eq!(
+        (Bitboard::rank(0) | Bitboard::rank(7)) & Bitboard::file(3),
+        EDGE_FILES[3]
+    );
+    let (a, b) = (Bitboard::new(0xF0_F0), Bitboard::from(0xFF_00));
+    assert_eq!(a | b, a.union(b));
+    assert_eq!(a & b, a.intersection(b));
+    assert_eq!(a & !b, a.difference(b));
+    assert_eq!(!a, a.complement());
+    assert_eq!(Bitboard::FULL.count_empty(), Bitboard::EMPTY.count_set());
+    assert!(Bitboard::new(Position::C4.raw()).contains(Position::C4));
+}
+
+#[test]
+fn square_classes_are_consistent() {
+    use magpie::othello::{Symmetry, masks};
+
+    assert_eq!(
+        masks::EDGES,
+        masks::CORNERS | masks::C_SQUARES | masks::A_SQUARES | masks::B_SQUARES
+    );
+    assert_eq!(masks::INNER_EDGES, masks::EDGES.difference(masks::CORNERS));
+    assert_eq!(Bitboard::center4(), masks::CENTER);
+    assert_eq!(Bitboard::corners(), masks::CORNERS);
+    assert_eq!(Bitboard::edges(), masks::EDGES);
+    assert_eq!(masks::CENTER, masks::SWEET_16 & masks::CENTER);
+    for (mask, count) in [
+        (masks::CORNERS, 4),
+        (masks::C_SQUARES, 8),
+        (masks::X_SQUARES, 4),
+        (masks::A_SQUARES, 8),
+        (masks::B_SQUARES, 8),
+        (masks::SWEET_16, 16),
+    ] {
+        assert_eq!(count, mask.count_set());
+        for symmetry in Symmetry::ALL {
+            assert_eq!(mask, mask.transform(symmetry));
+        }
+    }
+}
+
+#[test]
+fn ranks_and_files_follow_the_layout() {
+    let bitboard = Bitboard::from(0x81_42_24_18_0f_f0_aa_55);
+    let ranks = bitboard.ranks();
+    let files = bitboard.files();
+    for pos in Bitboard::FULL.hot_bits() {
+        let (rank, file) = (pos.rank(), pos.file());
+        let set = bitboard.contains(pos);
+        assert_eq!(set, ranks[rank as usize] & (0x80 >> file) != 0);
+        assert_eq!(set, files[file as usize] & (0x80 >> rank) != 0);
+    }
+    assert_eq!(bitboard, Bitboard::from_ranks(ranks));
+    assert!(bitboard.bytes().eq((0..8).zip(ranks)));
+}
@@ -0,0 +1,180 @@
+use magpie::othello::{Board, Stone, Transcript, book::Book};
+use rand::{Rng, SeedableRng, rngs::StdRng};
+
+mod common;
+
+use common::random_game;
+
+#[test]
+fn book_recognizes_rotated_positions() {
+    let mut rng = StdRng::seed_from_u64(15);
+    for _ in 0..50 {
+        let (board, stone) = random_position(&mut rng);
+        let Some(position) = board.moves_for(stone).hot_bits().next() else {
+            continue;
+        };
+        let mut book = Book::new();
+        book.insert(&board, stone, position, 7);
+
+        let variants = [
+            (board.cw(), position.rotate_cw()),
+            (board.ccw(), position.rotate_ccw()),
+            (board.flip180(), position.flip180()),
+        ];
+        for (board, position) in variants {
+            let found = book.best_move(&board, stone).unwrap();
+            assert_eq!(position, found.position);
+            assert_eq!(7, found.score);
+            assert!(book.moves(&board, stone.flip()).is_empty());
+        }
+    }
+}
+
+#[test]
+fn book_save_load_identity() {
+    let mut book = Book::new();
+    let lines = ["f5d6c3d3c4", "f5f6e6f4", "c4c3d3c5"];
+    for (score, line) in lines.iter().enumerate() {
+        let transcript: Transcript = line.parse().unwrap();
+        book.insert_line(&transcript, score as i32).unwrap();
+    }
+
+    let mut bytes = Vec::new();
+    book.save(&mut bytes).unwrap();
+    assert_eq!(book, Book::load(bytes.as_slice()).unwrap());
+    assert!(Book::load(&bytes[..bytes.len() - 1]).is_err());
+}
+
+#[test]
+fn book_rejects_illegal_lines() {
+    let mut book = Book::new();
+    let transcript: Transcript = "f5a1".parse().unwrap();
+    assert!(book.insert_line(&transcript, 0).is_err());
+    assert_eq!(1, book.len());
+}
+
+#[test]
+fn book_stats_count_transpositions() {
+    let mut book = Book::new();
+    // Both lines reach the same position after five moves
+    for line in ["f5d6c3d3c4f4", "f5d6c4d3c3f4"] {
+        let transcript: Transcript = line.parse().unwrap();
+        book.insert_line(&transcript, 0).unwrap();
+    }
+    let stats = book.stats();
+    assert_eq!(book.len(), stats.positions);
+    assert_eq!(vec![1, 1, 1, 2, 2, 1], stats.positions_per_ply);
+    assert_eq!(1, stats.transpositions);
+}
+
+#[test]
+fn book_merge_and_prune() {
+    let lines = ["f5d6c3", "f5d6c5", "f5f6e6"];
+    let mut book = Book::new();
+    let mut other = Book::new();
+    for (index, line) in lines.iter().enumerate() {
+        let transcript: Transcript = line.parse().unwrap();
+        book.insert_line(&transcript, index as i32).unwrap();
+        other.insert_line(&transcript, -(index as i32)).unwrap();
+    }
+    book.merge(&other);
+    let start = book.moves(&Board::standard(), Stone::Black);
+    assert_eq!(1, start.len());
+    assert_eq!(6, start[0].games);
+
+    let mut pruned = book.clone();
+    assert_eq!(4, pruned.prune_by_games(3));
+    assert_eq!(2, pruned.len());
+    assert_eq!(0, pruned.prune_by_error(0));
+
+    // Merging a book into an empty one copies it
+    let mut copy = Book::new();
+    copy.merge(&book);
+    assert_eq!(book, copy);
+}
+
+#[test]
+fn book_loads_version_one() {
+    let mut bytes = b"MPBK\x01".to_vec();
+    bytes.extend_from_slice(&1u32.to_le_bytes());
+    let board = Board::standard();
+    bytes.extend_from_slice(&board.bits_for(Stone::Black).raw().to_le_bytes());
+    bytes.extend_from_slice(&board.bits_for(Stone::White).raw().to_le_bytes());
+    bytes.extend_from_slice(&[0, 1, 37]);
+    bytes.extend_from_slice(&5i32.to_le_bytes());
+
+    let book = Book::load(bytes.as_slice()).unwrap();
+    let moves = book.moves(&board, Stone::Black);
+    assert_eq!(1, moves.len());
+    assert_eq!((5, 1), (moves[0].score, moves[0].games));
+}
+
+/// Encodes a position in the format of Edax books, with the moves given as
+/// indices where A1 is 0.
+fn edax_position(player: u64, opponent: u64, links: &[(i8, u8)], leaf: (i8, u8)) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    bytes.extend_from_slice(&player.reverse_bits().to_le_bytes());
+    bytes.extend_from_slice(&opponent.reverse_bits().to_le_bytes());
+    // Wins, draws, losses, lines, and the score with its bounds
+    bytes.extend_from_slice(&[0; 22]);
+    bytes.extend_from_slice(&[links.len() as u8, 60]);
+    for (score, mv) in links.iter().chain([&leaf]) {
+        bytes.extend_from_slice(&[*score as u8, *mv]);
+    }
+    bytes
+}
+
+#[test]
+fn book_loads_edax_books() {
+    let mut bytes = b"XADEKOOB".to_vec();
+    bytes.extend_from_slice(&[4, 4]);
+    bytes.extend_from_slice(&[0; 28]);
+    bytes.extend_from_slice(&2u32.to_le_bytes());
+
+    let start = Board::standard();
+    let black = start.bits_for(Stone::Black).raw();
+    let white = start.bits_for(Stone::White).raw();
+    // F5 (37) is linked, D3 (19) is the best move outside the book
+    bytes.extend(edax_position(black, white, &[(0, 37)], (-2, 19)));
+    let mut after = start;
+    after.play(Stone::Black, "f5".parse().unwrap());
+    let black = after.bits_for(Stone::Black).raw();
+    let white = after.bits_for(Stone::White).raw();
+    // White to move, with no move outside the book
+    bytes.extend(edax_position(white, black, &[(1, 45), (-3, 43)], (0, 65)));
+
+    let book = Book::load_edax(bytes.as_slice()).unwrap();
+    assert_eq!(2, book.len());
+    let best = book.best_move(&start, Stone::Black).unwrap();
+    assert_eq!(("f5".parse().unwrap(), 0), (best.position, best.score));
+    assert_eq!(2, book.moves(&start, Stone::Black).len());
+    let best = book.best_move(&after, Stone::White).unwrap();
+    assert_eq!(("f6".parse().unwrap(), 1), (best.position, best.score));
+    assert_eq!(2, book.moves(&after, Stone::White).len());
+
+    assert!(Book::load_edax(&bytes[..bytes.len() - 1]).is_err());
+    bytes[0] = b'Y';
+    assert!(Book::load_edax(bytes.as_slice()).is_err());
+}
+
+#[cfg(feature = "openings")]
+#[test]
+fn named_openings_are_legal() {
+    use magpie::othello::book::{NAMED_OPENINGS, opening_name};
+
+    for (name, line) in NAMED_OPENINGS {
+        let transcript: Transcript = line.parse().unwrap();
+        assert!(transcript.replay().is_ok(), "{name} is illegal");
+        assert_eq!(Some(*name), opening_name(&transcript));
+    }
+    let book = Book::with_named_openings();
+    assert!(!book.is_empty());
+}
+
+fn random_position(rng: &mut StdRng) -> (Board, Stone) {
+    // Positions early in the game are often symmetric, in which case several
+    // equivalent moves are correct
+    let plies = rng.random_range(8..40);
+    let game = random_game(rng, plies);
+    (game.board(), game.current_turn())
+}
@@ -0,0 +1,62 @@
+#![cfg(feature = "cli")]
+
+use std::{
+    io::Write,
+    process::{Command, Output, Stdio},
+};
+
+fn run(args: &[&str], input: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_magpie-cli"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(input.as_bytes())
+        .unwrap();
+    child.wait_with_output().unwrap()
+}
+
+#[test]
+fn cli_plays_complete_games() {
+    // Trying every square in turn plays the first legal move each time
+    let squares: String = ('a'..='h')
+        .flat_map(|file| (1..=8).map(move |rank| format!("{file}{rank}\n")))
+        .collect();
+    let input = squares.repeat(60);
+    for color in [&[][..], &["--white"][..]] {
+        let args = [color, &["--depth", "2"]].concat();
+        let output = run(&args, &input);
+        assert!(output.status.success());
+        let stdout = String::from_utf8(output.stdout).unwrap();
+        let result = stdout.lines().last().unwrap();
+        assert!(
+            ["You win", "Magpie wins", "The game is drawn"]
+                .iter()
+                .any(|outcome| result.starts_with(outcome)),
+            "unexpected result: {result}"
+        );
+    }
+}
+
+#[test]
+fn cli_handles_commands() {
+    let output = run(&["--depth", "1"], "hint\nf5\nundo\nundo\nd3\nb\nquit\nf4\n");
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("Magpie suggests"));
+    assert_eq!(2, stdout.matches("Magpie plays").count());
+    assert_eq!(1, stdout.matches("There is nothing to undo.").count());
+    // The game ends with the quit command, before f4 is read
+    assert!(!stdout.contains("f4 is not a legal move"));
+
+    let output = run(&["x3"], "");
+    assert!(!output.status.success());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("unexpected argument 'x3'"));
+}
@@ -0,0 +1,79 @@
+use magpie::{
+    clock::{Clock, Flagged, TimeControl},
+    othello::Stone,
+};
+use std::time::Duration;
+
+#[test]
+fn clocks_flag_when_time_runs_out() {
+    let mut clock = Clock::new(TimeControl::SuddenDeath {
+        base: Duration::from_secs(10),
+    });
+    clock.record(Stone::Black, Duration::from_secs(6)).unwrap();
+    clock.record(Stone::White, Duration::from_secs(10)).unwrap();
+    assert_eq!(Duration::ZERO, clock.remaining(Stone::White));
+    assert_eq!(
+        Err(Flagged(Stone::Black)),
+        clock.record(Stone::Black, Duration::from_secs(5))
+    );
+    // A flagged clock stops accepting moves
+    assert_eq!(
+        Err(Flagged(Stone::Black)),
+        clock.record(Stone::White, Duration::ZERO)
+    );
+    assert_eq!(2, clock.moves_played());
+}
+
+#[test]
+fn byo_yomi_uses_up_exceeded_periods() {
+    let mut clock = Clock::new(TimeControl::ByoYomi {
+        base: Duration::from_secs(30),
+        period: Duration::from_secs(5),
+        periods: 3,
+    });
+    clock.record(Stone::Black, Duration::from_secs(30)).unwrap();
+    assert_eq!(3, clock.player(Stone::Black).periods);
+
+    // Exceeds two periods and finishes within the third
+    clock.record(Stone::Black, Duration::from_secs(12)).unwrap();
+    assert_eq!(1, clock.player(Stone::Black).periods);
+    assert_eq!(Duration::from_secs(5), clock.available(Stone::Black));
+
+    clock.record(Stone::Black, Duration::from_secs(5)).unwrap();
+    assert!(
+        clock
+            .record(Stone::Black, Duration::from_millis(5001))
+            .is_err()
+    );
+    assert_eq!(Some(Stone::Black), clock.flagged());
+    assert_eq!(Duration::from_secs(30), clock.remaining(Stone::White));
+}
+
+#[test]
+fn clock_states_fit_within_available_time() {
+    let mut clock = Clock::new(TimeControl::ByoYomi {
+        base: Duration::from_secs(1),
+        period: Duration::from_secs(2),
+        periods: 1,
+    });
+    clock.record(Stone::Black, Duration::from_secs(1)).unwrap();
+    let state = clock.state(Stone::Black, 40);
+    assert_eq!(Duration::from_secs(2), state.remaining);
+    assert_eq!(Duration::from_secs(2), state.increment);
+    assert_eq!(1, state.moves_played);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn clocks_roundtrip_through_serde() -> serde_json::Result<()> {
+    let mut clock = Clock::new(TimeControl::Increment {
+        base: Duration::from_secs(60),
+        increment: Duration::from_millis(500),
+    });
+    clock
+        .record(Stone::Black, Duration::from_millis(1500))
+        .unwrap();
+    let json = serde_json::to_string(&clock)?;
+    assert_eq!(clock, serde_json::from_str(&json)?);
+    Ok(())
+}
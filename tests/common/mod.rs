@@ -1,4 +1,5 @@
 use magpie::othello::{Bitboard, Board, Game, OthelloError, Position, PositionError, Stone};
+use rand::Rng;
 
 #[derive(Debug, Clone)]
 #[cfg_attr(kani, derive(kani::Arbitrary))]
@@ -111,3 +112,24 @@ impl TryFrom<ShadowGame> for Game {
         Game::from_state(game.board, game.next_player, game.passed_last_turn)
     }
 }
+
+/// Plays up to the specified number of uniformly random moves from the
+/// standard opening position.
+///
+/// Forced passes are made automatically and do not count as moves, so the
+/// player to move has a legal move unless the game is over. Sixty moves
+/// always play a game to the end.
+#[allow(dead_code)]
+pub fn random_game(rng: &mut impl Rng, plies: usize) -> Game {
+    let mut game = Game::new();
+    for _ in 0..plies {
+        game.pass_if_forced();
+        let moves: Vec<_> = game.moves().hot_bits().collect();
+        if moves.is_empty() {
+            break;
+        }
+        game.play(moves[rng.random_range(0..moves.len())]).unwrap();
+    }
+    game.pass_if_forced();
+    game
+}
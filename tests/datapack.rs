@@ -0,0 +1,166 @@
+use magpie::{
+    datapack::{Codec, PackError, PackReader, PackRecord, PackView, PackWriter},
+    othello::{Board, Position, Stone},
+    search::RandomRollout,
+    selfplay::{Record, SelfPlay},
+};
+use std::io::{self, Cursor};
+
+/// Run-length encodes the data, which works well on mostly empty records.
+struct RunLength;
+
+impl Codec for RunLength {
+    fn id(&self) -> u8 {
+        1
+    }
+
+    fn compress(&mut self, data: &[u8]) -> Vec<u8> {
+        let mut compressed = Vec::new();
+        for chunk in data.chunk_by(|a, b| a == b) {
+            for run in chunk.chunks(255) {
+                compressed.extend_from_slice(&[run.len() as u8, run[0]]);
+            }
+        }
+        compressed
+    }
+
+    fn decompress(&mut self, data: &[u8]) -> io::Result<Vec<u8>> {
+        if !data.len().is_multiple_of(2) {
+            return Err(io::ErrorKind::InvalidData.into());
+        }
+        Ok(data
+            .chunks(2)
+            .flat_map(|run| std::iter::repeat_n(run[1], run[0] as usize))
+            .collect())
+    }
+}
+
+fn records(count: usize) -> Vec<PackRecord> {
+    let moves = ["f5", "d3", "c4", "e6"];
+    (0..count)
+        .map(|index| PackRecord {
+            board: Board::standard(),
+            stone: if index % 2 == 0 {
+                Stone::Black
+            } else {
+                Stone::White
+            },
+            mv: Position::try_from(moves[index % 4]).unwrap(),
+            result: ((index % 129) as i16 - 64) as i8,
+            eval: (index % 3 == 0).then_some(index as f32 / 10.0),
+        })
+        .collect()
+}
+
+fn write(records: &[PackRecord], codec: impl Codec) -> Vec<u8> {
+    let mut writer = PackWriter::with_codec(Cursor::new(Vec::new()), codec).unwrap();
+    for record in records {
+        writer.write(record).unwrap();
+    }
+    assert_eq!(records.len() as u64, writer.len());
+    writer.finish().unwrap().into_inner()
+}
+
+#[test]
+fn uncompressed_round_trip() {
+    let records = records(100);
+    let bytes = write(&records, magpie::datapack::Uncompressed);
+    assert_eq!(16 + 100 * 24, bytes.len());
+
+    let reader = PackReader::new(bytes.as_slice()).unwrap();
+    assert_eq!(100, reader.header().count);
+    let read: Vec<_> = reader.collect::<Result<_, _>>().unwrap();
+    assert_eq!(records, read);
+
+    let view = PackView::new(&bytes).unwrap();
+    assert_eq!(100, view.len());
+    assert_eq!(records[57], view.get(57).unwrap().unwrap());
+    let read: Vec<_> = view.iter().collect::<Result<_, _>>().unwrap();
+    assert_eq!(records, read);
+}
+
+#[test]
+fn compressed_round_trip_across_blocks() {
+    let records = records(10_000);
+    let bytes = write(&records, RunLength);
+    assert!(bytes.len() < 16 + 10_000 * 24);
+
+    let reader = PackReader::with_codec(bytes.as_slice(), RunLength).unwrap();
+    let read: Vec<_> = reader.collect::<Result<_, _>>().unwrap();
+    assert_eq!(records, read);
+}
+
+#[test]
+fn codec_must_match() {
+    let bytes = write(&records(3), RunLength);
+    assert!(matches!(
+        PackReader::new(bytes.as_slice()),
+        Err(PackError::UnsupportedCodec(1))
+    ));
+    assert!(matches!(
+        PackView::new(&bytes),
+        Err(PackError::UnsupportedCodec(1))
+    ));
+
+    let bytes = write(&records(3), magpie::datapack::Uncompressed);
+    assert!(matches!(
+        PackReader::with_codec(bytes.as_slice(), RunLength),
+        Err(PackError::UnsupportedCodec(0))
+    ));
+}
+
+#[test]
+fn invalid_packs_are_rejected() {
+    let mut bytes = write(&records(4), magpie::datapack::Uncompressed);
+    assert!(matches!(
+        PackView::new(&bytes[..bytes.len() - 1]),
+        Err(PackError::Truncated)
+    ));
+
+    // Place a white stone on a square already taken by a black one
+    let black = &bytes[16 + 2 * 24..16 + 2 * 24 + 8].to_vec();
+    bytes[16 + 2 * 24 + 8..16 + 2 * 24 + 16].copy_from_slice(black);
+    let view = PackView::new(&bytes).unwrap();
+    assert!(view.get(1).unwrap().is_ok());
+    assert!(matches!(
+        view.get(2).unwrap(),
+        Err(PackError::InvalidRecord(2))
+    ));
+
+    let results: Vec<_> = PackReader::new(bytes.as_slice()).unwrap().collect();
+    assert_eq!(3, results.len());
+    assert!(results[2].is_err());
+
+    bytes[0] = b'X';
+    assert!(matches!(
+        PackView::new(&bytes),
+        Err(PackError::InvalidMagic)
+    ));
+}
+
+#[test]
+fn self_play_records_convert() {
+    let selfplay = SelfPlay::new(|| RandomRollout::new(1), || RandomRollout::new(2)).with_games(2);
+    let mut records: Vec<Record> = Vec::new();
+    selfplay.run(&mut records);
+
+    let packed: Vec<PackRecord> = records.iter().copied().map(PackRecord::from).collect();
+    let bytes = write(&packed, magpie::datapack::Uncompressed);
+    let view = PackView::new(&bytes).unwrap();
+    for (record, read) in records.iter().zip(view.iter()) {
+        let read = read.unwrap();
+        assert_eq!(record.board, read.board);
+        assert_eq!(record.stone, read.stone);
+        assert_eq!(record.mv, read.mv);
+        assert_eq!(record.score, read.result);
+        assert_eq!(None, read.eval);
+        assert!(read.is_consistent());
+    }
+
+    // Half of the sample records have white moving first
+    let flagged = self::records(4)
+        .iter()
+        .filter(|record| !record.is_consistent())
+        .count();
+    assert_eq!(2, flagged);
+}
@@ -0,0 +1,69 @@
+use magpie::{
+    dedup::{Deduplicator, GameStatus},
+    othello::{Board, Game, Move, Symmetry, Transcript},
+};
+use rand::{SeedableRng, rngs::StdRng};
+
+mod common;
+
+use common::random_game;
+
+/// Replays the game with every move transformed by the symmetry.
+fn transform(game: &Game, symmetry: Symmetry) -> Game {
+    let mut transformed = Game::new();
+    for played in game.history() {
+        match played.mv() {
+            Move::Play(pos) => transformed.play(pos.transform(symmetry)).unwrap(),
+            Move::Pass => transformed.pass_turn(),
+        }
+    }
+    transformed
+}
+
+#[test]
+fn symmetric_copies_are_duplicates() {
+    let mut rng = StdRng::seed_from_u64(1);
+    let games: Vec<_> = (0..20).map(|_| random_game(&mut rng, 60)).collect();
+    let mut dedup = Deduplicator::new();
+    for game in &games {
+        assert_eq!(GameStatus::Unique, dedup.add_game(game));
+    }
+    let unique = dedup.stats();
+    assert_eq!(0, unique.duplicate_games);
+    assert!(unique.distinct_positions <= unique.positions);
+
+    // Only some symmetries keep the opening position in place
+    let symmetries = Symmetry::ALL
+        .into_iter()
+        .filter(|symmetry| Board::standard().transform(*symmetry) == Board::standard());
+    for symmetry in symmetries {
+        for (index, game) in games.iter().enumerate() {
+            let copy = transform(game, symmetry);
+            assert_eq!(GameStatus::Duplicate(index), dedup.add_game(&copy));
+        }
+    }
+    let stats = dedup.stats();
+    assert!(stats.duplicate_games >= 20);
+    assert_eq!(stats.games, 20 + stats.duplicate_games);
+    // Duplicates do not add positions
+    assert_eq!(unique.positions, stats.positions);
+    assert_eq!(unique.distinct_positions, stats.distinct_positions);
+    assert_eq!(stats.duplicate_games as usize, dedup.duplicates().len());
+}
+
+#[test]
+fn transpositions_are_counted() {
+    let mut dedup = Deduplicator::new();
+    for moves in ["f5d6c3d3c4", "f5d6c4d3c3", "f5d6c3d3c4f4"] {
+        let transcript: Transcript = moves.parse().unwrap();
+        let game = transcript.replay().unwrap();
+        assert_eq!(GameStatus::Unique, dedup.add_game(&game));
+    }
+    let stats = dedup.stats();
+    assert_eq!(19, stats.positions);
+    // The first game reaches 6 positions, the second only adds 2 before
+    // transposing into it, and the third extends the first by 1
+    assert_eq!(9, stats.distinct_positions);
+    assert_eq!(1, stats.transpositions);
+    assert!(stats.repeated_position_rate() > 0.0);
+}
@@ -2,6 +2,7 @@ use indoc::indoc;
 use magpie::othello::{Board, Format, Stone};
 
 #[test]
+#[allow(deprecated)]
 fn display_opening_with_stone_format_standard() {
     let board = Board::standard();
     let result = board
@@ -33,6 +34,38 @@ fn display_opening_with_stone_format_standard() {
 }
 
 #[test]
+fn display_opening_with_legal_moves_format_standard() {
+    let board = Board::standard();
+    let result = board
+        .display()
+        .with_format(Format::Standard)
+        .with_legal_moves(Stone::Black)
+        .to_string();
+    let expected = indoc! {"
+          A   B   C   D   E   F   G   H
+        +---+---+---+---+---+---+---+---+
+      1 |   |   |   |   |   |   |   |   |
+        +---+---+---+---+---+---+---+---+
+      2 |   |   |   |   |   |   |   |   |
+        +---+---+---+---+---+---+---+---+
+      3 |   |   |   | * |   |   |   |   |
+        +---+---+---+---+---+---+---+---+
+      4 |   |   | * | W | B |   |   |   |
+        +---+---+---+---+---+---+---+---+
+      5 |   |   |   | B | W | * |   |   |
+        +---+---+---+---+---+---+---+---+
+      6 |   |   |   |   | * |   |   |   |
+        +---+---+---+---+---+---+---+---+
+      7 |   |   |   |   |   |   |   |   |
+        +---+---+---+---+---+---+---+---+
+      8 |   |   |   |   |   |   |   |   |
+        +---+---+---+---+---+---+---+---+
+     "};
+    assert_eq!(expected, result);
+}
+
+#[test]
+#[allow(deprecated)]
 fn display_opening_with_stone_format_compact() {
     let board = Board::standard();
     let result = board
@@ -56,6 +89,30 @@ fn display_opening_with_stone_format_compact() {
     assert_eq!(expected, result);
 }
 
+#[test]
+fn display_opening_with_legal_moves_format_compact() {
+    let board = Board::standard();
+    let result = board
+        .display()
+        .with_format(Format::Compact)
+        .with_legal_moves(Stone::Black)
+        .to_string();
+    let expected = indoc! {"
+         ABCDEFGH
+        +--------+
+      1 |........|
+      2 |........|
+      3 |...*....|
+      4 |..*WB...|
+      5 |...BW*..|
+      6 |....*...|
+      7 |........|
+      8 |........|
+        +--------+
+     "};
+    assert_eq!(expected, result);
+}
+
 #[test]
 fn display_opening_format_standard() {
     let board = Board::standard();
@@ -102,3 +159,91 @@ fn display_opening_format_compact() {
       "};
     assert_eq!(expected, result);
 }
+
+#[test]
+fn display_opening_with_legal_moves_format_unicode() {
+    let board = Board::standard();
+    let result = board
+        .display()
+        .with_format(Format::Unicode)
+        .with_legal_moves(Stone::Black)
+        .to_string();
+    let expected = indoc! {"
+           A B C D E F G H
+         ┌─────────────────┐
+       1 │ · · · · · · · · │
+       2 │ · · · · · · · · │
+       3 │ · · · × · · · · │
+       4 │ · · × ○ ● · · · │
+       5 │ · · · ● ○ × · · │
+       6 │ · · · · × · · · │
+       7 │ · · · · · · · · │
+       8 │ · · · · · · · · │
+         └─────────────────┘
+     "};
+    assert_eq!(expected, result);
+}
+
+#[test]
+fn display_opening_without_coordinates() {
+    let board = Board::standard();
+    let result = board
+        .display()
+        .with_format(Format::Compact)
+        .with_coordinates(false)
+        .to_string();
+    let expected = indoc! {"
+        +--------+
+        |........|
+        |........|
+        |........|
+        |...WB...|
+        |...BW...|
+        |........|
+        |........|
+        |........|
+        +--------+
+    "};
+    assert_eq!(expected, result);
+}
+
+#[test]
+fn display_with_ansi_colors() {
+    let board = Board::standard();
+    let plain = board.display().with_legal_moves(Stone::White).to_string();
+    let colored = board
+        .display()
+        .with_legal_moves(Stone::White)
+        .ansi_colors()
+        .to_string();
+    assert_eq!(64, colored.matches("\x1b[0m").count());
+
+    // Removing the escape codes leaves the plain output
+    let mut stripped = String::new();
+    let mut rest = colored.as_str();
+    while let Some(start) = rest.find('\x1b') {
+        stripped.push_str(&rest[..start]);
+        rest = &rest[start + rest[start..].find('m').unwrap() + 1..];
+    }
+    stripped.push_str(rest);
+    assert_eq!(plain, stripped);
+}
+
+#[test]
+fn display_bitboard() {
+    let bitboard = Board::standard().moves_for(Stone::Black);
+    let expected = indoc! {"
+         ABCDEFGH
+        +--------+
+      1 |........|
+      2 |........|
+      3 |...X....|
+      4 |..X.....|
+      5 |.....X..|
+      6 |....X...|
+      7 |........|
+      8 |........|
+        +--------+
+      "};
+    assert_eq!(expected, bitboard.to_string());
+}
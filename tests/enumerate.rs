@@ -0,0 +1,33 @@
+use magpie::{
+    enumerate::{positions_at_ply, unique_positions_at_ply},
+    othello::{Board, Stone, perft},
+};
+use std::collections::HashSet;
+
+#[test]
+fn position_counts_match_published_numbers() {
+    let expected = [1, 4, 12, 54, 236, 1288, 7092, 42614];
+    for (ply, count) in (0..).zip(expected) {
+        assert_eq!(count, positions_at_ply(ply, |_, _| {}));
+        assert!(count <= perft(&Board::standard(), Stone::Black, ply));
+    }
+}
+
+#[test]
+fn unique_positions_cover_all_positions() {
+    for ply in 0..6 {
+        let mut unique = HashSet::new();
+        let count = unique_positions_at_ply(ply, |board, stone| {
+            assert!(unique.insert((*board, stone)));
+        });
+        assert_eq!(count, unique.len() as u64);
+
+        let mut covered = HashSet::new();
+        positions_at_ply(ply, |board, stone| {
+            let (black, white) = board.count_stones();
+            assert_eq!(u32::from(ply) + 4, u32::from(black) + u32::from(white));
+            covered.insert((board.canonical().0, stone));
+        });
+        assert_eq!(unique, covered);
+    }
+}
@@ -0,0 +1,248 @@
+use magpie::{
+    eval::{
+        DiscDifference, Evaluator, Heuristic, IncrementalEvaluator, Pattern, PatternError,
+        PatternEvaluator, Tapered, TrackedBoard, WdlModel, wdl_model,
+    },
+    othello::{Board, Stone},
+    search::Wdl,
+};
+use rand::{Rng, SeedableRng, rngs::StdRng};
+
+mod common;
+
+use common::random_game;
+
+#[test]
+fn heuristic_is_antisymmetric() {
+    let heuristic = Heuristic::default();
+    for board in random_boards(100) {
+        assert_eq!(
+            heuristic.evaluate(&board, Stone::Black),
+            -heuristic.evaluate(&board, Stone::White)
+        );
+    }
+}
+
+#[test]
+fn heuristic_is_invariant_under_rotation() {
+    let heuristic = Heuristic::default();
+    for board in random_boards(100) {
+        let score = heuristic.evaluate(&board, Stone::Black);
+        assert_eq!(score, heuristic.evaluate(&board.cw(), Stone::Black));
+        assert_eq!(score, heuristic.evaluate(&board.ccw(), Stone::Black));
+        assert_eq!(score, heuristic.evaluate(&board.flip180(), Stone::Black));
+    }
+}
+
+#[test]
+fn heuristic_reduces_to_disc_difference() {
+    let discs_only = Heuristic {
+        mobility: 0,
+        frontier: 0,
+        corners: 0,
+        x_squares: 0,
+        discs: 1,
+    };
+    for board in random_boards(100) {
+        assert_eq!(
+            DiscDifference.evaluate(&board, Stone::White),
+            discs_only.evaluate(&board, Stone::White)
+        );
+    }
+}
+
+#[test]
+fn heuristic_values_corners() {
+    let heuristic = Heuristic::default();
+    // Black owns A1, white owns B2 and H8 is empty
+    let board = Board::try_from((1 << 63, 1 << 54)).unwrap();
+    assert!(heuristic.evaluate(&board, Stone::Black) > 0);
+    // Black owns B2 next to an empty A1, white owns H8
+    let board = Board::try_from((1 << 54, 1)).unwrap();
+    assert!(heuristic.evaluate(&board, Stone::Black) < 0);
+}
+
+fn random_boards(count: usize) -> Vec<Board> {
+    let mut rng = StdRng::seed_from_u64(13);
+    (0..count)
+        .map(|_| {
+            let plies = rng.random_range(0..60);
+            random_game(&mut rng, plies).board()
+        })
+        .collect()
+}
+
+#[test]
+fn pattern_evaluator_is_invariant_under_rotation() {
+    let mut rng = StdRng::seed_from_u64(14);
+    let mut evaluator = PatternEvaluator::new(3);
+    for stage in 0..3 {
+        for pattern in Pattern::ALL {
+            for weight in evaluator.weights_mut(stage, pattern) {
+                *weight = rng.random_range(-100..100);
+            }
+        }
+    }
+    for board in random_boards(100) {
+        let score = evaluator.evaluate(&board, Stone::White);
+        assert_eq!(score, evaluator.evaluate(&board.cw(), Stone::White));
+        assert_eq!(score, evaluator.evaluate(&board.flip180(), Stone::White));
+    }
+}
+
+#[test]
+fn incremental_patterns_match_full_evaluation() {
+    let mut rng = StdRng::seed_from_u64(15);
+    let mut evaluator = PatternEvaluator::new(4);
+    for stage in 0..4 {
+        for pattern in Pattern::ALL {
+            for weight in evaluator.weights_mut(stage, pattern) {
+                *weight = rng.random_range(-100..100);
+            }
+        }
+    }
+    for _ in 0..20 {
+        let mut tracked = TrackedBoard::new(&evaluator, Board::standard());
+        let mut stone = Stone::Black;
+        let mut undos = Vec::new();
+        loop {
+            let board = *tracked.board();
+            for to_move in [Stone::Black, Stone::White] {
+                assert_eq!(
+                    evaluator.evaluate(&board, to_move),
+                    tracked.evaluate(to_move)
+                );
+            }
+            assert_eq!(&evaluator.accumulate(&board), tracked.accumulator());
+
+            let moves: Vec<_> = board.moves_for(stone).hot_bits().collect();
+            if moves.is_empty() {
+                if board.moves_for(stone.flip()).is_empty() {
+                    break;
+                }
+                stone = stone.flip();
+                continue;
+            }
+            undos.push((
+                board,
+                tracked.make(stone, moves[rng.random_range(0..moves.len())]),
+            ));
+            stone = stone.flip();
+        }
+        while let Some((board, undo)) = undos.pop() {
+            tracked.unmake(undo);
+            assert_eq!(&evaluator.accumulate(&board), tracked.accumulator());
+        }
+        assert_eq!(&Board::standard(), tracked.board());
+    }
+}
+
+#[test]
+fn pattern_indices_are_in_bounds() {
+    let evaluator = PatternEvaluator::new(1);
+    for board in random_boards(100) {
+        let indices = evaluator.indices(&board, Stone::Black);
+        assert_eq!(48, indices.len());
+        for (pattern, index) in indices {
+            assert!(index < pattern.table_size());
+        }
+    }
+}
+
+#[test]
+fn pattern_evaluator_rejects_invalid_weights() {
+    let mut bytes = Vec::new();
+    PatternEvaluator::new(1).save(&mut bytes).unwrap();
+    assert!(PatternEvaluator::load(&bytes[..bytes.len() - 1]).is_err());
+    bytes[0] = b'X';
+    assert!(matches!(
+        PatternEvaluator::load(bytes.as_slice()),
+        Err(PatternError::InvalidFormat)
+    ));
+}
+
+#[test]
+fn tapered_interpolates_between_phases() {
+    let phases = vec![
+        Heuristic::default(),
+        Heuristic {
+            discs: 30,
+            ..Heuristic::default()
+        },
+        Heuristic {
+            mobility: 0,
+            discs: 60,
+            ..Heuristic::default()
+        },
+    ];
+    let tapered = Tapered::new(phases.clone());
+    for board in random_boards(100) {
+        let scores: Vec<i32> = phases
+            .iter()
+            .map(|phase| phase.evaluate(&board, Stone::Black))
+            .collect();
+        let score = tapered.evaluate(&board, Stone::Black);
+        let (earlier, later, weight) = tapered.blend(&board);
+        assert_eq!(
+            usize::from(board.stage()) * 2,
+            earlier * 60 + weight as usize
+        );
+        let (low, high) = (
+            scores[earlier].min(scores[later]),
+            scores[earlier].max(scores[later]),
+        );
+        assert!((low..=high).contains(&score));
+        // Blending is symmetric, just like the phases
+        assert_eq!(-score, tapered.evaluate(&board, Stone::White));
+    }
+}
+
+#[test]
+fn wdl_model_is_monotonic_and_symmetric() {
+    for empties in [0, 10, 30, 60] {
+        let mut previous = 0.0;
+        for score in -64..=64 {
+            let probabilities = wdl_model(f64::from(score), empties);
+            let total = probabilities.win + probabilities.draw + probabilities.loss;
+            assert!((total - 1.0).abs() < 1e-9);
+            assert!(probabilities.win >= previous);
+            previous = probabilities.win;
+            let flipped = wdl_model(-f64::from(score), empties);
+            assert!((probabilities.win - flipped.loss).abs() < 1e-9);
+        }
+    }
+    assert_eq!(
+        WdlModel::default().probabilities(3.0, 20),
+        wdl_model(3.0, 20)
+    );
+}
+
+#[test]
+fn wdl_model_fit_recovers_spread() {
+    // Final results drawn from a known model
+    let truth = WdlModel {
+        base: 1.0,
+        per_empty: 0.5,
+    };
+    let mut rng = StdRng::seed_from_u64(4);
+    let samples: Vec<_> = (0..4000)
+        .map(|_| {
+            let score = f64::from(rng.random_range(-20..=20));
+            let empties = rng.random_range(0..=60);
+            let probabilities = truth.probabilities(score, empties);
+            let roll: f64 = rng.random();
+            let outcome = if roll < probabilities.win {
+                Wdl::Win
+            } else if roll < probabilities.win + probabilities.draw {
+                Wdl::Draw
+            } else {
+                Wdl::Loss
+            };
+            (score, empties, outcome)
+        })
+        .collect();
+    let model = WdlModel::fit(&samples);
+    assert!((model.spread(30) - truth.spread(30)).abs() < 2.0);
+    assert!((model.per_empty - truth.per_empty).abs() < 0.2);
+    assert_eq!(WdlModel::default(), WdlModel::fit(&[]));
+}
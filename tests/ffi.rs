@@ -0,0 +1,151 @@
+#![cfg(feature = "ffi")]
+
+use magpie::{
+    ffi::{
+        MAGPIE_BLACK, MAGPIE_WHITE, MagpieBoard, MagpieResult, MagpieStatus, magpie_board_moves,
+        magpie_board_play, magpie_board_standard, magpie_game_board, magpie_game_current_turn,
+        magpie_game_free, magpie_game_moves, magpie_game_new, magpie_game_pass_if_forced,
+        magpie_game_play, magpie_game_status, magpie_game_undo,
+    },
+    othello::{Board, Game, Stone},
+};
+
+#[test]
+fn ffi_board_matches_library() {
+    let mut board = magpie_board_standard();
+    let mut expected = Board::standard();
+    let mut stone = MAGPIE_BLACK;
+    let mut expected_stone = Stone::Black;
+
+    for _ in 0..20 {
+        let moves = magpie_board_moves(board, stone);
+        assert_eq!(expected.moves_for(expected_stone).raw(), moves);
+        if moves == 0 {
+            break;
+        }
+        let position = 1 << moves.trailing_zeros();
+        let result = unsafe { magpie_board_play(&mut board, stone, position) };
+        assert_eq!(MagpieResult::Ok, result);
+        expected.play(expected_stone, position.try_into().unwrap());
+        assert_eq!(MagpieBoard::from(expected), board);
+
+        stone = if stone == MAGPIE_BLACK {
+            MAGPIE_WHITE
+        } else {
+            MAGPIE_BLACK
+        };
+        expected_stone = expected_stone.flip();
+    }
+}
+
+#[test]
+fn ffi_board_rejects_invalid_arguments() {
+    let mut board = magpie_board_standard();
+    let d3 = 1 << 44;
+
+    assert_eq!(0, magpie_board_moves(board, 2));
+    let overlapping = MagpieBoard { black: 1, white: 1 };
+    assert_eq!(0, magpie_board_moves(overlapping, MAGPIE_BLACK));
+
+    let result = unsafe { magpie_board_play(&mut board, MAGPIE_BLACK, 0b11) };
+    assert_eq!(MagpieResult::InvalidArgument, result);
+    let result = unsafe { magpie_board_play(std::ptr::null_mut(), MAGPIE_BLACK, d3) };
+    assert_eq!(MagpieResult::InvalidArgument, result);
+    let result = unsafe { magpie_board_play(&mut board, MAGPIE_WHITE, d3) };
+    assert_eq!(MagpieResult::IllegalMove, result);
+    assert_eq!(magpie_board_standard(), board);
+}
+
+#[test]
+fn ffi_game_plays_to_completion() {
+    let game = magpie_game_new();
+    let mut expected = Game::new();
+
+    unsafe {
+        while magpie_game_status(game) == MagpieStatus::Progressing {
+            if magpie_game_pass_if_forced(game) {
+                expected.pass_turn();
+                continue;
+            }
+            let moves = magpie_game_moves(game);
+            assert_eq!(expected.moves().raw(), moves);
+            let position = 1 << (63 - moves.leading_zeros());
+            assert_eq!(MagpieResult::Ok, magpie_game_play(game, position));
+            expected.play(position.try_into().unwrap()).unwrap();
+            assert_eq!(MagpieBoard::from(expected.board()), magpie_game_board(game));
+        }
+
+        let black = expected.board().bits_for(Stone::Black).count_set();
+        let white = expected.board().bits_for(Stone::White).count_set();
+        let status = match black.cmp(&white) {
+            std::cmp::Ordering::Greater => MagpieStatus::BlackWins,
+            std::cmp::Ordering::Less => MagpieStatus::WhiteWins,
+            std::cmp::Ordering::Equal => MagpieStatus::Draw,
+        };
+        assert_eq!(status, magpie_game_status(game));
+
+        assert!(magpie_game_undo(game));
+        assert_eq!(MagpieStatus::Progressing, magpie_game_status(game));
+        let turn = match expected.undo().unwrap().stone() {
+            Stone::Black => MAGPIE_BLACK,
+            Stone::White => MAGPIE_WHITE,
+        };
+        assert_eq!(turn, magpie_game_current_turn(game));
+
+        magpie_game_free(game);
+    }
+}
+
+#[test]
+fn ffi_header_declares_all_functions() {
+    let header = include_str!("../include/magpie.h");
+    let source = [
+        include_str!("../src/ffi/board.rs"),
+        include_str!("../src/ffi/game.rs"),
+    ]
+    .concat();
+
+    let exported: Vec<&str> = source
+        .split("extern \"C\" fn ")
+        .skip(1)
+        .map(|rest| rest.split('(').next().unwrap())
+        .collect();
+    assert!(!exported.is_empty());
+    for name in &exported {
+        assert!(
+            header.contains(&format!(" {name}(")) || header.contains(&format!("*{name}(")),
+            "{name} is missing from the header"
+        );
+    }
+
+    let declared = header
+        .lines()
+        .filter(|line| line.contains("magpie_") && line.trim_end().ends_with(");"))
+        .count();
+    assert_eq!(exported.len(), declared);
+}
+
+/// Regenerates the header with cbindgen and compares it with the committed
+/// one, which is rewritten instead if `MAGPIE_UPDATE_HEADER` is set.
+#[test]
+fn ffi_header_matches_cbindgen() {
+    let dir = env!("CARGO_MANIFEST_DIR");
+    let config = cbindgen::Config::from_file(format!("{dir}/cbindgen.toml")).unwrap();
+    let mut generated = Vec::new();
+    cbindgen::Builder::new()
+        .with_config(config)
+        .with_src(format!("{dir}/src/ffi/mod.rs"))
+        .generate()
+        .unwrap()
+        .write(&mut generated);
+
+    let path = format!("{dir}/include/magpie.h");
+    if std::env::var_os("MAGPIE_UPDATE_HEADER").is_some() {
+        std::fs::write(&path, &generated).unwrap();
+    }
+    let header = std::fs::read(&path).unwrap();
+    assert!(
+        header == generated,
+        "include/magpie.h is out of date, run `MAGPIE_UPDATE_HEADER=1 cargo test --features ffi` to regenerate it"
+    );
+}
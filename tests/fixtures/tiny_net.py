@@ -0,0 +1,106 @@
+"""Writes tiny_net.onnx, a small network used to test the ONNX evaluator.
+
+The network takes planes of shape [N, 2, 8, 8] and has two outputs:
+
+- value, of shape [N, 1]: tanh((own stones - opponent stones) / 16)
+- policy, of shape [N, 64]: a softmax that gives occupied squares a logit
+  of -4 and empty squares a logit of 0
+
+The protobuf encoding is written by hand, so that only the standard library
+is needed. Run it from this directory with `python3 tiny_net.py`.
+"""
+
+import struct
+
+
+def varint(value):
+    out = bytearray()
+    while True:
+        byte = value & 0x7F
+        value >>= 7
+        if value:
+            out.append(byte | 0x80)
+        else:
+            out.append(byte)
+            return bytes(out)
+
+
+def field(number, wire_type):
+    return varint(number << 3 | wire_type)
+
+
+def int_field(number, value):
+    return field(number, 0) + varint(value)
+
+
+def bytes_field(number, value):
+    if isinstance(value, str):
+        value = value.encode()
+    return field(number, 2) + varint(len(value)) + value
+
+
+FLOAT = 1
+INT = 2
+
+
+def tensor(name, dims, values):
+    raw = struct.pack("<%df" % len(values), *values)
+    return (
+        b"".join(int_field(1, dim) for dim in dims)
+        + int_field(2, FLOAT)
+        + bytes_field(8, name)
+        + bytes_field(9, raw)
+    )
+
+
+def value_info(name, dims):
+    shape = b"".join(
+        bytes_field(1, bytes_field(2, dim) if isinstance(dim, str) else int_field(1, dim))
+        for dim in dims
+    )
+    tensor_type = int_field(1, FLOAT) + bytes_field(2, shape)
+    return bytes_field(1, name) + bytes_field(2, bytes_field(1, tensor_type))
+
+
+def int_attribute(name, value):
+    return bytes_field(1, name) + int_field(3, value) + int_field(20, INT)
+
+
+def node(op_type, inputs, outputs, attributes=()):
+    return (
+        b"".join(bytes_field(1, name) for name in inputs)
+        + b"".join(bytes_field(2, name) for name in outputs)
+        + bytes_field(3, outputs[0])
+        + bytes_field(4, op_type)
+        + b"".join(bytes_field(5, attribute) for attribute in attributes)
+    )
+
+
+value_weights = [1 / 16] * 64 + [-1 / 16] * 64
+policy_weights = [
+    -4.0 if square % 64 == move else 0.0 for square in range(128) for move in range(64)
+]
+
+graph = (
+    bytes_field(1, node("Flatten", ["planes"], ["flat"], [int_attribute("axis", 1)]))
+    + bytes_field(1, node("MatMul", ["flat", "value_weights"], ["value_logit"]))
+    + bytes_field(1, node("Tanh", ["value_logit"], ["value"]))
+    + bytes_field(1, node("MatMul", ["flat", "policy_weights"], ["policy_logits"]))
+    + bytes_field(1, node("Softmax", ["policy_logits"], ["policy"], [int_attribute("axis", 1)]))
+    + bytes_field(2, "tiny_net")
+    + bytes_field(5, tensor("value_weights", [128, 1], value_weights))
+    + bytes_field(5, tensor("policy_weights", [128, 64], policy_weights))
+    + bytes_field(11, value_info("planes", ["N", 2, 8, 8]))
+    + bytes_field(12, value_info("value", ["N", 1]))
+    + bytes_field(12, value_info("policy", ["N", 64]))
+)
+
+model = (
+    int_field(1, 7)
+    + bytes_field(2, "magpie")
+    + bytes_field(7, graph)
+    + bytes_field(8, bytes_field(1, "") + int_field(2, 13))
+)
+
+with open("tiny_net.onnx", "wb") as file:
+    file.write(model)
@@ -1,12 +1,251 @@
-use magpie::othello::{Bitboard, Game, Position};
+use magpie::othello::{Board, Game, Move, OthelloError, Position, Rules, Scoring, Status, Stone};
 
 mod common;
 
-use common::ShadowGame;
+#[test]
+fn game_over_without_passing() {
+    // Neither player can move, so the game is over even though nobody passed
+    let board = Board::try_from((0xff_00_00_00_00_00_00_00, 0x00_00_00_00_00_00_00_0f)).unwrap();
+    let game = Game::from_state(board, Stone::White, false).unwrap();
+    assert_eq!(Status::Win(Stone::Black), game.status());
+    assert!(!game.must_pass());
+}
+
+#[test]
+fn forced_passes_are_handled() {
+    let mut game = Game::new();
+    while game.status() == Status::Progressing {
+        if !game.pass_if_forced() {
+            let pos = game.moves().hot_bits().next().unwrap();
+            game.play(pos).unwrap();
+        }
+    }
+    assert!(game.moves().is_empty());
+}
+
+#[test]
+fn undo_restores_every_state() {
+    let mut game = Game::new();
+    let mut states = vec![game.clone()];
+    while game.status() == Status::Progressing {
+        if !game.pass_if_forced() {
+            let pos = game.moves().hot_bits().last().unwrap();
+            game.play(pos).unwrap();
+        }
+        states.push(game.clone());
+    }
+    assert_eq!(states.len() - 1, game.history().len());
+
+    let end = game.clone();
+    for state in states.iter().rev().skip(1) {
+        let played = game.undo().unwrap();
+        assert_eq!(state.board(), game.board());
+        assert_eq!(state.current_turn(), game.current_turn());
+        assert_eq!(state.passed_last_turn(), game.passed_last_turn());
+        assert_eq!(played.stone(), game.current_turn());
+    }
+    assert!(game.undo().is_none());
+
+    while game.redo().is_some() {}
+    assert_eq!(end, game);
+}
+
+#[test]
+fn playing_discards_undone_moves() {
+    let mut game = Game::new();
+    game.play("f5".parse().unwrap()).unwrap();
+    game.undo();
+    game.play("d3".parse().unwrap()).unwrap();
+    assert!(game.redo().is_none());
+    assert_eq!(1, game.history().len());
+}
+
+#[test]
+fn playing_after_pass_resets_pass() {
+    let mut game = Game::new();
+    game.pass_turn();
+    assert!(game.passed_last_turn());
+    let pos = game.moves().hot_bits().next().unwrap();
+    game.play(pos).unwrap();
+    assert!(!game.passed_last_turn());
+    game.undo();
+    assert!(game.passed_last_turn());
+}
+
+#[test]
+fn reversi_starts_with_placement() {
+    let mut game = Game::with_rules(Rules::Reversi);
+    for notation in ["d4", "d5", "e4"] {
+        assert!(game.is_placing());
+        assert_eq!(Status::Progressing, game.status());
+        assert!(!game.is_legal_move("c3".parse().unwrap()));
+        game.play(notation.parse().unwrap()).unwrap();
+        assert!(game.history().last().unwrap().flipped().is_empty());
+    }
+    // The same center square cannot be taken twice
+    assert!(game.play("d4".parse().unwrap()).is_err());
+    assert_eq!(1, game.moves().count_set());
+    game.play("e5".parse().unwrap()).unwrap();
+
+    // Black placed D4 and E4, so this is not the standard opening
+    assert!(!game.is_placing());
+    assert_eq!(Stone::Black, game.current_turn());
+    assert_ne!(Board::standard(), game.board());
+    assert_eq!(game.board().moves_for(Stone::Black), game.moves());
+
+    while game.undo().is_some() {}
+    assert_eq!(Board::empty(), game.board());
+    assert!(game.is_placing());
+    assert_eq!(Rules::Reversi, game.rules());
+}
+
+#[test]
+fn custom_positions_are_validated() {
+    let mut overlapping = Board::standard();
+    overlapping.place_stone_unchecked(Stone::White, Board::standard().bits_for(Stone::Black));
+    assert_eq!(
+        Err(OthelloError::PiecesOverlapping),
+        Game::from_position(overlapping, Stone::Black)
+    );
+
+    // A game can never have fewer than four stones, all in the center
+    let board = Board::try_from((0x80_00_00_00_00_00_00_00, 0x40_00_00_00_00_00_00_00)).unwrap();
+    assert_eq!(
+        Err(OthelloError::ImpossiblePosition),
+        Game::from_position(board, Stone::Black)
+    );
+
+    let game = Game::from_position(Board::standard(), Stone::White).unwrap();
+    assert_eq!(Stone::White, game.current_turn());
+    assert!(game.history().is_empty());
+}
+
+#[test]
+fn checked_moves_report_what_went_wrong() {
+    // Black has no legal moves on this board, but white does
+    let board = Board::try_from((0x40_00_00_00_00_00_00_00, 0x80_00_00_00_00_00_00_00)).unwrap();
+    let mut game = Game::from_state(board, Stone::Black, false).unwrap();
+    let b1 = Move::Play("b1".parse().unwrap());
+    assert_eq!(
+        Err(OthelloError::IllegalMove),
+        game.play_move(Stone::Black, b1)
+    );
+    assert_eq!(
+        Err(OthelloError::WrongTurn),
+        game.play_move(Stone::White, Move::Pass)
+    );
+    assert_eq!(Ok(()), game.play_move(Stone::Black, Move::Pass));
+    assert_eq!(
+        Err(OthelloError::IllegalMove),
+        game.play_move(Stone::White, Move::Pass)
+    );
+
+    // The game ends once white captures the last black stone
+    let c1 = Move::Play("c1".parse().unwrap());
+    assert_eq!(Ok(()), game.play_move(Stone::White, c1));
+    assert_eq!(Status::Win(Stone::White), game.status());
+    let history = game.history().len();
+    assert_eq!(
+        Err(OthelloError::GameOver),
+        game.play_move(Stone::Black, Move::Pass)
+    );
+    assert_eq!(
+        Err(OthelloError::GameOver),
+        game.play("d1".parse().unwrap())
+    );
+    assert_eq!(history, game.history().len());
+}
+
+#[test]
+fn parse_errors_convert_into_othello_errors() {
+    use std::error::Error;
+
+    let error = OthelloError::from("a9".parse::<Position>().unwrap_err());
+    assert!(matches!(error, OthelloError::InvalidSquare(_)));
+    assert!(error.source().is_some());
+    let error = OthelloError::from(Board::from_position_str("X").unwrap_err());
+    assert!(matches!(error, OthelloError::InvalidNotation(_)));
+    assert!(error.to_string().starts_with("invalid board"));
+    assert!(OthelloError::GameOver.source().is_none());
+}
+
+#[test]
+fn handicap_corners_are_added_in_order() {
+    let mut previous = Board::standard().bits_for(Stone::White);
+    for corners in 1..=4 {
+        let game = Game::with_handicap(Stone::White, corners).unwrap();
+        let white = game.bits_for(Stone::White);
+        assert_eq!(previous, previous & white);
+        assert_eq!(2 + corners, white.count_set());
+        assert_eq!(
+            Board::standard().bits_for(Stone::Black),
+            game.bits_for(Stone::Black)
+        );
+        assert_eq!(Stone::Black, game.current_turn());
+        previous = white;
+    }
+    assert_eq!(
+        Err(OthelloError::ImpossiblePosition),
+        Game::with_handicap(Stone::White, 5)
+    );
+}
+
+#[test]
+fn final_scores_follow_the_convention() {
+    let square = |name: &str| Position::try_from(name).unwrap().raw();
+
+    // Black wipes out white with 61 empty squares left
+    let board = Board::try_from((square("e4"), square("d4"))).unwrap();
+    let mut game = Game::from_state(board, Stone::Black, false).unwrap();
+    assert_eq!(None, game.final_score(Scoring::WinnerTakesEmpties));
+    game.play("c4".parse().unwrap()).unwrap();
+    assert_eq!(Status::Win(Stone::Black), game.status());
+    assert_eq!(Some((3, 0)), game.final_score(Scoring::DiscCount));
+    assert_eq!(Some((64, 0)), game.final_score(Scoring::WinnerTakesEmpties));
+
+    // Empty squares are split evenly in a draw
+    let board = Board::try_from((0xC0 << 56, 0x03)).unwrap();
+    let game = Game::from_state(board, Stone::White, false).unwrap();
+    assert_eq!(Status::Draw, game.status());
+    assert_eq!(Some((2, 2)), game.final_score(Scoring::DiscCount));
+    assert_eq!(
+        Some((32, 32)),
+        game.final_score(Scoring::WinnerTakesEmpties)
+    );
+    assert_eq!(
+        0,
+        Scoring::WinnerTakesEmpties.difference(&board, Stone::Black)
+    );
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn random_playouts_finish_games() {
+    use rand::{SeedableRng, rngs::StdRng};
+
+    let mut rng = StdRng::seed_from_u64(1);
+    for _ in 0..50 {
+        let mut game = Game::new();
+        let status = game.random_playout(&mut rng);
+        assert_ne!(Status::Progressing, status);
+        assert_eq!(status, game.status());
+        while game.undo().is_some() {}
+        assert_eq!(Board::standard(), game.board());
+    }
+
+    let a = Board::random_reachable(&mut StdRng::seed_from_u64(2), 30);
+    let b = Board::random_reachable(&mut StdRng::seed_from_u64(2), 30);
+    assert_eq!(a, b);
+    let (board, stone) = a;
+    assert!(board.empty_squares().count_set() >= 30);
+    assert!(Game::from_position(board, stone).is_ok());
+}
 
 #[cfg(kani)]
 mod proof {
-    use super::*;
+    use super::common::ShadowGame;
+    use magpie::othello::{Bitboard, Game, Position};
+
     #[kani::proof]
     fn legal_moves_should_place_kani() {
         // Check so that all legal moves returned can actually be placed
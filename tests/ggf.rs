@@ -0,0 +1,129 @@
+use magpie::{
+    ggf::{self, GgfError, GgfGame, GgfMove, GgfReader},
+    othello::{Game, Move, Rules, Status, Stone, Transcript},
+};
+use rand::seq::IteratorRandom;
+use std::io::BufReader;
+
+#[test]
+fn ggf_random_games_roundtrip() {
+    let mut rng = rand::rng();
+    for _ in 0..50 {
+        let mut game = Game::new();
+        let mut transcript = Transcript::new();
+        while game.status() == Status::Progressing {
+            if game.pass_if_forced() {
+                continue;
+            }
+            let pos = game.moves().hot_bits().choose(&mut rng).unwrap();
+            game.play(pos).unwrap();
+            transcript.push(Move::Play(pos));
+        }
+
+        let mut ggf = GgfGame::from_transcript(&transcript).unwrap();
+        ggf.set_tag("PB", "Alice");
+        ggf.set_tag("PW", "Bob");
+        ggf.set_tag("RE", "+0.000");
+
+        let parsed = ggf::parse_game(&ggf.to_string()).unwrap();
+        assert_eq!(ggf, parsed);
+        assert_eq!(game.board(), parsed.replay().unwrap().board());
+    }
+}
+
+#[test]
+fn ggf_exports_games_from_custom_positions() {
+    let mut rng = rand::rng();
+    for corners in 0..=4 {
+        let mut game = Game::with_handicap(Stone::Black, corners).unwrap();
+        while game.status() == Status::Progressing {
+            if !game.pass_if_forced() {
+                let pos = game.moves().hot_bits().choose(&mut rng).unwrap();
+                game.play(pos).unwrap();
+            }
+        }
+
+        let ggf = game.to_ggf();
+        let start = Game::with_handicap(Stone::Black, corners).unwrap().board();
+        assert_eq!((start, Stone::Black), ggf.start_position());
+        let parsed = ggf::parse_game(&ggf.to_string()).unwrap();
+        assert_eq!(ggf, parsed);
+        assert_eq!(game, parsed.replay().unwrap());
+    }
+
+    let mut game = Game::with_rules(Rules::Reversi);
+    for notation in ["d4", "e5", "d5", "e4", "f4"] {
+        game.play(notation.parse().unwrap()).unwrap();
+    }
+    let parsed = ggf::parse_game(&game.to_ggf().to_string()).unwrap();
+    assert_eq!(1, parsed.moves().len());
+    assert_eq!(game.board(), parsed.replay().unwrap().board());
+}
+
+#[test]
+fn ggf_parses_nboard_style_game() {
+    let text = "(;GM[Othello]PC[NBoard]DT[2014-02-21 20:52:27 GMT]PB[Human]PW[Edax]\
+                RE[?]TI[15:00]TY[8]\
+                BO[8 -------- -------- -------- ---O*--- ---*O--- -------- -------- -------- *]\
+                B[F5]W[F6//1.2]B[E6/-2.5/0.8];)";
+    let game = ggf::parse_game(text).unwrap();
+    assert_eq!(Some("NBoard"), game.place());
+    assert_eq!(Some("15:00"), game.time_control());
+    assert_eq!(None, game.result());
+    assert_eq!(
+        GgfMove {
+            stone: Stone::Black,
+            mv: Move::Play("e6".parse().unwrap()),
+            eval: Some(-2.5),
+            time: Some(0.8),
+        },
+        game.moves()[2]
+    );
+    assert!(game.replay().is_ok());
+}
+
+#[test]
+fn ggf_rejects_invalid_games() {
+    assert_eq!(Err(GgfError::Syntax), ggf::parse_game("GM[Othello]"));
+    assert_eq!(
+        Err(GgfError::UnsupportedBoardSize(10)),
+        ggf::parse_game("(;BO[10 - *];)")
+    );
+    let game = ggf::parse_game(
+        "(;BO[8 ---------------------------O*------*O--------------------------- *]W[F5];)",
+    )
+    .unwrap();
+    assert_eq!(Err(GgfError::IllegalMove(0)), game.replay());
+}
+
+#[test]
+fn ggf_reader_matches_parse_games() {
+    let mut rng = rand::rng();
+    let mut text = String::from("1 games (including comments)\n");
+    for _ in 0..20 {
+        let mut game = Game::new();
+        let mut transcript = Transcript::new();
+        while game.status() == Status::Progressing {
+            if game.pass_if_forced() {
+                continue;
+            }
+            let pos = game.moves().hot_bits().choose(&mut rng).unwrap();
+            game.play(pos).unwrap();
+            transcript.push(Move::Play(pos));
+        }
+        let mut ggf = GgfGame::from_transcript(&transcript).unwrap();
+        ggf.set_tag("C", "(a comment)");
+        text.push_str(&format!("{ggf} (\n"));
+    }
+
+    // A tiny buffer forces games to be split across several reads
+    let reader = GgfReader::new(BufReader::with_capacity(7, text.as_bytes()));
+    let games: Vec<GgfGame> = reader.collect::<Result<_, _>>().unwrap();
+    assert_eq!(ggf::parse_games(&text).unwrap(), games);
+    assert_eq!(20, games.len());
+
+    let mut reader = GgfReader::new("(;GM[Othello];) (;GM[Othello]".as_bytes());
+    assert!(reader.next().unwrap().is_ok());
+    assert_eq!(Some(Err(GgfError::Syntax)), reader.next());
+    assert_eq!(None, reader.next());
+}
@@ -0,0 +1,99 @@
+#![cfg(feature = "net")]
+
+use magpie::{
+    ggs::{Client, Command, Event, Parser},
+    othello::{Board, Move, Stone},
+};
+use std::{io::Cursor, time::Duration};
+
+const JOIN: &str = "\
+/os: join .1.2 s8r20 K?
+|0 move(s)
+|*alice   (1800.0) 04:59//02:00
+|O bob    (1700.0) 05:00//02:00
+|
+|   A B C D E F G H
+| 1 - - - - - - - - 1
+| 2 - - - - - - - - 2
+| 3 - - - - - - - - 3
+| 4 - - - O * - - - 4
+| 5 - - - * O - - - 5
+| 6 - - - - - - - - 6
+| 7 - - - - - - - - 7
+| 8 - - - - - - - - 8
+|   A B C D E F G H
+|
+|* to move
+";
+
+#[test]
+fn ggs_parses_board_updates() {
+    let mut parser = Parser::new();
+    // Data may arrive in arbitrary chunks
+    for chunk in JOIN.as_bytes().chunks(7) {
+        parser.push(chunk);
+    }
+    let Some(Event::Update(update)) = parser.next_event() else {
+        panic!("expected an update");
+    };
+    assert_eq!(".1.2", update.id);
+    assert_eq!(Board::standard(), update.board);
+    assert_eq!(Stone::Black, update.to_move);
+    assert_eq!(
+        [
+            Some(Duration::from_secs(4 * 60 + 59)),
+            Some(Duration::from_secs(5 * 60))
+        ],
+        update.clocks
+    );
+    assert_eq!(None, parser.next_event());
+}
+
+#[test]
+fn ggs_client_logs_in_and_relays_events() {
+    let input = format!(
+        "Enter your handle: \nEnter your password: \nREADY\n\
+         /os: + match .1.2 1800.0 alice 1700.0 bob 8 R\n{JOIN}\
+         /os: - match .1.2 alice 33 bob 31\n"
+    );
+    let mut client = Client::new(Cursor::new(input.clone().into_bytes()), "alice", "secret");
+
+    let mut events = Vec::new();
+    while let Some(event) = client.next_event().unwrap() {
+        events.push(event);
+    }
+    assert_eq!(3, events.len());
+    let Event::MatchStarted(info) = &events[0] else {
+        panic!("expected a match");
+    };
+    assert_eq!(
+        [
+            (String::from("alice"), 1800.0),
+            (String::from("bob"), 1700.0)
+        ],
+        info.players
+    );
+    assert_eq!("8 R", info.kind);
+    assert!(matches!(events[1], Event::Update(_)));
+    assert_eq!(
+        Event::MatchEnded {
+            id: String::from(".1.2"),
+            result: String::from("alice 33 bob 31"),
+        },
+        events[2]
+    );
+
+    client
+        .send(&Command::Play {
+            id: String::from(".1.2"),
+            mv: Move::Pass,
+            eval: None,
+            time: Some(Duration::from_millis(1500)),
+        })
+        .unwrap();
+    let written = &client.stream().get_ref()[input.len()..];
+    assert_eq!(
+        "alice\nsecret\ntell /os play .1.2 PA//1.50\n",
+        String::from_utf8_lossy(written)
+    );
+}
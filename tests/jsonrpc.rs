@@ -0,0 +1,105 @@
+#![cfg(feature = "jsonrpc")]
+
+use indoc::indoc;
+use magpie::{
+    eval::DiscDifference,
+    othello::{Position, Stone},
+    protocol::jsonrpc::JsonRpcServer,
+    search::Searcher,
+};
+use serde_json::Value;
+
+fn call(server: &mut JsonRpcServer<Searcher<DiscDifference>>, input: &str) -> Vec<Value> {
+    let mut output = Vec::new();
+    server.run(input.as_bytes(), &mut output).unwrap();
+    String::from_utf8(output)
+        .unwrap()
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect()
+}
+
+#[test]
+fn jsonrpc_plays_legal_moves() {
+    let mut server = JsonRpcServer::new(Searcher::new(DiscDifference)).with_depth(3);
+    let input = indoc! {r#"
+        {"jsonrpc": "2.0", "id": "a", "method": "position", "params": {"moves": "f5d6c3"}}
+        {"jsonrpc": "2.0", "method": "play", "params": {"move": "D3"}}
+
+        {"jsonrpc": "2.0", "id": 2, "method": "go", "params": {"depth": 4}}
+    "#};
+    let responses = call(&mut server, input);
+    assert_eq!(2, responses.len());
+    assert_eq!("a", responses[0]["id"]);
+    assert_eq!("white", responses[0]["result"]["toMove"]);
+    assert_eq!(57, responses[0]["result"]["empties"]);
+    assert_eq!(Stone::Black, server.game().current_turn());
+
+    let result = &responses[1]["result"];
+    assert_eq!(2, responses[1]["id"]);
+    assert_eq!(4, result["depth"]);
+    let pos = Position::try_from(result["move"].as_str().unwrap()).unwrap();
+    assert!(server.game().is_legal_move(pos));
+    assert_eq!(result["move"], result["pv"][0]);
+}
+
+#[test]
+fn jsonrpc_ranks_moves_from_custom_positions() {
+    let mut server = JsonRpcServer::new(Searcher::new(DiscDifference)).with_depth(2);
+    let board = "-".repeat(27) + "OX------XO" + &"-".repeat(27);
+    let request = serde_json::json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "method": "position",
+        "params": {"board": board, "toMove": "white"},
+    });
+    let input = format!(
+        "{request}\n{}\n",
+        r#"{"jsonrpc":"2.0","id":2,"method":"analyze","params":{"count":3}}"#
+    );
+    let responses = call(&mut server, &input);
+    assert_eq!(Stone::White, server.game().current_turn());
+    assert_eq!(4, responses[0]["result"]["moves"].as_array().unwrap().len());
+
+    let moves = responses[1]["result"]["moves"].as_array().unwrap();
+    assert_eq!(3, moves.len());
+    let scores: Vec<f64> = moves
+        .iter()
+        .map(|mv| mv["score"].as_f64().unwrap())
+        .collect();
+    assert!(scores.windows(2).all(|pair| pair[0] >= pair[1]));
+}
+
+#[test]
+fn jsonrpc_reports_errors() {
+    let mut server = JsonRpcServer::new(Searcher::new(DiscDifference));
+    let input = indoc! {r#"
+        {"jsonrpc": "2.0", "id": 1, "method": "play"
+        {"jsonrpc": "2.0", "id": 2}
+        {"jsonrpc": "2.0", "id": 3, "method": "resign"}
+        {"jsonrpc": "2.0", "id": 4, "method": "go", "params": {"depth": "deep"}}
+        {"jsonrpc": "2.0", "id": 5, "method": "play", "params": {"move": "a1"}}
+        {"jsonrpc": "2.0", "id": 6, "method": "position", "params": {"moves": "f5f5"}}
+        {"jsonrpc": "2.0", "id": 7, "method": "quit"}
+        {"jsonrpc": "2.0", "id": 8, "method": "engine"}
+    "#};
+    let responses = call(&mut server, input);
+    let codes: Vec<i64> = responses
+        .iter()
+        .filter_map(|response| response["error"]["code"].as_i64())
+        .collect();
+    assert_eq!(vec![-32700, -32600, -32601, -32602, -32000, -32000], codes);
+    assert_eq!(Value::Null, responses[0]["id"]);
+    assert_eq!(7, responses.len());
+    assert_eq!(Value::Null, responses[6]["result"]);
+    assert_eq!(60, server.game().empty_squares().count_set());
+}
+
+#[test]
+fn jsonrpc_echoes_escaped_ids() {
+    let mut server = JsonRpcServer::new(Searcher::new(DiscDifference));
+    let input = r#"{"id": "caf\u00e9 \"\ud83d\ude00\"\n", "method": "engine", "jsonrpc": "2.0"}"#;
+    let responses = call(&mut server, input);
+    assert_eq!("café \"😀\"\n", responses[0]["id"]);
+    assert_eq!("magpie", responses[0]["result"]["name"]);
+}
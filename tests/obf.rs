@@ -0,0 +1,91 @@
+use magpie::{
+    obf::{self, ObfError, Problem},
+    othello::{Game, Move, PositionStrError, Status, Stone},
+};
+use rand::seq::IteratorRandom;
+
+#[test]
+fn problems_roundtrip() {
+    let mut rng = rand::rng();
+    for _ in 0..50 {
+        let mut game = Game::new();
+        let plies = (0..60).choose(&mut rng).unwrap();
+        for _ in 0..plies {
+            if game.status() != Status::Progressing {
+                break;
+            }
+            if !game.pass_if_forced() {
+                let pos = game.moves().hot_bits().choose(&mut rng).unwrap();
+                game.play(pos).unwrap();
+            }
+        }
+        let mut problem = Problem::new(game.board(), game.current_turn());
+        problem.scores = game
+            .moves()
+            .hot_bits()
+            .zip(-32..)
+            .map(|(pos, score)| (Move::Play(pos), score))
+            .collect();
+        if problem.scores.is_empty() {
+            problem.scores.push((Move::Pass, -4));
+        }
+        assert_eq!(problem, obf::parse_problem(&problem.to_string()).unwrap());
+    }
+}
+
+#[test]
+fn script_lines_have_no_scores() {
+    let text = "---------------------------OX------XO--------------------------- O";
+    let problem = obf::parse_problem(text).unwrap();
+    assert_eq!(Stone::White, problem.stone);
+    assert!(problem.scores.is_empty());
+    assert_eq!(None, problem.best_move());
+    assert_eq!(text, problem.to_string());
+}
+
+#[test]
+fn malformed_problems_are_rejected() {
+    let start = "---------------------------OX------XO---------------------------";
+    assert_eq!(
+        Err(ObfError::InvalidPosition(PositionStrError::InvalidLength)),
+        obf::parse_problem(&start[1..])
+    );
+    assert_eq!(
+        Err(ObfError::InvalidPosition(
+            PositionStrError::InvalidSideToMove
+        )),
+        obf::parse_problem(&format!("{start} -"))
+    );
+    assert_eq!(
+        Err(ObfError::InvalidMove(1)),
+        obf::parse_problem(&format!("{start} X; D3:+0; Z9:+0;"))
+    );
+    assert_eq!(
+        Err(ObfError::InvalidMove(0)),
+        obf::parse_problem(&format!("{start} X; D3"))
+    );
+    assert_eq!(
+        Err(ObfError::InvalidScore(0)),
+        obf::parse_problem(&format!("{start} X; D3:even;"))
+    );
+}
+
+#[cfg(feature = "ffo")]
+#[test]
+fn solver_matches_ffo_scores() {
+    use magpie::search::solve_endgame;
+
+    // Positions with more empty squares take minutes to solve
+    let problems = obf::ffo()
+        .into_iter()
+        .filter(|problem| problem.board.empty_squares().count_set() <= 22);
+    for problem in problems {
+        let (_, score) = problem.best_move().unwrap();
+        let (solved, best) = solve_endgame(&problem.board, problem.stone);
+        assert_eq!(score, solved);
+
+        // Any of the moves listed with the best score may be found
+        let best = Move::Play(best.unwrap());
+        assert!(problem.scores.contains(&(best, score)));
+    }
+}
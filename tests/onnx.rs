@@ -0,0 +1,57 @@
+#![cfg(feature = "onnx")]
+
+use magpie::{
+    eval::{ExternalEvaluator, OnnxEvaluator},
+    othello::{Board, Stone},
+    search::{MctsLimits, Puct},
+};
+
+/// A network with a value of tanh((own - opponent) / 16) and a policy that
+/// prefers empty squares, written by `tests/fixtures/tiny_net.py`.
+const TINY_NET: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/tiny_net.onnx");
+
+#[test]
+fn onnx_evaluator_predicts_batches() {
+    let mut evaluator = OnnxEvaluator::load(TINY_NET).unwrap();
+    let mut board = Board::standard();
+    let standard = board.to_planes(Stone::Black);
+    board.play(Stone::Black, "f5".parse().unwrap());
+    let after_f5 = board.to_planes(Stone::White);
+
+    let predictions = evaluator.predict(&[standard, after_f5]);
+    assert_eq!(2, predictions.len());
+    assert!(predictions[0].value.abs() < 1e-6);
+    assert!((predictions[1].value - (-3.0_f32 / 16.0).tanh()).abs() < 1e-6);
+
+    for (prediction, planes) in predictions.iter().zip([standard, after_f5]) {
+        assert!((prediction.policy.iter().sum::<f32>() - 1.0).abs() < 1e-5);
+        let empty = prediction.policy[0];
+        for (index, probability) in prediction.policy.iter().enumerate() {
+            let (rank, file) = (index / 8, index % 8);
+            let occupied = planes[0][rank][file] + planes[1][rank][file] > 0.0;
+            if occupied {
+                assert!(*probability < empty);
+            } else {
+                assert!((*probability - empty).abs() < 1e-6);
+            }
+        }
+    }
+    assert!(evaluator.predict(&[]).is_empty());
+}
+
+#[test]
+fn onnx_evaluator_drives_puct() {
+    let evaluator = OnnxEvaluator::load(TINY_NET).unwrap();
+    let board = Board::standard();
+    let mut puct = Puct::new(evaluator).with_batch_size(8);
+    let report = puct.search(&board, Stone::Black, MctsLimits::playouts(200));
+    assert_eq!(200, report.playouts);
+    let best_move = report.best_move.unwrap();
+    assert!(board.is_legal_move(Stone::Black, best_move));
+}
+
+#[test]
+fn onnx_evaluator_rejects_invalid_models() {
+    assert!(OnnxEvaluator::read(&b"not a model"[..]).is_err());
+    assert!(OnnxEvaluator::load("tests/fixtures/missing.onnx").is_err());
+}
@@ -1,7 +1,12 @@
-use magpie::othello::{Bitboard, Board, Position, Stone};
+use magpie::othello::{
+    Bitboard, Board, Direction, Kernel, KernelError, OthelloError, Position, PositionStrError,
+    SizedBoard, Square, Stone, Symmetry,
+};
+use rand::seq::IteratorRandom;
 
 mod common;
 
+#[cfg(kani)]
 use common::ShadowBoard;
 
 #[test]
@@ -18,6 +23,151 @@ fn legal_move_check_none_valid() {
     assert!(!board.is_legal_move(Stone::Black, pos.try_into().unwrap()));
 }
 
+#[test]
+fn kernels_agree() {
+    let mut rng = rand::rng();
+    let kernels: Vec<Kernel> = Kernel::ALL
+        .into_iter()
+        .filter(|kernel| kernel.is_supported())
+        .collect();
+    for _ in 0..200 {
+        let mut board = Board::standard();
+        let mut stone = Stone::Black;
+        loop {
+            // Flips are also checked for illegal moves, which must not flip
+            for pos in board.empty_squares().hot_bits() {
+                let flips = board.flips_with(Kernel::Rays, stone, pos);
+                for kernel in &kernels {
+                    assert_eq!(flips, board.flips_with(*kernel, stone, pos), "{kernel}");
+                }
+            }
+            let moves = board.moves_for(stone);
+            for kernel in &kernels {
+                assert_eq!(moves, board.moves_with(*kernel, stone));
+            }
+            if moves.is_empty() {
+                stone = stone.flip();
+                if board.moves_for(stone).is_empty() {
+                    break;
+                }
+                continue;
+            }
+            let pos = moves.hot_bits().choose(&mut rng).unwrap();
+            board.play(stone, pos);
+            stone = stone.flip();
+        }
+    }
+}
+
+#[test]
+fn kernels_parse_their_names() {
+    for kernel in Kernel::ALL {
+        assert_eq!(Ok(kernel), kernel.name().parse());
+    }
+    assert_eq!(Ok(Kernel::Bmi2), " BMI2 ".parse());
+    assert_eq!(Err(KernelError::Unknown), "avx512".parse::<Kernel>());
+    if !Kernel::Bmi2.is_supported() {
+        assert_eq!(
+            Err(KernelError::Unsupported(Kernel::Bmi2)),
+            Kernel::set_current(Kernel::Bmi2)
+        );
+    }
+}
+
+#[test]
+fn legal_moves_fast_and_mobility_match_moves_for() {
+    let mut rng = rand::rng();
+    for _ in 0..200 {
+        let mut board = Board::standard();
+        let mut stone = Stone::Black;
+        loop {
+            let moves = board.moves_for(stone);
+            assert_eq!(moves, board.legal_moves_fast(stone));
+            assert_eq!(moves.count_set(), board.mobility(stone));
+            if moves.is_empty() {
+                stone = stone.flip();
+                if board.moves_for(stone).is_empty() {
+                    break;
+                }
+                continue;
+            }
+            let pos = moves.hot_bits().choose(&mut rng).unwrap();
+            board.play(stone, pos);
+            stone = stone.flip();
+        }
+    }
+}
+
+#[test]
+fn transforms_commute_with_moves() {
+    let mut rng = rand::rng();
+    let mut board = Board::standard();
+    let mut stone = Stone::Black;
+    for _ in 0..30 {
+        let Some(pos) = board.moves_for(stone).hot_bits().choose(&mut rng) else {
+            break;
+        };
+        for symmetry in Symmetry::ALL {
+            let mut transformed = board.transform(symmetry);
+            assert_eq!(
+                board.moves_for(stone).transform(symmetry),
+                transformed.moves_for(stone)
+            );
+            transformed.play(stone, pos.transform(symmetry));
+            let mut played = board;
+            played.play(stone, pos);
+            assert_eq!(played.transform(symmetry), transformed);
+            assert_eq!(played, transformed.transform(symmetry.inverse()));
+        }
+        board.play(stone, pos);
+        stone = stone.flip();
+    }
+}
+
+#[test]
+fn symmetries_compose() {
+    let board = Board::try_from((0x00_11_66_0c_3c_2c_00_00, 0x00_66_00_52_40_52_56_00)).unwrap();
+    for first in Symmetry::ALL {
+        assert_eq!(Symmetry::Identity, first.compose(first.inverse()));
+        assert_eq!(Symmetry::Identity, first.inverse().compose(first));
+        for second in Symmetry::ALL {
+            assert_eq!(
+                board.transform(first).transform(second),
+                board.transform(first.compose(second))
+            );
+        }
+    }
+}
+
+#[test]
+fn canonical_board_is_shared_by_symmetric_boards() {
+    let mut rng = rand::rng();
+    let mut board = Board::standard();
+    let mut stone = Stone::Black;
+    for _ in 0..30 {
+        let Some(pos) = board.moves_for(stone).hot_bits().choose(&mut rng) else {
+            break;
+        };
+        board.play(stone, pos);
+        stone = stone.flip();
+
+        let (canonical, _) = board.canonical();
+        let symmetric = [
+            board.cw(),
+            board.ccw(),
+            board.flip180(),
+            Board::try_from((
+                board.bits_for(Stone::Black).mirror_diagonal(),
+                board.bits_for(Stone::White).mirror_diagonal(),
+            ))
+            .unwrap(),
+        ];
+        for other in symmetric {
+            assert_eq!(canonical, other.canonical().0);
+        }
+    }
+}
+
 // Returns a board with only one legal move for black, that is, the following
 // move represented as a bitboard: 0x00_00_00_00_08_00_00_00.
 fn board_one_legal_move() -> Board {
@@ -77,3 +227,613 @@ fn stone_at_consistency() {
 
     assert!(success);
 }
+
+#[cfg(feature = "simd")]
+#[test]
+fn batched_moves_match_moves_for() {
+    let mut rng = rand::rng();
+    let mut boards = [Board::standard(); 8];
+    let mut stones = [Stone::Black; 8];
+    for _ in 0..60 {
+        let moves = Board::moves_for_x8(&boards, stones);
+        let low = Board::moves_for_x4(
+            &boards[..4].try_into().unwrap(),
+            stones[..4].try_into().unwrap(),
+        );
+        assert_eq!(moves[..4], low);
+        for lane in 0..8 {
+            assert_eq!(boards[lane].moves_for(stones[lane]), moves[lane]);
+            if let Some(pos) = moves[lane].hot_bits().choose(&mut rng) {
+                boards[lane].play(stones[lane], pos);
+            }
+            stones[lane] = stones[lane].flip();
+        }
+    }
+}
+
+#[cfg(feature = "bench")]
+#[test]
+fn raw_bench_api_matches_board() {
+    use magpie::othello::bench;
+
+    let mut rng = rand::rng();
+    let mut board = Board::standard();
+    let mut stone = Stone::Black;
+    for _ in 0..60 {
+        let own = board.bits_for(stone);
+        let opponent = board.bits_for(stone.flip());
+        let moves = board.moves_for(stone);
+        assert_eq!(moves.raw(), bench::moves(own.raw(), opponent.raw()));
+        for pos in moves.hot_bits() {
+            for kernel in Kernel::ALL {
+                let flips = bench::flips(kernel, own.raw(), opponent.raw(), pos.raw());
+                assert_eq!(board.flips_for(stone, pos).raw(), flips);
+            }
+        }
+        assert_eq!(
+            own.ccw().raw(),
+            bench::transform(Symmetry::RotateCcw, own.raw())
+        );
+        if let Some(pos) = moves.hot_bits().choose(&mut rng) {
+            board.play(stone, pos);
+        }
+        stone = stone.flip();
+    }
+}
+
+#[test]
+fn flips_for_matches_play() {
+    let mut rng = rand::rng();
+    for _ in 0..100 {
+        let mut board = Board::standard();
+        let mut stone = Stone::Black;
+        for _ in 0..60 {
+            for pos in board.empty_squares().hot_bits() {
+                let flips = board.flips_for(stone, pos);
+                assert_eq!(board.is_legal_move(stone, pos), !flips.is_empty());
+            }
+            let Some(pos) = board.moves_for(stone).hot_bits().choose(&mut rng) else {
+                stone = stone.flip();
+                continue;
+            };
+            let before = board;
+            let flips = before.flips_for(stone, pos);
+            board.play(stone, pos);
+            let placed = Bitboard::from(pos);
+            assert_eq!(
+                before.bits_for(stone) | flips | placed,
+                board.bits_for(stone)
+            );
+            assert_eq!(
+                before.bits_for(stone.flip()) ^ flips,
+                board.bits_for(stone.flip())
+            );
+            stone = stone.flip();
+        }
+    }
+}
+
+#[test]
+fn moves_with_flips_match_flips_for() {
+    let mut rng = rand::rng();
+    for _ in 0..100 {
+        let mut board = Board::standard();
+        let mut stone = Stone::Black;
+        for _ in 0..60 {
+            let moves: Vec<(Position, Bitboard)> = board.moves_with_flips(stone).collect();
+            let positions: Vec<Position> = board.moves_for(stone).hot_bits().collect();
+            assert_eq!(
+                positions,
+                moves.iter().map(|(pos, _)| *pos).collect::<Vec<_>>()
+            );
+            for (pos, flips) in &moves {
+                assert_eq!(board.flips_for(stone, *pos), *flips);
+            }
+            let Some((pos, flips)) = moves.into_iter().choose(&mut rng) else {
+                stone = stone.flip();
+                continue;
+            };
+            let mut expected = board;
+            expected.play(stone, pos);
+            board.play_with_flips(stone, pos, flips);
+            assert_eq!(expected, board);
+            stone = stone.flip();
+        }
+    }
+}
+
+#[test]
+fn unmake_restores_the_board() {
+    let mut rng = rand::rng();
+    for _ in 0..100 {
+        let mut board = Board::standard();
+        let mut stone = Stone::Black;
+        let mut history = vec![board];
+        let mut undos = Vec::new();
+        for _ in 0..60 {
+            let Some(pos) = board.moves_for(stone).hot_bits().choose(&mut rng) else {
+                stone = stone.flip();
+                continue;
+            };
+            let undo = board.make(stone, pos);
+            assert_eq!((stone, pos), (undo.stone(), undo.pos()));
+            assert_eq!(history.last().unwrap().flips_for(stone, pos), undo.flips());
+            history.push(board);
+            undos.push(undo);
+            stone = stone.flip();
+        }
+        while let Some(undo) = undos.pop() {
+            assert_eq!(history.pop().unwrap(), board);
+            board.unmake(undo);
+        }
+        assert_eq!(Board::standard(), board);
+    }
+}
+
+#[test]
+fn stable_discs_are_never_flipped() {
+    let mut rng = rand::rng();
+    for _ in 0..100 {
+        let mut board = Board::standard();
+        let mut stone = Stone::Black;
+        let mut stable = [Bitboard::from(0); 2];
+        let mut passes = 0;
+        while passes < 2 {
+            for (index, color) in [Stone::Black, Stone::White].into_iter().enumerate() {
+                let discs = board.stable_discs(color);
+                assert_eq!(discs, discs & board.bits_for(color));
+                // Stable discs must stay put, and can only ever grow
+                assert_eq!(stable[index], stable[index] & discs);
+                stable[index] = discs;
+            }
+            let Some(pos) = board.moves_for(stone).hot_bits().choose(&mut rng) else {
+                passes += 1;
+                stone = stone.flip();
+                continue;
+            };
+            passes = 0;
+            board.play(stone, pos);
+            stone = stone.flip();
+        }
+        // Every disc is stable on a full board
+        if board.empty_squares().is_empty() {
+            assert_eq!(board.bits_for(Stone::Black), stable[0]);
+            assert_eq!(board.bits_for(Stone::White), stable[1]);
+        }
+    }
+}
+
+#[test]
+fn frontier_and_potential_mobility_match_rays() {
+    let mut rng = rand::rng();
+    let adjacent = |pos: Position| {
+        Bitboard::from(u64::MAX)
+            .hot_bits()
+            .filter(|other| {
+                let ranks = pos.rank().abs_diff(other.rank());
+                let files = pos.file().abs_diff(other.file());
+                ranks.max(files) == 1
+            })
+            .fold(Bitboard::from(0), |acc, neighbour| acc | neighbour)
+    };
+    let mut board = Board::standard();
+    let mut stone = Stone::Black;
+    for _ in 0..60 {
+        for color in [Stone::Black, Stone::White] {
+            let frontier = board
+                .bits_for(color)
+                .hot_bits()
+                .filter(|pos| !(adjacent(*pos) & board.empty_squares()).is_empty())
+                .fold(Bitboard::from(0), |acc, pos| acc | pos);
+            assert_eq!(frontier, board.frontier(color));
+
+            let potential = board
+                .empty_squares()
+                .hot_bits()
+                .filter(|pos| !(adjacent(*pos) & board.bits_for(color.flip())).is_empty())
+                .count();
+            assert_eq!(potential, usize::from(board.potential_mobility(color)));
+        }
+        let Some(pos) = board.moves_for(stone).hot_bits().choose(&mut rng) else {
+            break;
+        };
+        board.play(stone, pos);
+        stone = stone.flip();
+    }
+}
+
+#[test]
+fn empty_regions_partition_empty_squares() {
+    let mut rng = rand::rng();
+    let grow = |region: Bitboard| {
+        region
+            .hot_bits()
+            .flat_map(|pos| {
+                Direction::ALL
+                    .into_iter()
+                    .filter_map(move |direction| nearest(pos, direction))
+            })
+            .fold(region, |acc, pos| acc | pos)
+    };
+    for _ in 0..20 {
+        let mut board = Board::standard();
+        let mut stone = Stone::Black;
+        for _ in 0..60 {
+            let regions: Vec<Bitboard> = board.empty_regions().collect();
+            let mut union = Bitboard::from(0);
+            for region in &regions {
+                assert!(!region.is_empty());
+                assert!((union & region).is_empty());
+                union |= region;
+                // No region touches an empty square outside of itself
+                assert_eq!(*region, grow(*region) & board.empty_squares());
+            }
+            assert_eq!(board.empty_squares(), union);
+
+            let odd = regions
+                .iter()
+                .filter(|region| region.count_set() % 2 == 1)
+                .fold(Bitboard::from(0), |acc, region| acc | region);
+            assert_eq!(odd, board.odd_regions());
+
+            let Some(pos) = board.moves_for(stone).hot_bits().choose(&mut rng) else {
+                break;
+            };
+            board.play(stone, pos);
+            stone = stone.flip();
+        }
+    }
+}
+
+// Returns the square next to the position in the specified direction.
+fn nearest(pos: Position, direction: Direction) -> Option<Position> {
+    let ray = Bitboard::ray(pos, direction);
+    match direction {
+        Direction::North | Direction::NorthEast | Direction::West | Direction::NorthWest => {
+            ray.hot_bits().last()
+        }
+        _ => ray.hot_bits().next(),
+    }
+}
+
+#[test]
+fn sized_board_matches_board() {
+    let mut rng = rand::rng();
+    // SizedBoard counts squares from the least significant bit
+    let widen = |bits: Bitboard| u128::from(bits.raw().reverse_bits());
+    for _ in 0..20 {
+        let mut board = Board::standard();
+        let mut sized = SizedBoard::<8>::standard();
+        let mut stone = Stone::Black;
+        for _ in 0..60 {
+            for color in [Stone::Black, Stone::White] {
+                assert_eq!(widen(board.bits_for(color)), sized.bits_for(color));
+                assert_eq!(widen(board.moves_for(color)), sized.moves_for(color));
+            }
+            let Some(pos) = board.moves_for(stone).hot_bits().choose(&mut rng) else {
+                break;
+            };
+            let square = pos.raw().leading_zeros() as usize;
+            assert_eq!(
+                widen(board.flips_for(stone, pos)),
+                sized.flips_for(stone, square)
+            );
+            board.play(stone, pos);
+            sized.play(stone, square).unwrap();
+            stone = stone.flip();
+        }
+    }
+}
+
+fn sized_moves_match_flips<const N: usize>() {
+    let mut rng = rand::rng();
+    for _ in 0..20 {
+        let mut board = SizedBoard::<N>::standard();
+        let mut stone = Stone::Black;
+        loop {
+            let moves = board.moves_for(stone);
+            for square in 0..SizedBoard::<N>::SQUARES {
+                let empty = board.empty_squares() & (1 << square) != 0;
+                let flips = board.flips_for(stone, square);
+                assert_eq!(empty && flips != 0, moves & (1 << square) != 0);
+            }
+            let Some(square) = (0..SizedBoard::<N>::SQUARES)
+                .filter(|square| moves & (1 << square) != 0)
+                .choose(&mut rng)
+            else {
+                break;
+            };
+            let (black, white) = board.count_stones();
+            board.play(stone, square).unwrap();
+            let (new_black, new_white) = board.count_stones();
+            assert_eq!(black + white + 1, new_black + new_white);
+            stone = stone.flip();
+        }
+    }
+}
+
+#[test]
+fn sized_boards_generate_moves_consistently() {
+    sized_moves_match_flips::<4>();
+    sized_moves_match_flips::<6>();
+    sized_moves_match_flips::<10>();
+}
+
+fn sized_rejects_squares_off_the_board<const N: usize>() {
+    let board = SizedBoard::<N>::standard();
+    for square in [SizedBoard::<N>::SQUARES, 127, 128, usize::MAX] {
+        let mut next = board;
+        assert_eq!(
+            Err(OthelloError::IllegalMove),
+            next.play(Stone::Black, square)
+        );
+        assert_eq!(board, next);
+        assert_eq!(None, board.stone_at(square));
+        assert_eq!(0, board.flips_for(Stone::Black, square));
+        assert!(!board.is_legal_move(Stone::Black, square));
+    }
+}
+
+#[test]
+fn sized_boards_reject_squares_off_the_board() {
+    sized_rejects_squares_off_the_board::<4>();
+    sized_rejects_squares_off_the_board::<6>();
+    sized_rejects_squares_off_the_board::<8>();
+    sized_rejects_squares_off_the_board::<10>();
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "overlap")]
+fn playing_on_occupied_squares_is_caught() {
+    let mut board = Board::standard();
+    // D4 already holds a white stone
+    board.play(Stone::Black, "d4".parse().unwrap());
+}
+
+#[test]
+fn planes_match_stones() {
+    let mut rng = rand::rng();
+    let mut board = Board::standard();
+    let mut stone = Stone::Black;
+    for _ in 0..60 {
+        let planes = board.to_planes(stone);
+        for pos in Bitboard::from(u64::MAX).hot_bits() {
+            let (rank, file) = (usize::from(pos.rank()), usize::from(pos.file()));
+            let expected = match board.stone_at(pos) {
+                Some(owner) if owner == stone => [1.0, 0.0],
+                Some(_) => [0.0, 1.0],
+                None => [0.0, 0.0],
+            };
+            assert_eq!(expected, [planes[0][rank][file], planes[1][rank][file]]);
+        }
+
+        let variants = board.symmetric_planes(stone);
+        let (canonical, _) = board.canonical();
+        let canonical = canonical.to_planes(stone);
+        assert!(variants.contains(&canonical));
+        for variant in variants {
+            let count = |plane: &[[f32; 8]; 8]| plane.iter().flatten().sum::<f32>();
+            assert_eq!(count(&planes[0]), count(&variant[0]));
+            assert_eq!(count(&planes[1]), count(&variant[1]));
+        }
+
+        let Some(pos) = board.moves_for(stone).hot_bits().choose(&mut rng) else {
+            break;
+        };
+        board.play(stone, pos);
+        stone = stone.flip();
+    }
+}
+
+#[test]
+fn position_str_roundtrip() {
+    let mut rng = rand::rng();
+    let mut board = Board::standard();
+    let mut stone = Stone::Black;
+    for _ in 0..60 {
+        let text = board.position_str(stone);
+        assert_eq!(66, text.len());
+        assert_eq!(Ok((board, stone)), Board::from_position_str(&text));
+        assert_eq!(
+            Ok((board, stone)),
+            Board::from_position_str(&text.to_lowercase().replace('-', "."))
+        );
+
+        let Some(pos) = board.moves_for(stone).hot_bits().choose(&mut rng) else {
+            break;
+        };
+        board.play(stone, pos);
+        stone = stone.flip();
+    }
+}
+
+#[test]
+fn position_str_rejects_invalid_text() {
+    let squares = "-".repeat(64);
+    assert_eq!(
+        Err(PositionStrError::InvalidLength),
+        Board::from_position_str(&squares)
+    );
+    assert_eq!(
+        Err(PositionStrError::InvalidLength),
+        Board::from_position_str(&format!("{squares} X X"))
+    );
+    assert_eq!(
+        Err(PositionStrError::InvalidSideToMove),
+        Board::from_position_str(&format!("{squares} -"))
+    );
+    assert_eq!(
+        Err(PositionStrError::InvalidSquare(3)),
+        Board::from_position_str(&format!("---#{} X", &squares[4..]))
+    );
+}
+
+#[test]
+fn played_boards_are_reachable() {
+    let mut rng = rand::rng();
+    for _ in 0..50 {
+        let mut board = Board::standard();
+        let mut stone = Stone::Black;
+        for _ in 0..60 {
+            assert!(board.is_reachable_with(stone));
+            // No player can be forced to pass before the 8th move
+            if board.stage() < 8 {
+                assert!(!board.is_reachable_with(stone.flip()));
+            }
+            let Some(pos) = board.moves_for(stone).hot_bits().choose(&mut rng) else {
+                if board.moves_for(stone.flip()).is_empty() {
+                    break;
+                }
+                stone = stone.flip();
+                continue;
+            };
+            board.play(stone, pos);
+            stone = stone.flip();
+        }
+    }
+}
+
+#[test]
+fn tampered_boards_are_unreachable() {
+    let [a1, e4, f5, f6] = ["a1", "e4", "f5", "f6"].map(|pos| Position::try_from(pos).unwrap());
+    let mut board = Board::standard();
+    board.play(Stone::Black, f5);
+    board.play(Stone::White, f6);
+    assert!(board.is_reachable_with(Stone::Black));
+
+    // Recoloring a stone early on cannot be explained by any sequence of moves
+    let mut recolored = board;
+    let e4_bits = Bitboard::from(e4);
+    let owner = recolored.stone_at(e4).unwrap();
+    recolored.remove_stone_unchecked(owner, e4_bits);
+    recolored.place_stone_unchecked(owner.flip(), e4_bits);
+    assert!(!recolored.is_reachable());
+
+    let mut isolated = board;
+    isolated.place_stone_unchecked(Stone::Black, Bitboard::from(a1));
+    assert!(!isolated.is_reachable());
+
+    let mut overlapping = board;
+    overlapping.place_stone_unchecked(Stone::White, Bitboard::from(f5));
+    assert!(!overlapping.is_reachable());
+
+    let mut centerless = board;
+    centerless.remove_stone_unchecked(owner, e4_bits);
+    assert!(!centerless.is_reachable());
+}
+
+#[test]
+fn played_moves_are_inferred() {
+    let mut rng = rand::rng();
+    for _ in 0..50 {
+        let mut board = Board::standard();
+        let mut stone = Stone::Black;
+        for _ in 0..60 {
+            let Some(pos) = board.moves_for(stone).hot_bits().choose(&mut rng) else {
+                if board.moves_for(stone.flip()).is_empty() {
+                    break;
+                }
+                stone = stone.flip();
+                continue;
+            };
+            let mut next = board;
+            next.play(stone, pos);
+            assert_eq!(Some((stone, pos)), board.infer_move(&next));
+            assert_eq!(None, next.infer_move(&board));
+
+            let diff = board.diff(&next);
+            assert_eq!(
+                board.flips_for(stone, pos),
+                diff.changed() & !Bitboard::from(pos)
+            );
+            board = next;
+            stone = stone.flip();
+        }
+    }
+}
+
+#[test]
+fn illegal_transitions_are_rejected() {
+    let [c4, d3, f5] = ["c4", "d3", "f5"].map(|pos| Position::try_from(pos).unwrap());
+    let board = Board::standard();
+    assert_eq!(None, board.infer_move(&board));
+
+    // Two moves at once
+    let mut next = board;
+    next.play(Stone::Black, f5);
+    next.play(Stone::Black, c4);
+    assert_eq!(None, board.infer_move(&next));
+
+    // A stone placed where it flips nothing
+    let mut next = board;
+    next.place_stone_unchecked(Stone::Black, d3.into());
+    assert_eq!(None, board.infer_move(&next));
+
+    // A move with extra flips
+    let mut next = board;
+    next.play(Stone::Black, f5);
+    let extra = next.bits_for(Stone::White);
+    next.remove_stone_unchecked(Stone::White, extra);
+    next.place_stone_unchecked(Stone::Black, extra);
+    assert_eq!(None, board.infer_move(&next));
+    assert_eq!(2, board.diff(&next).flipped_to_black.count_set());
+}
+
+#[test]
+fn square_arrays_round_trip() {
+    let mut rng = rand::rng();
+    let mut board = Board::standard();
+    let mut stone = Stone::Black;
+    for _ in 0..60 {
+        let squares: [[Square; 8]; 8] = board.into();
+        assert_eq!(board, Board::from(squares));
+        for pos in Bitboard::from(u64::MAX).hot_bits() {
+            let square = squares[pos.rank() as usize][pos.file() as usize];
+            assert_eq!(square, board[pos]);
+            assert_eq!(square.stone(), board.get(pos));
+        }
+        if let Some(pos) = board.moves_for(stone).hot_bits().choose(&mut rng) {
+            board.play(stone, pos);
+        }
+        stone = stone.flip();
+    }
+}
+
+#[test]
+fn squares_are_visited_rank_by_rank() {
+    let mut board = Board::standard();
+    board.play(Stone::Black, Position::F5);
+    let squares: Vec<_> = board.squares().collect();
+    assert_eq!(64, squares.len());
+    for (index, (pos, square)) in squares.into_iter().enumerate() {
+        assert_eq!(Position::from_index(index as u8).unwrap(), pos);
+        assert_eq!(board[pos], square);
+    }
+}
+
+#[cfg(feature = "board128")]
+#[test]
+fn board128_matches_board() {
+    use magpie::othello::Board128;
+
+    let mut rng = rand::rng();
+    let mut board = Board::standard();
+    let mut packed = Board128::standard();
+    let mut stone = Stone::Black;
+    for _ in 0..60 {
+        assert_eq!(board, Board::from(packed));
+        assert_eq!(board.count_stones(), packed.count_stones());
+        assert_eq!(board.empty_squares(), packed.empty_squares());
+        let moves = board.moves_for(stone);
+        assert_eq!(moves, packed.moves_for(stone));
+        for pos in moves.hot_bits() {
+            assert_eq!(board.flips_for(stone, pos), packed.flips_for(stone, pos));
+        }
+        if let Some(pos) = moves.hot_bits().choose(&mut rng) {
+            board.play(stone, pos);
+            packed.play(stone, pos);
+        }
+        stone = stone.flip();
+    }
+}
@@ -1,4 +1,4 @@
-use magpie::othello::{Board, Stone};
+use magpie::othello::{Board, Move, Stone, perft, perft_divide};
 
 macro_rules! perft_test {
     ($($depth:literal)*) => {
@@ -27,7 +27,7 @@ perft_test!(ignore 10 11 12 13 14);
 
 fn test_perft(depth: u8) {
     let target = perft_key(depth);
-    let actual = perft(&Board::standard(), Stone::Black, false, depth);
+    let actual = perft(&Board::standard(), Stone::Black, depth);
     assert_eq!(target, actual);
 }
 
@@ -53,28 +53,35 @@ fn perft_key(depth: u8) -> u64 {
     }
 }
 
-fn perft(board: &Board, stone: Stone, passed: bool, depth: u8) -> u64 {
-    if depth == 0 {
-        return 1;
+#[test]
+fn perft_divide_sums_to_perft() {
+    let mut board = Board::standard();
+    board.play(Stone::Black, "f5".parse().unwrap());
+    for depth in 1..=6 {
+        let divide = perft_divide(&board, Stone::White, depth);
+        let total: u64 = divide.iter().map(|(_, count)| count).sum();
+        assert_eq!(perft(&board, Stone::White, depth), total);
     }
+}
 
-    let moves = board.moves_for(stone);
-    if moves == 0 {
-        if passed {
-            1
-        } else {
-            perft(board, stone.flip(), true, depth - 1)
-        }
-    } else if depth == 1 {
-        moves.count_set().into()
-    } else {
-        moves
-            .hot_bits()
-            .map(|pos| {
-                let mut new_board = board.clone();
-                new_board.play(stone, pos);
-                perft(&new_board, stone.flip(), false, depth - 1)
-            })
-            .sum()
+#[test]
+fn perft_divide_reports_passes() {
+    // Black has no moves but white does
+    let board = Board::try_from((0x40 << 56, 0x80 << 56)).unwrap();
+    assert!(board.moves_for(Stone::Black).is_empty());
+    let divide = perft_divide(&board, Stone::Black, 2);
+    assert_eq!(Move::Pass, divide[0].0);
+    assert_eq!(perft(&board, Stone::Black, 2), divide[0].1);
+}
+
+#[test]
+fn perft_divide_counts_concluded_games() {
+    // Neither player has a move
+    let board = Board::try_from((u64::MAX << 1, 1)).unwrap();
+    for depth in 1..=3 {
+        let divide = perft_divide(&board, Stone::Black, depth);
+        assert_eq!(vec![(Move::Pass, 1)], divide);
+        let total: u64 = divide.iter().map(|(_, count)| count).sum();
+        assert_eq!(perft(&board, Stone::Black, depth), total);
     }
 }
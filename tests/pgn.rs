@@ -0,0 +1,111 @@
+use indoc::indoc;
+use magpie::{
+    othello::{Game, Move, Status, Transcript},
+    pgn::{self, Line, PgnError, PgnGame, PgnMove},
+};
+use rand::seq::IteratorRandom;
+use std::time::Duration;
+
+#[test]
+fn pgn_random_annotated_games_roundtrip() {
+    let mut rng = rand::rng();
+    for _ in 0..50 {
+        let mut game = Game::new();
+        let mut transcript = Transcript::new();
+        while game.status() == Status::Progressing {
+            if game.pass_if_forced() {
+                continue;
+            }
+            let pos = game.moves().hot_bits().choose(&mut rng).unwrap();
+            game.play(pos).unwrap();
+            transcript.push(Move::Play(pos));
+        }
+
+        let mut pgn = PgnGame::from_transcript(&transcript).unwrap();
+        pgn.set_tag("Black", "Alice \"the Great\"");
+        pgn.set_tag("Result", "*");
+        pgn.line_mut().comment = Some("A random game".to_string());
+        for (index, mv) in pgn.line_mut().moves.iter_mut().enumerate() {
+            if index % 3 == 0 {
+                mv.eval = Some(index as f32 / 4.0 - 3.0);
+                mv.time = Some(Duration::from_millis(index as u64 * 1250));
+            }
+            if index % 7 == 0 {
+                mv.nags.push(index as u8 % 6 + 1);
+                mv.comment = Some(format!("Move {index}"));
+            }
+        }
+        let alternative = pgn.moves()[4].mv;
+        pgn.line_mut().moves[3].variations.push(Line {
+            comment: Some("Instead".to_string()),
+            moves: vec![PgnMove::new(alternative)],
+        });
+
+        let text = pgn.to_string();
+        assert!(text.lines().all(|line| line.len() <= 80));
+        let parsed = pgn::parse_game(&text).unwrap();
+        assert_eq!(pgn, parsed);
+        assert_eq!(game.board(), parsed.replay().unwrap().board());
+    }
+}
+
+#[test]
+fn pgn_parses_hand_written_game() {
+    let text = indoc! {r#"
+        [Event "Club championship"]
+        [Black "Alice"]
+        [White "Bob"]
+
+        {Alice opens} 1.f5 d6 {[%eval -0.5] [%emt 1:02.5] The Tiger; or is it?}
+        2. c3!
+        ( 2. c5?! ; Too passive
+          2... f4 ( 2... d3 $10 ) )
+        2... d3 {[%csl Gf5]} 36-28
+    "#};
+    let game = pgn::parse_game(text).unwrap();
+    assert_eq!(Some("Club championship"), game.event());
+    assert_eq!(Some((36, 28)), game.result());
+    assert_eq!(Some("Alice opens"), game.line().comment.as_deref());
+    assert_eq!("f5d6c3d3", game.transcript().to_string());
+
+    let d6 = &game.moves()[1];
+    assert_eq!(Some(-0.5), d6.eval);
+    assert_eq!(Some(Duration::from_millis(62_500)), d6.time);
+    assert_eq!(Some("The Tiger; or is it?"), d6.comment.as_deref());
+
+    let c3 = &game.moves()[2];
+    assert_eq!(vec![1], c3.nags);
+    let variation = &c3.variations[0];
+    assert_eq!(vec![6], variation.moves[0].nags);
+    assert_eq!(Some("Too passive"), variation.moves[0].comment.as_deref());
+    assert_eq!(vec![10], variation.moves[1].variations[0].moves[0].nags);
+
+    // Unknown commands are kept as part of the comment
+    assert_eq!(Some("[%csl Gf5]"), game.moves()[3].comment.as_deref());
+}
+
+#[test]
+fn pgn_parses_several_games() {
+    // The second game lacks a termination marker
+    let text = "1. f5 *\n[Black \"Alice\"]\n1. e6 d6\n\n[Black \"Carol\"]\n1. c4 c3 32-32";
+    let games = pgn::parse_games(text).unwrap();
+    assert_eq!(3, games.len());
+    assert_eq!(Some("Alice"), games[1].black_player());
+    assert_eq!(Some((32, 32)), games[2].result());
+}
+
+#[test]
+fn pgn_rejects_malformed_games() {
+    assert_eq!(Err(PgnError::Syntax), pgn::parse_game("1. f5 (d6 *"));
+    assert_eq!(Err(PgnError::Syntax), pgn::parse_game("1. f5 {d6 *"));
+    assert_eq!(Err(PgnError::Syntax), pgn::parse_game("(1. f5) *"));
+    assert_eq!(Err(PgnError::Syntax), pgn::parse_game("[Black Alice] *"));
+    assert_eq!(
+        Err(PgnError::InvalidMove(9)),
+        pgn::parse_game("1. f5 2. z9 *")
+    );
+    assert_eq!(
+        Err(PgnError::IllegalMove(1)),
+        pgn::parse_game("1. f5 f5 *").unwrap().replay().map(|_| ())
+    );
+}
@@ -0,0 +1,46 @@
+#![cfg(feature = "proptest")]
+
+use magpie::othello::{Bitboard, Board, Position, Stone, Symmetry};
+use proptest::prelude::*;
+
+proptest! {
+    #[test]
+    fn generated_boards_are_reachable(board in any::<Board>()) {
+        prop_assert!(board.is_valid());
+        prop_assert!(board.is_reachable());
+    }
+
+    #[test]
+    fn legal_moves_flip_stones(board in any::<Board>(), stone in any::<Stone>()) {
+        let (black, white) = (board.bits_for(Stone::Black), board.bits_for(Stone::White));
+        for pos in board.moves_for(stone).hot_bits() {
+            let flips = board.flips_for(stone, pos);
+            prop_assert!(!flips.is_empty());
+            prop_assert_eq!(flips, flips & board.bits_for(stone.flip()));
+
+            let mut next = board;
+            next.play(stone, pos);
+            let placed = black.count_set() + white.count_set() + 1;
+            let count = next.bits_for(Stone::Black).count_set()
+                + next.bits_for(Stone::White).count_set();
+            prop_assert_eq!(placed, count);
+        }
+    }
+
+    #[test]
+    fn moves_commute_with_symmetries(board in any::<Board>(), stone in any::<Stone>()) {
+        for symmetry in Symmetry::ALL {
+            prop_assert_eq!(
+                board.moves_for(stone).transform(symmetry),
+                board.transform(symmetry).moves_for(stone)
+            );
+        }
+    }
+
+    #[test]
+    fn positions_are_single_squares(pos in any::<Position>(), bits in any::<Bitboard>()) {
+        let square = Bitboard::from(pos);
+        prop_assert_eq!(1, square.count_set());
+        prop_assert_eq!(bits.contains(pos), !(bits & square).is_empty());
+    }
+}
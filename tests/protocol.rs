@@ -0,0 +1,371 @@
+use magpie::{
+    eval::DiscDifference,
+    ggf::GgfGame,
+    othello::{Position, Stone, Transcript},
+    protocol::{
+        Engine, EngineConfig,
+        nboard::NboardServer,
+        uoi::{OptionValue, UoiOption, UoiServer},
+    },
+    search::{ParallelSearcher, Searcher},
+};
+use std::{
+    io::Cursor,
+    thread,
+    time::{Duration, Instant},
+};
+
+fn run(server: &mut NboardServer<Searcher<DiscDifference>>, input: &str) -> Vec<String> {
+    let mut output = Vec::new();
+    server.run(input.as_bytes(), &mut output).unwrap();
+    String::from_utf8(output)
+        .unwrap()
+        .lines()
+        .map(String::from)
+        .collect()
+}
+
+#[test]
+fn nboard_plays_legal_moves() {
+    let mut server = NboardServer::new(Searcher::new(DiscDifference)).with_depth(3);
+    let transcript: Transcript = "f5d6c3".parse().unwrap();
+    let game = GgfGame::from_transcript(&transcript).unwrap();
+
+    let input = format!("nboard 2\nset depth 4\nset game {game}\nping 7\ngo\n");
+    let output = run(&mut server, &input);
+    assert_eq!(4, server.depth());
+    assert_eq!(57, server.game().empty_squares().count_set());
+    assert_eq!(Stone::White, server.game().current_turn());
+    assert_eq!("set myname magpie", output[0]);
+    assert_eq!("pong 7", output[1]);
+
+    let response = output.last().unwrap().strip_prefix("=== ").unwrap();
+    let notation = response.split('/').next().unwrap();
+    let pos = Position::try_from(notation).unwrap();
+    assert!(server.game().is_legal_move(pos));
+
+    run(&mut server, &format!("move {notation}/1.00/0.5\n"));
+    assert_eq!(Stone::Black, server.game().current_turn());
+}
+
+#[test]
+fn nboard_ranks_hints() {
+    let mut server = NboardServer::new(Searcher::new(DiscDifference)).with_depth(2);
+    let output = run(&mut server, "hint 3\n");
+    let hints: Vec<&String> = output
+        .iter()
+        .filter(|line| line.starts_with("search"))
+        .collect();
+    assert_eq!(3, hints.len());
+
+    let scores: Vec<f32> = hints
+        .iter()
+        .map(|line| line.split_whitespace().nth(2).unwrap().parse().unwrap())
+        .collect();
+    assert!(scores.windows(2).all(|pair| pair[0] >= pair[1]));
+    assert_eq!(Some(&String::from("status")), output.last());
+}
+
+#[test]
+fn nboard_reports_invalid_commands() {
+    let mut server = NboardServer::new(Searcher::new(DiscDifference));
+    let output = run(
+        &mut server,
+        "move A1\nset game garbage\nunknown command\nquit\nping 1\n",
+    );
+    assert_eq!(2, output.len());
+    assert!(output.iter().all(|line| line.starts_with("status")));
+    assert_eq!(60, server.game().empty_squares().count_set());
+}
+
+fn uoi<E: Engine + Send + 'static>(server: &mut UoiServer<E>, input: &str) -> Vec<String> {
+    let mut output = Vec::new();
+    server
+        .run(Cursor::new(input.to_string()), &mut output)
+        .unwrap();
+    String::from_utf8(output)
+        .unwrap()
+        .lines()
+        .map(String::from)
+        .collect()
+}
+
+fn best_move(output: &[String]) -> &str {
+    output
+        .iter()
+        .find_map(|line| line.strip_prefix("bestmove "))
+        .unwrap()
+}
+
+#[test]
+fn uoi_searches_to_depth() {
+    let mut server = UoiServer::new(Searcher::new(DiscDifference));
+    let output = uoi(
+        &mut server,
+        "uoi\nisready\nposition startpos moves f5 d6 c3\ngo depth 4\n",
+    );
+    assert_eq!("id name magpie", output[0]);
+    assert_eq!(
+        "option name Depth type spin default 8 min 1 max 60",
+        output[1]
+    );
+    assert_eq!(["uoiok", "readyok"], output[2..4]);
+
+    let depths: Vec<&str> = output
+        .iter()
+        .filter_map(|line| line.strip_prefix("info depth "))
+        .map(|info| info.split_whitespace().next().unwrap())
+        .collect();
+    assert_eq!(vec!["1", "2", "3", "4"], depths);
+    let pos = Position::try_from(best_move(&output)).unwrap();
+    assert!(server.game().is_legal_move(pos));
+    assert_eq!(Stone::White, server.game().current_turn());
+}
+
+#[test]
+fn uoi_stops_infinite_searches() {
+    let mut server = UoiServer::new(Searcher::new(DiscDifference));
+    let mut output = Vec::new();
+    server.handle("go infinite", &mut output).unwrap();
+    assert!(server.is_searching());
+    assert!(server.engine().is_none());
+
+    thread::sleep(Duration::from_millis(50));
+    server.poll(&mut output).unwrap();
+    server.handle("isready", &mut output).unwrap();
+    let text = String::from_utf8(output.clone()).unwrap();
+    assert!(!text.contains("bestmove"));
+    assert!(text.ends_with("readyok\n"));
+
+    server.handle("stop", &mut output).unwrap();
+    assert!(!server.is_searching());
+    let output: Vec<String> = String::from_utf8(output)
+        .unwrap()
+        .lines()
+        .map(String::from)
+        .collect();
+    let pos = Position::try_from(best_move(&output)).unwrap();
+    assert!(server.game().is_legal_move(pos));
+}
+
+#[test]
+fn uoi_searches_within_time() {
+    let mut server = UoiServer::new(Searcher::new(DiscDifference));
+    let start = Instant::now();
+    let output = uoi(&mut server, "go movetime 50\n");
+    assert!(start.elapsed() < Duration::from_secs(5));
+    assert!(Position::try_from(best_move(&output)).is_ok());
+
+    let output = uoi(&mut server, "go btime 1000 wtime 1000 binc 10\n");
+    assert!(Position::try_from(best_move(&output)).is_ok());
+}
+
+#[test]
+fn uoi_sets_positions_and_options() {
+    let mut server = UoiServer::new(Searcher::new(DiscDifference)).with_option(
+        UoiOption::combo("Style", "Normal", &["Normal", "Aggressive"]),
+        |_, _| {},
+    );
+    let board = "-".repeat(27) + "OX------XO" + &"-".repeat(27);
+    let input = format!(
+        "position board {board} white moves e3\n\
+         setoption name Depth value 3\n\
+         setoption name style value aggressive\n\
+         setoption name Style value Passive\n\
+         setoption name Threads value 2\n\
+         position startpos moves f5 f5\n\
+         position nowhere\n\
+         go\n"
+    );
+    let output = uoi(&mut server, &input);
+    assert_eq!(Stone::Black, server.game().current_turn());
+    assert_eq!(59, server.game().empty_squares().count_set());
+    assert_eq!(Some(&OptionValue::Spin(3)), server.options().value("depth"));
+    assert_eq!(
+        Some(&OptionValue::String(String::from("Aggressive"))),
+        server.options().value("Style")
+    );
+
+    let errors: Vec<&String> = output
+        .iter()
+        .filter(|line| line.starts_with("info string"))
+        .collect();
+    assert_eq!(4, errors.len());
+    assert!(output.iter().any(|line| line.starts_with("info depth 3 ")));
+    assert!(!output.iter().any(|line| line.starts_with("info depth 4 ")));
+}
+
+#[test]
+fn uoi_reports_passes() {
+    let mut server = UoiServer::new(Searcher::new(DiscDifference));
+    // Black has no moves, while white can capture A2 from A1
+    let board = format!("O-------X{}OO------OO{}", "-".repeat(18), "-".repeat(27));
+    let output = uoi(&mut server, &format!("position board {board} black\ngo\n"));
+    assert_eq!(vec!["bestmove pass"], output);
+
+    let output = uoi(
+        &mut server,
+        &format!("position board {board} black moves pass a3\ngo\n"),
+    );
+    assert_eq!(vec!["bestmove none"], output);
+}
+
+#[cfg(feature = "config")]
+#[test]
+fn configs_load_from_json_and_toml() {
+    use magpie::protocol::ConfigError;
+    use std::fs;
+
+    let dir = std::env::temp_dir().join(format!("magpie-config-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let weights = dir.join("pattern.weights");
+    fs::write(&weights, b"weights").unwrap();
+
+    let toml = dir.join("engine.toml");
+    let text = format!(
+        "hash_mb = 1_024\nthreads = 8\neval_weights = '{}'\n",
+        weights.display()
+    );
+    fs::write(&toml, text).unwrap();
+    let config = EngineConfig::load(&toml).unwrap();
+    assert_eq!(1024, config.hash_mb);
+    assert_eq!(8, config.threads);
+    assert_eq!(Some(weights.clone()), config.eval_weights);
+
+    let json = dir.join("engine.json");
+    fs::write(
+        &json,
+        r#"{"book": true, "depth": 12, "eval_weights": "missing"}"#,
+    )
+    .unwrap();
+    assert!(matches!(
+        EngineConfig::load(&json),
+        Err(ConfigError::MissingWeights(_))
+    ));
+    let config = EngineConfig::from_json(&fs::read_to_string(&json).unwrap()).unwrap();
+    assert!(config.book);
+    assert_eq!(12, config.depth);
+
+    assert!(matches!(
+        EngineConfig::from_toml("threads = 2\n[search]\n"),
+        Err(ConfigError::Toml(_))
+    ));
+    assert!(matches!(
+        EngineConfig::from_toml("book = 1"),
+        Err(ConfigError::Toml(_))
+    ));
+    assert!(matches!(
+        EngineConfig::from_toml("threads = "),
+        Err(ConfigError::Toml(_))
+    ));
+    assert!(matches!(
+        EngineConfig::from_json(r#"{"ponder": true}"#),
+        Err(ConfigError::Json(_))
+    ));
+    assert!(matches!(
+        EngineConfig::load(dir.join("missing.toml")),
+        Err(ConfigError::Io(_))
+    ));
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn uoi_applies_config_options() {
+    let config = EngineConfig {
+        depth: 2,
+        ..EngineConfig::default()
+    };
+    let mut server = UoiServer::new(config.searcher(DiscDifference)).with_config(
+        config,
+        |searcher: &mut ParallelSearcher<DiscDifference>, config| {
+            *searcher = config.searcher(DiscDifference);
+        },
+    );
+    let output = uoi(
+        &mut server,
+        "uoi\n\
+         setoption name Threads value 3\n\
+         setoption name EvalWeights value missing.weights\n\
+         setoption name OwnBook value true\n\
+         go\n",
+    );
+    assert!(output.contains(&String::from(
+        "option name EvalWeights type string default <empty>"
+    )));
+    assert_eq!(
+        Some(&OptionValue::Spin(3)),
+        server.options().value("threads")
+    );
+    assert_eq!(
+        Some(&OptionValue::String(String::from("<empty>"))),
+        server.options().value("EvalWeights")
+    );
+    assert_eq!(3, server.engine().unwrap().threads());
+    let errors = output
+        .iter()
+        .filter(|line| line.starts_with("info string"))
+        .count();
+    assert_eq!(1, errors);
+    assert!(output.iter().any(|line| line.starts_with("info depth 2 ")));
+    assert!(!output.iter().any(|line| line.starts_with("info depth 3 ")));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn configs_roundtrip_through_serde() -> serde_json::Result<()> {
+    let config = EngineConfig {
+        threads: 4,
+        eval_weights: Some("pattern.weights".into()),
+        ..EngineConfig::default()
+    };
+    let json = serde_json::to_string(&config)?;
+    assert_eq!(config, serde_json::from_str(&json)?);
+    assert_eq!(
+        EngineConfig::default(),
+        serde_json::from_str::<EngineConfig>("{}")?
+    );
+    Ok(())
+}
+
+#[test]
+fn uoi_deterministic_searches_repeat() {
+    let search = || {
+        let config = EngineConfig {
+            threads: 4,
+            deterministic: true,
+            ..EngineConfig::default()
+        };
+        assert_eq!(1, config.search_threads());
+        let mut server = UoiServer::new(config.searcher(DiscDifference)).with_config(
+            config,
+            |searcher: &mut ParallelSearcher<DiscDifference>, config| {
+                *searcher = config.searcher(DiscDifference);
+            },
+        );
+        let mut output = uoi(
+            &mut server,
+            "position startpos moves f5 d6 c3\ngo movetime 20\n",
+        );
+        output.extend(uoi(&mut server, "go nodes 3000\n"));
+        // Everything but the time spent has to repeat
+        output
+            .iter()
+            .map(|line| {
+                let mut tokens: Vec<&str> = line.split_whitespace().collect();
+                if let Some(index) = tokens.iter().position(|token| *token == "time") {
+                    tokens.drain(index..=index + 1);
+                }
+                tokens.join(" ")
+            })
+            .collect::<Vec<String>>()
+    };
+    let output = search();
+    assert_eq!(
+        2,
+        output
+            .iter()
+            .filter(|line| line.starts_with("bestmove"))
+            .count()
+    );
+    assert_eq!(output, search());
+}
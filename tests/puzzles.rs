@@ -0,0 +1,91 @@
+use magpie::{
+    eval::{DiscDifference, Heuristic},
+    othello::Game,
+    puzzles::PuzzleFinder,
+    search::{SearchLimits, Searcher, solve_endgame},
+};
+use rand::{SeedableRng, rngs::StdRng};
+
+mod common;
+
+use common::random_game;
+
+#[test]
+fn puzzles_have_a_clear_solution() {
+    let mut rng = StdRng::seed_from_u64(2);
+    let mut finder = PuzzleFinder::new(Searcher::new(Heuristic::default()), SearchLimits::depth(3))
+        .with_margin(600)
+        .with_solve_empties(10);
+    let mut found = 0;
+    for _ in 0..4 {
+        let game = random_game(&mut rng, 60);
+        for puzzle in finder.scan(&game) {
+            found += 1;
+            assert!(puzzle.margin >= 600);
+            assert!(puzzle.board.moves_for(puzzle.stone).count_set() >= 2);
+            assert!(puzzle.board.is_legal_move(puzzle.stone, puzzle.solution));
+            assert_eq!(puzzle.solution, puzzle.line[0]);
+            let mut replay = game.clone();
+            while replay.history().len() > puzzle.ply {
+                replay.undo();
+            }
+            assert_eq!(replay.board(), puzzle.board);
+            let empties = puzzle.board.empty_squares().count_set();
+            assert_eq!(empties <= 10, puzzle.solved);
+        }
+    }
+    assert!(found > 0);
+}
+
+#[test]
+fn solved_puzzles_are_exact() {
+    let mut rng = StdRng::seed_from_u64(9);
+    let mut finder = PuzzleFinder::new(Searcher::new(DiscDifference), SearchLimits::depth(1))
+        .with_margin(200)
+        .with_solve_empties(8);
+    let mut solved = 0;
+    for _ in 0..10 {
+        let game = random_game(&mut rng, 60);
+        for puzzle in finder
+            .scan(&game)
+            .into_iter()
+            .filter(|puzzle| puzzle.solved)
+        {
+            solved += 1;
+            let (score, _) = solve_endgame(&puzzle.board, puzzle.stone);
+            assert_eq!(score * 100, puzzle.score);
+            // Every other move loses at least the margin
+            for pos in puzzle.board.moves_for(puzzle.stone).hot_bits() {
+                if pos != puzzle.solution {
+                    let mut child = puzzle.board;
+                    child.play(puzzle.stone, pos);
+                    let other = -solve_endgame(&child, puzzle.stone.flip()).0;
+                    assert!(score - other >= 2);
+                }
+            }
+            // The line is legal and reaches the end of the game
+            let mut board = puzzle.board;
+            let mut stone = puzzle.stone;
+            for pos in &puzzle.line {
+                if !board.is_legal_move(stone, *pos) {
+                    stone = stone.flip();
+                }
+                board.play(stone, *pos);
+                stone = stone.flip();
+            }
+            assert!(board.moves_for(stone).is_empty() && board.moves_for(stone.flip()).is_empty());
+        }
+    }
+    assert!(solved > 0);
+}
+
+#[test]
+fn equivalent_moves_are_not_puzzles() {
+    let game = Game::new();
+    let mut finder =
+        PuzzleFinder::new(Searcher::new(DiscDifference), SearchLimits::depth(2)).with_margin(0);
+    // All four opening moves are equivalent, so none is clearly best
+    assert!(finder.find(&game.board(), game.current_turn()).is_some());
+    let mut finder = finder.with_margin(1);
+    assert!(finder.find(&game.board(), game.current_turn()).is_none());
+}
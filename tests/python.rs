@@ -0,0 +1,143 @@
+#![cfg(feature = "python")]
+
+use magpie::{
+    othello::{Board, Game, Stone},
+    python::{self, PyBoard, PyGame, PySearcher, PyStatus, PyStone},
+    search::solve_endgame,
+};
+use pyo3::{ffi::c_str, prelude::*};
+use std::sync::Once;
+
+/// Black owns every square except A1 and B1, where white has a stone.
+const BLACK: u64 = 0x3FFF_FFFF_FFFF_FFFF;
+const WHITE: u64 = 0x4000_0000_0000_0000;
+
+/// Registers the extension module with the embedded interpreter before
+/// starting it, which may only happen once per process.
+fn with_python<F: FnOnce(Python<'_>) -> R, R>(f: F) -> R {
+    static INIT: Once = Once::new();
+    INIT.call_once(|| {
+        use python::magpie as module;
+        pyo3::append_to_inittab!(module);
+        Python::initialize();
+    });
+    Python::attach(f)
+}
+
+#[test]
+fn python_board_matches_board() {
+    let board = PyBoard::new();
+    let standard = Board::standard();
+    assert_eq!(
+        standard.bits_for(Stone::Black).raw(),
+        board.bits(PyStone::Black)
+    );
+    assert_eq!(
+        standard.moves_for(Stone::White).raw(),
+        board.legal_moves(PyStone::White)
+    );
+    assert_eq!(vec!["d3", "c4", "f5", "e6"], board.moves(PyStone::Black));
+
+    let cells = board.cells();
+    assert_eq!(64, cells.len());
+    assert_eq!(2, cells[27]);
+    assert_eq!(1, cells[28]);
+    assert_eq!(60, cells.iter().filter(|cell| **cell == 0).count());
+}
+
+#[test]
+fn python_game_plays_full_game() {
+    let mut python = PyGame::new();
+    let mut game = Game::new();
+    while python.status() == PyStatus::Progressing {
+        assert_eq!(game.pass_if_forced(), python.pass_if_forced());
+        let pos = game.moves().hot_bits().next().unwrap();
+        game.play(pos).unwrap();
+        python.play(&pos.to_string()).unwrap();
+        assert_eq!(game.board(), magpie_board(&python.board()));
+    }
+    assert!(python.undo());
+    assert!(python.redo());
+    assert!(!python.redo());
+}
+
+#[test]
+fn python_searcher_requires_a_limit() {
+    let searcher = PySearcher::new();
+    with_python(|py| {
+        let board = PyBoard::new();
+        assert!(
+            searcher
+                .search(py, &board, PyStone::Black, None, None, None)
+                .is_err()
+        );
+        assert!(
+            searcher
+                .search(py, &board, PyStone::Black, None, Some(-1.0), None)
+                .is_err()
+        );
+        let report = searcher
+            .search(py, &board, PyStone::Black, None, None, Some(1000))
+            .unwrap();
+        assert!(report.best_move.is_some());
+    });
+}
+
+#[test]
+fn python_module_plays_and_solves() {
+    with_python(|py| {
+        py.run(
+            c_str!(
+                r#"
+from magpie import Board, Game, Searcher, Status, Stone, solve_endgame
+
+BLACK = 0x3FFFFFFFFFFFFFFF
+WHITE = 0x4000000000000000
+
+game = Game()
+game.play("f5")
+assert game.current_turn() == Stone.White
+assert sorted(game.moves()) == ["d6", "f4", "f6"]
+assert game.status() == Status.Progressing
+
+board = game.board()
+copy = board.copy()
+copy.play(Stone.White, "d6")
+assert board != copy
+assert eval(repr(board)) == board
+
+try:
+    board.play(Stone.White, "a1")
+    raise AssertionError("illegal moves must raise")
+except ValueError:
+    pass
+
+report = Searcher().search(board, Stone.White, depth=4)
+assert report.depth == 4
+assert report.best_move in game.moves()
+assert report.pv[0] == report.best_move
+
+assert solve_endgame(Board.from_bits(BLACK, WHITE), Stone.Black) == (64, "a1")
+"#
+            ),
+            None,
+            None,
+        )
+        .unwrap();
+    });
+}
+
+#[test]
+fn python_solver_matches_solver() {
+    let board = Board::try_from((BLACK, WHITE)).unwrap();
+    let python = PyBoard::from_bits(BLACK, WHITE).unwrap();
+    let (score, best_move) = solve_endgame(&board, Stone::Black);
+    let expected = (score, best_move.map(|pos| pos.to_string()));
+    with_python(|py| {
+        assert_eq!(expected, python::solve_endgame(py, &python, PyStone::Black));
+    });
+}
+
+fn magpie_board(board: &PyBoard) -> Board {
+    Board::try_from((board.bits(PyStone::Black), board.bits(PyStone::White))).unwrap()
+}
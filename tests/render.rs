@@ -0,0 +1,118 @@
+#![cfg(feature = "render")]
+
+use magpie::{
+    othello::{Board, Position, Stone},
+    render::BoardImage,
+};
+
+/// Splits a PNG image into its chunks, checking their checksums.
+fn chunks(png: &[u8]) -> Vec<([u8; 4], &[u8])> {
+    assert_eq!(b"\x89PNG\r\n\x1a\n", &png[..8]);
+    let mut chunks = Vec::new();
+    let mut rest = &png[8..];
+    while !rest.is_empty() {
+        let len = u32::from_be_bytes(rest[..4].try_into().unwrap()) as usize;
+        let kind: [u8; 4] = rest[4..8].try_into().unwrap();
+        let data = &rest[8..8 + len];
+        let crc = u32::from_be_bytes(rest[8 + len..12 + len].try_into().unwrap());
+        assert_eq!(crc32(&rest[4..8 + len]), crc);
+        chunks.push((kind, data));
+        rest = &rest[12 + len..];
+    }
+    chunks
+}
+
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = u32::MAX;
+    for byte in data {
+        crc ^= u32::from(*byte);
+        for _ in 0..8 {
+            crc = (crc >> 1) ^ (0xEDB8_8320 & (crc & 1).wrapping_neg());
+        }
+    }
+    !crc
+}
+
+/// Decodes the RGB pixels of an image made of stored deflate blocks.
+fn pixels(png: &[u8]) -> (u32, Vec<u8>) {
+    let chunks = chunks(png);
+    let (kind, header) = chunks[0];
+    assert_eq!(b"IHDR", &kind);
+    let width = u32::from_be_bytes(header[..4].try_into().unwrap());
+    assert_eq!(&[8, 2, 0, 0, 0], &header[8..]);
+    assert_eq!(b"IEND", &chunks.last().unwrap().0);
+
+    let stream: Vec<u8> = chunks
+        .iter()
+        .filter(|(kind, _)| kind == b"IDAT")
+        .flat_map(|(_, data)| data.iter().copied())
+        .collect();
+    let mut raw = Vec::new();
+    let mut rest = &stream[2..];
+    loop {
+        let last = rest[0] == 1;
+        let len = u16::from_le_bytes([rest[1], rest[2]]) as usize;
+        raw.extend_from_slice(&rest[5..5 + len]);
+        rest = &rest[5 + len..];
+        if last {
+            break;
+        }
+    }
+    let pixels = raw
+        .chunks(width as usize * 3 + 1)
+        .flat_map(|row| {
+            assert_eq!(0, row[0]);
+            row[1..].iter().copied()
+        })
+        .collect();
+    (width, pixels)
+}
+
+#[test]
+fn png_images_show_the_board() {
+    let mut board = Board::standard();
+    let f5 = Position::try_from("f5").unwrap();
+    board.play(Stone::Black, f5);
+    let image = BoardImage::new(&board)
+        .with_square_size(20)
+        .with_last_move(f5)
+        .with_legal_moves(Stone::White);
+    let (width, pixels) = pixels(&image.to_png());
+    assert_eq!(160, width);
+    assert_eq!(160 * 160 * 3, pixels.len());
+
+    let color = |x: u32, y: u32| {
+        let index = ((y * width + x) * 3) as usize;
+        [pixels[index], pixels[index + 1], pixels[index + 2]]
+    };
+    // The center of a square given its name, with A1 in the top left corner
+    let center = |name: &str| {
+        let index = Position::try_from(name).unwrap().raw().leading_zeros();
+        color(index % 8 * 20 + 10, index / 8 * 20 + 10)
+    };
+    let background = color(5, 5);
+    assert_eq!([0, 0, 0], color(0, 5));
+    assert_eq!([0x11; 3], center("e4"));
+    assert_eq!([0xF4; 3], center("d4"));
+    assert_eq!(background, center("a1"));
+    // Legal moves and the last move are marked
+    assert_ne!(background, center("f4"));
+    assert_ne!(center("e5"), center("f5"));
+}
+
+#[test]
+fn svg_images_show_the_board() {
+    let board = Board::standard();
+    let svg = BoardImage::new(&board).to_svg();
+    assert!(svg.starts_with(r#"<svg xmlns="http://www.w3.org/2000/svg" width="384""#));
+    assert!(svg.ends_with("</svg>\n"));
+    assert_eq!(4, svg.matches("<circle").count());
+    assert_eq!(2, svg.matches("#111111").count());
+    assert_eq!(2, svg.matches("#f4f4f4").count());
+
+    let svg = BoardImage::new(&board)
+        .with_legal_moves(Stone::Black)
+        .with_last_move("d5".parse().unwrap())
+        .to_svg();
+    assert_eq!(9, svg.matches("<circle").count());
+}
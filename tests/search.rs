@@ -0,0 +1,617 @@
+use magpie::{
+    eval::{DiscDifference, Evaluator, Heuristic, Prediction},
+    othello::{Board, Planes, Position, Scoring, Stone},
+    search::{
+        Analysis, Bound, ClockState, CornersFirst, Entry, HistoryHeuristic, KillerMoves, Mcts,
+        MctsLimits, MoveOrderer, ParallelSearcher, ProbCut, ProbCutPair, Puct, RandomRollout,
+        Replacement, SearchLimits, SearchReport, SearchStats, Searcher, SharedTranspositionTable,
+        TimeManager, TranspositionTable, WIN_SCORE, Wdl, solve_endgame, solve_wdl,
+    },
+};
+use rand::{SeedableRng, rngs::StdRng};
+use std::time::Duration;
+
+mod common;
+
+use common::random_game;
+
+fn entry(depth: u8, score: i32) -> Entry {
+    Entry {
+        depth,
+        score,
+        bound: Bound::Exact,
+        best_move: None,
+    }
+}
+
+// Returns a number of distinct boards that all map to the same slot in a
+// table with a single entry.
+fn boards() -> Vec<Board> {
+    (0..8_u64)
+        .map(|i| Board::try_from((1 << i, 1 << (i + 8))).unwrap())
+        .collect()
+}
+
+#[test]
+fn tt_depth_preferred_keeps_deeper_entries() {
+    let mut tt = TranspositionTable::new(1, Replacement::DepthPreferred);
+    let boards = boards();
+    tt.store(&boards[0], Stone::Black, entry(8, 1));
+    tt.store(&boards[1], Stone::Black, entry(2, 2));
+    assert_eq!(Some(entry(8, 1)), tt.probe(&boards[0], Stone::Black));
+    assert_eq!(None, tt.probe(&boards[1], Stone::Black));
+
+    // Entries from previous searches are always replaced
+    tt.new_search();
+    tt.store(&boards[1], Stone::Black, entry(2, 2));
+    assert_eq!(Some(entry(2, 2)), tt.probe(&boards[1], Stone::Black));
+}
+
+#[test]
+fn tt_always_replace_keeps_newest_entries() {
+    let mut tt = TranspositionTable::new(1, Replacement::AlwaysReplace);
+    let boards = boards();
+    tt.store(&boards[0], Stone::Black, entry(8, 1));
+    tt.store(&boards[1], Stone::Black, entry(2, 2));
+    assert_eq!(None, tt.probe(&boards[0], Stone::Black));
+    assert_eq!(Some(entry(2, 2)), tt.probe(&boards[1], Stone::Black));
+    assert_eq!(1, tt.len());
+
+    tt.clear();
+    assert!(tt.is_empty());
+}
+
+#[test]
+fn tt_never_returns_foreign_entries() {
+    let mut tt = TranspositionTable::new(64, Replacement::AlwaysReplace);
+    let boards = boards();
+    for (i, board) in boards.iter().enumerate() {
+        tt.store(board, Stone::White, entry(1, i as i32));
+    }
+    for (i, board) in boards.iter().enumerate() {
+        if let Some(found) = tt.probe(board, Stone::White) {
+            assert_eq!(i as i32, found.score);
+        }
+        assert_eq!(None, tt.probe(board, Stone::Black));
+    }
+}
+
+#[test]
+fn searcher_matches_minimax() {
+    let mut rng = StdRng::seed_from_u64(10);
+    for _ in 0..20 {
+        let game = random_game(&mut rng, 20);
+        let board = game.board();
+        let stone = game.current_turn();
+        for depth in 1..=3 {
+            let mut searcher = Searcher::new(DiscDifference);
+            let report = searcher.search(&board, stone, SearchLimits::depth(depth));
+            assert_eq!(minimax(&board, stone, depth), report.score);
+        }
+    }
+}
+
+#[test]
+fn aspiration_windows_match_minimax() {
+    // The scores of the heuristic vary a lot more between iterations than
+    // the disc difference, so the aspiration window has to be widened
+    let mut rng = StdRng::seed_from_u64(11);
+    for _ in 0..20 {
+        let game = random_game(&mut rng, 24);
+        let board = game.board();
+        let stone = game.current_turn();
+        let mut searcher = Searcher::new(Heuristic::default());
+        let report = searcher.search(&board, stone, SearchLimits::depth(4));
+        let expected = minimax_with(&Heuristic::default(), &board, stone, 4);
+        assert_eq!(expected, report.score);
+    }
+}
+
+#[test]
+fn multipv_matches_minimax() {
+    let mut rng = StdRng::seed_from_u64(11);
+    for _ in 0..20 {
+        let game = random_game(&mut rng, 20);
+        let board = game.board();
+        let stone = game.current_turn();
+        let depth = 3;
+        let mut expected: Vec<i32> = board
+            .moves_for(stone)
+            .hot_bits()
+            .map(|pos| {
+                let mut next = board;
+                next.play(stone, pos);
+                -minimax(&next, stone.flip(), depth - 1)
+            })
+            .collect();
+        expected.sort_unstable_by(|a, b| b.cmp(a));
+        expected.truncate(3);
+
+        let mut searcher = Searcher::new(DiscDifference);
+        let report = searcher.multipv(&board, stone, SearchLimits::depth(depth), 3);
+        let scores: Vec<i32> = report.lines.iter().map(|line| line.score).collect();
+        assert_eq!(expected, scores);
+        for line in &report.lines {
+            assert_eq!(line.mv, line.pv[0]);
+            let mut next = board;
+            next.play(stone, line.mv);
+            assert_eq!(line.score, -minimax(&next, stone.flip(), depth - 1));
+        }
+    }
+}
+
+#[test]
+fn ordered_searcher_matches_minimax() {
+    let mut rng = StdRng::seed_from_u64(12);
+    for _ in 0..20 {
+        let game = random_game(&mut rng, 20);
+        let board = game.board();
+        let stone = game.current_turn();
+        let orderer = KillerMoves::new()
+            .then(HistoryHeuristic::new())
+            .then(CornersFirst);
+        let mut searcher = Searcher::new(DiscDifference).with_move_orderer(orderer);
+        for depth in 1..=3 {
+            let report = searcher.search(&board, stone, SearchLimits::depth(depth));
+            assert_eq!(minimax(&board, stone, depth), report.score);
+        }
+    }
+}
+
+#[test]
+fn move_ordering_reduces_nodes() {
+    let mut rng = StdRng::seed_from_u64(21);
+    let mut totals = [(0, SearchStats::default()); 2];
+    for _ in 0..10 {
+        let game = random_game(&mut rng, 16);
+        let board = game.board();
+        let stone = game.current_turn();
+        let orderer = KillerMoves::new()
+            .then(HistoryHeuristic::new())
+            .then(CornersFirst);
+        let mut unordered = Searcher::new(Heuristic::default());
+        let mut ordered = Searcher::new(Heuristic::default()).with_move_orderer(orderer);
+        for (total, searcher) in totals.iter_mut().zip([&mut unordered, &mut ordered]) {
+            let report = searcher.search(&board, stone, SearchLimits::depth(6));
+            total.0 += report.nodes;
+            total.1 += report.stats;
+        }
+    }
+    let [(unordered_nodes, unordered), (ordered_nodes, ordered)] = totals;
+    assert!(ordered_nodes < unordered_nodes);
+    assert!(ordered.first_move_cutoff_rate() > unordered.first_move_cutoff_rate());
+    assert!(ordered.leaf_nodes < ordered_nodes);
+    assert!(ordered.tt_cutoffs <= ordered.tt_hits && ordered.tt_hits <= ordered.tt_probes);
+}
+
+#[test]
+fn probcut_prunes_nodes() {
+    let mut rng = StdRng::seed_from_u64(22);
+    let pair = ProbCutPair {
+        depth: 4,
+        shallow: 2,
+        slope: 1.0,
+        intercept: 0.0,
+        sigma: 20.0,
+    };
+    let mut nodes = [0; 2];
+    let mut probcuts = 0;
+    for _ in 0..10 {
+        let game = random_game(&mut rng, 16);
+        let board = game.board();
+        let stone = game.current_turn();
+        let mut plain = Searcher::new(Heuristic::default());
+        let expected = plain.search(&board, stone, SearchLimits::depth(6));
+        nodes[0] += expected.nodes;
+
+        let mut pruned =
+            Searcher::new(Heuristic::default()).with_probcut(ProbCut::new().with_pair(pair));
+        let report = pruned.search(&board, stone, SearchLimits::depth(6));
+        assert!(board.is_legal_move(stone, report.best_move.unwrap()));
+        nodes[1] += report.nodes;
+        probcuts += report.stats.probcuts;
+
+        // Bounds that are never cleared leave the result unchanged
+        let probcut = ProbCut::new().with_pair(pair).with_threshold(1e9);
+        let mut cautious = Searcher::new(Heuristic::default()).with_probcut(probcut);
+        let report = cautious.search(&board, stone, SearchLimits::depth(6));
+        assert_eq!(expected.score, report.score);
+        assert_eq!(0, report.stats.probcuts);
+    }
+    assert!(probcuts > 0);
+    assert!(nodes[1] < nodes[0]);
+}
+
+#[test]
+fn chained_orderers_break_ties() {
+    let board = Board::standard();
+    let [a1, b2, c4, d3, h8] =
+        ["a1", "b2", "c4", "d3", "h8"].map(|notation| Position::try_from(notation).unwrap());
+
+    let mut orderer = KillerMoves::new().then(CornersFirst);
+    orderer.on_cutoff(&board, Stone::Black, 1, 4, c4);
+    let mut moves = vec![b2, d3, h8, c4, a1];
+    orderer.order(&board, Stone::Black, 1, &mut moves);
+    assert_eq!(vec![c4, h8, a1, d3, b2], moves);
+
+    // Killers are only remembered for the ply they were found at
+    let mut moves = vec![b2, d3, h8, c4, a1];
+    orderer.order(&board, Stone::Black, 2, &mut moves);
+    assert_eq!(vec![h8, a1, d3, c4, b2], moves);
+
+    orderer.new_search();
+    let mut moves = vec![b2, d3, h8, c4, a1];
+    orderer.order(&board, Stone::Black, 1, &mut moves);
+    assert_eq!(vec![h8, a1, d3, c4, b2], moves);
+}
+
+#[test]
+fn searcher_solves_endgames() {
+    let mut rng = StdRng::seed_from_u64(11);
+    for _ in 0..10 {
+        let game = random_game(&mut rng, 52);
+        let board = game.board();
+        let stone = game.current_turn();
+        let empties = board.empty_squares().count_set();
+        let mut searcher = Searcher::new(DiscDifference);
+        let report = searcher.search(&board, stone, SearchLimits::depth(64));
+        assert_eq!(minimax(&board, stone, empties), report.score);
+        assert!(report.depth <= empties);
+        if let Some(best_move) = report.best_move {
+            assert_eq!(Some(&best_move), report.pv.first());
+        }
+    }
+}
+
+#[test]
+fn endgame_solver_matches_minimax() {
+    let mut rng = StdRng::seed_from_u64(12);
+    for _ in 0..20 {
+        let game = random_game(&mut rng, 51);
+        let board = game.board();
+        let stone = game.current_turn();
+        let empties = board.empty_squares().count_set();
+        let expected = minimax(&board, stone, empties);
+        let (score, best_move) = solve_endgame(&board, stone);
+        assert_eq!(expected, score + score.signum() * WIN_SCORE);
+
+        // The best move must actually achieve the score
+        if let Some(best_move) = best_move {
+            let mut next = board;
+            next.play(stone, best_move);
+            assert_eq!(-score, solve_endgame(&next, stone.flip()).0);
+        }
+    }
+}
+
+#[test]
+fn solvers_award_empty_squares_to_the_winner() {
+    // Neither player can move, with A1 and H8 left empty
+    let board = Board::try_from((0x7FFF_FFFF_FFFF_FFFE, 0)).unwrap();
+    assert_eq!((64, None), solve_endgame(&board, Stone::Black));
+    assert_eq!((-64, None), solve_endgame(&board, Stone::White));
+
+    // Taking A1 from white ends the game with H8 still empty
+    let board = Board::try_from((0x3FFF_FFFF_FFFF_FFFE, 0x4000_0000_0000_0000)).unwrap();
+    let a1 = Position::try_from("a1").unwrap();
+    assert_eq!((64, Some(a1)), solve_endgame(&board, Stone::Black));
+    assert_eq!((Wdl::Win, Some(a1)), solve_wdl(&board, Stone::Black));
+
+    let mut searcher = Searcher::new(DiscDifference);
+    let report = searcher.search(&board, Stone::Black, SearchLimits::depth(4));
+    assert_eq!(WIN_SCORE + 64, report.score);
+    assert_eq!(Some(a1), report.best_move);
+}
+
+#[test]
+fn wdl_solver_matches_endgame_solver() {
+    let mut rng = StdRng::seed_from_u64(13);
+    for _ in 0..20 {
+        let game = random_game(&mut rng, 48);
+        let board = game.board();
+        let stone = game.current_turn();
+        let (score, _) = solve_endgame(&board, stone);
+        let (wdl, best_move) = solve_wdl(&board, stone);
+        let expected = match score.signum() {
+            1 => Wdl::Win,
+            -1 => Wdl::Loss,
+            _ => Wdl::Draw,
+        };
+        assert_eq!(expected, wdl);
+
+        // The best move must actually achieve the outcome
+        if let Some(best_move) = best_move {
+            let mut next = board;
+            next.play(stone, best_move);
+            assert_eq!(wdl.flip(), solve_wdl(&next, stone.flip()).0);
+        }
+    }
+}
+
+#[test]
+fn searcher_respects_time_limit() {
+    let mut searcher = Searcher::new(DiscDifference);
+    let limits = SearchLimits::time(Duration::from_millis(50));
+    let report = searcher.search(&Board::standard(), Stone::Black, limits);
+    assert!(report.depth >= 1);
+    assert!(report.elapsed < Duration::from_secs(1));
+}
+
+#[test]
+fn parallel_searcher_solves_endgames() {
+    let mut rng = StdRng::seed_from_u64(17);
+    let mut searcher = ParallelSearcher::new(DiscDifference).with_threads(4);
+    for _ in 0..10 {
+        let game = random_game(&mut rng, 50);
+        let board = game.board();
+        let stone = game.current_turn();
+        let empties = board.empty_squares().count_set();
+        let report = searcher.search(&board, stone, SearchLimits::depth(64));
+        assert_eq!(minimax(&board, stone, empties), report.score);
+        if let Some(best_move) = report.best_move {
+            let mut next = board;
+            next.play(stone, best_move);
+            assert_eq!(report.score, -minimax(&next, stone.flip(), empties));
+        }
+    }
+}
+
+#[test]
+fn parallel_searcher_shares_the_table() {
+    let tt = SharedTranspositionTable::new(1 << 12, Replacement::DepthPreferred);
+    let mut searcher = ParallelSearcher::new(DiscDifference)
+        .with_threads(3)
+        .with_transposition_table(tt);
+    let report = searcher.search(&Board::standard(), Stone::Black, SearchLimits::depth(5));
+    assert_eq!(5, report.depth);
+    assert_eq!(3, searcher.threads());
+    assert!(!searcher.transposition_table().is_empty());
+    assert!(report.stats.tt_hits > 0);
+}
+
+#[test]
+fn analysis_reports_every_iteration() {
+    let board = Board::standard();
+    let mut analysis = Analysis::start(
+        Searcher::new(DiscDifference),
+        &board,
+        Stone::Black,
+        SearchLimits::depth(3),
+    );
+    let depths: Vec<u8> = std::iter::from_fn(|| analysis.recv())
+        .map(|update| update.depth)
+        .collect();
+    assert_eq!(vec![1, 2, 3], depths);
+    assert_eq!(3, analysis.latest().unwrap().depth);
+
+    let (_, report) = analysis.stop();
+    assert_eq!(3, report.depth);
+}
+
+#[test]
+fn analysis_stops_on_request() {
+    let board = Board::standard();
+    let analysis = Analysis::start(
+        Searcher::new(DiscDifference),
+        &board,
+        Stone::Black,
+        SearchLimits::depth(60),
+    );
+    std::thread::sleep(Duration::from_millis(50));
+    assert!(!analysis.is_finished());
+
+    let (mut searcher, report) = analysis.stop();
+    assert!(report.depth < 60);
+    assert!(board.is_legal_move(Stone::Black, report.best_move.unwrap()));
+    // The searcher can be reused afterwards
+    let report = searcher.search(&board, Stone::Black, SearchLimits::depth(2));
+    assert_eq!(2, report.depth);
+}
+
+#[test]
+fn node_budgets_are_reproducible() {
+    let mut rng = StdRng::seed_from_u64(23);
+    for _ in 0..5 {
+        let game = random_game(&mut rng, 12);
+        let board = game.board();
+        let stone = game.current_turn();
+        let limits = SearchLimits::time(Duration::from_millis(5)).deterministic();
+        assert_eq!(Some(5000), limits.nodes);
+
+        let search = || {
+            let mut searcher =
+                Searcher::new(Heuristic::default()).with_move_orderer(KillerMoves::new());
+            let report = searcher.search(&board, stone, limits);
+            SearchReport {
+                elapsed: Duration::ZERO,
+                ..report
+            }
+        };
+        let report = search();
+        assert!(report.depth > 1);
+        assert!(report.nodes <= 5000 + 64);
+        assert_eq!(report, search());
+    }
+}
+
+#[test]
+fn time_manager_divides_the_clock() {
+    let manager = TimeManager::new();
+    let clock = ClockState {
+        remaining: Duration::from_secs(60),
+        increment: Duration::ZERO,
+        moves_played: 20,
+        empties: 40,
+    };
+    let budget = manager.budget(&clock);
+    assert!(budget.soft <= budget.panic && budget.panic <= budget.hard);
+    assert!(budget.hard <= clock.remaining / 2);
+    // 20 moves left for the player to move
+    assert!(budget.soft.abs_diff(Duration::from_millis(2995)) < Duration::from_millis(10));
+
+    // Fewer empties leave more time per move, and so does an increment
+    let late = manager.budget(&ClockState {
+        empties: 10,
+        ..clock
+    });
+    assert!(late.soft > budget.soft);
+    let increment = manager.budget(&ClockState {
+        increment: Duration::from_secs(2),
+        ..clock
+    });
+    assert!(increment.soft > budget.soft);
+    // The opening moves are given less time
+    let opening = manager.budget(&ClockState {
+        moves_played: 0,
+        ..clock
+    });
+    assert!(opening.soft < budget.soft);
+
+    let flagging = manager.budget(&ClockState {
+        remaining: Duration::from_millis(50),
+        ..clock
+    });
+    assert_eq!(Duration::ZERO, flagging.hard);
+}
+
+#[test]
+fn searcher_respects_time_budget() {
+    let clock = ClockState {
+        remaining: Duration::from_secs(2),
+        increment: Duration::ZERO,
+        moves_played: 20,
+        empties: 60,
+    };
+    let budget = TimeManager::new().budget(&clock);
+    let mut searcher = Searcher::new(DiscDifference);
+    let report = searcher.search(
+        &Board::standard(),
+        Stone::Black,
+        SearchLimits::budget(budget),
+    );
+    assert!(report.depth >= 1);
+    assert!(report.elapsed < budget.hard + Duration::from_millis(50));
+}
+
+#[test]
+fn mcts_finds_winning_moves() {
+    let mut rng = StdRng::seed_from_u64(13);
+    let mut checked = 0;
+    while checked < 10 {
+        let game = random_game(&mut rng, 56);
+        let board = game.board();
+        let stone = game.current_turn();
+        let (score, _) = solve_endgame(&board, stone);
+        if score <= 0 || board.moves_for(stone).count_set() < 2 {
+            continue;
+        }
+        checked += 1;
+
+        let mut mcts = Mcts::new(RandomRollout::new(checked));
+        let report = mcts.search(&board, stone, MctsLimits::playouts(5000));
+        let mut next = board;
+        next.play(stone, report.best_move.unwrap());
+        assert!(solve_endgame(&next, stone.flip()).0 < 0);
+    }
+}
+
+#[test]
+fn mcts_reports_root_visits() {
+    let mut mcts = Mcts::new(RandomRollout::default());
+    let board = Board::standard();
+    let report = mcts.search(&board, Stone::Black, MctsLimits::playouts(1000));
+    let total: u32 = report.visits.iter().map(|(_, visits)| visits).sum();
+    assert_eq!(1000, total);
+    assert!((0.0..=1.0).contains(&report.win_rate));
+
+    // The player has to pass
+    let board = Board::try_from((0x40 << 56, 0x80 << 56)).unwrap();
+    let report = mcts.search(&board, Stone::Black, MctsLimits::playouts(1000));
+    assert_eq!(None, report.best_move);
+    assert_eq!(0, report.playouts);
+}
+
+fn uniform_model(batch: &[Planes]) -> Vec<Prediction> {
+    batch
+        .iter()
+        .map(|_| Prediction {
+            value: 0.0,
+            policy: [1.0; 64],
+        })
+        .collect()
+}
+
+#[test]
+fn puct_finds_winning_moves() {
+    let mut rng = StdRng::seed_from_u64(17);
+    let mut checked = 0;
+    while checked < 10 {
+        let game = random_game(&mut rng, 56);
+        let board = game.board();
+        let stone = game.current_turn();
+        let (score, _) = solve_endgame(&board, stone);
+        if score <= 0 || board.moves_for(stone).count_set() < 2 {
+            continue;
+        }
+        checked += 1;
+
+        // Without any knowledge, the search relies on finished games alone
+        let mut puct = Puct::new(uniform_model).with_batch_size(4);
+        let report = puct.search(&board, stone, MctsLimits::playouts(5000));
+        let mut next = board;
+        next.play(stone, report.best_move.unwrap());
+        assert!(solve_endgame(&next, stone.flip()).0 < 0);
+    }
+}
+
+#[test]
+fn puct_follows_the_policy_in_batches() {
+    let board = Board::standard();
+    let favourite = board.moves_for(Stone::Black).hot_bits().last().unwrap();
+    let index = favourite.raw().leading_zeros() as usize;
+    let mut batches = Vec::new();
+    let model = |batch: &[Planes]| {
+        batches.push(batch.len());
+        let mut policy = [0.0; 64];
+        policy[index] = 1.0;
+        vec![Prediction { value: 0.0, policy }; batch.len()]
+    };
+
+    let mut puct = Puct::new(model).with_batch_size(8);
+    let report = puct.search(&board, Stone::Black, MctsLimits::playouts(500));
+    assert_eq!(500, report.playouts);
+    assert_eq!(Some(favourite), report.best_move);
+    let total: u32 = report.visits.iter().map(|(_, visits)| visits).sum();
+    assert_eq!(499, total);
+    assert!(batches.iter().all(|size| (1..=8).contains(size)));
+    assert!(batches.iter().any(|size| *size > 1));
+}
+
+// A plain negamax search without any pruning, using the same scoring
+// conventions as the searcher.
+fn minimax(board: &Board, stone: Stone, depth: u8) -> i32 {
+    minimax_with(&DiscDifference, board, stone, depth)
+}
+
+fn minimax_with<E: Evaluator>(evaluator: &E, board: &Board, stone: Stone, depth: u8) -> i32 {
+    let moves = board.moves_for(stone);
+    if moves.is_empty() {
+        if board.moves_for(stone.flip()).is_empty() {
+            let diff = i32::from(Scoring::WinnerTakesEmpties.difference(board, stone));
+            return diff + diff.signum() * WIN_SCORE;
+        }
+        return -minimax_with(evaluator, board, stone.flip(), depth);
+    }
+    if depth == 0 {
+        return evaluator.evaluate(board, stone);
+    }
+    moves
+        .hot_bits()
+        .map(|pos| {
+            let mut next = *board;
+            next.play(stone, pos);
+            -minimax_with(evaluator, &next, stone.flip(), depth - 1)
+        })
+        .max()
+        .unwrap()
+}
@@ -0,0 +1,137 @@
+use magpie::{
+    eval::DiscDifference,
+    othello::{Bitboard, Board, Position, Scoring, Stone},
+    search::{RandomRollout, SearchLimits, Searcher},
+    selfplay::{Record, SearchAgent, SelfPlay},
+};
+use std::collections::BTreeMap;
+
+fn first_move(_: &Board, _: Stone, moves: Bitboard) -> Position {
+    moves.hot_bits().next().unwrap()
+}
+
+fn last_move(_: &Board, _: Stone, moves: Bitboard) -> Position {
+    moves.hot_bits().last().unwrap()
+}
+
+#[test]
+fn records_describe_the_games() {
+    let selfplay = SelfPlay::new(|| RandomRollout::new(1), || RandomRollout::new(2))
+        .with_games(10)
+        .with_threads(3);
+    let mut records: Vec<Record> = Vec::new();
+    let summary = selfplay.run(&mut records);
+    assert_eq!(10, summary.games());
+
+    let mut games: BTreeMap<usize, Vec<Record>> = BTreeMap::new();
+    for record in records {
+        games.entry(record.game).or_default().push(record);
+    }
+    assert_eq!(10, games.len());
+    for records in games.values() {
+        assert_eq!(Board::standard(), records[0].board);
+        for pair in records.windows(2) {
+            let [before, after] = pair else {
+                unreachable!()
+            };
+            let mut board = before.board;
+            assert!(board.is_legal_move(before.stone, before.mv));
+            board.play(before.stone, before.mv);
+            assert_eq!(board, after.board);
+            // Both players share the same final result
+            let expected = if before.stone == after.stone {
+                before.score
+            } else {
+                -before.score
+            };
+            assert_eq!(expected, after.score);
+        }
+    }
+}
+
+#[test]
+fn agents_alternate_colors() {
+    let opening = |records: Vec<Record>| {
+        let mut openings: Vec<(usize, Position)> = records
+            .iter()
+            .filter(|record| record.board == Board::standard())
+            .map(|record| (record.game, record.mv))
+            .collect();
+        openings.sort_by_key(|(game, _)| *game);
+        openings.into_iter().map(|(_, mv)| mv).collect::<Vec<_>>()
+    };
+    let moves = Board::standard().moves_for(Stone::Black);
+    let first = moves.hot_bits().next().unwrap();
+    let last = moves.hot_bits().last().unwrap();
+
+    let mut records: Vec<Record> = Vec::new();
+    SelfPlay::new(|| first_move, || last_move)
+        .with_games(3)
+        .run(&mut records);
+    assert_eq!(vec![first, last, first], opening(records));
+
+    let mut records: Vec<Record> = Vec::new();
+    SelfPlay::new(|| first_move, || last_move)
+        .with_games(3)
+        .with_alternating_colors(false)
+        .run(&mut records);
+    assert_eq!(vec![first, first, first], opening(records));
+}
+
+#[test]
+fn search_agents_beat_random_play() {
+    let selfplay = SelfPlay::new(
+        || SearchAgent::new(Searcher::new(DiscDifference), SearchLimits::depth(3)),
+        || RandomRollout::new(7),
+    )
+    .with_games(4);
+    let summary = selfplay.run(&mut |_| {});
+    assert_eq!(4, summary.games());
+    assert!(summary.first_wins >= 2);
+}
+
+#[test]
+fn random_openings_are_reproducible() {
+    let run = |threads| {
+        let selfplay = SelfPlay::new(|| first_move, || first_move)
+            .with_games(6)
+            .with_threads(threads)
+            .with_random_opening(6);
+        let mut records: Vec<Record> = Vec::new();
+        let summary = selfplay.run(&mut records);
+        records.sort_by_key(|record| record.game);
+        (summary, records)
+    };
+    let (summary, records) = run(1);
+    assert_eq!((summary, records.clone()), run(4));
+    // Random moves are not recorded
+    assert!(
+        records
+            .iter()
+            .all(|record| record.board.empty_squares().count_set() <= 54)
+    );
+    let distinct: std::collections::HashSet<Board> =
+        records.iter().map(|record| record.board).collect();
+    assert!(distinct.len() > records.len() / 6);
+}
+
+#[test]
+fn scores_follow_the_scoring_convention() {
+    let run = |scoring| {
+        let selfplay = SelfPlay::new(|| RandomRollout::new(3), || RandomRollout::new(4))
+            .with_games(20)
+            .with_scoring(scoring);
+        let mut records: Vec<Record> = Vec::new();
+        let summary = selfplay.run(&mut records);
+        records.sort_by_key(|record| record.game);
+        (summary, records)
+    };
+    let (disc_summary, disc_records) = run(Scoring::DiscCount);
+    let (tournament_summary, tournament_records) = run(Scoring::WinnerTakesEmpties);
+    // The winners are the same, only the margins may grow
+    assert_eq!(disc_summary, tournament_summary);
+    for (disc, tournament) in disc_records.iter().zip(&tournament_records) {
+        assert_eq!(disc.score.signum(), tournament.score.signum());
+        assert!(disc.score.abs() <= tournament.score.abs());
+    }
+}
@@ -0,0 +1,168 @@
+use magpie::{
+    othello::{Board6x6, SizedBoard, Stone},
+    search::Wdl,
+    tablebase::{Tablebase, TablebaseError, TablebaseGenerator, TablebaseView},
+};
+use rand::{Rng, SeedableRng, rngs::StdRng};
+
+fn squares(bits: u128) -> impl Iterator<Item = usize> {
+    (0..128).filter(move |square| bits & (1 << square) != 0)
+}
+
+/// Plays random moves until the board has the specified number of empty
+/// squares, returning `None` if the game ends before. The moves played are
+/// returned along with the board.
+fn random_endgame(rng: &mut StdRng, empties: u32) -> Option<(Board6x6, Stone, Vec<usize>)> {
+    let mut board = Board6x6::standard();
+    let mut stone = Stone::Black;
+    let mut played = Vec::new();
+    while board.empty_squares().count_ones() > empties {
+        let moves = board.moves_for(stone);
+        if moves == 0 {
+            if board.moves_for(stone.flip()) == 0 {
+                return None;
+            }
+        } else {
+            let moves: Vec<_> = squares(moves).collect();
+            let square = moves[rng.random_range(0..moves.len())];
+            board.play(stone, square).unwrap();
+            played.push(square);
+        }
+        stone = stone.flip();
+    }
+    Some((board, stone, played))
+}
+
+/// Replays the moves transformed onto other squares, with the colors of
+/// the players swapped if specified.
+fn replay(played: &[usize], swap: bool, transform: impl Fn(usize, usize) -> usize) -> Board6x6 {
+    let mut board = Board6x6::standard();
+    let mut stone = if swap { Stone::White } else { Stone::Black };
+    for square in played {
+        if board.moves_for(stone) == 0 {
+            stone = stone.flip();
+        }
+        board
+            .play(stone, transform(square / 6, square % 6))
+            .unwrap();
+        stone = stone.flip();
+    }
+    board
+}
+
+fn minimax<const N: usize>(board: &SizedBoard<N>, stone: Stone) -> i32 {
+    let moves = board.moves_for(stone);
+    if moves == 0 {
+        if board.moves_for(stone.flip()) == 0 {
+            let own = board.bits_for(stone).count_ones();
+            let opponent = board.bits_for(stone.flip()).count_ones();
+            return i32::try_from(own).unwrap() - i32::try_from(opponent).unwrap();
+        }
+        return -minimax(board, stone.flip());
+    }
+    squares(moves)
+        .map(|square| {
+            let mut next = *board;
+            next.play(stone, square).unwrap();
+            -minimax(&next, stone.flip())
+        })
+        .max()
+        .unwrap()
+}
+
+fn wdl(score: i32) -> Wdl {
+    match score.signum() {
+        1 => Wdl::Win,
+        -1 => Wdl::Loss,
+        _ => Wdl::Draw,
+    }
+}
+
+#[test]
+fn tablebase_matches_minimax() {
+    let mut rng = StdRng::seed_from_u64(1);
+    let mut checked = 0;
+    while checked < 10 {
+        let Some((board, stone, _)) = random_endgame(&mut rng, 9) else {
+            continue;
+        };
+        let tablebase = TablebaseGenerator::new(9).generate(&board, stone);
+        assert_eq!(
+            Some(wdl(minimax(&board, stone))),
+            tablebase.probe(&board, stone)
+        );
+
+        // Every position after it is covered as well
+        for square in squares(board.moves_for(stone)) {
+            let mut next = board;
+            next.play(stone, square).unwrap();
+            let expected = wdl(minimax(&next, stone.flip()));
+            assert_eq!(Some(expected), tablebase.probe(&next, stone.flip()));
+        }
+        checked += 1;
+    }
+}
+
+#[test]
+fn symmetric_positions_share_entries() {
+    let mut rng = StdRng::seed_from_u64(2);
+    let (board, stone, played) = loop {
+        if let Some(endgame) = random_endgame(&mut rng, 8) {
+            break endgame;
+        }
+    };
+    let tablebase = TablebaseGenerator::new(8).generate(&board, stone);
+    let expected = tablebase.probe(&board, stone);
+    assert!(expected.is_some());
+
+    // The opening position is symmetric along the diagonal, and mirrored
+    // when the colors are swapped
+    let transposed = replay(&played, false, |rank, file| Board6x6::square(file, rank));
+    let swapped = replay(&played, true, |rank, file| Board6x6::square(rank, 5 - file));
+    assert_ne!(board, transposed);
+    assert_eq!(expected, tablebase.probe(&transposed, stone));
+    assert_eq!(expected, tablebase.probe(&swapped, stone.flip()));
+}
+
+#[test]
+fn tablebases_roundtrip_through_files() {
+    let board = SizedBoard::<4>::standard();
+    let tablebase = TablebaseGenerator::new(12).generate(&board, Stone::Black);
+    assert_eq!(Some(Wdl::Loss), tablebase.probe(&board, Stone::Black));
+
+    let mut bytes = Vec::new();
+    tablebase.write_to(&mut bytes).unwrap();
+    assert_eq!(16 + 8 * tablebase.len(), bytes.len());
+    assert_eq!(
+        tablebase,
+        Tablebase::<4>::read_from(bytes.as_slice()).unwrap()
+    );
+
+    let view = TablebaseView::<4>::new(&bytes).unwrap();
+    assert_eq!(12, view.max_empties());
+    let mut next = board;
+    next.play(Stone::Black, SizedBoard::<4>::square(0, 1))
+        .unwrap();
+    assert_eq!(
+        tablebase.probe(&next, Stone::White),
+        view.probe(&next, Stone::White)
+    );
+    assert_eq!(Some(Wdl::Win), view.probe(&next, Stone::White));
+
+    assert!(matches!(
+        TablebaseView::<6>::new(&bytes),
+        Err(TablebaseError::SizeMismatch(4))
+    ));
+    assert!(matches!(
+        TablebaseView::<4>::new(&bytes[..bytes.len() - 1]),
+        Err(TablebaseError::Truncated)
+    ));
+    assert!(matches!(
+        Tablebase::<4>::read_from(&bytes[..20]),
+        Err(TablebaseError::Truncated)
+    ));
+    assert!(matches!(
+        TablebaseView::<4>::new(b"MGPK\x01\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00"),
+        Err(TablebaseError::InvalidMagic)
+    ));
+}
@@ -0,0 +1,104 @@
+use magpie::{
+    clock::TimeControl,
+    othello::{Bitboard, Board, Position, Stone},
+    search::{ClockState, RandomRollout},
+    selfplay::Agent,
+    tournament::{Match, MatchReport, Sprt, SprtOutcome},
+};
+use std::{thread, time::Duration};
+
+fn first_move(_: &Board, _: Stone, moves: Bitboard) -> Position {
+    moves.hot_bits().next().unwrap()
+}
+
+/// Plays the first legal move, but takes its time doing so.
+struct Slow;
+
+impl Agent for Slow {
+    fn choose(&mut self, board: &Board, stone: Stone, moves: Bitboard) -> Position {
+        first_move(board, stone, moves)
+    }
+
+    fn choose_timed(
+        &mut self,
+        board: &Board,
+        stone: Stone,
+        moves: Bitboard,
+        clock: &ClockState,
+    ) -> Position {
+        assert!(clock.empties <= 60);
+        thread::sleep(Duration::from_millis(30));
+        self.choose(board, stone, moves)
+    }
+}
+
+#[test]
+fn identical_agents_split_the_games() {
+    let report = Match::new(|| first_move, || first_move)
+        .with_games(4)
+        .with_threads(2)
+        .run();
+    assert_eq!(4, report.games());
+    // Both agents play every opening with either color
+    assert_eq!(report.wins, report.losses);
+    assert_eq!(None, report.sprt);
+}
+
+#[test]
+fn agents_lose_on_time() {
+    let report = Match::new(|| Slow, || RandomRollout::new(1))
+        .with_games(2)
+        .with_time_control(TimeControl::SuddenDeath {
+            base: Duration::from_millis(50),
+        })
+        .run();
+    assert_eq!(2, report.losses);
+    assert_eq!(2, report.first_timeouts);
+    assert_eq!(0, report.second_timeouts);
+}
+
+#[test]
+fn adjudication_decides_games() {
+    let report = Match::new(|| first_move, || first_move)
+        .with_games(4)
+        .with_adjudication(14)
+        .run();
+    assert_eq!(4, report.adjudicated);
+    // Both agents reach the same position with either color
+    assert_eq!(report.wins, report.losses);
+}
+
+#[test]
+fn elo_is_symmetric() {
+    let report = MatchReport {
+        wins: 120,
+        draws: 20,
+        losses: 60,
+        ..MatchReport::default()
+    };
+    let mirrored = MatchReport {
+        wins: 60,
+        losses: 120,
+        ..report
+    };
+    let (elo, mirrored_elo) = (report.elo(), mirrored.elo());
+    assert!((elo.difference + mirrored_elo.difference).abs() < 1e-9);
+    assert!((elo.lower + mirrored_elo.upper).abs() < 1e-9);
+
+    let sprt = Sprt::new(0.0, 20.0).with_errors(0.1, 0.1);
+    assert_eq!(SprtOutcome::AcceptH1, sprt.outcome(&report));
+    assert_eq!(SprtOutcome::AcceptH0, sprt.outcome(&mirrored));
+}
+
+#[cfg(feature = "openings")]
+#[test]
+fn xot_openings_are_distinct() {
+    use std::collections::HashSet;
+
+    let openings = magpie::openings::xot();
+    let canonical: HashSet<Board> = openings
+        .iter()
+        .map(|(board, _)| board.canonical().0)
+        .collect();
+    assert_eq!(openings.len(), canonical.len());
+}
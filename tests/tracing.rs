@@ -0,0 +1,119 @@
+#![cfg(feature = "tracing")]
+
+use magpie::{
+    eval::DiscDifference,
+    othello::{Board, Stone},
+    protocol::nboard::NboardServer,
+    search::{SearchLimits, Searcher},
+};
+use std::{
+    fmt,
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicU64, Ordering},
+    },
+};
+use tracing::{
+    Event, Metadata, Subscriber,
+    field::{Field, Visit},
+    span::{Attributes, Id, Record},
+};
+
+/// The names and values of the fields of an event.
+type EventFields = Vec<(String, String)>;
+
+/// Records the names of all spans and the fields of all events, shared
+/// between its clones.
+#[derive(Clone, Default)]
+struct Recorder {
+    next_id: Arc<AtomicU64>,
+    spans: Arc<Mutex<Vec<String>>>,
+    events: Arc<Mutex<Vec<EventFields>>>,
+}
+
+struct Fields(EventFields);
+
+impl Visit for Fields {
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        self.0
+            .push((field.name().to_string(), format!("{value:?}")));
+    }
+}
+
+impl Subscriber for Recorder {
+    fn enabled(&self, _: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, span: &Attributes<'_>) -> Id {
+        self.spans
+            .lock()
+            .unwrap()
+            .push(span.metadata().name().to_string());
+        Id::from_u64(self.next_id.fetch_add(1, Ordering::Relaxed) + 1)
+    }
+
+    fn record(&self, _: &Id, _: &Record<'_>) {}
+
+    fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+    fn event(&self, event: &Event<'_>) {
+        let mut fields = Fields(Vec::new());
+        event.record(&mut fields);
+        self.events.lock().unwrap().push(fields.0);
+    }
+
+    fn enter(&self, _: &Id) {}
+
+    fn exit(&self, _: &Id) {}
+}
+
+fn field<'a>(fields: &'a [(String, String)], name: &str) -> Option<&'a str> {
+    fields
+        .iter()
+        .find(|(field, _)| field == name)
+        .map(|(_, value)| value.as_str())
+}
+
+#[test]
+fn searches_report_every_iteration() {
+    let recorder = Recorder::default();
+    tracing::subscriber::with_default(recorder.clone(), || {
+        let mut searcher = Searcher::new(DiscDifference);
+        searcher.search(&Board::standard(), Stone::Black, SearchLimits::depth(4));
+    });
+
+    let spans = recorder.spans.lock().unwrap();
+    assert_eq!(1, spans.iter().filter(|name| *name == "search").count());
+    assert_eq!(4, spans.iter().filter(|name| *name == "iteration").count());
+
+    let events = recorder.events.lock().unwrap();
+    let depths: Vec<&str> = events
+        .iter()
+        .filter_map(|fields| field(fields, "depth"))
+        .collect();
+    assert_eq!(vec!["1", "2", "3", "4"], depths);
+    for fields in events.iter() {
+        for name in ["nodes", "tt_hit_rate", "beta_cutoffs", "elapsed_ms"] {
+            assert!(field(fields, name).is_some(), "{name} is missing");
+        }
+        let rate: f64 = field(fields, "tt_hit_rate").unwrap().parse().unwrap();
+        assert!((0.0..=1.0).contains(&rate));
+    }
+}
+
+#[test]
+fn protocol_commands_are_spanned() {
+    let recorder = Recorder::default();
+    tracing::subscriber::with_default(recorder.clone(), || {
+        let mut server = NboardServer::new(Searcher::new(DiscDifference));
+        let mut output = Vec::new();
+        server
+            .run("nboard 2\nset depth 2\ngo\n".as_bytes(), &mut output)
+            .unwrap();
+    });
+
+    let spans = recorder.spans.lock().unwrap();
+    assert_eq!(3, spans.iter().filter(|name| *name == "nboard").count());
+    assert!(spans.iter().any(|name| name == "search"));
+}
@@ -0,0 +1,81 @@
+use magpie::othello::{Game, Move, Status, Transcript, TranscriptError};
+use rand::seq::IteratorRandom;
+
+#[test]
+fn transcript_random_games_roundtrip() {
+    let mut rng = rand::rng();
+    for _ in 0..100 {
+        let mut game = Game::new();
+        let mut transcript = Transcript::new();
+        while game.status() == Status::Progressing {
+            if game.pass_if_forced() {
+                transcript.push(Move::Pass);
+                continue;
+            }
+            let pos = game.moves().hot_bits().choose(&mut rng).unwrap();
+            game.play(pos).unwrap();
+            transcript.push(Move::Play(pos));
+        }
+
+        let parsed: Transcript = transcript.to_string().parse().unwrap();
+        assert_eq!(transcript, parsed);
+        assert_eq!(game.board(), parsed.replay().unwrap().board());
+
+        // Passes can be inferred when they are left out
+        let without_passes: Transcript = transcript
+            .moves()
+            .iter()
+            .copied()
+            .filter(|mv| *mv != Move::Pass)
+            .collect();
+        assert_eq!(game.board(), without_passes.replay().unwrap().board());
+
+        let text = transcript.to_string();
+        let replayed = text.parse::<Transcript>().unwrap().replay().unwrap();
+        assert_eq!(text, replayed.transcript().to_string());
+        assert_eq!(transcript, Transcript::from(&game));
+    }
+}
+
+#[test]
+fn transcript_rejects_illegal_moves() {
+    let transcript: Transcript = "f5f5".parse().unwrap();
+    assert_eq!(Err(TranscriptError::IllegalMove(1)), transcript.replay());
+
+    let transcript: Transcript = "f5--".parse().unwrap();
+    assert_eq!(Err(TranscriptError::IllegalMove(1)), transcript.replay());
+}
+
+#[test]
+fn transcript_rejects_invalid_notation() {
+    let result = "f5d6z9".parse::<Transcript>();
+    assert_eq!(Err(TranscriptError::InvalidNotation(2)), result);
+}
+
+#[cfg(feature = "openings")]
+#[test]
+fn opening_names_survive_symmetries_and_later_moves() {
+    use magpie::othello::{Symmetry, book::NAMED_OPENINGS};
+
+    for (name, line) in NAMED_OPENINGS {
+        let transcript: Transcript = line.parse().unwrap();
+        assert_eq!(Some(*name), transcript.opening_name());
+        for symmetry in Symmetry::ALL {
+            let transformed: Transcript = transcript
+                .moves()
+                .iter()
+                .map(|mv| match mv {
+                    Move::Play(pos) => Move::Play(pos.transform(symmetry)),
+                    Move::Pass => Move::Pass,
+                })
+                .collect();
+            // Not every symmetry keeps the opening position in place
+            if transformed.replay().is_ok() {
+                assert_eq!(Some(*name), transformed.opening_name());
+            }
+        }
+    }
+    let transcript: Transcript = "f5d6c3d3c4f4f6f3e6e7d7".parse().unwrap();
+    assert_eq!(Some("Rose-bill"), transcript.opening_name());
+    assert_eq!(None, Transcript::new().opening_name());
+}
@@ -0,0 +1,141 @@
+use magpie::{
+    othello::{Board, Move, OthelloError, Stone, Transcript, TranscriptError},
+    tree::{GameDag, Node},
+};
+use rand::seq::IteratorRandom;
+
+/// Plays a random game, returning the moves without passes.
+fn random_game() -> Vec<Move> {
+    let mut rng = rand::rng();
+    let mut board = Board::standard();
+    let mut stone = Stone::Black;
+    let mut moves = Vec::new();
+    loop {
+        if board.moves_for(stone).is_empty() {
+            stone = stone.flip();
+        }
+        let Some(pos) = board.moves_for(stone).hot_bits().choose(&mut rng) else {
+            return moves;
+        };
+        board.play(stone, pos);
+        moves.push(Move::Play(pos));
+        stone = stone.flip();
+    }
+}
+
+#[test]
+fn children_lead_to_their_nodes() {
+    let mut dag: GameDag = GameDag::default();
+    for _ in 0..20 {
+        let transcript = Transcript::from(random_game());
+        dag.insert_line(&transcript).unwrap();
+    }
+
+    let mut board = Board::standard();
+    let mut stone = Stone::Black;
+    let mut checked = 0;
+    let transcript = Transcript::from(random_game());
+    dag.insert_line(&transcript).unwrap();
+    for mv in transcript.moves() {
+        if board.moves_for(stone).is_empty() {
+            stone = stone.flip();
+        }
+        let node = dag.node(dag.get(&board, stone).unwrap());
+        assert_eq!(board.canonical().0, node.board());
+        assert_eq!(stone, node.stone());
+
+        for (child_move, child) in dag.children(&board, stone) {
+            let Move::Play(pos) = child_move else {
+                panic!("passes are only forced when there are no moves");
+            };
+            let mut next = board;
+            next.play(stone, pos);
+            assert_eq!(next.canonical().0, dag.node(child).board());
+            assert!(
+                dag.node(child)
+                    .parents()
+                    .contains(&dag.get(&board, stone).unwrap())
+            );
+            checked += 1;
+        }
+
+        let Move::Play(pos) = mv else { unreachable!() };
+        board.play(stone, *pos);
+        stone = stone.flip();
+    }
+    assert!(checked >= transcript.len());
+}
+
+#[test]
+fn transpositions_share_nodes() {
+    let mut dag: GameDag<i32> = GameDag::default();
+    let a = dag.insert_line(&"f5d6c3d3c4".parse().unwrap()).unwrap();
+    let b = dag.insert_line(&"f5d6c4d3c3".parse().unwrap()).unwrap();
+    assert_eq!(a, b);
+    let parents = dag.node(a).parents();
+    assert_eq!(2, parents.len());
+    assert_ne!(parents[0], parents[1]);
+    // A tree of the same positions would have one edge less
+    assert_eq!(dag.node_count(), dag.edge_count());
+
+    *dag.annotation_mut(b) = 7;
+    let game = "f5d6c3d3c4"
+        .parse::<Transcript>()
+        .unwrap()
+        .replay()
+        .unwrap();
+    let node = dag.get(&game.board(), game.current_turn()).unwrap();
+    assert_eq!(7, *dag.node(node).annotation());
+}
+
+#[test]
+fn illegal_moves_are_rejected() {
+    let mut dag: GameDag = GameDag::default();
+    let board = Board::standard();
+    assert_eq!(
+        Err(OthelloError::IllegalMove),
+        dag.add_move(&board, Stone::Black, Move::Play("a1".parse().unwrap()))
+    );
+    assert_eq!(
+        Err(OthelloError::IllegalMove),
+        dag.add_move(&board, Stone::Black, Move::Pass)
+    );
+    assert_eq!(
+        Err(TranscriptError::IllegalMove(1)),
+        dag.insert_line(&"f5f5".parse().unwrap())
+    );
+    // The first move is still added
+    assert_eq!(2, dag.node_count());
+}
+
+/// Returns the number of nodes and edges in a graph written as DOT.
+fn count_dot(dot: &str) -> (usize, usize) {
+    let edges = dot.lines().filter(|line| line.contains(" -> ")).count();
+    let nodes = dot.lines().filter(|line| line.contains("[label=")).count() - edges;
+    (nodes, edges)
+}
+
+#[test]
+fn dot_export_respects_depth_and_filter() {
+    let mut dag: GameDag<i32> = GameDag::default();
+    let good = dag.insert_line(&"f5d6c3d3".parse().unwrap()).unwrap();
+    let bad = dag.insert_line(&"f5f6e6f4".parse().unwrap()).unwrap();
+    *dag.annotation_mut(good) = 5;
+    let bad_parent = dag.node(bad).parents()[0];
+    *dag.annotation_mut(bad_parent) = -40;
+    *dag.annotation_mut(bad) = -40;
+
+    let full = dag.to_dot();
+    assert_eq!((dag.node_count(), dag.edge_count()), count_dot(&full));
+
+    let label = |node: &Node<i32>| node.annotation().to_string();
+    let filter = |node: &Node<i32>| *node.annotation() > -10;
+    let dot = dag.dot().with_label(&label).with_filter(&filter).to_dot();
+    assert!(dot.contains("[label=\"5\"]"));
+    assert!(!dot.contains("-40"));
+    // The first line, along with the second move of the other line
+    assert_eq!((6, 5), count_dot(&dot));
+
+    let shallow = dag.dot().with_max_depth(1).to_dot();
+    assert_eq!((2, 1), count_dot(&shallow));
+}
@@ -0,0 +1,131 @@
+use magpie::{
+    eval::{Evaluator, Heuristic, Pattern, PatternEvaluator, Tapered},
+    othello::{Board, Stone},
+    search::RandomRollout,
+    selfplay::{Record, SelfPlay},
+    tuning::{Sample, Tunable, Tuner, fit_probcut},
+};
+
+fn samples(games: usize) -> Vec<Sample> {
+    let mut records: Vec<Record> = Vec::new();
+    SelfPlay::new(|| RandomRollout::new(1), || RandomRollout::new(2))
+        .with_games(games)
+        .run(&mut records);
+    records.into_iter().map(Sample::from).collect()
+}
+
+fn assert_features_match<T: Tunable + Evaluator>(evaluator: &T, samples: &[Sample]) {
+    let weights = evaluator.parameters();
+    let mut features = Vec::new();
+    for sample in samples {
+        features.clear();
+        evaluator.features(&sample.board, sample.stone, &mut features);
+        let eval: f64 = features
+            .iter()
+            .map(|(index, value)| weights[*index] * value)
+            .sum();
+        assert_eq!(
+            f64::from(evaluator.evaluate(&sample.board, sample.stone)),
+            eval
+        );
+    }
+}
+
+#[test]
+fn features_reproduce_the_evaluation() {
+    let samples = samples(4);
+    assert_features_match(&Heuristic::default(), &samples);
+
+    let mut patterns = PatternEvaluator::new(4);
+    for (index, weight) in patterns
+        .weights_mut(2, Pattern::Edge2X)
+        .iter_mut()
+        .enumerate()
+    {
+        *weight = index as i32 % 7 - 3;
+    }
+    patterns.weights_mut(3, Pattern::Diagonal5)[42] = 11;
+    assert_features_match(&patterns, &samples);
+}
+
+#[test]
+fn tapered_features_blend_the_phases() {
+    let tapered = Tapered::new(vec![
+        Heuristic::default(),
+        Heuristic {
+            discs: 20,
+            ..Heuristic::default()
+        },
+    ]);
+    let weights = tapered.parameters();
+    assert_eq!(10, weights.len());
+    assert_eq!(10, tapered.parameter_count());
+
+    let mut features = Vec::new();
+    for sample in samples(4) {
+        features.clear();
+        tapered.features(&sample.board, sample.stone, &mut features);
+        let eval: f64 = features
+            .iter()
+            .map(|(index, value)| weights[*index] * value)
+            .sum();
+        // Blended evaluations are rounded towards zero
+        let expected = f64::from(tapered.evaluate(&sample.board, sample.stone));
+        assert!((eval - expected).abs() < 1.0);
+    }
+}
+
+#[test]
+fn set_weights_round_trips() {
+    let mut heuristic = Heuristic::default();
+    heuristic.set_parameters(&[1.0, 2.4, 2.6, -4.0, 5.0]);
+    assert_eq!([1.0, 2.0, 3.0, -4.0, 5.0], heuristic.parameters()[..]);
+
+    let mut patterns = PatternEvaluator::new(2);
+    let mut weights = patterns.parameters();
+    weights[12345] = 17.0;
+    patterns.set_parameters(&weights);
+    assert_eq!(weights, patterns.parameters());
+}
+
+#[test]
+fn tuning_reduces_the_loss() {
+    let samples = samples(40);
+    let tuner = Tuner::new().with_epochs(50).with_validation(0.25);
+
+    let mut heuristic = Heuristic {
+        mobility: 0,
+        frontier: 0,
+        corners: 0,
+        x_squares: 0,
+        discs: 0,
+    };
+    let mut reports = Vec::new();
+    let last = tuner.fit_with(&mut heuristic, &samples, |report| {
+        reports.push(*report);
+        std::ops::ControlFlow::Continue(())
+    });
+    assert_eq!(51, reports.len());
+    assert_eq!(Some(&last), reports.last());
+    assert!(last.training_loss < reports[0].training_loss);
+    assert!(last.validation_loss.unwrap() < reports[0].validation_loss.unwrap());
+    assert!(heuristic.corners > 0);
+
+    // Every position of the standard opening is a draw by symmetry
+    let draw = [Sample::from_score(Board::standard(), Stone::Black, 0)];
+    let mut patterns = PatternEvaluator::new(1);
+    let report = Tuner::new().with_epochs(10).fit(&mut patterns, &draw);
+    assert!(report.training_loss <= 2.0_f64.ln() + 1e-9);
+}
+
+#[test]
+fn probcut_fits_deep_scores() {
+    let samples = samples(4);
+    let heuristic = Heuristic::default();
+    let pair = fit_probcut(&heuristic, &samples, 4, 2).unwrap();
+    assert_eq!((4, 2), (pair.depth, pair.shallow));
+    assert!(pair.slope > 0.0);
+    assert!(pair.sigma > 0.0 && pair.sigma.is_finite());
+    assert_eq!(None, fit_probcut(&heuristic, &samples, 2, 2));
+    assert_eq!(None, fit_probcut(&heuristic, &samples[..1], 4, 2));
+}
@@ -0,0 +1,59 @@
+#![cfg(feature = "wasm")]
+
+use magpie::{
+    othello::{Board, Game, Stone},
+    wasm::{WasmBoard, WasmGame, WasmPosition, WasmStatus, WasmStone},
+};
+
+#[test]
+fn wasm_board_matches_board() {
+    let board = WasmBoard::new();
+    let standard = Board::standard();
+    assert_eq!(
+        standard.bits_for(Stone::Black).raw(),
+        board.bits(WasmStone::Black)
+    );
+    assert_eq!(
+        standard.moves_for(Stone::White).raw(),
+        board.legal_moves(WasmStone::White)
+    );
+    assert_eq!(vec!["d3", "c4", "f5", "e6"], board.moves(WasmStone::Black));
+
+    let cells = board.cells();
+    assert_eq!(64, cells.len());
+    let d4 = WasmPosition::new("d4").unwrap().index() as usize;
+    let e4 = WasmPosition::new("e4").unwrap().index() as usize;
+    assert_eq!(2, cells[d4]);
+    assert_eq!(1, cells[e4]);
+    assert_eq!(60, cells.iter().filter(|cell| **cell == 0).count());
+}
+
+#[test]
+fn wasm_game_plays_full_game() {
+    let mut wasm = WasmGame::new();
+    let mut game = Game::new();
+    while wasm.status() == WasmStatus::Progressing {
+        assert_eq!(game.pass_if_forced(), wasm.pass_if_forced());
+        let pos = game.moves().hot_bits().next().unwrap();
+        game.play(pos).unwrap();
+        wasm.play(&pos.to_string()).unwrap();
+        assert_eq!(game.board(), magpie_board(&wasm.board()));
+    }
+    assert!(wasm.undo());
+    assert!(wasm.redo());
+    assert!(!wasm.redo());
+}
+
+#[test]
+fn wasm_positions_round_trip() {
+    for index in 0..64 {
+        let position = WasmPosition::from_index(index).unwrap();
+        assert_eq!(index, position.index());
+        let parsed = WasmPosition::new(&position.to_string()).unwrap();
+        assert_eq!(position, parsed);
+    }
+}
+
+fn magpie_board(board: &WasmBoard) -> Board {
+    Board::try_from((board.bits(WasmStone::Black), board.bits(WasmStone::White))).unwrap()
+}
@@ -0,0 +1,104 @@
+#![cfg(feature = "wthor")]
+
+use magpie::{
+    othello::{Game, Status, Stone},
+    wthor::{GameReader, WthorError, read_games},
+};
+use rand::seq::IteratorRandom;
+
+fn header(game_count: u32) -> Vec<u8> {
+    let mut bytes = vec![0; 16];
+    bytes[0] = 20;
+    bytes[1] = 24;
+    bytes[4..8].copy_from_slice(&game_count.to_le_bytes());
+    bytes[10..12].copy_from_slice(&2024_u16.to_le_bytes());
+    bytes[12] = 8;
+    bytes
+}
+
+fn record(game: &[u8], black_score: u8) -> Vec<u8> {
+    let mut bytes = vec![0; 68];
+    bytes[0..2].copy_from_slice(&3_u16.to_le_bytes());
+    bytes[2..4].copy_from_slice(&1_u16.to_le_bytes());
+    bytes[4..6].copy_from_slice(&2_u16.to_le_bytes());
+    bytes[6] = black_score;
+    bytes[7] = black_score;
+    bytes[8..8 + game.len()].copy_from_slice(game);
+    bytes
+}
+
+#[test]
+fn wthor_random_games_replay() {
+    let mut rng = rand::rng();
+    let mut bytes = header(20);
+    let mut expected = Vec::new();
+    for _ in 0..20 {
+        let mut game = Game::new();
+        let mut moves = Vec::new();
+        while game.status() == Status::Progressing {
+            if game.pass_if_forced() {
+                continue;
+            }
+            let pos = game.moves().hot_bits().choose(&mut rng).unwrap();
+            game.play(pos).unwrap();
+            moves.push((pos.rank() + 1) * 10 + pos.file() + 1);
+        }
+        let black_score = game.bits_for(Stone::Black).count_set();
+        bytes.extend(record(&moves, black_score));
+        expected.push(game.board());
+    }
+
+    let (header, games) = read_games(&bytes).unwrap();
+    assert_eq!(2024, header.created_year);
+    assert_eq!(2024, header.game_year);
+    assert_eq!(20, games.len());
+    for (record, board) in games.iter().zip(expected) {
+        assert_eq!(
+            (3, 1, 2),
+            (record.tournament, record.black_player, record.white_player)
+        );
+        let game = record.replay().unwrap();
+        assert_eq!(board, game.board());
+        assert_eq!(record.black_score, game.bits_for(Stone::Black).count_set());
+    }
+}
+
+#[test]
+fn wthor_rejects_malformed_files() {
+    let mut bytes = header(2);
+    bytes.extend(record(&[56], 0));
+    assert_eq!(Err(WthorError::Truncated), read_games(&bytes));
+
+    let mut bytes = header(1);
+    bytes.extend(record(&[56, 99], 0));
+    assert_eq!(Err(WthorError::InvalidMove(0)), read_games(&bytes));
+
+    let mut bytes = header(0);
+    bytes[12] = 10;
+    assert_eq!(
+        Err(WthorError::UnsupportedBoardSize(10)),
+        read_games(&bytes)
+    );
+}
+
+#[test]
+fn wthor_reader_matches_read_games() {
+    let mut bytes = header(3);
+    bytes.extend(record(&[56, 66, 65], 0));
+    bytes.extend(record(&[56, 64], 0));
+    bytes.extend(record(&[56], 0));
+
+    let (_, expected) = read_games(&bytes).unwrap();
+    let reader = GameReader::new(bytes.as_slice()).unwrap();
+    assert_eq!(2024, reader.header().game_year);
+    assert_eq!((0, Some(3)), reader.size_hint());
+    let games: Vec<_> = reader.collect::<Result<_, _>>().unwrap();
+    assert_eq!(expected, games);
+
+    bytes.truncate(bytes.len() - 1);
+    let mut reader = GameReader::new(bytes.as_slice()).unwrap();
+    assert!(reader.next().unwrap().is_ok());
+    assert!(reader.next().unwrap().is_ok());
+    assert_eq!(Some(Err(WthorError::Truncated)), reader.next());
+    assert_eq!(None, reader.next());
+}